@@ -0,0 +1,728 @@
+//! Annotations of functions, classes and fields, e.g. `@deprecated("Use jump instead")`, and the compile-time
+//! plugins handling them.
+// I Language annotations.
+// Version: 1.0.0
+
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use crate::classes::ClassTable;
+use crate::diagnostic::Diagnostic;
+use crate::generics::{self, TypeNames};
+use crate::modules::Imports;
+use crate::resolve::{self, address, Declaration, Resolution};
+use crate::types::{self, Types};
+
+use lexer::tokens::token::{Location, TypeDefinition};
+use parser::ast::{
+    Annotation, Argument, Class, ClassMember, Expression, Field, Function, Identifier, Member,
+    Program, Statement, TypeAnnotation,
+};
+use parser::visit::{self, Visitor};
+
+
+/////////////
+// TARGETS //
+/////////////
+
+/// A declaration an annotation is attached to.
+#[derive(Clone, Copy, Debug)]
+pub enum Target<'ast> {
+    /// A class, interface or enum.
+    Class(&'ast Class),
+    /// A field of a class.
+    Field(&'ast Field),
+    /// A function outside of classes.
+    Function(&'ast Function),
+    /// A method or a constructor of a class.
+    Method(&'ast Function),
+}
+
+impl<'ast> Target<'ast> {
+    /// Returns the annotations of the declaration, in source order.
+    #[inline]
+    pub fn annotations(&self) -> &'ast [Annotation] {
+        match *self {
+            Self::Class(class) => &class.annotations,
+            Self::Field(field) => &field.annotations,
+            Self::Function(function) | Self::Method(function) => &function.annotations,
+        }
+    }
+
+    /// Returns the declared name.
+    #[inline]
+    pub fn name(&self) -> &'ast Identifier {
+        match *self {
+            Self::Class(class) => &class.name,
+            Self::Field(field) => &field.name,
+            Self::Function(function) | Self::Method(function) => &function.name,
+        }
+    }
+
+    /// Returns the kind of the declaration used in messages, e.g. "method".
+    #[inline]
+    pub fn kind(&self) -> &'static str {
+        match *self {
+            Self::Class(class) if class.interface => "interface",
+            Self::Class(class) if class.enumeration => "enum",
+            Self::Class(_) => "class",
+            Self::Field(_) => "field",
+            Self::Function(_) => "function",
+            Self::Method(_) => "method",
+        }
+    }
+}
+
+
+/////////////
+// PLUGINS //
+/////////////
+
+/// A compile-time plugin handling the annotations with a given name.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::annotations::{self, Plugin, Plugins, Target};
+/// # use compiler::diagnostic::Diagnostic;
+/// # use compiler::modules::Imports;
+/// # use parser::ast::Annotation;
+/// struct Pure;
+///
+/// impl Plugin for Pure {
+///     fn name(&self) -> &'static str {
+///         "pure"
+///     }
+///
+///     fn check(&self, annotation: &Annotation, target: Target<'_>) -> Vec<Diagnostic> {
+///         match target {
+///             Target::Function(_) => vec![],
+///             _ => vec![Diagnostic::error("E0067", "`@pure` can only be used on functions", annotation.location.clone())],
+///         }
+///     }
+/// }
+///
+/// let tokens = lexer::lex::lex("@pure function double(int x) -> int { return x * 2; }", "<stdin>").unwrap();
+/// let program = parser::parse::parse(&tokens).unwrap();
+/// let mut plugins = Plugins::default();
+/// plugins.register(Box::new(Pure));
+///
+/// assert!(annotations::check_with(&program, &Imports::default(), &plugins).is_empty());
+/// ```
+pub trait Plugin {
+    /// Returns the name of the annotations the plugin handles, without the `@` mark.
+    fn name(&self) -> &'static str;
+
+    /// Checks an annotation at the declaration it is attached to, e.g. its arguments.
+    ///
+    /// # Parameters
+    ///
+    /// - `annotation`: The annotation, named like the plugin.
+    /// - `target`: The annotated declaration.
+    ///
+    /// # Returns
+    ///
+    /// The diagnostics found.
+    fn check(&self, annotation: &Annotation, target: Target<'_>) -> Vec<Diagnostic>;
+
+    /// Checks a use of an annotated declaration, e.g. a call of an annotated function. Uses inside the
+    /// declaration itself and uses of members inside their class are not checked. Does nothing by default.
+    ///
+    /// # Parameters
+    ///
+    /// - `annotation`: The annotation, named like the plugin.
+    /// - `target`: The annotated declaration.
+    /// - `location`: The location of the use.
+    ///
+    /// # Returns
+    ///
+    /// The diagnostics found.
+    #[inline]
+    fn check_use(
+        &self,
+        annotation: &Annotation,
+        target: Target<'_>,
+        location: &Location,
+    ) -> Vec<Diagnostic> {
+        let _: (&Annotation, Target<'_>, &Location) = (annotation, target, location);
+        vec![]
+    }
+}
+
+/// The plugins used to check annotations, by name. The default plugins handle the built-in annotations
+/// `@deprecated`, `@test`, `@inline` and `@noinline`.
+pub struct Plugins {
+    /// The registered plugins, in registration order.
+    plugins: Vec<Box<dyn Plugin>>,
+}
+
+impl Plugins {
+    /// Creates a set of plugins without any plugin, not even the built-in ones.
+    #[inline]
+    pub fn new() -> Self {
+        Self { plugins: vec![] }
+    }
+
+    /// Registers a plugin. It replaces a plugin registered before with the same name.
+    #[inline]
+    pub fn register(&mut self, plugin: Box<dyn Plugin>) {
+        self.plugins.retain(|found| found.name() != plugin.name());
+        self.plugins.push(plugin);
+    }
+
+    /// Returns the plugin handling the annotations with the given name.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&dyn Plugin> {
+        self.plugins
+            .iter()
+            .find(|plugin| plugin.name() == name)
+            .map(Box::as_ref)
+    }
+
+    /// Returns the names of all registered plugins, in registration order.
+    #[inline]
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.plugins.iter().map(|plugin| plugin.name())
+    }
+}
+
+impl Default for Plugins {
+    #[inline]
+    fn default() -> Self {
+        let mut plugins: Self = Self::new();
+        plugins.register(Box::new(Deprecated));
+        plugins.register(Box::new(Hint {
+            name: "inline",
+            opposite: "noinline",
+        }));
+        plugins.register(Box::new(Hint {
+            name: "noinline",
+            opposite: "inline",
+        }));
+        plugins.register(Box::new(Test));
+
+        plugins
+    }
+}
+
+
+//////////////////////
+// BUILT-IN PLUGINS //
+//////////////////////
+
+/// The `@deprecated` annotation with an optional message, e.g. `@deprecated("Use jump instead")`. Every use of the
+/// annotated declaration is reported with a warning.
+struct Deprecated;
+
+impl Plugin for Deprecated {
+    fn name(&self) -> &'static str {
+        "deprecated"
+    }
+
+    fn check(&self, annotation: &Annotation, _target: Target<'_>) -> Vec<Diagnostic> {
+        match *annotation.arguments {
+            [] => vec![],
+            [ref argument] if message(argument).is_some() => vec![],
+            _ => vec![Diagnostic::error(
+                "E0067",
+                "`@deprecated` takes a single `str` message",
+                annotation.location.clone(),
+            )
+            .with_label("invalid arguments")],
+        }
+    }
+
+    fn check_use(
+        &self,
+        annotation: &Annotation,
+        target: Target<'_>,
+        location: &Location,
+    ) -> Vec<Diagnostic> {
+        let name: &str = &target.name().name;
+        let note: String = annotation.arguments.first().and_then(message).map_or_else(
+            || format!("`{name}` is marked with `@deprecated`"),
+            str::to_owned,
+        );
+
+        vec![Diagnostic::warning(
+            "E0068",
+            &format!("use of deprecated {} `{name}`", target.kind()),
+            location.clone(),
+        )
+        .with_label("deprecated")
+        .with_note(&note, Some(annotation.location.clone()))]
+    }
+}
+
+/// Returns the message of a `@deprecated` annotation: a string literal, optionally passed as `message = "..."`.
+fn message(argument: &Argument) -> Option<&str> {
+    if argument
+        .name
+        .as_ref()
+        .map_or(false, |name| name.name != "message")
+    {
+        return None;
+    }
+
+    match argument.value {
+        Expression::Literal(ref literal) if literal.kind == TypeDefinition::String => {
+            Some(&literal.value)
+        }
+        _ => None,
+    }
+}
+
+/// The `@inline` and `@noinline` annotations, hints for the backend whether to inline calls of a function or
+/// method. See [`inlining`].
+struct Hint {
+    /// The name of the annotation.
+    name: &'static str,
+    /// The name of the annotation giving the opposite hint.
+    opposite: &'static str,
+}
+
+impl Plugin for Hint {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn check(&self, annotation: &Annotation, target: Target<'_>) -> Vec<Diagnostic> {
+        let mut diagnostics: Vec<Diagnostic> = no_arguments(annotation).into_iter().collect();
+        if !matches!(target, Target::Function(_) | Target::Method(_)) {
+            diagnostics.push(wrong_target(annotation, target, "functions and methods"));
+        }
+
+        // Only the later of two conflicting hints is reported
+        if let Some(other) = target
+            .annotations()
+            .iter()
+            .take_while(|found| !core::ptr::eq(*found, annotation))
+            .find(|found| found.name.name == self.opposite)
+        {
+            diagnostics.push(
+                Diagnostic::error(
+                    "E0067",
+                    &format!("`@{}` conflicts with `@{}`", self.name, self.opposite),
+                    annotation.location.clone(),
+                )
+                .with_label("conflicting hint")
+                .with_note(
+                    &format!("`@{}` is used here", self.opposite),
+                    Some(other.location.clone()),
+                ),
+            );
+        }
+
+        diagnostics
+    }
+}
+
+/// The `@test` annotation, marking a function without parameters the test runner calls. See [`tests`].
+struct Test;
+
+impl Plugin for Test {
+    fn name(&self) -> &'static str {
+        "test"
+    }
+
+    fn check(&self, annotation: &Annotation, target: Target<'_>) -> Vec<Diagnostic> {
+        let mut diagnostics: Vec<Diagnostic> = no_arguments(annotation).into_iter().collect();
+        match target {
+            Target::Function(function) => {
+                if let Some(parameter) = function.parameters.first() {
+                    diagnostics.push(
+                        Diagnostic::error(
+                            "E0067",
+                            &format!(
+                                "test function `{}` cannot have parameters",
+                                function.name.name
+                            ),
+                            parameter.location.clone(),
+                        )
+                        .with_label("the test runner cannot pass arguments")
+                        .with_note("marked as test here", Some(annotation.location.clone())),
+                    );
+                }
+            }
+            _ => diagnostics.push(wrong_target(annotation, target, "functions")),
+        }
+
+        diagnostics
+    }
+}
+
+/// Returns an error if an annotation that takes no arguments has arguments.
+fn no_arguments(annotation: &Annotation) -> Option<Diagnostic> {
+    let argument: &Argument = annotation.arguments.first()?;
+    Some(
+        Diagnostic::error(
+            "E0067",
+            &format!("`@{}` takes no arguments", annotation.name.name),
+            argument.location.clone(),
+        )
+        .with_label("unexpected argument"),
+    )
+}
+
+/// Returns the error for an annotation attached to a kind of declaration it cannot be used on.
+fn wrong_target(annotation: &Annotation, target: Target<'_>, allowed: &str) -> Diagnostic {
+    Diagnostic::error(
+        "E0067",
+        &format!("`@{}` can only be used on {allowed}", annotation.name.name),
+        annotation.location.clone(),
+    )
+    .with_label(&format!("cannot be used on a {}", target.kind()))
+}
+
+
+/////////////
+// QUERIES //
+/////////////
+
+/// Returns the first annotation with the given name, without the `@` mark.
+#[inline]
+pub fn find<'ast>(annotations: &'ast [Annotation], name: &str) -> Option<&'ast Annotation> {
+    annotations
+        .iter()
+        .find(|annotation| annotation.name.name == name)
+}
+
+/// Returns the functions of a program marked with `@test`, in source order. Only functions at the top level of
+/// the program are tests.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::annotations;
+/// let tokens = lexer::lex::lex("@test function adds() { } function helper() { }", "<stdin>").unwrap();
+/// let program = parser::parse::parse(&tokens).unwrap();
+/// let tests = annotations::tests(&program);
+///
+/// assert_eq!(tests.len(), 1);
+/// assert_eq!(tests[0].name.name, "adds");
+/// ```
+#[inline]
+pub fn tests(program: &Program) -> Vec<&Function> {
+    program
+        .statements
+        .iter()
+        .filter_map(|statement| match *statement {
+            Statement::Function(ref function) if find(&function.annotations, "test").is_some() => {
+                Some(function)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Whether the backend should inline calls of a function, as hinted with `@inline` or `@noinline`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Inlining {
+    /// No hint is given, the backend decides.
+    Default,
+    /// Calls should be inlined, hinted with `@inline`.
+    Always,
+    /// Calls should never be inlined, hinted with `@noinline`.
+    Never,
+}
+
+/// Returns the inlining hint of a function or method.
+#[inline]
+pub fn inlining(function: &Function) -> Inlining {
+    if find(&function.annotations, "noinline").is_some() {
+        Inlining::Never
+    } else if find(&function.annotations, "inline").is_some() {
+        Inlining::Always
+    } else {
+        Inlining::Default
+    }
+}
+
+
+////////////
+// CHECKS //
+////////////
+
+/// Checks all annotations of a program with the default [`Plugins`], see [`check_with`].
+///
+/// # Parameters
+///
+/// - `program`: The program to check.
+/// - `imports`: The names the program imports from other modules.
+///
+/// # Returns
+///
+/// The diagnostics found, in source order.
+#[inline]
+pub fn check<'ast>(program: &'ast Program, imports: &Imports<'ast>) -> Vec<Diagnostic> {
+    check_with(program, imports, &Plugins::default())
+}
+
+/// Checks all annotations of a program and the uses of annotated declarations with the given plugins.
+///
+/// Every annotation must be handled by a plugin and may only be attached once to the same declaration. Uses of
+/// annotated functions, classes and fields are passed to their plugins, including uses of declarations imported
+/// from other modules.
+///
+/// # Parameters
+///
+/// - `program`: The program to check.
+/// - `imports`: The names the program imports from other modules.
+/// - `plugins`: The plugins handling the annotations.
+///
+/// # Returns
+///
+/// The diagnostics found, in source order.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::{annotations, modules::Imports};
+/// let source = "@deprecated(\"Use jump instead\") function hop() { } hop();";
+/// let tokens = lexer::lex::lex(source, "<stdin>").unwrap();
+/// let program = parser::parse::parse(&tokens).unwrap();
+/// let diagnostics = annotations::check(&program, &Imports::default());
+///
+/// assert_eq!(diagnostics[0].code, "E0068");
+/// assert_eq!(diagnostics[0].notes[0].message, "Use jump instead");
+/// ```
+#[inline]
+pub fn check_with<'ast>(
+    program: &'ast Program,
+    imports: &Imports<'ast>,
+    plugins: &Plugins,
+) -> Vec<Diagnostic> {
+    let names: TypeNames = TypeNames::default();
+    let classes: ClassTable = ClassTable::new(program, imports);
+    let resolution: Resolution = resolve::resolve(program, imports);
+    let types: Types = types::infer_with(program, &names, &classes, &resolution);
+    check_analyzed(program, plugins, &classes, &resolution, &types)
+}
+
+/// Checks all annotations of a program like [`check_with`], with the classes, the resolved names and the types of
+/// the program already determined.
+///
+/// # Parameters
+///
+/// - `program`: The program to check.
+/// - `plugins`: The plugins handling the annotations.
+/// - `classes`: The classes of the program, see [`ClassTable::new`].
+/// - `resolution`: The declarations of the names used in the program, see [`resolve::resolve`].
+/// - `types`: The types of the expressions of the program, see [`types::infer_with`].
+///
+/// # Returns
+///
+/// The diagnostics found, in source order.
+#[inline]
+pub fn check_analyzed<'ast>(
+    program: &'ast Program,
+    plugins: &Plugins,
+    classes: &ClassTable<'ast>,
+    resolution: &Resolution<'ast>,
+    types: &Types<'ast>,
+) -> Vec<Diagnostic> {
+    let mut checker: AnnotationChecker = AnnotationChecker {
+        plugins,
+        classes,
+        resolution,
+        types,
+        enclosing: vec![],
+        diagnostics: vec![],
+    };
+    checker.visit_program(program);
+
+    checker.diagnostics
+}
+
+
+////////////////////////
+// ANNOTATION CHECKER //
+////////////////////////
+
+/// A visitor checking annotations and the uses of annotated declarations.
+struct AnnotationChecker<'plugins, 'table, 'ast> {
+    /// The plugins handling the annotations.
+    plugins: &'plugins Plugins,
+    /// The classes of the program.
+    classes: &'table ClassTable<'ast>,
+    /// The declarations of the names used in the program.
+    resolution: &'table Resolution<'ast>,
+    /// The types of the expressions of the program.
+    types: &'table Types<'ast>,
+    /// The names of the declarations the visited node is in, innermost last.
+    enclosing: Vec<*const Identifier>,
+    /// The diagnostics found so far.
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'ast> AnnotationChecker<'_, '_, 'ast> {
+    /// Checks the annotations attached to a declaration.
+    fn declare(&mut self, target: Target<'ast>) {
+        let annotations: &[Annotation] = target.annotations();
+        for (index, annotation) in annotations.iter().enumerate() {
+            let name: &str = &annotation.name.name;
+            if let Some(first) = find(annotations.get(..index).unwrap_or_default(), name) {
+                self.diagnostics.push(
+                    Diagnostic::error(
+                        "E0067",
+                        &format!("duplicate annotation `@{name}`"),
+                        annotation.location.clone(),
+                    )
+                    .with_label("used more than once")
+                    .with_note("first used here", Some(first.location.clone())),
+                );
+                continue;
+            }
+
+            let Some(plugin) = self.plugins.get(name) else {
+                let mut known: Vec<String> = self
+                    .plugins
+                    .names()
+                    .map(|known| format!("`@{known}`"))
+                    .collect();
+                known.sort();
+                self.diagnostics.push(
+                    Diagnostic::error(
+                        "E0066",
+                        &format!("unknown annotation `@{name}`"),
+                        annotation.location.clone(),
+                    )
+                    .with_label("no plugin handles this annotation")
+                    .with_note(
+                        &format!("the known annotations are {}", known.join(", ")),
+                        None,
+                    ),
+                );
+                continue;
+            };
+
+            self.diagnostics.extend(plugin.check(annotation, target));
+        }
+    }
+
+    /// Passes a use of a declaration to the plugins of its annotations, unless it is used inside itself.
+    fn used(&mut self, target: Target<'ast>, location: &Location) {
+        if self.enclosing.contains(&address(target.name())) {
+            return;
+        }
+
+        let annotations: &[Annotation] = target.annotations();
+        for (index, annotation) in annotations.iter().enumerate() {
+            let name: &str = &annotation.name.name;
+            if find(annotations.get(..index).unwrap_or_default(), name).is_some() {
+                continue;
+            }
+
+            if let Some(plugin) = self.plugins.get(name) {
+                self.diagnostics
+                    .extend(plugin.check_use(annotation, target, location));
+            }
+        }
+    }
+
+    /// Checks a function or method and its body.
+    fn function(&mut self, node: &'ast Function, target: Target<'ast>) {
+        self.declare(target);
+        self.enclosing.push(&node.name);
+        visit::walk_function(self, node);
+        self.enclosing.pop();
+    }
+}
+
+impl<'ast> Visitor<'ast> for AnnotationChecker<'_, '_, 'ast> {
+    fn visit_class(&mut self, node: &'ast Class) {
+        self.declare(Target::Class(node));
+        self.enclosing.push(&node.name);
+        visit::walk_class(self, node);
+        self.enclosing.pop();
+    }
+
+    fn visit_class_member(&mut self, node: &'ast ClassMember) {
+        match *node {
+            ClassMember::Constructor(ref function) | ClassMember::Method(ref function) => {
+                self.function(function, Target::Method(function));
+            }
+            ClassMember::Field(ref field) => {
+                self.declare(Target::Field(field));
+                visit::walk_field(self, field);
+            }
+        }
+    }
+
+    fn visit_function(&mut self, node: &'ast Function) {
+        self.function(node, Target::Function(node));
+    }
+
+    fn visit_expression(&mut self, node: &'ast Expression) {
+        if let Expression::Identifier(ref identifier) = *node {
+            match self.resolution.declaration(identifier) {
+                Some(Declaration::Class(class)) => {
+                    self.used(Target::Class(class), &identifier.location);
+                }
+                Some(Declaration::Function(function)) => {
+                    self.used(Target::Function(function), &identifier.location);
+                }
+                _ => {}
+            }
+        }
+
+        visit::walk_expression(self, node);
+    }
+
+    fn visit_member(&mut self, node: &'ast Member) {
+        let class: Option<&Class> = self.types.type_of(&node.target).and_then(|found| {
+            self.classes
+                .get(generics::split(generics::non_null(found)).0)
+        });
+        if let Some(class) = class {
+            let name: &str = &node.member.name;
+            let member: Option<(&Class, Target)> = self
+                .classes
+                .find_method(class, name)
+                .map(|(owner, method)| (owner, Target::Method(method)))
+                .or_else(|| {
+                    self.classes
+                        .find_field(class, name)
+                        .map(|(owner, field)| (owner, Target::Field(field)))
+                });
+            // Members used inside the class declaring them are not reported
+            if let Some((owner, target)) = member {
+                if !self.enclosing.contains(&address(&owner.name)) {
+                    self.used(target, &node.member.location);
+                }
+            }
+        }
+
+        visit::walk_member(self, node);
+    }
+
+    fn visit_type_annotation(&mut self, node: &'ast TypeAnnotation) {
+        if let Some(class) = self.classes.get(&node.name) {
+            self.used(Target::Class(class), &node.location);
+        }
+
+        visit::walk_type_annotation(self, node);
+    }
+}
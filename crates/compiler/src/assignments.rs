@@ -0,0 +1,726 @@
+//! Checks that variables declared without a value are assigned on every path before they are read, and that fields
+//! and variables of the module that would start as `none` have a value.
+// I Language definite assignment analysis.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use std::collections::{HashMap, HashSet};
+
+use crate::classes::ClassTable;
+use crate::diagnostic::Diagnostic;
+use crate::integers;
+use crate::modules::Imports;
+use crate::patterns;
+use crate::resolve::{self, address, Declaration, Resolution};
+
+use lexer::tokens::mark::Mark;
+use lexer::tokens::token::{Location, TypeDefinition};
+use parser::ast::{
+    Block, Class, ClassMember, Expression, Field, ForIterable, FormatPart, Function, LambdaBody,
+    Match, MatchBody, Program, Statement, Try, TypeAnnotation, VariableDeclaration,
+};
+
+
+///////////
+// STATE //
+///////////
+
+/// A path that skips the assignment of a variable, used in the notes of diagnostics.
+#[derive(Clone, Debug)]
+struct Skip {
+    /// What happens on the path, e.g. "`{name}` is not assigned in this branch", with `{name}` standing for the
+    /// name of the variable.
+    message: String,
+    /// The location of the start of the path.
+    location: Location,
+}
+
+/// Whether a variable is assigned at a point of the program.
+#[derive(Clone, Debug)]
+enum Status {
+    /// The variable is assigned on every path reaching the point.
+    Assigned,
+    /// The variable is not assigned on at least one path, which is described if it is known.
+    Unassigned(Option<Skip>),
+}
+
+/// The status of each tracked variable at a point of the program, keyed by the address of its declaration.
+/// `None` if the point cannot be reached, e.g. after a `return`.
+type State = Option<HashMap<*const VariableDeclaration, Status>>;
+
+/// Joins the states of paths meeting at a point. A variable is only assigned if it is assigned on every reachable
+/// path. Variables that are not tracked on every path, because they are declared inside a branch, are dropped.
+///
+/// # Parameters
+///
+/// - `paths`: The states at the end of the paths, each with a description of the path used when it skips an
+///   assignment.
+#[allow(clippy::iter_over_hash_type)]
+fn join(paths: Vec<(State, Option<Skip>)>) -> State {
+    let reachable: Vec<(HashMap<*const VariableDeclaration, Status>, Option<Skip>)> = paths
+        .into_iter()
+        .filter_map(|(state, skip)| state.map(|found| (found, skip)))
+        .collect();
+    let &(ref first, _) = reachable.first()?;
+
+    let mut result: HashMap<*const VariableDeclaration, Status> = HashMap::new();
+    for &variable in first.keys() {
+        let mut status: Option<Status> = Some(Status::Assigned);
+        for &(ref state, ref skip) in &reachable {
+            status = match (status, state.get(&variable)) {
+                (None, _) | (_, None) => None,
+                (Some(Status::Assigned), Some(&Status::Unassigned(ref reason))) => {
+                    Some(Status::Unassigned(reason.clone().or_else(|| skip.clone())))
+                }
+                (Some(current), Some(_)) => Some(current),
+            };
+        }
+
+        if let Some(status) = status {
+            result.insert(variable, status);
+        }
+    }
+
+    Some(result)
+}
+
+/// Returns the state at the start of the `finally` block of a `try` statement. A variable the `try` or `catch`
+/// blocks may assign is only unassigned there if an exception interrupts them first. If none of their ends can be
+/// reached, any variable may have been assigned before the exception.
+///
+/// # Parameters
+///
+/// - `state`: The state before the `try` statement.
+/// - `paths`: The states at the end of the `try` and `catch` blocks.
+/// - `node`: The `try` statement.
+#[allow(clippy::iter_over_hash_type)]
+fn interrupted(mut state: State, paths: &[(State, Option<Skip>)], node: &Try) -> State {
+    let reachable: Vec<&HashMap<*const VariableDeclaration, Status>> = paths
+        .iter()
+        .filter_map(|&(ref found, _)| found.as_ref())
+        .collect();
+
+    if let Some(ref mut found) = state {
+        for (variable, status) in found.iter_mut() {
+            let assigned: bool = reachable.is_empty()
+                || reachable
+                    .iter()
+                    .any(|path| matches!(path.get(variable), Some(&Status::Assigned)));
+            if matches!(*status, Status::Unassigned(None)) && assigned {
+                *status = Status::Unassigned(Some(skip(
+                    "`{name}` is not assigned if an exception is thrown before its assignment",
+                    &node.location,
+                )));
+            }
+        }
+    }
+    state
+}
+
+/// Returns whether variables and fields of a type have a value before anything is assigned to them: values of
+/// nullable types are `none`, integers zero, `str` values empty and `bool` values `false`.
+fn has_default(annotation: &TypeAnnotation) -> bool {
+    annotation.nullable
+        || matches!(annotation.name.as_str(), "str" | "bool")
+        || integers::is_integer(&annotation.name)
+}
+
+/// Returns whether a constructor assigns a field in a statement that always runs, e.g. `this.value = value;`.
+fn assigns(constructor: &Function, field: &Field) -> bool {
+    let Some(ref body) = constructor.body else {
+        return false;
+    };
+    body.statements.iter().any(|statement| {
+        let Statement::Expression(ref statement) = *statement else {
+            return false;
+        };
+        let Expression::Assignment(ref assignment) = statement.expression else {
+            return false;
+        };
+        matches!(
+            *assignment.target,
+            Expression::Member(ref member) if member.member.name == field.name.name
+                && matches!(*member.target, Expression::Identifier(ref target) if target.name == "this")
+        )
+    })
+}
+
+/// Returns the diagnostic for a field or a variable of the module whose type has no default value and that is
+/// declared without a value, see [`has_default`].
+fn uninitialized(
+    kind: &str,
+    name: &str,
+    annotation: &TypeAnnotation,
+    location: &Location,
+) -> Diagnostic {
+    Diagnostic::error(
+        "E0077",
+        &format!("{kind} `{name}` has no initial value"),
+        location.clone(),
+    )
+    .with_label(&format!(
+        "a `{annotation}` would be `none` until it is assigned"
+    ))
+}
+
+/// Returns the description of a path on which a variable is not assigned.
+fn skip(message: &str, location: &Location) -> Skip {
+    Skip {
+        message: message.to_owned(),
+        location: location.clone(),
+    }
+}
+
+
+////////////
+// CHECKS //
+////////////
+
+/// Checks that all variables declared without a value, e.g. `int goal;`, are assigned on every path before they
+/// are read.
+///
+/// Each function and the top level of the program are checked on their own. The analysis follows `if`/`else`,
+/// `match`, loops with `break` and `continue`, `return`, `throw` and `try`/`catch`/`finally`. A `while (true)`
+/// loop is only left with `break`, and a `catch` clause may run before any statement of the `try` block.
+///
+/// Fields and variables of the module are read by functions, which may run before they are assigned. If their type
+/// has no default value, e.g. a class or `list<int>`, they need an initial value or a nullable type. A field may
+/// also be assigned by a statement of the constructor that always runs.
+///
+/// # Parameters
+///
+/// - `program`: The program to check.
+/// - `imports`: The names the program imports from other modules.
+///
+/// # Returns
+///
+/// The diagnostics found, at most one per variable.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::{assignments, modules::Imports};
+/// let tokens = lexer::lex::lex("int goal; if (true) { goal = 1; } print(goal);", "<stdin>").unwrap();
+/// let program = parser::parse::parse(&tokens).unwrap();
+/// let diagnostics = assignments::check(&program, &Imports::default());
+///
+/// assert_eq!(diagnostics[0].code, "E0047");
+/// assert_eq!(diagnostics[0].notes[1].message, "`goal` is not assigned when the condition is false");
+/// ```
+#[inline]
+pub fn check<'ast>(program: &'ast Program, imports: &Imports<'ast>) -> Vec<Diagnostic> {
+    let resolution: Resolution = resolve::resolve(program, imports);
+    let classes: ClassTable = ClassTable::new(program, imports);
+    let mut checker: AssignmentChecker = AssignmentChecker {
+        resolution: &resolution,
+        classes: &classes,
+        loops: vec![],
+        reported: HashSet::new(),
+        diagnostics: vec![],
+    };
+    let mut state: State = Some(HashMap::new());
+    for statement in &program.statements {
+        // Functions may read the variables of the module before the assignment after their declaration
+        if let Statement::VariableDeclaration(ref variable) = *statement {
+            if let (&Some(ref annotation), &None) = (&variable.type_annotation, &variable.value) {
+                if !has_default(annotation) {
+                    checker.diagnostics.push(
+                        uninitialized(
+                            "variable",
+                            &variable.name.name,
+                            annotation,
+                            &variable.name.location,
+                        )
+                        .with_note(
+                            &format!("give it a value or declare it as `{annotation}?`"),
+                            None,
+                        ),
+                    );
+                }
+            }
+        }
+        state = checker.statement(statement, state);
+    }
+
+    checker.diagnostics
+}
+
+
+////////////////////////
+// ASSIGNMENT CHECKER //
+////////////////////////
+
+/// Follows the paths through the statements of a program, tracking which variables are assigned.
+struct AssignmentChecker<'table, 'ast> {
+    /// The declarations of the names used in the program.
+    resolution: &'table Resolution<'ast>,
+    /// The classes of the program, used to find matches covering every variant of an enum.
+    classes: &'table ClassTable<'ast>,
+    /// The states at the `break` statements of each loop the visited statement is in, innermost last.
+    loops: Vec<Vec<State>>,
+    /// The variables already reported, so every variable is only reported once.
+    reported: HashSet<*const VariableDeclaration>,
+    /// The diagnostics found so far.
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'ast> AssignmentChecker<'_, 'ast> {
+    /// Checks a function, method or constructor on its own. Variables of the enclosing code are not tracked.
+    fn function(&mut self, node: &'ast Function) {
+        if let Some(ref body) = node.body {
+            let loops: Vec<Vec<State>> = core::mem::take(&mut self.loops);
+            self.statements(&body.statements, Some(HashMap::new()));
+            self.loops = loops;
+        }
+
+        for parameter in &node.parameters {
+            if let Some(ref default) = parameter.default {
+                self.expression(default, &mut Some(HashMap::new()));
+            }
+        }
+    }
+
+    /// Checks the methods, constructors and field values of a class, and that the fields that would start as `none`
+    /// have a value or are assigned by the constructor.
+    fn class(&mut self, node: &'ast Class) {
+        let constructor: Option<&Function> = node.members.iter().find_map(|member| match *member {
+            ClassMember::Constructor(ref function) => Some(function),
+            _ => None,
+        });
+
+        for member in &node.members {
+            match *member {
+                ClassMember::Constructor(ref function) | ClassMember::Method(ref function) => {
+                    self.function(function);
+                }
+                ClassMember::Field(ref field) => match field.value {
+                    Some(ref value) => self.expression(value, &mut Some(HashMap::new())),
+                    None if !has_default(&field.type_annotation)
+                        && !constructor.map_or(false, |found| assigns(found, field)) =>
+                    {
+                        self.diagnostics.push(
+                            uninitialized("field", &field.name.name, &field.type_annotation, &field.name.location)
+                                .with_note(
+                                    &format!(
+                                        "give it a value, assign it in the constructor or declare it as `{}?`",
+                                        field.type_annotation
+                                    ),
+                                    None,
+                                ),
+                        );
+                    }
+                    None => {}
+                },
+            }
+        }
+    }
+
+    /// Follows a list of statements and returns the state after them.
+    fn statements(&mut self, statements: &'ast [Statement], mut state: State) -> State {
+        for statement in statements {
+            state = self.statement(statement, state);
+        }
+
+        state
+    }
+
+    /// Follows a block and returns the state after it.
+    fn block(&mut self, node: &'ast Block, state: State) -> State {
+        self.statements(&node.statements, state)
+    }
+
+    /// Follows a statement and returns the state after it.
+    #[allow(clippy::iter_over_hash_type, clippy::too_many_lines)]
+    fn statement(&mut self, node: &'ast Statement, mut state: State) -> State {
+        match *node {
+            Statement::Assert(ref statement) => {
+                self.expression(&statement.condition, &mut state);
+                if let Some(ref message) = statement.message {
+                    self.expression(message, &mut state);
+                }
+                state
+            }
+            Statement::Block(ref block) => self.block(block, state),
+            Statement::Break(_) => {
+                if let Some(breaks) = self.loops.last_mut() {
+                    breaks.push(state);
+                }
+                None
+            }
+            Statement::Continue(_) => None,
+            Statement::Class(ref class) => {
+                self.class(class);
+                state
+            }
+            Statement::Expression(ref statement) => {
+                self.expression(&statement.expression, &mut state);
+                state
+            }
+            Statement::Yield(ref statement) => {
+                self.expression(&statement.value, &mut state);
+                state
+            }
+            Statement::Function(ref function) => {
+                self.function(function);
+                state
+            }
+            Statement::If(ref node) => {
+                self.expression(&node.condition, &mut state);
+                let then_state: State = self.block(&node.then_block, state.clone());
+
+                match node.else_block {
+                    Some(ref else_block) => {
+                        let else_state: State = self.block(else_block, state);
+                        join(vec![
+                            (
+                                then_state,
+                                Some(skip(
+                                    "`{name}` is not assigned in this branch",
+                                    &node.then_block.location,
+                                )),
+                            ),
+                            (
+                                else_state,
+                                Some(skip(
+                                    "`{name}` is not assigned in this branch",
+                                    &else_block.location,
+                                )),
+                            ),
+                        ])
+                    }
+                    None => join(vec![
+                        (then_state, None),
+                        (
+                            state,
+                            Some(skip(
+                                "`{name}` is not assigned when the condition is false",
+                                &node.location,
+                            )),
+                        ),
+                    ]),
+                }
+            }
+            Statement::Import(_) | Statement::Use(_) => state,
+            Statement::Match(ref node) => self.match_statement(node, state),
+            Statement::Return(ref node) => {
+                if let Some(ref value) = node.value {
+                    self.expression(value, &mut state);
+                }
+                None
+            }
+            Statement::Throw(ref node) => {
+                self.expression(&node.value, &mut state);
+                None
+            }
+            Statement::Try(ref node) => {
+                let body: State = self.block(&node.body, state.clone());
+
+                let mut paths: Vec<(State, Option<Skip>)> = vec![(body, None)];
+                for catch in &node.catches {
+                    // An exception may be thrown before any assignment of the `try` block
+                    let caught: State = self.block(&catch.body, state.clone());
+                    paths.push((
+                        caught,
+                        Some(skip(
+                            "`{name}` is not assigned if this exception is caught",
+                            &catch.location,
+                        )),
+                    ));
+                }
+                let entry: State = interrupted(state, &paths, node);
+                let joined: State = join(paths);
+
+                match node.finally {
+                    // The `finally` block may also run before any assignment of the `try` block
+                    Some(ref finally) => match (joined, self.block(finally, entry)) {
+                        (Some(mut joined), Some(finally)) => {
+                            for (variable, status) in finally {
+                                if matches!(status, Status::Assigned) {
+                                    joined.insert(variable, Status::Assigned);
+                                }
+                            }
+                            Some(joined)
+                        }
+                        _ => None,
+                    },
+                    None => joined,
+                }
+            }
+            Statement::VariableDeclaration(ref node) => {
+                let status: Status = match node.value {
+                    Some(ref value) => {
+                        self.expression(value, &mut state);
+                        Status::Assigned
+                    }
+                    None => Status::Unassigned(None),
+                };
+
+                if let Some(ref mut found) = state {
+                    found.insert(address(node), status);
+                }
+                state
+            }
+            Statement::For(ref node) => {
+                match node.iterable {
+                    ForIterable::Range(ref range) => {
+                        self.expression(&range.start, &mut state);
+                        self.expression(&range.end, &mut state);
+                        if let Some(ref step) = range.step {
+                            self.expression(step, &mut state);
+                        }
+                    }
+                    ForIterable::Value(ref value) => self.expression(value, &mut state),
+                }
+                self.loop_body(&node.body, false, &node.location, state)
+            }
+            Statement::While(ref node) => {
+                self.expression(&node.condition, &mut state);
+                let endless: bool = matches!(
+                    node.condition,
+                    Expression::Literal(ref literal) if literal.kind == TypeDefinition::True
+                );
+                self.loop_body(&node.body, endless, &node.location, state)
+            }
+        }
+    }
+
+    /// Follows the body of a loop and returns the state after the loop.
+    ///
+    /// # Parameters
+    ///
+    /// - `body`: The loop body.
+    /// - `endless`: Whether the loop is only left with `break`, e.g. `while (true)`.
+    /// - `location`: The location of the loop.
+    /// - `state`: The state before the first iteration.
+    fn loop_body(
+        &mut self,
+        body: &'ast Block,
+        endless: bool,
+        location: &Location,
+        state: State,
+    ) -> State {
+        self.loops.push(vec![]);
+        self.block(body, state.clone());
+        let breaks: Vec<State> = self.loops.pop().unwrap_or_default();
+
+        let mut paths: Vec<(State, Option<Skip>)> = breaks
+            .into_iter()
+            .map(|found| {
+                (
+                    found,
+                    Some(skip(
+                        "`{name}` is not assigned when the loop is left with `break`",
+                        location,
+                    )),
+                )
+            })
+            .collect();
+        if !endless {
+            // The body may never run, and the assignments it makes on the first run are the ones that count
+            paths.insert(
+                0,
+                (
+                    state,
+                    Some(skip(
+                        "`{name}` is not assigned if the loop body does not run",
+                        location,
+                    )),
+                ),
+            );
+        }
+        join(paths)
+    }
+
+    /// Follows a match statement or the blocks of a match expression and returns the state after it.
+    fn match_statement(&mut self, node: &'ast Match, mut state: State) -> State {
+        self.expression(&node.subject, &mut state);
+
+        let mut paths: Vec<(State, Option<Skip>)> = vec![];
+        for arm in &node.arms {
+            let mut arm_state: State = state.clone();
+            if let Some(ref guard) = arm.guard {
+                self.expression(guard, &mut arm_state);
+            }
+            let arm_state: State = match arm.body {
+                MatchBody::Block(ref block) => self.block(block, arm_state),
+                MatchBody::Expression(ref value) => {
+                    self.expression(value, &mut arm_state);
+                    arm_state
+                }
+            };
+            paths.push((
+                arm_state,
+                Some(skip("`{name}` is not assigned in this case", &arm.location)),
+            ));
+        }
+        if !patterns::is_exhaustive(node, None, self.classes) {
+            paths.push((
+                state,
+                Some(skip(
+                    "`{name}` is not assigned when no case matches",
+                    &node.location,
+                )),
+            ));
+        }
+
+        join(paths)
+    }
+
+    /// Follows an expression, reporting reads of unassigned variables and updating the state with assignments.
+    fn expression(&mut self, node: &'ast Expression, state: &mut State) {
+        match *node {
+            Expression::Assignment(ref assignment) => {
+                let Expression::Identifier(ref target) = *assignment.target else {
+                    self.expression(&assignment.target, state);
+                    self.expression(&assignment.value, state);
+                    return;
+                };
+
+                // Compound assignments read the variable first
+                if assignment.operator != Mark::Assign {
+                    self.read(target, state);
+                }
+                self.expression(&assignment.value, state);
+                if let Some(Declaration::Variable(variable)) = self.resolution.declaration(target) {
+                    if let Some(ref mut found) = *state {
+                        if let Some(status) = found.get_mut(&address(variable)) {
+                            *status = Status::Assigned;
+                        }
+                    }
+                }
+            }
+            Expression::Binary(ref binary) => {
+                self.expression(&binary.left, state);
+                if matches!(binary.operator, Mark::And | Mark::Or | Mark::Coalesce) {
+                    // The right operand is not always evaluated, so its assignments do not count
+                    self.expression(&binary.right, &mut state.clone());
+                } else {
+                    self.expression(&binary.right, state);
+                }
+            }
+            Expression::Call(ref call) => {
+                self.expression(&call.callee, state);
+                for argument in &call.arguments {
+                    self.expression(&argument.value, state);
+                }
+            }
+            Expression::Dict(ref dict) => {
+                for entry in &dict.entries {
+                    self.expression(&entry.key, state);
+                    self.expression(&entry.value, state);
+                }
+            }
+            Expression::FormatString(ref format_string) => {
+                for part in &format_string.parts {
+                    if let FormatPart::Field(ref field) = *part {
+                        self.expression(&field.value, state);
+                    }
+                }
+            }
+            Expression::Identifier(ref identifier) => self.read(identifier, state),
+            Expression::Index(ref index) => {
+                self.expression(&index.target, state);
+                self.expression(&index.index, state);
+            }
+            Expression::List(ref list) => {
+                for element in &list.elements {
+                    self.expression(element, state);
+                }
+            }
+            Expression::Slice(ref slice) => {
+                self.expression(&slice.target, state);
+                for bound in slice.start.iter().chain(&slice.end) {
+                    self.expression(bound, state);
+                }
+            }
+            Expression::Tuple(ref tuple) => {
+                for element in &tuple.elements {
+                    self.expression(element, state);
+                }
+            }
+            // The body only runs when the lambda is called, so its assignments do not count. The variables it
+            // captures must already be assigned.
+            Expression::Lambda(ref lambda) => {
+                for parameter in &lambda.parameters {
+                    if let Some(ref default) = parameter.default {
+                        self.expression(default, &mut state.clone());
+                    }
+                }
+                match lambda.body {
+                    LambdaBody::Block(ref body) => {
+                        let loops: Vec<Vec<State>> = core::mem::take(&mut self.loops);
+                        self.block(body, state.clone());
+                        self.loops = loops;
+                    }
+                    LambdaBody::Expression(ref value) => self.expression(value, &mut state.clone()),
+                }
+            }
+            Expression::Literal(_) => {}
+            Expression::Match(ref node) => *state = self.match_statement(node, state.take()),
+            Expression::Member(ref member) => self.expression(&member.target, state),
+            Expression::Unary(ref unary) => self.expression(&unary.operand, state),
+        }
+    }
+
+    /// Reports a read of a variable that is not assigned on every path reaching it.
+    fn read(&mut self, identifier: &'ast parser::ast::Identifier, state: &State) {
+        let Some(Declaration::Variable(variable)) = self.resolution.declaration(identifier) else {
+            return;
+        };
+        let Some(&Status::Unassigned(ref reason)) = state
+            .as_ref()
+            .and_then(|found| found.get(&address(variable)))
+        else {
+            return;
+        };
+        if !self.reported.insert(address(variable)) {
+            return;
+        }
+
+        let name: &str = &identifier.name;
+        let mut diagnostic: Diagnostic = Diagnostic::error(
+            "E0047",
+            &match *reason {
+                Some(_) => format!("`{name}` may be used before it is assigned"),
+                None => format!("`{name}` is used before it is assigned"),
+            },
+            identifier.location.clone(),
+        )
+        .with_label("read here")
+        .with_note(
+            &format!("`{name}` is declared here without a value"),
+            Some(variable.name.location.clone()),
+        );
+        if let Some(ref reason) = *reason {
+            diagnostic = diagnostic.with_note(
+                &reason.message.replace("{name}", name),
+                Some(reason.location.clone()),
+            );
+        }
+
+        self.diagnostics.push(diagnostic);
+    }
+}
@@ -0,0 +1,158 @@
+//! Runs the semantic checks of the compiler on a program.
+// I Language compiler checks.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use crate::annotations::{self, Plugins};
+use crate::assignments;
+use crate::classes::{self, ClassTable};
+use crate::constants;
+use crate::diagnostic::Diagnostic;
+use crate::exceptions;
+use crate::flow;
+use crate::functions;
+use crate::generators;
+use crate::generics::{self, TypeNames};
+use crate::modules::{Imports, Modules};
+use crate::patterns;
+use crate::resolve::{self, Resolution};
+use crate::types::{self, Types};
+
+use parser::ast::Program;
+
+
+////////////////////
+// CHECK FUNCTION //
+////////////////////
+
+/// Runs all semantic checks on a parsed program that does not import other modules. The constants of the program
+/// are folded into a copy first, like [`Modules::load`] does for every module, see [`constants::fold`].
+///
+/// # Parameters
+///
+/// - `program`: The program to check, usually the output of [`parser::parse::parse`].
+///
+/// # Returns
+///
+/// All diagnostics found. The program may only be compiled if none of them is an error.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::check;
+/// let tokens = lexer::lex::lex("class Player { function jump(); } Player();", "<stdin>").unwrap();
+/// let program = parser::parse::parse(&tokens).unwrap();
+/// let diagnostics = check::check(&program);
+///
+/// assert_eq!(diagnostics.len(), 1);
+/// assert_eq!(diagnostics[0].code, "E0013");
+/// ```
+///
+/// # See also
+///
+/// - [`Diagnostic`]
+/// - [`check_modules`]
+#[inline]
+pub fn check(program: &Program) -> Vec<Diagnostic> {
+    let folded: Program = constants::fold(program.clone());
+    check_program(&folded, &Imports::default(), &Plugins::default())
+}
+
+/// Runs all semantic checks on every module of a program, with the names each module imports.
+///
+/// # Parameters
+///
+/// - `modules`: The modules to check, usually the output of [`Modules::load`].
+///
+/// # Returns
+///
+/// All diagnostics found, module by module. The program may only be compiled if none of them is an error.
+/// Diagnostics of imported modules point into their files, use [`Modules::source`] to render them.
+///
+/// # See also
+///
+/// - [`check`]
+/// - [`check_modules_with`]
+#[inline]
+#[allow(clippy::module_name_repetitions)]
+pub fn check_modules(modules: &Modules) -> Vec<Diagnostic> {
+    check_modules_with(modules, &Plugins::default())
+}
+
+/// Runs all semantic checks on every module of a program like [`check_modules`], with the given plugins handling
+/// the annotations instead of the built-in ones.
+///
+/// # Parameters
+///
+/// - `modules`: The modules to check, usually the output of [`Modules::load`].
+/// - `plugins`: The plugins handling the annotations, see [`annotations::Plugin`].
+///
+/// # Returns
+///
+/// All diagnostics found, module by module.
+#[inline]
+#[allow(clippy::module_name_repetitions)]
+pub fn check_modules_with(modules: &Modules, plugins: &Plugins) -> Vec<Diagnostic> {
+    modules
+        .iter()
+        .flat_map(|module| check_program(&module.program, &modules.imports(module), plugins))
+        .collect()
+}
+
+/// Runs all semantic checks on a single program. The classes, the resolved names and the types are determined once
+/// and shared by the type and annotation checks.
+fn check_program<'ast>(
+    program: &'ast Program,
+    imports: &Imports<'ast>,
+    plugins: &Plugins,
+) -> Vec<Diagnostic> {
+    let names: TypeNames = TypeNames::default();
+    let classes: ClassTable = ClassTable::new(program, imports);
+    let resolution: Resolution = resolve::resolve(program, imports);
+    let mut types: Types = types::infer_with(program, &names, &classes, &resolution);
+
+    let mut diagnostics: Vec<Diagnostic> = vec![];
+    diagnostics.extend(resolve::check(program, imports));
+    diagnostics.extend(constants::check(program, imports));
+    diagnostics.extend(generics::check(program, imports));
+    diagnostics.extend(classes::check(program, imports));
+    diagnostics.extend(functions::check(program, imports));
+    diagnostics.extend(flow::check(program, imports));
+    diagnostics.extend(generators::check(program, imports));
+    diagnostics.extend(patterns::check(program, imports));
+    diagnostics.extend(exceptions::check(program, imports));
+    diagnostics.append(&mut types.diagnostics);
+    diagnostics.extend(assignments::check(program, imports));
+    diagnostics.extend(annotations::check_analyzed(
+        program,
+        plugins,
+        &classes,
+        &resolution,
+        &types,
+    ));
+
+    diagnostics
+}
@@ -0,0 +1,1114 @@
+//! Semantic checks for class declarations: inheritance, members, overrides, method calls and instantiations.
+// I Language class checks.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use std::collections::HashMap;
+
+use crate::diagnostic::Diagnostic;
+use crate::functions;
+use crate::generics::{self, Substitution, TypeNames};
+use crate::modules::{Imports, Item};
+use crate::prelude;
+use crate::resolve::address;
+
+use lexer::tokens::token::Location;
+use parser::ast::{
+    Block, Call, Catch, Class, ClassMember, Expression, Field, For, Function, Lambda, MatchArm,
+    Member, Parameter, Pattern, Program, TypeAnnotation, VariableDeclaration,
+};
+use parser::visit::{self, Visitor};
+
+
+/////////////////
+// CLASS TABLE //
+/////////////////
+
+/// All classes declared in a program, indexed by their name.
+/// Used to look up base classes and members, including inherited ones.
+#[derive(Clone, Debug, Default)]
+pub struct ClassTable<'ast> {
+    /// The classes by name. Only the first declaration of a name is stored.
+    classes: HashMap<String, &'ast Class>,
+    /// All class declarations in source order, including duplicates.
+    declarations: Vec<&'ast Class>,
+    /// The base classes and interfaces of classes declared in other modules, see [`Imports::supertypes`].
+    supertypes: HashMap<*const TypeAnnotation, &'ast Class>,
+}
+
+impl<'ast> ClassTable<'ast> {
+    /// Collects all classes declared anywhere in a program, the classes of the [prelude](`crate::prelude`)
+    /// and the classes the program imports with `use`, by their alias if they have one.
+    #[inline]
+    #[allow(clippy::iter_over_hash_type)]
+    pub fn new(program: &'ast Program, imports: &Imports<'ast>) -> Self {
+        let mut collector: Collector = Collector {
+            table: ClassTable::default(),
+        };
+        collector.visit_program(prelude::program());
+        collector.visit_program(program);
+
+        let mut table: ClassTable = collector.table;
+        for (&name, item) in &imports.items {
+            if let Item::Class(class) = *item {
+                table.classes.entry(name.to_owned()).or_insert(class);
+                // Variants of an imported enum are named after its alias, e.g. `S.Circle` for `use shapes.Shape as S`
+                for variant in &class.variants {
+                    let short: &str = variant_name(class, variant);
+                    table
+                        .classes
+                        .entry(format!("{name}.{short}"))
+                        .or_insert(variant);
+                }
+            }
+        }
+        table.supertypes.clone_from(&imports.supertypes);
+
+        table
+    }
+
+    /// Returns the class with the given name.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&'ast Class> {
+        self.classes.get(name).copied()
+    }
+
+    /// Returns the names of all classes, including imported and built-in ones.
+    #[inline]
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.classes.keys().map(String::as_str)
+    }
+
+    /// Returns the class or interface named by a base or an interface of a class.
+    #[inline]
+    pub fn lookup(&self, annotation: &TypeAnnotation) -> Option<&'ast Class> {
+        self.supertypes
+            .get(&address(annotation))
+            .copied()
+            .or_else(|| self.get(&annotation.name))
+    }
+
+    /// Returns the direct base class of a class, if it has one and it exists. Interfaces have no base class.
+    #[inline]
+    pub fn parent(&self, class: &Class) -> Option<&'ast Class> {
+        if class.interface {
+            return None;
+        }
+
+        class
+            .bases
+            .iter()
+            .find_map(|base| self.lookup(base))
+            .filter(|parent| !parent.interface)
+    }
+
+    /// Returns the interfaces a class implements, directly, through its ancestors or through the interfaces they
+    /// extend, or the interfaces an interface extends. Each interface appears once, nearest first.
+    #[inline]
+    pub fn interfaces(&self, class: &'ast Class) -> Vec<&'ast Class> {
+        let mut result: Vec<&Class> = vec![];
+        let mut pending: Vec<&Class> = core::iter::once(class)
+            .chain(self.ancestors(class))
+            .collect();
+
+        let mut index: usize = 0;
+        while let Some(&current) = pending.get(index) {
+            index += 1;
+            let annotations: &[TypeAnnotation] = if current.interface {
+                &current.bases
+            } else {
+                &current.interfaces
+            };
+
+            for annotation in annotations {
+                let Some(found) = self.lookup(annotation).filter(|found| found.interface) else {
+                    continue;
+                };
+                if !result.iter().any(|seen| core::ptr::eq(*seen, found)) {
+                    result.push(found);
+                    pending.push(found);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Returns the base classes of a class, starting with the direct parent.
+    /// Stops when a class appears a second time, so inheritance cycles do not loop forever.
+    #[inline]
+    pub fn ancestors(&self, class: &'ast Class) -> Vec<&'ast Class> {
+        let mut result: Vec<&Class> = vec![];
+        let mut current: &Class = class;
+
+        while let Some(parent) = self.parent(current) {
+            if core::ptr::eq(parent, class)
+                || result.iter().any(|found| core::ptr::eq(*found, parent))
+            {
+                break;
+            }
+
+            result.push(parent);
+            current = parent;
+        }
+
+        result
+    }
+
+    /// Finds a method by name in a class or its ancestors, then in the interfaces it implements, returning the
+    /// declaring class or interface and the method.
+    #[inline]
+    pub fn find_method(
+        &self,
+        class: &'ast Class,
+        name: &str,
+    ) -> Option<(&'ast Class, &'ast Function)> {
+        self.lineage(class)
+            .into_iter()
+            .find_map(|candidate| method(candidate, name).map(|found| (candidate, found)))
+    }
+
+    /// Returns the methods called on instances of a class by name, with the classes or interfaces declaring them.
+    /// Methods of a class override the ones of its ancestors, which override the default methods of interfaces.
+    #[inline]
+    pub fn vtable(&self, class: &'ast Class) -> HashMap<&'ast str, (&'ast Class, &'ast Function)> {
+        let mut result: HashMap<&str, (&Class, &Function)> = HashMap::new();
+        for candidate in self.lineage(class) {
+            for found in methods(candidate) {
+                result
+                    .entry(found.name.name.as_str())
+                    .or_insert((candidate, found));
+            }
+        }
+
+        result
+    }
+
+    /// Finds a field by name in a class or its ancestors, returning the declaring class and the field.
+    #[inline]
+    pub fn find_field(&self, class: &'ast Class, name: &str) -> Option<(&'ast Class, &'ast Field)> {
+        core::iter::once(class)
+            .chain(self.ancestors(class))
+            .find_map(|candidate| field(candidate, name).map(|found| (candidate, found)))
+    }
+
+    /// Returns whether a class is an exception class, i.e. a subclass of `Exception`.
+    #[inline]
+    pub fn is_exception(&self, class: &'ast Class) -> bool {
+        self.get(prelude::EXCEPTION)
+            .map_or(false, |exception| self.is_subclass(class, exception))
+    }
+
+    /// Returns all fields of a class in declaration order, inherited fields first.
+    #[inline]
+    pub fn fields(&self, class: &'ast Class) -> Vec<&'ast Field> {
+        let mut result: Vec<&Field> = vec![];
+        for candidate in self.ancestors(class).iter().rev().chain([&class]) {
+            result.extend(candidate.members.iter().filter_map(|member| match *member {
+                ClassMember::Field(ref found) => Some(found),
+                _ => None,
+            }));
+        }
+
+        result
+    }
+
+    /// Finds the constructor used to instantiate a class. Classes without a constructor use the one of their parent.
+    #[inline]
+    pub fn find_constructor(&self, class: &'ast Class) -> Option<&'ast Function> {
+        core::iter::once(class)
+            .chain(self.ancestors(class))
+            .find_map(constructor)
+    }
+
+    /// Returns the abstract methods a class has, either declared by itself or inherited and not overridden,
+    /// including the methods of its interfaces without a default body.
+    #[inline]
+    pub fn abstract_methods(&self, class: &'ast Class) -> Vec<(&'ast Class, &'ast Function)> {
+        let mut names: Vec<&str> = vec![];
+        let mut result: Vec<(&Class, &Function)> = vec![];
+
+        for candidate in self.lineage(class) {
+            for found in methods(candidate) {
+                if names.contains(&found.name.name.as_str()) {
+                    continue;
+                }
+
+                names.push(&found.name.name);
+                if found.body.is_none() {
+                    result.push((candidate, found));
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Returns whether a class is a subclass of (or the same as) another class, or implements an interface.
+    #[inline]
+    pub fn is_subclass(&self, class: &'ast Class, base: &Class) -> bool {
+        let supertypes: Vec<&Class> = if base.interface {
+            self.interfaces(class)
+        } else {
+            self.ancestors(class)
+        };
+
+        core::ptr::eq(class, base) || supertypes.iter().any(|found| core::ptr::eq(*found, base))
+    }
+
+    /// Returns the enum variant a member expression names, e.g. `Shape.Circle`, if its target is the name of an enum.
+    /// Names declared in the scope of the expression are not checked, the target may be a variable of the same name.
+    #[inline]
+    pub fn variant(&self, member: &Member) -> Option<&'ast Class> {
+        let Expression::Identifier(ref target) = *member.target else {
+            return None;
+        };
+        self.get(&target.name).filter(|found| found.enumeration)?;
+        self.get(&format!("{}.{}", target.name, member.member.name))
+    }
+
+    /// Returns the enum a class is a variant of, if it is one.
+    #[inline]
+    pub fn enumeration(&self, class: &Class) -> Option<&'ast Class> {
+        self.parent(class).filter(|parent| {
+            parent
+                .variants
+                .iter()
+                .any(|variant| core::ptr::eq(variant, class))
+        })
+    }
+
+    /// Returns a class, its ancestors and its interfaces, in the order methods are looked up.
+    fn lineage(&self, class: &'ast Class) -> Vec<&'ast Class> {
+        let mut result: Vec<&Class> = vec![class];
+        result.extend(self.ancestors(class));
+        result.extend(self.interfaces(class));
+
+        result
+    }
+}
+
+
+/// A visitor collecting all class declarations into a [`ClassTable`].
+struct Collector<'ast> {
+    /// The table being filled.
+    table: ClassTable<'ast>,
+}
+
+impl<'ast> Visitor<'ast> for Collector<'ast> {
+    fn visit_class(&mut self, node: &'ast Class) {
+        self.table.declarations.push(node);
+        self.table
+            .classes
+            .entry(node.name.name.clone())
+            .or_insert(node);
+
+        visit::walk_class(self, node);
+    }
+}
+
+
+/////////////
+// HELPERS //
+/////////////
+
+/// Returns the name of an enum variant without the name of its enum, e.g. `Circle` for `Shape.Circle`.
+fn variant_name<'ast>(enumeration: &Class, variant: &'ast Class) -> &'ast str {
+    variant
+        .name
+        .name
+        .strip_prefix(enumeration.name.name.as_str())
+        .and_then(|name| name.strip_prefix('.'))
+        .unwrap_or(&variant.name.name)
+}
+
+/// Returns the methods declared directly in a class.
+fn methods(class: &Class) -> impl Iterator<Item = &Function> {
+    class.members.iter().filter_map(|member| match *member {
+        ClassMember::Method(ref function) => Some(function),
+        _ => None,
+    })
+}
+
+/// Returns a method declared directly in a class.
+fn method<'ast>(class: &'ast Class, name: &str) -> Option<&'ast Function> {
+    methods(class).find(|function| function.name.name == name)
+}
+
+/// Returns the constructor declared directly in a class.
+fn constructor(class: &Class) -> Option<&Function> {
+    class.members.iter().find_map(|member| match *member {
+        ClassMember::Constructor(ref function) => Some(function),
+        _ => None,
+    })
+}
+
+/// Returns a field declared directly in a class.
+fn field<'ast>(class: &'ast Class, name: &str) -> Option<&'ast Field> {
+    class.members.iter().find_map(|member| match *member {
+        ClassMember::Field(ref found) if found.name.name == name => Some(found),
+        _ => None,
+    })
+}
+
+/// Returns the name and location of a class member.
+fn member_name(member: &ClassMember) -> (&str, &Location) {
+    match *member {
+        ClassMember::Constructor(ref function) | ClassMember::Method(ref function) => {
+            (&function.name.name, &function.name.location)
+        }
+        ClassMember::Field(ref found) => (&found.name.name, &found.name.location),
+    }
+}
+
+/// Formats the signature of a function with the given type arguments, e.g. `(int, str) -> bool`.
+fn signature<'ast>(
+    names: &'ast TypeNames,
+    function: &'ast Function,
+    substitution: &Substitution<'ast>,
+) -> String {
+    let parameters: Vec<&str> = function
+        .parameters
+        .iter()
+        .map(|parameter| {
+            names.substitute(
+                names.annotation_type(&parameter.type_annotation),
+                substitution,
+            )
+        })
+        .collect();
+    let return_type: &str = names.substitute(names.return_type(function), substitution);
+
+    format!("({}) -> {return_type}", parameters.join(", "))
+}
+
+
+////////////
+// CHECKS //
+////////////
+
+/// Checks all class declarations of a program and all method calls on values with a known class.
+///
+/// # Parameters
+///
+/// - `program`: The program to check.
+/// - `imports`: The names the program imports from other modules.
+///
+/// # Returns
+///
+/// The diagnostics found, in the order the checks ran.
+#[inline]
+pub fn check<'ast>(program: &'ast Program, imports: &Imports<'ast>) -> Vec<Diagnostic> {
+    let table: ClassTable = ClassTable::new(program, imports);
+    let mut diagnostics: Vec<Diagnostic> = vec![];
+
+    for class in &table.declarations {
+        check_declaration(&table, class, &mut diagnostics);
+    }
+
+    let mut checker: CallChecker = CallChecker {
+        table: &table,
+        scopes: vec![HashMap::new()],
+        classes: vec![],
+        diagnostics,
+    };
+    checker.visit_program(program);
+
+    checker.diagnostics
+}
+
+/// Checks a single class declaration.
+#[allow(clippy::cognitive_complexity, clippy::indexing_slicing, clippy::too_many_lines)]
+fn check_declaration<'ast>(
+    table: &ClassTable<'ast>,
+    class: &'ast Class,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let name: &str = &class.name.name;
+
+    if let Some(first) = table
+        .get(name)
+        .filter(|first| !core::ptr::eq(*first, class))
+    {
+        diagnostics.push(
+            Diagnostic::error(
+                "E0003",
+                &format!("duplicate class `{name}`"),
+                class.name.location.clone(),
+            )
+            .with_note("first declared here", Some(first.name.location.clone())),
+        );
+        return;
+    }
+
+    // Bases
+    let mut parents: Vec<&Class> = vec![];
+    for base in &class.bases {
+        match table.lookup(base) {
+            Some(found)
+                if found.enumeration
+                    && !found
+                        .variants
+                        .iter()
+                        .any(|variant| core::ptr::eq(variant, class)) =>
+            {
+                diagnostics.push(sealed_base(
+                    class,
+                    base,
+                    found,
+                    "the variants of an enum are declared inside it",
+                ));
+            }
+            Some(found) if table.enumeration(found).is_some() => diagnostics.push(sealed_base(
+                class,
+                base,
+                found,
+                "enum variants cannot be extended",
+            )),
+            Some(found) if found.interface && !class.interface => diagnostics.push(
+                mismatched_kind(base, found, "interfaces are implemented with `implements`"),
+            ),
+            Some(found) if !found.interface && class.interface => diagnostics.push(
+                mismatched_kind(base, found, "interfaces can only extend other interfaces"),
+            ),
+            Some(parent) => parents.push(parent),
+            None => diagnostics.push(
+                Diagnostic::error(
+                    "E0004",
+                    &format!("unknown base class `{}`", base.name),
+                    base.location.clone(),
+                )
+                .with_label("not found"),
+            ),
+        }
+    }
+    if let Some(second) = parents.get(1).filter(|_| !class.interface) {
+        diagnostics.push(
+            Diagnostic::error(
+                "E0005",
+                &format!("class `{name}` has more than one base class"),
+                class.bases[1].location.clone(),
+            )
+            .with_label(&format!("`{}` is a second base class", second.name.name))
+            .with_note("classes only support single inheritance", None),
+        );
+    }
+    for annotation in &class.interfaces {
+        match table.lookup(annotation) {
+            Some(found) if !found.interface => diagnostics.push(mismatched_kind(
+                annotation,
+                found,
+                "a base class is listed after the colon",
+            )),
+            Some(_) => {}
+            None => diagnostics.push(
+                Diagnostic::error(
+                    "E0004",
+                    &format!("unknown interface `{}`", annotation.name),
+                    annotation.location.clone(),
+                )
+                .with_label("not found"),
+            ),
+        }
+    }
+    let cyclic: bool = if class.interface {
+        table
+            .interfaces(class)
+            .iter()
+            .any(|found| core::ptr::eq(*found, class))
+    } else {
+        table
+            .parent(class)
+            .map_or(false, |parent| table.is_subclass(parent, class))
+    };
+    if cyclic {
+        let kind: &str = if class.interface {
+            "interface"
+        } else {
+            "class"
+        };
+        diagnostics.push(
+            Diagnostic::error(
+                "E0006",
+                &format!("{kind} `{name}` inherits from itself"),
+                class.name.location.clone(),
+            )
+            .with_label("inheritance cycle"),
+        );
+        return;
+    }
+
+    // Members
+    let mut constructor: Option<&Location> = None;
+    let mut seen: HashMap<&str, &Location> = HashMap::new();
+    for member in &class.members {
+        let (member_name, location) = member_name(member);
+        let first: Option<&Location> = if member_name == name {
+            constructor.replace(location)
+        } else {
+            seen.insert(member_name, location)
+        };
+
+        if let Some(first) = first {
+            diagnostics.push(
+                Diagnostic::error(
+                    "E0007",
+                    &format!("duplicate member `{member_name}` in class `{name}`"),
+                    location.clone(),
+                )
+                .with_note("first declared here", Some(first.clone())),
+            );
+        }
+
+        let kind: &str = match *member {
+            ClassMember::Constructor(_) => "a constructor",
+            ClassMember::Field(_) => "a field",
+            ClassMember::Method(_) => continue,
+        };
+        let (declared, note): (&str, &str) = if class.interface {
+            ("interface", "interfaces only declare methods")
+        } else if class.enumeration {
+            ("enum", "the data of an enum is declared by its variants")
+        } else {
+            continue;
+        };
+        diagnostics.push(
+            Diagnostic::error(
+                "E0058",
+                &format!("{declared} `{name}` cannot declare {kind}"),
+                location.clone(),
+            )
+            .with_label(&format!("`{member_name}` is {kind}"))
+            .with_note(note, None),
+        );
+    }
+
+    // Inherited members
+    let parent: Option<&Class> = table.parent(class);
+    let interfaces: Vec<&Class> = table.interfaces(class);
+    for member in &class.members {
+        match *member {
+            ClassMember::Field(ref own) => {
+                let inherited: Option<(&Class, &Field)> =
+                    parent.and_then(|parent| table.find_field(parent, &own.name.name));
+                if let Some((declaring, inherited)) = inherited {
+                    diagnostics.push(
+                        Diagnostic::error(
+                            "E0008",
+                            &format!(
+                                "field `{}` redeclares a field inherited from `{}`",
+                                own.name.name, declaring.name.name
+                            ),
+                            own.name.location.clone(),
+                        )
+                        .with_note(
+                            "inherited field declared here",
+                            Some(inherited.name.location.clone()),
+                        ),
+                    );
+                }
+            }
+            ClassMember::Method(ref own) => {
+                // Interface methods are checked below, also if they are found through the parent
+                let overridden: Vec<(&Class, &Function)> = parent
+                    .and_then(|parent| table.find_method(parent, &own.name.name))
+                    .filter(|&(declaring, _)| !declaring.interface)
+                    .into_iter()
+                    .chain(interfaces.iter().filter_map(|&interface| {
+                        method(interface, &own.name.name).map(|found| (interface, found))
+                    }))
+                    .collect();
+
+                for (declaring, inherited) in overridden {
+                    check_override(table, class, own, declaring, inherited, diagnostics);
+                }
+            }
+            ClassMember::Constructor(_) => {}
+        }
+    }
+
+    // An interface or a class declaring abstract methods itself is abstract and may leave inherited ones
+    // unimplemented
+    if class.interface || methods(class).any(|function| function.body.is_none()) {
+        return;
+    }
+    for (declaring, missing) in table.abstract_methods(class) {
+        let diagnostic: Diagnostic = if declaring.interface {
+            Diagnostic::error(
+                "E0010",
+                &format!(
+                    "class `{name}` does not implement `{}.{}`",
+                    declaring.name.name, missing.name.name
+                ),
+                class.name.location.clone(),
+            )
+            .with_label(&format!(
+                "missing implementation of `{}`",
+                missing.name.name
+            ))
+            .with_note(
+                &format!("required by the interface `{}` here", declaring.name.name),
+                Some(missing.name.location.clone()),
+            )
+        } else {
+            Diagnostic::error(
+                "E0010",
+                &format!(
+                    "class `{name}` does not override abstract method `{}.{}`",
+                    declaring.name.name, missing.name.name
+                ),
+                class.name.location.clone(),
+            )
+            .with_label(&format!("missing override of `{}`", missing.name.name))
+            .with_note(
+                "abstract method declared here",
+                Some(missing.name.location.clone()),
+            )
+        };
+        diagnostics.push(diagnostic);
+    }
+}
+
+/// Checks a method overriding a method of a base class or implementing a method of an interface. The signatures
+/// must match after substituting the type arguments of the base, and methods implementing an interface must be
+/// public.
+#[allow(clippy::else_if_without_else)]
+fn check_override<'ast>(
+    table: &ClassTable<'ast>,
+    class: &'ast Class,
+    own: &'ast Function,
+    declaring: &'ast Class,
+    inherited: &'ast Function,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let names: TypeNames = TypeNames::default();
+    let substitution: Substitution =
+        generics::inherited(&names, table, class, declaring, Substitution::new());
+    let expected: String = signature(&names, inherited, &substitution);
+    let found: String = signature(&names, own, &Substitution::new());
+
+    if expected != found {
+        let (verb, note): (&str, &str) = if declaring.interface {
+            ("implements", "implemented method declared here")
+        } else {
+            ("overrides", "overridden method declared here")
+        };
+        diagnostics.push(
+            Diagnostic::error(
+                "E0009",
+                &format!(
+                    "method `{}` {verb} `{}.{}` with a different signature",
+                    own.name.name, declaring.name.name, inherited.name.name
+                ),
+                own.name.location.clone(),
+            )
+            .with_label(&format!("expected `{expected}`, found `{found}`"))
+            .with_note(note, Some(inherited.name.location.clone())),
+        );
+    } else if declaring.interface && !class.interface && !own.public {
+        diagnostics.push(
+            Diagnostic::error(
+                "E0060",
+                &format!(
+                    "method `{}.{}` implements `{}.{}` and must be public",
+                    class.name.name, own.name.name, declaring.name.name, inherited.name.name
+                ),
+                own.name.location.clone(),
+            )
+            .with_label("private method")
+            .with_note(
+                "interface methods can be called wherever the interface is visible",
+                Some(inherited.name.location.clone()),
+            ),
+        );
+    }
+}
+
+/// Returns the diagnostic for a class extending an enum it is not a variant of, or extending an enum variant.
+fn sealed_base(
+    class: &Class,
+    annotation: &TypeAnnotation,
+    found: &Class,
+    note: &str,
+) -> Diagnostic {
+    let kind: &str = if found.enumeration {
+        "enum"
+    } else {
+        "enum variant"
+    };
+
+    Diagnostic::error(
+        "E0061",
+        &format!(
+            "class `{}` cannot extend {kind} `{}`",
+            class.name.name, annotation.name
+        ),
+        annotation.location.clone(),
+    )
+    .with_label(&format!("`{}` is an {kind}", annotation.name))
+    .with_note(note, Some(found.name.location.clone()))
+}
+
+/// Returns the diagnostic for an interface used as a base class, or a class used as an interface.
+fn mismatched_kind(annotation: &TypeAnnotation, found: &Class, note: &str) -> Diagnostic {
+    let (message, label): (String, &str) = if found.interface {
+        (
+            format!("`{}` is an interface, not a class", annotation.name),
+            "expected a class",
+        )
+    } else {
+        (
+            format!("`{}` is a class, not an interface", annotation.name),
+            "expected an interface",
+        )
+    };
+
+    Diagnostic::error("E0057", &message, annotation.location.clone())
+        .with_label(label)
+        .with_note(note, Some(found.name.location.clone()))
+}
+
+
+//////////////////
+// CALL CHECKER //
+//////////////////
+
+/// A visitor checking method calls, member privacy and instantiations of abstract classes.
+struct CallChecker<'table, 'ast> {
+    /// All classes of the program.
+    table: &'table ClassTable<'ast>,
+    /// The names of the classes of variables, one map per block.
+    scopes: Vec<HashMap<String, String>>,
+    /// The names of the classes whose bodies are currently visited, innermost last.
+    classes: Vec<String>,
+    /// The diagnostics found so far.
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl CallChecker<'_, '_> {
+    /// Declares a variable in the innermost scope.
+    fn declare(&mut self, name: &str, type_name: &str) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name.to_owned(), type_name.to_owned());
+        }
+    }
+
+    /// Declares the names bound by a pattern. Only typed bindings have a known class.
+    fn declare_pattern(&mut self, pattern: &Pattern) {
+        match *pattern {
+            Pattern::Alternative(ref alternative) => {
+                for found in &alternative.patterns {
+                    self.declare_pattern(found);
+                }
+            }
+            Pattern::Binding(ref binding) => self.declare(
+                &binding.name.name,
+                binding
+                    .type_annotation
+                    .as_ref()
+                    .map_or("", |annotation| &annotation.name),
+            ),
+            Pattern::Class(ref class) => {
+                for found in &class.fields {
+                    self.declare_pattern(found);
+                }
+            }
+            Pattern::Literal(_) | Pattern::Range(_) => {}
+        }
+    }
+
+    /// Checks the arguments of a call creating an instance of a class and that the class is not abstract.
+    fn construct(&mut self, class: &Class, location: &Location, node: &Call) {
+        let (parameters, declaration): (&[Parameter], &Location) =
+            match self.table.find_constructor(class) {
+                Some(found) => (&found.parameters, &found.name.location),
+                None => (&[], &class.name.location),
+            };
+        self.diagnostics.extend(functions::check_arguments(
+            &class.name.name,
+            parameters,
+            declaration,
+            node,
+        ));
+
+        if let Some(&(declaring, missing)) = self.table.abstract_methods(class).first() {
+            self.diagnostics.push(
+                Diagnostic::error(
+                    "E0013",
+                    &format!("cannot instantiate abstract class `{}`", class.name.name),
+                    location.clone(),
+                )
+                .with_note(
+                    &format!(
+                        "`{}.{}` is abstract",
+                        declaring.name.name, missing.name.name
+                    ),
+                    Some(missing.name.location.clone()),
+                ),
+            );
+        }
+    }
+
+    /// Returns the class name of the value of an expression, if it is known.
+    fn class_of(&self, expression: &Expression) -> Option<String> {
+        let Expression::Identifier(ref identifier) = *expression else {
+            return None;
+        };
+
+        match identifier.name.as_str() {
+            "this" => self.classes.last().cloned(),
+            "super" => {
+                let current: &Class = self.table.get(self.classes.last()?)?;
+                self.table
+                    .parent(current)
+                    .map(|parent| parent.name.name.clone())
+            }
+            name => self
+                .scopes
+                .iter()
+                .rev()
+                .find_map(|scope| scope.get(name))
+                .filter(|type_name| self.table.get(type_name).is_some())
+                .cloned(),
+        }
+    }
+
+    /// Checks that a private member is only used inside the class declaring it.
+    fn check_privacy(&mut self, declaring: &Class, public: bool, member: &Member) {
+        let inside: bool = self.classes.last() == Some(&declaring.name.name);
+        if !public && !inside {
+            self.diagnostics.push(
+                Diagnostic::error(
+                    "E0012",
+                    &format!(
+                        "`{}.{}` is private",
+                        declaring.name.name, member.member.name
+                    ),
+                    member.member.location.clone(),
+                )
+                .with_label("private member")
+                .with_note(
+                    "mark the member with `pub` to use it outside of its class",
+                    None,
+                ),
+            );
+        }
+    }
+}
+
+impl Visitor<'_> for CallChecker<'_, '_> {
+    fn visit_block(&mut self, node: &Block) {
+        self.scopes.push(HashMap::new());
+        visit::walk_block(self, node);
+        self.scopes.pop();
+    }
+
+    fn visit_class(&mut self, node: &Class) {
+        self.classes.push(node.name.name.clone());
+        visit::walk_class(self, node);
+        self.classes.pop();
+    }
+
+    fn visit_function(&mut self, node: &Function) {
+        self.scopes.push(HashMap::new());
+        for parameter in &node.parameters {
+            self.declare(&parameter.name.name, &parameter.type_annotation.name);
+        }
+        visit::walk_function(self, node);
+        self.scopes.pop();
+    }
+
+    fn visit_lambda(&mut self, node: &Lambda) {
+        self.scopes.push(HashMap::new());
+        for parameter in &node.parameters {
+            self.declare(&parameter.name.name, &parameter.type_annotation.name);
+        }
+        visit::walk_lambda(self, node);
+        self.scopes.pop();
+    }
+
+    fn visit_variable_declaration(&mut self, node: &VariableDeclaration) {
+        visit::walk_variable_declaration(self, node);
+        let type_name: &str = match (&node.type_annotation, &node.value) {
+            (&Some(ref annotation), _) => &annotation.name,
+            // A variable declared with `var` has the class of the object it is initialized with
+            (&None, &Some(Expression::Call(ref call))) => match *call.callee {
+                Expression::Identifier(ref identifier) => &identifier.name,
+                _ => "",
+            },
+            (&None, _) => "",
+        };
+        self.declare(&node.name.name, type_name);
+    }
+
+    fn visit_catch(&mut self, node: &Catch) {
+        self.scopes.push(HashMap::new());
+        if let Some(ref binding) = node.binding {
+            self.declare(&binding.name, &node.exception.name);
+        }
+        visit::walk_catch(self, node);
+        self.scopes.pop();
+    }
+
+    fn visit_for(&mut self, node: &For) {
+        self.visit_for_iterable(&node.iterable);
+        self.scopes.push(HashMap::new());
+        let type_name: &str = node
+            .type_annotation
+            .as_ref()
+            .map_or("", |annotation| &annotation.name);
+        self.declare(&node.variable.name, type_name);
+        self.visit_block(&node.body);
+        self.scopes.pop();
+    }
+
+    fn visit_match_arm(&mut self, node: &MatchArm) {
+        self.scopes.push(HashMap::new());
+        if let Some(ref pattern) = node.pattern {
+            self.declare_pattern(pattern);
+        }
+        visit::walk_match_arm(self, node);
+        self.scopes.pop();
+    }
+
+    #[allow(clippy::else_if_without_else, clippy::too_many_lines)]
+    fn visit_call(&mut self, node: &Call) {
+        match *node.callee {
+            Expression::Member(ref member) => {
+                // A variant of an enum, e.g. `Shape.Circle(1)`, unless the enum is shadowed by a variable
+                if let Some(variant) = self
+                    .table
+                    .variant(member)
+                    .filter(|_| self.class_of(&member.target).is_none())
+                {
+                    self.construct(variant, &member.member.location, node);
+                    for argument in &node.arguments {
+                        self.visit_argument(argument);
+                    }
+                    return;
+                }
+
+                if let Some(class) = self
+                    .class_of(&member.target)
+                    .and_then(|name| self.table.get(&name))
+                {
+                    let name: &str = &member.member.name;
+                    match self.table.find_method(class, name) {
+                        Some((declaring, found)) => {
+                            // Interface methods are always public
+                            self.check_privacy(
+                                declaring,
+                                found.public || declaring.interface,
+                                member,
+                            );
+                            self.diagnostics.extend(functions::check_arguments(
+                                name,
+                                &found.parameters,
+                                &found.name.location,
+                                node,
+                            ));
+                        }
+                        None if self.table.find_field(class, name).is_some() => {}
+                        None => self.diagnostics.push(
+                            Diagnostic::error(
+                                "E0011",
+                                &format!(
+                                    "call to undefined method `{name}` on class `{}`",
+                                    class.name.name
+                                ),
+                                member.member.location.clone(),
+                            )
+                            .with_label("method not found")
+                            .with_note(
+                                &format!("`{}` is declared here", class.name.name),
+                                Some(class.name.location.clone()),
+                            ),
+                        ),
+                    }
+                }
+
+                // The callee was checked above, only check the member target and the arguments
+                self.visit_expression(&member.target);
+                for argument in &node.arguments {
+                    self.visit_argument(argument);
+                }
+                return;
+            }
+            Expression::Identifier(ref identifier) => {
+                if let Some(declared) = self
+                    .table
+                    .get(&identifier.name)
+                    .filter(|found| found.interface || found.enumeration)
+                {
+                    let (kind, suggestion): (&str, String) = if declared.interface {
+                        (
+                            "interface",
+                            format!(
+                                "instantiate a class implementing `{}` instead",
+                                declared.name.name
+                            ),
+                        )
+                    } else {
+                        (
+                            "enum",
+                            format!(
+                                "instantiate one of the variants of `{}` instead",
+                                declared.name.name
+                            ),
+                        )
+                    };
+                    self.diagnostics.push(
+                        Diagnostic::error(
+                            "E0059",
+                            &format!("cannot instantiate {kind} `{}`", declared.name.name),
+                            identifier.location.clone(),
+                        )
+                        .with_label(&format!("{kind}s have no instances of their own"))
+                        .with_note(&suggestion, Some(declared.name.location.clone())),
+                    );
+                } else if let Some(class) = self.table.get(&identifier.name) {
+                    self.construct(class, &identifier.location, node);
+                }
+            }
+            _ => {}
+        }
+
+        visit::walk_call(self, node);
+    }
+
+    fn visit_member(&mut self, node: &Member) {
+        if let Some(class) = self
+            .class_of(&node.target)
+            .and_then(|name| self.table.get(&name))
+        {
+            if let Some((declaring, found)) = self.table.find_field(class, &node.member.name) {
+                self.check_privacy(declaring, found.public, node);
+            }
+        }
+
+        visit::walk_member(self, node);
+    }
+}
@@ -0,0 +1,276 @@
+//! The built-in collection types `list`, `dict` and `tuple`: their type parameters and methods.
+// I Language collections.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use crate::generics::{self, Substitution};
+use crate::integers;
+
+
+/////////////
+// METHODS //
+/////////////
+
+/// A method of a built-in collection type. The types of the parameters and the return type use the type parameters
+/// of the collection, see [`type_parameters`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Method {
+    /// The name of the method.
+    pub name: &'static str,
+    /// The names and types of the parameters.
+    pub parameters: &'static [(&'static str, &'static str)],
+    /// The type of the result.
+    pub return_type: &'static str,
+}
+
+/// The methods of `list<T>`.
+const LIST_METHODS: [Method; 6] = [
+    Method {
+        name: "append",
+        parameters: &[("value", "T")],
+        return_type: "none",
+    },
+    Method {
+        name: "insert",
+        parameters: &[("index", "int"), ("value", "T")],
+        return_type: "none",
+    },
+    Method {
+        name: "pop",
+        parameters: &[],
+        return_type: "T",
+    },
+    Method {
+        name: "contains",
+        parameters: &[("value", "T")],
+        return_type: "bool",
+    },
+    Method {
+        name: "len",
+        parameters: &[],
+        return_type: "int",
+    },
+    Method {
+        name: "sort",
+        parameters: &[("compare", "function(T, T) -> int")],
+        return_type: "none",
+    },
+];
+
+/// The methods of `dict<K, V>`.
+const DICT_METHODS: [Method; 5] = [
+    Method {
+        name: "contains",
+        parameters: &[("key", "K")],
+        return_type: "bool",
+    },
+    Method {
+        name: "remove",
+        parameters: &[("key", "K")],
+        return_type: "V",
+    },
+    Method {
+        name: "keys",
+        parameters: &[],
+        return_type: "list<K>",
+    },
+    Method {
+        name: "values",
+        parameters: &[],
+        return_type: "list<V>",
+    },
+    Method {
+        name: "len",
+        parameters: &[],
+        return_type: "int",
+    },
+];
+
+/// The methods of `tuple`.
+const TUPLE_METHODS: [Method; 1] = [Method {
+    name: "len",
+    parameters: &[],
+    return_type: "int",
+}];
+
+/// The methods of `str`. Lengths and positions count characters, not bytes.
+const STR_METHODS: [Method; 13] = [
+    Method {
+        name: "len",
+        parameters: &[],
+        return_type: "int",
+    },
+    Method {
+        name: "upper",
+        parameters: &[],
+        return_type: "str",
+    },
+    Method {
+        name: "lower",
+        parameters: &[],
+        return_type: "str",
+    },
+    Method {
+        name: "strip",
+        parameters: &[],
+        return_type: "str",
+    },
+    Method {
+        name: "split",
+        parameters: &[("separator", "str")],
+        return_type: "list<str>",
+    },
+    Method {
+        name: "join",
+        parameters: &[("parts", "list<str>")],
+        return_type: "str",
+    },
+    Method {
+        name: "replace",
+        parameters: &[("old", "str"), ("new", "str")],
+        return_type: "str",
+    },
+    Method {
+        name: "find",
+        parameters: &[("part", "str")],
+        return_type: "int?",
+    },
+    Method {
+        name: "contains",
+        parameters: &[("part", "str")],
+        return_type: "bool",
+    },
+    Method {
+        name: "starts_with",
+        parameters: &[("prefix", "str")],
+        return_type: "bool",
+    },
+    Method {
+        name: "ends_with",
+        parameters: &[("suffix", "str")],
+        return_type: "bool",
+    },
+    Method {
+        name: "chars",
+        parameters: &[],
+        return_type: "list<str>",
+    },
+    Method {
+        name: "graphemes",
+        parameters: &[],
+        return_type: "list<str>",
+    },
+];
+
+/// Returns whether a type is a built-in collection type, e.g. `list<int>`, or `str`, a sequence of characters.
+#[inline]
+pub fn is_collection(type_name: &str) -> bool {
+    matches!(
+        generics::split(type_name).0,
+        "list" | "dict" | "tuple" | "str"
+    )
+}
+
+/// Returns whether values of a type can be keys of a `dict`: `none`, `bool`, integers, `str` and their nullable types.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::collections;
+/// assert!(collections::is_key("u8?"));
+/// assert!(!collections::is_key("list<int>"));
+/// ```
+#[inline]
+pub fn is_key(type_name: &str) -> bool {
+    let name: &str = generics::non_null(type_name);
+    matches!(name, "none" | "bool" | "str") || integers::is_integer(name)
+}
+
+/// Returns the type parameters of a built-in type, e.g. `K` and `V` for `dict`. A `tuple` takes any number of
+/// type arguments, one per element, and other built-in types take none.
+#[inline]
+pub const fn type_parameters(name: &str) -> &'static [&'static str] {
+    match name.as_bytes() {
+        b"list" => &["T"],
+        b"dict" => &["K", "V"],
+        _ => &[],
+    }
+}
+
+/// Returns the methods of a built-in type.
+#[inline]
+pub const fn methods(name: &str) -> &'static [Method] {
+    match name.as_bytes() {
+        b"list" => &LIST_METHODS,
+        b"dict" => &DICT_METHODS,
+        b"tuple" => &TUPLE_METHODS,
+        b"str" => &STR_METHODS,
+        _ => &[],
+    }
+}
+
+/// Returns a method of a value of a built-in type.
+///
+/// # Parameters
+///
+/// - `type_name`: The type of the value, e.g. `list<int>`.
+/// - `name`: The name of the method.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::collections;
+/// assert_eq!(collections::method("list<int>", "pop").map(|method| method.return_type), Some("T"));
+/// assert!(collections::method("dict<str, int>", "pop").is_none());
+/// ```
+#[inline]
+pub fn method(type_name: &str, name: &str) -> Option<&'static Method> {
+    methods(generics::split(type_name).0)
+        .iter()
+        .find(|method| method.name == name)
+}
+
+/// Returns the mapping of the type parameters of a collection type to the type arguments of a value, e.g. `K` to
+/// `str` and `V` to `int` for `dict<str, int>`. Returns an empty mapping if the type has no type arguments.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::collections;
+/// let substitution = collections::substitution("dict<str, list<int>>");
+///
+/// assert_eq!(substitution.get("V"), Some(&"list<int>"));
+/// ```
+#[inline]
+pub fn substitution(type_name: &str) -> Substitution<'_> {
+    let (base, arguments): (&str, Vec<&str>) = generics::split(type_name);
+    let parameters: &[&str] = type_parameters(base);
+    if arguments.len() != parameters.len() {
+        return Substitution::new();
+    }
+
+    parameters.iter().copied().zip(arguments).collect()
+}
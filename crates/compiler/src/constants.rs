@@ -0,0 +1,790 @@
+//! Evaluates constants at compile time and checks their declarations and uses.
+// I Language constant evaluation.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use core::fmt::{self, Display};
+use std::collections::HashMap;
+
+use crate::diagnostic::Diagnostic;
+use crate::integers;
+use crate::modules::Imports;
+use crate::resolve::{self, address, Declaration, Resolution};
+
+use lexer::tokens::mark::Mark;
+use lexer::tokens::token::{Location, TypeDefinition};
+use parser::ast::{
+    Assert, Assignment, Call, Expression, Function, Identifier, Literal, Parameter, Pattern,
+    Program, RangeBound, Statement, Unary, VariableDeclaration,
+};
+use parser::fold::{self, Fold};
+use parser::visit::{self, Visitor};
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// The stack space a statement or expression needs at least while folding. With less space left, folding continues on
+/// a newly allocated stack segment, so code nested up to the limit of the parser does not overflow the native stack.
+const RED_ZONE: usize = 128 * 1024;
+
+/// The size of the stack segments allocated when the stack runs low, see [`RED_ZONE`].
+const STACK_SEGMENT: usize = 4 * 1024 * 1024;
+
+
+//////////////
+// CONSTANT //
+//////////////
+
+/// The value of a constant, known at compile time.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Constant {
+    /// An `int` value.
+    Int(i64),
+    /// A `str` value, as written in the source: escape sequences are not replaced.
+    Str(String),
+    /// A `bool` value.
+    Bool(bool),
+    /// The `none` value.
+    None,
+}
+
+impl Constant {
+    /// Returns the name of the type of the value.
+    #[inline]
+    pub const fn type_name(&self) -> &'static str {
+        match *self {
+            Self::Int(_) => "int",
+            Self::Str(_) => "str",
+            Self::Bool(_) => "bool",
+            Self::None => "none",
+        }
+    }
+
+    /// Converts the value into a literal, e.g. to replace a constant in a pattern.
+    #[inline]
+    pub fn literal(&self, location: Location) -> Literal {
+        let (kind, value): (TypeDefinition, String) = match *self {
+            Self::Int(value) => (TypeDefinition::Integer, value.to_string()),
+            Self::Str(ref value) => (TypeDefinition::String, value.clone()),
+            Self::Bool(true) => (TypeDefinition::True, "true".to_owned()),
+            Self::Bool(false) => (TypeDefinition::False, "false".to_owned()),
+            Self::None => (TypeDefinition::None, "none".to_owned()),
+        };
+
+        Literal {
+            kind,
+            value,
+            location,
+        }
+    }
+}
+
+impl Display for Constant {
+    #[inline]
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Int(value) => write!(formatter, "{value}"),
+            Self::Str(ref value) => write!(formatter, "\"{value}\""),
+            Self::Bool(value) => write!(formatter, "{value}"),
+            Self::None => write!(formatter, "none"),
+        }
+    }
+}
+
+
+///////////////
+// EVALUATOR //
+///////////////
+
+/// Evaluates constant expressions: literals, constants and operators applied to them.
+///
+/// Arithmetic is checked, so overflows and divisions by zero are found at compile time instead of wrapping or
+/// raising an exception at runtime.
+#[derive(Debug)]
+#[allow(clippy::partial_pub_fields)]
+pub struct Evaluator<'table, 'ast> {
+    /// The declarations of the names used in the program.
+    resolution: &'table Resolution<'ast>,
+    /// The values of the evaluated constants, `None` if a constant has no valid value.
+    values: HashMap<*const VariableDeclaration, Option<Constant>>,
+    /// The constant arguments of a call, used as the values of the parameters when evaluating the `requires`
+    /// clauses of the called function.
+    arguments: HashMap<*const Parameter, Constant>,
+
+    /// The diagnostics found in the values of constants.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl<'table, 'ast> Evaluator<'table, 'ast> {
+    /// Creates an evaluator for the constants of a program.
+    #[inline]
+    pub fn new(resolution: &'table Resolution<'ast>) -> Self {
+        Self {
+            resolution,
+            values: HashMap::new(),
+            arguments: HashMap::new(),
+
+            diagnostics: vec![],
+        }
+    }
+
+    /// Returns the value of a constant declaration, evaluating it the first time.
+    /// Errors in its value are added to the diagnostics once.
+    ///
+    /// # Returns
+    ///
+    /// The value, or `None` if the declaration is not a constant or its value is not a valid constant.
+    #[inline]
+    pub fn constant(&mut self, node: &'ast VariableDeclaration) -> Option<Constant> {
+        if !node.constant {
+            return None;
+        }
+        if let Some(value) = self.values.get(&address(node)) {
+            return value.clone();
+        }
+        let value: &Expression = node.value.as_ref()?;
+        let declared: Option<&str> = node
+            .type_annotation
+            .as_ref()
+            .map(|annotation| annotation.name.as_str());
+        // The values of `bigint` constants may not fit into the `int` values the evaluator computes with
+        if declared == Some(integers::BIGINT) {
+            return None;
+        }
+
+        let result: Option<Constant> = match self.evaluate(value) {
+            Ok(Constant::Int(found))
+                if integers::literal(value).is_none()
+                    && declared
+                        .and_then(integers::range)
+                        .map_or(false, |(min, max)| {
+                            !(min..=max).contains(&i128::from(found))
+                        }) =>
+            {
+                let declared: &str = declared.unwrap_or_default();
+                self.diagnostics.push(
+                    overflow(
+                        &format!("`{found}` does not fit into `{declared}`"),
+                        value.location(),
+                    )
+                    .with_note(
+                        &format!("`{}` is declared as `{declared}` here", node.name.name),
+                        Some(node.name.location.clone()),
+                    ),
+                );
+                None
+            }
+            Ok(found) => Some(found),
+            Err(error) => {
+                if let Some(diagnostic) = error {
+                    self.diagnostics.push((*diagnostic).with_note(
+                        &format!(
+                            "the value of `{}` must be known at compile time",
+                            node.name.name
+                        ),
+                        Some(node.name.location.clone()),
+                    ));
+                }
+                None
+            }
+        };
+        self.values.insert(address(node), result.clone());
+
+        result
+    }
+
+    /// Evaluates a constant expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns a diagnostic if the expression is not a constant expression, if an operation overflows or divides
+    /// by zero. Returns `None` instead of a diagnostic if the error is reported elsewhere, e.g. operands of the
+    /// wrong type or a constant used in the expression whose own value is invalid.
+    #[inline]
+    pub fn evaluate(
+        &mut self,
+        node: &'ast Expression,
+    ) -> Result<Constant, Option<Box<Diagnostic>>> {
+        match *node {
+            Expression::Literal(ref literal) => Ok(match literal.kind {
+                // Literals out of the range of their type are reported by the type checker
+                TypeDefinition::Integer => match literal.value.replace('_', "").parse() {
+                    Ok(value) => Constant::Int(value),
+                    Err(_) => return Err(None),
+                },
+                TypeDefinition::String => Constant::Str(literal.value.clone()),
+                TypeDefinition::True => Constant::Bool(true),
+                TypeDefinition::False => Constant::Bool(false),
+                TypeDefinition::None => Constant::None,
+                // Format strings are parsed into `Expression::FormatString`
+                TypeDefinition::FormatString => return Err(None),
+            }),
+            Expression::Identifier(ref identifier) => match self.resolution.declaration(identifier)
+            {
+                Some(Declaration::Variable(variable)) if variable.constant => {
+                    self.constant(variable).ok_or(None)
+                }
+                Some(Declaration::Parameter(parameter)) => self
+                    .arguments
+                    .get(&address(parameter))
+                    .cloned()
+                    .ok_or_else(|| {
+                        Some(not_constant(
+                            node,
+                            &format!("`{}` is not a constant", identifier.name),
+                        ))
+                    }),
+                Some(_) => Err(Some(not_constant(
+                    node,
+                    &format!("`{}` is not a constant", identifier.name),
+                ))),
+                None => Err(None),
+            },
+            Expression::Binary(ref binary) => {
+                let left: Constant = self.evaluate(&binary.left)?;
+                let right: Constant = self.evaluate(&binary.right)?;
+                operation(&binary.operator, &left, &right).map_err(|message| {
+                    message.map(|found| Box::new(overflow(&found, &binary.location)))
+                })
+            }
+            Expression::Unary(ref unary) => match (&unary.operator, self.evaluate(&unary.operand)?)
+            {
+                (&Mark::Bang, Constant::Bool(value)) => Ok(Constant::Bool(!value)),
+                (&Mark::BitNot, Constant::Int(value)) => Ok(Constant::Int(!value)),
+                (&Mark::Subtract, Constant::Int(value)) => {
+                    value.checked_neg().map(Constant::Int).ok_or_else(|| {
+                        Some(Box::new(overflow(
+                            &format!("`-{value}` does not fit into `int`"),
+                            &unary.location,
+                        )))
+                    })
+                }
+                (&(Mark::Increase | Mark::Decrease), _) => {
+                    Err(Some(not_constant(node, "changes a variable")))
+                }
+                _ => Err(None),
+            },
+            Expression::Assignment(_) => {
+                Err(Some(not_constant(node, "assignments change a variable")))
+            }
+            Expression::Call(_) => Err(Some(not_constant(
+                node,
+                "calls are not evaluated at compile time",
+            ))),
+            Expression::Dict(_)
+            | Expression::Index(_)
+            | Expression::List(_)
+            | Expression::Member(_)
+            | Expression::Slice(_)
+            | Expression::Tuple(_) => Err(Some(not_constant(
+                node,
+                "only literals, constants and operators are evaluated at compile time",
+            ))),
+            Expression::Match(_) => Err(Some(not_constant(
+                node,
+                "`match` is not evaluated at compile time",
+            ))),
+            Expression::FormatString(_) => Err(Some(not_constant(
+                node,
+                "format strings are formatted at runtime",
+            ))),
+            Expression::Lambda(_) => {
+                Err(Some(not_constant(node, "lambdas are created at runtime")))
+            }
+        }
+    }
+}
+
+
+/////////////
+// HELPERS //
+/////////////
+
+/// Returns the diagnostic for an expression that is not a constant expression.
+fn not_constant(node: &Expression, label: &str) -> Box<Diagnostic> {
+    Box::new(
+        Diagnostic::error(
+            "E0048",
+            "expected a compile-time constant",
+            node.location().clone(),
+        )
+        .with_label(label)
+        .with_note(
+            "constants may only use literals, other constants and operators",
+            None,
+        ),
+    )
+}
+
+/// Returns the diagnostic for an operation failing during the evaluation of a constant.
+fn overflow(message: &str, location: &Location) -> Diagnostic {
+    Diagnostic::error("E0050", "evaluation of a constant failed", location.clone())
+        .with_label(message)
+}
+
+/// Applies a binary operator to two constants.
+///
+/// # Errors
+///
+/// Returns a description of the failure if the operation overflows, divides by zero or has an invalid exponent or
+/// shift. Returns `None` if the operator does not support the types of the values, which the type checker reports.
+fn operation(
+    operator: &Mark,
+    left: &Constant,
+    right: &Constant,
+) -> Result<Constant, Option<String>> {
+    let result: Option<Constant> = match (operator, left, right) {
+        (&Mark::Equal, _, _) => Some(Constant::Bool(left == right)),
+        (&Mark::NotEqual, _, _) => Some(Constant::Bool(left != right)),
+        (&Mark::Coalesce, &Constant::None, _) => Some(right.clone()),
+        (&Mark::Coalesce, _, _) => Some(left.clone()),
+        (_, &Constant::Int(first), &Constant::Int(second)) => {
+            let checked: Option<i64> = match *operator {
+                Mark::Add => first.checked_add(second),
+                Mark::Subtract => first.checked_sub(second),
+                Mark::Multiply => first.checked_mul(second),
+                Mark::Divide | Mark::Modulo if second == 0 => {
+                    return Err(Some(format!("`{first} {operator} 0` divides by zero")));
+                }
+                Mark::Divide => first.checked_div(second),
+                Mark::Modulo => first.checked_rem(second),
+                Mark::Exponentiation => {
+                    let exponent: u32 = u32::try_from(second).ok().ok_or_else(|| {
+                        Some(format!("the exponent `{second}` must not be negative"))
+                    })?;
+                    first.checked_pow(exponent)
+                }
+                Mark::BitAnd => Some(first & second),
+                Mark::BitOr => Some(first | second),
+                Mark::BitXor => Some(first ^ second),
+                Mark::ShiftLeft | Mark::ShiftRight => {
+                    let shift: u32 = u32::try_from(second)
+                        .ok()
+                        .filter(|shift| *shift < i64::BITS)
+                        .ok_or_else(|| Some(format!("the shift `{second}` is out of range")))?;
+                    Some(if *operator == Mark::ShiftLeft {
+                        first << shift
+                    } else {
+                        first >> shift
+                    })
+                }
+                Mark::Less => return Ok(Constant::Bool(first < second)),
+                Mark::LessEqual => return Ok(Constant::Bool(first <= second)),
+                Mark::Greater => return Ok(Constant::Bool(first > second)),
+                Mark::GreaterEqual => return Ok(Constant::Bool(first >= second)),
+                _ => return Err(None),
+            };
+
+            return checked.map(Constant::Int).ok_or_else(|| {
+                Some(format!(
+                    "`{first} {operator} {second}` does not fit into `int`"
+                ))
+            });
+        }
+        (_, &Constant::Str(ref first), &Constant::Str(ref second)) => match *operator {
+            Mark::Add => Some(Constant::Str(format!("{first}{second}"))),
+            Mark::Less => Some(Constant::Bool(first < second)),
+            Mark::LessEqual => Some(Constant::Bool(first <= second)),
+            Mark::Greater => Some(Constant::Bool(first > second)),
+            Mark::GreaterEqual => Some(Constant::Bool(first >= second)),
+            _ => None,
+        },
+        (_, &Constant::Bool(first), &Constant::Bool(second)) => match *operator {
+            Mark::And => Some(Constant::Bool(first && second)),
+            Mark::Or => Some(Constant::Bool(first || second)),
+            Mark::BitAnd => Some(Constant::Bool(first & second)),
+            Mark::BitOr => Some(Constant::Bool(first | second)),
+            Mark::BitXor => Some(Constant::Bool(first ^ second)),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    result.ok_or(None)
+}
+
+
+////////////
+// CHECKS //
+////////////
+
+/// Checks the constants of a program: their values must be constant expressions that can be evaluated without
+/// overflowing, and they must never be assigned.
+///
+/// # Parameters
+///
+/// - `program`: The program to check.
+/// - `imports`: The names the program imports from other modules.
+///
+/// # Returns
+///
+/// The diagnostics found, in source order of the checked nodes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::{constants, modules::Imports};
+/// let tokens = lexer::lex::lex("const int MAX = 25 * 4; MAX = 1;", "<stdin>").unwrap();
+/// let program = parser::parse::parse(&tokens).unwrap();
+/// let diagnostics = constants::check(&program, &Imports::default());
+///
+/// assert_eq!(diagnostics[0].code, "E0049");
+/// ```
+#[inline]
+pub fn check<'ast>(program: &'ast Program, imports: &Imports<'ast>) -> Vec<Diagnostic> {
+    let resolution: Resolution = resolve::resolve(program, imports);
+    let mut checker: ConstantChecker = ConstantChecker {
+        evaluator: Evaluator::new(&resolution),
+        diagnostics: vec![],
+    };
+    checker.visit_program(program);
+
+    let mut diagnostics: Vec<Diagnostic> = checker.evaluator.diagnostics;
+    diagnostics.extend(checker.diagnostics);
+    diagnostics
+}
+
+/// Replaces the uses of constants that must be known before the program runs with their values: constants used as
+/// patterns or range bounds, e.g. `case MAX` or `case 0 .. MAX`, and default values of parameters that are constant expressions, e.g. `int x = MAX`.
+///
+/// The loader of [`crate::modules`] folds every module before it is checked and run, so later checks and the
+/// interpreter see the values as literals. Constants whose value is invalid are left as they are, their errors are
+/// reported by [`check`].
+///
+/// # Parameters
+///
+/// - `program`: The program to fold.
+///
+/// # Returns
+///
+/// The program with the constants replaced.
+#[inline]
+pub fn fold(program: Program) -> Program {
+    let resolution: Resolution = resolve::resolve(&program, &Imports::default());
+    let mut collector: Collector = Collector {
+        evaluator: Evaluator::new(&resolution),
+        patterns: HashMap::new(),
+        defaults: HashMap::new(),
+    };
+    collector.visit_program(&program);
+
+    let mut folder: Folder = Folder {
+        patterns: collector.patterns,
+        defaults: collector.defaults,
+    };
+    folder.fold_program(program)
+}
+
+
+//////////////////////
+// CONSTANT CHECKER //
+//////////////////////
+
+/// A visitor evaluating all constant declarations and reporting assignments to constants.
+struct ConstantChecker<'table, 'ast> {
+    /// The evaluator of the constants.
+    evaluator: Evaluator<'table, 'ast>,
+    /// The diagnostics found for assignments.
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'ast> ConstantChecker<'_, 'ast> {
+    /// Reports a change of a constant.
+    fn check_target(&mut self, target: &'ast Expression, location: &Location) {
+        let Expression::Identifier(ref identifier) = *target else {
+            return;
+        };
+        let Some(Declaration::Variable(variable)) =
+            self.evaluator.resolution.declaration(identifier)
+        else {
+            return;
+        };
+        if !variable.constant {
+            return;
+        }
+
+        self.diagnostics.push(
+            Diagnostic::error(
+                "E0049",
+                &format!("cannot assign to constant `{}`", identifier.name),
+                location.clone(),
+            )
+            .with_label("assignment to a constant")
+            .with_note(
+                &format!("`{}` is declared as a constant here", identifier.name),
+                Some(variable.location.clone()),
+            )
+            .with_note(
+                "declare a variable instead of a constant to change its value",
+                None,
+            ),
+        );
+    }
+
+    /// Returns whether a condition is false whatever values the variables it uses have.
+    fn is_false(&mut self, condition: &'ast Expression) -> bool {
+        matches!(
+            self.evaluator.evaluate(condition),
+            Ok(Constant::Bool(false))
+        )
+    }
+
+    /// Reports the `requires` clauses of a called function that the constant arguments of the call violate.
+    /// Arguments that are not constant expressions are unknown, so clauses using them are not reported.
+    fn check_arguments(&mut self, node: &'ast Call, function: &'ast Function) {
+        for (index, argument) in node.arguments.iter().enumerate() {
+            let parameter: Option<&Parameter> = match argument.name {
+                Some(ref name) => function
+                    .parameters
+                    .iter()
+                    .find(|parameter| parameter.name.name == name.name),
+                None => function.parameters.get(index),
+            };
+            if let (Some(parameter), Ok(value)) =
+                (parameter, self.evaluator.evaluate(&argument.value))
+            {
+                self.evaluator.arguments.insert(address(parameter), value);
+            }
+        }
+        for parameter in &function.parameters {
+            let key: *const Parameter = address(parameter);
+            if let (false, Some(default)) = (
+                self.evaluator.arguments.contains_key(&key),
+                parameter.default.as_ref(),
+            ) {
+                if let Ok(value) = self.evaluator.evaluate(default) {
+                    self.evaluator.arguments.insert(key, value);
+                }
+            }
+        }
+
+        if !self.evaluator.arguments.is_empty() {
+            for condition in &function.requires {
+                if self.is_false(condition) {
+                    self.diagnostics.push(
+                        Diagnostic::error(
+                            "E0073",
+                            &format!(
+                                "the arguments violate a `requires` clause of `{}`",
+                                function.name.name
+                            ),
+                            node.callee.location().clone(),
+                        )
+                        .with_label("this call always fails")
+                        .with_note(
+                            "the clause is false for the constant arguments of the call",
+                            Some(condition.location().clone()),
+                        ),
+                    );
+                }
+            }
+        }
+        self.evaluator.arguments.clear();
+    }
+}
+
+impl<'ast> Visitor<'ast> for ConstantChecker<'_, 'ast> {
+    fn visit_assert(&mut self, node: &'ast Assert) {
+        if self.is_false(&node.condition) {
+            self.diagnostics.push(
+                Diagnostic::warning(
+                    "E0074",
+                    "this assertion is always false",
+                    node.location.clone(),
+                )
+                .with_label("always fails")
+                .with_note(
+                    "use `throw` to signal code that should never be reached",
+                    None,
+                ),
+            );
+        }
+        visit::walk_assert(self, node);
+    }
+
+    fn visit_call(&mut self, node: &'ast Call) {
+        if let Expression::Identifier(ref callee) = *node.callee {
+            if let Some(Declaration::Function(function)) =
+                self.evaluator.resolution.declaration(callee)
+            {
+                self.check_arguments(node, function);
+            }
+        }
+        visit::walk_call(self, node);
+    }
+
+    fn visit_function(&mut self, node: &'ast Function) {
+        for (clause, conditions) in [("requires", &node.requires), ("ensures", &node.ensures)] {
+            for condition in conditions {
+                if self.is_false(condition) {
+                    self.diagnostics.push(
+                        Diagnostic::error(
+                            "E0073",
+                            &format!(
+                                "a `{clause}` clause of `{}` is always false",
+                                node.name.name
+                            ),
+                            condition.location().clone(),
+                        )
+                        .with_label("never true")
+                        .with_note(
+                            "the condition does not depend on the arguments or the result",
+                            None,
+                        ),
+                    );
+                }
+            }
+        }
+        visit::walk_function(self, node);
+    }
+
+    fn visit_assignment(&mut self, node: &'ast Assignment) {
+        self.check_target(&node.target, &node.location);
+        visit::walk_assignment(self, node);
+    }
+
+    fn visit_unary(&mut self, node: &'ast Unary) {
+        if matches!(node.operator, Mark::Increase | Mark::Decrease) {
+            self.check_target(&node.operand, &node.location);
+        }
+        visit::walk_unary(self, node);
+    }
+
+    fn visit_variable_declaration(&mut self, node: &'ast VariableDeclaration) {
+        self.evaluator.constant(node);
+        visit::walk_variable_declaration(self, node);
+    }
+}
+
+
+/////////////
+// FOLDING //
+/////////////
+
+/// A visitor collecting the values of the constants used in patterns and default values.
+struct Collector<'table, 'ast> {
+    /// The evaluator of the constants.
+    evaluator: Evaluator<'table, 'ast>,
+    /// The values of the constants used as patterns or range bounds, by the location of the name.
+    patterns: HashMap<Location, Constant>,
+    /// The values of the constant default values, by the location of the default value.
+    defaults: HashMap<Location, Constant>,
+}
+
+impl<'ast> Visitor<'ast> for Collector<'_, 'ast> {
+    fn visit_parameter(&mut self, node: &'ast Parameter) {
+        if let Some(ref default) = node.default {
+            if !matches!(*default, Expression::Literal(_)) {
+                if let Ok(value) = self.evaluator.evaluate(default) {
+                    self.defaults.insert(default.location().clone(), value);
+                }
+            }
+        }
+        visit::walk_parameter(self, node);
+    }
+
+    fn visit_pattern(&mut self, node: &'ast Pattern) {
+        let names: Vec<&Identifier> = match *node {
+            Pattern::Binding(ref binding) => vec![&binding.name],
+            Pattern::Range(ref range) => [&range.start, &range.end]
+                .into_iter()
+                .filter_map(|bound| match *bound {
+                    RangeBound::Constant(ref name) => Some(name),
+                    RangeBound::Literal(_) => None,
+                })
+                .collect(),
+            _ => vec![],
+        };
+        for name in names {
+            if let Some(Declaration::Variable(variable)) =
+                self.evaluator.resolution.declaration(name)
+            {
+                if let Some(value) = self.evaluator.constant(variable) {
+                    self.patterns.insert(name.location.clone(), value);
+                }
+            }
+        }
+        visit::walk_pattern(self, node);
+    }
+}
+
+/// A folder replacing the constants found by the [`Collector`] with their values.
+struct Folder {
+    /// The values of the constants used as patterns or range bounds, by the location of the name.
+    patterns: HashMap<Location, Constant>,
+    /// The values of the constant default values, by the location of the default value.
+    defaults: HashMap<Location, Constant>,
+}
+
+impl Fold for Folder {
+    fn fold_expression(&mut self, node: Expression) -> Expression {
+        stacker::maybe_grow(RED_ZONE, STACK_SEGMENT, || {
+            fold::walk_expression(self, node)
+        })
+    }
+
+    fn fold_parameter(&mut self, mut node: Parameter) -> Parameter {
+        if let Some(value) = node
+            .default
+            .as_ref()
+            .and_then(|default| self.defaults.get(default.location()))
+        {
+            let location: Location = node
+                .default
+                .as_ref()
+                .map_or_else(Location::default, |default| default.location().clone());
+            node.default = Some(Expression::Literal(value.literal(location)));
+        }
+
+        fold::walk_parameter(self, node)
+    }
+
+    fn fold_pattern(&mut self, node: Pattern) -> Pattern {
+        match node {
+            Pattern::Binding(binding) => match self.patterns.get(&binding.name.location) {
+                Some(value) => Pattern::Literal(value.literal(binding.name.location)),
+                None => Pattern::Binding(binding),
+            },
+            other => fold::walk_pattern(self, other),
+        }
+    }
+
+    fn fold_range_bound(&mut self, node: RangeBound) -> RangeBound {
+        match node {
+            RangeBound::Constant(name) => match self.patterns.get(&name.location) {
+                Some(value) => RangeBound::Literal(value.literal(name.location)),
+                None => RangeBound::Constant(name),
+            },
+            literal @ RangeBound::Literal(_) => fold::walk_range_bound(self, literal),
+        }
+    }
+
+    fn fold_statement(&mut self, node: Statement) -> Statement {
+        stacker::maybe_grow(RED_ZONE, STACK_SEGMENT, || fold::walk_statement(self, node))
+    }
+}
@@ -0,0 +1,96 @@
+//! Assertions and contracts: the `assert` statements and the `requires` and `ensures` clauses of functions.
+// I Language compiler.
+// Version: 1.0.0
+
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use parser::ast::{Block, Function, Program, Statement};
+use parser::visit_mut::{self, VisitorMut};
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// The name of the returned value in the `ensures` clauses of a function, e.g. `ensures result >= 0`.
+pub const RESULT: &str = "result";
+
+
+///////////////
+// STRIPPING //
+///////////////
+
+/// Removes the assertions and contracts of a program for a release build: the `assert` statements and the
+/// `requires` and `ensures` clauses. Debug builds keep them and check them at runtime.
+///
+/// # Parameters
+///
+/// - `program`: The checked program to strip.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::contracts;
+/// let tokens = lexer::lex::lex(
+///     "function half(int x) -> int requires x % 2 == 0 { assert x != 0; return x / 2; }",
+///     "<stdin>",
+/// )
+/// .unwrap();
+/// let mut program = parser::parse::parse(&tokens).unwrap();
+/// contracts::strip(&mut program);
+///
+/// let parser::ast::Statement::Function(ref function) = program.statements[0] else { unreachable!() };
+/// assert!(function.requires.is_empty());
+/// assert_eq!(function.body.as_ref().unwrap().statements.len(), 1);
+/// ```
+#[inline]
+pub fn strip(program: &mut Program) {
+    program
+        .statements
+        .retain(|statement| !is_assertion(statement));
+    Stripper.visit_program(program);
+}
+
+/// Returns whether a statement is an `assert` statement.
+const fn is_assertion(statement: &Statement) -> bool {
+    matches!(*statement, Statement::Assert(_))
+}
+
+/// A visitor removing assertions and contracts.
+struct Stripper;
+
+impl VisitorMut for Stripper {
+    fn visit_block(&mut self, node: &mut Block) {
+        node.statements.retain(|statement| !is_assertion(statement));
+        visit_mut::walk_block(self, node);
+    }
+
+    fn visit_function(&mut self, node: &mut Function) {
+        node.requires.clear();
+        node.ensures.clear();
+        visit_mut::walk_function(self, node);
+    }
+}
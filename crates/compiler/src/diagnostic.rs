@@ -0,0 +1,398 @@
+//! Diagnostics (errors and warnings) reported by the compiler.
+// I Language compiler diagnostics.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use core;
+
+use lexer::error::LexerError;
+use lexer::tokens::token::Location;
+use parser::error::ParserError;
+
+use annotate_snippets;
+
+
+//////////////
+// SEVERITY //
+//////////////
+
+/// How severe a [`Diagnostic`] is. Only errors stop the compilation.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Severity {
+    /// An error, the program can not be compiled.
+    Error,
+    /// A warning, the program can be compiled, but probably contains a mistake.
+    Warning,
+}
+
+impl core::fmt::Display for Severity {
+    #[inline]
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::Error => write!(formatter, "error"),
+            Self::Warning => write!(formatter, "warning"),
+        }
+    }
+}
+
+
+////////////////
+// DIAGNOSTIC //
+////////////////
+
+/// An additional note attached to a [`Diagnostic`], e.g. pointing at a previous declaration.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Note {
+    /// The text of the note.
+    pub message: String,
+    /// The location the note points at, if any.
+    pub location: Option<Location>,
+}
+
+/// An error or warning found while checking a program.
+///
+/// Every diagnostic has a code, warnings included. Codes are shared with the lexer, which uses `E0001`
+/// (syntax error) and `E0002` (unterminated string literal):
+///
+/// | Code    | Description                                                                                          |
+/// | ------- | ---------------------------------------------------------------------------------------------------- |
+/// | `E0003` | A class is declared more than once.                                                                  |
+/// | `E0004` | A base class or interface does not exist.                                                            |
+/// | `E0005` | A class has more than one base class.                                                                |
+/// | `E0006` | A class or interface inherits from itself.                                                           |
+/// | `E0007` | A class member is declared more than once.                                                           |
+/// | `E0008` | A field redeclares an inherited field.                                                               |
+/// | `E0009` | A method overrides or implements a method with another signature.                                    |
+/// | `E0010` | An abstract or interface method is not overridden.                                                   |
+/// | `E0011` | A called method does not exist.                                                                      |
+/// | `E0012` | A private member is used outside of its class.                                                       |
+/// | `E0013` | An abstract class is instantiated.                                                                   |
+/// | `E0014` | A function has two parameters with the same name.                                                    |
+/// | `E0015` | A parameter without a default follows one with a default.                                            |
+/// | `E0016` | A call has too many arguments.                                                                       |
+/// | `E0017` | A keyword argument does not name a parameter.                                                        |
+/// | `E0018` | An argument is given more than once.                                                                 |
+/// | `E0019` | An argument for a required parameter is missing.                                                     |
+/// | `E0020` | A positional argument follows a keyword argument.                                                    |
+/// | `E0021` | A value is returned from a function without return type.                                             |
+/// | `E0022` | A `return;` in a function with a return type.                                                        |
+/// | `E0023` | A value has another type than expected.                                                              |
+/// | `E0024` | A function does not return a value on all paths.                                                     |
+/// | `E0025` | A function outside of a class has no body.                                                           |
+/// | `E0026` | A case of a match is never reached.                                                                  |
+/// | `E0027` | A match does not cover every value, a warning for match statements on a `bool` or an enum.           |
+/// | `E0028` | A range pattern is empty or its bounds are not integer literals or constants.                        |
+/// | `E0029` | A class pattern names an unknown class.                                                              |
+/// | `E0030` | A class pattern has another number of fields than the class.                                         |
+/// | `E0031` | A `catch` clause names a class that is not an exception.                                             |
+/// | `E0032` | A value that is not an exception is thrown.                                                          |
+/// | `E0033` | A `catch` clause is never reached.                                                                   |
+/// | `E0034` | An imported module does not exist.                                                                   |
+/// | `E0035` | Modules import each other in a cycle.                                                                |
+/// | `E0036` | A `use` statement or a member access like `random.choice` names a private item.                      |
+/// | `E0037` | A `use` statement or a member access like `random.choice` names an item the module does not declare. |
+/// | `E0038` | A name is not declared in any visible scope.                                                         |
+/// | `E0039` | A type is neither built-in nor a known class.                                                        |
+/// | `E0040` | A name is declared more than once in the same scope.                                                 |
+/// | `E0041` | A variable shadows a variable of an outer scope.                                                     |
+/// | `E0042` | `this` or `super` is used outside of a method.                                                       |
+/// | `E0043` | An operator does not support the types of its operands or is not defined by a class.                 |
+/// | `E0044` | A value that is not a function is called.                                                            |
+/// | `E0045` | A value cannot be converted to the requested type.                                                   |
+/// | `E0046` | The type of a variable declared with `var` cannot be inferred.                                       |
+/// | `E0047` | A variable is read before it is assigned on every path.                                              |
+/// | `E0048` | A constant is initialized with an expression that is not a compile-time constant.                    |
+/// | `E0049` | A constant is assigned or changed.                                                                   |
+/// | `E0050` | Evaluating a constant overflows, divides by zero or uses an invalid exponent or shift.               |
+/// | `E0051` | `break` or `continue` is used outside of a loop.                                                     |
+/// | `E0052` | Code is never run.                                                                                   |
+/// | `E0053` | A function with a return type runs a loop that never ends.                                           |
+/// | `E0054` | A type argument does not satisfy the bound of its type parameter, or a `dict` key type is invalid.   |
+/// | `E0055` | A generic class or collection is given the wrong number of type arguments, or none.                  |
+/// | `E0056` | A type argument of a generic function call cannot be inferred.                                       |
+/// | `E0057` | An interface is used as a base class, or a class is used as an interface.                            |
+/// | `E0058` | An interface or an enum declares a field or a constructor.                                           |
+/// | `E0059` | An interface or an enum is instantiated.                                                             |
+/// | `E0060` | A method implementing an interface method is not public.                                             |
+/// | `E0061` | A class extends an enum or an enum variant.                                                          |
+/// | `E0062` | A value cannot be indexed or sliced, or an element of a `tuple` or `str` is assigned.                |
+/// | `E0063` | A value that cannot be iterated is used in a `for` loop.                                             |
+/// | `E0064` | A method of a class cannot be used for the operator it is named after.                               |
+/// | `E0065` | A value that may be `none` is used without checking it first.                                        |
+/// | `E0066` | An annotation is unknown.                                                                            |
+/// | `E0067` | An annotation has invalid arguments, is repeated or is attached to the wrong declaration.            |
+/// | `E0068` | A deprecated function, method, class or field is used.                                               |
+/// | `E0069` | An integer literal is out of the range of the integer type it is used as.                            |
+/// | `E0070` | `yield` is used outside of a function, in a lambda or in a constructor.                              |
+/// | `E0071` | A generator does not return `Iterator<T>` or returns a value.                                        |
+/// | `E0072` | `yield` is used inside of a `try` statement, which cannot apply while the generator is suspended.    |
+/// | `E0073` | A `requires` or `ensures` clause is always false, or a call with constant arguments violates it.     |
+/// | `E0074` | The condition of an `assert` statement is always false.                                              |
+/// | `E0075` | A format specification is invalid or cannot format the type of its value.                            |
+/// | `E0076` | A class, built-in function or module is used as a value.                                             |
+/// | `E0077` | A field or a variable of the module has no initial value, although its type has no default value.    |
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Diagnostic {
+    /// Whether this is an error or a warning.
+    pub severity: Severity,
+    /// The code of the diagnostic, e.g. `E0003`.
+    pub code: &'static str,
+    /// The main message, e.g. "duplicate class `Player`".
+    pub message: String,
+    /// The location the diagnostic points at.
+    pub location: Location,
+    /// The number of characters the label spans, by default the word at the location.
+    pub length: Option<usize>,
+    /// A short label shown at the location.
+    pub label: String,
+    /// Additional notes.
+    pub notes: Vec<Note>,
+}
+
+impl Diagnostic {
+    /// Creates a new error. The label defaults to the message.
+    #[inline]
+    pub fn error(code: &'static str, message: &str, location: Location) -> Self {
+        Self {
+            severity: Severity::Error,
+            code,
+            message: message.to_owned(),
+            location,
+            length: None,
+            label: message.to_owned(),
+            notes: vec![],
+        }
+    }
+
+    /// Creates a new warning. The label defaults to the message.
+    #[inline]
+    pub fn warning(code: &'static str, message: &str, location: Location) -> Self {
+        Self {
+            severity: Severity::Warning,
+            ..Self::error(code, message, location)
+        }
+    }
+
+    /// Replaces the label shown at the location.
+    #[inline]
+    #[must_use]
+    pub fn with_label(mut self, label: &str) -> Self {
+        label.clone_into(&mut self.label);
+        self
+    }
+
+    /// Makes the label span `length` characters instead of the word at the location, e.g. a whole module path.
+    #[inline]
+    #[must_use]
+    pub fn with_length(mut self, length: usize) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Adds a note, optionally pointing at another location.
+    #[inline]
+    #[must_use]
+    pub fn with_note(mut self, message: &str, location: Option<Location>) -> Self {
+        self.notes.push(Note {
+            message: message.to_owned(),
+            location,
+        });
+        self
+    }
+
+    /// Returns whether the diagnostic is an error.
+    #[inline]
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
+    /// Renders the diagnostic with the source code it points at, like the lexer does for its errors.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: The source code of the file the diagnostic is in.
+    ///
+    /// # Returns
+    ///
+    /// The rendered diagnostic, including colors.
+    #[inline]
+    pub fn render(&self, source: &str) -> String {
+        self.render_with(|file| (file == self.location.file).then_some(source))
+    }
+
+    /// Renders the diagnostic like [`Diagnostic::render`], but with notes in other files, e.g. pointing at the
+    /// declaration of an item in another module, shown with the source code of their file.
+    ///
+    /// # Parameters
+    ///
+    /// - `sources`: Returns the source code of a file, or `None` if it is unknown.
+    ///
+    /// # Returns
+    ///
+    /// The rendered diagnostic, including colors.
+    #[inline]
+    pub fn render_with<'source, F>(&self, sources: F) -> String
+    where
+        F: Fn(&str) -> Option<&'source str>,
+    {
+        let line = |location: &Location| -> Option<&'source str> {
+            sources(&location.file)?
+                .split('\n')
+                .nth(location.line.saturating_sub(1))
+        };
+        let annotation_type = |severity: Severity| match severity {
+            Severity::Error => annotate_snippets::AnnotationType::Error,
+            Severity::Warning => annotate_snippets::AnnotationType::Warning,
+        };
+
+        let mut slices: Vec<annotate_snippets::Slice> = vec![];
+        let mut footer: Vec<annotate_snippets::Annotation> = vec![];
+
+        if let Some(line) = line(&self.location) {
+            let range: (usize, usize) = match self.length {
+                Some(length) => {
+                    let start: usize = self.location.column.saturating_sub(1);
+                    (start, start + length.max(1))
+                }
+                None => word_range(line, self.location.column),
+            };
+            slices.push(annotate_snippets::Slice {
+                source: line,
+                line_start: self.location.line,
+                origin: Some(&self.location.file),
+                annotations: vec![annotate_snippets::SourceAnnotation {
+                    range,
+                    label: &self.label,
+                    annotation_type: annotation_type(self.severity),
+                }],
+                fold: false,
+            });
+        }
+
+        for note in &self.notes {
+            match (
+                note.location.as_ref().and_then(line),
+                note.location.as_ref(),
+            ) {
+                (Some(line), Some(location)) => slices.push(annotate_snippets::Slice {
+                    source: line,
+                    line_start: location.line,
+                    origin: Some(&location.file),
+                    annotations: vec![annotate_snippets::SourceAnnotation {
+                        range: word_range(line, location.column),
+                        label: &note.message,
+                        annotation_type: annotate_snippets::AnnotationType::Note,
+                    }],
+                    fold: false,
+                }),
+                _ => footer.push(annotate_snippets::Annotation {
+                    id: None,
+                    label: Some(&note.message),
+                    annotation_type: annotate_snippets::AnnotationType::Note,
+                }),
+            }
+        }
+
+        let snippet: annotate_snippets::Snippet = annotate_snippets::Snippet {
+            title: Some(annotate_snippets::Annotation {
+                id: Some(self.code),
+                label: Some(&self.message),
+                annotation_type: annotation_type(self.severity),
+            }),
+            footer,
+            slices,
+        };
+
+        annotate_snippets::Renderer::styled()
+            .render(snippet)
+            .to_string()
+    }
+}
+
+impl core::fmt::Display for Diagnostic {
+    #[inline]
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            formatter,
+            "{}[{}]: {} at {}",
+            self.severity, self.code, self.message, self.location
+        )?;
+
+        for note in &self.notes {
+            match note.location {
+                Some(ref location) => {
+                    write!(formatter, "\n  note: {} at {location}", note.message)?;
+                }
+                None => write!(formatter, "\n  note: {}", note.message)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<&LexerError> for Diagnostic {
+    #[inline]
+    fn from(error: &LexerError) -> Self {
+        match *error {
+            LexerError::UnterminatedString { ref location } => {
+                Self::error("E0002", "Unterminated string literal", location.clone())
+            }
+            LexerError::InvalidMark { ref location }
+            | LexerError::UnexpectedCharacter { ref location, .. }
+            | LexerError::UnterminatedComment { ref location } => {
+                Self::error("E0001", "Syntax error", location.clone())
+            }
+        }
+        .with_label(&error.to_string())
+    }
+}
+
+impl From<&ParserError> for Diagnostic {
+    #[inline]
+    fn from(error: &ParserError) -> Self {
+        Self::error("E0001", "Syntax error", error.location().clone())
+            .with_label(&error.to_string())
+    }
+}
+
+
+/////////////
+// HELPERS //
+/////////////
+
+/// Returns the character range of the word starting at the one-based `column` of a line.
+/// Falls back to a single character if no word starts there.
+fn word_range(line: &str, column: usize) -> (usize, usize) {
+    let start: usize = column.saturating_sub(1);
+    let length: usize = line
+        .chars()
+        .skip(start)
+        .take_while(|character| character.is_alphanumeric() || *character == '_')
+        .count();
+
+    (start, start + length.max(1))
+}
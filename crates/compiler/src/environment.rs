@@ -0,0 +1,355 @@
+//! The names visible at a point of a program and a simple inference of expression types.
+// I Language compiler environment.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use std::collections::HashMap;
+
+use crate::classes::ClassTable;
+use crate::functions;
+use crate::generics::{self, Substitution, TypeNames};
+use crate::integers;
+use crate::modules::{self, Imports, Item, Module};
+
+use lexer::tokens::mark::Mark;
+use lexer::tokens::token::TypeDefinition;
+use parser::ast::{
+    Expression, For, ForIterable, Function, Literal, MatchBody, Pattern, Statement,
+    VariableDeclaration,
+};
+
+
+/////////////
+// BINDING //
+/////////////
+
+/// What a name in a scope refers to.
+#[derive(Clone, Copy, Debug)]
+pub enum Binding<'ast> {
+    /// A function declared with `function`.
+    Function(&'ast Function),
+    /// A module bound with `import`.
+    Module(&'ast Module),
+    /// A variable, parameter or pattern binding, with its type if it is known.
+    Variable(Option<&'ast str>),
+}
+
+
+/////////////////
+// ENVIRONMENT //
+/////////////////
+
+/// The names visible while visiting a program, one scope per block, innermost last.
+#[derive(Clone, Debug, Default)]
+pub struct Environment<'ast> {
+    /// The names declared in each scope.
+    scopes: Vec<HashMap<&'ast str, Binding<'ast>>>,
+}
+
+impl<'ast> Environment<'ast> {
+    /// Opens a new scope. Functions declared directly in the statements are visible in the whole scope.
+    #[inline]
+    pub fn enter(&mut self, statements: &'ast [Statement]) {
+        let mut scope: HashMap<&str, Binding> = HashMap::new();
+        for statement in statements {
+            if let Statement::Function(ref function) = *statement {
+                scope
+                    .entry(&function.name.name)
+                    .or_insert(Binding::Function(function));
+            }
+        }
+
+        self.scopes.push(scope);
+    }
+
+    /// Declares the modules and functions a program imports in the innermost scope.
+    /// Imported classes are part of the [`ClassTable`] instead.
+    #[inline]
+    #[allow(clippy::iter_over_hash_type)]
+    pub fn import(&mut self, imports: &Imports<'ast>) {
+        let Some(scope) = self.scopes.last_mut() else {
+            return;
+        };
+
+        for (&name, &module) in &imports.modules {
+            scope.entry(name).or_insert(Binding::Module(module));
+        }
+        for (&name, item) in &imports.items {
+            if let Item::Function(function) = *item {
+                scope.entry(name).or_insert(Binding::Function(function));
+            }
+        }
+    }
+
+    /// Closes the innermost scope.
+    #[inline]
+    pub fn exit(&mut self) {
+        self.scopes.pop();
+    }
+
+    /// Declares a variable in the innermost scope.
+    #[inline]
+    pub fn declare(&mut self, name: &'ast str, type_name: Option<&'ast str>) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name, Binding::Variable(type_name));
+        }
+    }
+
+    /// Declares a declared variable in the innermost scope.
+    /// Variables declared with `var` get the type of their initial value, if it is known and not `none`.
+    #[inline]
+    pub fn declare_variable(&mut self, node: &'ast VariableDeclaration) {
+        let type_name: Option<&str> = match node.type_annotation {
+            Some(ref annotation) => Some(&annotation.name),
+            None => node
+                .value
+                .as_ref()
+                .and_then(|value| self.type_of(value))
+                .filter(|found| *found != "none"),
+        };
+
+        self.declare(&node.name.name, type_name);
+    }
+
+    /// Declares the variable of a `for` loop in the innermost scope. The variable of a range loop without a type is
+    /// an `int`.
+    #[inline]
+    pub fn declare_loop_variable(&mut self, node: &'ast For) {
+        let type_name: Option<&str> = match (&node.type_annotation, &node.iterable) {
+            (&Some(ref annotation), _) => Some(&annotation.name),
+            (&None, &ForIterable::Range(_)) => Some("int"),
+            (&None, &ForIterable::Value(_)) => None,
+        };
+
+        self.declare(&node.variable.name, type_name);
+    }
+
+    /// Declares the names bound by a pattern in the innermost scope.
+    ///
+    /// # Parameters
+    ///
+    /// - `pattern`: The pattern declaring the names.
+    /// - `type_name`: The type of the matched value, if it is known.
+    /// - `classes`: The classes of the program, used for the fields of class patterns.
+    /// - `names`: The type names of the check, the types of fields with type arguments are stored in it.
+    #[inline]
+    pub fn bind(
+        &mut self,
+        pattern: &'ast Pattern,
+        type_name: Option<&'ast str>,
+        classes: &ClassTable<'ast>,
+        names: &'ast TypeNames,
+    ) {
+        match *pattern {
+            Pattern::Alternative(ref alternative) => {
+                for found in &alternative.patterns {
+                    self.bind(found, type_name, classes, names);
+                }
+            }
+            Pattern::Binding(ref binding) => self.declare(
+                &binding.name.name,
+                binding
+                    .type_annotation
+                    .as_ref()
+                    .map(|annotation| annotation.name.as_str())
+                    .or(type_name),
+            ),
+            Pattern::Class(ref class_pattern) => {
+                let fields: Vec<Option<&str>> = classes
+                    .get(&class_pattern.class.name)
+                    .map(|class| {
+                        // The type arguments of the matched value apply to the class itself and to the variants
+                        // of an enum, fields whose type is still a type parameter have no known type
+                        let substitution: Substitution = type_name
+                            .filter(|found| {
+                                let base: &str = generics::split(found).0;
+                                class.name.name == base
+                                    || classes
+                                        .enumeration(class)
+                                        .map_or(false, |enumeration| enumeration.name.name == base)
+                            })
+                            .map(|found| generics::class_substitution(class, found))
+                            .unwrap_or_default();
+
+                        classes
+                            .fields(class)
+                            .iter()
+                            .map(|field| {
+                                let found: &str = names.substitute(
+                                    names.annotation_type(&field.type_annotation),
+                                    &substitution,
+                                );
+                                Some(found).filter(|found| {
+                                    !class.type_parameters.iter().any(|parameter| {
+                                        generics::mentions(found, &parameter.name.name)
+                                    })
+                                })
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                for (index, found) in class_pattern.fields.iter().enumerate() {
+                    self.bind(found, fields.get(index).copied().flatten(), classes, names);
+                }
+            }
+            Pattern::Literal(_) | Pattern::Range(_) => {}
+        }
+    }
+
+    /// Returns what a name refers to.
+    #[inline]
+    pub fn lookup(&self, name: &str) -> Option<Binding<'ast>> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name))
+            .copied()
+    }
+
+    /// Returns the type of an expression, if it can be determined without a full type checker.
+    #[inline]
+    pub fn type_of(&self, expression: &Expression) -> Option<&'ast str> {
+        match *expression {
+            Expression::Literal(ref literal) => Some(literal_type(literal)),
+            Expression::FormatString(_) => Some("str"),
+            Expression::Identifier(ref identifier) => match self.lookup(&identifier.name)? {
+                Binding::Variable(type_name) => type_name,
+                Binding::Function(_) => Some("function"),
+                Binding::Module(_) => None,
+            },
+            Expression::Call(ref call) => match *call.callee {
+                Expression::Identifier(ref identifier) => match self.lookup(&identifier.name)? {
+                    // The return type of a generic function depends on the inferred type arguments
+                    Binding::Function(function) if function.type_parameters.is_empty() => {
+                        Some(functions::return_type(function))
+                    }
+                    Binding::Function(_) | Binding::Variable(_) | Binding::Module(_) => None,
+                },
+                Expression::Member(ref member) => match *member.target {
+                    Expression::Identifier(ref target) => match self.lookup(&target.name)? {
+                        Binding::Module(module) => {
+                            match modules::items(&module.program)
+                                .get(member.member.name.as_str())?
+                            {
+                                &Item::Function(function)
+                                    if function.public && function.type_parameters.is_empty() =>
+                                {
+                                    Some(functions::return_type(function))
+                                }
+                                _ => None,
+                            }
+                        }
+                        _ => None,
+                    },
+                    _ => None,
+                },
+                _ => None,
+            },
+            Expression::Binary(ref binary) => match binary.operator {
+                Mark::Equal
+                | Mark::NotEqual
+                | Mark::Less
+                | Mark::LessEqual
+                | Mark::Greater
+                | Mark::GreaterEqual
+                | Mark::And
+                | Mark::Or => Some("bool"),
+                Mark::Add
+                | Mark::Subtract
+                | Mark::Multiply
+                | Mark::Divide
+                | Mark::Modulo
+                | Mark::Exponentiation
+                | Mark::BitAnd
+                | Mark::BitOr
+                | Mark::BitXor
+                | Mark::ShiftLeft
+                | Mark::ShiftRight => {
+                    let left: &str = self.type_of(&binary.left)?;
+                    let right: &str = self.type_of(&binary.right)?;
+                    match (left, right) {
+                        (left, right) if left == right && integers::is_integer(left) => Some(left),
+                        // An integer literal takes the type of the other operand
+                        (found, "int")
+                            if integers::is_integer(found)
+                                && integers::literal(&binary.right).is_some() =>
+                        {
+                            Some(found)
+                        }
+                        ("int", found)
+                            if integers::is_integer(found)
+                                && integers::literal(&binary.left).is_some() =>
+                        {
+                            Some(found)
+                        }
+                        ("str", "str") if binary.operator == Mark::Add => Some("str"),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            },
+            Expression::Unary(ref unary) => match unary.operator {
+                Mark::Bang => Some("bool"),
+                _ => self
+                    .type_of(&unary.operand)
+                    .filter(|found| integers::is_integer(found)),
+            },
+            Expression::Assignment(ref assignment) => self.type_of(&assignment.target),
+            // A match expression has a type if all of its cases have the same type
+            Expression::Match(ref node) => {
+                let mut types = node.arms.iter().map(|arm| match arm.body {
+                    MatchBody::Expression(ref value) => self.type_of(value),
+                    MatchBody::Block(_) => None,
+                });
+                let first: &str = types.next()??;
+                types.all(|found| found == Some(first)).then_some(first)
+            }
+            Expression::Dict(_) => Some("dict"),
+            Expression::Lambda(_) => Some("function"),
+            Expression::List(_) => Some("list"),
+            Expression::Tuple(_) => Some("tuple"),
+            Expression::Index(_) | Expression::Member(_) | Expression::Slice(_) => None,
+        }
+    }
+}
+
+
+/////////////
+// HELPERS //
+/////////////
+
+/// Returns the name of the type of a literal, e.g. `int` for `1`. Integer literals too large for an `int` are
+/// `bigint` values.
+#[inline]
+pub fn literal_type(literal: &Literal) -> &'static str {
+    match literal.kind {
+        TypeDefinition::Integer => integers::literal_type(&literal.value),
+        TypeDefinition::String | TypeDefinition::FormatString => "str",
+        TypeDefinition::True | TypeDefinition::False => "bool",
+        TypeDefinition::None => "none",
+    }
+}
@@ -0,0 +1,240 @@
+//! Semantic checks for exceptions: thrown values and `catch` clauses.
+// I Language exception checks.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use crate::classes::ClassTable;
+use crate::diagnostic::Diagnostic;
+use crate::environment::Environment;
+use crate::modules::Imports;
+use crate::prelude;
+
+use parser::ast::{
+    Block, Catch, Class, Expression, For, Function, Lambda, Program, Throw, Try,
+    VariableDeclaration,
+};
+use parser::visit::{self, Visitor};
+
+
+////////////
+// CHECKS //
+////////////
+
+/// Checks all `throw` statements and `catch` clauses of a program.
+///
+/// Only instances of `Exception` and its subclasses can be thrown and caught. A `catch` clause after a
+/// clause catching one of its base classes is never executed.
+///
+/// # Parameters
+///
+/// - `program`: The program to check.
+/// - `imports`: The names the program imports from other modules.
+///
+/// # Returns
+///
+/// The diagnostics found, in source order.
+#[inline]
+pub fn check<'ast>(program: &'ast Program, imports: &Imports<'ast>) -> Vec<Diagnostic> {
+    let classes: ClassTable = ClassTable::new(program, imports);
+    let mut checker: ExceptionChecker = ExceptionChecker {
+        classes: &classes,
+        environment: Environment::default(),
+        diagnostics: vec![],
+    };
+    checker.environment.enter(&program.statements);
+    checker.environment.import(imports);
+    checker.visit_program(program);
+
+    checker.diagnostics
+}
+
+
+///////////////////////
+// EXCEPTION CHECKER //
+///////////////////////
+
+/// A visitor checking thrown values and caught exception classes.
+struct ExceptionChecker<'table, 'ast> {
+    /// The classes of the program, including the built-in exception classes.
+    classes: &'table ClassTable<'ast>,
+    /// The names visible at the visited node.
+    environment: Environment<'ast>,
+    /// The diagnostics found so far.
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'ast> ExceptionChecker<'_, 'ast> {
+    /// Returns the type of a thrown value, if it is known. Calls of classes create instances of the class.
+    fn type_of(&self, value: &Expression) -> Option<String> {
+        if let Expression::Call(ref call) = *value {
+            if let Expression::Identifier(ref identifier) = *call.callee {
+                if self.environment.lookup(&identifier.name).is_none()
+                    && self.classes.get(&identifier.name).is_some()
+                {
+                    return Some(identifier.name.clone());
+                }
+            }
+        }
+
+        self.environment.type_of(value).map(str::to_owned)
+    }
+
+    /// Returns the exception class caught by a clause, reporting classes that are no exceptions.
+    fn caught(&mut self, node: &Catch) -> Option<&'ast Class> {
+        let name: &str = &node.exception.name;
+        match self.classes.get(name) {
+            Some(class) if self.classes.is_exception(class) => Some(class),
+            found => {
+                let message: String = if found.is_some() || is_built_in(name) {
+                    format!("`{name}` is not an exception class")
+                } else {
+                    format!("unknown exception class `{name}`")
+                };
+                self.diagnostics.push(
+                    Diagnostic::error("E0031", &message, node.exception.location.clone())
+                        .with_label("cannot be caught")
+                        .with_note(
+                            "only `Exception` and its subclasses can be caught",
+                            found.map(|class| class.name.location.clone()),
+                        ),
+                );
+                None
+            }
+        }
+    }
+}
+
+/// Returns whether a type is a built-in type.
+fn is_built_in(name: &str) -> bool {
+    prelude::BUILT_IN_TYPES.contains(&name)
+}
+
+impl<'ast> Visitor<'ast> for ExceptionChecker<'_, 'ast> {
+    fn visit_block(&mut self, node: &'ast Block) {
+        self.environment.enter(&node.statements);
+        visit::walk_block(self, node);
+        self.environment.exit();
+    }
+
+    fn visit_function(&mut self, node: &'ast Function) {
+        self.environment.enter(&[]);
+        for parameter in &node.parameters {
+            self.environment
+                .declare(&parameter.name.name, Some(&parameter.type_annotation.name));
+        }
+        visit::walk_function(self, node);
+        self.environment.exit();
+    }
+
+    fn visit_lambda(&mut self, node: &'ast Lambda) {
+        self.environment.enter(&[]);
+        for parameter in &node.parameters {
+            self.environment
+                .declare(&parameter.name.name, Some(&parameter.type_annotation.name));
+        }
+        visit::walk_lambda(self, node);
+        self.environment.exit();
+    }
+
+    fn visit_catch(&mut self, node: &'ast Catch) {
+        self.environment.enter(&[]);
+        if let Some(ref binding) = node.binding {
+            self.environment
+                .declare(&binding.name, Some(&node.exception.name));
+        }
+        visit::walk_catch(self, node);
+        self.environment.exit();
+    }
+
+    fn visit_for(&mut self, node: &'ast For) {
+        self.visit_for_iterable(&node.iterable);
+        self.environment.enter(&[]);
+        self.environment.declare_loop_variable(node);
+        self.visit_block(&node.body);
+        self.environment.exit();
+    }
+
+    fn visit_variable_declaration(&mut self, node: &'ast VariableDeclaration) {
+        visit::walk_variable_declaration(self, node);
+        self.environment.declare_variable(node);
+    }
+
+    fn visit_throw(&mut self, node: &'ast Throw) {
+        visit::walk_throw(self, node);
+
+        let Some(found) = self.type_of(&node.value) else {
+            return;
+        };
+        let is_exception: bool = self
+            .classes
+            .get(&found)
+            .map_or(false, |class| self.classes.is_exception(class));
+        if !is_exception && (is_built_in(&found) || self.classes.get(&found).is_some()) {
+            self.diagnostics.push(
+                Diagnostic::error(
+                    "E0032",
+                    &format!("cannot throw a value of type `{found}`"),
+                    node.value.location().clone(),
+                )
+                .with_label("not an exception")
+                .with_note(
+                    "only instances of `Exception` and its subclasses can be thrown",
+                    None,
+                ),
+            );
+        }
+    }
+
+    fn visit_try(&mut self, node: &'ast Try) {
+        let mut previous: Vec<(&Class, &Catch)> = vec![];
+        for catch in &node.catches {
+            let Some(class) = self.caught(catch) else {
+                continue;
+            };
+
+            if let Some(&(base, earlier)) = previous
+                .iter()
+                .find(|&&(base, _)| self.classes.is_subclass(class, base))
+            {
+                self.diagnostics.push(
+                    Diagnostic::warning(
+                        "E0033",
+                        "unreachable catch clause",
+                        catch.exception.location.clone(),
+                    )
+                    .with_label(&format!(
+                        "`{}` is already caught as `{}`",
+                        class.name.name, base.name.name
+                    ))
+                    .with_note("caught here", Some(earlier.exception.location.clone())),
+                );
+            }
+            previous.push((class, catch));
+        }
+
+        visit::walk_try(self, node);
+    }
+}
@@ -0,0 +1,560 @@
+//! Control-flow graphs of function bodies and the checks built on them: `break` and `continue` outside of loops,
+//! unreachable code, endless loops and missing returns.
+// I Language control flow.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use std::collections::HashMap;
+
+use crate::classes::ClassTable;
+use crate::diagnostic::Diagnostic;
+use crate::functions::Callable;
+use crate::generators;
+use crate::modules::Imports;
+use crate::patterns;
+use crate::resolve::address;
+
+use lexer::tokens::token::TypeDefinition;
+use parser::ast::{
+    Expression, For, Function, Lambda, LambdaBody, Match, MatchBody, Program, Statement, Try, While,
+};
+use parser::visit::{self, Visitor};
+
+
+///////////
+// GRAPH //
+///////////
+
+/// A basic block of a [`Graph`]: statements that always run one after the other.
+#[derive(Clone, Debug, Default)]
+pub struct BasicBlock<'ast> {
+    /// The statements starting in the block. Compound statements like `if` start in the block of their condition.
+    pub statements: Vec<&'ast Statement>,
+    /// The indices of the blocks the control may continue with.
+    pub successors: Vec<usize>,
+}
+
+/// The control-flow graph of a function body or of the top-level code of a program.
+///
+/// Nested functions, lambdas and classes get graphs of their own, their bodies are not part of the graph. The graph is
+/// built from the syntax alone: a `while (true)` loop is only left with `break`, every `catch` and `finally` block
+/// may be entered from the start of its `try`, and `throw` leaves the body.
+#[derive(Clone, Debug)]
+#[allow(clippy::partial_pub_fields)]
+pub struct Graph<'ast> {
+    /// The basic blocks, indexed by the successors.
+    pub blocks: Vec<BasicBlock<'ast>>,
+    /// The index of the block the body starts with.
+    pub entry: usize,
+    /// The index of the empty block reached by running past the last statement of the body.
+    pub end: usize,
+    /// The index of the empty block reached by `return` and `throw` and from the end.
+    pub exit: usize,
+    /// The `while` loops of the body with the index of the block of their condition.
+    pub loops: Vec<(&'ast While, usize)>,
+    /// The `break` and `continue` statements outside of any loop.
+    pub stray: Vec<&'ast Statement>,
+    /// The index of the block each statement starts in.
+    starts: HashMap<*const Statement, usize>,
+    /// The statement lists of the body, in source order.
+    lists: Vec<&'ast [Statement]>,
+}
+
+impl<'ast> Graph<'ast> {
+    /// Builds the graph of a list of statements.
+    ///
+    /// # Parameters
+    ///
+    /// - `statements`: The statements of a function body or of a program.
+    /// - `classes`: The classes of the program, used to find matches covering every variant of an enum.
+    ///
+    /// # Returns
+    ///
+    /// The control-flow graph of the statements.
+    #[inline]
+    pub fn build(statements: &'ast [Statement], classes: &ClassTable<'ast>) -> Self {
+        let mut builder: Builder = Builder {
+            classes,
+            graph: Graph {
+                blocks: vec![BasicBlock::default(); 3],
+                entry: 0,
+                end: 1,
+                exit: 2,
+                loops: vec![],
+                stray: vec![],
+                starts: HashMap::new(),
+                lists: vec![],
+            },
+            loops: vec![],
+        };
+        let entry: usize = builder.graph.entry;
+        let end: usize = builder.graph.end;
+        let exit: usize = builder.graph.exit;
+
+        if let Some(last) = builder.statements(statements, Some(entry)) {
+            builder.edge(last, end);
+        }
+        builder.edge(end, exit);
+
+        builder.graph
+    }
+
+    /// Returns the index of the block a statement starts in, or `None` if the statement is not part of the graph.
+    #[inline]
+    pub fn block_of(&self, statement: &Statement) -> Option<usize> {
+        self.starts.get(&address(statement)).copied()
+    }
+
+    /// Returns which blocks can be reached from a block, following the successors.
+    ///
+    /// # Returns
+    ///
+    /// A flag for every block, `true` if the block can be reached. The start block is reachable.
+    #[inline]
+    #[allow(clippy::indexing_slicing)]
+    pub fn reachable(&self, from: usize) -> Vec<bool> {
+        let mut reached: Vec<bool> = vec![false; self.blocks.len()];
+        let mut pending: Vec<usize> = vec![from];
+
+        while let Some(block) = pending.pop() {
+            if reached[block] {
+                continue;
+            }
+            reached[block] = true;
+            pending.extend(&self.blocks[block].successors);
+        }
+
+        reached
+    }
+}
+
+
+/////////////
+// BUILDER //
+/////////////
+
+/// Builds a [`Graph`] statement by statement. A position of `None` means the control never gets there.
+struct Builder<'table, 'ast> {
+    /// The classes of the program.
+    classes: &'table ClassTable<'ast>,
+    /// The graph built so far.
+    graph: Graph<'ast>,
+    /// The enclosing loops, innermost last, as the blocks `continue` and `break` jump to.
+    loops: Vec<(usize, usize)>,
+}
+
+impl<'ast> Builder<'_, 'ast> {
+    /// Adds an empty block and returns its index.
+    fn block(&mut self) -> usize {
+        self.graph.blocks.push(BasicBlock::default());
+        self.graph.blocks.len() - 1
+    }
+
+    /// Adds an edge between two blocks.
+    #[allow(clippy::indexing_slicing)]
+    fn edge(&mut self, from: usize, to: usize) {
+        self.graph.blocks[from].successors.push(to);
+    }
+
+    /// Adds a new block entered from a position and returns it.
+    fn branch(&mut self, from: Option<usize>) -> usize {
+        let block: usize = self.block();
+        if let Some(from) = from {
+            self.edge(from, block);
+        }
+        block
+    }
+
+    /// Adds a block joining the given positions, or returns `None` if none of them is reached.
+    fn join(&mut self, positions: &[Option<usize>]) -> Option<usize> {
+        if positions.iter().all(Option::is_none) {
+            return None;
+        }
+
+        let block: usize = self.block();
+        for position in positions.iter().flatten() {
+            self.edge(*position, block);
+        }
+        Some(block)
+    }
+
+    /// Adds a list of statements and returns the position after them.
+    fn statements(
+        &mut self,
+        statements: &'ast [Statement],
+        mut position: Option<usize>,
+    ) -> Option<usize> {
+        self.graph.lists.push(statements);
+        for statement in statements {
+            position = self.statement(statement, position);
+        }
+
+        position
+    }
+
+    /// Adds a statement and returns the position after it.
+    #[allow(clippy::indexing_slicing)]
+    fn statement(&mut self, node: &'ast Statement, position: Option<usize>) -> Option<usize> {
+        // Statements after the end of the control flow start a block that is never entered
+        let start: usize = position.unwrap_or_else(|| self.block());
+        self.graph.blocks[start].statements.push(node);
+        self.graph.starts.insert(address(node), start);
+
+        match *node {
+            Statement::Block(ref block) => self.statements(&block.statements, Some(start)),
+            Statement::Break(_) | Statement::Continue(_) => {
+                let Some(&(condition, after)) = self.loops.last() else {
+                    self.graph.stray.push(node);
+                    return Some(start);
+                };
+
+                let target: usize = if matches!(*node, Statement::Break(_)) {
+                    after
+                } else {
+                    condition
+                };
+                self.edge(start, target);
+                None
+            }
+            Statement::Return(_) | Statement::Throw(_) => {
+                let exit: usize = self.graph.exit;
+                self.edge(start, exit);
+                None
+            }
+            Statement::If(ref node) => {
+                let then_block: usize = self.branch(Some(start));
+                let then_end: Option<usize> =
+                    self.statements(&node.then_block.statements, Some(then_block));
+                let else_end: Option<usize> = match node.else_block {
+                    Some(ref else_block) => {
+                        let else_start: usize = self.branch(Some(start));
+                        self.statements(&else_block.statements, Some(else_start))
+                    }
+                    None => Some(start),
+                };
+
+                self.join(&[then_end, else_end])
+            }
+            Statement::Match(ref node) => self.match_statement(node, start),
+            Statement::Try(ref node) => self.try_statement(node, start),
+            Statement::While(ref node) => Some(self.while_statement(node, start)),
+            Statement::For(ref node) => Some(self.for_statement(node, start)),
+            Statement::Assert(_)
+            | Statement::Class(_)
+            | Statement::Expression(_)
+            | Statement::Function(_)
+            | Statement::Import(_)
+            | Statement::Use(_)
+            | Statement::VariableDeclaration(_)
+            | Statement::Yield(_) => Some(start),
+        }
+    }
+
+    /// Adds a `match` statement starting in a block and returns the position after it.
+    fn match_statement(&mut self, node: &'ast Match, start: usize) -> Option<usize> {
+        let mut ends: Vec<Option<usize>> = vec![];
+        for arm in &node.arms {
+            let arm_start: usize = self.branch(Some(start));
+            ends.push(match arm.body {
+                MatchBody::Block(ref block) => self.statements(&block.statements, Some(arm_start)),
+                MatchBody::Expression(_) => Some(arm_start),
+            });
+        }
+        if !patterns::is_exhaustive(node, None, self.classes) {
+            ends.push(Some(start));
+        }
+
+        self.join(&ends)
+    }
+
+    /// Adds a `try` statement starting in a block and returns the position after it.
+    fn try_statement(&mut self, node: &'ast Try, start: usize) -> Option<usize> {
+        let body: usize = self.branch(Some(start));
+        let mut ends: Vec<Option<usize>> = vec![self.statements(&node.body.statements, Some(body))];
+
+        // An exception may be thrown before any statement of the `try` block
+        for catch in &node.catches {
+            let catch_start: usize = self.branch(Some(start));
+            ends.push(self.statements(&catch.body.statements, Some(catch_start)));
+        }
+
+        let Some(ref finally) = node.finally else {
+            return self.join(&ends);
+        };
+
+        // The `finally` block also runs when the `try` is left with `return`, `throw`, `break` or `continue`
+        let mut entries: Vec<Option<usize>> = ends.clone();
+        entries.push(Some(start));
+        let finally_start: Option<usize> = self.join(&entries);
+        let finally_end: Option<usize> = self.statements(&finally.statements, finally_start);
+
+        if ends.iter().all(Option::is_none) {
+            if let Some(finally_end) = finally_end {
+                let exit: usize = self.graph.exit;
+                self.edge(finally_end, exit);
+            }
+            return None;
+        }
+        finally_end
+    }
+
+    /// Adds a `while` loop starting in a block and returns the position after it.
+    fn while_statement(&mut self, node: &'ast While, start: usize) -> usize {
+        let condition: usize = self.branch(Some(start));
+        let after: usize = self.block();
+        // A `while (true)` loop is only left with `break`
+        if !is_true(&node.condition) {
+            self.edge(condition, after);
+        }
+        self.graph.loops.push((node, condition));
+
+        let body: usize = self.branch(Some(condition));
+        self.loops.push((condition, after));
+        if let Some(end) = self.statements(&node.body.statements, Some(body)) {
+            self.edge(end, condition);
+        }
+        self.loops.pop();
+
+        after
+    }
+
+    /// Adds a `for` loop starting in a block and returns the position after it. The loop ends when its values run
+    /// out, so it is like a `while` loop whose condition may always be false.
+    fn for_statement(&mut self, node: &'ast For, start: usize) -> usize {
+        let header: usize = self.branch(Some(start));
+        let after: usize = self.block();
+        self.edge(header, after);
+
+        let body: usize = self.branch(Some(header));
+        self.loops.push((header, after));
+        if let Some(end) = self.statements(&node.body.statements, Some(body)) {
+            self.edge(end, header);
+        }
+        self.loops.pop();
+
+        after
+    }
+}
+
+/// Returns whether an expression is the literal `true`.
+fn is_true(expression: &Expression) -> bool {
+    matches!(
+        *expression,
+        Expression::Literal(ref literal) if literal.kind == TypeDefinition::True
+    )
+}
+
+
+////////////
+// CHECKS //
+////////////
+
+/// Checks the control flow of every function body and of the top-level code of a program: `break` and `continue`
+/// outside of loops, code that is never run, loops that never end in functions with a return type and functions
+/// that may finish without returning a value.
+///
+/// # Parameters
+///
+/// - `program`: The program to check.
+/// - `imports`: The names the program imports from other modules.
+///
+/// # Returns
+///
+/// The diagnostics found, in source order of the checked bodies.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::{flow, modules::Imports};
+/// let tokens = lexer::lex::lex("function f() -> int { return 1; print(2); }", "<stdin>").unwrap();
+/// let program = parser::parse::parse(&tokens).unwrap();
+/// let diagnostics = flow::check(&program, &Imports::default());
+///
+/// assert_eq!(diagnostics[0].code, "E0052");
+/// ```
+#[inline]
+pub fn check<'ast>(program: &'ast Program, imports: &Imports<'ast>) -> Vec<Diagnostic> {
+    let classes: ClassTable = ClassTable::new(program, imports);
+    let mut checker: FlowChecker = FlowChecker {
+        classes: &classes,
+        diagnostics: vec![],
+    };
+    checker.body(&program.statements, None);
+    checker.visit_program(program);
+
+    checker.diagnostics
+}
+
+
+//////////////////
+// FLOW CHECKER //
+//////////////////
+
+/// A visitor building the graph of every function body and reporting its problems.
+struct FlowChecker<'table, 'ast> {
+    /// The classes of the program.
+    classes: &'table ClassTable<'ast>,
+    /// The diagnostics found so far.
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl FlowChecker<'_, '_> {
+    /// Checks the body of a function or lambda, or the top-level code of a program.
+    #[allow(clippy::indexing_slicing)]
+    fn body(&mut self, statements: &[Statement], function: Option<Callable>) {
+        let graph: Graph = Graph::build(statements, self.classes);
+        let reached: Vec<bool> = graph.reachable(graph.entry);
+
+        for node in &graph.stray {
+            let keyword: &str = if matches!(**node, Statement::Continue(_)) {
+                "continue"
+            } else {
+                "break"
+            };
+            self.diagnostics.push(
+                Diagnostic::error(
+                    "E0051",
+                    &format!("`{keyword}` outside of a loop"),
+                    node.location().clone(),
+                )
+                .with_label("not inside a loop")
+                .with_note(
+                    &format!("`{keyword}` may only be used inside a loop of the same function"),
+                    None,
+                ),
+            );
+        }
+
+        self.unreachable(&graph, &reached);
+
+        let Some(function) = function else {
+            return;
+        };
+        // Generators return their values with `yield`, so they do not need to end or return
+        if matches!(function, Callable::Function(found) if generators::is_generator(found)) {
+            return;
+        }
+        let Some(expected) = function.return_type() else {
+            return;
+        };
+        if expected.name == "none" {
+            return;
+        }
+        let subject: String = match function {
+            Callable::Function(function) => format!("function `{}`", function.name.name),
+            Callable::Lambda(_) => "the lambda".to_owned(),
+        };
+
+        for &(node, condition) in &graph.loops {
+            if reached[condition]
+                && is_true(&node.condition)
+                && !graph.reachable(condition)[graph.exit]
+            {
+                self.diagnostics.push(
+                    Diagnostic::warning(
+                        "E0053",
+                        &format!("{subject} never returns"),
+                        node.location.clone(),
+                    )
+                    .with_label("this loop never ends")
+                    .with_note(
+                        &format!("the function promises to return `{}` here", expected.name),
+                        Some(expected.location.clone()),
+                    )
+                    .with_note(
+                        "leave the loop with `break` or `return` a value inside of it",
+                        None,
+                    ),
+                );
+            }
+        }
+
+        if reached[graph.end] {
+            self.diagnostics.push(
+                Diagnostic::error(
+                    "E0024",
+                    &format!("{subject} does not return a value on all paths"),
+                    function.location().clone(),
+                )
+                .with_label("may finish without returning a value")
+                .with_note(
+                    &format!("expected `{}` because of this return type", expected.name),
+                    Some(expected.location.clone()),
+                ),
+            );
+        }
+    }
+
+    /// Reports the first statement that is never run after a statement that is run, in every statement list.
+    #[allow(clippy::indexing_slicing, clippy::missing_asserts_for_indexing)]
+    fn unreachable(&mut self, graph: &Graph, reached: &[bool]) {
+        let is_reached = |statement: &Statement| {
+            graph
+                .block_of(statement)
+                .map_or(false, |block| reached[block])
+        };
+
+        for list in &graph.lists {
+            let Some(index) = list
+                .windows(2)
+                .position(|pair| is_reached(&pair[0]) && !is_reached(&pair[1]))
+            else {
+                continue;
+            };
+            let (previous, first): (&Statement, &Statement) = (&list[index], &list[index + 1]);
+
+            let reason: &str = match *previous {
+                Statement::Return(_) => "any code after this `return` is never run",
+                Statement::Throw(_) => "any code after this `throw` is never run",
+                Statement::Break(_) => "any code after this `break` is never run",
+                Statement::Continue(_) => "any code after this `continue` is never run",
+                Statement::While(_) => "this loop is only left with `return` or `throw`",
+                _ => "every path through this statement leaves it early",
+            };
+            self.diagnostics.push(
+                Diagnostic::warning("E0052", "unreachable code", first.location().clone())
+                    .with_label("this code is never run")
+                    .with_note(reason, Some(previous.location().clone())),
+            );
+        }
+    }
+}
+
+impl<'ast> Visitor<'ast> for FlowChecker<'_, 'ast> {
+    fn visit_function(&mut self, node: &'ast Function) {
+        if let Some(ref body) = node.body {
+            self.body(&body.statements, Some(Callable::Function(node)));
+        }
+        visit::walk_function(self, node);
+    }
+
+    fn visit_lambda(&mut self, node: &'ast Lambda) {
+        if let LambdaBody::Block(ref body) = node.body {
+            self.body(&body.statements, Some(Callable::Lambda(node)));
+        }
+        visit::walk_lambda(self, node);
+    }
+}
@@ -0,0 +1,271 @@
+//! The format specifications of the fields of format strings, e.g. `>8` in `f"{price:>8}"`.
+// I Language format specifications.
+// Version: 1.0.0
+
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use crate::integers;
+
+
+////////////////////
+// SPECIFICATIONS //
+////////////////////
+
+/// The side a formatted value is moved to when it is shorter than the width.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Alignment {
+    /// `<`, the default for values other than integers.
+    Left,
+    /// `>`, the default for integers.
+    Right,
+    /// `^`, with the extra fill character after the value.
+    Center,
+}
+
+impl Alignment {
+    /// Returns the alignment written as a character, e.g. `Left` for `<`.
+    const fn new(character: char) -> Option<Self> {
+        match character {
+            '<' => Some(Self::Left),
+            '>' => Some(Self::Right),
+            '^' => Some(Self::Center),
+            _ => None,
+        }
+    }
+}
+
+/// How a value is written before it is aligned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Presentation {
+    /// No type, the value as `str` converts it.
+    Text,
+    /// `?`, the value as written in source code, e.g. strings in quotes.
+    Debug,
+    /// `b`, an integer in base 2.
+    Binary,
+    /// `o`, an integer in base 8.
+    Octal,
+    /// `x`, an integer in base 16 with lowercase letters.
+    Hex,
+    /// `X`, an integer in base 16 with uppercase letters.
+    UpperHex,
+}
+
+impl Presentation {
+    /// Returns the base integers are written in, `None` for the presentations of any value.
+    #[inline]
+    pub const fn radix(self) -> Option<u32> {
+        match self {
+            Self::Text | Self::Debug => None,
+            Self::Binary => Some(2),
+            Self::Octal => Some(8),
+            Self::Hex | Self::UpperHex => Some(16),
+        }
+    }
+}
+
+/// A parsed format specification, written `[[fill]align][0][width][.precision][type]` after the colon of a field,
+/// e.g. `*^9`, `08`, `.3` or `x`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Specification {
+    /// The character filling the width, a space by default.
+    pub fill: Option<char>,
+    /// The side the value is moved to, see [`Alignment`].
+    pub alignment: Option<Alignment>,
+    /// Whether integers are filled with zeros after their sign.
+    pub zero: bool,
+    /// The minimum number of characters.
+    pub width: usize,
+    /// The maximum number of characters of a value that is not an integer.
+    pub precision: Option<usize>,
+    /// How the value is written.
+    pub presentation: Presentation,
+}
+
+impl Specification {
+    /// Parses a format specification.
+    ///
+    /// # Errors
+    ///
+    /// Returns the reason if the specification is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use compiler::formats::{Alignment, Specification};
+    /// let specification = Specification::parse("*^9").unwrap();
+    ///
+    /// assert_eq!((specification.fill, specification.alignment, specification.width), (Some('*'), Some(Alignment::Center), 9));
+    /// assert!(Specification::parse("9z").is_err());
+    /// ```
+    #[inline]
+    #[allow(clippy::else_if_without_else, clippy::indexing_slicing)]
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let characters: Vec<char> = text.chars().collect();
+        let mut specification: Self = Self {
+            fill: None,
+            alignment: None,
+            zero: false,
+            width: 0,
+            precision: None,
+            presentation: Presentation::Text,
+        };
+
+        let mut index: usize = 0;
+        if let Some(alignment) = characters.get(1).copied().and_then(Alignment::new) {
+            specification.fill = characters.first().copied();
+            specification.alignment = Some(alignment);
+            index = 2;
+        } else if let Some(alignment) = characters.first().copied().and_then(Alignment::new) {
+            specification.alignment = Some(alignment);
+            index = 1;
+        }
+        if characters.get(index) == Some(&'0') {
+            specification.zero = true;
+            index += 1;
+        }
+
+        let number = |position: &mut usize, name: &str| -> Result<Option<usize>, String> {
+            let digits: String = characters[*position..]
+                .iter()
+                .take_while(|character| character.is_ascii_digit())
+                .collect();
+            *position += digits.len();
+            if digits.is_empty() {
+                return Ok(None);
+            }
+            digits
+                .parse()
+                .ok()
+                .map(Some)
+                .ok_or_else(|| format!("the {name} `{digits}` is too large"))
+        };
+        specification.width = number(&mut index, "width")?.unwrap_or_default();
+        if characters.get(index) == Some(&'.') {
+            index += 1;
+            specification.precision = Some(
+                number(&mut index, "precision")?
+                    .ok_or_else(|| "`.` must be followed by a precision".to_owned())?,
+            );
+        }
+
+        specification.presentation = match characters.get(index) {
+            Some(&'?') => Presentation::Debug,
+            Some(&'b') => Presentation::Binary,
+            Some(&'o') => Presentation::Octal,
+            Some(&'x') => Presentation::Hex,
+            Some(&'X') => Presentation::UpperHex,
+            _ => Presentation::Text,
+        };
+        if specification.presentation != Presentation::Text {
+            index += 1;
+        }
+        match characters.get(index..) {
+            Some(rest) if !rest.is_empty() => Err(format!(
+                "unexpected `{}`, expected `[[fill]align][0][width][.precision][type]` with a type of `?`, `b`, `o`, \
+                 `x` or `X`",
+                rest.iter().collect::<String>()
+            )),
+            _ => Ok(specification),
+        }
+    }
+
+    /// Returns why the specification cannot format values of a type, `None` if it can. Unknown types are accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use compiler::formats::Specification;
+    /// assert!(Specification::parse("08x").unwrap().reject("u8").is_none());
+    /// assert!(Specification::parse("x").unwrap().reject("str").is_some());
+    /// ```
+    #[inline]
+    pub fn reject(&self, type_name: &str) -> Option<&'static str> {
+        if integers::is_integer(type_name) {
+            self.precision
+                .map(|_| "a precision limits the length of values that are not integers")
+        } else if self.presentation.radix().is_some() {
+            Some("`b`, `o`, `x` and `X` write integers in another base")
+        } else if self.zero {
+            Some("`0` fills integers with zeros after their sign")
+        } else {
+            None
+        }
+    }
+
+    /// Applies the precision, the width and the alignment to a written value.
+    ///
+    /// # Parameters
+    ///
+    /// - `text`: The value, already written in its presentation.
+    /// - `integer`: Whether the value is an integer, which is aligned to the right by default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use compiler::formats::Specification;
+    /// assert_eq!(Specification::parse("05").unwrap().align("-42", true), "-0042");
+    /// assert_eq!(Specification::parse("-^7.3").unwrap().align("éclair", false), "--écl--");
+    /// ```
+    #[inline]
+    #[allow(clippy::integer_division, clippy::integer_division_remainder_used)]
+    pub fn align(&self, text: &str, integer: bool) -> String {
+        let text: String = match self.precision {
+            Some(precision) => text.chars().take(precision).collect(),
+            None => text.to_owned(),
+        };
+        let padding: usize = self.width.saturating_sub(text.chars().count());
+        if padding == 0 {
+            return text;
+        }
+
+        if self.zero && self.alignment.is_none() {
+            let (sign, digits): (&str, &str) = text
+                .strip_prefix('-')
+                .map_or(("", text.as_str()), |digits| ("-", digits));
+            return format!("{sign}{}{digits}", "0".repeat(padding));
+        }
+        let fill: String = self
+            .fill
+            .unwrap_or(if self.zero { '0' } else { ' ' })
+            .to_string();
+        let default: Alignment = if integer {
+            Alignment::Right
+        } else {
+            Alignment::Left
+        };
+        let before: usize = match self.alignment.unwrap_or(default) {
+            Alignment::Left => 0,
+            Alignment::Right => padding,
+            Alignment::Center => padding / 2,
+        };
+        format!(
+            "{}{text}{}",
+            fill.repeat(before),
+            fill.repeat(padding - before)
+        )
+    }
+}
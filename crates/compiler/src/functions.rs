@@ -0,0 +1,524 @@
+//! Semantic checks for functions: parameters, arguments at call sites and returned values. Missing returns are
+//! found on the control-flow graph, see [`crate::flow`].
+// I Language function checks.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use std::collections::HashMap;
+
+use crate::classes::ClassTable;
+use crate::diagnostic::Diagnostic;
+use crate::environment::{Binding, Environment};
+use crate::generators;
+use crate::generics::{self, TypeNames};
+use crate::integers;
+use crate::modules::Imports;
+use crate::prelude::BUILT_IN_TYPES;
+
+use lexer::tokens::token::Location;
+use parser::ast::{
+    Block, Call, Catch, Expression, For, Function, Lambda, Match, Parameter, Program, Return,
+    Statement, TypeAnnotation, VariableDeclaration,
+};
+use parser::visit::{self, Visitor};
+
+
+/////////////
+// HELPERS //
+/////////////
+
+
+/// Returns the name of the return type of a function, `none` if it has no return type.
+#[inline]
+pub fn return_type(function: &Function) -> &str {
+    function
+        .return_type
+        .as_ref()
+        .map_or("none", |return_type| &return_type.name)
+}
+
+/// A function or lambda, the code a `return` statement leaves.
+#[derive(Clone, Copy, Debug)]
+pub enum Callable<'ast> {
+    /// A function, method or constructor.
+    Function(&'ast Function),
+    /// A lambda.
+    Lambda(&'ast Lambda),
+}
+
+impl<'ast> Callable<'ast> {
+    /// Returns how the function is referred to in messages, e.g. "`jump`" or "the lambda".
+    #[inline]
+    pub fn describe(&self) -> String {
+        match *self {
+            Self::Function(function) => format!("`{}`", function.name.name),
+            Self::Lambda(_) => "the lambda".to_owned(),
+        }
+    }
+
+    /// Returns the declared return type, if any. Lambdas with an expression have none.
+    #[inline]
+    pub const fn return_type(&self) -> Option<&'ast TypeAnnotation> {
+        match *self {
+            Self::Function(function) => function.return_type.as_ref(),
+            Self::Lambda(lambda) => lambda.return_type.as_ref(),
+        }
+    }
+
+    /// Returns the location messages about the function point at: the name of a function or the start of a lambda.
+    #[inline]
+    pub const fn location(&self) -> &'ast Location {
+        match *self {
+            Self::Function(function) => &function.name.location,
+            Self::Lambda(lambda) => &lambda.location,
+        }
+    }
+}
+
+/// Returns whether a value of type `found` can be used where a value of the annotated type is expected.
+///
+/// Class types are not compared with each other yet, since that needs the class hierarchy.
+/// `none` may only be used for nullable types, e.g. `Player?`.
+fn is_compatible(expected: &TypeAnnotation, found: &str) -> bool {
+    expected.name == found
+        || (found == "none" && expected.nullable)
+        || (!BUILT_IN_TYPES.contains(&expected.name.as_str()) && !BUILT_IN_TYPES.contains(&found))
+}
+
+
+///////////////
+// ARGUMENTS //
+///////////////
+
+/// Checks the arguments of a call against the parameters of the called function.
+///
+/// Positional arguments are matched in order, keyword arguments by the name of the parameter.
+/// Parameters with a default value may be left out.
+///
+/// # Parameters
+///
+/// - `name`: The name of the called function, used in the messages.
+/// - `parameters`: The parameters of the called function.
+/// - `declaration`: The location of the name of the called function.
+/// - `call`: The checked call.
+///
+/// # Returns
+///
+/// The diagnostics found, empty if the arguments match the parameters.
+#[inline]
+#[allow(clippy::indexing_slicing)]
+pub fn check_arguments(
+    name: &str,
+    parameters: &[Parameter],
+    declaration: &Location,
+    call: &Call,
+) -> Vec<Diagnostic> {
+    let mut diagnostics: Vec<Diagnostic> = vec![];
+    let mut assigned: Vec<Option<&Location>> = vec![None; parameters.len()];
+    let mut position: usize = 0;
+    let mut keyword: Option<&Location> = None;
+
+    for argument in &call.arguments {
+        let Some(ref argument_name) = argument.name else {
+            if let Some(keyword) = keyword {
+                diagnostics.push(
+                    Diagnostic::error(
+                        "E0020",
+                        "positional argument after keyword argument",
+                        argument.location.clone(),
+                    )
+                    .with_note("first keyword argument given here", Some(keyword.clone())),
+                );
+                continue;
+            }
+
+            match assigned.get_mut(position) {
+                Some(slot) => *slot = Some(&argument.location),
+                None => diagnostics.push(
+                    Diagnostic::error(
+                        "E0016",
+                        &format!(
+                            "`{name}` takes {} argument{} but more were given",
+                            parameters.len(),
+                            if parameters.len() == 1 { "" } else { "s" }
+                        ),
+                        argument.location.clone(),
+                    )
+                    .with_label("unexpected argument")
+                    .with_note(
+                        &format!("`{name}` is declared here"),
+                        Some(declaration.clone()),
+                    ),
+                ),
+            }
+            position += 1;
+            continue;
+        };
+
+        keyword.get_or_insert(&argument_name.location);
+        let Some(index) = parameters
+            .iter()
+            .position(|parameter| parameter.name.name == argument_name.name)
+        else {
+            diagnostics.push(
+                Diagnostic::error(
+                    "E0017",
+                    &format!("`{name}` has no parameter named `{}`", argument_name.name),
+                    argument_name.location.clone(),
+                )
+                .with_label("unknown keyword argument")
+                .with_note(
+                    &format!("`{name}` is declared here"),
+                    Some(declaration.clone()),
+                ),
+            );
+            continue;
+        };
+
+        if let Some(first) = assigned[index].replace(&argument_name.location) {
+            diagnostics.push(
+                Diagnostic::error(
+                    "E0018",
+                    &format!(
+                        "argument for parameter `{}` given more than once",
+                        argument_name.name
+                    ),
+                    argument_name.location.clone(),
+                )
+                .with_note("first given here", Some(first.clone())),
+            );
+        }
+    }
+
+    for (parameter, slot) in parameters.iter().zip(&assigned) {
+        if slot.is_none() && parameter.default.is_none() {
+            diagnostics.push(
+                Diagnostic::error(
+                    "E0019",
+                    &format!(
+                        "missing argument for parameter `{}` of `{name}`",
+                        parameter.name.name
+                    ),
+                    call.location.clone(),
+                )
+                .with_label(&format!("missing `{}`", parameter.name.name))
+                .with_note(
+                    "parameter declared here",
+                    Some(parameter.name.location.clone()),
+                ),
+            );
+        }
+    }
+
+    diagnostics
+}
+
+
+////////////
+// CHECKS //
+////////////
+
+/// Checks all function declarations of a program, the calls of functions and the returned values.
+///
+/// Method and constructor calls are checked by [`crate::classes::check`].
+///
+/// # Parameters
+///
+/// - `program`: The program to check.
+/// - `imports`: The names the program imports from other modules.
+///
+/// # Returns
+///
+/// The diagnostics found, in source order of the checked nodes.
+#[inline]
+pub fn check<'ast>(program: &'ast Program, imports: &Imports<'ast>) -> Vec<Diagnostic> {
+    let names: TypeNames = TypeNames::default();
+    let classes: ClassTable = ClassTable::new(program, imports);
+    let mut checker: FunctionChecker = FunctionChecker {
+        names: &names,
+        classes: &classes,
+        environment: Environment::default(),
+        functions: vec![],
+        diagnostics: vec![],
+    };
+    checker.environment.enter(&program.statements);
+    checker.environment.import(imports);
+    checker.visit_program(program);
+
+    checker.diagnostics
+}
+
+
+//////////////////////
+// FUNCTION CHECKER //
+//////////////////////
+
+/// A visitor checking function declarations, calls and return statements.
+struct FunctionChecker<'table, 'ast> {
+    /// The type names created while checking.
+    names: &'ast TypeNames,
+    /// The classes of the program.
+    classes: &'table ClassTable<'ast>,
+    /// The names visible at the visited node.
+    environment: Environment<'ast>,
+    /// The functions and lambdas whose bodies are currently visited, innermost last.
+    functions: Vec<Callable<'ast>>,
+    /// The diagnostics found so far.
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl FunctionChecker<'_, '_> {
+    /// Checks the parameters of a function declaration or lambda.
+    fn check_parameters(&mut self, node: Callable, parameters: &[Parameter]) {
+        let mut seen: HashMap<&str, &Location> = HashMap::new();
+        let mut default: Option<&Parameter> = None;
+
+        for parameter in parameters {
+            if let Some(first) = seen.insert(&parameter.name.name, &parameter.name.location) {
+                self.diagnostics.push(
+                    Diagnostic::error(
+                        "E0014",
+                        &format!(
+                            "duplicate parameter `{}` in {}",
+                            parameter.name.name,
+                            node.describe()
+                        ),
+                        parameter.name.location.clone(),
+                    )
+                    .with_note("first declared here", Some(first.clone())),
+                );
+            }
+
+            match parameter.default {
+                Some(ref value) => {
+                    default = Some(parameter);
+                    self.check_type(&parameter.type_annotation, value);
+                }
+                None => {
+                    if let Some(default) = default {
+                        self.diagnostics.push(
+                            Diagnostic::error(
+                                "E0015",
+                                &format!(
+                                    "parameter `{}` without a default value follows a parameter with one",
+                                    parameter.name.name
+                                ),
+                                parameter.name.location.clone(),
+                            )
+                            .with_note(
+                                "parameter with a default value declared here",
+                                Some(default.name.location.clone()),
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reports a value whose type does not match the annotated type.
+    fn check_type(&mut self, annotation: &TypeAnnotation, value: &Expression) {
+        if let Some(diagnostics) = integers::check_literal(&annotation.name, value) {
+            self.diagnostics.extend(diagnostics);
+            return;
+        }
+        let Some(found) = self.environment.type_of(value) else {
+            return;
+        };
+
+        if !is_compatible(annotation, found) {
+            let expected: String = if annotation.nullable {
+                generics::join("?", &[&annotation.name])
+            } else {
+                annotation.name.clone()
+            };
+            self.diagnostics.push(
+                Diagnostic::error("E0023", "mismatched types", value.location().clone())
+                    .with_label(&format!("expected `{expected}`, found `{found}`"))
+                    .with_note(
+                        &format!("expected `{expected}` because of this"),
+                        Some(annotation.location.clone()),
+                    ),
+            );
+        }
+    }
+}
+
+impl<'ast> Visitor<'ast> for FunctionChecker<'_, 'ast> {
+    fn visit_block(&mut self, node: &'ast Block) {
+        self.environment.enter(&node.statements);
+        visit::walk_block(self, node);
+        self.environment.exit();
+    }
+
+    fn visit_statement(&mut self, node: &'ast Statement) {
+        if let Statement::Function(ref function) = *node {
+            if function.body.is_none() {
+                self.diagnostics.push(
+                    Diagnostic::error(
+                        "E0025",
+                        &format!("function `{}` has no body", function.name.name),
+                        function.name.location.clone(),
+                    )
+                    .with_label("expected a body")
+                    .with_note("only methods of classes can be abstract", None),
+                );
+            }
+        }
+
+        visit::walk_statement(self, node);
+    }
+
+    fn visit_function(&mut self, node: &'ast Function) {
+        self.check_parameters(Callable::Function(node), &node.parameters);
+
+        self.environment.enter(&[]);
+        for parameter in &node.parameters {
+            self.environment
+                .declare(&parameter.name.name, Some(&parameter.type_annotation.name));
+        }
+        self.functions.push(Callable::Function(node));
+        visit::walk_function(self, node);
+        self.functions.pop();
+        self.environment.exit();
+    }
+
+    fn visit_lambda(&mut self, node: &'ast Lambda) {
+        self.check_parameters(Callable::Lambda(node), &node.parameters);
+
+        self.environment.enter(&[]);
+        for parameter in &node.parameters {
+            self.environment
+                .declare(&parameter.name.name, Some(&parameter.type_annotation.name));
+        }
+        self.functions.push(Callable::Lambda(node));
+        visit::walk_lambda(self, node);
+        self.functions.pop();
+        self.environment.exit();
+    }
+
+    fn visit_return(&mut self, node: &'ast Return) {
+        visit::walk_return(self, node);
+
+        let Some(function) = self.functions.last().copied() else {
+            return;
+        };
+        // The returns of generators are checked by `generators::check`
+        if matches!(function, Callable::Function(found) if generators::is_generator(found)) {
+            return;
+        }
+        let expected: &str = function
+            .return_type()
+            .map_or("none", |return_type| &return_type.name);
+
+        match (node.value.as_ref(), function.return_type()) {
+            (Some(value), _) if expected == "none" => self.diagnostics.push(
+                Diagnostic::error(
+                    "E0021",
+                    &format!(
+                        "{} does not return a value, but a value is returned",
+                        function.describe()
+                    ),
+                    value.location().clone(),
+                )
+                .with_label("unexpected return value")
+                .with_note(
+                    "add a return type to the function to return a value",
+                    Some(function.location().clone()),
+                ),
+            ),
+            (Some(value), Some(annotation)) => {
+                self.check_type(annotation, value);
+            }
+            (None, Some(annotation)) if expected != "none" => self.diagnostics.push(
+                Diagnostic::error(
+                    "E0022",
+                    &format!("{} must return a value", function.describe()),
+                    node.location.clone(),
+                )
+                .with_label(&format!("expected a value of type `{expected}`"))
+                .with_note(
+                    "return type declared here",
+                    Some(annotation.location.clone()),
+                ),
+            ),
+            _ => {}
+        }
+    }
+
+    fn visit_catch(&mut self, node: &'ast Catch) {
+        self.environment.enter(&[]);
+        if let Some(ref binding) = node.binding {
+            self.environment
+                .declare(&binding.name, Some(&node.exception.name));
+        }
+        visit::walk_catch(self, node);
+        self.environment.exit();
+    }
+
+    fn visit_for(&mut self, node: &'ast For) {
+        self.visit_for_iterable(&node.iterable);
+        self.environment.enter(&[]);
+        self.environment.declare_loop_variable(node);
+        self.visit_block(&node.body);
+        self.environment.exit();
+    }
+
+    fn visit_variable_declaration(&mut self, node: &'ast VariableDeclaration) {
+        visit::walk_variable_declaration(self, node);
+        self.environment.declare_variable(node);
+    }
+
+    fn visit_match(&mut self, node: &'ast Match) {
+        self.visit_expression(&node.subject);
+
+        let subject: Option<&str> = self.environment.type_of(&node.subject);
+        for arm in &node.arms {
+            self.environment.enter(&[]);
+            if let Some(ref pattern) = arm.pattern {
+                self.environment
+                    .bind(pattern, subject, self.classes, self.names);
+            }
+            self.visit_match_arm(arm);
+            self.environment.exit();
+        }
+    }
+
+    fn visit_call(&mut self, node: &'ast Call) {
+        if let Expression::Identifier(ref identifier) = *node.callee {
+            if let Some(Binding::Function(function)) = self.environment.lookup(&identifier.name) {
+                self.diagnostics.extend(check_arguments(
+                    &function.name.name,
+                    &function.parameters,
+                    &function.name.location,
+                    node,
+                ));
+            }
+        }
+
+        visit::walk_call(self, node);
+    }
+}
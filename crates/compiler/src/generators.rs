@@ -0,0 +1,598 @@
+//! Generators: functions with `yield` statements, the checks of their bodies and the state machines running them.
+// I Language generators.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use crate::diagnostic::Diagnostic;
+use crate::modules::Imports;
+use crate::prelude;
+
+use lexer::tokens::token::Location;
+use parser::ast::{
+    Block, Class, ClassMember, Expression, For, Function, Lambda, Match, MatchArm, MatchBody,
+    Program, Return, Statement, Try, TypeAnnotation, Yield,
+};
+use parser::visit::{self, Visitor};
+
+
+////////////////
+// GENERATORS //
+////////////////
+
+/// Returns whether a function is a generator: a function with a `yield` statement in its body, outside of the
+/// functions, lambdas and classes declared in it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::generators;
+/// let tokens = lexer::lex::lex("function count() -> Iterator<int> { yield 1; }", "<stdin>").unwrap();
+/// let program = parser::parse::parse(&tokens).unwrap();
+/// let parser::ast::Statement::Function(ref function) = program.statements[0] else { unreachable!() };
+///
+/// assert!(generators::is_generator(function));
+/// ```
+#[inline]
+pub fn is_generator(function: &Function) -> bool {
+    function
+        .body
+        .as_ref()
+        .map_or(false, |body| body.statements.iter().any(yields))
+}
+
+/// Returns the type of the values a generator produces: the type argument of its `Iterator<T>` return type.
+/// Returns `None` if the function does not declare such a return type.
+#[inline]
+pub fn element_type(function: &Function) -> Option<&TypeAnnotation> {
+    let annotation: &TypeAnnotation = function.return_type.as_ref()?;
+    match *annotation.arguments.as_slice() {
+        [ref element] if annotation.name == prelude::ITERATOR && !annotation.nullable => {
+            Some(element)
+        }
+        _ => None,
+    }
+}
+
+/// Returns whether a statement contains a `yield` statement of the function it is in.
+fn yields(statement: &Statement) -> bool {
+    let mut finder: YieldFinder = YieldFinder { found: false };
+    finder.visit_statement(statement);
+    finder.found
+}
+
+/// A visitor looking for `yield` statements, skipping nested functions, lambdas and classes.
+struct YieldFinder {
+    /// Whether a `yield` statement was found.
+    found: bool,
+}
+
+impl<'ast> Visitor<'ast> for YieldFinder {
+    fn visit_yield(&mut self, _node: &'ast Yield) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _node: &'ast Function) {}
+
+    fn visit_lambda(&mut self, _node: &'ast Lambda) {}
+
+    fn visit_class(&mut self, _node: &'ast Class) {}
+}
+
+
+///////////////////
+// STATE MACHINE //
+///////////////////
+
+/// The body of a generator, transformed into states that can be suspended after every `yield`.
+///
+/// A generator runs its states in order, starting with the first, until a `yield` produces a value. The next value
+/// is produced by continuing with the state after that `yield`, with the variables and the loops in progress
+/// restored. Statements without `yield` are single states, so only the `if`, `while`, `for` and `match` statements
+/// and the blocks around a `yield` are split into several states.
+#[derive(Clone, Debug, Default)]
+pub struct StateMachine<'ast> {
+    /// The states, in the order they are run without jumps.
+    pub states: Vec<State<'ast>>,
+}
+
+/// A state of a [`StateMachine`].
+#[derive(Clone, Debug)]
+pub enum State<'ast> {
+    /// Runs a statement without `yield`. A `break` or `continue` leaving the statement jumps to the targets of the
+    /// innermost loop around it, which are `None` outside of loops. A `return` ends the generator.
+    Run {
+        /// The statement.
+        statement: &'ast Statement,
+        /// The targets of `break` and `continue`, in this order.
+        exits: Option<(Target, Target)>,
+    },
+    /// Produces a value and suspends the generator.
+    Yield(&'ast Yield),
+    /// Opens a scope for the variables and functions declared in a block.
+    Enter(&'ast Block),
+    /// Closes the innermost scope.
+    Exit,
+    /// Continues with another state.
+    Jump(Target),
+    /// Evaluates a condition, continuing with the next state if it is true and jumping otherwise.
+    Branch {
+        /// The condition.
+        condition: &'ast Expression,
+        /// The state run if the condition is false.
+        otherwise: Target,
+    },
+    /// Evaluates the matched value of a `match` and keeps it for the cases in a new scope.
+    Subject(&'ast Match),
+    /// Matches the value of the innermost `match` against a case. If the pattern and the guard match, the names
+    /// bound by the pattern are declared in a new scope, otherwise it jumps.
+    Case {
+        /// The case.
+        arm: &'ast MatchArm,
+        /// The state run if the case does not match.
+        otherwise: Target,
+    },
+    /// Evaluates the expression of a case without a block.
+    Evaluate(&'ast Expression),
+    /// Evaluates the iterated values of a `for` loop and starts iterating over them.
+    Iterate(&'ast For),
+    /// Takes the next value of the innermost iteration and binds the loop variable to it in a new scope, or jumps
+    /// if there are no more values.
+    Next {
+        /// The loop.
+        node: &'ast For,
+        /// The state run after the last value.
+        done: Target,
+    },
+}
+
+/// A state a generator jumps to, with the number of scopes and iterations open there. Jumping closes the scopes
+/// and ends the iterations opened after it, e.g. when leaving a loop with `break`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Target {
+    /// The index of the state.
+    pub state: usize,
+    /// The number of scopes open at the state, besides the scope of the parameters.
+    pub scopes: usize,
+    /// The number of `for` loops iterating at the state.
+    pub iterations: usize,
+}
+
+/// Transforms the body of a generator into a state machine.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::generators::{self, State};
+/// let tokens = lexer::lex::lex(
+///     "function count() -> Iterator<int> { int i = 0; while (true) { yield i; i += 1; } }",
+///     "<stdin>",
+/// )
+/// .unwrap();
+/// let program = parser::parse::parse(&tokens).unwrap();
+/// let parser::ast::Statement::Function(ref function) = program.statements[0] else { unreachable!() };
+/// let machine = generators::lower(function);
+///
+/// assert!(matches!(machine.states[0], State::Run { .. }));
+/// assert!(matches!(machine.states[1], State::Branch { .. }));
+/// assert!(matches!(machine.states[3], State::Yield(_)));
+/// ```
+#[inline]
+pub fn lower(function: &Function) -> StateMachine<'_> {
+    let mut lowering: Lowering = Lowering::default();
+    if let Some(ref body) = function.body {
+        lowering.statements(&body.statements);
+    }
+
+    lowering.machine
+}
+
+/// The loop a `break` or `continue` in a generator leaves or continues.
+#[derive(Debug, Default)]
+struct Loop {
+    /// The target of `continue`.
+    next: Target,
+    /// The target of `break`, whose state is known after the loop.
+    after: Target,
+    /// The states jumping to `after`, updated when the state after the loop is known.
+    pending: Vec<usize>,
+}
+
+/// Builds a [`StateMachine`] statement by statement.
+#[derive(Debug, Default)]
+struct Lowering<'ast> {
+    /// The states built so far.
+    machine: StateMachine<'ast>,
+    /// The number of scopes open at the end of the states.
+    scopes: usize,
+    /// The number of iterations open at the end of the states.
+    iterations: usize,
+    /// The enclosing loops, innermost last.
+    loops: Vec<Loop>,
+}
+
+impl<'ast> Lowering<'ast> {
+    /// Adds a state and returns its index.
+    fn push(&mut self, state: State<'ast>) -> usize {
+        self.machine.states.push(state);
+        self.machine.states.len() - 1
+    }
+
+    /// Returns a target at the end of the states built so far.
+    fn here(&self) -> Target {
+        Target {
+            state: self.machine.states.len(),
+            scopes: self.scopes,
+            iterations: self.iterations,
+        }
+    }
+
+    /// Sets the state a jump, branch or exhausted iteration continues with.
+    fn patch(&mut self, index: usize, state: usize) {
+        if let Some(
+            &mut (State::Jump(ref mut target)
+            | State::Branch {
+                otherwise: ref mut target,
+                ..
+            }
+            | State::Case {
+                otherwise: ref mut target,
+                ..
+            }
+            | State::Next {
+                done: ref mut target,
+                ..
+            }
+            | State::Run {
+                exits: Some((ref mut target, _)),
+                ..
+            }),
+        ) = self.machine.states.get_mut(index)
+        {
+            target.state = state;
+        }
+    }
+
+    /// Adds the states of a list of statements.
+    fn statements(&mut self, statements: &'ast [Statement]) {
+        for statement in statements {
+            self.statement(statement);
+        }
+    }
+
+    /// Adds the statements of a block in a scope of their own.
+    fn block(&mut self, block: &'ast Block) {
+        self.push(State::Enter(block));
+        self.scopes += 1;
+        self.statements(&block.statements);
+        self.scopes -= 1;
+        self.push(State::Exit);
+    }
+
+    /// Adds the states of a statement.
+    fn statement(&mut self, statement: &'ast Statement) {
+        if !yields(statement) {
+            let exits: Option<(Target, Target)> =
+                self.loops.last().map(|found| (found.after, found.next));
+            let index: usize = self.push(State::Run { statement, exits });
+            if let Some(found) = self.loops.last_mut() {
+                found.pending.push(index);
+            }
+            return;
+        }
+
+        match *statement {
+            Statement::Yield(ref node) => {
+                self.push(State::Yield(node));
+            }
+            Statement::Block(ref block) => self.block(block),
+            Statement::If(ref node) => {
+                let branch: usize = self.push(State::Branch {
+                    condition: &node.condition,
+                    otherwise: self.here(),
+                });
+                self.block(&node.then_block);
+                match node.else_block {
+                    Some(ref else_block) => {
+                        let jump: usize = self.push(State::Jump(self.here()));
+                        self.patch(branch, self.here().state);
+                        self.block(else_block);
+                        self.patch(jump, self.here().state);
+                    }
+                    None => self.patch(branch, self.here().state),
+                }
+            }
+            Statement::While(ref node) => {
+                let condition: Target = self.here();
+                let branch: usize = self.push(State::Branch {
+                    condition: &node.condition,
+                    otherwise: condition,
+                });
+                self.body(&node.body, condition, condition, branch);
+            }
+            Statement::For(ref node) => {
+                self.push(State::Iterate(node));
+                let after: Target = self.here();
+                self.iterations += 1;
+                let next: Target = self.here();
+                let index: usize = self.push(State::Next { node, done: after });
+                self.scopes += 1;
+                self.body(&node.body, next, after, index);
+                self.scopes -= 1;
+                self.iterations -= 1;
+            }
+            Statement::Match(ref node) => {
+                self.push(State::Subject(node));
+                self.scopes += 1;
+                let mut ends: Vec<usize> = vec![];
+                for arm in &node.arms {
+                    let case: usize = self.push(State::Case {
+                        arm,
+                        otherwise: self.here(),
+                    });
+                    self.scopes += 1;
+                    match arm.body {
+                        MatchBody::Block(ref block) => self.block(block),
+                        MatchBody::Expression(ref value) => {
+                            self.push(State::Evaluate(value));
+                        }
+                    }
+                    self.scopes -= 1;
+                    self.push(State::Exit);
+                    ends.push(self.push(State::Jump(self.here())));
+                    self.patch(case, self.here().state);
+                }
+                for end in ends {
+                    self.patch(end, self.here().state);
+                }
+                self.scopes -= 1;
+                self.push(State::Exit);
+            }
+            // `yield` is not allowed in the other statements, see `check`
+            _ => {
+                self.push(State::Run {
+                    statement,
+                    exits: None,
+                });
+            }
+        }
+    }
+
+    /// Adds the body of a loop, which jumps back to `next` at its end.
+    ///
+    /// # Parameters
+    ///
+    /// - `body`: The body of the loop.
+    /// - `next`: The target of `continue` and of the end of the body.
+    /// - `after`: The target of `break`, whose state is set after the loop.
+    /// - `exit`: The state leaving the loop when it ends, jumping to `after`.
+    fn body(&mut self, body: &'ast Block, next: Target, after: Target, exit: usize) {
+        self.loops.push(Loop {
+            next,
+            after,
+            pending: vec![exit],
+        });
+        self.block(body);
+        self.push(State::Jump(next));
+
+        let end: usize = self.here().state;
+        for index in self
+            .loops
+            .pop()
+            .map(|found| found.pending)
+            .unwrap_or_default()
+        {
+            self.patch(index, end);
+        }
+    }
+}
+
+
+/////////////
+// CHECKER //
+/////////////
+
+/// Checks the generators of a program and the `yield` statements outside of generators.
+///
+/// `yield` can only be used in functions and methods, which return `Iterator<T>` and no value then. It cannot be
+/// used inside `try` statements: the `catch` clauses would have to apply to the code the caller runs while the
+/// generator is suspended, and the `finally` block would never run if the generator is not resumed.
+///
+/// # Parameters
+///
+/// - `program`: The program to check.
+/// - `imports`: The names the program imports from other modules.
+///
+/// # Returns
+///
+/// The diagnostics found, in source order.
+#[inline]
+pub fn check<'ast>(program: &'ast Program, _imports: &Imports<'ast>) -> Vec<Diagnostic> {
+    let mut checker: GeneratorChecker = GeneratorChecker {
+        callables: vec![],
+        diagnostics: vec![],
+    };
+    checker.visit_program(program);
+
+    checker.diagnostics
+}
+
+/// The code a `yield` statement is in.
+#[derive(Debug)]
+struct Callable<'ast> {
+    /// The function, `None` for lambdas and constructors, which cannot be generators.
+    function: Option<&'ast Function>,
+    /// The locations of the `try` statements around the visited node, innermost last.
+    tries: Vec<&'ast Location>,
+}
+
+/// A visitor checking generators and `yield` statements.
+struct GeneratorChecker<'ast> {
+    /// The functions, lambdas and constructors around the visited node, innermost last.
+    callables: Vec<Callable<'ast>>,
+    /// The diagnostics found so far.
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'ast> GeneratorChecker<'ast> {
+    /// Checks the body of a function, lambda or constructor, which is a generator if `function` is `Some`.
+    fn callable(&mut self, function: Option<&'ast Function>, walk: impl FnOnce(&mut Self)) {
+        self.callables.push(Callable {
+            function,
+            tries: vec![],
+        });
+        walk(self);
+        self.callables.pop();
+    }
+
+
+    /// Reports a generator without an `Iterator<T>` return type.
+    fn return_type(&mut self, node: &Function) {
+        if element_type(node).is_some() {
+            return;
+        }
+
+        let (message, location): (String, &Location) = match node.return_type {
+            Some(ref annotation) => (
+                format!(
+                    "generator `{}` returns `{}`",
+                    node.name.name, annotation.name
+                ),
+                &annotation.location,
+            ),
+            None => (
+                format!("generator `{}` has no return type", node.name.name),
+                &node.name.location,
+            ),
+        };
+        self.diagnostics.push(
+            Diagnostic::error("E0071", &message, location.clone())
+                .with_label(&format!("expected `{}<T>`", prelude::ITERATOR))
+                .with_note(
+                    &format!(
+                        "a function with `yield` returns an `{}` of the values it yields",
+                        prelude::ITERATOR
+                    ),
+                    None,
+                ),
+        );
+    }
+}
+
+impl<'ast> Visitor<'ast> for GeneratorChecker<'ast> {
+    fn visit_function(&mut self, node: &'ast Function) {
+        if is_generator(node) {
+            self.return_type(node);
+        }
+        self.callable(Some(node), |checker| visit::walk_function(checker, node));
+    }
+
+    fn visit_class_member(&mut self, node: &'ast ClassMember) {
+        match *node {
+            ClassMember::Constructor(ref constructor) => {
+                self.callable(None, |checker| visit::walk_function(checker, constructor));
+            }
+            _ => visit::walk_class_member(self, node),
+        }
+    }
+
+    fn visit_lambda(&mut self, node: &'ast Lambda) {
+        self.callable(None, |checker| visit::walk_lambda(checker, node));
+    }
+
+    fn visit_try(&mut self, node: &'ast Try) {
+        if let Some(callable) = self.callables.last_mut() {
+            callable.tries.push(&node.location);
+        }
+        visit::walk_try(self, node);
+        if let Some(callable) = self.callables.last_mut() {
+            callable.tries.pop();
+        }
+    }
+
+    fn visit_return(&mut self, node: &'ast Return) {
+        visit::walk_return(self, node);
+
+        let generator: Option<&Function> = self
+            .callables
+            .last()
+            .and_then(|callable| callable.function)
+            .filter(|function| is_generator(function));
+        if let (Some(generator), Some(value)) = (generator, node.value.as_ref()) {
+            self.diagnostics.push(
+                Diagnostic::error(
+                    "E0071",
+                    &format!("generator `{}` returns a value", generator.name.name),
+                    value.location().clone(),
+                )
+                .with_label("unexpected return value")
+                .with_note(
+                    "generators produce their values with `yield` and end with `return;`",
+                    Some(generator.name.location.clone()),
+                ),
+            );
+        }
+    }
+
+    fn visit_yield(&mut self, node: &'ast Yield) {
+        visit::walk_yield(self, node);
+
+        match self.callables.last() {
+            Some(&Callable {
+                function: Some(_),
+                ref tries,
+            }) => {
+                if let Some(&location) = tries.last() {
+                    self.diagnostics.push(
+                        Diagnostic::error(
+                            "E0072",
+                            "`yield` inside of a `try` statement",
+                            node.location.clone(),
+                        )
+                        .with_label("the generator cannot be suspended here")
+                        .with_note("the `try` statement starts here", Some(location.clone()))
+                        .with_note(
+                            "the `try` statement would not apply to the code run while the generator is suspended, \
+                             move the `yield` out of it",
+                            None,
+                        ),
+                    );
+                }
+            }
+            found => {
+                let place: &str = if found.is_some() {
+                    "in a lambda or constructor"
+                } else {
+                    "outside of a function"
+                };
+                self.diagnostics.push(
+                    Diagnostic::error("E0070", &format!("`yield` {place}"), node.location.clone())
+                        .with_label("not inside a function that can be a generator")
+                        .with_note("only functions and methods can be generators", None),
+                );
+            }
+        }
+    }
+}
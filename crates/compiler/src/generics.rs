@@ -0,0 +1,691 @@
+//! Generic classes and functions: type names with type arguments, substitution, inference of type arguments from
+//! values, bounds and the checks of type arguments written in type annotations.
+//!
+//! Generics are erased: a generic class or function exists once and is run for every type argument, the
+//! interpreter never sees type arguments. The type checker substitutes the type arguments into the types of fields,
+//! parameters and return values, so a `Box<int>` has a field of type `int` while the class `Box<T>` declares a
+//! field of type `T`. Type names with type arguments are kept as text, e.g. `Box<list<int>>`, like all other types;
+//! the names created by substitution are stored in the [`TypeNames`] of the check.
+// I Language generics.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use std::collections::HashMap;
+
+use crate::classes::ClassTable;
+use crate::collections;
+use crate::diagnostic::Diagnostic;
+use crate::modules::Imports;
+use crate::prelude;
+
+use lexer::tokens::token::Location;
+
+use parser::ast::{
+    BindingPattern, Class, ClassPattern, Function, Program, TypeAnnotation, TypeParameter,
+};
+use parser::visit::{self, Visitor};
+
+use typed_arena::Arena;
+
+
+////////////////
+// TYPE NAMES //
+////////////////
+
+
+/// A mapping from the names of type parameters to their type arguments, e.g. `T` to `int` for a `Box<int>`.
+pub type Substitution<'ast> = HashMap<&'ast str, &'ast str>;
+
+/// The type names created while checking a program that are not written in the source, e.g. `Box<int>` for a
+/// field of type `Box<T>` or `int?` for an annotation with type arguments. Every check owns its type names, they
+/// are freed when the check ends.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::generics::TypeNames;
+/// let names = TypeNames::default();
+/// assert_eq!(names.nullable("Box<int>"), "Box<int>?");
+/// assert_eq!(names.nullable("int?"), "int?");
+/// assert_eq!(names.nullable("none"), "none");
+/// ```
+#[derive(Default)]
+pub struct TypeNames {
+    /// The text of the created names.
+    arena: Arena<u8>,
+}
+
+impl core::fmt::Debug for TypeNames {
+    #[inline]
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.debug_struct("TypeNames").finish_non_exhaustive()
+    }
+}
+
+impl TypeNames {
+    /// Stores a type name, so types created by substitution can be used like the names written in the source.
+    #[inline]
+    pub fn intern(&self, name: &str) -> &str {
+        self.arena.alloc_str(name)
+    }
+
+    /// Returns the type an annotation stands for, including its type arguments, e.g. `Box<int>` or `int?`.
+    #[inline]
+    pub fn annotation_type<'names>(
+        &'names self,
+        annotation: &'names TypeAnnotation,
+    ) -> &'names str {
+        if annotation.arguments.is_empty() && !annotation.nullable {
+            &annotation.name
+        } else {
+            self.intern(&annotation.to_string())
+        }
+    }
+
+    /// Returns the return type of a function including its type arguments, `none` if it has none.
+    #[inline]
+    pub fn return_type<'names>(&'names self, function: &'names Function) -> &'names str {
+        function
+            .return_type
+            .as_ref()
+            .map_or("none", |annotation| self.annotation_type(annotation))
+    }
+
+    /// Returns the nullable version of a type, e.g. `int?` for `int`. Nullable types, `none` and function types
+    /// are returned unchanged.
+    #[inline]
+    pub fn nullable<'names>(&'names self, name: &'names str) -> &'names str {
+        if accepts_none(name) {
+            name
+        } else {
+            self.intern(&format!("{name}?"))
+        }
+    }
+
+    /// Returns the type of a function used as a value, e.g. `function(int) -> bool` for
+    /// `function even(int number) -> bool { ... }`. Generic functions have no such type, since their type
+    /// arguments are inferred at each call.
+    #[inline]
+    pub fn function_type<'names>(&'names self, function: &'names Function) -> Option<&'names str> {
+        if !function.type_parameters.is_empty() {
+            return None;
+        }
+
+        let mut types: Vec<&str> = function
+            .parameters
+            .iter()
+            .map(|parameter| self.annotation_type(&parameter.type_annotation))
+            .collect();
+        types.push(self.return_type(function));
+        Some(self.intern(&join("function", &types)))
+    }
+
+    /// Replaces the type parameters in a type name with their type arguments, e.g. `Box<T>` with `Box<int>` if `T`
+    /// is `int`. Type parameters without a type argument are kept.
+    #[inline]
+    pub fn substitute<'names>(
+        &'names self,
+        name: &'names str,
+        substitution: &Substitution<'names>,
+    ) -> &'names str {
+        if substitution.is_empty() {
+            return name;
+        }
+
+        let (base, arguments): (&str, Vec<&str>) = split(name);
+        if arguments.is_empty() {
+            return substitution.get(base).copied().unwrap_or(name);
+        }
+
+        let arguments: Vec<&str> = arguments
+            .into_iter()
+            .map(|argument| self.substitute(argument, substitution))
+            .collect();
+        self.intern(&join(base, &arguments))
+    }
+}
+
+/// Returns whether a type accepts `none` besides its own values, e.g. `int?`. The `?` at the end of a function type
+/// belongs to its return type, so function types are never nullable.
+#[inline]
+pub fn is_nullable(name: &str) -> bool {
+    name.ends_with('?') && !name.starts_with("function(")
+}
+
+
+/// Returns the type of the values of a nullable type that are not `none`, e.g. `int` for `int?`. Other types are
+/// returned unchanged.
+#[inline]
+#[allow(clippy::string_slice)]
+pub fn non_null(name: &str) -> &str {
+    if is_nullable(name) {
+        &name[..name.len() - 1]
+    } else {
+        name
+    }
+}
+
+/// Splits a type name into its base name and its type arguments, e.g. `Box<list<int>, str>` into `Box` and
+/// `list<int>` and `str`. Function types are split into `function`, the parameter types and the return type,
+/// nullable types into `?` and the type that is made nullable.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::generics;
+/// assert_eq!(generics::split("Pair<Box<int>, str>"), ("Pair", vec!["Box<int>", "str"]));
+/// assert_eq!(generics::split("int"), ("int", vec![]));
+/// assert_eq!(generics::split("function(int, str) -> bool"), ("function", vec!["int", "str", "bool"]));
+/// assert_eq!(generics::split("Box<int>?"), ("?", vec!["Box<int>"]));
+/// ```
+#[inline]
+#[allow(clippy::string_slice)]
+pub fn split(name: &str) -> (&str, Vec<&str>) {
+    if is_nullable(name) {
+        return ("?", vec![non_null(name)]);
+    }
+    if let Some(inner) = name.strip_prefix("function(") {
+        let close: usize = nested(inner)
+            .find(|&(_, character, depth)| character == ')' && depth == 0)
+            .map_or(inner.len(), |(index, _, _)| index);
+        let mut arguments: Vec<&str> = if inner[..close].trim().is_empty() {
+            vec![]
+        } else {
+            separate(&inner[..close])
+        };
+        let result: &str = inner.get(close + 1..).unwrap_or_default().trim_start();
+        arguments.push(result.strip_prefix("->").unwrap_or(result).trim());
+
+        return ("function", arguments);
+    }
+
+    let Some(start) = name.find('<') else {
+        return (name, vec![]);
+    };
+    let inner: &str = name[start + 1..]
+        .strip_suffix('>')
+        .unwrap_or(&name[start + 1..]);
+
+    (&name[..start], separate(inner))
+}
+
+/// Returns the characters of a list of types with their positions and how deeply they are nested in angle brackets
+/// and parentheses before them. The arrows of function types do not count as angle brackets.
+fn nested(types: &str) -> impl Iterator<Item = (usize, char, usize)> + '_ {
+    let mut depth: usize = 0;
+    let mut previous: char = ' ';
+    types.char_indices().map(move |(index, character)| {
+        let before: usize = depth;
+        match character {
+            '<' | '(' => depth += 1,
+            '>' if previous != '-' => depth = depth.saturating_sub(1),
+            ')' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        previous = character;
+        (index, character, before)
+    })
+}
+
+/// Separates a list of types at the commas that are not nested, e.g. `int, Box<str, int>` into `int` and
+/// `Box<str, int>`.
+#[allow(clippy::string_slice)]
+fn separate(types: &str) -> Vec<&str> {
+    let mut arguments: Vec<&str> = vec![];
+    let mut begin: usize = 0;
+    for (index, character, depth) in nested(types) {
+        if character == ',' && depth == 0 {
+            arguments.push(types[begin..index].trim());
+            begin = index + 1;
+        }
+    }
+    arguments.push(types[begin..].trim());
+
+    arguments
+}
+
+/// Returns whether `none` is a value of a type, so it has no separate nullable version.
+fn accepts_none(name: &str) -> bool {
+    name == "none" || is_nullable(name) || name.starts_with("function(")
+}
+
+/// Builds a type name from a base name and type arguments, the reverse of [`split`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::generics;
+/// assert_eq!(generics::join("Pair", &["int", "str"]), "Pair<int, str>");
+/// assert_eq!(generics::join("function", &["int", "bool"]), "function(int) -> bool");
+/// assert_eq!(generics::join("?", &["int"]), "int?");
+/// ```
+#[inline]
+pub fn join(base: &str, arguments: &[&str]) -> String {
+    match arguments.split_last() {
+        Some((&inner, _)) if base == "?" && accepts_none(inner) => inner.to_owned(),
+        Some((inner, _)) if base == "?" => format!("{inner}?"),
+        Some((result, parameters)) if base == "function" => {
+            format!("function({}) -> {result}", parameters.join(", "))
+        }
+        None => base.to_owned(),
+        Some(_) => format!("{base}<{}>", arguments.join(", ")),
+    }
+}
+
+/// Infers type arguments by matching the type of a parameter with the type of the value given for it, e.g. `T` is
+/// `int` if a `Box<int>` is given for a `Box<T>`. Type parameters that already have a type argument are kept, a
+/// value of another type is reported when the substituted parameter type is checked.
+#[inline]
+pub fn unify<'ast>(
+    parameter: &'ast str,
+    found: &'ast str,
+    parameters: &[&'ast TypeParameter],
+    substitution: &mut Substitution<'ast>,
+) {
+    if found == "none" {
+        return;
+    }
+    // A value that is never `none` determines the type made nullable, e.g. `T` is `int` if an `int` is given for a `T?`
+    if is_nullable(parameter) && !is_nullable(found) {
+        unify(non_null(parameter), found, parameters, substitution);
+        return;
+    }
+    if let Some(type_parameter) = parameters
+        .iter()
+        .find(|type_parameter| type_parameter.name.name == parameter)
+    {
+        substitution
+            .entry(type_parameter.name.name.as_str())
+            .or_insert(found);
+        return;
+    }
+
+    let (expected_base, expected_arguments): (&str, Vec<&str>) = split(parameter);
+    let (found_base, found_arguments): (&str, Vec<&str>) = split(found);
+    if expected_base == found_base && expected_arguments.len() == found_arguments.len() {
+        for (expected, argument) in expected_arguments.into_iter().zip(found_arguments) {
+            unify(expected, argument, parameters, substitution);
+        }
+    }
+}
+
+/// Returns whether a type name mentions a type parameter, e.g. `Box<T>` mentions `T`.
+#[inline]
+pub fn mentions(name: &str, parameter: &str) -> bool {
+    let (base, arguments): (&str, Vec<&str>) = split(name);
+    base == parameter
+        || arguments
+            .into_iter()
+            .any(|argument| mentions(argument, parameter))
+}
+
+/// Returns the type parameter with the given name among the visible type parameters, innermost last.
+#[inline]
+pub fn find<'ast>(scope: &[&'ast TypeParameter], name: &str) -> Option<&'ast TypeParameter> {
+    scope
+        .iter()
+        .rev()
+        .find(|parameter| parameter.name.name == name)
+        .copied()
+}
+
+/// Returns the mapping of the type parameters of a class to the type arguments of an instance type, e.g. `T` to
+/// `int` for `Box<int>`. Returns an empty mapping if the number of type arguments is wrong.
+#[inline]
+pub fn class_substitution<'ast>(class: &'ast Class, name: &'ast str) -> Substitution<'ast> {
+    let (_, arguments): (&str, Vec<&str>) = split(name);
+    if arguments.len() != class.type_parameters.len() {
+        return Substitution::new();
+    }
+
+    class
+        .type_parameters
+        .iter()
+        .map(|parameter| parameter.name.name.as_str())
+        .zip(arguments)
+        .collect()
+}
+
+/// Returns the mapping of the type parameters of a base class or interface when a member is inherited, e.g. `T` to
+/// `int` for a field of `Box<T>` used through `class IntBox: Box<int>`.
+///
+/// # Parameters
+///
+/// - `classes`: The classes of the program.
+/// - `class`: The class the member is used on.
+/// - `owner`: The class or interface declaring the member, `class` itself or one of its supertypes.
+/// - `substitution`: The type arguments of `class`.
+/// - `names`: The type names of the check, the substituted type arguments are stored in it.
+#[inline]
+pub fn inherited<'ast>(
+    names: &'ast TypeNames,
+    classes: &ClassTable<'ast>,
+    class: &'ast Class,
+    owner: &'ast Class,
+    substitution: Substitution<'ast>,
+) -> Substitution<'ast> {
+    let mut seen: Vec<&Class> = vec![];
+    search(names, classes, class, owner, substitution, &mut seen).unwrap_or_default()
+}
+
+/// Searches the base classes and interfaces of a class for `owner`, see [`inherited`]. Returns `None` if `owner` is
+/// not a supertype of `class`.
+fn search<'ast>(
+    names: &'ast TypeNames,
+    classes: &ClassTable<'ast>,
+    class: &'ast Class,
+    owner: &'ast Class,
+    substitution: Substitution<'ast>,
+    seen: &mut Vec<&'ast Class>,
+) -> Option<Substitution<'ast>> {
+    if core::ptr::eq(class, owner) {
+        return Some(substitution);
+    }
+    if seen.iter().any(|found| core::ptr::eq(*found, class)) {
+        return None;
+    }
+    seen.push(class);
+
+    for annotation in class.bases.iter().chain(&class.interfaces) {
+        let Some(supertype) = classes.lookup(annotation) else {
+            continue;
+        };
+        let arguments: Vec<&str> = annotation
+            .arguments
+            .iter()
+            .map(|argument| names.substitute(names.annotation_type(argument), &substitution))
+            .collect();
+        let next: Substitution = supertype
+            .type_parameters
+            .iter()
+            .map(|parameter| parameter.name.name.as_str())
+            .zip(arguments)
+            .collect();
+
+        if let Some(found) = search(names, classes, supertype, owner, next, seen) {
+            return Some(found);
+        }
+    }
+
+    None
+}
+
+/// Returns whether a type satisfies the bound of a type parameter: it must be the bound class or a subclass of
+/// it, or implement the bound interface. A type parameter satisfies a bound if its own bound does.
+///
+/// # Parameters
+///
+/// - `classes`: The classes of the program.
+/// - `found`: The type argument.
+/// - `bound`: The bound of the type parameter.
+/// - `scope`: The type parameters visible where the type argument is used.
+#[inline]
+pub fn satisfies(
+    classes: &ClassTable,
+    found: &str,
+    bound: &TypeAnnotation,
+    scope: &[&TypeParameter],
+) -> bool {
+    let (base, _): (&str, Vec<&str>) = split(found);
+    // Unknown bounds are reported by the name resolution
+    let Some(expected) = classes.get(&bound.name) else {
+        return true;
+    };
+
+    let class: Option<&Class> = match find(scope, base) {
+        Some(parameter) => parameter
+            .bound
+            .as_ref()
+            .and_then(|own| classes.get(&own.name)),
+        None => classes.get(base),
+    };
+    class.map_or(false, |class| classes.is_subclass(class, expected))
+}
+
+/// Returns the diagnostic for a type whose values cannot be keys of a `dict`, see [`collections::is_key`].
+#[inline]
+pub fn invalid_key(found: &str, location: &Location) -> Diagnostic {
+    Diagnostic::error(
+        "E0054",
+        &format!("the type `{found}` cannot be used as the key of a `dict`"),
+        location.clone(),
+    )
+    .with_label(&format!("`{found}` values cannot be keys"))
+    .with_note(
+        "only `none`, `bool`, integer and `str` values can be keys",
+        None,
+    )
+}
+
+/// Returns the diagnostic for a type argument that does not satisfy the bound of a type parameter.
+#[inline]
+pub fn unsatisfied(found: &str, parameter: &TypeParameter, location: &Location) -> Diagnostic {
+    let bound: String = parameter
+        .bound
+        .as_ref()
+        .map(ToString::to_string)
+        .unwrap_or_default();
+
+    Diagnostic::error(
+        "E0054",
+        &format!(
+            "the type `{found}` does not satisfy the bound `{}: {bound}`",
+            parameter.name.name
+        ),
+        location.clone(),
+    )
+    .with_label(&format!(
+        "`{found}` is not `{bound}` and does not extend or implement it"
+    ))
+    .with_note(
+        &format!(
+            "the type parameter `{}` requires `{bound}` here",
+            parameter.name.name
+        ),
+        parameter
+            .bound
+            .as_ref()
+            .map(|annotation| annotation.location.clone()),
+    )
+}
+
+
+////////////
+// CHECKS //
+////////////
+
+/// Checks the type arguments written in the type annotations of a program: their number must match the type
+/// parameters of the class and they must satisfy the bounds of the type parameters. Built-in types and type
+/// parameters take no type arguments.
+///
+/// Type arguments inferred at calls are checked by [`crate::types::check`].
+///
+/// # Parameters
+///
+/// - `program`: The program to check.
+/// - `imports`: The names the program imports from other modules.
+///
+/// # Returns
+///
+/// The diagnostics found, in source order of the checked nodes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::{generics, modules::Imports};
+/// let tokens = lexer::lex::lex("class Box<T> { T value; } Box<int, str> box;", "<stdin>").unwrap();
+/// let program = parser::parse::parse(&tokens).unwrap();
+/// let diagnostics = generics::check(&program, &Imports::default());
+///
+/// assert_eq!(diagnostics[0].code, "E0055");
+/// ```
+#[inline]
+pub fn check<'ast>(program: &'ast Program, imports: &Imports<'ast>) -> Vec<Diagnostic> {
+    let classes: ClassTable = ClassTable::new(program, imports);
+    let mut checker: GenericChecker = GenericChecker {
+        classes: &classes,
+        scope: vec![],
+        diagnostics: vec![],
+    };
+    checker.visit_program(program);
+
+    checker.diagnostics
+}
+
+
+/////////////////////
+// GENERIC CHECKER //
+/////////////////////
+
+/// A visitor checking the type arguments of type annotations.
+struct GenericChecker<'table, 'ast> {
+    /// The classes of the program.
+    classes: &'table ClassTable<'ast>,
+    /// The visible type parameters, innermost last.
+    scope: Vec<&'ast TypeParameter>,
+    /// The diagnostics found so far.
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'ast> Visitor<'ast> for GenericChecker<'_, 'ast> {
+    fn visit_class(&mut self, node: &'ast Class) {
+        let length: usize = self.scope.len();
+        self.scope.extend(&node.type_parameters);
+        visit::walk_class(self, node);
+        self.scope.truncate(length);
+    }
+
+    fn visit_function(&mut self, node: &'ast Function) {
+        let length: usize = self.scope.len();
+        self.scope.extend(&node.type_parameters);
+        visit::walk_function(self, node);
+        self.scope.truncate(length);
+    }
+
+    // The type arguments of a class in a pattern are those of the matched value, e.g. `case Some(value)`
+    fn visit_binding_pattern(&mut self, node: &'ast BindingPattern) {
+        match node.type_annotation {
+            Some(ref annotation) if annotation.arguments.is_empty() => {
+                self.visit_identifier(&node.name);
+            }
+            _ => visit::walk_binding_pattern(self, node),
+        }
+    }
+
+    fn visit_class_pattern(&mut self, node: &'ast ClassPattern) {
+        if node.class.arguments.is_empty() {
+            for field in &node.fields {
+                self.visit_pattern(field);
+            }
+        } else {
+            visit::walk_class_pattern(self, node);
+        }
+    }
+
+    fn visit_type_annotation(&mut self, node: &'ast TypeAnnotation) {
+        visit::walk_type_annotation(self, node);
+
+        let (count, parameters): (usize, &[TypeParameter]) = match self.classes.get(&node.name) {
+            Some(class) if find(&self.scope, &node.name).is_none() => {
+                (class.type_parameters.len(), &class.type_parameters)
+            }
+            // Unknown types are reported by the name resolution
+            None if !prelude::BUILT_IN_TYPES.contains(&node.name.as_str())
+                && find(&self.scope, &node.name).is_none() =>
+            {
+                return;
+            }
+            // A tuple takes one type argument per element, e.g. `tuple<int, str>`, and a function type one per
+            // parameter and its return type
+            None if matches!(node.name.as_str(), "tuple" | "function")
+                && find(&self.scope, &node.name).is_none() =>
+            {
+                return
+            }
+            None if find(&self.scope, &node.name).is_none() => {
+                (collections::type_parameters(&node.name).len(), &[])
+            }
+            _ => (0, &[]),
+        };
+
+        if node.arguments.len() != count {
+            let expected: String = match count {
+                0 => "no type arguments".to_owned(),
+                1 => "1 type argument".to_owned(),
+                count => format!("{count} type arguments"),
+            };
+            self.diagnostics.push(
+                Diagnostic::error(
+                    "E0055",
+                    &format!(
+                        "`{}` takes {expected} but {} {} given",
+                        node.name,
+                        node.arguments.len(),
+                        if node.arguments.len() == 1 {
+                            "was"
+                        } else {
+                            "were"
+                        }
+                    ),
+                    node.location.clone(),
+                )
+                .with_label(&format!(
+                    "wrong number of type arguments for `{}`",
+                    node.name
+                )),
+            );
+            return;
+        }
+
+        // The keys of a `dict` are hashed, which only works for the values of some built-in types
+        if let Some(key) = node
+            .arguments
+            .first()
+            .filter(|_| node.name == "dict" && find(&self.scope, "dict").is_none())
+        {
+            let known: bool = self.classes.get(&key.name).is_some()
+                || prelude::BUILT_IN_TYPES.contains(&key.name.as_str());
+            if known
+                && find(&self.scope, &key.name).is_none()
+                && !collections::is_key(&key.to_string())
+            {
+                self.diagnostics
+                    .push(invalid_key(&key.to_string(), &key.location));
+            }
+        }
+
+        for (argument, parameter) in node.arguments.iter().zip(parameters) {
+            let Some(ref bound) = parameter.bound else {
+                continue;
+            };
+            let found: String = argument.to_string();
+            if !satisfies(self.classes, &found, bound, &self.scope) {
+                self.diagnostics
+                    .push(unsatisfied(&found, parameter, &argument.location));
+            }
+        }
+    }
+}
@@ -0,0 +1,257 @@
+//! The integer types: `int`, the sized integer types, e.g. `u8`, and `bigint`.
+// I Language integer types.
+// Version: 1.0.0
+
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use crate::diagnostic::Diagnostic;
+use crate::generics;
+
+use lexer::tokens::mark::Mark;
+use lexer::tokens::token::TypeDefinition;
+use parser::ast::Expression;
+
+
+///////////
+// TYPES //
+///////////
+
+/// The name of the arbitrary-precision integer type.
+pub const BIGINT: &str = "bigint";
+
+/// The names of the integer types with a fixed width: `int`, which has 64 bits, and the sized integer types.
+pub const FIXED_TYPES: [&str; 9] = ["int", "i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64"];
+
+/// The width and signedness of a sized integer type, e.g. `u8`. `int` has the range of `i64`, but is a type of its
+/// own.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Width {
+    /// `i8`, from -128 to 127.
+    I8,
+    /// `i16`, from -32768 to 32767.
+    I16,
+    /// `i32`, from -2147483648 to 2147483647.
+    I32,
+    /// `i64`, with the range of `int`.
+    I64,
+    /// `u8`, from 0 to 255.
+    U8,
+    /// `u16`, from 0 to 65535.
+    U16,
+    /// `u32`, from 0 to 4294967295.
+    U32,
+    /// `u64`, from 0 to 18446744073709551615.
+    U64,
+}
+
+impl Width {
+    /// Returns the sized integer type with the given name.
+    #[inline]
+    pub fn new(name: &str) -> Option<Self> {
+        match name {
+            "i8" => Some(Self::I8),
+            "i16" => Some(Self::I16),
+            "i32" => Some(Self::I32),
+            "i64" => Some(Self::I64),
+            "u8" => Some(Self::U8),
+            "u16" => Some(Self::U16),
+            "u32" => Some(Self::U32),
+            "u64" => Some(Self::U64),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the type, e.g. `u8`.
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::I8 => "i8",
+            Self::I16 => "i16",
+            Self::I32 => "i32",
+            Self::I64 => "i64",
+            Self::U8 => "u8",
+            Self::U16 => "u16",
+            Self::U32 => "u32",
+            Self::U64 => "u64",
+        }
+    }
+
+    /// Returns the smallest value of the type.
+    #[inline]
+    #[allow(clippy::decimal_literal_representation)]
+    pub const fn min(self) -> i128 {
+        match self {
+            Self::I8 => -128,
+            Self::I16 => -32_768,
+            Self::I32 => -2_147_483_648,
+            Self::I64 => -9_223_372_036_854_775_808,
+            Self::U8 | Self::U16 | Self::U32 | Self::U64 => 0,
+        }
+    }
+
+    /// Returns the largest value of the type.
+    #[inline]
+    #[allow(clippy::decimal_literal_representation)]
+    pub const fn max(self) -> i128 {
+        match self {
+            Self::I8 => 127,
+            Self::I16 => 32_767,
+            Self::I32 => 2_147_483_647,
+            Self::I64 => 9_223_372_036_854_775_807,
+            Self::U8 => 255,
+            Self::U16 => 65_535,
+            Self::U32 => 4_294_967_295,
+            Self::U64 => 18_446_744_073_709_551_615,
+        }
+    }
+
+    /// Returns whether the type can hold a value.
+    #[inline]
+    pub const fn contains(self, value: i128) -> bool {
+        self.min() <= value && value <= self.max()
+    }
+}
+
+/// Returns the smallest and the largest value of an integer type with a fixed width, `None` for other types.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::integers;
+/// assert_eq!(integers::range("u8"), Some((0, 255)));
+/// assert_eq!(integers::range("int"), Some((i128::from(i64::MIN), i128::from(i64::MAX))));
+/// assert_eq!(integers::range("bigint"), None);
+/// ```
+#[inline]
+pub fn range(name: &str) -> Option<(i128, i128)> {
+    if name == "int" {
+        return Some((i128::from(i64::MIN), i128::from(i64::MAX)));
+    }
+
+    Width::new(name).map(|width| (width.min(), width.max()))
+}
+
+/// Returns whether a type is an integer type: `int`, a sized integer type or `bigint`.
+#[inline]
+pub fn is_integer(name: &str) -> bool {
+    find(name).is_some()
+}
+
+/// Returns the name of the integer type with the given name, `None` if it is no integer type.
+#[inline]
+pub fn find(name: &str) -> Option<&'static str> {
+    FIXED_TYPES
+        .iter()
+        .chain(&[BIGINT])
+        .find(|found| **found == name)
+        .copied()
+}
+
+
+//////////////
+// LITERALS //
+//////////////
+
+/// Returns the value of an integer literal, which may be negated, e.g. `-128`. Literals too large for an `i128`
+/// saturate, so they are outside of the range of every fixed-width type.
+#[inline]
+pub fn literal(value: &Expression) -> Option<i128> {
+    match *value {
+        Expression::Literal(ref literal) if literal.kind == TypeDefinition::Integer => {
+            Some(literal.value.replace('_', "").parse().unwrap_or(i128::MAX))
+        }
+        Expression::Unary(ref unary) if unary.operator == Mark::Subtract && !unary.postfix => {
+            literal(&unary.operand).map(|found| -found)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the type an integer literal has without an expected type: `int`, or `bigint` if it does not fit into an
+/// `int`.
+#[inline]
+pub fn literal_type(value: &str) -> &'static str {
+    if value.replace('_', "").parse::<i64>().is_ok() {
+        "int"
+    } else {
+        BIGINT
+    }
+}
+
+/// Checks an integer literal used where a value of an integer type is expected. The literal takes the expected
+/// type, e.g. `5` is a `u8` in `u8 small = 5;`, so it has to be in the range of that type.
+///
+/// # Parameters
+///
+/// - `expected`: The expected type. A nullable integer type accepts the literals its type without `?` accepts.
+/// - `value`: The value used.
+///
+/// # Returns
+///
+/// `None` if the value is not an integer literal or the expected type is not an integer type, otherwise the
+/// diagnostics found, empty if the literal fits into the type.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::integers;
+/// let tokens = lexer::lex::lex("300;", "<stdin>").unwrap();
+/// let program = parser::parse::parse(&tokens).unwrap();
+/// let parser::ast::Statement::Expression(ref statement) = program.statements[0] else { unreachable!() };
+///
+/// assert_eq!(integers::check_literal("u8", &statement.expression).unwrap()[0].code, "E0069");
+/// assert_eq!(integers::check_literal("u16", &statement.expression), Some(vec![]));
+/// assert_eq!(integers::check_literal("str", &statement.expression), None);
+/// ```
+#[inline]
+pub fn check_literal(expected: &str, value: &Expression) -> Option<Vec<Diagnostic>> {
+    let expected: &str = generics::non_null(expected);
+    if !is_integer(expected) {
+        return None;
+    }
+    let found: i128 = literal(value)?;
+    let Some((min, max)) = range(expected) else {
+        return Some(vec![]);
+    };
+    if (min..=max).contains(&found) {
+        return Some(vec![]);
+    }
+
+    Some(vec![Diagnostic::error(
+        "E0069",
+        &format!("integer literal out of range for `{expected}`"),
+        value.location().clone(),
+    )
+    .with_label(&format!("does not fit into `{expected}`"))
+    .with_note(
+        &format!("`{expected}` holds values from {min} to {max}"),
+        None,
+    )
+    .with_note(
+        "use a wider integer type or `bigint` to hold larger values",
+        None,
+    )])
+}
@@ -1,14 +1,68 @@
-pub fn add(left: usize, right: usize) -> usize {
-    left + right
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
+//! I-Language compiler checks and compiles the abstract syntax tree of the I-Programming-Language.
+// I Language compiler.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+// Restriction lints contradicting the idioms of the compiler, the same as in the parser: errors are propagated with
+// `?`, patterns bind with `ref` as `pattern_type_mismatch` asks for, also in borrowed tuples such as
+// `&(ref state, ref skip)` that `needless_borrowed_reference` would turn back into default binding modes, items are
+// grouped by banner sections instead of alphabetically, the checks are split into helpers, matches on AST nodes only
+// handle the kinds a check is about and trait implementations name their parameters like the lexer does, e.g.
+// `formatter`. Bindings are also unwrapped under their own name, e.g. `if let Some(class) = class`.
+#![allow(
+    clippy::arbitrary_source_item_ordering,
+    clippy::needless_borrowed_reference,
+    clippy::question_mark_used,
+    clippy::ref_patterns,
+    clippy::renamed_function_params,
+    clippy::shadow_reuse,
+    clippy::shadow_same,
+    clippy::single_call_fn,
+    clippy::wildcard_enum_match_arm
+)]
+
+/////////////
+// EXPORTS //
+/////////////
+
+pub mod annotations;
+pub mod assignments;
+pub mod check;
+pub mod classes;
+pub mod collections;
+pub mod constants;
+pub mod contracts;
+pub mod diagnostic;
+pub mod environment;
+pub mod exceptions;
+pub mod flow;
+pub mod formats;
+pub mod functions;
+pub mod generators;
+pub mod generics;
+pub mod integers;
+pub mod modules;
+pub mod operators;
+pub mod patterns;
+pub mod prelude;
+pub mod resolve;
+pub mod types;
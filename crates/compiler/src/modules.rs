@@ -0,0 +1,615 @@
+//! Loads the modules a program imports and resolves its `import` and `use` statements.
+// I Language modules.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::constants;
+use crate::contracts;
+use crate::diagnostic::Diagnostic;
+use crate::prelude;
+use crate::resolve::address;
+
+use parser::ast::{Class, Function, Identifier, Import, Program, Statement, TypeAnnotation, Use};
+use parser::visit::Visitor;
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// The extension of source files.
+pub const EXTENSION: &str = "il";
+
+/// The directory the modules of the standard library are located in, used for their locations.
+pub const STANDARD_LIBRARY: &str = "<std>";
+
+/// The modules of the standard library and their source code. Files next to the program take precedence.
+const STANDARD_MODULES: [(&str, &str); 1] = [("random", include_str!("std/random.il"))];
+
+
+////////////
+// MODULE //
+////////////
+
+/// A parsed source file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Module {
+    /// The name used to import the module, e.g. `foo.bar` for `foo/bar.il`.
+    pub name: String,
+    /// The file name used in the locations of the module.
+    pub file: String,
+    /// The source code of the module.
+    pub source: String,
+    /// The parsed source code.
+    pub program: Program,
+}
+
+/// A declaration other modules can `use`.
+#[derive(Clone, Copy, Debug)]
+pub enum Item<'ast> {
+    /// A class declared at the top level of a module.
+    Class(&'ast Class),
+    /// A function declared at the top level of a module.
+    Function(&'ast Function),
+}
+
+impl<'ast> Item<'ast> {
+    /// Returns whether the item is marked with `pub`.
+    #[inline]
+    pub const fn is_public(&self) -> bool {
+        match *self {
+            Self::Class(class) => class.public,
+            Self::Function(function) => function.public,
+        }
+    }
+
+    /// Returns the name of the item.
+    #[inline]
+    pub const fn name(&self) -> &'ast Identifier {
+        match *self {
+            Self::Class(class) => &class.name,
+            Self::Function(function) => &function.name,
+        }
+    }
+}
+
+/// Returns the items declared at the top level of a program, public or not, including the variants of enums.
+/// Only the first declaration of a name is returned.
+#[inline]
+pub fn items(program: &Program) -> HashMap<&str, Item<'_>> {
+    let mut result: HashMap<&str, Item> = HashMap::new();
+    for statement in &program.statements {
+        let found: Vec<Item> = match *statement {
+            Statement::Class(ref class) => core::iter::once(class)
+                .chain(&class.variants)
+                .map(Item::Class)
+                .collect(),
+            Statement::Function(ref function) => vec![Item::Function(function)],
+            _ => continue,
+        };
+        for item in found {
+            result.entry(&item.name().name).or_insert(item);
+        }
+    }
+
+    result
+}
+
+/// Returns the item of a module named by a `use` statement or by a member expression like `random.choice`.
+///
+/// # Parameters
+///
+/// - `module`: The module declaring the item.
+/// - `name`: The name of the item.
+///
+/// # Returns
+///
+/// The public item with the name.
+///
+/// # Errors
+///
+/// Errors with `E0037` if the module does not declare the item and with `E0036` if the item is private.
+#[inline]
+pub fn item<'module>(
+    module: &'module Module,
+    name: &Identifier,
+) -> Result<Item<'module>, Box<Diagnostic>> {
+    match items(&module.program).get(name.name.as_str()) {
+        None => Err(Box::new(
+            Diagnostic::error(
+                "E0037",
+                &format!("module `{}` has no item `{}`", module.name, name.name),
+                name.location.clone(),
+            )
+            .with_label("not found in the module"),
+        )),
+        Some(item) if !item.is_public() => Err(Box::new(
+            Diagnostic::error(
+                "E0036",
+                &format!("`{}` is private to module `{}`", name.name, module.name),
+                name.location.clone(),
+            )
+            .with_label("private item")
+            .with_note(
+                "declared here without `pub`",
+                Some(item.name().location.clone()),
+            ),
+        )),
+        Some(&item) => Ok(item),
+    }
+}
+
+
+////////////////////
+// IMPORTED NAMES //
+////////////////////
+
+/// The names a module binds with `import` and `use` statements.
+#[derive(Clone, Debug, Default)]
+pub struct Imports<'ast> {
+    /// The imported modules by the name they are bound to, the alias or the last name of their path.
+    pub modules: HashMap<&'ast str, &'ast Module>,
+    /// The items bound with `use`, by their alias or name.
+    pub items: HashMap<&'ast str, Item<'ast>>,
+    /// The base classes and interfaces of the classes declared in other modules, which are resolved in the
+    /// modules declaring them. Keyed by the address of the type annotation naming them.
+    pub supertypes: HashMap<*const TypeAnnotation, &'ast Class>,
+}
+
+
+/////////////
+// MODULES //
+/////////////
+
+/// All modules of a program, loaded starting at the entry module.
+#[derive(Clone, Debug)]
+pub struct Modules {
+    /// The loaded modules. Modules are stored after the modules they import, the entry module last.
+    modules: Vec<Module>,
+    /// The files that could not be lexed or parsed and their source code, used to render their diagnostics.
+    invalid: Vec<(String, String)>,
+}
+
+impl Modules {
+    /// Loads all modules imported by a module, directly or indirectly.
+    ///
+    /// Module paths are resolved relative to the directory of the entry module, usually `src`:
+    /// `import foo.bar;` loads `src/foo/bar.il`. Modules of the standard library are used if no such file exists.
+    ///
+    /// # Parameters
+    ///
+    /// - `entry`: The module the program starts in.
+    ///
+    /// # Returns
+    ///
+    /// The loaded modules and the diagnostics for unknown modules, import cycles, unknown or private items
+    /// and modules that could not be lexed or parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use compiler::modules::{Module, Modules};
+    /// let source = "import random; print(random.randint(1, 6));";
+    /// let tokens = lexer::lex::lex(source, "main.il").unwrap();
+    /// let entry = Module {
+    ///     name: "main".to_owned(),
+    ///     file: "main.il".to_owned(),
+    ///     source: source.to_owned(),
+    ///     program: parser::parse::parse(&tokens).unwrap(),
+    /// };
+    ///
+    /// let (modules, diagnostics) = Modules::load(entry);
+    /// assert!(diagnostics.is_empty());
+    /// assert!(modules.get("random").is_some());
+    /// ```
+    #[inline]
+    pub fn load(entry: Module) -> (Self, Vec<Diagnostic>) {
+        let mut loader: Loader = Loader {
+            root: Path::new(&entry.file)
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default(),
+            modules: vec![],
+            invalid: vec![],
+            stack: vec![],
+            failed: vec![],
+            diagnostics: vec![],
+        };
+        loader.load(entry);
+
+        (
+            Self {
+                modules: loader.modules,
+                invalid: loader.invalid,
+            },
+            loader.diagnostics,
+        )
+    }
+
+    /// Returns the module with the given name.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&Module> {
+        self.modules.iter().find(|module| module.name == name)
+    }
+
+    /// Returns the module the program starts in.
+    ///
+    /// # Panics
+    ///
+    /// Never panics, the entry module is always loaded.
+    #[inline]
+    pub fn entry(&self) -> &Module {
+        self.modules
+            .last()
+            .expect("the entry module is always loaded")
+    }
+
+    /// Returns all modules, each after the modules it imports.
+    #[inline]
+    pub fn iter(&self) -> core::slice::Iter<'_, Module> {
+        self.modules.iter()
+    }
+
+    /// Removes the assertions and contracts of all modules for a release build, see [`contracts::strip`].
+    /// The modules should be checked first, so errors in the removed code are still reported.
+    #[inline]
+    pub fn strip_contracts(&mut self) {
+        for module in &mut self.modules {
+            contracts::strip(&mut module.program);
+        }
+    }
+
+    /// Returns the source code of a file, used to render diagnostics.
+    #[inline]
+    pub fn source(&self, file: &str) -> Option<&str> {
+        self.modules
+            .iter()
+            .map(|module| (&module.file, &module.source))
+            .chain(
+                self.invalid
+                    .iter()
+                    .map(|&(ref found, ref source)| (found, source)),
+            )
+            .find(|&(found, _)| found == file)
+            .map(|(_, source)| source.as_str())
+    }
+
+    /// Resolves the `import` and `use` statements of a module. Statements that cannot be resolved are skipped,
+    /// they are reported by [`Modules::load`].
+    ///
+    /// # Panics
+    ///
+    /// Never panics, the parser only creates `import` statements with at least one name.
+    #[inline]
+    pub fn imports<'ast>(&'ast self, module: &'ast Module) -> Imports<'ast> {
+        let mut imports: Imports = Imports::default();
+
+        for (path, node) in statements(&module.program) {
+            match node {
+                ImportNode::Import(import) => {
+                    if let Some(found) = self.get(&path) {
+                        let name: &Identifier = import.alias.as_ref().unwrap_or_else(|| {
+                            import.path.last().expect("paths have at least one name")
+                        });
+                        imports.modules.insert(&name.name, found);
+                    }
+                }
+                ImportNode::Use(node) => {
+                    let item: Option<Item> = self.get(&path).and_then(|found| {
+                        let name: &Identifier = node.path.last()?;
+                        items(&found.program)
+                            .get(name.name.as_str())
+                            .copied()
+                            .filter(Item::is_public)
+                    });
+                    if let Some(item) = item {
+                        let name: &Identifier = node.alias.as_ref().unwrap_or_else(|| item.name());
+                        imports.items.insert(&name.name, item);
+                    }
+                }
+            }
+        }
+
+        for other in &self.modules {
+            if !core::ptr::eq(other, module) {
+                imports.supertypes.extend(self.supertypes(other));
+            }
+        }
+
+        imports
+    }
+
+    /// Resolves the base classes and interfaces of the top level classes of a module, using the names visible in it.
+    #[allow(clippy::iter_over_hash_type)]
+    fn supertypes<'ast>(
+        &'ast self,
+        module: &'ast Module,
+    ) -> impl Iterator<Item = (*const TypeAnnotation, &'ast Class)> {
+        let mut classes: HashMap<&str, &Class> = HashMap::new();
+        for statement in &prelude::program().statements {
+            if let Statement::Class(ref class) = *statement {
+                classes.insert(&class.name.name, class);
+            }
+        }
+        for (path, node) in statements(&module.program) {
+            let ImportNode::Use(node) = node else {
+                continue;
+            };
+            let item: Option<Item> = self.get(&path).and_then(|found| {
+                items(&found.program)
+                    .get(node.path.last()?.name.as_str())
+                    .copied()
+            });
+            if let Some(Item::Class(class)) = item {
+                let name: &Identifier = node.alias.as_ref().unwrap_or(&class.name);
+                classes.insert(&name.name, class);
+            }
+        }
+        for (name, item) in items(&module.program) {
+            if let Item::Class(class) = item {
+                classes.insert(name, class);
+            }
+        }
+
+        items(&module.program)
+            .into_values()
+            .filter_map(|item| match item {
+                Item::Class(class) => Some(class.bases.iter().chain(&class.interfaces)),
+                Item::Function(_) => None,
+            })
+            .flatten()
+            .filter_map(move |annotation| {
+                Some((address(annotation), *classes.get(annotation.name.as_str())?))
+            })
+    }
+}
+
+impl<'modules> IntoIterator for &'modules Modules {
+    type Item = &'modules Module;
+    type IntoIter = core::slice::Iter<'modules, Module>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+
+/////////////
+// HELPERS //
+/////////////
+
+/// An `import` or `use` statement.
+#[derive(Clone, Copy)]
+enum ImportNode<'ast> {
+    /// An `import` statement.
+    Import(&'ast Import),
+    /// A `use` statement.
+    Use(&'ast Use),
+}
+
+impl ImportNode<'_> {
+    /// Returns the names of the imported module path.
+    fn module_path(&self) -> &[Identifier] {
+        match *self {
+            Self::Import(node) => &node.path,
+            Self::Use(node) => node.path.split_last().map_or(&[], |(_, path)| path),
+        }
+    }
+}
+
+/// A visitor collecting all `import` and `use` statements of a program, including nested ones.
+#[derive(Default)]
+struct ImportCollector<'ast> {
+    /// The statements found, in source order.
+    nodes: Vec<ImportNode<'ast>>,
+}
+
+impl<'ast> Visitor<'ast> for ImportCollector<'ast> {
+    fn visit_import(&mut self, node: &'ast Import) {
+        self.nodes.push(ImportNode::Import(node));
+    }
+
+    fn visit_use(&mut self, node: &'ast Use) {
+        self.nodes.push(ImportNode::Use(node));
+    }
+}
+
+/// Returns the `import` and `use` statements of a program with the names of the modules they import,
+/// e.g. `foo.bar`.
+fn statements(program: &Program) -> Vec<(String, ImportNode<'_>)> {
+    let mut collector: ImportCollector = ImportCollector::default();
+    collector.visit_program(program);
+
+    collector
+        .nodes
+        .into_iter()
+        .map(|node| (path_name(node.module_path()), node))
+        .collect()
+}
+
+/// Joins the names of a module path with dots.
+fn path_name(path: &[Identifier]) -> String {
+    let names: Vec<&str> = path.iter().map(|name| name.name.as_str()).collect();
+    names.join(".")
+}
+
+
+////////////
+// LOADER //
+////////////
+
+/// Loads modules depth first, so cycles can be found with the stack of modules being loaded.
+struct Loader {
+    /// The directory module paths are relative to.
+    root: PathBuf,
+    /// The modules loaded completely, each after the modules it imports.
+    modules: Vec<Module>,
+    /// The files that could not be lexed or parsed and their source code.
+    invalid: Vec<(String, String)>,
+    /// The names of the modules being loaded, the outermost first.
+    stack: Vec<String>,
+    /// The names of the modules that do not exist or could not be parsed, reported only once.
+    failed: Vec<String>,
+    /// The diagnostics found so far.
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Loader {
+    /// Loads the modules a module imports, then stores the module.
+    #[allow(clippy::indexing_slicing)]
+    fn load(&mut self, mut module: Module) {
+        module.program = constants::fold(module.program);
+        self.stack.push(module.name.clone());
+
+        for (name, node) in statements(&module.program) {
+            let path: &[Identifier] = node.module_path();
+            let Some(first) = path.first() else {
+                continue;
+            };
+
+            if let Some(index) = self.stack.iter().position(|found| *found == name) {
+                let mut cycle: Vec<String> = self.stack[index..].to_vec();
+                cycle.push(name.clone());
+                self.diagnostics.push(
+                    Diagnostic::error(
+                        "E0035",
+                        &format!("import cycle with module `{name}`"),
+                        first.location.clone(),
+                    )
+                    .with_label(&format!("`{name}` is still being loaded here"))
+                    .with_note(
+                        &format!("the modules import each other: {}", cycle.join(" -> ")),
+                        None,
+                    ),
+                );
+                continue;
+            }
+
+            if !self.modules.iter().any(|found| found.name == name) && !self.failed.contains(&name)
+            {
+                if let Some((file, source)) = self.read(path) {
+                    self.parse(name.clone(), file, &source);
+                } else {
+                    let file: PathBuf = self.path(path);
+                    let mut unknown: Diagnostic = Diagnostic::error(
+                        "E0034",
+                        &format!("unknown module `{name}`"),
+                        first.location.clone(),
+                    )
+                    .with_note(&format!("no file `{}` exists", file.display()), None);
+                    if let Some(last) = path
+                        .last()
+                        .filter(|last| last.location.line == first.location.line)
+                    {
+                        unknown = unknown.with_length(
+                            last.location.column + last.name.chars().count()
+                                - first.location.column,
+                        );
+                    }
+                    self.diagnostics.push(unknown);
+                    self.failed.push(name.clone());
+                }
+            }
+
+            if let ImportNode::Use(node) = node {
+                self.check_item(&name, node);
+            }
+        }
+
+        self.stack.pop();
+        self.modules.push(module);
+    }
+
+    /// Returns the file a module path maps to.
+    fn path(&self, path: &[Identifier]) -> PathBuf {
+        let mut file: PathBuf = self.root.clone();
+        file.extend(path.iter().map(|name| &name.name));
+        file.set_extension(EXTENSION);
+
+        file
+    }
+
+    /// Reads the source code of a module from its file or the standard library.
+    /// Returns the file name used in locations and the source code.
+    fn read(&self, path: &[Identifier]) -> Option<(String, String)> {
+        let file: PathBuf = self.path(path);
+        if let Ok(source) = std::fs::read_to_string(&file) {
+            return Some((file.display().to_string(), source));
+        }
+
+        let name: String = path_name(path);
+        STANDARD_MODULES
+            .iter()
+            .find(|&&(found, _)| found == name)
+            .map(|&(_, source)| {
+                (
+                    format!("{STANDARD_LIBRARY}/{name}.{EXTENSION}"),
+                    source.to_owned(),
+                )
+            })
+    }
+
+    /// Lexes and parses a module, then loads it.
+    fn parse(&mut self, name: String, file: String, source: &str) {
+        let source: String = source.trim().to_owned();
+        let program: Result<Program, Diagnostic> = match lexer::lex::lex(&source, &file) {
+            Ok(tokens) => parser::parse::parse(&tokens).map_err(|error| Diagnostic::from(&error)),
+            Err(error) => Err(Diagnostic::from(&error)),
+        };
+
+        match program {
+            Ok(program) => self.load(Module {
+                name,
+                file,
+                source,
+                program,
+            }),
+            Err(diagnostic) => {
+                self.diagnostics.push(diagnostic);
+                self.invalid.push((file, source));
+                self.failed.push(name);
+            }
+        }
+    }
+
+    /// Checks that the item of a `use` statement exists and is public.
+    fn check_item(&mut self, module: &str, node: &Use) {
+        let Some(name) = node.path.last() else {
+            return;
+        };
+        let Some(found) = self.modules.iter().find(|found| found.name == module) else {
+            return;
+        };
+
+        if let Err(diagnostic) = item(found, name) {
+            self.diagnostics.push(*diagnostic);
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Operators defined by classes through methods with special names, e.g. `add` for `+`.
+// I Language operators.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use lexer::tokens::mark::Mark;
+
+
+/////////////
+// METHODS //
+/////////////
+
+/// The name of the method reading an element of an object with `object[index]`.
+pub const INDEX_METHOD: &str = "index";
+
+/// The name of the method assigning an element of an object with `object[index] = value`.
+pub const SET_INDEX_METHOD: &str = "set_index";
+
+/// Returns the name of the method a class defines to support a binary operator on its instances, e.g. `add` for
+/// `+`. The object is the left operand and the right operand is the argument.
+///
+/// `!=` uses the method of `==` and negates its result, objects without it are compared by identity. `&&` and `||`
+/// cannot be defined, since they only evaluate their right operand if needed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::operators;
+/// # use lexer::tokens::mark::Mark;
+/// assert_eq!(operators::binary_method(&Mark::Add), Some("add"));
+/// assert_eq!(operators::binary_method(&Mark::NotEqual), Some("eq"));
+/// assert_eq!(operators::binary_method(&Mark::And), None);
+/// ```
+#[inline]
+pub const fn binary_method(operator: &Mark) -> Option<&'static str> {
+    match *operator {
+        Mark::Add => Some("add"),
+        Mark::Subtract => Some("sub"),
+        Mark::Multiply => Some("mul"),
+        Mark::Divide => Some("div"),
+        Mark::Modulo => Some("mod"),
+        Mark::Exponentiation => Some("pow"),
+        Mark::BitAnd => Some("bit_and"),
+        Mark::BitOr => Some("bit_or"),
+        Mark::BitXor => Some("bit_xor"),
+        Mark::ShiftLeft => Some("shl"),
+        Mark::ShiftRight => Some("shr"),
+        Mark::Equal | Mark::NotEqual => Some("eq"),
+        Mark::Less => Some("lt"),
+        Mark::LessEqual => Some("le"),
+        Mark::Greater => Some("gt"),
+        Mark::GreaterEqual => Some("ge"),
+        _ => None,
+    }
+}
+
+/// Returns the name of the method a class defines to support a unary operator on its instances, e.g. `neg` for
+/// `-`. `!`, `++` and `--` cannot be defined.
+#[inline]
+pub const fn unary_method(operator: &Mark) -> Option<&'static str> {
+    match *operator {
+        Mark::Subtract => Some("neg"),
+        Mark::BitNot => Some("bit_not"),
+        _ => None,
+    }
+}
+
+/// Returns the name of the method a class may define to change its instances in place for a compound assignment,
+/// e.g. `add_assign` for `+=`. Without it, the compound assignment falls back to the binary operator and assigns
+/// its result.
+#[inline]
+pub const fn compound_method(operator: &Mark) -> Option<&'static str> {
+    match *operator {
+        Mark::AddAssign => Some("add_assign"),
+        Mark::SubtractAssign => Some("sub_assign"),
+        Mark::MultiplyAssign => Some("mul_assign"),
+        Mark::DivideAssign => Some("div_assign"),
+        Mark::ModuloAssign => Some("mod_assign"),
+        Mark::BitAndAssign => Some("bit_and_assign"),
+        Mark::BitOrAssign => Some("bit_or_assign"),
+        Mark::BitXorAssign => Some("bit_xor_assign"),
+        Mark::ShiftLeftAssign => Some("shl_assign"),
+        Mark::ShiftRightAssign => Some("shr_assign"),
+        _ => None,
+    }
+}
+
+/// Returns whether the method of an operator compares two values and has to return a `bool`.
+#[inline]
+pub fn is_comparison(method: &str) -> bool {
+    matches!(method, "eq" | "lt" | "le" | "gt" | "ge")
+}
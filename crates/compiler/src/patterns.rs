@@ -0,0 +1,637 @@
+//! Semantic checks for `match`: pattern types, unreachable cases and missing coverage.
+// I Language pattern checks.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use crate::classes::ClassTable;
+use crate::diagnostic::Diagnostic;
+use crate::environment::{self, Environment};
+use crate::generics::{self, Substitution, TypeNames};
+use crate::integers;
+use crate::modules::Imports;
+
+use lexer::tokens::token::{Location, TypeDefinition};
+use parser::ast::{
+    Block, Catch, Class, ClassPattern, Expression, Field, For, Function, Lambda, Literal, Match,
+    MatchArm, Pattern, Program, RangeBound, RangePattern, VariableDeclaration,
+};
+use parser::visit::{self, Visitor};
+
+
+/////////////
+// HELPERS //
+/////////////
+
+/// Returns whether a pattern matches every value of the given type.
+fn is_irrefutable(pattern: &Pattern, type_name: Option<&str>) -> bool {
+    match *pattern {
+        Pattern::Alternative(ref alternative) => alternative
+            .patterns
+            .iter()
+            .any(|found| is_irrefutable(found, type_name)),
+        Pattern::Binding(ref binding) => {
+            binding.type_annotation.as_ref().map_or(true, |annotation| {
+                type_name.map_or(false, |type_name| annotation.name == type_name)
+            })
+        }
+        Pattern::Class(_) | Pattern::Literal(_) | Pattern::Range(_) => false,
+    }
+}
+
+/// Returns whether a case matches every value of the given type, i.e. all later cases are unreachable.
+fn is_catch_all(arm: &MatchArm, type_name: Option<&str>) -> bool {
+    arm.guard.is_none()
+        && arm
+            .pattern
+            .as_ref()
+            .map_or(true, |pattern| is_irrefutable(pattern, type_name))
+}
+
+/// Returns whether a match covers every possible value, either with a catch-all case, by listing both
+/// `true` and `false`, or by matching every variant of an enum.
+///
+/// # Parameters
+///
+/// - `node`: The match to check.
+/// - `type_name`: The type of the matched value, if it is known.
+/// - `classes`: The classes of the program, used to find the variants of an enum.
+///
+/// # Returns
+///
+/// Whether a case is executed for every value. Cases with a guard never count, since the guard may be false.
+#[inline]
+pub fn is_exhaustive<'ast>(
+    node: &Match,
+    type_name: Option<&'ast str>,
+    classes: &ClassTable<'ast>,
+) -> bool {
+    let mut values: Vec<Value> = vec![];
+    for arm in node.arms.iter().filter(|arm| arm.guard.is_none()) {
+        if is_catch_all(arm, type_name) {
+            return true;
+        }
+        if let Some(ref pattern) = arm.pattern {
+            values.extend(Value::of(pattern));
+        }
+    }
+
+    (values.contains(&Value::Bool(true)) && values.contains(&Value::Bool(false)))
+        || missing_variants(node, type_name, classes).map_or(false, |missing| missing.is_empty())
+}
+
+/// Returns the variants of the matched enum that no case without a guard matches completely, i.e. with a class
+/// pattern whose field patterns match every value of their field.
+///
+/// The enum is the type of the matched value. If that type is unknown, it is the enum of the first variant named by
+/// a class pattern. Returns `None` if the matched value is not an enum.
+fn missing_variants<'ast>(
+    node: &Match,
+    type_name: Option<&'ast str>,
+    classes: &ClassTable<'ast>,
+) -> Option<Vec<&'ast Class>> {
+    let patterns: Vec<&ClassPattern> = node
+        .arms
+        .iter()
+        .filter(|arm| arm.guard.is_none())
+        .filter_map(|arm| arm.pattern.as_ref())
+        .flat_map(class_patterns)
+        .collect();
+
+    let enumeration: &Class = match type_name {
+        Some(found) => classes
+            .get(generics::split(found).0)
+            .filter(|class| class.enumeration)?,
+        None => patterns.iter().find_map(|pattern| {
+            let class: &Class = classes.get(&pattern.class.name)?;
+            classes.enumeration(class)
+        })?,
+    };
+    let substitution: Substitution = type_name
+        .map(|found| generics::class_substitution(enumeration, found))
+        .unwrap_or_default();
+
+    let names: TypeNames = TypeNames::default();
+    let covers = |variant: &Class, pattern: &ClassPattern| {
+        let fields: Vec<&Field> = classes.fields(variant);
+        pattern.class.name == variant.name.name
+            && pattern.fields.len() == fields.len()
+            && pattern.fields.iter().zip(fields).all(|(found, field)| {
+                let field_type: &str =
+                    names.substitute(names.annotation_type(&field.type_annotation), &substitution);
+                is_irrefutable(found, Some(field_type))
+            })
+    };
+
+    Some(
+        enumeration
+            .variants
+            .iter()
+            .filter(|variant| !patterns.iter().any(|pattern| covers(variant, pattern)))
+            .collect(),
+    )
+}
+
+/// Returns the class patterns of a pattern and of its alternatives, without the ones nested in fields.
+fn class_patterns(pattern: &Pattern) -> Vec<&ClassPattern> {
+    match *pattern {
+        Pattern::Alternative(ref alternative) => alternative
+            .patterns
+            .iter()
+            .flat_map(class_patterns)
+            .collect(),
+        Pattern::Class(ref class_pattern) => vec![class_pattern],
+        Pattern::Binding(_) | Pattern::Literal(_) | Pattern::Range(_) => vec![],
+    }
+}
+
+/// A value (or range of values) matched by a literal or range pattern, used to find duplicate cases.
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    /// A `true` or `false` literal.
+    Bool(bool),
+    /// An integer range, the end is excluded. Integer literals are ranges with a single value.
+    Integer(i128, i128),
+    /// A string literal.
+    String(String),
+    /// The `none` literal.
+    None,
+}
+
+impl Value {
+    /// Returns the values matched by the literals and ranges of a pattern.
+    fn of(pattern: &Pattern) -> Vec<Self> {
+        match *pattern {
+            Pattern::Alternative(ref alternative) => {
+                alternative.patterns.iter().flat_map(Self::of).collect()
+            }
+            Pattern::Literal(ref literal) => Self::from_literal(literal).into_iter().collect(),
+            Pattern::Range(ref range) => match (bound(&range.start), bound(&range.end)) {
+                (Some(start), Some(end)) => vec![Self::Integer(start, end)],
+                _ => vec![],
+            },
+            Pattern::Binding(_) | Pattern::Class(_) => vec![],
+        }
+    }
+
+    /// Converts a literal into a value.
+    fn from_literal(literal: &Literal) -> Option<Self> {
+        match literal.kind {
+            TypeDefinition::True => Some(Self::Bool(true)),
+            TypeDefinition::False => Some(Self::Bool(false)),
+            TypeDefinition::Integer => {
+                integer(literal).map(|value| Self::Integer(value, value + 1))
+            }
+            TypeDefinition::String => Some(Self::String(literal.value.clone())),
+            TypeDefinition::None => Some(Self::None),
+            TypeDefinition::FormatString => None,
+        }
+    }
+
+    /// Returns whether every value matched by `self` is also matched by `other`.
+    fn is_covered_by(&self, other: &Self) -> bool {
+        match (self, other) {
+            (&Self::Integer(start, end), &Self::Integer(other_start, other_end)) => {
+                other_start <= start && end <= other_end
+            }
+            _ => self == other,
+        }
+    }
+}
+
+/// Returns the value of an integer literal, ignoring underscores.
+fn integer(literal: &Literal) -> Option<i128> {
+    if literal.kind != TypeDefinition::Integer {
+        return None;
+    }
+
+    literal.value.replace('_', "").parse().ok()
+}
+
+/// Returns the value of a bound of a range pattern. Constants are replaced with their values before the checks,
+/// so a bound that is still a name has no known value.
+fn bound(bound: &RangeBound) -> Option<i128> {
+    match *bound {
+        RangeBound::Literal(ref literal) => integer(literal),
+        RangeBound::Constant(_) => None,
+    }
+}
+
+
+////////////
+// CHECKS //
+////////////
+
+/// Checks all `match` statements and expressions of a program.
+///
+/// Reports patterns that can never match the type of the matched value, invalid range and class patterns,
+/// cases that are never reached because earlier cases already match all their values, and matches on
+/// `bool` values that do not cover both `true` and `false`.
+///
+/// # Parameters
+///
+/// - `program`: The program to check.
+/// - `imports`: The names the program imports from other modules.
+///
+/// # Returns
+///
+/// The diagnostics found, in source order of the checked matches.
+#[inline]
+pub fn check<'ast>(program: &'ast Program, imports: &Imports<'ast>) -> Vec<Diagnostic> {
+    let names: TypeNames = TypeNames::default();
+    let classes: ClassTable = ClassTable::new(program, imports);
+    let mut checker: PatternChecker = PatternChecker {
+        names: &names,
+        classes: &classes,
+        environment: Environment::default(),
+        diagnostics: vec![],
+    };
+    checker.environment.enter(&program.statements);
+    checker.environment.import(imports);
+    checker.visit_program(program);
+
+    checker.diagnostics
+}
+
+
+/////////////////////
+// PATTERN CHECKER //
+/////////////////////
+
+/// A visitor checking the patterns and cases of matches.
+struct PatternChecker<'table, 'ast> {
+    /// The type names created while checking.
+    names: &'ast TypeNames,
+    /// The classes of the program.
+    classes: &'table ClassTable<'ast>,
+    /// The names visible at the visited node.
+    environment: Environment<'ast>,
+    /// The diagnostics found so far.
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'ast> PatternChecker<'_, 'ast> {
+    /// Reports a pattern that can never match a value of the matched type.
+    fn mismatch(&mut self, expected: &str, found: &str, location: &Location, subject: &Location) {
+        self.diagnostics.push(
+            Diagnostic::error("E0023", "mismatched types", location.clone())
+                .with_label(&format!("expected `{expected}`, found `{found}`"))
+                .with_note(
+                    &format!("the matched value has type `{expected}`"),
+                    Some(subject.clone()),
+                ),
+        );
+    }
+
+    /// Checks a pattern against the type of the matched value.
+    fn check_pattern(&mut self, pattern: &Pattern, type_name: Option<&str>, subject: &Location) {
+        let builtin: Option<&str> =
+            type_name.filter(|found| ["int", "str", "bool", "none"].contains(found));
+
+        match *pattern {
+            Pattern::Alternative(ref alternative) => {
+                for found in &alternative.patterns {
+                    self.check_pattern(found, type_name, subject);
+                }
+            }
+            Pattern::Binding(ref binding) => {
+                if let (Some(expected), Some(annotation)) =
+                    (builtin, binding.type_annotation.as_ref())
+                {
+                    if annotation.name != expected {
+                        self.mismatch(expected, &annotation.name, &annotation.location, subject);
+                    }
+                }
+            }
+            Pattern::Class(ref class_pattern) => {
+                let Some(class) = self.classes.get(&class_pattern.class.name) else {
+                    self.diagnostics.push(
+                        Diagnostic::error(
+                            "E0029",
+                            &format!("unknown class `{}` in pattern", class_pattern.class.name),
+                            class_pattern.class.location.clone(),
+                        )
+                        .with_label("not found"),
+                    );
+                    return;
+                };
+                if let Some(expected) = builtin {
+                    self.mismatch(expected, &class.name.name, &class_pattern.location, subject);
+                }
+
+                let fields: Vec<Option<&str>> = self
+                    .classes
+                    .fields(class)
+                    .iter()
+                    .map(|field| Some(field.type_annotation.name.as_str()))
+                    .collect();
+                if fields.len() != class_pattern.fields.len() {
+                    self.diagnostics.push(
+                        Diagnostic::error(
+                            "E0030",
+                            &format!(
+                                "pattern has {} field{}, but class `{}` has {}",
+                                class_pattern.fields.len(),
+                                if class_pattern.fields.len() == 1 {
+                                    ""
+                                } else {
+                                    "s"
+                                },
+                                class.name.name,
+                                fields.len()
+                            ),
+                            class_pattern.location.clone(),
+                        )
+                        .with_note(
+                            &format!("`{}` is declared here", class.name.name),
+                            Some(class.name.location.clone()),
+                        ),
+                    );
+                }
+
+                for (index, found) in class_pattern.fields.iter().enumerate() {
+                    let field: Option<&str> = fields.get(index).copied().flatten();
+                    self.check_pattern(found, field, &class_pattern.location);
+                }
+            }
+            Pattern::Literal(ref literal) => {
+                let found: &str = environment::literal_type(literal);
+                // Integer literals take the integer type of the subject
+                if let Some(expected) = builtin.filter(|expected| {
+                    *expected != found
+                        && !(integers::is_integer(expected) && integers::is_integer(found))
+                }) {
+                    self.mismatch(expected, found, &literal.location, subject);
+                }
+            }
+            Pattern::Range(ref range) => {
+                self.check_range(range);
+                if let Some(expected) =
+                    builtin.filter(|expected| !integers::FIXED_TYPES.contains(expected))
+                {
+                    self.mismatch(expected, "int", &range.location, subject);
+                }
+            }
+        }
+    }
+
+    /// Checks that a range pattern has integer bounds and is not empty.
+    fn check_range(&mut self, range: &RangePattern) {
+        for found in [&range.start, &range.end] {
+            if let RangeBound::Constant(ref name) = *found {
+                self.diagnostics.push(
+                    Diagnostic::error(
+                        "E0028",
+                        &format!("range bound `{}` is not a constant", name.name),
+                        name.location.clone(),
+                    )
+                    .with_label("expected an integer literal or constant"),
+                );
+                return;
+            }
+        }
+
+        match (bound(&range.start), bound(&range.end)) {
+            (Some(start), Some(end)) if start >= end => self.diagnostics.push(
+                Diagnostic::error("E0028", "empty range pattern", range.location.clone())
+                    .with_label(&format!("`{start} .. {end}` matches no value"))
+                    .with_note("the end of a range is excluded", None),
+            ),
+            (Some(_), Some(_)) => {}
+            _ => self.diagnostics.push(
+                Diagnostic::error(
+                    "E0028",
+                    "range patterns need integer bounds",
+                    range.location.clone(),
+                )
+                .with_label("expected integers"),
+            ),
+        }
+    }
+
+    /// Checks the patterns and cases of a match statement or expression and visits its cases.
+    fn check_match(&mut self, node: &'ast Match, expression: bool) {
+        self.visit_expression(&node.subject);
+
+        let subject: Option<&str> = self.environment.type_of(&node.subject);
+        for arm in &node.arms {
+            if let Some(ref pattern) = arm.pattern {
+                self.check_pattern(pattern, subject, node.subject.location());
+            }
+        }
+        self.check_cases(node, subject, expression);
+
+        for arm in &node.arms {
+            self.environment.enter(&[]);
+            if let Some(ref pattern) = arm.pattern {
+                self.environment
+                    .bind(pattern, subject, self.classes, self.names);
+            }
+            self.visit_match_arm(arm);
+            self.environment.exit();
+        }
+    }
+
+    /// Reports cases that are never reached and missing cases for `true`, `false` or the variants of an enum.
+    /// Missing cases are errors in match expressions, which have no value if no case matches, and warnings in match
+    /// statements.
+    #[allow(clippy::else_if_without_else, clippy::too_many_lines)]
+    fn check_cases(&mut self, node: &Match, type_name: Option<&'ast str>, expression: bool) {
+        let mut catch_all: Option<&MatchArm> = None;
+        let mut values: Vec<(Value, &Location)> = vec![];
+
+        for arm in &node.arms {
+            if let Some(previous) = catch_all {
+                self.diagnostics.push(
+                    Diagnostic::warning("E0026", "unreachable case", arm.location.clone())
+                        .with_note(
+                            "every value is already matched by this case",
+                            Some(previous.location.clone()),
+                        ),
+                );
+                continue;
+            }
+
+            let Some(ref pattern) = arm.pattern else {
+                catch_all = Some(arm);
+                continue;
+            };
+
+            let matched: Vec<Value> = Value::of(pattern);
+            let covering: Vec<&Location> = matched
+                .iter()
+                .filter_map(|value| {
+                    values
+                        .iter()
+                        .find(|&&(ref other, _)| value.is_covered_by(other))
+                        .map(|&(_, location)| location)
+                })
+                .collect();
+            if !matched.is_empty() && covering.len() == matched.len() {
+                self.diagnostics.push(
+                    Diagnostic::warning("E0026", "unreachable case", pattern.location().clone())
+                        .with_label("already matched by an earlier case")
+                        .with_note(
+                            "first matched here",
+                            covering.first().map(|found| (*found).clone()),
+                        ),
+                );
+            }
+
+            if arm.guard.is_none() {
+                if is_catch_all(arm, type_name) {
+                    catch_all = Some(arm);
+                }
+                values.extend(matched.into_iter().map(|value| (value, &arm.location)));
+            }
+        }
+
+        if is_exhaustive(node, type_name, self.classes) {
+            return;
+        }
+        let report: fn(&'static str, &str, Location) -> Diagnostic = if expression {
+            Diagnostic::error
+        } else {
+            Diagnostic::warning
+        };
+        // Only a match on a value known to be an enum reports missing variants
+        if let Some(missing) =
+            type_name.and_then(|_| missing_variants(node, type_name, self.classes))
+        {
+            let names: Vec<String> = missing
+                .iter()
+                .map(|variant| format!("`{}`", variant.name.name))
+                .collect();
+            self.diagnostics.push(
+                report(
+                    "E0027",
+                    &format!("match does not cover {}", names.join(", ")),
+                    node.location.clone(),
+                )
+                .with_label(&format!(
+                    "variant{} {} not covered",
+                    if names.len() == 1 { "" } else { "s" },
+                    names.join(", ")
+                ))
+                .with_note("add the missing cases or a `default` case", None),
+            );
+        } else if type_name == Some("bool") {
+            let missing: &str = if values
+                .iter()
+                .any(|&(ref value, _)| *value == Value::Bool(true))
+            {
+                "false"
+            } else if values
+                .iter()
+                .any(|&(ref value, _)| *value == Value::Bool(false))
+            {
+                "true"
+            } else {
+                "true` and `false"
+            };
+            self.diagnostics.push(
+                report(
+                    "E0027",
+                    &format!("match does not cover `{missing}`"),
+                    node.location.clone(),
+                )
+                .with_label(&format!("`{missing}` not covered"))
+                .with_note("add the missing cases or a `default` case", None),
+            );
+        } else if expression && type_name.is_some() {
+            self.diagnostics.push(
+                Diagnostic::error(
+                    "E0027",
+                    "match expression does not cover every value",
+                    node.location.clone(),
+                )
+                .with_label("has no value if no case matches")
+                .with_note("add a `default` case", None),
+            );
+        }
+    }
+}
+
+impl<'ast> Visitor<'ast> for PatternChecker<'_, 'ast> {
+    fn visit_block(&mut self, node: &'ast Block) {
+        self.environment.enter(&node.statements);
+        visit::walk_block(self, node);
+        self.environment.exit();
+    }
+
+    fn visit_function(&mut self, node: &'ast Function) {
+        self.environment.enter(&[]);
+        for parameter in &node.parameters {
+            self.environment
+                .declare(&parameter.name.name, Some(&parameter.type_annotation.name));
+        }
+        visit::walk_function(self, node);
+        self.environment.exit();
+    }
+
+    fn visit_lambda(&mut self, node: &'ast Lambda) {
+        self.environment.enter(&[]);
+        for parameter in &node.parameters {
+            self.environment
+                .declare(&parameter.name.name, Some(&parameter.type_annotation.name));
+        }
+        visit::walk_lambda(self, node);
+        self.environment.exit();
+    }
+
+    fn visit_catch(&mut self, node: &'ast Catch) {
+        self.environment.enter(&[]);
+        if let Some(ref binding) = node.binding {
+            self.environment
+                .declare(&binding.name, Some(&node.exception.name));
+        }
+        visit::walk_catch(self, node);
+        self.environment.exit();
+    }
+
+    fn visit_for(&mut self, node: &'ast For) {
+        self.visit_for_iterable(&node.iterable);
+        self.environment.enter(&[]);
+        self.environment.declare_loop_variable(node);
+        self.visit_block(&node.body);
+        self.environment.exit();
+    }
+
+    fn visit_variable_declaration(&mut self, node: &'ast VariableDeclaration) {
+        visit::walk_variable_declaration(self, node);
+        self.environment.declare_variable(node);
+    }
+
+    fn visit_expression(&mut self, node: &'ast Expression) {
+        match *node {
+            Expression::Match(ref inner) => self.check_match(inner, true),
+            _ => visit::walk_expression(self, node),
+        }
+    }
+
+    fn visit_match(&mut self, node: &'ast Match) {
+        self.check_match(node, false);
+    }
+}
@@ -0,0 +1,96 @@
+//! Declarations available in every program, like the built-in exception classes.
+// I Language prelude.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use parser::ast::Program;
+
+
+/////////////
+// PRELUDE //
+/////////////
+
+/// The name of the file the prelude declarations are located in.
+pub const FILE: &str = "<prelude>";
+
+/// The name of the base class of all exceptions.
+pub const EXCEPTION: &str = "Exception";
+
+/// The name of the interface of the values a `for` loop produces one at a time.
+pub const ITERATOR: &str = "Iterator";
+
+/// The name of the interface of the values a `for` loop can iterate over.
+pub const ITERABLE: &str = "Iterable";
+
+/// The names of the built-in types.
+pub const BUILT_IN_TYPES: [&str; 17] = [
+    "int", "str", "bool", "none", "list", "dict", "tuple", "function", "i8", "i16", "i32", "i64",
+    "u8", "u16", "u32", "u64", "bigint",
+];
+
+/// The names of the built-in functions.
+pub const BUILT_IN_FUNCTIONS: [&str; 15] = [
+    "print", "input", "time", "int", "str", "bool", "i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64",
+    "bigint",
+];
+
+/// The source code of the prelude.
+const SOURCE: &str = include_str!("prelude.il");
+
+/// Returns the parsed prelude. It is parsed once, the first time it is needed.
+///
+/// # Returns
+///
+/// The prelude program, containing the built-in exception classes (`Exception`, `TypeError`, `ValueError`,
+/// `IndexError`, `KeyError`, `ZeroDivisionError`, `OverflowError`, `RecursionError`, `EOFError`, `AssertionError` and
+/// `RuntimeError`) and the iteration interfaces (`Iterator` and `Iterable`).
+///
+/// # Panics
+///
+/// Panics if the prelude is not a valid program, which is covered by the tests.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::prelude;
+/// assert!(!prelude::program().statements.is_empty());
+/// ```
+#[inline]
+pub fn program() -> &'static Program {
+    static PRELUDE: Mutex<Option<&'static Program>> = Mutex::new(None);
+
+    let mut prelude: MutexGuard<Option<&'static Program>> =
+        PRELUDE.lock().unwrap_or_else(PoisonError::into_inner);
+    prelude.get_or_insert_with(|| {
+        let tokens: Vec<lexer::tokens::token::Token> =
+            lexer::lex::lex(SOURCE, FILE).expect("The prelude could not be lexed");
+        Box::leak(Box::new(
+            parser::parse::parse(&tokens).expect("The prelude could not be parsed"),
+        ))
+    })
+}
@@ -0,0 +1,919 @@
+//! Resolves names: links every identifier to its declaration and reports undefined, duplicate and shadowed names.
+// I Language name resolution.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use std::collections::HashMap;
+
+use crate::classes::ClassTable;
+use crate::contracts;
+use crate::diagnostic::Diagnostic;
+use crate::generics;
+use crate::modules::{Imports, Item, Module};
+use crate::prelude;
+
+use lexer::tokens::token::Location;
+use parser::ast::{
+    Block, Catch, Class, ClassPattern, Expression, Field, For, Function, Identifier, Lambda,
+    MatchArm, Member, Parameter, Pattern, Program, RangeBound, Statement, TypeAnnotation,
+    TypeParameter, VariableDeclaration,
+};
+use parser::visit::{self, Visitor};
+
+
+/////////////
+// ADDRESS //
+/////////////
+
+/// Returns the address of a node, the key of the tables that record what is known about a node.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::resolve::address;
+/// let name = String::from("goal");
+/// assert_eq!(address(&name), address(&name));
+/// ```
+#[inline]
+pub const fn address<T>(node: &T) -> *const T {
+    node
+}
+
+
+/////////////////
+// DECLARATION //
+/////////////////
+
+/// What an identifier refers to.
+#[derive(Clone, Copy, Debug)]
+pub enum Declaration<'ast> {
+    /// A built-in function, e.g. `print`.
+    BuiltIn(&'static str),
+    /// A class, declared in the program, imported or built-in.
+    Class(&'ast Class),
+    /// A function, declared in the program or imported.
+    Function(&'ast Function),
+    /// A module bound with `import`.
+    Module(&'ast Module),
+    /// A parameter of a function, method or constructor.
+    Parameter(&'ast Parameter),
+    /// A name bound by a pattern or a `catch` clause.
+    Binding(&'ast Identifier),
+    /// `this` or `super` in a method, constructor or field of a class.
+    This(&'ast Class),
+    /// A variable declared with a type, e.g. `int goal = 3;`.
+    Variable(&'ast VariableDeclaration),
+    /// The value a function returns, named `result` in its `ensures` clauses.
+    Result(&'ast Function),
+}
+
+impl Declaration<'_> {
+    /// Returns the location of the declared name. Built-in functions have no location.
+    #[inline]
+    pub const fn location(&self) -> Option<&Location> {
+        match *self {
+            Self::BuiltIn(_) => None,
+            Self::Class(class) | Self::This(class) => Some(&class.name.location),
+            Self::Function(function) | Self::Result(function) => Some(&function.name.location),
+            Self::Module(module) => Some(&module.program.location),
+            Self::Parameter(parameter) => Some(&parameter.name.location),
+            Self::Binding(identifier) => Some(&identifier.location),
+            Self::Variable(variable) => Some(&variable.name.location),
+        }
+    }
+
+    /// Returns whether the declaration is a variable, parameter or binding, which can be shadowed.
+    #[inline]
+    pub const fn is_variable(&self) -> bool {
+        matches!(
+            *self,
+            Self::Parameter(_) | Self::Binding(_) | Self::Variable(_)
+        )
+    }
+
+    /// Returns a short description of the declaration, e.g. "variable".
+    #[inline]
+    pub const fn kind(&self) -> &'static str {
+        match *self {
+            Self::BuiltIn(_) => "built-in function",
+            Self::Class(_) => "class",
+            Self::Function(_) => "function",
+            Self::Module(_) => "module",
+            Self::Parameter(_) => "parameter",
+            Self::Binding(_) | Self::Variable(_) => "variable",
+            Self::This(_) => "object",
+            Self::Result(_) => "result",
+        }
+    }
+}
+
+
+////////////////
+// RESOLUTION //
+////////////////
+
+/// The result of resolving the names of a program.
+#[derive(Clone, Debug, Default)]
+#[allow(clippy::partial_pub_fields)]
+pub struct Resolution<'ast> {
+    /// The declarations of the resolved identifiers, keyed by the address of the identifier.
+    declarations: HashMap<*const Identifier, Declaration<'ast>>,
+    /// The variables each lambda captures, keyed by the address of the lambda.
+    captures: HashMap<*const Lambda, Vec<&'ast str>>,
+    /// The diagnostics found while resolving.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl<'ast> Resolution<'ast> {
+    /// Returns the declaration an identifier used in an expression or a constant used in a pattern refers to.
+    /// Returns `None` for undefined names and for identifiers that are not uses, like declared names.
+    #[inline]
+    pub fn declaration(&self, identifier: &Identifier) -> Option<Declaration<'ast>> {
+        self.declarations.get(&address(identifier)).copied()
+    }
+
+    /// Returns the names of the variables and functions of the enclosing functions and blocks a lambda uses, in the
+    /// order of their first use. The lambda shares them with the code around it. Names of the module are not
+    /// captured.
+    #[inline]
+    pub fn captures(&self, lambda: &Lambda) -> &[&'ast str] {
+        self.captures
+            .get(&address(lambda))
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Resolves all names used in a program.
+///
+/// Blocks, functions, lambdas, classes and the module each open a scope. Functions and classes are visible in their
+/// whole scope, variables after their declaration. Functions only see their own names and the names of the module,
+/// not the variables of the blocks they are declared in. Lambdas see and capture those variables.
+///
+/// # Parameters
+///
+/// - `program`: The program to resolve.
+/// - `imports`: The names the program imports from other modules.
+///
+/// # Returns
+///
+/// The declarations of all resolved identifiers and diagnostics for undefined names and types, duplicate
+/// declarations, shadowed variables and uses of `this` or `super` outside of classes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::{modules::Imports, resolve};
+/// let tokens = lexer::lex::lex("int guess = 0; print(gues);", "<stdin>").unwrap();
+/// let program = parser::parse::parse(&tokens).unwrap();
+/// let resolution = resolve::resolve(&program, &Imports::default());
+///
+/// assert_eq!(resolution.diagnostics[0].code, "E0038");
+/// assert_eq!(resolution.diagnostics[0].notes[0].message, "a variable with a similar name exists: `guess`");
+/// ```
+#[inline]
+#[allow(clippy::iter_over_hash_type)]
+pub fn resolve<'ast>(program: &'ast Program, imports: &Imports<'ast>) -> Resolution<'ast> {
+    let classes: ClassTable = ClassTable::new(program, imports);
+    let mut resolver: Resolver = Resolver {
+        classes: &classes,
+        scopes: vec![],
+        globals: HashMap::new(),
+        class: None,
+        type_parameters: vec![],
+        lambdas: vec![],
+        resolution: Resolution::default(),
+    };
+
+    let mut built_ins: Scope = Scope::new(ScopeKind::Module);
+    for name in prelude::BUILT_IN_FUNCTIONS {
+        built_ins.names.insert(name, Declaration::BuiltIn(name));
+    }
+    for statement in &prelude::program().statements {
+        if let Statement::Class(ref class) = *statement {
+            built_ins
+                .names
+                .insert(&class.name.name, Declaration::Class(class));
+        }
+    }
+    resolver.scopes.push(built_ins);
+
+    let mut module: Scope = Scope::new(ScopeKind::Module);
+    for (&name, &found) in &imports.modules {
+        module.names.insert(name, Declaration::Module(found));
+    }
+    for (&name, item) in &imports.items {
+        module.names.insert(
+            name,
+            match *item {
+                Item::Class(class) => Declaration::Class(class),
+                Item::Function(function) => Declaration::Function(function),
+            },
+        );
+    }
+    resolver.scopes.push(module);
+    resolver.hoist(&program.statements);
+
+    for statement in &program.statements {
+        if let Statement::VariableDeclaration(ref variable) = *statement {
+            resolver
+                .globals
+                .entry(&variable.name.name)
+                .or_insert(Declaration::Variable(variable));
+        }
+    }
+
+    resolver.visit_program(program);
+    resolver.resolution
+}
+
+/// Resolves all names used in a program and returns the diagnostics found, see [`resolve`].
+///
+/// # Parameters
+///
+/// - `program`: The program to check.
+/// - `imports`: The names the program imports from other modules.
+///
+/// # Returns
+///
+/// The diagnostics found, in source order.
+#[inline]
+pub fn check<'ast>(program: &'ast Program, imports: &Imports<'ast>) -> Vec<Diagnostic> {
+    resolve(program, imports).diagnostics
+}
+
+
+/////////////
+// HELPERS //
+/////////////
+
+/// Returns the edit distance between two names: the number of characters to insert, delete or replace, or pairs of
+/// adjacent characters to swap.
+#[allow(clippy::indexing_slicing)]
+fn distance(first: &str, second: &str) -> usize {
+    let first: Vec<char> = first.chars().collect();
+    let second: Vec<char> = second.chars().collect();
+    let mut distances: Vec<Vec<usize>> = vec![vec![0; second.len() + 1]; first.len() + 1];
+
+    for (index, row) in distances.iter_mut().enumerate() {
+        row[0] = index;
+    }
+    for (index, cell) in distances[0].iter_mut().enumerate() {
+        *cell = index;
+    }
+
+    for row in 1..=first.len() {
+        for column in 1..=second.len() {
+            let cost: usize = usize::from(first[row - 1] != second[column - 1]);
+            let mut found: usize = (distances[row - 1][column - 1] + cost)
+                .min(distances[row - 1][column] + 1)
+                .min(distances[row][column - 1] + 1);
+            if row > 1
+                && column > 1
+                && first[row - 1] == second[column - 2]
+                && first[row - 2] == second[column - 1]
+            {
+                found = found.min(distances[row - 2][column - 2] + 1);
+            }
+            distances[row][column] = found;
+        }
+    }
+
+    distances[first.len()][second.len()]
+}
+
+/// Returns the candidate most similar to a name, if one is similar enough to be a likely typo.
+#[allow(clippy::integer_division, clippy::integer_division_remainder_used)]
+fn suggestion<'name>(
+    name: &str,
+    candidates: impl Iterator<Item = &'name str>,
+) -> Option<&'name str> {
+    let limit: usize = (name.chars().count() / 3).max(1);
+
+    candidates
+        .filter(|candidate| *candidate != name)
+        .map(|candidate| (distance(name, candidate), candidate))
+        .filter(|&(found, _)| found <= limit)
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+
+//////////////
+// RESOLVER //
+//////////////
+
+/// The different kinds of scopes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ScopeKind {
+    /// The built-in names or the top level of the module.
+    Module,
+    /// The parameters of a function, or the names visible in a field value.
+    Function,
+    /// The parameters of a lambda. Unlike functions, lambdas see the scopes around them.
+    Lambda,
+    /// A block, a case of a match or a `catch` clause.
+    Block,
+}
+
+/// The names declared in a scope.
+struct Scope<'ast> {
+    /// The kind of the scope.
+    kind: ScopeKind,
+    /// The declared names.
+    names: HashMap<&'ast str, Declaration<'ast>>,
+}
+
+impl Scope<'_> {
+    /// Creates an empty scope.
+    fn new(kind: ScopeKind) -> Self {
+        Self {
+            kind,
+            names: HashMap::new(),
+        }
+    }
+}
+
+/// A visitor resolving the names of a program.
+struct Resolver<'table, 'ast> {
+    /// The classes visible in the program.
+    classes: &'table ClassTable<'ast>,
+    /// The open scopes: the built-in names, the module and the nested scopes, innermost last.
+    scopes: Vec<Scope<'ast>>,
+    /// All variables declared at the top level of the module. Functions can use them before their declaration,
+    /// since functions are usually called after the top level declared its variables.
+    globals: HashMap<&'ast str, Declaration<'ast>>,
+    /// The class whose members are visited, if any.
+    class: Option<&'ast Class>,
+    /// The type parameters of the visited classes and functions, innermost last.
+    type_parameters: Vec<&'ast TypeParameter>,
+    /// The lambdas whose bodies are visited with the index of their scope, innermost last.
+    lambdas: Vec<(usize, &'ast Lambda)>,
+    /// The resolution being built.
+    resolution: Resolution<'ast>,
+}
+
+impl<'ast> Resolver<'_, 'ast> {
+    /// Returns the scopes visible from the innermost scope with their index, innermost first.
+    /// Inside functions, the scopes between the function and the module are skipped.
+    fn visible(&self) -> impl Iterator<Item = (usize, &Scope<'ast>)> {
+        let mut function: bool = false;
+        self.scopes
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(move |&(_, scope)| {
+                let visible: bool = !function || scope.kind == ScopeKind::Module;
+                function |= scope.kind == ScopeKind::Function;
+                visible
+            })
+    }
+
+    /// Returns whether the innermost scope is inside a function or lambda.
+    fn in_function(&self) -> bool {
+        self.scopes
+            .iter()
+            .any(|scope| matches!(scope.kind, ScopeKind::Function | ScopeKind::Lambda))
+    }
+
+    /// Returns what a name refers to.
+    fn lookup(&self, name: &str) -> Option<Declaration<'ast>> {
+        let mut scopes = self.visible();
+        let module: Option<Declaration> =
+            scopes.find_map(|(_, scope)| scope.names.get(name).copied());
+
+        module.or_else(|| {
+            self.in_function()
+                .then(|| self.globals.get(name).copied())
+                .flatten()
+        })
+    }
+
+    /// Returns the names visible in the innermost scope.
+    fn names(&self) -> Vec<(&'ast str, Declaration<'ast>)> {
+        let mut names: Vec<(&str, Declaration)> = self
+            .visible()
+            .flat_map(|(_, scope)| scope.names.iter().map(|(&name, &found)| (name, found)))
+            .collect();
+        if self.in_function() {
+            names.extend(self.globals.iter().map(|(&name, &found)| (name, found)));
+        }
+
+        names
+    }
+
+    /// Declares the functions and classes declared directly in the statements in the innermost scope, including the
+    /// variants of enums.
+    fn hoist(&mut self, statements: &'ast [Statement]) {
+        for statement in statements {
+            match *statement {
+                Statement::Class(ref class) => {
+                    // Duplicate classes are reported by the class checks
+                    if let Some(scope) = self.scopes.last_mut() {
+                        scope
+                            .names
+                            .entry(&class.name.name)
+                            .or_insert(Declaration::Class(class));
+                    }
+                }
+                Statement::Function(ref function) => {
+                    self.declare(&function.name, Declaration::Function(function));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Declares a name in the innermost scope, reporting duplicates in the same scope and shadowed variables.
+    fn declare(&mut self, name: &'ast Identifier, declaration: Declaration<'ast>) {
+        if name.name == "_" {
+            return;
+        }
+
+        let previous: Option<Declaration> = self
+            .scopes
+            .last()
+            .and_then(|scope| scope.names.get(name.name.as_str()).copied());
+        if let Some(previous) = previous {
+            self.resolution.diagnostics.push(
+                Diagnostic::error(
+                    "E0040",
+                    &format!("`{}` is declared more than once in this scope", name.name),
+                    name.location.clone(),
+                )
+                .with_label("declared again here")
+                .with_note(
+                    &format!("the {} `{}` is declared here", previous.kind(), name.name),
+                    previous.location().cloned(),
+                ),
+            );
+            return;
+        }
+
+        let shadowed: Option<Declaration> = self
+            .lookup(&name.name)
+            .filter(|found| declaration.is_variable() && found.is_variable());
+        if let Some(shadowed) = shadowed {
+            self.resolution.diagnostics.push(
+                Diagnostic::warning(
+                    "E0041",
+                    &format!(
+                        "`{}` shadows a {} with the same name",
+                        name.name,
+                        shadowed.kind()
+                    ),
+                    name.location.clone(),
+                )
+                .with_label("shadows the outer declaration")
+                .with_note(
+                    &format!("the shadowed {} is declared here", shadowed.kind()),
+                    shadowed.location().cloned(),
+                ),
+            );
+        }
+
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.names.insert(&name.name, declaration);
+        }
+    }
+
+    /// Resolves an identifier used in an expression.
+    #[allow(clippy::else_if_without_else)]
+    fn resolve(&mut self, identifier: &'ast Identifier) {
+        let name: &str = &identifier.name;
+        if name == "this" || name == "super" {
+            let class: Option<&Class> = self
+                .class
+                .filter(|class| self.in_function() && (name == "this" || !class.bases.is_empty()));
+            match class {
+                Some(class) => {
+                    self.resolution
+                        .declarations
+                        .insert(address(identifier), Declaration::This(class));
+                }
+                None => self.resolution.diagnostics.push(
+                    Diagnostic::error(
+                        "E0042",
+                        &if self.class.is_some() && name == "super" {
+                            "`super` is used in a class without a base class".to_owned()
+                        } else {
+                            format!("`{name}` is used outside of a method")
+                        },
+                        identifier.location.clone(),
+                    )
+                    .with_label("not available here"),
+                ),
+            }
+            return;
+        }
+
+        if let Some(declaration) = self.lookup(name) {
+            self.resolution
+                .declarations
+                .insert(address(identifier), declaration);
+            self.capture(&identifier.name);
+            return;
+        }
+
+        let mut diagnostic: Diagnostic = Diagnostic::error(
+            "E0038",
+            &format!("cannot find `{name}` in this scope"),
+            identifier.location.clone(),
+        )
+        .with_label("not found in this scope");
+
+        let names: Vec<(&str, Declaration)> = self.names();
+        let field: bool = self.class.map_or(false, |class| {
+            self.classes.find_field(class, name).is_some()
+        });
+        if field {
+            diagnostic = diagnostic.with_note(
+                &format!("use `this.{name}` to access the field of the object"),
+                None,
+            );
+        } else if let Some(similar) = suggestion(name, names.iter().map(|&(found, _)| found)) {
+            let declaration: Option<Declaration> = names
+                .iter()
+                .find(|&&(found, _)| found == similar)
+                .map(|&(_, found)| found);
+            diagnostic = diagnostic.with_note(
+                &format!(
+                    "a {} with a similar name exists: `{similar}`",
+                    declaration.map_or("name", |found| found.kind())
+                ),
+                declaration.and_then(|found| found.location().cloned()),
+            );
+        }
+
+        self.resolution.diagnostics.push(diagnostic);
+    }
+
+    /// Resolves the variant a member of an enum names, e.g. `Circle` in `Shape.Circle`, and reports unknown variants.
+    fn variant(&mut self, member: &'ast Member) {
+        let Expression::Identifier(ref target) = *member.target else {
+            return;
+        };
+        let Some(Declaration::Class(enumeration)) = self.resolution.declaration(target) else {
+            return;
+        };
+        if !enumeration.enumeration {
+            return;
+        }
+
+        if let Some(variant) = self.classes.variant(member) {
+            self.resolution
+                .declarations
+                .insert(address(&member.member), Declaration::Class(variant));
+            return;
+        }
+
+        let name: &str = &member.member.name;
+        let mut diagnostic: Diagnostic = Diagnostic::error(
+            "E0038",
+            &format!("cannot find variant `{name}` in enum `{}`", target.name),
+            member.member.location.clone(),
+        )
+        .with_label("not a variant of the enum");
+        let variants = enumeration.variants.iter().map(|variant| {
+            variant
+                .name
+                .name
+                .rsplit('.')
+                .next()
+                .unwrap_or(&variant.name.name)
+        });
+        if let Some(similar) = suggestion(name, variants) {
+            diagnostic = diagnostic.with_note(
+                &format!(
+                    "a variant with a similar name exists: `{}.{similar}`",
+                    target.name
+                ),
+                None,
+            );
+        }
+        self.resolution.diagnostics.push(diagnostic);
+    }
+
+    /// Records a name declared in a function or block and used inside lambdas as captured by every lambda between
+    /// its scope and the use. Nested lambdas capture it too, so they can pass it on to the inner ones.
+    fn capture(&mut self, name: &'ast str) {
+        let Some((index, scope)) = self
+            .visible()
+            .find(|&(_, scope)| scope.names.contains_key(name))
+        else {
+            return;
+        };
+        if scope.kind == ScopeKind::Module {
+            return;
+        }
+
+        for &(depth, lambda) in &self.lambdas {
+            if depth <= index {
+                continue;
+            }
+            let captures: &mut Vec<&str> =
+                self.resolution.captures.entry(address(lambda)).or_default();
+            if !captures.contains(&name) {
+                captures.push(name);
+            }
+        }
+    }
+
+    /// Declares the names bound by a pattern. Alternatives may bind the same names.
+    fn bind(&mut self, pattern: &'ast Pattern, alternative: bool) {
+        match *pattern {
+            Pattern::Alternative(ref node) => {
+                for found in &node.patterns {
+                    self.bind(found, true);
+                }
+            }
+            Pattern::Binding(ref node) => {
+                if let Some(ref annotation) = node.type_annotation {
+                    self.visit_type_annotation(annotation);
+                }
+
+                // A name of a constant matches the value of the constant instead of binding a new name
+                let constant: Option<Declaration> = self.lookup(&node.name.name).filter(|found| {
+                    node.type_annotation.is_none()
+                        && matches!(*found, Declaration::Variable(variable) if variable.constant)
+                });
+                if let Some(constant) = constant {
+                    self.resolution
+                        .declarations
+                        .insert(address(&node.name), constant);
+                    return;
+                }
+
+                let declared: bool = self.scopes.last().map_or(false, |scope| {
+                    scope.names.contains_key(node.name.name.as_str())
+                });
+                if !(alternative && declared) {
+                    self.declare(&node.name, Declaration::Binding(&node.name));
+                }
+            }
+            Pattern::Class(ref node) => self.visit_class_pattern_fields(node, alternative),
+            Pattern::Range(ref node) => {
+                for bound in [&node.start, &node.end] {
+                    if let RangeBound::Constant(ref name) = *bound {
+                        self.resolve(name);
+                    }
+                }
+            }
+            Pattern::Literal(_) => {}
+        }
+    }
+
+    /// Declares the names bound by the fields of a class pattern. The class itself is checked by the pattern checks.
+    fn visit_class_pattern_fields(&mut self, node: &'ast ClassPattern, alternative: bool) {
+        for found in &node.fields {
+            self.bind(found, alternative);
+        }
+    }
+
+    /// Makes type parameters visible, reporting duplicate names, and resolves their bounds.
+    #[allow(clippy::indexing_slicing)]
+    fn declare_type_parameters(&mut self, parameters: &'ast [TypeParameter]) {
+        for (index, parameter) in parameters.iter().enumerate() {
+            if let Some(bound) = parameter.bound.as_ref() {
+                self.visit_type_annotation(bound);
+            }
+
+            if let Some(first) = parameters[..index]
+                .iter()
+                .find(|found| found.name.name == parameter.name.name)
+            {
+                self.resolution.diagnostics.push(
+                    Diagnostic::error(
+                        "E0040",
+                        &format!(
+                            "`{}` is declared more than once in this scope",
+                            parameter.name.name
+                        ),
+                        parameter.name.location.clone(),
+                    )
+                    .with_label("declared again here")
+                    .with_note(
+                        &format!("the type parameter `{}` is declared here", first.name.name),
+                        Some(first.name.location.clone()),
+                    ),
+                );
+            }
+            self.type_parameters.push(parameter);
+        }
+    }
+
+    /// Resolves the parameters and body of a function in a new function scope.
+    fn function(&mut self, node: &'ast Function) {
+        let length: usize = self.type_parameters.len();
+        self.declare_type_parameters(&node.type_parameters);
+        if let Some(ref return_type) = node.return_type {
+            self.visit_type_annotation(return_type);
+        }
+
+        self.scopes.push(Scope::new(ScopeKind::Function));
+        self.parameters(&node.parameters);
+        for condition in &node.requires {
+            self.visit_expression(condition);
+        }
+        // The `ensures` clauses see the returned value, the body does not
+        self.scopes.push(Scope::new(ScopeKind::Block));
+        if let Some(scope) = self.scopes.last_mut() {
+            scope
+                .names
+                .insert(contracts::RESULT, Declaration::Result(node));
+        }
+        for condition in &node.ensures {
+            self.visit_expression(condition);
+        }
+        self.scopes.pop();
+        if let Some(ref body) = node.body {
+            self.visit_block(body);
+        }
+        self.scopes.pop();
+        self.type_parameters.truncate(length);
+    }
+
+    /// Resolves the types and default values of parameters and declares them in the innermost scope.
+    fn parameters(&mut self, parameters: &'ast [Parameter]) {
+        for parameter in parameters {
+            self.visit_type_annotation(&parameter.type_annotation);
+            if let Some(ref default) = parameter.default {
+                self.visit_expression(default);
+            }
+
+            // Duplicate parameters are reported by the function checks
+            let duplicate: bool = self.scopes.last().map_or(false, |scope| {
+                scope.names.contains_key(parameter.name.name.as_str())
+            });
+            if !duplicate {
+                self.declare(&parameter.name, Declaration::Parameter(parameter));
+            }
+        }
+    }
+}
+
+impl<'ast> Visitor<'ast> for Resolver<'_, 'ast> {
+    fn visit_block(&mut self, node: &'ast Block) {
+        self.scopes.push(Scope::new(ScopeKind::Block));
+        self.hoist(&node.statements);
+        visit::walk_block(self, node);
+        self.scopes.pop();
+    }
+
+    fn visit_catch(&mut self, node: &'ast Catch) {
+        // The exception class is checked by the exception checks
+        self.scopes.push(Scope::new(ScopeKind::Block));
+        if let Some(ref binding) = node.binding {
+            self.declare(binding, Declaration::Binding(binding));
+        }
+        self.visit_block(&node.body);
+        self.scopes.pop();
+    }
+
+    fn visit_class(&mut self, node: &'ast Class) {
+        // Base classes are checked by the class checks
+        let outer: Option<&Class> = self.class.replace(node);
+        let length: usize = self.type_parameters.len();
+        self.declare_type_parameters(&node.type_parameters);
+        for member in &node.members {
+            self.visit_class_member(member);
+        }
+        self.type_parameters.truncate(length);
+        self.class = outer;
+    }
+
+    fn visit_class_pattern(&mut self, node: &'ast ClassPattern) {
+        for found in &node.fields {
+            self.visit_pattern(found);
+        }
+    }
+
+    fn visit_expression(&mut self, node: &'ast Expression) {
+        match *node {
+            Expression::Identifier(ref identifier) => self.resolve(identifier),
+            Expression::Member(ref member) => {
+                self.visit_expression(&member.target);
+                self.variant(member);
+            }
+            _ => visit::walk_expression(self, node),
+        }
+    }
+
+    fn visit_field(&mut self, node: &'ast Field) {
+        self.visit_type_annotation(&node.type_annotation);
+        if let Some(ref value) = node.value {
+            self.scopes.push(Scope::new(ScopeKind::Function));
+            self.visit_expression(value);
+            self.scopes.pop();
+        }
+    }
+
+    fn visit_function(&mut self, node: &'ast Function) {
+        self.function(node);
+    }
+
+    fn visit_lambda(&mut self, node: &'ast Lambda) {
+        if let Some(ref return_type) = node.return_type {
+            self.visit_type_annotation(return_type);
+        }
+
+        self.scopes.push(Scope::new(ScopeKind::Lambda));
+        self.lambdas.push((self.scopes.len() - 1, node));
+        self.parameters(&node.parameters);
+        self.visit_lambda_body(&node.body);
+        self.lambdas.pop();
+        self.scopes.pop();
+    }
+
+    fn visit_for(&mut self, node: &'ast For) {
+        // The iterated values are evaluated before the loop variable exists
+        if let Some(ref annotation) = node.type_annotation {
+            self.visit_type_annotation(annotation);
+        }
+        self.visit_for_iterable(&node.iterable);
+        self.scopes.push(Scope::new(ScopeKind::Block));
+        self.declare(&node.variable, Declaration::Binding(&node.variable));
+        self.visit_block(&node.body);
+        self.scopes.pop();
+    }
+
+    fn visit_match_arm(&mut self, node: &'ast MatchArm) {
+        self.scopes.push(Scope::new(ScopeKind::Block));
+        if let Some(ref pattern) = node.pattern {
+            self.bind(pattern, false);
+        }
+        if let Some(ref guard) = node.guard {
+            self.visit_expression(guard);
+        }
+        self.visit_match_body(&node.body);
+        self.scopes.pop();
+    }
+
+    fn visit_type_annotation(&mut self, node: &'ast TypeAnnotation) {
+        for argument in &node.arguments {
+            self.visit_type_annotation(argument);
+        }
+
+        let name: &str = &node.name;
+        if prelude::BUILT_IN_TYPES.contains(&name)
+            || self.classes.get(name).is_some()
+            || generics::find(&self.type_parameters, name).is_some()
+        {
+            return;
+        }
+
+        let mut diagnostic: Diagnostic = Diagnostic::error(
+            "E0039",
+            &format!("unknown type `{name}`"),
+            node.location.clone(),
+        )
+        .with_label("not a built-in type or class");
+        let candidates = prelude::BUILT_IN_TYPES
+            .into_iter()
+            .chain(self.classes.names())
+            .chain(
+                self.type_parameters
+                    .iter()
+                    .map(|parameter| parameter.name.name.as_str()),
+            );
+        if let Some(similar) = suggestion(name, candidates) {
+            diagnostic = diagnostic.with_note(
+                &format!("a type with a similar name exists: `{similar}`"),
+                None,
+            );
+        }
+        self.resolution.diagnostics.push(diagnostic);
+    }
+
+    fn visit_variable_declaration(&mut self, node: &'ast VariableDeclaration) {
+        if let Some(ref annotation) = node.type_annotation {
+            self.visit_type_annotation(annotation);
+        }
+        if let Some(ref value) = node.value {
+            self.visit_expression(value);
+        }
+        self.declare(&node.name, Declaration::Variable(node));
+    }
+}
@@ -0,0 +1,2869 @@
+//! Checks the types of all expressions: declarations, assignments, operators, conditions and calls.
+// I Language type checking.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use std::collections::HashMap;
+
+use crate::classes::ClassTable;
+use crate::collections;
+use crate::diagnostic::Diagnostic;
+use crate::environment::literal_type;
+use crate::formats::Specification;
+use crate::functions;
+use crate::generics::{self, Substitution, TypeNames};
+use crate::integers;
+use crate::modules::{self, Imports, Item, Module};
+use crate::operators;
+use crate::prelude;
+use crate::resolve::{self, address, Declaration, Resolution};
+
+use lexer::tokens::mark::Mark;
+use lexer::tokens::token::{Location, TypeDefinition};
+use parser::ast::{
+    Argument, Assert, Assignment, Block, Call, Catch, Class, Expression, Field, For, ForIterable,
+    FormatField, FormatPart, Function, Identifier, If, Index, Lambda, LambdaBody, Match, MatchArm,
+    MatchBody, Member, Parameter, Pattern, Program, Return, Slice, Statement, TypeAnnotation,
+    TypeParameter, VariableDeclaration, While, Yield,
+};
+use parser::visit::{self, Visitor};
+
+
+/////////////
+// HELPERS //
+/////////////
+
+/// Returns the type of a binary operation on two values of the given types, `None` if the operator does not
+/// support them. `==` and `!=` compare values of any types. Arithmetic needs two values of the same integer type,
+/// bitwise operators and shifts are not supported by `bigint`.
+#[inline]
+pub fn binary_type(operator: &Mark, left: &str, right: &str) -> Option<&'static str> {
+    let integer: Option<&str> = integers::find(left).filter(|_| left == right);
+    match (operator, left, right) {
+        (&(Mark::Equal | Mark::NotEqual), _, _) => Some("bool"),
+        (
+            &(Mark::Add
+            | Mark::Subtract
+            | Mark::Multiply
+            | Mark::Divide
+            | Mark::Modulo
+            | Mark::Exponentiation),
+            _,
+            _,
+        ) if integer.is_some() => integer,
+        (
+            &(Mark::BitAnd | Mark::BitOr | Mark::BitXor | Mark::ShiftLeft | Mark::ShiftRight),
+            _,
+            _,
+        ) if integer.map_or(false, |found| found != integers::BIGINT) => integer,
+        (&Mark::Add, "str", "str") => Some("str"),
+        (&(Mark::Less | Mark::LessEqual | Mark::Greater | Mark::GreaterEqual), _, _)
+            if integer.is_some() =>
+        {
+            Some("bool")
+        }
+        (&(Mark::Less | Mark::LessEqual | Mark::Greater | Mark::GreaterEqual), "str", "str")
+        | (&(Mark::BitAnd | Mark::BitOr | Mark::BitXor | Mark::And | Mark::Or), "bool", "bool") => {
+            Some("bool")
+        }
+        _ => None,
+    }
+}
+
+/// Returns the type of a unary operation on a value of the given type, `None` if the operator does not support it.
+#[inline]
+pub fn unary_type(operator: &Mark, operand: &str) -> Option<&'static str> {
+    let integer: Option<&str> = integers::find(operand);
+    match (operator, operand) {
+        (&Mark::Bang, "bool") => Some("bool"),
+        (&(Mark::Subtract | Mark::Increase | Mark::Decrease), _) => integer,
+        (&Mark::BitNot, _) => integer.filter(|found| *found != integers::BIGINT),
+        _ => None,
+    }
+}
+
+/// Returns the binary operator of a compound assignment, e.g. [`Mark::Add`] for [`Mark::AddAssign`].
+#[inline]
+pub const fn compound_operator(mark: &Mark) -> Option<Mark> {
+    match *mark {
+        Mark::AddAssign => Some(Mark::Add),
+        Mark::SubtractAssign => Some(Mark::Subtract),
+        Mark::MultiplyAssign => Some(Mark::Multiply),
+        Mark::DivideAssign => Some(Mark::Divide),
+        Mark::ModuloAssign => Some(Mark::Modulo),
+        Mark::BitAndAssign => Some(Mark::BitAnd),
+        Mark::BitOrAssign => Some(Mark::BitOr),
+        Mark::BitXorAssign => Some(Mark::BitXor),
+        Mark::ShiftLeftAssign => Some(Mark::ShiftLeft),
+        Mark::ShiftRightAssign => Some(Mark::ShiftRight),
+        _ => None,
+    }
+}
+
+/// Returns the types a binary operator supports, used in the notes of unsupported operations.
+const fn supported_types(operator: &Mark) -> &'static str {
+    match *operator {
+        Mark::Add | Mark::Less | Mark::LessEqual | Mark::Greater | Mark::GreaterEqual => {
+            "two values of the same integer type, or `str` and `str`"
+        }
+        Mark::BitAnd | Mark::BitOr | Mark::BitXor => {
+            "two values of the same fixed-width integer type, or `bool` and `bool`"
+        }
+        Mark::ShiftLeft | Mark::ShiftRight => "two values of the same fixed-width integer type",
+        Mark::And | Mark::Or => "`bool` and `bool`",
+        _ => "two values of the same integer type",
+    }
+}
+
+/// Returns the arguments of a call with the parameters they are given for, matched like in
+/// [`functions::check_arguments`]. Arguments without a parameter are skipped.
+fn pairs<'ast>(
+    parameters: &'ast [Parameter],
+    node: &'ast Call,
+) -> Vec<(&'ast Parameter, &'ast Argument)> {
+    let mut position: usize = 0;
+
+    node.arguments
+        .iter()
+        .filter_map(|argument| {
+            let parameter: Option<&Parameter> = if let Some(ref name) = argument.name {
+                parameters
+                    .iter()
+                    .find(|parameter| parameter.name.name == name.name)
+            } else {
+                position += 1;
+                parameters.get(position - 1)
+            };
+            parameter.map(|found| (found, argument))
+        })
+        .collect()
+}
+
+/// Returns the return type of a built-in function. The conversions to integer types return their type.
+fn built_in_type(name: &str) -> &'static str {
+    match name {
+        "input" | "str" => "str",
+        "bool" => "bool",
+        "time" => "int",
+        _ => integers::find(name).unwrap_or("none"),
+    }
+}
+
+/// Returns whether an expression is the `none` literal.
+fn is_none(expression: &Expression) -> bool {
+    matches!(*expression, Expression::Literal(ref literal) if literal.kind == TypeDefinition::None)
+}
+
+/// Returns whether an expression is an empty list or dict literal, whose type follows from where it is used.
+fn is_empty_literal(expression: &Expression) -> bool {
+    match *expression {
+        Expression::List(ref list) => list.elements.is_empty(),
+        Expression::Dict(ref dict) => dict.entries.is_empty(),
+        _ => false,
+    }
+}
+
+
+///////////
+// TYPES //
+///////////
+
+/// The types of the expressions and variables of a program.
+#[derive(Clone, Debug)]
+#[allow(clippy::partial_pub_fields)]
+pub struct Types<'ast> {
+    /// The type names created while checking, see [`TypeNames`].
+    type_names: &'ast TypeNames,
+    /// The types of the typed expressions, keyed by the address of the expression.
+    expressions: HashMap<*const Expression, &'ast str>,
+    /// The inferred types of the variables declared with `var`, keyed by the address of the declaration.
+    variables: HashMap<*const VariableDeclaration, &'ast str>,
+    /// The declared names and the uses of names whose type is known, in the order they were checked.
+    names: Vec<(&'ast Identifier, &'ast str)>,
+    /// The diagnostics found while checking the types.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl<'ast> Types<'ast> {
+    /// Returns the type of an expression. Returns `None` if the type could not be determined, e.g. because the
+    /// expression uses an undefined name.
+    #[inline]
+    pub fn type_of(&self, expression: &Expression) -> Option<&'ast str> {
+        self.expressions.get(&address(expression)).copied()
+    }
+
+    /// Returns the type of a declared variable: its declared type, or the inferred type if it is declared with
+    /// `var`. Returns `None` if the type could not be inferred.
+    #[inline]
+    pub fn variable_type(&self, node: &'ast VariableDeclaration) -> Option<&'ast str> {
+        match node.type_annotation {
+            Some(ref annotation) => Some(self.type_names.annotation_type(annotation)),
+            None => self.variables.get(&address(node)).copied(),
+        }
+    }
+
+    /// Returns the type of the variable, parameter or name at a location, e.g. to show it when hovering over a
+    /// name in an editor. The location may point at any character of the name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use compiler::{generics::TypeNames, modules::Imports, types};
+    /// # use lexer::tokens::token::Location;
+    /// let tokens = lexer::lex::lex("var goal = 3 * 2;", "<stdin>").unwrap();
+    /// let program = parser::parse::parse(&tokens).unwrap();
+    /// let names = TypeNames::default();
+    /// let types = types::infer(&program, &Imports::default(), &names);
+    ///
+    /// let location = Location { file: "<stdin>".to_owned(), line: 1, column: 6 };
+    /// assert_eq!(types.type_at(&location), Some("int"));
+    /// ```
+    #[inline]
+    pub fn type_at(&self, location: &Location) -> Option<&'ast str> {
+        self.names
+            .iter()
+            .find(|&&(name, _)| {
+                name.location.file == location.file
+                    && name.location.line == location.line
+                    && (name.location.column..name.location.column + name.name.chars().count())
+                        .contains(&location.column)
+            })
+            .map(|&(_, found)| found)
+    }
+}
+
+/// Determines and checks the types of all expressions of a program.
+///
+/// Expressions whose type cannot be determined, e.g. uses of undefined names, are not reported again.
+/// Returned values and default values of parameters are checked by [`functions::check`].
+///
+/// # Parameters
+///
+/// - `program`: The program to check.
+/// - `imports`: The names the program imports from other modules.
+/// - `names`: The type names of the check, the types created by substitution are stored in it.
+///
+/// # Returns
+///
+/// The types of the expressions and diagnostics for mismatched types, unsupported operators, conditions that are
+/// not `bool`, arguments of the wrong type and invalid conversions.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::{generics::TypeNames, modules::Imports, types};
+/// let tokens = lexer::lex::lex("int goal = \"a\" - 1;", "<stdin>").unwrap();
+/// let program = parser::parse::parse(&tokens).unwrap();
+/// let names = TypeNames::default();
+/// let types = types::infer(&program, &Imports::default(), &names);
+///
+/// assert_eq!(types.diagnostics[0].code, "E0043");
+/// assert_eq!(types.diagnostics[0].message, "unsupported operand types for `-`: `str` and `int`");
+/// ```
+#[inline]
+pub fn infer<'ast>(
+    program: &'ast Program,
+    imports: &Imports<'ast>,
+    names: &'ast TypeNames,
+) -> Types<'ast> {
+    let classes: ClassTable = ClassTable::new(program, imports);
+    let resolution: Resolution = resolve::resolve(program, imports);
+    infer_with(program, names, &classes, &resolution)
+}
+
+/// Determines and checks the types of all expressions of a program like [`infer`], with the classes and the
+/// resolved names of the program already collected.
+///
+/// # Parameters
+///
+/// - `program`: The program to check.
+/// - `names`: The type names of the check, the types created by substitution are stored in it.
+/// - `classes`: The classes of the program, see [`ClassTable::new`].
+/// - `resolution`: The declarations of the names used in the program, see [`resolve::resolve`].
+///
+/// # Returns
+///
+/// The types of the expressions and the diagnostics found.
+#[inline]
+pub fn infer_with<'ast>(
+    program: &'ast Program,
+    names: &'ast TypeNames,
+    classes: &ClassTable<'ast>,
+    resolution: &Resolution<'ast>,
+) -> Types<'ast> {
+    let mut finder: SharedFinder = SharedFinder {
+        resolution,
+        globals: program
+            .statements
+            .iter()
+            .filter_map(|statement| match *statement {
+                Statement::VariableDeclaration(ref variable) => Some(address(&variable.name)),
+                _ => None,
+            })
+            .collect(),
+        functions: 0,
+        lambdas: vec![],
+        targets: vec![],
+    };
+    finder.visit_program(program);
+    let mut nones: NoneFinder = NoneFinder {
+        resolution,
+        variables: vec![],
+    };
+    nones.visit_program(program);
+    let mut checker: TypeChecker = TypeChecker {
+        names,
+        classes,
+        resolution,
+        bindings: HashMap::new(),
+        pending: vec![],
+        scope: vec![],
+        narrowed: vec![],
+        assigned: vec![],
+        shared: finder.targets,
+        nullable: nones.variables,
+        returns: vec![],
+        types: Types {
+            type_names: names,
+            expressions: HashMap::new(),
+            variables: HashMap::new(),
+            names: vec![],
+            diagnostics: vec![],
+        },
+    };
+    checker.visit_program(program);
+    checker.report_pending();
+
+    checker.types
+}
+
+/// Checks the types of all expressions of a program and returns the diagnostics found, see [`infer`].
+///
+/// # Parameters
+///
+/// - `program`: The program to check.
+/// - `imports`: The names the program imports from other modules.
+///
+/// # Returns
+///
+/// The diagnostics found, in source order of the checked nodes.
+#[inline]
+pub fn check<'ast>(program: &'ast Program, imports: &Imports<'ast>) -> Vec<Diagnostic> {
+    let names: TypeNames = TypeNames::default();
+    infer(program, imports, &names).diagnostics
+}
+
+
+//////////////////
+// TYPE CHECKER //
+//////////////////
+
+/// A visitor determining and checking the types of expressions.
+struct TypeChecker<'table, 'ast> {
+    /// The type names created while checking.
+    names: &'ast TypeNames,
+    /// The classes of the program.
+    classes: &'table ClassTable<'ast>,
+    /// The declarations of the names used in the program.
+    resolution: &'table Resolution<'ast>,
+    /// The types of the names bound by patterns and `catch` clauses, keyed by the address of the bound name.
+    bindings: HashMap<*const Identifier, &'ast str>,
+    /// The variables declared with `var` whose type could not be inferred from their initial value yet.
+    pending: Vec<&'ast VariableDeclaration>,
+    /// The type parameters of the visited classes and functions, innermost last.
+    scope: Vec<&'ast TypeParameter>,
+    /// The types of variables of nullable types narrowed by the checks for `none` that apply to the checked code,
+    /// keyed by the address of the declared name. Later entries replace earlier ones.
+    narrowed: Vec<(*const Identifier, &'ast str)>,
+    /// The variables of nullable types assigned so far, with their declared types, see [`Self::restore`].
+    assigned: Vec<(*const Identifier, &'ast str)>,
+    /// The variables assigned by functions and lambdas they are not declared in, see [`Self::forget_shared`].
+    shared: Vec<&'ast Identifier>,
+    /// The variables declared with `var` that are initialized with or assigned `none`, so their inferred types are
+    /// nullable.
+    nullable: Vec<*const VariableDeclaration>,
+    /// The return types of the visited functions and lambdas, innermost last.
+    returns: Vec<&'ast str>,
+    /// The types found so far.
+    types: Types<'ast>,
+}
+
+/// The result of applying an operator to a value whose class may overload it, see [`TypeChecker::overload`].
+enum Overload<'ast> {
+    /// The value is not an object, so its class cannot overload the operator.
+    NotObject,
+    /// The operator calls a method of the class of the object. The type of the result is unknown if the class does
+    /// not define the method or it cannot be used for the operator.
+    Method(Option<&'ast str>),
+}
+
+impl<'ast> TypeChecker<'_, 'ast> {
+    /// Returns whether a value of type `found` can be used where a value of type `expected` is expected.
+    ///
+    /// Only nullable types accept `none`, a nullable type accepts the values its type without `?` accepts and
+    /// other types do not accept values that may be `none`.
+    /// A class type accepts instances of the class and its subclasses.
+    /// A function type accepts functions whose parameters accept its parameter types and whose return type it accepts.
+    /// Unknown class types are accepted, since they are reported by the name resolution.
+    /// Instances of a generic class must have the same type arguments, a subclass the ones it passes to the class.
+    /// A type parameter only accepts itself, its values can be used as its bound.
+    fn is_compatible(&self, expected: &str, found: &str) -> bool {
+        if expected == found {
+            return true;
+        }
+        if generics::is_nullable(expected) {
+            return found == "none"
+                || self.is_compatible(generics::non_null(expected), generics::non_null(found));
+        }
+
+        let (expected_base, expected_arguments): (&str, Vec<&str>) = generics::split(expected);
+        let (found_base, found_arguments): (&str, Vec<&str>) = generics::split(found);
+        if generics::find(&self.scope, expected_base).is_some() {
+            return false;
+        }
+        if let Some(parameter) = generics::find(&self.scope, found_base) {
+            return parameter.bound.as_ref().map_or(false, |bound| {
+                generics::find(&self.scope, &bound.name).is_none()
+                    && self.is_compatible(expected, self.names.annotation_type(bound))
+            });
+        }
+        // A function accepts the parameters of the expected type and returns a value the expected type accepts
+        if let (
+            Some((expected_result, expected_parameters)),
+            Some((found_result, found_parameters)),
+        ) = (
+            expected_arguments.split_last(),
+            found_arguments.split_last(),
+        ) {
+            if expected_base == "function" && found_base == "function" {
+                return expected_parameters.len() == found_parameters.len()
+                    && expected_parameters
+                        .iter()
+                        .zip(found_parameters)
+                        .all(|(parameter, argument)| self.is_compatible(argument, parameter))
+                    && self.is_compatible(expected_result, found_result);
+            }
+        }
+        if expected_base == found_base {
+            return expected_arguments == found_arguments;
+        }
+
+        if prelude::BUILT_IN_TYPES.contains(&expected_base) || generics::is_nullable(found) {
+            return false;
+        }
+        if prelude::BUILT_IN_TYPES.contains(&found_base) {
+            // `none` is only accepted by unknown class types, which are reported by the name resolution
+            return found == "none" && self.classes.get(expected_base).is_none();
+        }
+
+        match (
+            self.classes.get(found_base),
+            self.classes.get(expected_base),
+        ) {
+            (Some(class), Some(base)) if expected_arguments.is_empty() => {
+                self.classes.is_subclass(class, base)
+            }
+            (Some(class), Some(base)) => {
+                // The type arguments of the base follow from the ones of the value, e.g. `Some<int>` is an
+                // `Option<int>` for `enum Option<T> { Some(T value) }`
+                self.classes.is_subclass(class, base) && {
+                    let substitution: Substitution = generics::inherited(
+                        self.names,
+                        self.classes,
+                        class,
+                        base,
+                        generics::class_substitution(class, self.names.intern(found)),
+                    );
+                    self.is_compatible(
+                        expected,
+                        self.names.substitute(self.own_type(base), &substitution),
+                    )
+                }
+            }
+            _ => true,
+        }
+    }
+
+    /// Returns whether a type or one of its type arguments has the wrong number of type arguments, e.g. `list` or
+    /// `int<str>`.
+    fn is_malformed(&self, type_name: &str) -> bool {
+        let (base, arguments): (&str, Vec<&str>) = generics::split(generics::non_null(type_name));
+        if arguments.iter().any(|argument| self.is_malformed(argument)) {
+            return true;
+        }
+        if matches!(base, "tuple" | "function") || generics::find(&self.scope, base).is_some() {
+            return false;
+        }
+
+        let count: usize = match self.classes.get(base) {
+            Some(class) => class.type_parameters.len(),
+            None if prelude::BUILT_IN_TYPES.contains(&base) => {
+                collections::type_parameters(base).len()
+            }
+            None => return false,
+        };
+        arguments.len() != count
+    }
+
+    /// Returns whether values of a type can be keys of a `dict`. Type parameters are accepted, the types they stand
+    /// for are not known here.
+    fn is_key(&self, type_name: &str) -> bool {
+        collections::is_key(type_name)
+            || generics::find(&self.scope, generics::non_null(type_name)).is_some()
+    }
+
+    /// Returns the class of a value of the given type and the type arguments of the class.
+    /// A value of a type parameter is an instance of the bound of the type parameter.
+    fn instance(&self, type_name: &'ast str) -> Option<(&'ast Class, Substitution<'ast>)> {
+        let (base, _): (&str, Vec<&str>) = generics::split(type_name);
+        if let Some(parameter) = generics::find(&self.scope, base) {
+            let bound: &TypeAnnotation = parameter.bound.as_ref()?;
+            let class: &Class = self.classes.get(&bound.name)?;
+            return Some((
+                class,
+                generics::class_substitution(class, self.names.annotation_type(bound)),
+            ));
+        }
+
+        let class: &Class = self.classes.get(base)?;
+        Some((class, generics::class_substitution(class, type_name)))
+    }
+
+    /// Returns the type of a value of a class inside its own methods, e.g. `Box<T>` in `class Box<T>`.
+    fn own_type(&self, class: &'ast Class) -> &'ast str {
+        if class.type_parameters.is_empty() {
+            return &class.name.name;
+        }
+
+        let parameters: Vec<&str> = class
+            .type_parameters
+            .iter()
+            .map(|parameter| parameter.name.name.as_str())
+            .collect();
+        self.names
+            .intern(&format!("{}<{}>", class.name.name, parameters.join(", ")))
+    }
+
+    /// Reports a value whose type does not match the expected type.
+    ///
+    /// # Parameters
+    ///
+    /// - `expected`: The expected type.
+    /// - `value`: The checked value, already visited.
+    /// - `reason`: The location of the code expecting the type, e.g. a type annotation.
+    /// - `note`: What the reason is, e.g. "because of this parameter".
+    fn expect(
+        &mut self,
+        expected: &str,
+        value: &Expression,
+        reason: Option<&Location>,
+        note: &str,
+    ) {
+        if let Some(diagnostics) = integers::check_literal(expected, value) {
+            self.types.diagnostics.extend(diagnostics);
+            return;
+        }
+        // A type with the wrong number of type arguments is reported by `generics::check`
+        if self.is_malformed(expected) {
+            return;
+        }
+        // The integer literals of a list literal take the element type of the expected list, e.g. `[1, 2]` is a
+        // `list<u8>` in `list<u8> bytes = [1, 2];`
+        if let Expression::List(ref list) = *value {
+            let (base, arguments): (&str, Vec<&str>) =
+                generics::split(generics::non_null(expected));
+            let element: Option<&str> = match (base, arguments.as_slice()) {
+                ("list", &[element]) if integers::is_integer(element) => Some(element),
+                _ => None,
+            };
+            let literals: bool = !list.elements.is_empty()
+                && list
+                    .elements
+                    .iter()
+                    .all(|found| integers::literal(found).is_some());
+            if let Some(element) = element.filter(|_| literals) {
+                for found in &list.elements {
+                    self.expect(element, found, reason, note);
+                }
+                self.record(value, Some(self.names.intern(generics::non_null(expected))));
+                return;
+            }
+        }
+        let Some(found) = self.types.type_of(value) else {
+            return;
+        };
+
+        // A value whose type arguments cannot be inferred on its own takes them from the expected type, e.g. `[]` is a
+        // `list<int>` in `list<int> items = [];` and `Nothing()` an `Option<int>` in `Option<int> none = Nothing();`
+        let (base, _): (&str, Vec<&str>) = generics::split(generics::non_null(expected));
+        let inferred: bool = match (self.classes.get(found), self.classes.get(base)) {
+            (Some(class), Some(expected_class)) => {
+                !class.type_parameters.is_empty() && self.classes.is_subclass(class, expected_class)
+            }
+            _ => is_empty_literal(value) && found == base,
+        };
+        if inferred {
+            self.record(value, Some(self.names.intern(generics::non_null(expected))));
+            return;
+        }
+
+        if !self.is_compatible(expected, found) {
+            self.types.diagnostics.push(
+                Diagnostic::error("E0023", "mismatched types", value.location().clone())
+                    .with_label(&format!("expected `{expected}`, found `{found}`"))
+                    .with_note(&format!("expected `{expected}` {note}"), reason.cloned()),
+            );
+        }
+    }
+
+    /// Checks that a condition has the type `bool`.
+    fn condition(&mut self, value: &'ast Expression) {
+        self.visit_expression(value);
+        self.expect("bool", value, None, "because conditions must be `bool`");
+    }
+
+    /// Records and returns the type of an expression.
+    fn record(
+        &mut self,
+        expression: &Expression,
+        type_name: Option<&'ast str>,
+    ) -> Option<&'ast str> {
+        if let Some(found) = type_name {
+            self.types.expressions.insert(address(expression), found);
+        }
+
+        type_name
+    }
+
+    /// Returns the type of a value the resolved name refers to, narrowed by the checks for `none` that apply.
+    fn declaration_type(&self, identifier: &'ast Identifier) -> Option<&'ast str> {
+        let key: Option<*const Identifier> = self.key(identifier);
+        self.narrowed
+            .iter()
+            .rev()
+            .find(|&&(name, _)| Some(name) == key)
+            .map(|&(_, found)| found)
+            .or_else(|| self.declared_type(identifier))
+    }
+
+    /// Returns the address of the declared name of the variable, parameter or binding a name refers to, used to
+    /// narrow its type.
+    fn key(&self, identifier: &'ast Identifier) -> Option<*const Identifier> {
+        match self.resolution.declaration(identifier)? {
+            Declaration::Variable(variable) => Some(address(&variable.name)),
+            Declaration::Parameter(parameter) => Some(address(&parameter.name)),
+            Declaration::Binding(binding) => Some(address(binding)),
+            _ => None,
+        }
+    }
+
+    /// Returns the declared type of a value the resolved name refers to.
+    fn declared_type(&self, identifier: &'ast Identifier) -> Option<&'ast str> {
+        match self.resolution.declaration(identifier)? {
+            Declaration::Variable(variable) => self.types.variable_type(variable),
+            Declaration::Parameter(parameter) => {
+                Some(self.names.annotation_type(&parameter.type_annotation))
+            }
+            Declaration::Binding(binding) => self.bindings.get(&address(binding)).copied(),
+            Declaration::This(class) if identifier.name == "this" => Some(self.own_type(class)),
+            Declaration::This(class) => self
+                .classes
+                .parent(class)
+                .and(class.bases.first())
+                .map(|annotation| self.names.annotation_type(annotation)),
+            Declaration::Function(function) => self.names.function_type(function),
+            Declaration::Result(function) => Some(self.names.return_type(function)),
+            Declaration::BuiltIn(_) | Declaration::Class(_) | Declaration::Module(_) => None,
+        }
+    }
+
+    /// Narrows the type of an assigned variable of a nullable type to the type of its new value: its type without
+    /// `none` if the value is never `none`, otherwise its declared type.
+    fn reassign(&mut self, identifier: &'ast Identifier, value: &Expression) {
+        let (Some(key), Some(declared)) = (self.key(identifier), self.declared_type(identifier))
+        else {
+            return;
+        };
+        if !generics::is_nullable(declared) {
+            return;
+        }
+
+        let never_none: bool = self.types.type_of(value).map_or(false, |found| {
+            found != "none" && !generics::is_nullable(found)
+        });
+        self.narrowed.push((
+            key,
+            if never_none {
+                generics::non_null(declared)
+            } else {
+                declared
+            },
+        ));
+        self.assigned.push((key, declared));
+    }
+
+    /// Returns the variables whose types are narrowed if a condition has the given truth value, e.g. `x` to `int`
+    /// for an `int? x` if `x != none` is true. Conditions combined with `&&`, `||` and `!` are followed.
+    fn facts(
+        &self,
+        condition: &'ast Expression,
+        truth: bool,
+    ) -> Vec<(*const Identifier, &'ast str)> {
+        let binary = match *condition {
+            Expression::Binary(ref binary) => binary,
+            Expression::Unary(ref unary) if unary.operator == Mark::Bang => {
+                return self.facts(&unary.operand, !truth);
+            }
+            _ => return vec![],
+        };
+
+        match binary.operator {
+            Mark::And if truth => {
+                let mut facts: Vec<(*const Identifier, &str)> = self.facts(&binary.left, true);
+                facts.extend(self.facts(&binary.right, true));
+                facts
+            }
+            Mark::Or if !truth => {
+                let mut facts: Vec<(*const Identifier, &str)> = self.facts(&binary.left, false);
+                facts.extend(self.facts(&binary.right, false));
+                facts
+            }
+            Mark::Equal | Mark::NotEqual if (binary.operator == Mark::NotEqual) == truth => {
+                let checked: Option<&Identifier> = match (&*binary.left, &*binary.right) {
+                    (
+                        &Expression::Identifier(ref identifier),
+                        &Expression::Literal(ref literal),
+                    )
+                    | (
+                        &Expression::Literal(ref literal),
+                        &Expression::Identifier(ref identifier),
+                    ) if literal.kind == TypeDefinition::None => Some(identifier),
+                    _ => None,
+                };
+                checked
+                    .and_then(|identifier| {
+                        Some((self.key(identifier)?, self.declaration_type(identifier)?))
+                    })
+                    .filter(|&(_, found)| generics::is_nullable(found))
+                    .map(|(key, found)| vec![(key, generics::non_null(found))])
+                    .unwrap_or_default()
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Drops the narrowed types found after the given lengths of [`Self::narrowed`] and [`Self::assigned`], e.g.
+    /// at the end of a block. The variables assigned since may be `none` again.
+    #[allow(clippy::indexing_slicing)]
+    fn restore(&mut self, narrowed: usize, assigned: usize) {
+        self.narrowed.truncate(narrowed);
+        let forgotten: Vec<(*const Identifier, &str)> = self.assigned[assigned..].to_vec();
+        self.narrowed.extend(forgotten);
+    }
+
+    /// Forgets the narrowed types of the variables of nullable types assigned in a list of statements, since the
+    /// statements may run again after the checks for `none` of a loop.
+    fn forget_assigned(&mut self, statements: &'ast [Statement]) {
+        let mut finder: AssignmentFinder = AssignmentFinder { targets: vec![] };
+        for statement in statements {
+            finder.visit_statement(statement);
+        }
+
+        for identifier in finder.targets {
+            if let (Some(key), Some(declared)) =
+                (self.key(identifier), self.declared_type(identifier))
+            {
+                if generics::is_nullable(declared) {
+                    self.narrowed.push((key, declared));
+                }
+            }
+        }
+    }
+
+    /// Forgets the narrowed types of the variables functions and lambdas assign outside of their own scope after a
+    /// call, since the called code may have set them to `none`. Built-in functions assign no variables.
+    fn forget_shared(&mut self, node: &Call) {
+        if let Expression::Identifier(ref identifier) = *node.callee {
+            if let Some(Declaration::BuiltIn(_)) = self.resolution.declaration(identifier) {
+                return;
+            }
+        }
+
+        let forgotten: Vec<(*const Identifier, &str)> = self
+            .shared
+            .iter()
+            .filter_map(|&identifier| {
+                let (key, declared): (*const Identifier, &str) =
+                    (self.key(identifier)?, self.declared_type(identifier)?);
+                (generics::is_nullable(declared)
+                    && self.declaration_type(identifier) != Some(declared))
+                .then_some((key, declared))
+            })
+            .collect();
+        self.narrowed.extend(&forgotten);
+        self.assigned.extend(forgotten);
+    }
+
+    /// Reports a value of a nullable type used in a way that needs a value, e.g. accessing a member, and returns
+    /// the type of its values that are not `none`.
+    ///
+    /// # Parameters
+    ///
+    /// - `found`: The type of the value.
+    /// - `value`: The value.
+    /// - `action`: How the value is used, e.g. "indexed".
+    fn dereference(&mut self, found: &'ast str, value: &Expression, action: &str) -> &'ast str {
+        if generics::is_nullable(found) {
+            self.types.diagnostics.push(
+                Diagnostic::error(
+                    "E0065",
+                    &format!("a value of type `{found}` may be `none`"),
+                    value.location().clone(),
+                )
+                .with_label(&format!("cannot be {action} while it may be `none`"))
+                .with_note(
+                    "check that the value is not `none` with `!= none` first, or use `?.` or `??`",
+                    None,
+                ),
+            );
+        }
+
+        generics::non_null(found)
+    }
+
+    /// Returns the type of the target of a member access without `none`, and whether the result may be `none`
+    /// because the member is accessed with `?.` on a value that may be `none`.
+    fn navigate(&mut self, found: &'ast str, member: &'ast Member) -> (&'ast str, bool) {
+        if member.safe {
+            (generics::non_null(found), generics::is_nullable(found))
+        } else {
+            let action: String = format!("accessed with `.{}`", member.member.name);
+            (self.dereference(found, &member.target, &action), false)
+        }
+    }
+
+    /// Checks a null coalescing `left ?? right` with already visited operands and returns its type: the type of
+    /// `left` without `none`, which may still be `none` if `right` may be.
+    fn coalesce(&mut self, left: &Expression, right: &Expression) -> Option<&'ast str> {
+        let found: &str = self.types.type_of(left)?;
+        if found == "none" {
+            return self.types.type_of(right);
+        }
+
+        let mut result: &str = generics::non_null(found);
+        if self.types.type_of(right).map_or(false, |other| {
+            other == "none" || generics::is_nullable(other)
+        }) {
+            result = self.names.nullable(result);
+        }
+        self.expect(
+            result,
+            right,
+            Some(left.location()),
+            "because the left side of `??` has this type",
+        );
+
+        Some(result)
+    }
+
+    /// Declares the types of the names bound by a pattern.
+    fn bind(&mut self, pattern: &'ast Pattern, type_name: Option<&'ast str>) {
+        match *pattern {
+            Pattern::Alternative(ref node) => {
+                for found in &node.patterns {
+                    self.bind(found, type_name);
+                }
+            }
+            Pattern::Binding(ref node) => {
+                let found: Option<&str> = node
+                    .type_annotation
+                    .as_ref()
+                    .map(|annotation| self.names.annotation_type(annotation))
+                    .or(type_name);
+                if let Some(found) = found {
+                    self.bindings.insert(address(&node.name), found);
+                }
+            }
+            Pattern::Class(ref node) => {
+                // The type arguments of the matched value apply if it is an instance of the class itself, or of
+                // the enum of a variant, since variants share the type parameters of their enum
+                let variant_of: Option<&Class> = self
+                    .classes
+                    .get(&node.class.name)
+                    .and_then(|class| self.classes.enumeration(class));
+                let substitution: Substitution = type_name
+                    .and_then(|found| self.instance(found))
+                    .filter(|&(class, _)| {
+                        class.name.name == node.class.name
+                            || variant_of
+                                .map_or(false, |enumeration| core::ptr::eq(enumeration, class))
+                    })
+                    .map(|(_, substitution)| substitution)
+                    .unwrap_or_default();
+                let fields: Vec<&str> = self
+                    .classes
+                    .get(&node.class.name)
+                    .map(|class| {
+                        self.classes
+                            .fields(class)
+                            .iter()
+                            .map(|field| {
+                                self.names.substitute(
+                                    self.names.annotation_type(&field.type_annotation),
+                                    &substitution,
+                                )
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                for (index, found) in node.fields.iter().enumerate() {
+                    self.bind(found, fields.get(index).copied());
+                }
+            }
+            Pattern::Literal(_) | Pattern::Range(_) => {}
+        }
+    }
+
+    /// Determines the type of an expression and checks its parts.
+    #[allow(clippy::cognitive_complexity, clippy::too_many_lines)]
+    fn expression(&mut self, node: &'ast Expression) -> Option<&'ast str> {
+        let found: Option<&str> = match *node {
+            Expression::Literal(ref literal) => Some(literal_type(literal)),
+            Expression::FormatString(ref format_string) => {
+                for part in &format_string.parts {
+                    if let FormatPart::Field(ref field) = *part {
+                        let found: Option<&str> = self.expression(&field.value);
+                        self.format_field(field, found);
+                    }
+                }
+                Some("str")
+            }
+            Expression::Identifier(ref identifier) => {
+                let found: Option<&str> = match self.resolution.declaration(identifier) {
+                    // Classes, built-in functions and modules can only be called or have their members accessed
+                    Some(
+                        declaration @ (Declaration::BuiltIn(_)
+                        | Declaration::Class(_)
+                        | Declaration::Module(_)),
+                    ) => {
+                        self.types.diagnostics.push(
+                            Diagnostic::error(
+                                "E0076",
+                                &format!(
+                                    "expected a value, found {} `{}`",
+                                    declaration.kind(),
+                                    identifier.name
+                                ),
+                                identifier.location.clone(),
+                            )
+                            .with_label("not a value"),
+                        );
+                        None
+                    }
+                    _ => self.declaration_type(identifier),
+                };
+                if let Some(found) = found {
+                    self.types.names.push((identifier, found));
+                }
+                found
+            }
+            Expression::Assignment(ref assignment) => {
+                let (target, value): (Option<&str>, Option<&str>) = match *assignment.target {
+                    // Assigning an element of an object calls its `set_index` method
+                    Expression::Index(ref index) if assignment.operator == Mark::Assign => {
+                        let target: Option<&str> = self.index(index, Some(&assignment.value));
+                        (
+                            self.record(&assignment.target, target),
+                            self.types.type_of(&assignment.value),
+                        )
+                    }
+                    _ => (
+                        self.expression(&assignment.target),
+                        self.expression(&assignment.value),
+                    ),
+                };
+                if let Expression::Index(ref index) = *assignment.target {
+                    self.immutable(index);
+                }
+                // A variable checked for `none` before accepts values of its declared type again
+                let target: Option<&str> = match *assignment.target {
+                    Expression::Identifier(ref identifier)
+                        if assignment.operator == Mark::Assign =>
+                    {
+                        self.declared_type(identifier)
+                    }
+                    _ => target,
+                };
+
+                if let Some(operator) = compound_operator(&assignment.operator) {
+                    // A class may change its objects in place instead of using the binary operator
+                    let in_place: Option<&str> = operators::compound_method(&assignment.operator)
+                        .filter(|method| target.map_or(false, |found| self.defines(found, method)));
+                    let result: Option<&str> = match in_place {
+                        Some(method) => {
+                            self.overload(
+                                method,
+                                &assignment.operator.to_string(),
+                                target?,
+                                &[&assignment.value],
+                                &assignment.location,
+                            );
+                            target
+                        }
+                        None => self.operation(
+                            &operator,
+                            &assignment.target,
+                            &assignment.value,
+                            &assignment.location,
+                        ),
+                    };
+                    if let (Some(target), Some(result)) = (target, result) {
+                        if !self.is_compatible(target, result) {
+                            self.types.diagnostics.push(
+                                Diagnostic::error(
+                                    "E0023",
+                                    "mismatched types",
+                                    assignment.location.clone(),
+                                )
+                                .with_label(&format!("expected `{target}`, found `{result}`"))
+                                .with_note(
+                                    &format!(
+                                        "`{}` produces a value of type `{result}`",
+                                        assignment.operator
+                                    ),
+                                    None,
+                                ),
+                            );
+                        }
+                    }
+                } else {
+                    let target: Option<&str> = target.or_else(|| self.infer_later(assignment));
+                    let inferred: Option<&VariableDeclaration> = match *assignment.target {
+                        Expression::Identifier(ref identifier) => {
+                            match self.resolution.declaration(identifier) {
+                                Some(Declaration::Variable(variable))
+                                    if variable.type_annotation.is_none() =>
+                                {
+                                    Some(variable)
+                                }
+                                _ => None,
+                            }
+                        }
+                        _ => None,
+                    };
+                    match (target, inferred) {
+                        (Some(target), Some(variable)) => self.expect(
+                            target,
+                            &assignment.value,
+                            Some(&variable.name.location),
+                            &format!(
+                                "because the type of `{}` was inferred here",
+                                variable.name.name
+                            ),
+                        ),
+                        (Some(target), None) => self.expect(
+                            target,
+                            &assignment.value,
+                            Some(assignment.target.location()),
+                            "because of the type of this",
+                        ),
+                        (None, _) => {}
+                    }
+                }
+                if let Expression::Identifier(ref identifier) = *assignment.target {
+                    self.reassign(identifier, &assignment.value);
+                }
+
+                target.or(value)
+            }
+            Expression::Binary(ref binary) => {
+                self.expression(&binary.left);
+                // The right side of `&&` is only evaluated if the left side is true, the one of `||` if it is false
+                let (narrowed, assigned): (usize, usize) =
+                    (self.narrowed.len(), self.assigned.len());
+                match binary.operator {
+                    Mark::And => self.narrowed.extend(self.facts(&binary.left, true)),
+                    Mark::Or => self.narrowed.extend(self.facts(&binary.left, false)),
+                    _ => {}
+                }
+                self.expression(&binary.right);
+                self.restore(narrowed, assigned);
+
+                if binary.operator == Mark::Coalesce {
+                    let result: Option<&str> = self.coalesce(&binary.left, &binary.right);
+                    return self.record(node, result);
+                }
+                self.operation(
+                    &binary.operator,
+                    &binary.left,
+                    &binary.right,
+                    &binary.location,
+                )
+            }
+            Expression::Unary(ref unary) => {
+                let operand: Option<&str> = self.expression(&unary.operand).map(|found| {
+                    let action: String = format!("used with `{}`", unary.operator);
+                    self.dereference(found, &unary.operand, &action)
+                });
+                match operand {
+                    Some(operand) => {
+                        let result: Option<&str> = unary_type(&unary.operator, operand);
+                        if let (None, Some(method)) =
+                            (result, operators::unary_method(&unary.operator))
+                        {
+                            if let Overload::Method(overloaded) = self.overload(
+                                method,
+                                &unary.operator.to_string(),
+                                operand,
+                                &[],
+                                &unary.location,
+                            ) {
+                                return self.record(node, overloaded);
+                            }
+                        }
+                        if result.is_none() {
+                            self.types.diagnostics.push(
+                                Diagnostic::error(
+                                    "E0043",
+                                    &format!(
+                                        "unsupported operand type for `{}`: `{operand}`",
+                                        unary.operator
+                                    ),
+                                    unary.location.clone(),
+                                )
+                                .with_label(&format!(
+                                    "`{}` cannot be applied to `{operand}`",
+                                    unary.operator
+                                ))
+                                .with_note(
+                                    &format!(
+                                        "`{}` expects a value of type `{}`",
+                                        unary.operator,
+                                        if unary.operator == Mark::Bang {
+                                            "bool"
+                                        } else {
+                                            "int"
+                                        }
+                                    ),
+                                    Some(unary.operand.location().clone()),
+                                ),
+                            );
+                        }
+                        result
+                    }
+                    None => (unary.operator == Mark::Bang).then_some("bool"),
+                }
+            }
+            Expression::Call(ref call) => {
+                let found: Option<&str> = self.call(call);
+                self.forget_shared(call);
+                found
+            }
+            Expression::Member(ref member) => {
+                if let Some(module) = self.member_module(member) {
+                    self.module_item(module, member);
+                    return None;
+                }
+                // Variants of enums are classes too, even those without fields are created by calling them
+                if let Some(Declaration::Class(variant)) =
+                    self.resolution.declaration(&member.member)
+                {
+                    self.types.diagnostics.push(
+                        Diagnostic::error(
+                            "E0076",
+                            &format!(
+                                "expected a value, found enum variant `{}`",
+                                variant.name.name
+                            ),
+                            member.member.location.clone(),
+                        )
+                        .with_label("not a value")
+                        .with_note(
+                            &format!("create the variant with `{}()`", variant.name.name),
+                            None,
+                        ),
+                    );
+                    return None;
+                }
+                if self.is_enum_member(member) {
+                    return None;
+                }
+                let target: &str = self.expression(&member.target)?;
+                let (target, safe): (&str, bool) = self.navigate(target, member);
+                let (class, substitution): (&Class, Substitution) = self.instance(target)?;
+                self.classes
+                    .find_field(class, &member.member.name)
+                    .map(|(owner, field)| {
+                        self.member_type(class, owner, substitution, &field.type_annotation)
+                    })
+                    .map(|found| {
+                        if safe {
+                            self.names.nullable(found)
+                        } else {
+                            found
+                        }
+                    })
+            }
+            Expression::Index(ref index) => self.index(index, None),
+            Expression::Slice(ref slice) => self.slice(slice),
+            Expression::List(ref list) => {
+                for element in &list.elements {
+                    self.expression(element);
+                }
+                let elements: Vec<&Expression> = list.elements.iter().collect();
+                Some(match self.element_type(&elements) {
+                    Some(element) => self.names.intern(&format!("list<{element}>")),
+                    None => "list",
+                })
+            }
+            Expression::Dict(ref dict) => {
+                for entry in &dict.entries {
+                    self.expression(&entry.key);
+                    self.expression(&entry.value);
+                }
+                let keys: Vec<&Expression> = dict.entries.iter().map(|entry| &entry.key).collect();
+                let values: Vec<&Expression> =
+                    dict.entries.iter().map(|entry| &entry.value).collect();
+                if let Some((key, found)) = keys
+                    .iter()
+                    .filter_map(|key| Some((key, self.types.type_of(key)?)))
+                    .find(|&(_, found)| !self.is_key(found))
+                {
+                    self.types
+                        .diagnostics
+                        .push(generics::invalid_key(found, key.location()));
+                }
+                Some(
+                    match (self.element_type(&keys), self.element_type(&values)) {
+                        (Some(key), Some(value)) => {
+                            self.names.intern(&format!("dict<{key}, {value}>"))
+                        }
+                        _ => "dict",
+                    },
+                )
+            }
+            Expression::Tuple(ref tuple) => {
+                let elements: Option<Vec<&str>> = tuple
+                    .elements
+                    .iter()
+                    .map(|element| self.expression(element))
+                    .collect::<Vec<Option<&str>>>()
+                    .into_iter()
+                    .collect();
+                Some(match elements {
+                    Some(elements) if !elements.is_empty() => self
+                        .names
+                        .intern(&format!("tuple<{}>", elements.join(", "))),
+                    _ => "tuple",
+                })
+            }
+            Expression::Match(ref node) => self.match_type(node),
+            Expression::Lambda(ref lambda) => Some(self.lambda(lambda)),
+        };
+
+        self.record(node, found)
+    }
+
+    /// Returns the type shared by the elements of a list literal or the keys or values of a dict literal: the type
+    /// of the first element that accepts all others, made nullable if an element is `none`. If there is none, the
+    /// elements not matching the first one are reported. Returns `None` if the literal is empty or the type of an
+    /// element is unknown.
+    ///
+    /// Empty list and dict literals take the shared type of the other elements, e.g. `[[1], []]` is a
+    /// `list<list<int>>`.
+    #[allow(clippy::indexing_slicing)]
+    fn element_type(&mut self, elements: &[&'ast Expression]) -> Option<&'ast str> {
+        let (empty, elements): (Vec<&Expression>, Vec<&Expression>) = elements
+            .iter()
+            .partition(|element| is_empty_literal(element));
+        let found: Vec<&str> = elements
+            .iter()
+            .map(|element| self.types.type_of(element))
+            .collect::<Option<Vec<&str>>>()?;
+        let with_none: bool = found.contains(&"none");
+        if let Some(shared) = found
+            .iter()
+            .map(|&candidate| {
+                if with_none {
+                    self.names.nullable(candidate)
+                } else {
+                    candidate
+                }
+            })
+            .find(|candidate| {
+                found
+                    .iter()
+                    .all(|element| self.is_compatible(candidate, element))
+            })
+        {
+            for element in empty {
+                self.expect(shared, element, None, "because of the other elements");
+            }
+            return Some(shared);
+        }
+
+        let (first, rest): (&&Expression, &[&Expression]) = elements.split_first()?;
+        for element in rest {
+            self.expect(
+                found[0],
+                element,
+                Some(first.location()),
+                "because of the first element",
+            );
+        }
+        Some(found[0])
+    }
+
+    /// Checks an indexing and returns the type of the element: `list<T>` and `str` are indexed with an `int`,
+    /// `dict<K, V>` with a `K` and `tuple` with an `int`, whose element type is known if the index is a literal.
+    /// Objects are indexed with the `index` method of their class, or assigned with its `set_index` method if a
+    /// value is assigned to the element.
+    fn index(&mut self, node: &'ast Index, value: Option<&'ast Expression>) -> Option<&'ast str> {
+        let target: Option<&str> = self.expression(&node.target);
+        self.expression(&node.index);
+        if let Some(value) = value {
+            self.expression(value);
+        }
+        let target: &str = self.dereference(target?, &node.target, "indexed");
+        let (base, arguments): (&str, Vec<&str>) = generics::split(target);
+
+        match base {
+            "list" | "str" | "tuple" => {
+                self.expect(
+                    "int",
+                    &node.index,
+                    None,
+                    &format!("because a `{base}` is indexed by position"),
+                );
+                match base {
+                    "str" => Some("str"),
+                    "list" => arguments.first().copied(),
+                    _ => match *node.index {
+                        Expression::Literal(ref literal)
+                            if literal.kind == TypeDefinition::Integer =>
+                        {
+                            let position: usize = literal.value.replace('_', "").parse().ok()?;
+                            arguments.get(position).copied()
+                        }
+                        _ => arguments
+                            .first()
+                            .copied()
+                            .filter(|first| arguments.iter().all(|element| element == first)),
+                    },
+                }
+            }
+            "dict" => {
+                if let Some(key) = arguments.first() {
+                    self.expect(key, &node.index, None, "because of the type of the keys");
+                }
+                arguments.get(1).copied()
+            }
+            _ => {
+                let overloaded: Overload = match value {
+                    Some(value) => match self.overload(
+                        operators::SET_INDEX_METHOD,
+                        "[]",
+                        target,
+                        &[&node.index, value],
+                        &node.location,
+                    ) {
+                        Overload::NotObject => Overload::NotObject,
+                        Overload::Method(_) => Overload::Method(None),
+                    },
+                    None => self.overload(
+                        operators::INDEX_METHOD,
+                        "[]",
+                        target,
+                        &[&node.index],
+                        &node.location,
+                    ),
+                };
+                match overloaded {
+                    Overload::NotObject => {
+                        self.not_indexable(target, "indexed", node.target.location());
+                        None
+                    }
+                    Overload::Method(result) => result,
+                }
+            }
+        }
+    }
+
+    /// Checks a slice and returns its type: a slice of a `list` or `str` has the type of the sliced value, a slice
+    /// of a `tuple` is a `tuple` of unknown elements.
+    fn slice(&mut self, node: &'ast Slice) -> Option<&'ast str> {
+        let target: Option<&str> = self.expression(&node.target);
+        for bound in node.start.iter().chain(&node.end) {
+            self.expression(bound);
+            self.expect(
+                "int",
+                bound,
+                None,
+                "because the bounds of a slice are positions",
+            );
+        }
+
+        match generics::split(target?).0 {
+            "list" | "str" => target,
+            "tuple" => Some("tuple"),
+            _ => {
+                self.not_indexable(target?, "sliced", node.target.location());
+                None
+            }
+        }
+    }
+
+    /// Returns the type of the values a `for` loop produces for an iterated value: the elements of a `list` or
+    /// `tuple`, the keys of a `dict`, the characters of a `str` or the type argument of an `Iterable` or `Iterator`
+    /// implemented by a class. Reports values that cannot be iterated.
+    fn element(&mut self, found: &'ast str, value: &Expression) -> Option<&'ast str> {
+        let (base, arguments): (&str, Vec<&str>) = generics::split(found);
+        match base {
+            "list" | "dict" => return arguments.first().copied(),
+            "str" => return Some("str"),
+            "tuple" => {
+                return arguments
+                    .first()
+                    .copied()
+                    .filter(|first| arguments.iter().all(|element| element == first))
+            }
+            _ => {}
+        }
+
+        if let Some((class, substitution)) = self.instance(found) {
+            for name in [prelude::ITERABLE, prelude::ITERATOR] {
+                let Some(protocol) = self.classes.get(name) else {
+                    continue;
+                };
+                if self.classes.is_subclass(class, protocol) {
+                    let substitution: Substitution = generics::inherited(
+                        self.names,
+                        self.classes,
+                        class,
+                        protocol,
+                        substitution,
+                    );
+                    let parameter: &TypeParameter = protocol.type_parameters.first()?;
+                    return substitution.get(parameter.name.name.as_str()).copied();
+                }
+            }
+        }
+
+        self.types.diagnostics.push(
+            Diagnostic::error(
+                "E0063",
+                &format!("a value of type `{found}` cannot be iterated"),
+                value.location().clone(),
+            )
+            .with_label("not iterable")
+            .with_note(
+                &format!(
+                    "`for` loops iterate over ranges, `list`, `dict`, `tuple` and `str` values and classes implementing `{}` or `{}`",
+                    prelude::ITERABLE,
+                    prelude::ITERATOR
+                ),
+                None,
+            ),
+        );
+        None
+    }
+
+    /// Reports an assignment to an element of a `tuple` or `str`, which cannot be changed.
+    fn immutable(&mut self, node: &Index) {
+        let Some(found) = self.types.type_of(&node.target) else {
+            return;
+        };
+        let base: &str = generics::split(found).0;
+        if matches!(base, "tuple" | "str") {
+            self.types.diagnostics.push(
+                Diagnostic::error(
+                    "E0062",
+                    &format!("the elements of a `{base}` cannot be assigned"),
+                    node.target.location().clone(),
+                )
+                .with_label(&format!("this has type `{found}`"))
+                .with_note(
+                    &format!("a `{base}` cannot be changed after it is created"),
+                    None,
+                ),
+            );
+        }
+    }
+
+    /// Reports an indexing or slice of a value that is not a `list`, `dict`, `tuple` or `str`.
+    fn not_indexable(&mut self, found: &str, action: &str, location: &Location) {
+        self.types.diagnostics.push(
+            Diagnostic::error(
+                "E0062",
+                &format!("a value of type `{found}` cannot be {action}"),
+                location.clone(),
+            )
+            .with_label(&format!("this has type `{found}`"))
+            .with_note(
+                "only values of type `list`, `dict`, `tuple` and `str` can be indexed",
+                None,
+            ),
+        );
+    }
+
+    /// Infers the type of a variable declared with `var` without a usable initial value from the first value
+    /// assigned to it, e.g. `var goal; goal = 3;`.
+    fn infer_later(&mut self, node: &'ast Assignment) -> Option<&'ast str> {
+        let Expression::Identifier(ref target) = *node.target else {
+            return None;
+        };
+        let Some(Declaration::Variable(variable)) = self.resolution.declaration(target) else {
+            return None;
+        };
+        let index: usize = self
+            .pending
+            .iter()
+            .position(|pending| core::ptr::eq(*pending, variable))?;
+        let found: &str = self
+            .types
+            .type_of(&node.value)
+            .filter(|found| *found != "none" && !self.is_malformed(found))?;
+
+        self.pending.remove(index);
+        self.infer(variable, found);
+        self.types.names.push((target, found));
+        self.record(&node.target, Some(found))
+    }
+
+    /// Records the inferred type of a variable declared with `var`, e.g. `str?` for a variable both assigned `"a"`
+    /// and `none`.
+    fn infer(&mut self, node: &'ast VariableDeclaration, found: &'ast str) {
+        let type_name: &str = if self.nullable.contains(&address(node)) {
+            self.names.nullable(found)
+        } else {
+            found
+        };
+        self.types.variables.insert(address(node), type_name);
+        self.types.names.push((&node.name, type_name));
+    }
+
+    /// Reports the variables declared with `var` whose type could not be inferred.
+    fn report_pending(&mut self) {
+        for node in core::mem::take(&mut self.pending) {
+            let mut diagnostic: Diagnostic = Diagnostic::error(
+                "E0046",
+                &format!("cannot infer the type of `{}`", node.name.name),
+                node.name.location.clone(),
+            )
+            .with_label("type annotation needed");
+
+            let mut example: &str = "int";
+            if let Some(ref value) = node.value {
+                if self.types.type_of(value) == Some("none") {
+                    example = "int?";
+                    diagnostic = diagnostic.with_note(
+                        "`none` can be a value of any nullable type, so the other values assigned decide the type",
+                        Some(value.location().clone()),
+                    );
+                }
+                if is_empty_literal(value) {
+                    let (kind, declared): (&str, &str) = match *value {
+                        Expression::List(_) => ("list", "list<int>"),
+                        _ => ("dict", "dict<str, int>"),
+                    };
+                    example = declared;
+                    diagnostic = diagnostic.with_note(
+                        &format!("the element types of an empty {kind} cannot be inferred"),
+                        Some(value.location().clone()),
+                    );
+                }
+            }
+            self.types.diagnostics.push(diagnostic.with_note(
+                &format!(
+                    "declare the type instead of `var`, e.g. `{example} {}`, or assign a value to the variable later",
+                    node.name.name
+                ),
+                None,
+            ));
+        }
+    }
+
+    /// Checks a binary operation on two already visited operands and returns its type.
+    fn operation(
+        &mut self,
+        operator: &Mark,
+        left: &Expression,
+        right: &Expression,
+        location: &Location,
+    ) -> Option<&'ast str> {
+        let boolean: bool = matches!(
+            *operator,
+            Mark::Equal
+                | Mark::NotEqual
+                | Mark::Less
+                | Mark::LessEqual
+                | Mark::Greater
+                | Mark::GreaterEqual
+                | Mark::And
+                | Mark::Or
+        );
+        let (Some(left_type), Some(right_type)) =
+            (self.types.type_of(left), self.types.type_of(right))
+        else {
+            return boolean.then_some("bool");
+        };
+
+        // Objects are compared by identity unless their class defines `eq`, and always with `none`
+        let equality: bool = matches!(*operator, Mark::Equal | Mark::NotEqual);
+        let (left_type, right_type): (&str, &str) = if equality {
+            (left_type, right_type)
+        } else {
+            let action: String = format!("used with `{operator}`");
+            (
+                self.dereference(left_type, left, &action),
+                self.dereference(right_type, right, &action),
+            )
+        };
+        let (left_type, right_type): (&str, &str) = self.adapt(left_type, right_type, left, right);
+        if let Some(method) = operators::binary_method(operator)
+            .filter(|method| !equality || (right_type != "none" && self.defines(left_type, method)))
+        {
+            if let Overload::Method(result) =
+                self.overload(method, &operator.to_string(), left_type, &[right], location)
+            {
+                return result.or_else(|| boolean.then_some("bool"));
+            }
+        }
+
+        let result: Option<&str> = binary_type(operator, left_type, right_type);
+        if result.is_none() {
+            self.types.diagnostics.push(
+                Diagnostic::error(
+                    "E0043",
+                    &format!("unsupported operand types for `{operator}`: `{left_type}` and `{right_type}`"),
+                    location.clone(),
+                )
+                .with_label(&format!("`{operator}` cannot be applied to `{left_type}` and `{right_type}`"))
+                .with_note(&format!("this has type `{left_type}`"), Some(left.location().clone()))
+                .with_note(&format!("this has type `{right_type}`"), Some(right.location().clone()))
+                .with_note(&format!("`{operator}` expects {}", supported_types(operator)), None),
+            );
+        }
+
+        result.or_else(|| boolean.then_some("bool"))
+    }
+
+    /// Lets an integer literal operand take the integer type of the other operand, e.g. `1` is a `u8` in
+    /// `small + 1` if `small` is a `u8`. Literals that fit into an `int` adapt first, so `1 + big` is a `bigint`
+    /// for a `bigint` literal `big`.
+    ///
+    /// # Returns
+    ///
+    /// The types of the operands after the adaption.
+    fn adapt(
+        &mut self,
+        left_type: &'ast str,
+        right_type: &'ast str,
+        left: &Expression,
+        right: &Expression,
+    ) -> (&'ast str, &'ast str) {
+        if left_type == right_type
+            || !integers::is_integer(left_type)
+            || !integers::is_integer(right_type)
+        {
+            return (left_type, right_type);
+        }
+
+        let left_literal: bool = integers::literal(left).is_some();
+        let right_literal: bool = integers::literal(right).is_some();
+        if left_literal && (left_type == "int" || !right_literal) {
+            if let Some(diagnostics) = integers::check_literal(right_type, left) {
+                self.types.diagnostics.extend(diagnostics);
+            }
+            self.record(left, Some(right_type));
+            (right_type, right_type)
+        } else if right_literal {
+            if let Some(diagnostics) = integers::check_literal(left_type, right) {
+                self.types.diagnostics.extend(diagnostics);
+            }
+            self.record(right, Some(left_type));
+            (left_type, left_type)
+        } else {
+            (left_type, right_type)
+        }
+    }
+
+    /// Returns whether the class of an object defines a method.
+    fn defines(&self, type_name: &'ast str, method: &str) -> bool {
+        self.instance(type_name).map_or(false, |(class, _)| {
+            self.classes.find_method(class, method).is_some()
+        })
+    }
+
+    /// Checks an operator applied to an object through the method of its class named after the operator, see
+    /// [`operators`]. The arguments have to be visited already.
+    ///
+    /// # Parameters
+    ///
+    /// - `method`: The name of the method, e.g. `add`.
+    /// - `operator`: The operator, shown in the messages.
+    /// - `target`: The type of the object the operator is applied to.
+    /// - `arguments`: The other operands, passed to the method.
+    /// - `location`: The location of the operation.
+    ///
+    /// # Returns
+    ///
+    /// Whether the target is an object, with the type of the result if it is, see [`Overload`].
+    fn overload(
+        &mut self,
+        method: &str,
+        operator: &str,
+        target: &'ast str,
+        arguments: &[&Expression],
+        location: &Location,
+    ) -> Overload<'ast> {
+        let Some((class, substitution)) = self.instance(target) else {
+            return Overload::NotObject;
+        };
+        let Some((owner, function)) = self.classes.find_method(class, method) else {
+            self.types.diagnostics.push(
+                Diagnostic::error(
+                    "E0043",
+                    &format!("`{operator}` is not defined for `{target}`"),
+                    location.clone(),
+                )
+                .with_label(&format!("`{target}` has no method `{method}`"))
+                .with_note(
+                    &format!(
+                        "define a method `{method}` in `{}` to support `{operator}`",
+                        class.name.name
+                    ),
+                    Some(class.name.location.clone()),
+                ),
+            );
+            return Overload::Method(None);
+        };
+
+        let comparison: bool = operators::is_comparison(method);
+        if function.parameters.len() != arguments.len()
+            || (comparison && self.names.return_type(function) != "bool")
+        {
+            let parameters: String = match arguments.len() {
+                0 => "no parameters".to_owned(),
+                1 => "one parameter".to_owned(),
+                count => format!("{count} parameters"),
+            };
+            self.types.diagnostics.push(
+                Diagnostic::error(
+                    "E0064",
+                    &format!(
+                        "`{}.{method}` cannot be used for `{operator}`",
+                        owner.name.name
+                    ),
+                    location.clone(),
+                )
+                .with_label(&format!("`{operator}` uses `{method}` here"))
+                .with_note(
+                    &format!(
+                        "`{method}` has to take {parameters}{}",
+                        if comparison { " and return `bool`" } else { "" }
+                    ),
+                    Some(function.name.location.clone()),
+                ),
+            );
+            return Overload::Method(None);
+        }
+
+        let substitution: Substitution =
+            generics::inherited(self.names, self.classes, class, owner, substitution);
+        for (parameter, argument) in function.parameters.iter().zip(arguments) {
+            let expected: &str = self.names.substitute(
+                self.names.annotation_type(&parameter.type_annotation),
+                &substitution,
+            );
+            self.expect(
+                expected,
+                argument,
+                Some(&parameter.type_annotation.location),
+                &format!("because of the parameter of `{}.{method}`", owner.name.name),
+            );
+        }
+        Overload::Method(Some(
+            self.names
+                .substitute(self.names.return_type(function), &substitution),
+        ))
+    }
+
+    /// Checks a call and returns the type of its result.
+    fn call(&mut self, node: &'ast Call) -> Option<&'ast str> {
+        for argument in &node.arguments {
+            self.visit_argument(argument);
+        }
+
+        match *node.callee {
+            Expression::Identifier(ref identifier) => {
+                let result: Option<&str> = match self.resolution.declaration(identifier) {
+                    Some(Declaration::BuiltIn(name)) => {
+                        self.built_in(name, node);
+                        Some(built_in_type(name))
+                    }
+                    Some(Declaration::Function(function)) => {
+                        self.function_call(function, &Substitution::new(), node)
+                    }
+                    Some(Declaration::Class(class)) => Some(self.construct(class, node)),
+                    Some(Declaration::This(_)) if identifier.name == "super" => None,
+                    Some(_) => match self.declaration_type(identifier) {
+                        Some(found) => self.value_call(found, node),
+                        None => None,
+                    },
+                    None => None,
+                };
+                self.record(&node.callee, None);
+                result
+            }
+            Expression::Member(ref member) => {
+                if let Some(module) = self.member_module(member) {
+                    return match self.module_item(module, member)? {
+                        Item::Function(function) => {
+                            self.module_arguments(function, node);
+                            self.function_call(function, &Substitution::new(), node)
+                        }
+                        Item::Class(_) => None,
+                    };
+                }
+                if let Some(Declaration::Class(variant)) =
+                    self.resolution.declaration(&member.member)
+                {
+                    self.record(&node.callee, None);
+                    return Some(self.construct(variant, node));
+                }
+                if self.is_enum_member(member) {
+                    return None;
+                }
+
+                let target: &str = self.expression(&member.target)?;
+                let (target, safe): (&str, bool) = self.navigate(target, member);
+                let result: Option<&str> = self.method_call(target, member, node);
+                if safe {
+                    result.map(|found| self.names.nullable(found))
+                } else {
+                    result
+                }
+            }
+            _ => {
+                let found: &str = self.expression(&node.callee)?;
+                self.value_call(found, node)
+            }
+        }
+    }
+
+    /// Returns whether the target of a member expression names an enum. Members that are not variants of the enum
+    /// are reported when resolving names.
+    fn is_enum_member(&self, member: &Member) -> bool {
+        match *member.target {
+            Expression::Identifier(ref target) => matches!(
+                self.resolution.declaration(target),
+                Some(Declaration::Class(class)) if class.enumeration
+            ),
+            _ => false,
+        }
+    }
+
+    /// Returns the module the target of a member expression names, e.g. `random` in `random.choice`.
+    fn member_module(&self, member: &Member) -> Option<&'ast Module> {
+        match *member.target {
+            Expression::Identifier(ref target) => match self.resolution.declaration(target) {
+                Some(Declaration::Module(module)) => Some(module),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the item of a module a member expression names. Unknown and private items are reported, see
+    /// [`modules::item`].
+    fn module_item(&mut self, module: &'ast Module, member: &Member) -> Option<Item<'ast>> {
+        modules::item(module, &member.member)
+            .map_err(|diagnostic| self.types.diagnostics.push(*diagnostic))
+            .ok()
+    }
+
+    /// Checks the format specification of a field of a format string against the type of its value.
+    fn format_field(&mut self, field: &FormatField, found: Option<&str>) {
+        let Some(ref text) = field.specification else {
+            return;
+        };
+        let diagnostic: Diagnostic = match Specification::parse(text) {
+            Ok(specification) => {
+                let Some((found, reason)) = found
+                    .and_then(|found| specification.reject(found).map(|reason| (found, reason)))
+                else {
+                    return;
+                };
+                Diagnostic::error(
+                    "E0075",
+                    &format!("the format specification `{text}` cannot format a `{found}`"),
+                    field.location.clone(),
+                )
+                .with_label(&format!("`{found}` value"))
+                .with_note(reason, None)
+            }
+            Err(reason) => Diagnostic::error(
+                "E0075",
+                &format!("invalid format specification `{text}`"),
+                field.location.clone(),
+            )
+            .with_label(&reason),
+        };
+        self.types.diagnostics.push(diagnostic);
+    }
+
+    /// Checks a call of a method or of a field holding a function of a value that is not `none` and returns the type
+    /// of its result.
+    fn method_call(
+        &mut self,
+        target: &'ast str,
+        member: &'ast Member,
+        node: &'ast Call,
+    ) -> Option<&'ast str> {
+        if collections::is_collection(target) {
+            return self.collection_method(target, member, node);
+        }
+        let (class, substitution): (&Class, Substitution) = self.instance(target)?;
+        if let Some((owner, method)) = self.classes.find_method(class, &member.member.name) {
+            let substitution: Substitution =
+                generics::inherited(self.names, self.classes, class, owner, substitution);
+            return self.function_call(method, &substitution, node);
+        }
+        let (owner, field) = self.classes.find_field(class, &member.member.name)?;
+        let found: &str = self.member_type(class, owner, substitution, &field.type_annotation);
+        self.value_call(found, node)
+    }
+
+    /// Checks a call of a method of a built-in collection and returns the type of its result, see
+    /// [`collections::methods`]. Types mentioning a type parameter of a collection without type arguments, e.g. the
+    /// result of `pop` on a `list`, are unknown.
+    fn collection_method(
+        &mut self,
+        type_name: &'ast str,
+        member: &'ast Member,
+        node: &'ast Call,
+    ) -> Option<&'ast str> {
+        let base: &str = generics::split(type_name).0;
+        let Some(method) = collections::method(type_name, &member.member.name) else {
+            let names: Vec<String> = collections::methods(base)
+                .iter()
+                .map(|method| format!("`{}`", method.name))
+                .collect();
+            self.types.diagnostics.push(
+                Diagnostic::error(
+                    "E0011",
+                    &format!(
+                        "call to undefined method `{}` on `{type_name}`",
+                        member.member.name
+                    ),
+                    member.member.location.clone(),
+                )
+                .with_label("method not found")
+                .with_note(
+                    &format!("the methods of `{base}` are {}", names.join(", ")),
+                    None,
+                ),
+            );
+            return None;
+        };
+
+        let substitution: Substitution = collections::substitution(type_name);
+        let resolve = |found: &'static str| {
+            let found: &str = self.names.substitute(found, &substitution);
+            (!collections::type_parameters(base)
+                .iter()
+                .any(|parameter| generics::mentions(found, parameter)))
+            .then_some(found)
+        };
+
+        if let Some(argument) = node.arguments.get(method.parameters.len()) {
+            self.types.diagnostics.push(
+                Diagnostic::error(
+                    "E0016",
+                    &format!(
+                        "`{}` takes {} argument{} but {} were given",
+                        method.name,
+                        method.parameters.len(),
+                        if method.parameters.len() == 1 {
+                            ""
+                        } else {
+                            "s"
+                        },
+                        node.arguments.len()
+                    ),
+                    argument.location.clone(),
+                )
+                .with_label("unexpected argument"),
+            );
+        }
+        for (index, &(name, parameter)) in method.parameters.iter().enumerate() {
+            let Some(argument) = node.arguments.get(index) else {
+                self.types.diagnostics.push(
+                    Diagnostic::error(
+                        "E0019",
+                        &format!(
+                            "missing argument for parameter `{name}` of `{}`",
+                            method.name
+                        ),
+                        node.location.clone(),
+                    )
+                    .with_label(&format!("missing `{name}`")),
+                );
+                continue;
+            };
+            if let Some(expected) = resolve(parameter) {
+                self.expect(
+                    expected,
+                    &argument.value,
+                    None,
+                    &format!("because of the parameter `{name}` of `{}`", method.name),
+                );
+            }
+        }
+
+        resolve(method.return_type)
+    }
+
+    /// Checks the number of arguments of a call of a function of another module.
+    /// Calls of functions of the same module are checked by [`functions::check`].
+    fn module_arguments(&mut self, function: &Function, node: &Call) {
+        self.types.diagnostics.extend(functions::check_arguments(
+            &function.name.name,
+            &function.parameters,
+            &function.name.location,
+            node,
+        ));
+    }
+
+    /// Returns the type of a field or other member declared in `owner` and used on an instance of `class` with the
+    /// given type arguments.
+    fn member_type(
+        &self,
+        class: &'ast Class,
+        owner: &'ast Class,
+        substitution: Substitution<'ast>,
+        annotation: &'ast TypeAnnotation,
+    ) -> &'ast str {
+        let substitution: Substitution =
+            generics::inherited(self.names, self.classes, class, owner, substitution);
+        self.names
+            .substitute(self.names.annotation_type(annotation), &substitution)
+    }
+
+    /// Checks a call of a function or method and returns the type of its result. The type arguments of a generic
+    /// function are inferred from the arguments and must satisfy the bounds of the type parameters.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: The called function.
+    /// - `substitution`: The type arguments of the class of a called method.
+    /// - `node`: The call.
+    fn function_call(
+        &mut self,
+        function: &'ast Function,
+        substitution: &Substitution<'ast>,
+        node: &'ast Call,
+    ) -> Option<&'ast str> {
+        let substitution: Substitution = self.infer_arguments(
+            &function.type_parameters,
+            &function.parameters,
+            substitution,
+            node,
+        );
+        self.arguments(&function.parameters, &substitution, node);
+
+        let result: &str = self
+            .names
+            .substitute(self.names.return_type(function), &substitution);
+        let uninferred: Option<&TypeParameter> =
+            function.type_parameters.iter().find(|parameter| {
+                !substitution.contains_key(parameter.name.name.as_str())
+                    && generics::mentions(result, &parameter.name.name)
+            });
+        if let Some(parameter) = uninferred {
+            self.types.diagnostics.push(
+                Diagnostic::error(
+                    "E0056",
+                    &format!(
+                        "cannot infer the type parameter `{}` of `{}`",
+                        parameter.name.name, function.name.name
+                    ),
+                    node.callee.location().clone(),
+                )
+                .with_label(&format!(
+                    "no argument determines `{}`",
+                    parameter.name.name
+                ))
+                .with_note(
+                    &format!(
+                        "the return type `{result}` depends on the type parameter `{}` declared here",
+                        parameter.name.name
+                    ),
+                    Some(parameter.location.clone()),
+                ),
+            );
+            return None;
+        }
+
+        Some(result)
+    }
+
+    /// Checks the instantiation of a class and returns the type of the new object. The type arguments of a generic
+    /// class are inferred from the arguments of its constructor. A generic class whose type arguments cannot be
+    /// inferred, e.g. `Stack()`, is instantiated without type arguments and takes the ones of the expected type.
+    fn construct(&mut self, class: &'ast Class, node: &'ast Call) -> &'ast str {
+        let Some(constructor) = self.classes.find_constructor(class) else {
+            return &class.name.name;
+        };
+        // An inherited constructor uses the type parameters of the base class
+        let parameters: &[TypeParameter] = if constructor.name.name == class.name.name {
+            &class.type_parameters
+        } else {
+            &[]
+        };
+
+        let substitution: Substitution = self.infer_arguments(
+            parameters,
+            &constructor.parameters,
+            &Substitution::new(),
+            node,
+        );
+        self.arguments(&constructor.parameters, &substitution, node);
+
+        let arguments: Option<Vec<&str>> = class
+            .type_parameters
+            .iter()
+            .map(|parameter| substitution.get(parameter.name.name.as_str()).copied())
+            .collect();
+        match arguments {
+            Some(arguments) if !arguments.is_empty() => {
+                self.names
+                    .intern(&format!("{}<{}>", class.name.name, arguments.join(", ")))
+            }
+            _ => &class.name.name,
+        }
+    }
+
+    /// Infers the type arguments of a call of a generic function from the types of the arguments and checks them
+    /// against the bounds of the type parameters.
+    ///
+    /// # Returns
+    ///
+    /// The given type arguments with the inferred ones added.
+    #[allow(clippy::iter_over_hash_type)]
+    fn infer_arguments(
+        &mut self,
+        type_parameters: &'ast [TypeParameter],
+        parameters: &'ast [Parameter],
+        substitution: &Substitution<'ast>,
+        node: &'ast Call,
+    ) -> Substitution<'ast> {
+        let mut result: Substitution = substitution.clone();
+        if type_parameters.is_empty() {
+            return result;
+        }
+
+        let visible: Vec<&TypeParameter> = type_parameters.iter().collect();
+        let mut sources: HashMap<&str, &Expression> = HashMap::new();
+        for (parameter, argument) in pairs(parameters, node) {
+            let Some(found) = self.types.type_of(&argument.value) else {
+                continue;
+            };
+            let expected: &str = self.names.substitute(
+                self.names.annotation_type(&parameter.type_annotation),
+                substitution,
+            );
+
+            let mut inferred: Substitution = Substitution::new();
+            generics::unify(expected, found, &visible, &mut inferred);
+            for (name, value) in inferred {
+                if !result.contains_key(name) {
+                    result.insert(name, value);
+                    sources.insert(name, &argument.value);
+                }
+            }
+        }
+
+        for parameter in type_parameters {
+            let name: &str = &parameter.name.name;
+            let (Some(bound), Some(found)) = (parameter.bound.as_ref(), result.get(name)) else {
+                continue;
+            };
+            if !generics::satisfies(self.classes, found, bound, &self.scope) {
+                let location: &Location = sources
+                    .get(name)
+                    .map_or_else(|| node.callee.location(), |source| source.location());
+                self.types
+                    .diagnostics
+                    .push(generics::unsatisfied(found, parameter, location));
+            }
+        }
+
+        result
+    }
+
+    /// Checks the types of the arguments of a call, matched with the parameters like in
+    /// [`functions::check_arguments`]. Arguments without a parameter are skipped.
+    fn arguments(
+        &mut self,
+        parameters: &'ast [Parameter],
+        substitution: &Substitution<'ast>,
+        node: &'ast Call,
+    ) {
+        for (parameter, argument) in pairs(parameters, node) {
+            let expected: &str = self.names.substitute(
+                self.names.annotation_type(&parameter.type_annotation),
+                substitution,
+            );
+            self.expect(
+                expected,
+                &argument.value,
+                Some(&parameter.type_annotation.location),
+                &format!("because of the parameter `{}`", parameter.name.name),
+            );
+        }
+    }
+
+    /// Checks the arguments of a call of a built-in function.
+    #[allow(
+        clippy::else_if_without_else,
+        clippy::indexing_slicing,
+        clippy::too_many_lines
+    )]
+    fn built_in(&mut self, name: &str, node: &Call) {
+        if let Some(named) = node
+            .arguments
+            .iter()
+            .find_map(|argument| argument.name.as_ref())
+        {
+            self.types.diagnostics.push(
+                Diagnostic::error(
+                    "E0017",
+                    &format!("`{name}` has no parameter named `{}`", named.name),
+                    named.location.clone(),
+                )
+                .with_label("unknown keyword argument")
+                .with_note("built-in functions only take positional arguments", None),
+            );
+        }
+
+        let (minimum, maximum): (usize, usize) = match name {
+            "print" => (0, usize::MAX),
+            "input" => (0, 1),
+            "time" => (0, 0),
+            _ => (1, 1),
+        };
+        let count: usize = node.arguments.len();
+        if count > maximum {
+            self.types.diagnostics.push(
+                Diagnostic::error(
+                    "E0016",
+                    &format!(
+                        "`{name}` takes {maximum} argument{} but {count} were given",
+                        if maximum == 1 { "" } else { "s" }
+                    ),
+                    node.arguments[maximum].location.clone(),
+                )
+                .with_label("unexpected argument"),
+            );
+        } else if count < minimum {
+            self.types.diagnostics.push(
+                Diagnostic::error(
+                    "E0019",
+                    &format!("missing argument for `{name}`"),
+                    node.location.clone(),
+                )
+                .with_label("expected a value to convert"),
+            );
+        }
+
+        let Some(argument) = node
+            .arguments
+            .first()
+            .filter(|_| integers::is_integer(name))
+        else {
+            return;
+        };
+        if let Some(diagnostics) = integers::check_literal(name, &argument.value) {
+            self.types.diagnostics.extend(diagnostics);
+            return;
+        }
+        let found: Option<&str> = self.types.type_of(&argument.value);
+        let literal: Option<&str> = match argument.value {
+            Expression::Literal(ref literal) if literal.kind == TypeDefinition::String => {
+                Some(&literal.value)
+            }
+            _ => None,
+        };
+
+        if let Some(text) = literal {
+            let digits: &str = text.trim();
+            let digits: &str = digits.strip_prefix(['-', '+']).unwrap_or(digits);
+            if digits.is_empty() || !digits.chars().all(|character| character.is_ascii_digit()) {
+                self.types.diagnostics.push(
+                    Diagnostic::error(
+                        "E0045",
+                        &format!("cannot convert \"{text}\" to `{name}`"),
+                        argument.value.location().clone(),
+                    )
+                    .with_label("not a valid integer")
+                    .with_note("this conversion always raises a `TypeError`", None),
+                );
+            } else if let Some((min, max)) = integers::range(name).filter(|&(min, max)| {
+                !text
+                    .trim()
+                    .parse()
+                    .map_or(false, |value| (min..=max).contains(&value))
+            }) {
+                self.types.diagnostics.push(
+                    Diagnostic::error(
+                        "E0045",
+                        &format!("cannot convert \"{text}\" to `{name}`"),
+                        argument.value.location().clone(),
+                    )
+                    .with_label(&format!("does not fit into `{name}`"))
+                    .with_note(&format!("`{name}` holds values from {min} to {max}"), None)
+                    .with_note("this conversion always raises an `OverflowError`", None),
+                );
+            }
+        } else if let Some(found) =
+            found.filter(|found| !integers::is_integer(found) && !["str", "bool"].contains(found))
+        {
+            self.types.diagnostics.push(
+                Diagnostic::error(
+                    "E0045",
+                    &format!("cannot convert `{found}` to `{name}`"),
+                    argument.value.location().clone(),
+                )
+                .with_label(&format!(
+                    "expected an integer, `str` or `bool`, found `{found}`"
+                ))
+                .with_note(
+                    &format!("only integers, `str` and `bool` values can be converted to `{name}`"),
+                    None,
+                ),
+            );
+        }
+    }
+
+    /// Checks a call of a value, e.g. a lambda stored in a variable, and returns the type of its result. Values of a
+    /// function type take exactly one positional argument per parameter type, other values are not callable.
+    #[allow(clippy::else_if_without_else, clippy::indexing_slicing)]
+    fn value_call(&mut self, found: &'ast str, node: &'ast Call) -> Option<&'ast str> {
+        let (base, arguments): (&str, Vec<&str>) = generics::split(found);
+        if base != "function" {
+            self.not_callable(&node.callee, found);
+            return None;
+        }
+        // A function of unknown type can be called with any arguments
+        let (result, parameters): (&&str, &[&str]) = arguments.split_last()?;
+
+        if let Some(named) = node
+            .arguments
+            .iter()
+            .find_map(|argument| argument.name.as_ref())
+        {
+            self.types.diagnostics.push(
+                Diagnostic::error(
+                    "E0017",
+                    &format!(
+                        "a value of type `{found}` has no parameter named `{}`",
+                        named.name
+                    ),
+                    named.location.clone(),
+                )
+                .with_label("unknown keyword argument")
+                .with_note(
+                    "functions used as values only take positional arguments",
+                    None,
+                ),
+            );
+        }
+        let count: usize = node.arguments.len();
+        if count > parameters.len() {
+            self.types.diagnostics.push(
+                Diagnostic::error(
+                    "E0016",
+                    &format!(
+                        "a value of type `{found}` takes {} argument{} but {count} were given",
+                        parameters.len(),
+                        if parameters.len() == 1 { "" } else { "s" }
+                    ),
+                    node.arguments[parameters.len()].location.clone(),
+                )
+                .with_label("unexpected argument"),
+            );
+        } else if count < parameters.len() {
+            self.types.diagnostics.push(
+                Diagnostic::error(
+                    "E0019",
+                    &format!(
+                        "missing argument for the parameter of type `{}`",
+                        parameters[count]
+                    ),
+                    node.location.clone(),
+                )
+                .with_label(&format!("a value of type `{found}` is called here")),
+            );
+        }
+
+        for (expected, argument) in parameters.iter().zip(&node.arguments) {
+            self.expect(
+                expected,
+                &argument.value,
+                Some(node.callee.location()),
+                &format!("because the called value has the type `{found}`"),
+            );
+        }
+
+        Some(result)
+    }
+
+    /// Checks a lambda and returns its function type. A lambda with an expression returns the type of the
+    /// expression, a lambda with a block its declared return type.
+    fn lambda(&mut self, node: &'ast Lambda) -> &'ast str {
+        let mut types: Vec<&str> = vec![];
+        for parameter in &node.parameters {
+            let expected: &str = self.names.annotation_type(&parameter.type_annotation);
+            self.types.names.push((&parameter.name, expected));
+            if let Some(ref default) = parameter.default {
+                self.visit_expression(default);
+                self.expect(
+                    expected,
+                    default,
+                    Some(&parameter.type_annotation.location),
+                    "because of the type of the parameter",
+                );
+            }
+            types.push(expected);
+        }
+
+        // The lambda may run after the variables it uses were checked for `none` have changed
+        let narrowed: Vec<(*const Identifier, &str)> = core::mem::take(&mut self.narrowed);
+        let assigned: usize = self.assigned.len();
+        let result: Option<&str> = match node.body {
+            LambdaBody::Block(ref body) => {
+                let result: &str = node
+                    .return_type
+                    .as_ref()
+                    .map_or("none", |annotation| self.names.annotation_type(annotation));
+                self.returns.push(result);
+                self.visit_block(body);
+                self.returns.pop();
+                Some(result)
+            }
+            LambdaBody::Expression(ref value) => self.expression(value),
+        };
+        self.narrowed = narrowed;
+        self.restore(self.narrowed.len(), assigned);
+
+        // The type of a lambda whose result is unknown is only known to be a function
+        let Some(result) = result else {
+            return "function";
+        };
+        types.push(result);
+
+        self.names.intern(&generics::join("function", &types))
+    }
+
+    /// Reports a call of a value that is not a function.
+    fn not_callable(&mut self, callee: &Expression, found: &str) {
+        self.types.diagnostics.push(
+            Diagnostic::error(
+                "E0044",
+                &format!("a value of type `{found}` is not callable"),
+                callee.location().clone(),
+            )
+            .with_label("not a function")
+            .with_note("only functions, methods and classes can be called", None),
+        );
+    }
+
+    /// Checks a match and returns its type: the type shared by all of its cases, if it is an expression.
+    fn match_type(&mut self, node: &'ast Match) -> Option<&'ast str> {
+        let subject: Option<&str> = self.expression(&node.subject);
+        for arm in &node.arms {
+            if let Some(ref pattern) = arm.pattern {
+                self.bind(pattern, subject);
+            }
+            self.visit_match_arm(arm);
+        }
+
+        let mut types = node.arms.iter().map(|arm| match arm.body {
+            MatchBody::Expression(ref value) => self.types.type_of(value),
+            MatchBody::Block(_) => None,
+        });
+        let first: &str = types.next()??;
+        types.all(|found| found == Some(first)).then_some(first)
+    }
+}
+
+impl<'ast> Visitor<'ast> for TypeChecker<'_, 'ast> {
+    fn visit_argument(&mut self, node: &'ast Argument) {
+        self.visit_expression(&node.value);
+    }
+
+    fn visit_catch(&mut self, node: &'ast Catch) {
+        if let Some(ref binding) = node.binding {
+            self.bindings.insert(address(binding), &node.exception.name);
+        }
+        visit::walk_catch(self, node);
+    }
+
+    fn visit_expression(&mut self, node: &'ast Expression) {
+        self.expression(node);
+    }
+
+    fn visit_class(&mut self, node: &'ast Class) {
+        let length: usize = self.scope.len();
+        self.scope.extend(&node.type_parameters);
+        visit::walk_class(self, node);
+        self.scope.truncate(length);
+    }
+
+    fn visit_field(&mut self, node: &'ast Field) {
+        if let Some(ref value) = node.value {
+            self.visit_expression(value);
+            self.expect(
+                self.names.annotation_type(&node.type_annotation),
+                value,
+                Some(&node.type_annotation.location),
+                "because of the type of the field",
+            );
+        }
+    }
+
+    fn visit_function(&mut self, node: &'ast Function) {
+        let length: usize = self.scope.len();
+        self.scope.extend(&node.type_parameters);
+        for parameter in &node.parameters {
+            self.types.names.push((
+                &parameter.name,
+                self.names.annotation_type(&parameter.type_annotation),
+            ));
+        }
+        let narrowed: Vec<(*const Identifier, &str)> = core::mem::take(&mut self.narrowed);
+        self.returns.push(self.names.return_type(node));
+        visit::walk_function(self, node);
+        for condition in node.requires.iter().chain(&node.ensures) {
+            self.expect("bool", condition, None, "because contracts must be `bool`");
+        }
+        self.returns.pop();
+        self.narrowed = narrowed;
+        self.scope.truncate(length);
+    }
+
+    #[allow(clippy::else_if_without_else)]
+    fn visit_if(&mut self, node: &'ast If) {
+        self.condition(&node.condition);
+        let (narrowed, assigned): (usize, usize) = (self.narrowed.len(), self.assigned.len());
+        self.narrowed.extend(self.facts(&node.condition, true));
+        self.visit_block(&node.then_block);
+        self.narrowed.truncate(narrowed);
+        self.narrowed.extend(self.facts(&node.condition, false));
+        if let Some(ref else_block) = node.else_block {
+            self.visit_block(else_block);
+        }
+        self.restore(narrowed, assigned);
+
+        // The code after an `if` whose block always leaves runs only if the condition is false, and the other way round
+        let else_leaves: bool = node.else_block.as_ref().map_or(false, leaves);
+        if leaves(&node.then_block) && !else_leaves {
+            self.narrowed.extend(self.facts(&node.condition, false));
+        } else if else_leaves && !leaves(&node.then_block) {
+            self.narrowed.extend(self.facts(&node.condition, true));
+        }
+    }
+
+    fn visit_match(&mut self, node: &'ast Match) {
+        self.match_type(node);
+    }
+
+    fn visit_match_arm(&mut self, node: &'ast MatchArm) {
+        if let Some(ref guard) = node.guard {
+            self.condition(guard);
+        }
+        self.visit_match_body(&node.body);
+    }
+
+    fn visit_variable_declaration(&mut self, node: &'ast VariableDeclaration) {
+        if let Some(ref value) = node.value {
+            self.visit_expression(value);
+        }
+
+        match (&node.type_annotation, &node.value) {
+            (&Some(ref annotation), &Some(ref value)) => {
+                let expected: &str = self.names.annotation_type(annotation);
+                self.types.names.push((&node.name, expected));
+                self.expect(
+                    expected,
+                    value,
+                    Some(&annotation.location),
+                    "because of this type",
+                );
+            }
+            (&Some(ref annotation), &None) => self
+                .types
+                .names
+                .push((&node.name, self.names.annotation_type(annotation))),
+            (&None, value) => {
+                // Empty literals and generic classes have no type arguments to infer the type from
+                match value
+                    .as_ref()
+                    .and_then(|found| self.types.type_of(found))
+                    .filter(|found| *found != "none" && !self.is_malformed(found))
+                {
+                    Some(found) => self.infer(node, found),
+                    None => self.pending.push(node),
+                }
+            }
+        }
+    }
+
+    fn visit_for(&mut self, node: &'ast For) {
+        let element: Option<&str> = match node.iterable {
+            ForIterable::Range(ref range) => {
+                for bound in [&range.start, &range.end].into_iter().chain(&range.step) {
+                    self.visit_expression(bound);
+                    self.expect("int", bound, None, "because ranges count with `int` values");
+                }
+                Some("int")
+            }
+            ForIterable::Value(ref value) => {
+                self.visit_expression(value);
+                match self.types.type_of(value) {
+                    Some(found) => {
+                        let found: &str = self.dereference(found, value, "iterated");
+                        self.element(found, value)
+                    }
+                    None => None,
+                }
+            }
+        };
+
+        let declared: Option<&str> = node
+            .type_annotation
+            .as_ref()
+            .map(|annotation| self.names.annotation_type(annotation));
+        if let (Some(declared), Some(element)) = (declared, element) {
+            if !self.is_compatible(declared, element) {
+                self.types.diagnostics.push(
+                    Diagnostic::error("E0023", "mismatched types", node.variable.location.clone())
+                        .with_label(&format!("expected `{declared}`, found `{element}`"))
+                        .with_note(
+                            &format!("the loop produces values of type `{element}`"),
+                            match node.iterable {
+                                ForIterable::Range(ref range) => Some(range.location.clone()),
+                                ForIterable::Value(ref value) => Some(value.location().clone()),
+                            },
+                        ),
+                );
+            }
+        }
+        if let Some(found) = declared.or(element) {
+            self.bindings.insert(address(&node.variable), found);
+            self.types.names.push((&node.variable, found));
+        }
+
+        self.forget_assigned(&node.body.statements);
+        self.visit_block(&node.body);
+    }
+
+    fn visit_while(&mut self, node: &'ast While) {
+        self.forget_assigned(&node.body.statements);
+        self.condition(&node.condition);
+        let (narrowed, assigned): (usize, usize) = (self.narrowed.len(), self.assigned.len());
+        self.narrowed.extend(self.facts(&node.condition, true));
+        self.visit_block(&node.body);
+        self.restore(narrowed, assigned);
+    }
+
+    fn visit_block(&mut self, node: &'ast Block) {
+        let (narrowed, assigned): (usize, usize) = (self.narrowed.len(), self.assigned.len());
+        visit::walk_block(self, node);
+        self.restore(narrowed, assigned);
+    }
+
+    fn visit_assert(&mut self, node: &'ast Assert) {
+        self.condition(&node.condition);
+        if let Some(ref message) = node.message {
+            self.visit_expression(message);
+            self.expect(
+                "str",
+                message,
+                None,
+                "because the message of an assertion must be a `str`",
+            );
+        }
+    }
+
+    fn visit_yield(&mut self, node: &'ast Yield) {
+        self.visit_expression(&node.value);
+
+        // The return type of a generator is `Iterator<T>`, see `generators::check`
+        let Some(&returned) = self.returns.last() else {
+            return;
+        };
+        let (base, arguments): (&str, Vec<&str>) = generics::split(returned);
+        if let (prelude::ITERATOR, &[element]) = (base, arguments.as_slice()) {
+            self.expect(
+                element,
+                &node.value,
+                None,
+                "because the generator produces values of this type",
+            );
+        }
+    }
+
+    fn visit_return(&mut self, node: &'ast Return) {
+        let Some(ref value) = node.value else {
+            return;
+        };
+        self.visit_expression(value);
+
+        // Other mismatched return values are reported by `functions::check`, which does not know nullable types
+        let (Some(&expected), Some(found)) = (self.returns.last(), self.types.type_of(value))
+        else {
+            return;
+        };
+        if expected != "none"
+            && generics::is_nullable(found)
+            && !generics::is_nullable(expected)
+            && self.is_compatible(expected, generics::non_null(found))
+        {
+            self.expect(expected, value, None, "because of the return type");
+        }
+    }
+}
+
+/// Returns whether a block always leaves the code around it, because it ends with `return`, `throw`, `break` or
+/// `continue`.
+fn leaves(block: &Block) -> bool {
+    matches!(
+        block.statements.last(),
+        Some(
+            Statement::Return(_)
+                | Statement::Throw(_)
+                | Statement::Break(_)
+                | Statement::Continue(_)
+        )
+    )
+}
+
+/// A visitor collecting the variables assigned by functions and lambdas they are not declared in: the variables of
+/// the module assigned in functions, methods or lambdas, and the variables lambdas capture and assign.
+struct SharedFinder<'table, 'ast> {
+    /// The declarations of the names used in the program.
+    resolution: &'table Resolution<'ast>,
+    /// The declared names of the variables of the module.
+    globals: Vec<*const Identifier>,
+    /// The number of functions the visited node is nested in.
+    functions: usize,
+    /// The lambdas the visited node is nested in, innermost last.
+    lambdas: Vec<&'ast Lambda>,
+    /// The names of the assigned variables.
+    targets: Vec<&'ast Identifier>,
+}
+
+impl<'ast> Visitor<'ast> for SharedFinder<'_, 'ast> {
+    fn visit_function(&mut self, node: &'ast Function) {
+        self.functions += 1;
+        visit::walk_function(self, node);
+        self.functions -= 1;
+    }
+
+    fn visit_lambda(&mut self, node: &'ast Lambda) {
+        self.lambdas.push(node);
+        visit::walk_lambda(self, node);
+        self.lambdas.pop();
+    }
+
+    fn visit_assignment(&mut self, node: &'ast Assignment) {
+        if let Expression::Identifier(ref identifier) = *node.target {
+            let global: bool = match self.resolution.declaration(identifier) {
+                Some(Declaration::Variable(variable)) => {
+                    self.globals.contains(&address(&variable.name))
+                }
+                _ => false,
+            };
+            let captured: bool = self.lambdas.last().map_or(false, |lambda| {
+                self.resolution
+                    .captures(lambda)
+                    .contains(&identifier.name.as_str())
+            });
+            if captured || (global && (self.functions > 0 || !self.lambdas.is_empty())) {
+                self.targets.push(identifier);
+            }
+        }
+        visit::walk_assignment(self, node);
+    }
+}
+
+/// A visitor collecting the variables declared with `var` that are initialized with or assigned `none`, see
+/// [`TypeChecker::infer`].
+struct NoneFinder<'table, 'ast> {
+    /// The declarations of the names used in the program.
+    resolution: &'table Resolution<'ast>,
+    /// The found variables.
+    variables: Vec<*const VariableDeclaration>,
+}
+
+impl<'ast> Visitor<'ast> for NoneFinder<'_, 'ast> {
+    fn visit_variable_declaration(&mut self, node: &'ast VariableDeclaration) {
+        if node.type_annotation.is_none() && node.value.as_ref().map_or(false, is_none) {
+            self.variables.push(address(node));
+        }
+        visit::walk_variable_declaration(self, node);
+    }
+
+    fn visit_assignment(&mut self, node: &'ast Assignment) {
+        if let Expression::Identifier(ref identifier) = *node.target {
+            if let Some(Declaration::Variable(variable)) = self.resolution.declaration(identifier) {
+                if variable.type_annotation.is_none() && is_none(&node.value) {
+                    self.variables.push(address(variable));
+                }
+            }
+        }
+        visit::walk_assignment(self, node);
+    }
+}
+
+/// A visitor collecting the variables assigned in a list of statements, see [`TypeChecker::forget_assigned`].
+struct AssignmentFinder<'ast> {
+    /// The names of the assigned variables.
+    targets: Vec<&'ast Identifier>,
+}
+
+impl<'ast> Visitor<'ast> for AssignmentFinder<'ast> {
+    fn visit_assignment(&mut self, node: &'ast Assignment) {
+        if let Expression::Identifier(ref identifier) = *node.target {
+            self.targets.push(identifier);
+        }
+        visit::walk_assignment(self, node);
+    }
+}
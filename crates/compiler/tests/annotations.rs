@@ -0,0 +1,183 @@
+// I Language annotation tests.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::{check_with, parse};
+
+    use compiler::annotations::{self, Inlining, Plugin, Plugins, Target};
+    use compiler::diagnostic::Diagnostic;
+    use compiler::modules::Imports;
+    use parser::ast::{Annotation, Program, Statement};
+
+    fn check(input: &str) -> Vec<&'static str> {
+        check_with(annotations::check, input)
+    }
+
+    #[test]
+    fn test_valid_annotations() {
+        assert_eq!(
+            check(
+                "@deprecated(\"Use jump instead\") @inline
+                function hop() {}
+                @deprecated class Old {
+                    @deprecated(message = \"Use height\") pub int size;
+                    pub Old() { this.size = 1; }
+                    @noinline pub function grow() { this.size += 1; }
+                }
+                @test function hops() {}"
+            ),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_invalid_annotations() {
+        assert_eq!(check("@unknown function f() {}"), vec!["E0066"]);
+        assert_eq!(check("@test @test function f() {}"), vec!["E0067"]);
+        assert_eq!(check("@deprecated(1) function f() {}"), vec!["E0067"]);
+        assert_eq!(
+            check("@deprecated(\"a\", \"b\") function f() {}"),
+            vec!["E0067"]
+        );
+        assert_eq!(
+            check("@deprecated(reason = \"a\") function f() {}"),
+            vec!["E0067"]
+        );
+        assert_eq!(check("@test(1) function f() {}"), vec!["E0067"]);
+        assert_eq!(check("@test function f(int x) {}"), vec!["E0067"]);
+        assert_eq!(check("@test class A {}"), vec!["E0067"]);
+        assert_eq!(check("class A { @test function f() {} }"), vec!["E0067"]);
+        assert_eq!(check("@inline class A {}"), vec!["E0067"]);
+        assert_eq!(check("class A { @noinline pub int x; }"), vec!["E0067"]);
+        assert_eq!(check("@inline @noinline function f() {}"), vec!["E0067"]);
+        assert_eq!(check("@inline(true) function f() {}"), vec!["E0067"]);
+    }
+
+    #[test]
+    fn test_deprecated() {
+        let diagnostics: Vec<Diagnostic> = annotations::check(
+            &parse(
+                "@deprecated(\"Use jump instead\") function hop() -> int { return hop(); }
+                var height = hop();",
+            ),
+            &Imports::default(),
+        );
+        // The recursive call inside `hop` is not reported
+        assert_eq!(diagnostics.len(), 1);
+        assert!(!diagnostics[0].is_error());
+        assert_eq!(diagnostics[0].message, "use of deprecated function `hop`");
+        assert_eq!(diagnostics[0].location.line, 2);
+        assert_eq!(diagnostics[0].notes[0].message, "Use jump instead");
+
+        assert_eq!(
+            check(
+                "class Player {
+                    @deprecated pub int size = 1;
+                    pub Player() {}
+                    @deprecated pub function hop() {}
+                }
+                @deprecated class Old {}
+                Player player = Player();
+                player.hop();
+                print(player.size);
+                Old old = Old();"
+            ),
+            vec!["E0068", "E0068", "E0068", "E0068"]
+        );
+    }
+
+    #[test]
+    fn test_plugins() {
+        struct Pure;
+
+        impl Plugin for Pure {
+            fn name(&self) -> &'static str {
+                "pure"
+            }
+
+            fn check(&self, annotation: &Annotation, target: Target<'_>) -> Vec<Diagnostic> {
+                match target {
+                    Target::Function(_) => vec![],
+                    _ => vec![Diagnostic::error(
+                        "E0067",
+                        "`@pure` can only be used on functions",
+                        annotation.location.clone(),
+                    )],
+                }
+            }
+        }
+
+        let mut plugins: Plugins = Plugins::default();
+        plugins.register(Box::new(Pure));
+        let program: Program =
+            parse("@pure function f() {} @pure class A {} @test function g() {}");
+        let codes: Vec<&str> = annotations::check_with(&program, &Imports::default(), &plugins)
+            .iter()
+            .map(|diagnostic: &Diagnostic| diagnostic.code)
+            .collect();
+        assert_eq!(codes, vec!["E0067"]);
+
+        // Without the built-in plugins, `@test` is unknown
+        let mut plugins: Plugins = Plugins::new();
+        plugins.register(Box::new(Pure));
+        let codes: Vec<&str> = annotations::check_with(&program, &Imports::default(), &plugins)
+            .iter()
+            .map(|diagnostic: &Diagnostic| diagnostic.code)
+            .collect();
+        assert_eq!(codes, vec!["E0067", "E0066"]);
+    }
+
+    #[test]
+    fn test_queries() {
+        let program: Program = parse(
+            "@test function first() {}
+            @inline function helper() {}
+            @noinline @test function second() {}",
+        );
+
+        let tests: Vec<&str> = annotations::tests(&program)
+            .iter()
+            .map(|function| function.name.name.as_str())
+            .collect();
+        assert_eq!(tests, vec!["first", "second"]);
+
+        let inlining: Vec<Inlining> = program
+            .statements
+            .iter()
+            .filter_map(|statement| match *statement {
+                Statement::Function(ref function) => Some(annotations::inlining(function)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            inlining,
+            vec![Inlining::Default, Inlining::Always, Inlining::Never]
+        );
+    }
+}
@@ -0,0 +1,218 @@
+// I Language definite assignment tests.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::{check_with, parse};
+
+    use compiler::diagnostic::Diagnostic;
+    use compiler::modules::Imports;
+
+    fn diagnostics(input: &str) -> Vec<Diagnostic> {
+        compiler::assignments::check(&parse(input), &Imports::default())
+    }
+
+    fn check(input: &str) -> Vec<&'static str> {
+        check_with(compiler::assignments::check, input)
+    }
+
+    fn skipped(input: &str) -> String {
+        diagnostics(input)[0].notes[1].message.clone()
+    }
+
+    #[test]
+    fn test_assigned() {
+        assert_eq!(
+            check(
+                "int a; a = 1; print(a);
+                int b; if (a > 0) { b = 1; } else { b = 2; } print(b);
+                int c; while (true) { c = 1; break; } print(c);
+                int d; try { d = 1; } catch ValueError { d = 2; } print(d);
+                int e; try { } finally { e = 1; } print(e);
+                int f; match (a) { case 1 { f = 1; } default { f = 2; } } print(f);
+                int g; if (a > 0) { g = 1; } else { throw ValueError(); } print(g);
+                function h(bool flag) -> int {
+                    int result;
+                    if (flag) { return 0; }
+                    result = 1;
+                    return result;
+                }
+                int i; while (a > 0) { i = 1; print(i); }"
+            ),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_unassigned() {
+        assert_eq!(check("int goal; print(goal);"), vec!["E0047"]);
+        assert_eq!(check("int goal; goal += 1;"), vec!["E0047"]);
+        assert_eq!(check("int goal; print(goal); print(goal);"), vec!["E0047"]);
+        assert_eq!(
+            check("function f() { int goal; print(goal); }"),
+            vec!["E0047"]
+        );
+        assert_eq!(
+            diagnostics("int goal; print(goal);")[0].message,
+            "`goal` is used before it is assigned"
+        );
+
+        // A `finally` block may run before or after the assignments of the `try` block
+        assert_eq!(
+            diagnostics("int goal; try { goal = 1; } finally { print(goal); }")[0].message,
+            "`goal` may be used before it is assigned"
+        );
+        assert_eq!(
+            diagnostics(
+                "int goal; try { } catch ValueError { goal = 1; } finally { print(goal); }"
+            )[0]
+            .message,
+            "`goal` may be used before it is assigned"
+        );
+        assert_eq!(
+            diagnostics("int goal; try { } finally { print(goal); }")[0].message,
+            "`goal` is used before it is assigned"
+        );
+    }
+
+    #[test]
+    fn test_paths() {
+        assert_eq!(
+            skipped("int goal; if (true) { goal = 1; } print(goal);"),
+            "`goal` is not assigned when the condition is false"
+        );
+        assert_eq!(
+            skipped("int goal; if (true) { goal = 1; } else { } print(goal);"),
+            "`goal` is not assigned in this branch"
+        );
+        assert_eq!(
+            skipped("int goal; while (false) { goal = 1; } print(goal);"),
+            "`goal` is not assigned if the loop body does not run"
+        );
+        assert_eq!(
+            skipped(
+                "int goal; while (true) { if (false) { break; } goal = 1; break; } print(goal);"
+            ),
+            "`goal` is not assigned when the loop is left with `break`"
+        );
+        assert_eq!(
+            skipped("int goal; try { goal = 1; } catch ValueError { } print(goal);"),
+            "`goal` is not assigned if this exception is caught"
+        );
+        assert_eq!(
+            skipped("int goal; try { goal = 1; } finally { print(goal); }"),
+            "`goal` is not assigned if an exception is thrown before its assignment"
+        );
+        assert_eq!(
+            skipped("int goal; match (1) { case 1 { goal = 1; } } print(goal);"),
+            "`goal` is not assigned when no case matches"
+        );
+        assert_eq!(
+            skipped("int goal; match (1) { case 1 { goal = 1; } default { } } print(goal);"),
+            "`goal` is not assigned in this case"
+        );
+        assert_eq!(
+            diagnostics("int goal;\nif (true) {\ngoal = 1;\n}\nprint(goal);")[0].notes[1]
+                .location
+                .as_ref()
+                .unwrap()
+                .line,
+            2
+        );
+    }
+
+    #[test]
+    fn test_control_flow() {
+        assert_eq!(
+            check(
+                "int goal; while (true) { if (false) { continue; } goal = 1; break; } print(goal);"
+            ),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            check("int goal; bool done = false && (goal = 1) == 1; print(goal);"),
+            vec!["E0047"]
+        );
+        assert_eq!(
+            check("int goal; try { goal = 1; } finally { print(goal); }"),
+            vec!["E0047"]
+        );
+        assert_eq!(
+            check("function f() -> int { int goal; return goal; }"),
+            vec!["E0047"]
+        );
+    }
+
+    #[test]
+    fn test_initial_values() {
+        let classes: &str = "class A { pub int value = 1; }\n";
+        assert_eq!(
+            check(&format!(
+                "{classes}class B {{
+                    pub A first = A();
+                    pub A? second;
+                    pub str name;
+                    pub A third;
+                    pub B() {{ this.third = A(); }}
+                }}
+                class Box<T> {{ pub T value; pub Box(T value) {{ this.value = value; }} }}
+                A? maybe;
+                list<int> items = [];
+                function f() {{ A local; local = A(); print(local.value); }}"
+            )),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            check(&format!(
+                "{classes}class B {{ pub A a; }} print(B().a.value);"
+            )),
+            vec!["E0077"]
+        );
+        assert_eq!(
+            check(&format!(
+                "{classes}class B {{ pub A a; pub B() {{ if (true) {{ this.a = A(); }} }} }}"
+            )),
+            vec!["E0077"]
+        );
+        assert_eq!(
+            check(&format!(
+                "{classes}A global; function f() {{ print(global.value); }} f(); global = A();"
+            )),
+            vec!["E0077"]
+        );
+        let diagnostics: Vec<Diagnostic> = diagnostics("list<int> items;");
+        assert_eq!(
+            diagnostics[0].message,
+            "variable `items` has no initial value"
+        );
+        assert_eq!(
+            diagnostics[0].notes[0].message,
+            "give it a value or declare it as `list<int>?`"
+        );
+    }
+}
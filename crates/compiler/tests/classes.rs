@@ -0,0 +1,261 @@
+// I Language class check tests.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::{check_with, parse};
+
+    use compiler::diagnostic::Diagnostic;
+    use compiler::modules::Imports;
+
+    fn check(input: &str) -> Vec<&'static str> {
+        check_with(compiler::classes::check, input)
+    }
+
+    #[test]
+    fn test_valid_classes() {
+        assert_eq!(
+            check(
+                "class Entity {
+                    pub int health = 100;
+                    pub Entity(int health) { this.health = health; }
+                    pub function damage(int amount) { this.health -= amount; }
+                    pub function name() -> str;
+                }
+
+                pub class Player: Entity {
+                    str nickname;
+                    pub function name() -> str { return this.nickname; }
+                    pub function hit() { this.damage(10); super.damage(1); }
+                }
+
+                Player player = Player(100);
+                player.damage(5);
+                player.health = 3;"
+            ),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_declarations() {
+        assert_eq!(check("class A {} class A {}"), vec!["E0003"]);
+        assert_eq!(check("class A: B {}"), vec!["E0004"]);
+        assert_eq!(
+            check("class A {} class B {} class C: A, B {}"),
+            vec!["E0005"]
+        );
+        assert_eq!(check("class A: B {} class B: A {}"), vec!["E0006", "E0006"]);
+    }
+
+    #[test]
+    fn test_members() {
+        assert_eq!(check("class A { int x; function x() {} }"), vec!["E0007"]);
+        assert_eq!(check("class A { A() {} A(int x) {} }"), vec!["E0007"]);
+        assert_eq!(
+            check("class A { int x; } class B: A { int x; }"),
+            vec!["E0008"]
+        );
+        assert_eq!(
+            check("class A { function f(int x) {} } class B: A { function f(str x) {} }"),
+            vec!["E0009"]
+        );
+    }
+
+    #[test]
+    fn test_overrides() {
+        assert_eq!(
+            check("class A { function f(); } class B: A {}"),
+            vec!["E0010"]
+        );
+        // Classes declaring abstract methods themselves are abstract too
+        assert_eq!(
+            check("class A { function f(); } class B: A { function g(); }"),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            check("class A { function f(); } class B: A { function f() {} } B(); A();"),
+            vec!["E0013"]
+        );
+    }
+
+    #[test]
+    fn test_calls() {
+        assert_eq!(
+            check("class A { function f() { this.g(); } }"),
+            vec!["E0011"]
+        );
+        assert_eq!(
+            check("class A { pub function f() {} } A a = A(); a.f(); a.g();"),
+            vec!["E0011"]
+        );
+        assert_eq!(
+            check("class A { function f() {} int x; } A a = A(); a.f(); print(a.x);"),
+            vec!["E0012", "E0012"]
+        );
+        assert_eq!(
+            check(
+                "class A { A(int x) {} pub function f(int y) {} } class B: A {} B b = B(); b.f(1, 2);"
+            ),
+            vec!["E0019", "E0016"]
+        );
+        // Shadowing a variable with a non class type stops the checks
+        assert_eq!(
+            check("class A {} A a = A(); { int a = 1; a.g(); }"),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_interfaces() {
+        assert_eq!(
+            check(
+                "interface Named {
+                    function name() -> str;
+                    function greet() -> str { return \"Hello \" + this.name(); }
+                }
+                interface Shape: Named { function area() -> int; }
+                class Square implements Shape {
+                    pub function area() -> int { return 4; }
+                    pub function name() -> str { return \"square\"; }
+                }
+                class Tile: Square {}
+                Shape shape = Tile();
+                shape.greet();
+                shape.area();"
+            ),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            check("interface Shape { int sides; Shape() {} }"),
+            vec!["E0058", "E0058"]
+        );
+        assert_eq!(check("interface Shape {} Shape();"), vec!["E0059"]);
+        assert_eq!(
+            check(
+                "interface I {} class A {} class B: I {} class C implements A {} interface J: A {}"
+            ),
+            vec!["E0057", "E0057", "E0057"]
+        );
+        assert_eq!(
+            check("class A implements I {} interface J: J {}"),
+            vec!["E0004", "E0006"]
+        );
+        assert_eq!(
+            check("interface I {} interface J { function f(); } I i = none; i.f();"),
+            vec!["E0011"]
+        );
+    }
+
+    #[test]
+    fn test_interface_contract() {
+        let program: parser::ast::Program =
+            parse("interface Shape { function area() -> int; }\nclass Square implements Shape {}");
+        let diagnostics: Vec<Diagnostic> = compiler::classes::check(&program, &Imports::default());
+        assert_eq!(
+            diagnostics[0].message,
+            "class `Square` does not implement `Shape.area`"
+        );
+        assert_eq!(
+            diagnostics[0].notes[0].message,
+            "required by the interface `Shape` here"
+        );
+
+        assert_eq!(
+            check("interface Shape { function area() -> int; } class A implements Shape { pub function area() -> str; }"),
+            vec!["E0009"]
+        );
+        assert_eq!(
+            check("interface Shape { function area() -> int; } class A implements Shape { function area() -> int { return 1; } }"),
+            vec!["E0060"]
+        );
+        // Default methods need no implementation, abstract classes may leave methods to their subclasses
+        assert_eq!(
+            check(
+                "interface Shape { function area() -> int { return 0; } function name() -> str; }
+                class A implements Shape { function draw(); }
+                class B: A { pub function name() -> str { return \"b\"; } function draw() {} }"
+            ),
+            Vec::<&str>::new()
+        );
+        // Type arguments of generic interfaces are substituted into the signatures
+        assert_eq!(
+            check(
+                "interface Comparable<T> { function compare(T other) -> int; }
+                class Number implements Comparable<Number> {
+                    pub function compare(Number other) -> int { return 0; }
+                }"
+            ),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_enums() {
+        assert_eq!(
+            check(
+                "enum Shape {
+                    Circle(int radius), Empty;
+                    pub function area() -> int { return 0; }
+                }
+                Shape shape = Shape.Circle(1);
+                Shape empty = Shape.Empty();
+                shape.area();"
+            ),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            check("enum Shape { Circle(int radius); int size; Shape() {} }"),
+            vec!["E0058", "E0058"]
+        );
+        assert_eq!(
+            check("enum Shape { Empty } class Blob: Shape {}"),
+            vec!["E0061"]
+        );
+        assert_eq!(
+            check("enum Shape { Empty } class Blob: Shape.Empty {}"),
+            vec!["E0061"]
+        );
+        assert_eq!(
+            check("enum Shape { Empty } Shape shape = Shape();"),
+            vec!["E0059"]
+        );
+        assert_eq!(check("enum Shape { Empty, Empty }"), vec!["E0003"]);
+        assert_eq!(
+            check("enum Shape { Circle(int radius) } Shape.Circle(1, 2);"),
+            vec!["E0016"]
+        );
+        assert_eq!(
+            check("enum First { Empty } enum Second { Empty }"),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            check("enum Shape { Empty } class Empty {}"),
+            Vec::<&str>::new()
+        );
+    }
+}
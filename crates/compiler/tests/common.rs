@@ -0,0 +1,61 @@
+// I Language compiler test helpers.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use compiler::diagnostic::Diagnostic;
+use compiler::modules::Imports;
+use parser::ast::Program;
+
+
+/////////////
+// HELPERS //
+/////////////
+
+/// A check of a single compiler pass, e.g. `compiler::types::check`.
+pub type Check = for<'ast> fn(&'ast Program, &Imports<'ast>) -> Vec<Diagnostic>;
+
+/// Lexes and parses a program, panicking on syntax errors.
+pub fn parse(input: &str) -> Program {
+    parser::parse::parse(&lexer::lex::lex(input, "<stdin>").unwrap()).unwrap()
+}
+
+/// Returns the codes of diagnostics, in their order.
+pub fn codes(diagnostics: &[Diagnostic]) -> Vec<&'static str> {
+    diagnostics
+        .iter()
+        .map(|diagnostic: &Diagnostic| diagnostic.code)
+        .collect()
+}
+
+/// Runs all checks of the compiler on a program and returns the codes of the diagnostics.
+pub fn check(input: &str) -> Vec<&'static str> {
+    codes(&compiler::check::check(&parse(input)))
+}
+
+/// Runs a single check on a program without imports and returns the codes of the diagnostics.
+pub fn check_with(check: Check, input: &str) -> Vec<&'static str> {
+    codes(&check(&parse(input), &Imports::default()))
+}
@@ -0,0 +1,183 @@
+// I Language constant tests.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::{check_with, parse};
+
+    use compiler::constants::{self, Constant, Evaluator};
+    use compiler::diagnostic::Diagnostic;
+    use compiler::modules::Imports;
+    use compiler::resolve::{self, Resolution};
+    use parser::ast::{Expression, Pattern, Program, Statement};
+
+    fn diagnostics(input: &str) -> Vec<Diagnostic> {
+        constants::check(&parse(input), &Imports::default())
+    }
+
+    fn check(input: &str) -> Vec<&'static str> {
+        check_with(constants::check, input)
+    }
+
+    fn value(input: &str) -> Option<Constant> {
+        let program: Program = parse(input);
+        let resolution: Resolution = resolve::resolve(&program, &Imports::default());
+        let mut evaluator: Evaluator = Evaluator::new(&resolution);
+
+        program
+            .statements
+            .iter()
+            .rev()
+            .find_map(|statement| match *statement {
+                Statement::VariableDeclaration(ref declaration) => evaluator.constant(declaration),
+                _ => None,
+            })
+    }
+
+    #[test]
+    fn test_evaluate() {
+        assert_eq!(value("const int MAX = 25 * 4;"), Some(Constant::Int(100)));
+        assert_eq!(
+            value("const int MAX = 25 * 4; const int HALF = MAX / 2 - 1;"),
+            Some(Constant::Int(49))
+        );
+        assert_eq!(
+            value("const LIMIT = 0 - 2 ** 3 % 5;"),
+            Some(Constant::Int(-3))
+        );
+        assert_eq!(
+            value("const int MASK = 1 << 4 | 1;"),
+            Some(Constant::Int(17))
+        );
+        assert_eq!(
+            value("const str NAME = \"I\" + \" Language\";"),
+            Some(Constant::Str("I Language".to_owned()))
+        );
+        assert_eq!(
+            value("const int MAX = 25; const bool BIG = MAX > 10 && !false;"),
+            Some(Constant::Bool(true))
+        );
+        assert_eq!(value("int goal = 3; const int MAX = goal;"), None);
+    }
+
+    #[test]
+    fn test_valid() {
+        assert_eq!(
+            check(
+                "const int MAX = 25 * 4;
+                const int MIN = 0 - MAX;
+                int goal = MAX;
+                goal = MIN;
+                function f(int limit = MAX) -> int { return limit; }"
+            ),
+            Vec::<&str>::new()
+        );
+        assert!(compiler::check::check(&parse(
+            "const int MAX = 100; int goal = 3;
+            match (goal) { case MAX -> print(1); case 3 -> print(2); default -> print(3); }"
+        ))
+        .is_empty());
+    }
+
+    #[test]
+    fn test_not_constant() {
+        assert_eq!(check("int goal = 3; const int MAX = goal;"), vec!["E0048"]);
+        assert_eq!(check("const int MAX = int(\"3\");"), vec!["E0048"]);
+        assert_eq!(check("const int MAX = -(1);"), Vec::<&str>::new());
+
+        let diagnostic: &Diagnostic = &diagnostics("int goal = 3; const int MAX = goal;")[0];
+        assert_eq!(diagnostic.label, "`goal` is not a constant");
+        assert_eq!(
+            diagnostic.notes[1].message,
+            "the value of `MAX` must be known at compile time"
+        );
+    }
+
+    #[test]
+    fn test_assign_constant() {
+        assert_eq!(check("const int MAX = 100; MAX = 1;"), vec!["E0049"]);
+        assert_eq!(check("const int MAX = 100; MAX += 1;"), vec!["E0049"]);
+        assert_eq!(check("const int MAX = 100; MAX++;"), vec!["E0049"]);
+        assert_eq!(
+            check("const int MAX = 100; function f() { int MAX = 1; MAX = 2; }"),
+            Vec::<&str>::new()
+        );
+
+        let diagnostic: &Diagnostic = &diagnostics("const int MAX = 100; MAX = 1;")[0];
+        assert_eq!(diagnostic.message, "cannot assign to constant `MAX`");
+        assert_eq!(
+            diagnostic.notes[0].message,
+            "`MAX` is declared as a constant here"
+        );
+    }
+
+    #[test]
+    fn test_evaluation_failed() {
+        assert_eq!(
+            check("const int MAX = 9223372036854775807 + 1;"),
+            vec!["E0050"]
+        );
+        assert_eq!(check("const int MAX = 10 / (5 - 5);"), vec!["E0050"]);
+        assert_eq!(check("const int MAX = 2 ** 100;"), vec!["E0050"]);
+        assert_eq!(check("const int MAX = 1 << 64;"), vec!["E0050"]);
+        assert_eq!(check("const int MAX = 2 ** (0 - 1);"), vec!["E0050"]);
+        assert_eq!(
+            diagnostics("const int MAX = 10 / (5 - 5);")[0].label,
+            "`10 / 0` divides by zero"
+        );
+    }
+
+    #[test]
+    fn test_fold() {
+        let program: Program = constants::fold(parse(
+            "const int MAX = 100;
+            function f(int limit = MAX + 1) -> int { return limit; }
+            match (3) { case MAX -> print(1); case value -> print(value); }",
+        ));
+
+        let Statement::Function(ref function) = program.statements[1] else {
+            panic!("expected a function declaration");
+        };
+        assert!(matches!(
+            function.parameters[0].default,
+            Some(Expression::Literal(ref literal)) if literal.value == "101"
+        ));
+
+        let Statement::Match(ref statement) = program.statements[2] else {
+            panic!("expected a match statement");
+        };
+        assert!(matches!(
+            statement.arms[0].pattern,
+            Some(Pattern::Literal(ref literal)) if literal.value == "100"
+        ));
+        assert!(matches!(
+            statement.arms[1].pattern,
+            Some(Pattern::Binding(_))
+        ));
+    }
+}
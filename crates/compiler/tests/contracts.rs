@@ -0,0 +1,120 @@
+// I Language contract tests.
+// Version: 1.0.0
+
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::{check, parse};
+
+    use compiler::contracts;
+    use parser::ast::{Program, Statement};
+
+    #[test]
+    fn test_valid_contracts() {
+        assert_eq!(
+            check(
+                "function divide(int x, int y) -> int requires y != 0 ensures result * y <= x {
+                    assert x >= 0, \"negative dividend\";
+                    return x / y;
+                }
+                class Account {
+                    pub int balance;
+                    pub Account(int balance) requires balance >= 0 { this.balance = balance; }
+                }
+                int quotient = divide(7, 2);
+                assert quotient == 3;
+                Account account = Account(10);"
+            ),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_invalid_contracts() {
+        assert_eq!(
+            check("function f(int x) -> int requires 1 > 2 { return x; }"),
+            vec!["E0073"]
+        );
+        assert_eq!(
+            check("function f() -> int ensures false { return 1; }"),
+            vec!["E0073"]
+        );
+        assert_eq!(
+            check(
+                "function divide(int x, int y) -> int requires y != 0 { return x / y; }
+                divide(1, 0);"
+            ),
+            vec!["E0073"]
+        );
+        assert_eq!(
+            check(
+                "function divide(int x, int y = 0) -> int requires y != 0 { return x / y; }
+                divide(1);
+                divide(1, y = 2);"
+            ),
+            vec!["E0073"]
+        );
+        assert_eq!(check("assert 1 == 2;"), vec!["E0074"]);
+        assert_eq!(check("assert 1;"), vec!["E0023"]);
+        assert_eq!(check("assert true, 1;"), vec!["E0023"]);
+        assert_eq!(
+            check("function f(int x) -> int requires x { return x; }"),
+            vec!["E0023"]
+        );
+        assert_eq!(check("function f() ensures result { }"), vec!["E0023"]);
+        assert_eq!(
+            check("function f(int x) -> int requires result > 0 { return x; }"),
+            vec!["E0038"]
+        );
+    }
+
+    #[test]
+    fn test_strip() {
+        let mut program: Program = parse(
+            "assert true;
+            function f(int x) -> int requires x > 0 ensures result > 0 {
+                assert x != 1;
+                if (x > 2) { assert x != 3, \"three\"; return x; }
+                return 1;
+            }",
+        );
+        contracts::strip(&mut program);
+
+        assert_eq!(program.statements.len(), 1);
+        let Statement::Function(ref function) = program.statements[0] else {
+            panic!("expected a function");
+        };
+        assert!(function.requires.is_empty() && function.ensures.is_empty());
+        let statements: &[Statement] = &function.body.as_ref().unwrap().statements;
+        assert_eq!(statements.len(), 2);
+        let Statement::If(ref node) = statements[0] else {
+            panic!("expected an if statement");
+        };
+        assert_eq!(node.then_block.statements.len(), 1);
+    }
+}
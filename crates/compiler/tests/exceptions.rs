@@ -0,0 +1,83 @@
+// I Language exception check tests.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::check_with;
+
+    fn check(input: &str) -> Vec<&'static str> {
+        check_with(compiler::exceptions::check, input)
+    }
+
+    #[test]
+    fn test_valid_exceptions() {
+        assert_eq!(
+            check(
+                "class NegativeError: ValueError {}
+                function f(int x) -> int {
+                    if (x < 0) { throw NegativeError(\"negative\"); }
+                    return x;
+                }
+                try {
+                    f(1);
+                } catch NegativeError(error) {
+                    print(error.message);
+                    throw error;
+                } catch ValueError {
+                } catch Exception(error) {
+                    throw RuntimeError();
+                } finally { }"
+            ),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_throw() {
+        assert_eq!(check("throw 1;"), vec!["E0032"]);
+        assert_eq!(check("class A {} throw A();"), vec!["E0032"]);
+        assert_eq!(
+            check("str message = \"error\"; throw message;"),
+            vec!["E0032"]
+        );
+    }
+
+    #[test]
+    fn test_catch() {
+        assert_eq!(check("class A {} try { } catch A { }"), vec!["E0031"]);
+        assert_eq!(check("try { } catch int(value) { }"), vec!["E0031"]);
+        assert_eq!(
+            check("try { } catch Exception { } catch TypeError { }"),
+            vec!["E0033"]
+        );
+        assert_eq!(
+            check("try { } catch ValueError { } catch ValueError { }"),
+            vec!["E0033"]
+        );
+    }
+}
@@ -0,0 +1,191 @@
+// I Language control flow tests.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::{check_with, parse};
+
+    use compiler::classes::ClassTable;
+    use compiler::diagnostic::Diagnostic;
+    use compiler::flow::Graph;
+    use compiler::modules::Imports;
+    use parser::ast::Program;
+
+    fn diagnostics(input: &str) -> Vec<Diagnostic> {
+        compiler::flow::check(&parse(input), &Imports::default())
+    }
+
+    fn check(input: &str) -> Vec<&'static str> {
+        check_with(compiler::flow::check, input)
+    }
+
+    #[test]
+    fn test_graph() {
+        let program: Program =
+            parse("int x = 1; while (x < 10) { x++; } print(x); return; print(0);");
+        let graph: Graph = Graph::build(
+            &program.statements,
+            &ClassTable::new(&program, &Imports::default()),
+        );
+        let reached: Vec<bool> = graph.reachable(graph.entry);
+
+        let blocks: Vec<usize> = program
+            .statements
+            .iter()
+            .map(|statement| graph.block_of(statement).unwrap())
+            .collect();
+        assert_eq!(blocks[0], graph.entry);
+        assert_eq!(blocks[1], graph.entry);
+        assert!(reached[blocks[2]]);
+        assert!(!reached[blocks[4]]);
+        assert!(!reached[graph.end]);
+        assert!(reached[graph.exit]);
+        assert_eq!(graph.loops.len(), 1);
+    }
+
+    #[test]
+    fn test_loops() {
+        assert_eq!(
+            check(
+                "while (true) { break; }
+                while (true) { if (true) { continue; } break; }
+                function f() { while (true) { return; } }
+                for (var i in 0 .. 3) { if (i == 1) { continue; } break; }"
+            ),
+            Vec::<&str>::new()
+        );
+        assert_eq!(check("break;"), vec!["E0051"]);
+        assert_eq!(check("if (true) { continue; }"), vec!["E0051"]);
+        assert_eq!(
+            check("while (true) { function f() { break; } break; }"),
+            vec!["E0051"]
+        );
+        assert_eq!(check("for (var i in 0 .. 3) { } break;"), vec!["E0051"]);
+        assert_eq!(
+            diagnostics("continue;")[0].message,
+            "`continue` outside of a loop"
+        );
+    }
+
+    #[test]
+    fn test_unreachable() {
+        assert_eq!(
+            check(
+                "function f(bool a) {
+                    if (a) { return; }
+                    print(1);
+                    try { throw ValueError(); } catch ValueError { print(2); }
+                    print(3);
+                }"
+            ),
+            Vec::<&str>::new()
+        );
+        assert_eq!(check("function f() { return; print(1); }"), vec!["E0052"]);
+        assert_eq!(
+            check("throw ValueError(); print(1); print(2);"),
+            vec!["E0052"]
+        );
+        assert_eq!(check("while (true) { continue; print(1); }"), vec!["E0052"]);
+        assert_eq!(
+            check("function f(bool a) { if (a) { return; } else { return; } print(1); }"),
+            vec!["E0052"]
+        );
+        assert_eq!(check("while (true) { } print(1);"), vec!["E0052"]);
+
+        let diagnostic: &Diagnostic = &diagnostics("function f() { return; print(1); }")[0];
+        assert_eq!(diagnostic.label, "this code is never run");
+        assert_eq!(diagnostic.notes[0].location.as_ref().unwrap().column, 16);
+        assert_eq!(
+            diagnostic.notes[0].message,
+            "any code after this `return` is never run"
+        );
+    }
+
+    #[test]
+    fn test_returns() {
+        assert_eq!(
+            check(
+                "function f(bool a) -> int { if (a) { return 1; } else { return 0; } }
+                function g(bool a) -> int { match (a) { case true { return 1; } case false { return 0; } } }
+                function h(int a) -> int { while (true) { if (a > 0) { return a; } a++; } }
+                function i() -> int { try { return 1; } catch ValueError { return 0; } }
+                function j() -> int { try { print(1); } finally { return 0; } }
+                function k() { print(1); }"
+            ),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            check("function f(bool a) -> int { if (a) { return 1; } }"),
+            vec!["E0024"]
+        );
+        assert_eq!(
+            check("function f() -> int { while (true) { break; } }"),
+            vec!["E0024"]
+        );
+        assert_eq!(
+            check("function f(int a) -> int { match (a) { case 1 { return 1; } } }"),
+            vec!["E0024"]
+        );
+        assert_eq!(
+            check("function f() -> int { try { return 1; } catch ValueError { print(0); } }"),
+            vec!["E0024"]
+        );
+        assert_eq!(
+            check("class A { function f() -> int { print(1); } }"),
+            vec!["E0024"]
+        );
+        assert_eq!(
+            check("var f = (bool a) -> int { if (a) { return 1; } };"),
+            vec!["E0024"]
+        );
+        assert_eq!(
+            check("var f = (bool a) -> a; var g = () -> int { return 1; };"),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_endless_loop() {
+        assert_eq!(
+            check("function f() -> int { while (true) { print(1); } }"),
+            vec!["E0053"]
+        );
+        assert_eq!(
+            check("function f() { while (true) { print(1); } }"),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            check("function f() -> int { while (true) { throw ValueError(); } }"),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            diagnostics("function f() -> int { while (true) { } }")[0].label,
+            "this loop never ends"
+        );
+    }
+}
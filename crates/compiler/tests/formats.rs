@@ -0,0 +1,116 @@
+// I Language format string tests.
+// Version: 1.0.0
+
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::check;
+
+    use compiler::formats::{Alignment, Presentation, Specification};
+
+    #[test]
+    fn test_valid_strings() {
+        assert_eq!(
+            check(
+                "str name = \" Ann \".strip().upper();
+                list<str> words = \"a b\".split(\" \");
+                int? position = name.find(\"N\");
+                bool found = name.contains(\"A\") && name.starts_with(\"A\") || name.ends_with(\"n\");
+                str text = f\"{name:>8} {words.len():03} {255:x} {u8(7):b} {name:.2} {words:?} {{}}\";
+                str joined = \", \".join(name.lower().chars()) + \"|\".join(name.graphemes());
+                int count = text.len() + \"abc\".replace(\"b\", \"\").len();
+                for (var character in name) { print(f\"{character}\"); }"
+            ),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_invalid_strings() {
+        assert_eq!(check("print(f\"{1:9z}\");"), vec!["E0075"]);
+        assert_eq!(check("print(f\"{1:.}\");"), vec!["E0075"]);
+        assert_eq!(check("print(f\"{'a':x}\");"), vec!["E0075"]);
+        assert_eq!(check("print(f'{\"a\":05}');"), vec!["E0075"]);
+        assert_eq!(check("print(f'{42:.3}');"), vec!["E0075"]);
+        assert_eq!(check("print(f'{missing}');"), vec!["E0038"]);
+        assert_eq!(check("int position = \"abc\".find(\"b\");"), vec!["E0023"]);
+        assert_eq!(check("\"abc\".split(1);"), vec!["E0023"]);
+        assert_eq!(check("\", \".join([1, 2]);"), vec!["E0023"]);
+        assert_eq!(check("\"abc\".reverse();"), vec!["E0011"]);
+        assert_eq!(check("\"abc\".replace(\"a\");"), vec!["E0019"]);
+    }
+
+    #[test]
+    fn test_specification() {
+        assert_eq!(
+            Specification::parse("*^+9"),
+            Err(
+                "unexpected `+9`, expected `[[fill]align][0][width][.precision][type]` with a type of `?`, `b`, `o`, \
+                 `x` or `X`"
+                    .to_owned()
+            )
+        );
+        let specification: Specification = Specification::parse("0>12.4X").unwrap();
+        assert_eq!(
+            (
+                specification.fill,
+                specification.alignment,
+                specification.zero,
+                specification.width,
+                specification.precision,
+                specification.presentation
+            ),
+            (
+                Some('0'),
+                Some(Alignment::Right),
+                false,
+                12,
+                Some(4),
+                Presentation::UpperHex
+            )
+        );
+
+        for (text, value, integer, expected) in [
+            ("", "abc", false, "abc"),
+            ("5", "abc", false, "abc  "),
+            ("5", "42", true, "   42"),
+            ("^6", "ab", false, "  ab  "),
+            ("_^5", "ab", false, "_ab__"),
+            ("<04", "7", true, "7000"),
+            ("06", "-12", true, "-00012"),
+            (".2", "日本語", false, "日本"),
+            ("2", "日本語", false, "日本語"),
+        ] {
+            assert_eq!(
+                Specification::parse(text).unwrap().align(value, integer),
+                expected,
+                "{text}"
+            );
+        }
+    }
+}
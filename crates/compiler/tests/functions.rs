@@ -0,0 +1,125 @@
+// I Language function check tests.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::check_with;
+
+    fn check(input: &str) -> Vec<&'static str> {
+        check_with(compiler::functions::check, input)
+    }
+
+    #[test]
+    fn test_valid_functions() {
+        assert_eq!(
+            check(
+                "greet(\"Max\", excited = true);
+
+                function greet(str name, int times = 1, bool excited = false) -> str {
+                    if (excited) {
+                        return name + \"!!\";
+                    } else if (times > 1) {
+                        return name;
+                    }
+                    while (true) {
+                        times -= 1;
+                    }
+                }
+
+                function log(str message) {
+                    return;
+                }
+
+                log(message = greet(\"Max\"));"
+            ),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_declarations() {
+        assert_eq!(check("function f(int a, str a) {}"), vec!["E0014"]);
+        assert_eq!(check("function f(int a = 1, str b) {}"), vec!["E0015"]);
+        assert_eq!(check("function f(int a = \"abc\") {}"), vec!["E0023"]);
+        assert_eq!(check("function f();"), vec!["E0025"]);
+    }
+
+    #[test]
+    fn test_arguments() {
+        assert_eq!(check("function f(int a) {} f(1, 2);"), vec!["E0016"]);
+        assert_eq!(
+            check("function f(int a) {} f(b = 1);"),
+            vec!["E0017", "E0019"]
+        );
+        assert_eq!(check("function f(int a) {} f(1, a = 2);"), vec!["E0018"]);
+        assert_eq!(check("function f(int a, int b) {} f(1);"), vec!["E0019"]);
+        assert_eq!(
+            check("function f(int a, int b) {} f(a = 1, 2);"),
+            vec!["E0020", "E0019"]
+        );
+        // Shadowing a function with a variable stops the checks
+        assert_eq!(
+            check("function f() {} { int f = 1; f(1); }"),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_returns() {
+        assert_eq!(check("function f() { return 1; }"), vec!["E0021"]);
+        assert_eq!(check("function f() -> int { return; }"), vec!["E0022"]);
+        assert_eq!(
+            check("function f() -> int { return \"abc\"; }"),
+            vec!["E0023"]
+        );
+
+        assert_eq!(
+            check("function f() -> bool { return 1 < 2; } function g() -> int { return f(); }"),
+            vec!["E0023"]
+        );
+
+        // A return inside a lambda leaves the lambda, not the function around it
+        assert_eq!(
+            check("function f() -> int { var g = () -> { return; }; return 1; }"),
+            Vec::<&str>::new()
+        );
+        assert_eq!(check("var f = () -> { return 1; };"), vec!["E0021"]);
+        assert_eq!(check("var f = () -> int { return; };"), vec!["E0022"]);
+
+        // Only nullable types accept `none`
+        assert_eq!(
+            check("class A {} function f(A? a = none) -> A? { return none; }"),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            check("class A {} function f() -> A { return none; }"),
+            vec!["E0023"]
+        );
+        assert_eq!(check("function f(int x = none) {}"), vec!["E0023"]);
+    }
+}
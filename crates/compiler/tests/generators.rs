@@ -0,0 +1,154 @@
+// I Language control flow tests.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::{check, parse};
+
+    use compiler::generators::{self, State, StateMachine};
+    use parser::ast::{Program, Statement};
+
+    #[test]
+    fn test_valid_generators() {
+        assert_eq!(
+            check(
+                "function naturals() -> Iterator<int> {
+                    int i = 0;
+                    while (true) { yield i; i += 1; }
+                }
+                function evens(Iterator<int> source) -> Iterator<int> {
+                    for (var value in source) {
+                        if (value % 2 != 0) { continue; }
+                        yield value;
+                        if (value > 10) { return; }
+                    }
+                }
+                for (int value in evens(naturals())) { print(value); }
+                Iterator<int> numbers = naturals();
+                if (numbers.has_next()) { print(numbers.next()); }"
+            ),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_invalid_generators() {
+        assert_eq!(check("yield 1;"), vec!["E0070"]);
+        assert_eq!(check("var produce = () -> { yield 1; };"), vec!["E0070"]);
+        assert_eq!(
+            check("class Counter { pub Counter() { yield 1; } }"),
+            vec!["E0070"]
+        );
+        assert_eq!(check("function one() -> int { yield 1; }"), vec!["E0071"]);
+        assert_eq!(
+            check("function one() -> Iterator<int> { yield 1; return 2; }"),
+            vec!["E0071"]
+        );
+        assert_eq!(
+            check("function one() -> Iterator<int> { try { yield 1; } catch ValueError(e) {} }"),
+            vec!["E0072"]
+        );
+        assert_eq!(
+            check("function one(int n) -> Iterator<int> { match (n) { case 0 { yield 1; } } }"),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            check("function one() -> Iterator<int> { yield \"one\"; }"),
+            vec!["E0023"]
+        );
+    }
+
+    #[test]
+    fn test_lower() {
+        let program: Program = parse(
+            "function pairs(int n) -> Iterator<int> {
+                for (var i in 0 .. n) {
+                    if (i == 3) { break; }
+                    yield i;
+                }
+                print(n);
+            }",
+        );
+        let Statement::Function(ref function) = program.statements[0] else {
+            panic!("expected a function");
+        };
+        assert!(generators::is_generator(function));
+        assert_eq!(
+            generators::element_type(function).map(ToString::to_string),
+            Some("int".to_owned())
+        );
+
+        let machine: StateMachine = generators::lower(function);
+        // Iterate, Next, Enter, Run (if), Yield, Exit, Jump, Run (print)
+        assert_eq!(machine.states.len(), 8);
+        assert!(matches!(machine.states[0], State::Iterate(_)));
+        assert!(matches!(
+            machine.states[1],
+            State::Next { done, .. } if done.state == 7 && done.iterations == 0
+        ));
+        assert!(matches!(
+            machine.states[3],
+            State::Run { exits: Some((after, next)), .. }
+                if after.state == 7 && next.state == 1 && next.iterations == 1
+        ));
+        assert!(matches!(machine.states[4], State::Yield(_)));
+        assert!(matches!(machine.states[6], State::Jump(target) if target.state == 1));
+        assert!(matches!(machine.states[7], State::Run { exits: None, .. }));
+
+        let Statement::Function(ref function) = parse(
+            "function signs(int n) -> Iterator<str> {
+                match (n) { case 0 { yield \"zero\"; } default -> print(n); }
+            }",
+        )
+        .statements[0] else {
+            panic!("expected a function");
+        };
+        let machine: StateMachine = generators::lower(function);
+        // Subject, Case, Enter, Yield, Exit, Exit, Jump, Case, Evaluate, Exit, Jump, Exit
+        assert_eq!(machine.states.len(), 12);
+        assert!(matches!(machine.states[0], State::Subject(_)));
+        assert!(matches!(
+            machine.states[1],
+            State::Case { otherwise, .. } if otherwise.state == 7 && otherwise.scopes == 1
+        ));
+        assert!(matches!(machine.states[3], State::Yield(_)));
+        assert!(matches!(machine.states[6], State::Jump(target) if target.state == 11));
+        assert!(matches!(
+            machine.states[7],
+            State::Case { otherwise, .. } if otherwise.state == 11
+        ));
+        assert!(matches!(machine.states[8], State::Evaluate(_)));
+        assert!(matches!(machine.states[11], State::Exit));
+
+        let Statement::Function(ref function) = parse("function f() { print(1); }").statements[0]
+        else {
+            panic!("expected a function");
+        };
+        assert!(!generators::is_generator(function));
+    }
+}
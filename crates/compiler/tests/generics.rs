@@ -0,0 +1,288 @@
+// I Language generic tests.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::{check, parse};
+
+    use compiler::diagnostic::Diagnostic;
+    use compiler::generics::{self, TypeNames};
+    use compiler::modules::Imports;
+    use compiler::types::{self, Types};
+    use parser::ast::{Program, Statement};
+
+    fn diagnostics(input: &str) -> Vec<Diagnostic> {
+        compiler::check::check(&parse(input))
+    }
+
+    fn last_type(input: &str) -> Option<String> {
+        let program: Program = parse(input);
+        let names: TypeNames = TypeNames::default();
+        let types: Types = types::infer(&program, &Imports::default(), &names);
+        let Some(Statement::VariableDeclaration(ref declaration)) = program.statements.last()
+        else {
+            panic!("expected a variable declaration");
+        };
+        types.variable_type(declaration).map(str::to_owned)
+    }
+
+    const BOX: &str = "class Box<T> {
+        pub T value;
+        pub Box(T value) { this.value = value; }
+        pub function get() -> T { return this.value; }
+    }\n";
+
+    #[test]
+    fn test_valid_generics() {
+        assert_eq!(
+            check(&format!(
+                "{BOX}function first<T>(T a, T b) -> T {{ return a; }}
+                Box<int> numbers = Box(1);
+                int sum = numbers.get() + numbers.value + first(1, 2);
+                Box<Box<str>> nested = Box(Box(\"a\"));
+                Box<str> inner = nested.get();
+                str text = inner.value;"
+            )),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_inference() {
+        assert_eq!(
+            last_type(&format!("{BOX}var inferred = Box(1);")).as_deref(),
+            Some("Box<int>")
+        );
+        assert_eq!(
+            last_type(&format!(
+                "{BOX}var outer = Box(Box(true)); var inner = outer.get(); var inferred = inner.value;"
+            )).as_deref(),
+            Some("bool")
+        );
+        assert_eq!(
+            last_type("function first<T>(T a, T b) -> T { return a; } var inferred = first(\"a\", \"b\");")
+                .as_deref(),
+            Some("str")
+        );
+        assert_eq!(
+            last_type(
+                "class Pair<T> { pub T first; pub Pair(T first) { this.first = first; } }
+                function pair<T>(T first) -> Pair<T> { return Pair(first); }
+                var inferred = pair(1);"
+            )
+            .as_deref(),
+            Some("Pair<int>")
+        );
+        assert_eq!(
+            check(&format!("{BOX}Box<str> text = Box(1);")),
+            vec!["E0023"]
+        );
+        assert_eq!(
+            check(&format!(
+                "{BOX}Box<int> number = Box(1); str text = number.get();"
+            )),
+            vec!["E0023"]
+        );
+        // A subclass only has the type arguments it passes to its base class
+        assert_eq!(
+            check(&format!(
+                "{BOX}class Numbers: Box<int> {{ pub Numbers(int value) {{ super(value); }} }}
+                Box<int> number = Numbers(1);
+                Box<str> text = Numbers(2);"
+            )),
+            vec!["E0023"]
+        );
+    }
+
+    #[test]
+    fn test_type_parameters() {
+        // A value of a type parameter is only compatible with itself
+        assert_eq!(
+            check("function f<T>(T a) -> int { return a; }"),
+            vec!["E0023"]
+        );
+        assert_eq!(
+            check("function f<T>(T a, T b) -> T { return a + b; }"),
+            vec!["E0043"]
+        );
+        assert_eq!(
+            check("class A { pub int x = 0; } function f<T: A>(T a) -> int { return a.x; }"),
+            Vec::<&str>::new()
+        );
+        assert_eq!(check("function f<T, T>(T a) {}"), vec!["E0040"]);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let classes: &str = "class Shape {} class Square: Shape {} class Box<T: Shape> {
+            pub T value;
+            pub Box(T value) { this.value = value; }
+        }\n";
+
+        assert_eq!(
+            check(&format!("{classes}Box<Square> square = Box(Square());")),
+            Vec::<&str>::new()
+        );
+        assert_eq!(check(&format!("{classes}Box<int>? number;")), vec!["E0054"]);
+        assert_eq!(
+            check(
+                "interface Named { function name() -> str; }
+                class Player implements Named { pub function name() -> str { return \"I\"; } }
+                class Rock {}
+                function greet<T: Named>(T named) -> str { return named.name(); }
+                greet(Player());
+                greet(Rock());"
+            ),
+            vec!["E0054"]
+        );
+        assert_eq!(
+            check(&format!("{classes}var number = Box(1);")),
+            vec!["E0054"]
+        );
+
+        let diagnostics: Vec<Diagnostic> = diagnostics(&format!(
+            "{classes}function f<T: Shape>(T a) {{}} f(\"a\");"
+        ));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "the type `str` does not satisfy the bound `T: Shape`"
+        );
+        assert_eq!(diagnostics[0].location.line, 5);
+        assert_eq!(diagnostics[0].notes[0].location.as_ref().unwrap().line, 5);
+
+        // The keys of a `dict` must be hashable
+        assert_eq!(
+            check("class Point {} dict<Point, int>? ages;"),
+            vec!["E0054"]
+        );
+        assert_eq!(check("dict<list<int>, int>? ages;"), vec!["E0054"]);
+        assert_eq!(
+            check("dict<u8?, str>? names; function f<K>(dict<K, int> counts) {}"),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            generics::check(
+                &parse("class Point {} dict<Point, int> ages;"),
+                &Imports::default()
+            )[0]
+            .message,
+            "the type `Point` cannot be used as the key of a `dict`"
+        );
+    }
+
+    #[test]
+    fn test_arity() {
+        assert_eq!(check(&format!("{BOX}Box<int, str>? pair;")), vec!["E0055"]);
+        assert_eq!(check("int<str> number = 1;"), vec!["E0055"]);
+        assert_eq!(check("list<int, str>? items;"), vec!["E0055"]);
+        assert_eq!(check("dict<str>? entries;"), vec!["E0055"]);
+        assert_eq!(
+            check("list<int>? items; dict<str, list<int>>? groups; tuple<int, str, bool>? row;"),
+            Vec::<&str>::new()
+        );
+
+        // A generic class or collection needs its type arguments
+        assert_eq!(check(&format!("{BOX}Box raw = Box(\"b\");")), vec!["E0055"]);
+        assert_eq!(check("list all = [1];"), vec!["E0055"]);
+        assert_eq!(check("dict<str, list>? groups;"), vec!["E0055"]);
+        assert_eq!(
+            check("function f(list items) -> dict { return {}; }"),
+            vec!["E0055", "E0055"]
+        );
+
+        let diagnostics: Vec<Diagnostic> = generics::check(
+            &parse(&format!("{BOX}Box<int, str> pair;")),
+            &Imports::default(),
+        );
+        assert_eq!(
+            diagnostics[0].message,
+            "`Box` takes 1 type argument but 2 were given"
+        );
+        assert_eq!(
+            generics::check(&parse(&format!("{BOX}Box raw;")), &Imports::default())[0].message,
+            "`Box` takes 1 type argument but 0 were given"
+        );
+    }
+
+    #[test]
+    fn test_generic_enums() {
+        let option: &str = "enum Option<T> {
+            Some(T value), Nothing;
+            pub function or(T other) -> T {
+                return match (this) { case Option.Some(value) -> value, default -> other };
+            }
+        }\n";
+        assert_eq!(
+            check(&format!(
+                "{option}Option<int> some = Option.Some(1);
+                Option<int> nothing = Option.Nothing();
+                int total = some.or(2) + nothing.or(3);
+                match (some) {{ case Option.Some(found) {{ int copy = found; }} case Option.Nothing() {{ }} }}"
+            )),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            last_type(&format!("{option}var inferred = Option.Some(\"a\");")).as_deref(),
+            Some("Option.Some<str>")
+        );
+        assert_eq!(
+            check(&format!("{option}Option<str> text = Option.Some(1);")),
+            vec!["E0023"]
+        );
+        assert_eq!(
+            last_type(&format!("{option}Option<str> nothing = Option.Nothing();")).as_deref(),
+            Some("Option<str>")
+        );
+        // The fields of a variant have the type arguments of the matched value
+        assert_eq!(
+            check(&format!(
+                "{option}Option<int> some = Option.Some(1);
+                match (some) {{ case Option.Some(found) {{ str text = found; }} default {{ }} }}"
+            )),
+            vec!["E0023"]
+        );
+    }
+
+    #[test]
+    fn test_uninferable() {
+        let diagnostics: Vec<Diagnostic> =
+            diagnostics("function make<T>() -> T { return make(); } int x = make();");
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic: &Diagnostic| diagnostic.code)
+                .collect::<Vec<&str>>(),
+            vec!["E0056", "E0056"]
+        );
+        assert_eq!(
+            diagnostics[0].message,
+            "cannot infer the type parameter `T` of `make`"
+        );
+    }
+}
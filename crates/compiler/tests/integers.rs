@@ -0,0 +1,119 @@
+//! I-Language compiler checks and compiles the abstract syntax tree of the I-Programming-Language.
+// I Language compiler.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::{codes, parse};
+
+    use compiler::constants;
+    use compiler::diagnostic::Diagnostic;
+    use compiler::generics::TypeNames;
+    use compiler::modules::Imports;
+    use compiler::types;
+    use parser::ast::Program;
+
+    fn check(input: &str) -> Vec<&'static str> {
+        let program: Program = parse(input);
+        let mut diagnostics: Vec<Diagnostic> = constants::check(&program, &Imports::default());
+        diagnostics.extend(types::check(&program, &Imports::default()));
+        codes(&diagnostics)
+    }
+
+    #[test]
+    fn test_valid_integers() {
+        assert_eq!(
+            check(
+                "u8 small = 255;
+                i8 negative = -128;
+                u64 large = 18446744073709551615;
+                int limit = -9223372036854775808;
+                bigint huge = 123456789012345678901234567890 * 2;
+                var inferred = 99999999999999999999;
+                huge = huge + inferred;
+                small += 1;
+                small = small << 1 | u8(large % 2);
+                function half(u16 value) -> u16 { return value / 2; }
+                u16 halved = half(65535);
+                list<u32> values = [1, 2, 3];
+                bigint converted = bigint(small) + bigint(\"12\");
+                i32 parsed = i32(\"-2147483648\");
+                bool same = small == 3 && huge > 0;
+                match (small) { case 0 .. 10 -> print(small); default -> print(~small); }"
+            ),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_invalid_integers() {
+        assert_eq!(check("u8 small = 256;"), vec!["E0069"]);
+        assert_eq!(check("u8 small = -1;"), vec!["E0069"]);
+        assert_eq!(check("i8 small = -129;"), vec!["E0069"]);
+        assert_eq!(check("int big = 9223372036854775808;"), vec!["E0069"]);
+        assert_eq!(check("function f(u16 x) {} f(70000);"), vec!["E0069"]);
+        assert_eq!(check("list<u8> bytes = [1, 256];"), vec!["E0069"]);
+        assert_eq!(check("u8 a = 1; a = a + 300;"), vec!["E0069"]);
+        assert_eq!(check("print(u8(300));"), vec!["E0069"]);
+        assert_eq!(check("print(u8(\"300\"));"), vec!["E0045"]);
+        assert_eq!(check("print(i32(none));"), vec!["E0045"]);
+        assert_eq!(
+            check("const int BIG = 9223372036854775807 + 1;"),
+            vec!["E0050"]
+        );
+        assert_eq!(check("const u8 SMALL = 200 + 100;"), vec!["E0050", "E0023"]);
+        assert_eq!(check("u8 a = 1; u16 b = 2; print(a + b);"), vec!["E0043"]);
+        assert_eq!(
+            check("int a = 1; bigint b = 2; print(a + b);"),
+            vec!["E0043"]
+        );
+        assert_eq!(
+            check("bigint b = 2; print(b & 1, ~b);"),
+            vec!["E0043", "E0043"]
+        );
+        assert_eq!(check("u8 small = 99999999999999999999;"), vec!["E0069"]);
+    }
+
+    #[test]
+    fn test_literal_types() {
+        let program: Program = parse("var small = 1; var huge = 99999999999999999999;");
+        let names: TypeNames = TypeNames::default();
+        let types: types::Types = types::infer(&program, &Imports::default(), &names);
+        let found: Vec<&str> = program
+            .statements
+            .iter()
+            .filter_map(|statement| match *statement {
+                parser::ast::Statement::VariableDeclaration(ref declaration) => {
+                    declaration.value.as_ref()
+                }
+                _ => None,
+            })
+            .filter_map(|value| types.type_of(value))
+            .collect();
+        assert_eq!(found, vec!["int", "bigint"]);
+    }
+}
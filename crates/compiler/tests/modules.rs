@@ -0,0 +1,225 @@
+// I Language module tests.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::codes;
+
+    use compiler::diagnostic::Diagnostic;
+    use compiler::modules::{Module, Modules};
+
+    /// Writes the files to a new directory and loads its `main.il`.
+    fn load(directory: &str, files: &[(&str, &str)]) -> (Modules, Vec<Diagnostic>) {
+        let root: std::path::PathBuf =
+            std::env::temp_dir().join(format!("i-language-modules-{directory}"));
+        let _ = std::fs::remove_dir_all(&root);
+
+        for &(name, source) in files {
+            let path: std::path::PathBuf = root.join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, source).unwrap();
+        }
+
+        let file: String = root.join("main.il").display().to_string();
+        let source: String = std::fs::read_to_string(&file).unwrap();
+        let program: parser::ast::Program =
+            parser::parse::parse(&lexer::lex::lex(&source, &file).unwrap()).unwrap();
+
+        let (modules, mut diagnostics): (Modules, Vec<Diagnostic>) = Modules::load(Module {
+            name: "main".to_owned(),
+            file,
+            source,
+            program,
+        });
+        diagnostics.extend(compiler::check::check_modules(&modules));
+
+        (modules, diagnostics)
+    }
+
+    #[test]
+    fn test_valid_modules() {
+        let (modules, diagnostics): (Modules, Vec<Diagnostic>) = load(
+            "valid",
+            &[
+                (
+                    "main.il",
+                    "import random;
+                    import geometry.shapes as shapes;
+                    use geometry.shapes.Square;
+                    use geometry.shapes.area as square_area;
+                    use geometry.base.Named as Nameable;
+
+                    Square square = Square(3);
+                    int area = square_area(square) + shapes.area(square) + random.randint(1, 6);
+                    Nameable named = square;
+                    print(square.describe(), named.name());",
+                ),
+                (
+                    "geometry/shapes.il",
+                    "import geometry.base;
+                    use geometry.base.Shape;
+
+                    use geometry.base.Named;
+
+                    pub class Square: Shape implements Named {
+                        pub int side;
+                        pub Square(int side) { this.side = side; }
+                        pub function name() -> str { return \"square\"; }
+                    }
+
+                    pub function area(Square square) -> int {
+                        return square.side * square.side;
+                    }",
+                ),
+                (
+                    "geometry/base.il",
+                    "pub class Shape { pub function describe() -> str { return \"shape\"; } }
+                    pub interface Named { function name() -> str; }",
+                ),
+            ],
+        );
+
+        assert_eq!(codes(&diagnostics), Vec::<&str>::new());
+        let names: Vec<&str> = modules.iter().map(|module| module.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["random", "geometry.base", "geometry.shapes", "main"]
+        );
+        assert_eq!(modules.entry().name, "main");
+    }
+
+    #[test]
+    fn test_unknown_modules() {
+        let (modules, diagnostics): (Modules, Vec<Diagnostic>) = load(
+            "unknown",
+            &[
+                (
+                    "main.il",
+                    "import missing;\nimport broken;\nimport missing;\nimport geometry.missing;",
+                ),
+                ("broken.il", "int x = ;"),
+            ],
+        );
+
+        assert_eq!(codes(&diagnostics), vec!["E0034", "E0001", "E0034"]);
+        assert_eq!(diagnostics[0].location.line, 1);
+        assert_eq!(diagnostics[0].location.column, 8);
+        assert_eq!(diagnostics[0].length, Some(7));
+
+        // The label spans the whole path
+        assert_eq!(diagnostics[2].length, Some(16));
+        assert!(modules
+            .source(&diagnostics[1].location.file)
+            .map_or(false, |source| source.contains("int x")));
+    }
+
+    #[test]
+    fn test_cycles() {
+        let (_, diagnostics): (Modules, Vec<Diagnostic>) = load(
+            "cycles",
+            &[
+                ("main.il", "import first;"),
+                ("first.il", "import second;"),
+                ("second.il", "import first;"),
+            ],
+        );
+
+        assert_eq!(codes(&diagnostics), vec!["E0035"]);
+        assert!(diagnostics[0].location.file.ends_with("second.il"));
+        assert_eq!(
+            diagnostics[0].notes[0].message,
+            "the modules import each other: first -> second -> first"
+        );
+
+        let (_, diagnostics): (Modules, Vec<Diagnostic>) =
+            load("self", &[("main.il", "import main;")]);
+        assert_eq!(codes(&diagnostics), vec!["E0035"]);
+    }
+
+    #[test]
+    fn test_items() {
+        let (modules, diagnostics): (Modules, Vec<Diagnostic>) = load(
+            "items",
+            &[
+                (
+                    "main.il",
+                    "use items.hidden;\nuse items.missing as other;\nuse items.Visible;",
+                ),
+                ("items.il", "function hidden() {}\npub class Visible {}"),
+            ],
+        );
+
+        assert_eq!(codes(&diagnostics), vec!["E0036", "E0037"]);
+        assert_eq!(diagnostics[0].location.column, 11);
+        assert_eq!(diagnostics[1].location.line, 2);
+
+        // The note points at the declaration in the other module, shown with its source code
+        let rendered: String = diagnostics[0].render_with(|file| modules.source(file));
+        assert!(rendered.contains("use items.hidden;"));
+        assert!(rendered.contains("function hidden() {}"));
+        assert!(!diagnostics[0]
+            .render(modules.source(&diagnostics[0].location.file).unwrap())
+            .contains("function hidden() {}"));
+
+        // Items used through the module are checked the same way
+        let (_, diagnostics): (Modules, Vec<Diagnostic>) = load(
+            "members",
+            &[
+                (
+                    "main.il",
+                    "import members;\nmembers.hidden();\nmembers.missing(1);\nprint(members.gone);\nmembers.shown();",
+                ),
+                ("members.il", "function hidden() {}\npub function shown() {}"),
+            ],
+        );
+
+        assert_eq!(codes(&diagnostics), vec!["E0036", "E0037", "E0037"]);
+        assert_eq!(
+            diagnostics[0].message,
+            "`hidden` is private to module `members`"
+        );
+        assert_eq!(
+            (diagnostics[2].location.line, diagnostics[2].location.column),
+            (4, 15)
+        );
+
+        // A module is not a value
+        let (_, diagnostics): (Modules, Vec<Diagnostic>) = load(
+            "value",
+            &[(
+                "main.il",
+                "import random;\nint r = random;\nint n = random.randint(1, 6);",
+            )],
+        );
+        assert_eq!(codes(&diagnostics), vec!["E0076"]);
+        assert_eq!(
+            diagnostics[0].message,
+            "expected a value, found module `random`"
+        );
+    }
+}
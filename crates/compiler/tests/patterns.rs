@@ -0,0 +1,235 @@
+// I Language pattern check tests.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::{self, check_with, parse};
+
+    use compiler::diagnostic::{Diagnostic, Severity};
+    use compiler::modules::Imports;
+
+    fn check(input: &str) -> Vec<&'static str> {
+        check_with(compiler::patterns::check, input)
+    }
+
+    #[test]
+    fn test_valid_matches() {
+        assert_eq!(
+            check(
+                "class Point { int x; int y; }
+                Point point = Point();
+                int value = 5;
+                bool lucky = true;
+
+                match (value) {
+                    case 1 | -2 { }
+                    case 3 .. 10 if (lucky) { }
+                    case 3 .. 10 { }
+                    case int other { }
+                }
+                match (point) {
+                    case Point(x, 0) -> print(x);
+                    case Point(_, int y) { }
+                }
+                match (lucky) {
+                    case true { }
+                    case false { }
+                }
+                str name = match (value) { case 1 -> \"one\", default -> \"other\" };"
+            ),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_patterns() {
+        assert_eq!(
+            check("int value = 1; match (value) { case \"one\" { } default { } }"),
+            vec!["E0023"]
+        );
+        assert_eq!(
+            check("bool value = true; match (value) { case 1 .. 10 { } default { } }"),
+            vec!["E0023"]
+        );
+        assert_eq!(
+            check("int value = 1; match (value) { case 10 .. 1 { } case \"aa\" .. \"zz\" { } }"),
+            vec!["E0028", "E0028"]
+        );
+        assert_eq!(
+            check("int value = 1; match (value) { case Point(x) { } }"),
+            vec!["E0029"]
+        );
+        assert_eq!(
+            check("class Point { int x; int y; } Point point = Point(); match (point) { case Point(x) { } }"),
+            vec!["E0030"]
+        );
+    }
+
+    #[test]
+    fn test_constant_bounds() {
+        let check = common::check;
+
+        assert!(check(
+            "const int MIN = -5; const int MAX = MIN + 10; int value = 1;
+            match (value) { case MIN .. 0 { } case 0 .. MAX { } default { } }"
+        )
+        .is_empty());
+        assert_eq!(
+            check(
+                "int limit = 10; int value = 1; match (value) { case 0 .. limit { } default { } }"
+            ),
+            vec!["E0028"]
+        );
+        assert_eq!(
+            check(
+                "const int MAX = 5; int value = 1; match (value) { case MAX .. 1 { } default { } }"
+            ),
+            vec!["E0028"]
+        );
+        assert_eq!(
+            check("const str NAME = \"I\"; int value = 1; match (value) { case 0 .. NAME { } default { } }"),
+            vec!["E0028"]
+        );
+        assert_eq!(
+            check("int value = 1; match (value) { case 0 .. MISSING { } default { } }"),
+            vec!["E0038", "E0028"]
+        );
+    }
+
+    #[test]
+    fn test_unreachable() {
+        assert_eq!(
+            check("int value = 1; match (value) { default { } case 1 { } }"),
+            vec!["E0026"]
+        );
+        assert_eq!(
+            check("int value = 1; match (value) { case other { } case 1 { } }"),
+            vec!["E0026"]
+        );
+        assert_eq!(
+            check("int value = 1; match (value) { case 1 .. 10 { } case 2 | 5 { } }"),
+            vec!["E0026"]
+        );
+        // Guarded cases do not make later cases unreachable
+        assert_eq!(
+            check("int value = 1; match (value) { case 1 if (value > 0) { } case 1 { } }"),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_coverage() {
+        assert_eq!(
+            check("bool value = true; match (value) { case true { } }"),
+            vec!["E0027"]
+        );
+        assert_eq!(
+            check("bool value = true; match (value) { case true { } case false if (value) { } }"),
+            vec!["E0027"]
+        );
+        assert_eq!(
+            check("bool value = true; match (value) { case true { } default { } }"),
+            Vec::<&str>::new()
+        );
+
+        // Match expressions have no value if no case matches
+        let diagnostics: Vec<Diagnostic> = compiler::patterns::check(
+            &parse("bool value = true; int number = match (value) { case true -> 1 };"),
+            &Imports::default(),
+        );
+        assert_eq!(diagnostics[0].code, "E0027");
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert_eq!(
+            check(
+                "int value = 1; str text = match (value) { case 1 -> \"one\", case 2 -> \"two\" };"
+            ),
+            vec!["E0027"]
+        );
+        assert_eq!(
+            check("int value = 1; str text = match (value) { case 1 -> \"one\", default -> \"other\" };"),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_enum_coverage() {
+        let shape: &str = "enum Shape { Circle(int radius), Square(int side), Empty }
+            Shape shape = Shape.Empty();";
+        assert_eq!(
+            check(&format!(
+                "{shape} match (shape) {{ case Shape.Circle(r) {{ }} case Shape.Square(int s) {{ }} case Shape.Empty() {{ }} }}"
+            )),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            check(&format!(
+                "{shape} match (shape) {{ case Shape.Circle(r) | Shape.Square(r) {{ }} case Shape.Empty() {{ }} }}"
+            )),
+            Vec::<&str>::new()
+        );
+        // Guarded cases and refutable field patterns do not cover their variant
+        assert_eq!(
+            check(&format!(
+                "{shape} match (shape) {{ case Shape.Circle(1) {{ }} case Shape.Square(s) if (true) {{ }} case Shape.Empty() {{ }} }}"
+            )),
+            vec!["E0027"]
+        );
+        assert_eq!(
+            check(&format!(
+                "{shape} match (shape) {{ case Shape.Circle(r) {{ }} default {{ }} }}"
+            )),
+            Vec::<&str>::new()
+        );
+
+        let program: parser::ast::Program = parse(&format!(
+            "{shape} match (shape) {{ case Shape.Circle(r) {{ }} }}"
+        ));
+        let diagnostics: Vec<Diagnostic> = compiler::patterns::check(&program, &Imports::default());
+        assert_eq!(
+            diagnostics[0].message,
+            "match does not cover `Shape.Square`, `Shape.Empty`"
+        );
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(
+            check(&format!(
+                "{shape} int size = match (shape) {{ case Shape.Circle(r) -> r, case Shape.Empty() -> 0 }};"
+            )),
+            vec!["E0027"]
+        );
+
+        // The variants of a generic enum are covered like the ones of other enums
+        assert_eq!(
+            check(
+                "enum Option<T> { Some(T value), Nothing }
+                Option<int> option = Option.Nothing();
+                match (option) { case Option.Some(value) { } case Option.Nothing() { } }"
+            ),
+            Vec::<&str>::new()
+        );
+    }
+}
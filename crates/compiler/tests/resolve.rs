@@ -0,0 +1,240 @@
+// I Language name resolution tests.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::{check_with, parse};
+
+    use compiler::diagnostic::Diagnostic;
+    use compiler::modules::Imports;
+    use compiler::resolve::{self, Declaration, Resolution};
+    use parser::ast::{Expression, LambdaBody, Program, Statement};
+
+    fn check(input: &str) -> Vec<&'static str> {
+        check_with(resolve::check, input)
+    }
+
+    #[test]
+    fn test_valid_names() {
+        assert_eq!(
+            check(
+                "int limit = twice(2);
+                class Shape {
+                    int sides = 0;
+                    function describe() -> str { return str(this.sides); }
+                }
+                class Square: Shape {
+                    function describe() -> str { return super.describe() + str(limit); }
+                }
+                function twice(int value, int factor = 2) -> int { return value * factor; }
+                match (limit) {
+                    case 1 | 2 -> print(\"small\");
+                    case Square(sides) -> print(sides);
+                    case int other if (other > limit) -> print(other);
+                    default -> print(limit);
+                }
+                try {
+                    throw ValueError(\"invalid\");
+                } catch ValueError(error) {
+                    print(error.message);
+                }"
+            ),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_declarations() {
+        let program: Program =
+            parse("int goal = 3; function f(int goal) -> int { return goal; } print(goal);");
+        let resolution: Resolution = resolve::resolve(&program, &Imports::default());
+
+        let Statement::Expression(ref call) = program.statements[2] else {
+            panic!("expected an expression statement");
+        };
+        let Expression::Call(ref call) = call.expression else {
+            panic!("expected a call");
+        };
+        let Expression::Identifier(ref goal) = call.arguments[0].value else {
+            panic!("expected an identifier");
+        };
+        assert!(matches!(
+            resolution.declaration(goal),
+            Some(Declaration::Variable(_))
+        ));
+        let Expression::Identifier(ref print) = *call.callee else {
+            panic!("expected an identifier");
+        };
+        assert!(matches!(
+            resolution.declaration(print),
+            Some(Declaration::BuiltIn("print"))
+        ));
+        assert_eq!(resolution.diagnostics[0].code, "E0041");
+    }
+
+    #[test]
+    fn test_undefined_names() {
+        assert_eq!(check("print(missing);"), vec!["E0038"]);
+        assert_eq!(check("{ int inner = 1; } print(inner);"), vec!["E0038"]);
+        assert_eq!(check("print(later); int later = 1;"), vec!["E0038"]);
+        assert_eq!(check("for (var i in 0 .. 3) { } print(i);"), vec!["E0038"]);
+        assert_eq!(
+            check("{ int local = 1; function f() -> int { return local; } }"),
+            vec!["E0038"]
+        );
+        assert_eq!(
+            check("class A { int x = 0; function f() -> int { return x; } }"),
+            vec!["E0038"]
+        );
+
+        let diagnostics: Vec<Diagnostic> = resolve::check(
+            &parse("int counter = 0; print(countr);"),
+            &Imports::default(),
+        );
+        assert_eq!(
+            diagnostics[0].notes[0].message,
+            "a variable with a similar name exists: `counter`"
+        );
+        let diagnostics: Vec<Diagnostic> = resolve::check(
+            &parse("function f(int value) -> int { return valeu; }"),
+            &Imports::default(),
+        );
+        assert_eq!(
+            diagnostics[0].notes[0].message,
+            "a parameter with a similar name exists: `value`"
+        );
+        assert!(
+            resolve::check(&parse("print(abc);"), &Imports::default())[0]
+                .notes
+                .is_empty()
+        );
+
+        // Variants are only visible through their enum
+        assert_eq!(
+            check("enum Shape { Circle(int radius) } print(Shape.Circle(1));"),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            check("enum Shape { Circle(int radius) } print(Circle(1));"),
+            vec!["E0038"]
+        );
+        let diagnostics: Vec<Diagnostic> = resolve::check(
+            &parse("enum Shape { Circle(int radius) } print(Shape.Circel(1));"),
+            &Imports::default(),
+        );
+        assert_eq!(
+            diagnostics[0].message,
+            "cannot find variant `Circel` in enum `Shape`"
+        );
+        assert_eq!(
+            diagnostics[0].notes[0].message,
+            "a variant with a similar name exists: `Shape.Circle`"
+        );
+    }
+
+    #[test]
+    fn test_types() {
+        assert_eq!(check("strr name = \"a\";"), vec!["E0039"]);
+        assert_eq!(check("function f(Missing value) {}"), vec!["E0039"]);
+        assert_eq!(check("class A { Missing field; }"), vec!["E0039"]);
+        assert_eq!(check("class A {} A value = A();"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_duplicates() {
+        assert_eq!(check("int a = 1; int a = 2;"), vec!["E0040"]);
+        assert_eq!(check("function f() {} function f() {}"), vec!["E0040"]);
+        assert_eq!(check("function f() {} int f = 1;"), vec!["E0040"]);
+        assert_eq!(check("{ int a = 1; } { int a = 2; }"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_shadowing() {
+        assert_eq!(check("int a = 1; { int a = 2; }"), vec!["E0041"]);
+        assert_eq!(check("int a = 1; function f(int a) {}"), vec!["E0041"]);
+        assert_eq!(
+            check("int a = 1; match (a) { case int a -> print(a); }"),
+            vec!["E0041"]
+        );
+        assert_eq!(check("function a() {} { int a = 1; }"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_this() {
+        assert_eq!(check("print(this);"), vec!["E0042"]);
+        assert_eq!(check("function f() { print(this); }"), vec!["E0042"]);
+        assert_eq!(
+            check("class A { function f() { super.f(); } }"),
+            vec!["E0042"]
+        );
+    }
+
+    #[test]
+    fn test_captures() {
+        let program: Program = parse(
+            "int limit = 3;
+            function counter(int start) -> function() -> int {
+                int count = start;
+                function step() -> int { return 1; }
+                return () -> int {
+                    count += step();
+                    var inner = (int value) -> value + count + limit;
+                    return inner(count);
+                };
+            }",
+        );
+        let resolution: Resolution = resolve::resolve(&program, &Imports::default());
+        assert!(resolution.diagnostics.is_empty());
+
+        let Statement::Function(ref counter) = program.statements[1] else {
+            panic!("expected a function");
+        };
+        let body = counter.body.as_ref().unwrap();
+        let Statement::Return(ref node) = body.statements[2] else {
+            panic!("expected a return statement");
+        };
+        let Some(Expression::Lambda(ref outer)) = node.value else {
+            panic!("expected a lambda");
+        };
+        assert_eq!(resolution.captures(outer), ["count", "step"]);
+
+        let LambdaBody::Block(ref block) = outer.body else {
+            panic!("expected a block");
+        };
+        let Statement::VariableDeclaration(ref inner) = block.statements[1] else {
+            panic!("expected a variable declaration");
+        };
+        let Some(Expression::Lambda(ref inner)) = inner.value else {
+            panic!("expected a lambda");
+        };
+        assert_eq!(resolution.captures(inner), ["count"]);
+
+        assert_eq!(check("var f = (int x) -> x + y;"), vec!["E0038"]);
+        assert_eq!(check("var f = (int x) -> x; print(x);"), vec!["E0038"]);
+    }
+}
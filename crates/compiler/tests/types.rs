@@ -0,0 +1,650 @@
+// I Language type check tests.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::{check_with, parse};
+
+    use compiler::diagnostic::Diagnostic;
+    use compiler::generics::TypeNames;
+    use compiler::modules::Imports;
+    use compiler::types::{self, Types};
+    use lexer::tokens::token::Location;
+    use parser::ast::{Program, Statement};
+
+    fn check(input: &str) -> Vec<&'static str> {
+        check_with(types::check, input)
+    }
+
+    #[test]
+    fn test_valid_types() {
+        assert_eq!(
+            check(
+                "class Shape { pub int sides = 0; }
+                class Square: Shape { }
+                function area(int side, str unit = \"m\") -> int { return side * side; }
+                int size = area(2, unit = \"cm\") + int(\"12\") + int(true);
+                str text = \"size: \" + str(size);
+                bool small = size < 10 && !(text == \"\") || (size % 2 ^ 1) == 0;
+                Shape shape = Square();
+                Shape? empty = none;
+                size += shape.sides;
+                size <<= 1;
+                while (small) { small = false; }
+                if (input(\"> \") >= \"a\") { print(text, size, bool(shape)); }
+                match (size) {
+                    case int other if (other > 1) -> print(0 - other, ~other);
+                    default { }
+                }
+                try { } catch ValueError(error) { print(error.message + \"!\"); }"
+            ),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_mismatched_types() {
+        assert_eq!(check("int goal = \"a\";"), vec!["E0023"]);
+        assert_eq!(check("str name = 1; name = true;"), vec!["E0023", "E0023"]);
+        assert_eq!(check("class A {} class B {} A value = B();"), vec!["E0023"]);
+        assert_eq!(
+            check("class A {} class B: A {} B value = A();"),
+            vec!["E0023"]
+        );
+        assert_eq!(check("class A { int x = \"0\"; }"), vec!["E0023"]);
+        assert_eq!(
+            check(
+                "interface Shape { function area() -> int; }
+                class Square implements Shape { pub function area() -> int { return 1; } }
+                Shape shape = Square();
+                int area = shape.area();
+                str text = shape.area();
+                Square square = shape;"
+            ),
+            vec!["E0023", "E0023"]
+        );
+
+        let diagnostics: Vec<Diagnostic> =
+            types::check(&parse("int goal = \"a\";"), &Imports::default());
+        assert_eq!(diagnostics[0].label, "expected `int`, found `str`");
+        assert_eq!(
+            diagnostics[0].notes[0].message,
+            "expected `int` because of this type"
+        );
+    }
+
+    #[test]
+    fn test_conditions() {
+        assert_eq!(check("if (5) { }"), vec!["E0023"]);
+        assert_eq!(check("while (\"yes\") { }"), vec!["E0023"]);
+        assert_eq!(check("match (1) { case int x if (x) { } }"), vec!["E0023"]);
+        assert_eq!(check("bool done = true && 1;"), vec!["E0043"]);
+    }
+
+    #[test]
+    fn test_operators() {
+        assert_eq!(check("int goal = \"a\" - 1;"), vec!["E0043"]);
+        assert_eq!(check("str text = \"a\" * 2;"), vec!["E0043"]);
+        assert_eq!(check("bool flag = true < false;"), vec!["E0043"]);
+        assert_eq!(check("int goal = 1 + true;"), vec!["E0043"]);
+        assert_eq!(check("bool flag = !1;"), vec!["E0043"]);
+        assert_eq!(check("str text = \"a\"; text++;"), vec!["E0043"]);
+        assert_eq!(check("int goal = 1; goal += \"a\";"), vec!["E0043"]);
+        assert_eq!(
+            check("bool flag = true; flag &= false;"),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            check("str text = \"a\"; bool same = text == 1;"),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_overloading() {
+        let vector: &str = "class Vector {
+                pub function add(Vector other) -> Vector { return other; }
+                pub function mul(int factor) -> Vector { return this; }
+                pub function neg() -> Vector { return this; }
+                pub function eq(Vector other) -> bool { return true; }
+                pub function lt(Vector other) -> bool { return true; }
+                pub function index(int position) -> int { return position; }
+                pub function set_index(int position, int value) { }
+                pub function sub_assign(int amount) { }
+            }
+            class Broken {
+                pub function lt(Broken other) -> int { return 0; }
+                pub function sub(int first, int second) -> int { return 0; }
+            }
+            Vector a = Vector();
+            Broken broken = Broken();";
+        let check_with = |code: &str| check(&format!("{vector}\n{code}"));
+
+        assert_eq!(
+            check_with(
+                "Vector sum = a + a * 2;
+                Vector negated = -a;
+                bool same = a == a && a != none && a < a;
+                int first = a[0];
+                a[1] = 2;
+                a += a;
+                a -= 1;"
+            ),
+            Vec::<&str>::new()
+        );
+        assert_eq!(check_with("int sum = a + a;"), vec!["E0023"]);
+        assert_eq!(check_with("a + 1;"), vec!["E0023"]);
+        assert_eq!(check_with("a == 1;"), vec!["E0023"]);
+        assert_eq!(check_with("a[\"a\"] = 1;"), vec!["E0023"]);
+        assert_eq!(check_with("a / a;"), vec!["E0043"]);
+        assert_eq!(check_with("a *= a;"), vec!["E0023"]);
+        assert_eq!(check_with("~a;"), vec!["E0043"]);
+        assert_eq!(check_with("broken[0];"), vec!["E0043"]);
+        assert_eq!(check_with("broken < broken;"), vec!["E0064"]);
+        assert_eq!(check_with("broken - 1;"), vec!["E0064"]);
+        // Objects without `eq` are compared by identity
+        assert_eq!(
+            check_with("bool same = broken == broken;"),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_nullable() {
+        let node: &str = "class Node {
+                pub int value = 0;
+                pub Node? next;
+                pub function get() -> int { return this.value; }
+            }
+            Node? maybe = none;
+            int? number = none;";
+        let check_with = |code: &str| check(&format!("{node}\n{code}"));
+
+        assert_eq!(
+            check_with(
+                "int? other = 1;
+                list<int?> numbers = [1, none];
+                Node? next = maybe?.next;
+                int? value = maybe?.value;
+                int? result = maybe?.get();
+                int sum = (number ?? 0) + (maybe?.value ?? 1);
+                int? either = number ?? other;
+                if (number != none) { sum += number; }
+                if (maybe != none && maybe.value > 0) { sum += maybe.get(); }
+                if (number == none || number > 3) { }
+                if (!(maybe == none)) { maybe.value = 1; }
+                while (maybe != none) { maybe = maybe.next; }
+                function first(Node? node) -> int {
+                    if (node == none) { return 0; }
+                    return node.value;
+                }"
+            ),
+            Vec::<&str>::new()
+        );
+
+        assert_eq!(check_with("int value = maybe.value;"), vec!["E0065"]);
+        assert_eq!(check_with("maybe.get();"), vec!["E0065"]);
+        assert_eq!(check_with("int sum = number + 1;"), vec!["E0065"]);
+        assert_eq!(check_with("-number;"), vec!["E0065"]);
+        assert_eq!(
+            check_with("list<int>? items = none; items[0];"),
+            vec!["E0065"]
+        );
+        assert_eq!(
+            check_with("list<int>? items = none; for (var item in items) { }"),
+            vec!["E0065"]
+        );
+        assert_eq!(check_with("int value = maybe?.value;"), vec!["E0023"]);
+        assert_eq!(check_with("int value = number;"), vec!["E0023"]);
+        assert_eq!(check_with("int value = number ?? \"a\";"), vec!["E0023"]);
+        assert_eq!(check_with("Node plain = none;"), vec!["E0023"]);
+        assert_eq!(
+            check_with("function f(int? x) -> int { return x; }"),
+            vec!["E0023"]
+        );
+        // Narrowing ends with the checked block, an assignment and a lambda
+        assert_eq!(
+            check_with("if (number != none) { } number + 1;"),
+            vec!["E0065"]
+        );
+        assert_eq!(
+            check_with("if (number == none) { return; } number = none; number + 1;"),
+            vec!["E0065"]
+        );
+        assert_eq!(
+            check_with("if (number != none) { var f = () -> number + 1; }"),
+            vec!["E0065"]
+        );
+        assert_eq!(
+            check_with("if (number == none) { return; } if (true) { number = none; } number + 1;"),
+            vec!["E0065"]
+        );
+        assert_eq!(
+            check_with("if (number != none) { while (true) { number + 1; number = none; } }"),
+            vec!["E0065"]
+        );
+        // Narrowing ends with a call of code that may assign the variable
+        assert_eq!(
+            check_with(
+                "function clear() { number = none; } if (number != none) { clear(); number + 1; }"
+            ),
+            vec!["E0065"]
+        );
+        assert_eq!(
+            check_with(
+                "function f() {
+                    int? local = 3;
+                    var clear = () -> { local = none; };
+                    if (local != none) { clear(); local + 1; }
+                }"
+            ),
+            vec!["E0065"]
+        );
+        assert_eq!(
+            check_with(
+                "function clear() { number = none; }
+                if (number != none) { if (true) { clear(); } number + 1; }"
+            ),
+            vec!["E0065"]
+        );
+        assert_eq!(
+            check_with(
+                "function f() { int? local = 3; if (local != none) { print(local); f(); local + 1; } }
+                function clear() { number = none; }"
+            ),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_calls() {
+        assert_eq!(
+            check("function f(int x, str y = \"\") {} f(\"1\", y = 2);"),
+            vec!["E0023", "E0023"]
+        );
+        assert_eq!(
+            check("class A { A(int x) {} function f(bool flag) {} } A a = A(true); a.f(1);"),
+            vec!["E0023", "E0023"]
+        );
+        assert_eq!(check("int goal = 1; goal();"), vec!["E0044"]);
+        assert_eq!(
+            check("class A { int x = 1; } A a = A(); a.x();"),
+            vec!["E0044"]
+        );
+        assert_eq!(check("str(1, 2);"), vec!["E0016"]);
+        assert_eq!(check("int();"), vec!["E0019"]);
+        assert_eq!(check("print(value = 1);"), vec!["E0017"]);
+        assert_eq!(check("int goal = input();"), vec!["E0023"]);
+    }
+
+    #[test]
+    fn test_values() {
+        assert_eq!(check("int x = ValueError;"), vec!["E0076"]);
+        assert_eq!(check("int p = print;"), vec!["E0076"]);
+        assert_eq!(
+            check("class A { pub int x = 1; } print(A.x);"),
+            vec!["E0076"]
+        );
+        assert_eq!(
+            types::check(&parse("int x = ValueError;"), &Imports::default())[0].message,
+            "expected a value, found class `ValueError`"
+        );
+
+        // Variants without fields are created by calling them as well
+        let shape: &str = "enum Shape { Circle(int radius), Empty }";
+        let diagnostics: Vec<Diagnostic> = types::check(
+            &parse(&format!("{shape} Shape s = Shape.Empty;")),
+            &Imports::default(),
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "expected a value, found enum variant `Shape.Empty`"
+        );
+        assert_eq!(
+            diagnostics[0].notes[0].message,
+            "create the variant with `Shape.Empty()`"
+        );
+        assert!(check(&format!("{shape} Shape s = Shape.Empty();")).is_empty());
+
+        // Classes and built-in functions can still be called
+        assert!(check("class A { pub int x = 1; } print(A().x, ValueError(\"\"));").is_empty());
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(check("int goal = int(none);"), vec!["E0045"]);
+        assert_eq!(check("class A {} int goal = int(A());"), vec!["E0045"]);
+        assert_eq!(check("int goal = int(\"Test\");"), vec!["E0045"]);
+        assert_eq!(
+            check("str text = str(none); bool flag = bool(\"\");"),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_inference() {
+        assert_eq!(
+            check(
+                "class Shape { pub int sides = 4; }
+                var count = 3 * 2;
+                var name = \"n\" + str(count);
+                var shape = Shape();
+                var later;
+                later = true;
+                var empty = none;
+                empty = Shape();
+                count += shape.sides;
+                bool done = later && name == \"n6\" && empty.sides == count;"
+            ),
+            Vec::<&str>::new()
+        );
+        assert_eq!(check("var count = 1; count = \"a\";"), vec!["E0023"]);
+        assert_eq!(check("var flag; flag = true; flag = 1;"), vec!["E0023"]);
+        assert_eq!(
+            check("var name = \"a\"; int size = name - 1;"),
+            vec!["E0043"]
+        );
+        assert_eq!(check("var unknown;"), vec!["E0046"]);
+        assert_eq!(check("var empty = none;"), vec!["E0046"]);
+        assert_eq!(check("var empty = none; empty = none;"), vec!["E0046"]);
+
+        // A variable assigned `none` has a nullable type
+        assert_eq!(
+            check("var name = none; name = \"a\"; name = none; str? copy = name;"),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            check("var name = none; name = \"a\"; str copy = name;"),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            check("var name = \"a\"; name = none; str copy = name;"),
+            vec!["E0023"]
+        );
+        assert_eq!(
+            check("var name; name = 1; function f() -> int { return name + 1; } name = none;"),
+            vec!["E0065"]
+        );
+
+        let diagnostics: Vec<Diagnostic> = types::check(
+            &parse("var count = 1;\ncount = \"a\";"),
+            &Imports::default(),
+        );
+        assert_eq!(
+            diagnostics[0].notes[0].message,
+            "expected `int` because the type of `count` was inferred here"
+        );
+        assert_eq!(diagnostics[0].notes[0].location.as_ref().unwrap().line, 1);
+    }
+
+    #[test]
+    fn test_hover() {
+        let program: Program = parse(
+            "var goal = 3;\nfunction f(str text) -> str { var copy = text; return copy; }\nvar later;\nlater = f(\"a\");",
+        );
+        let names: TypeNames = TypeNames::default();
+        let types: Types = types::infer(&program, &Imports::default(), &names);
+        let hover = |line: usize, column: usize| {
+            types.type_at(&Location {
+                file: "<stdin>".to_owned(),
+                line,
+                column,
+            })
+        };
+
+        assert_eq!(hover(1, 5), Some("int"));
+        assert_eq!(hover(1, 8), Some("int"));
+        assert_eq!(hover(1, 9), None);
+        assert_eq!(hover(2, 17), Some("str"));
+        assert_eq!(hover(2, 35), Some("str"));
+        assert_eq!(hover(3, 5), Some("str"));
+        assert_eq!(hover(4, 1), Some("str"));
+
+        let Statement::VariableDeclaration(ref goal) = program.statements[0] else {
+            panic!("expected a variable declaration");
+        };
+        assert_eq!(types.variable_type(goal), Some("int"));
+    }
+
+    #[test]
+    fn test_type_of() {
+        let program: Program = parse("int goal = 3; str text = str(goal * 2) + \"!\";");
+        let names: TypeNames = TypeNames::default();
+        let types: Types = types::infer(&program, &Imports::default(), &names);
+
+        let Statement::VariableDeclaration(ref text) = program.statements[1] else {
+            panic!("expected a variable declaration");
+        };
+        assert_eq!(types.type_of(text.value.as_ref().unwrap()), Some("str"));
+    }
+
+    #[test]
+    fn test_collections() {
+        assert_eq!(
+            check(
+                "class Animal {} class Dog: Animal {}
+                list<int> items = [1, 2];
+                list<Animal?> animals = [Animal(), Dog(), none];
+                dict<str, list<int>> groups = {\"a\": items, \"b\": []};
+                tuple<int, str> pair = (1, \"x\");
+                int first = items[0] + groups[\"a\"][1] + pair[0] + items.pop() + items.len();
+                str name = pair[1] + \"abc\"[1] + \"abc\"[.. 2];
+                list<int> rest = items[1 ..];
+                items[0] = 3;
+                groups[\"c\"] = [first];
+                items.append(first);
+                bool found = groups.contains(\"a\") && items.contains(1);
+                list<str> keys = groups.keys();"
+            ),
+            Vec::<&str>::new()
+        );
+
+        assert_eq!(check("list<int> items = [1, \"a\"];"), vec!["E0023"]);
+        assert_eq!(check("list<str> items = [1];"), vec!["E0023"]);
+        assert_eq!(check("dict<str, int> ages = {1: 1};"), vec!["E0023"]);
+        assert_eq!(check("tuple<int, str> pair = (1, 2);"), vec!["E0023"]);
+        assert_eq!(
+            check("list<int> items; str text = items[0];"),
+            vec!["E0023"]
+        );
+        assert_eq!(
+            check("list<int> items; int first = items[\"a\"];"),
+            vec!["E0023"]
+        );
+        assert_eq!(check("dict<str, int> ages; ages[1] = 2;"), vec!["E0023"]);
+        assert_eq!(
+            check("list<int> items; items.append(\"a\");"),
+            vec!["E0023"]
+        );
+        assert_eq!(check("list<int> items; items.push(1);"), vec!["E0011"]);
+        assert_eq!(check("list<int> items; items.append();"), vec!["E0019"]);
+        assert_eq!(check("list<int> items; items.pop(1);"), vec!["E0016"]);
+        assert_eq!(check("int number = 1; print(number[0]);"), vec!["E0062"]);
+        assert_eq!(check("int number = 1; print(number[.. 1]);"), vec!["E0062"]);
+        assert_eq!(
+            check("tuple<int> single = (1,); single[0] = 2;"),
+            vec!["E0062"]
+        );
+
+        // A list without type arguments is reported by `generics::check`, and its elements are unknown
+        assert_eq!(
+            check("list items = [1]; int first = items.pop();"),
+            Vec::<&str>::new()
+        );
+        let program: Program =
+            parse("list<int> items = [1]; var rest = items[1 .. 2]; var pair = (1, \"x\"); var nested = [[1], []];");
+        let names: TypeNames = TypeNames::default();
+        let types: Types = types::infer(&program, &Imports::default(), &names);
+        let inferred: Vec<Option<&str>> = program.statements[1..]
+            .iter()
+            .map(|statement| match *statement {
+                Statement::VariableDeclaration(ref declaration) => types.variable_type(declaration),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            inferred,
+            vec![
+                Some("list<int>"),
+                Some("tuple<int, str>"),
+                Some("list<list<int>>")
+            ]
+        );
+        assert_eq!(check("list<int> items = [[]];"), vec!["E0023"]);
+
+        // Empty literals have no elements to infer the type arguments from
+        assert_eq!(check("var items = [];"), vec!["E0046"]);
+        assert_eq!(check("var ages = {};"), vec!["E0046"]);
+        assert_eq!(
+            check("var items = []; items = [1]; int first = items[0];"),
+            Vec::<&str>::new()
+        );
+        let diagnostics: Vec<Diagnostic> =
+            types::check(&parse("var ages = {};"), &Imports::default());
+        assert_eq!(
+            diagnostics[0].notes[1].message,
+            "declare the type instead of `var`, e.g. `dict<str, int> ages`, or assign a value to the variable later"
+        );
+
+        // Only values of some built-in types can be keys
+        assert_eq!(
+            check("class Point {} var ages = {Point(): 1};"),
+            vec!["E0054"]
+        );
+        assert_eq!(
+            check("var names = {1: \"a\", none: \"b\"}; var flags = {true: 1};"),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_for() {
+        assert_eq!(
+            check(
+                "class Countdown implements Iterator<int> {
+                    pub function has_next() -> bool { return false; }
+                    pub function next() -> int { return 0; }
+                }
+                class Numbers implements Iterable<str> {
+                    pub function iterator() -> Iterator<str> { return none; }
+                }
+                list<int> items = [1];
+                for (int i in 0 .. 10 step 2) { items.append(i); }
+                for (int item in items) { }
+                for (str key in {\"a\": 1}) { }
+                for (str character in \"abc\") { }
+                for (int element in (1, 2)) { }
+                for (int count in Countdown()) { }
+                for (var name in Numbers()) { str text = name; }
+                for (var mixed in (1, \"a\")) { str text = mixed; }"
+            ),
+            Vec::<&str>::new()
+        );
+
+        assert_eq!(check("for (var i in 5) { }"), vec!["E0063"]);
+
+        assert_eq!(check("class A {} for (var a in A()) { }"), vec!["E0063"]);
+        assert_eq!(check("for (str i in 0 .. 3) { }"), vec!["E0023"]);
+        assert_eq!(check("for (var i in 0 .. \"a\") { }"), vec!["E0023"]);
+        assert_eq!(check("for (var i in 0 .. 3 step true) { }"), vec!["E0023"]);
+        assert_eq!(check("for (var i in [1]) { str text = i; }"), vec!["E0023"]);
+    }
+
+    #[test]
+    fn test_lambdas() {
+        assert_eq!(
+            check(
+                "function apply(function(int) -> int f, int value) -> int { return f(value); }
+                class Button {
+                    pub function() -> none on_click = () -> { print(1); };
+                }
+                int doubled = apply((int x) -> x * 2, 1);
+                function(int, int) -> int add = function(int a, int b) -> int { return a + b; };
+                int sum = add(1, 2);
+                function(bool) -> str show = (bool value) -> str(value);
+                function() -> int make = () -> 1;
+                int made = make();
+                Button().on_click();
+                list<int> numbers = [3, 1, 2];
+                numbers.sort((int a, int b) -> a - b);"
+            ),
+            Vec::<&str>::new()
+        );
+
+        assert_eq!(
+            check("var f = (int x) -> x; str text = f(1);"),
+            vec!["E0023"]
+        );
+        assert_eq!(check("var f = (int x) -> x; f(\"a\");"), vec!["E0023"]);
+        assert_eq!(check("var f = (int x) -> x; f(1, 2);"), vec!["E0016"]);
+        assert_eq!(check("var f = (int x) -> x; f();"), vec!["E0019"]);
+        assert_eq!(check("var f = (int x) -> x; f(x = 1);"), vec!["E0017"]);
+        assert_eq!(
+            check("function(int) -> str f = (int x) -> x;"),
+            vec!["E0023"]
+        );
+        assert_eq!(
+            check("function(int, int) -> int f = (int x) -> x;"),
+            vec!["E0023"]
+        );
+        assert_eq!(
+            check("list<int> numbers = [1]; numbers.sort((str a, str b) -> 0);"),
+            vec!["E0023"]
+        );
+        assert_eq!(check("int value = 1; value();"), vec!["E0044"]);
+
+        // Parameters may accept more and results may promise more than the expected function type
+        assert_eq!(
+            check("class A {} class B: A {} function(B) -> A f = (A a) -> B();"),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            check("class A {} class B: A {} function(A) -> B f = (B b) -> b;"),
+            vec!["E0023"]
+        );
+    }
+
+    #[test]
+    fn test_nesting() {
+        // Code nested up to the limit of the parser does not overflow the stack of the checks
+        let depth: usize = parser::parse::MAX_NESTING - 10;
+        for input in [
+            format!("int x = {}1{};", "(".repeat(depth), ")".repeat(depth)),
+            format!("int x = 1{};", " + 1".repeat(depth)),
+            format!("bool x = {}true;", "!".repeat(depth)),
+            format!(
+                "int x = 1; {}print(x);{}",
+                "if (x > 0) { ".repeat(depth),
+                " }".repeat(depth)
+            ),
+        ] {
+            assert!(compiler::check::check(&parse(&input)).is_empty());
+        }
+    }
+}
@@ -0,0 +1,418 @@
+//! I-Language interpreter runs checked programs of the I-Programming-Language.
+// I Language interpreter.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use std::rc::Rc;
+
+use crate::bigint::BigInt;
+use crate::value::Value;
+
+use compiler::generics;
+use compiler::integers::{self, Width};
+use lexer::tokens::mark::Mark;
+
+
+/////////////
+// FAILURE //
+/////////////
+
+/// An exception raised by an integer operation, e.g. an `OverflowError` if the result does not fit into its type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Failure {
+    /// The class of the exception.
+    pub class: &'static str,
+    /// The message of the exception.
+    pub message: String,
+}
+
+impl Failure {
+    /// Creates a failure.
+    fn new(class: &'static str, message: &str) -> Self {
+        Self {
+            class,
+            message: message.to_owned(),
+        }
+    }
+}
+
+
+//////////
+// KIND //
+//////////
+
+/// The integer type an operation is done in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Kind {
+    /// `int`, with 64 bits.
+    Int,
+    /// A sized integer type.
+    Sized(Width),
+    /// `bigint`.
+    Big,
+}
+
+impl Kind {
+    /// Returns the integer type of a value, `None` if it is not an integer.
+    const fn of(value: &Value) -> Option<Self> {
+        match *value {
+            Value::Int(_) => Some(Self::Int),
+            Value::Sized(_, width) => Some(Self::Sized(width)),
+            Value::BigInt(_) => Some(Self::Big),
+            _ => None,
+        }
+    }
+
+    /// Returns the type an operation on two integers is done in. An `int` takes the type of the other operand, as
+    /// integer literals are `int` values until they are used with another type. Returns `None` for two different
+    /// sized integer types, which are not mixed.
+    fn common(left: Self, right: Self) -> Option<Self> {
+        match (left, right) {
+            (Self::Int, other) | (other, Self::Int) => Some(other),
+            (Self::Big, Self::Big) => Some(Self::Big),
+            (Self::Sized(left), Self::Sized(right)) if left == right => Some(Self::Sized(left)),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the type.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Int => "int",
+            Self::Sized(width) => width.name(),
+            Self::Big => integers::BIGINT,
+        }
+    }
+
+    /// Returns the number of bits of a type with a fixed width.
+    const fn bits(self) -> u32 {
+        match self {
+            Self::Sized(Width::I8 | Width::U8) => 8,
+            Self::Sized(Width::I16 | Width::U16) => 16,
+            Self::Sized(Width::I32 | Width::U32) => 32,
+            Self::Int | Self::Big | Self::Sized(Width::I64 | Width::U64) => 64,
+        }
+    }
+
+    /// Creates a value of the type, `None` if the value is out of range.
+    fn value<'ast>(self, value: i128) -> Option<Value<'ast>> {
+        match self {
+            Self::Int => i64::try_from(value).ok().map(Value::Int),
+            Self::Sized(width) => width.contains(value).then_some(Value::Sized(value, width)),
+            Self::Big => Some(Value::BigInt(Rc::new(BigInt::from(value)))),
+        }
+    }
+
+    /// Reduces a value to the width of the type, keeping the lowest bits like a shift of a machine integer does.
+    fn wrap(self, value: i128) -> i128 {
+        let modulus: i128 = 1_i128 << self.bits();
+        let value: i128 = value.rem_euclid(modulus);
+        match self {
+            Self::Sized(Width::U8 | Width::U16 | Width::U32 | Width::U64) => value,
+            _ if value >= modulus >> 1_u32 => value - modulus,
+            _ => value,
+        }
+    }
+}
+
+
+////////////////
+// OPERATIONS //
+////////////////
+
+/// Applies a binary operator to two integers. `int` and the sized integer types are checked: a result out of the
+/// range of the type raises an `OverflowError` instead of wrapping around. Shifts keep the lowest bits.
+///
+/// # Returns
+///
+/// `None` if the operands are not integers of compatible types or the operator is not defined for them, otherwise
+/// the result or the exception raised.
+#[inline]
+pub fn binary<'ast>(
+    operator: &Mark,
+    left: &Value<'ast>,
+    right: &Value<'ast>,
+) -> Option<Result<Value<'ast>, Failure>> {
+    let kind: Kind = Kind::common(Kind::of(left)?, Kind::of(right)?)?;
+    if kind == Kind::Big {
+        return big_binary(operator, &to_big(left)?, &to_big(right)?);
+    }
+    let (left, right): (i128, i128) = (left.integer()?, right.integer()?);
+
+    let overflow = || {
+        Failure::new(
+            "OverflowError",
+            &format!("`{left} {operator} {right}` overflows `{}`", kind.name()),
+        )
+    };
+    let checked = |result: Option<i128>| -> Result<Value<'ast>, Failure> {
+        result
+            .and_then(|result| kind.value(result))
+            .ok_or_else(overflow)
+    };
+    let result: Result<Value, Failure> = match *operator {
+        Mark::Add => checked(left.checked_add(right)),
+        Mark::Subtract => checked(left.checked_sub(right)),
+        Mark::Multiply => checked(left.checked_mul(right)),
+        Mark::Divide | Mark::Modulo if right == 0 => {
+            Err(Failure::new("ZeroDivisionError", "division by zero"))
+        }
+        Mark::Divide => checked(left.checked_div(right)),
+        Mark::Modulo => checked(left.checked_rem(right)),
+        Mark::Exponentiation => match u32::try_from(right) {
+            Ok(exponent) => checked(left.checked_pow(exponent)),
+            Err(_) => Err(Failure::new(
+                "ValueError",
+                "the exponent must not be negative",
+            )),
+        },
+        Mark::BitAnd => checked(Some(left & right)),
+        Mark::BitOr => checked(Some(left | right)),
+        Mark::BitXor => checked(Some(left ^ right)),
+        Mark::ShiftLeft | Mark::ShiftRight => {
+            match u32::try_from(right)
+                .ok()
+                .filter(|shift| *shift < kind.bits())
+            {
+                Some(shift) if *operator == Mark::ShiftLeft => {
+                    checked(Some(kind.wrap(left << shift)))
+                }
+                Some(shift) => checked(Some(left >> shift)),
+                None => Err(Failure::new("ValueError", "the shift is out of range")),
+            }
+        }
+        Mark::Less => Ok(Value::Bool(left < right)),
+        Mark::LessEqual => Ok(Value::Bool(left <= right)),
+        Mark::Greater => Ok(Value::Bool(left > right)),
+        Mark::GreaterEqual => Ok(Value::Bool(left >= right)),
+        _ => return None,
+    };
+    Some(result)
+}
+
+/// Applies a binary operator to two `bigint` values. They never overflow and have no bitwise operators.
+fn big_binary<'ast>(
+    operator: &Mark,
+    left: &BigInt,
+    right: &BigInt,
+) -> Option<Result<Value<'ast>, Failure>> {
+    let big = |value: BigInt| Ok(Value::BigInt(Rc::new(value)));
+    let result: Result<Value, Failure> = match *operator {
+        Mark::Add => big(left + right),
+        Mark::Subtract => big(left - right),
+        Mark::Multiply => big(left * right),
+        Mark::Divide | Mark::Modulo => match left.div_rem(right) {
+            Some((quotient, _)) if *operator == Mark::Divide => big(quotient),
+            Some((_, remainder)) => big(remainder),
+            None => Err(Failure::new("ZeroDivisionError", "division by zero")),
+        },
+        Mark::Exponentiation if right.is_negative() => Err(Failure::new(
+            "ValueError",
+            "the exponent must not be negative",
+        )),
+        Mark::Exponentiation => match right.to_i128().and_then(|found| u32::try_from(found).ok()) {
+            Some(exponent) => big(left.pow(exponent)),
+            None => Err(Failure::new(
+                "OverflowError",
+                &format!("the exponent {right} is too large"),
+            )),
+        },
+        Mark::Less => Ok(Value::Bool(left < right)),
+        Mark::LessEqual => Ok(Value::Bool(left <= right)),
+        Mark::Greater => Ok(Value::Bool(left > right)),
+        Mark::GreaterEqual => Ok(Value::Bool(left >= right)),
+        _ => return None,
+    };
+    Some(result)
+}
+
+/// Applies a unary operator (`-`, `~`, `++` or `--`) to an integer. The result is checked like the result of
+/// [`binary`].
+///
+/// # Returns
+///
+/// `None` if the operand is not an integer or the operator is not defined for it, otherwise the result or the
+/// exception raised.
+#[inline]
+pub fn unary<'ast>(operator: &Mark, operand: &Value<'ast>) -> Option<Result<Value<'ast>, Failure>> {
+    let kind: Kind = Kind::of(operand)?;
+    match *operator {
+        Mark::Subtract if kind == Kind::Big => Some(Ok(Value::BigInt(Rc::new(-&to_big(operand)?)))),
+        Mark::Subtract => Some(
+            binary(&Mark::Subtract, &zero(kind), operand)?
+                .ok()
+                .ok_or_else(|| {
+                    Failure::new(
+                        "OverflowError",
+                        &format!("`-({operand})` overflows `{}`", kind.name()),
+                    )
+                }),
+        ),
+        Mark::Increase => binary(&Mark::Add, operand, &Value::Int(1)),
+        Mark::Decrease => binary(&Mark::Subtract, operand, &Value::Int(1)),
+        Mark::BitNot => {
+            let value: i128 = operand.integer().filter(|_| kind != Kind::Big)?;
+            let result: i128 = match kind {
+                Kind::Sized(width) if width.min() == 0 => width.max() - value,
+                _ => !value,
+            };
+            kind.value(result).map(Ok)
+        }
+        _ => None,
+    }
+}
+
+
+/////////////////
+// CONVERSIONS //
+/////////////////
+
+/// Converts an integer to the integer type a variable, parameter, field or return value is declared with. Integer
+/// literals are `int` values when they are evaluated, or `bigint` values if they are too large, and take the
+/// declared type here. The elements of a list of integer literals are converted to the element type.
+///
+/// # Parameters
+///
+/// - `value`: The value.
+/// - `type_name`: The declared type. A nullable integer type converts like its type without `?`.
+///
+/// # Errors
+///
+/// Raises an `OverflowError` if the value does not fit into the type.
+#[inline]
+pub fn adapt<'ast>(value: Value<'ast>, type_name: &str) -> Result<Value<'ast>, Failure> {
+    if let Value::List(ref elements) = value {
+        let (base, arguments): (&str, Vec<&str>) = generics::split(generics::non_null(type_name));
+        let kind: Option<Kind> = match (base, arguments.as_slice()) {
+            ("list", &[element]) => target(element),
+            _ => None,
+        };
+        // The elements of a list all have the same type, so only lists created from literals are converted
+        let first: Option<Kind> = elements.borrow().first().and_then(Kind::of);
+        if let Some(kind) = kind.filter(|kind| first.map_or(false, |first| first != *kind)) {
+            for element in elements.borrow_mut().iter_mut() {
+                *element = convert(element, kind)?;
+            }
+        }
+        return Ok(value);
+    }
+
+    match (Kind::of(&value), target(type_name)) {
+        (Some(found), Some(kind)) if found != kind => convert(&value, kind),
+        _ => Ok(value),
+    }
+}
+
+/// Converts an `int` to the integer type of the current value of a variable, field or element it is assigned to.
+///
+/// # Errors
+///
+/// Raises an `OverflowError` if the value does not fit into the type.
+#[inline]
+pub fn retain<'ast>(current: &Value, value: Value<'ast>) -> Result<Value<'ast>, Failure> {
+    match (Kind::of(current), Kind::of(&value)) {
+        (Some(kind @ (Kind::Sized(_) | Kind::Big)), Some(Kind::Int)) => convert(&value, kind),
+        _ => Ok(value),
+    }
+}
+
+/// Converts a value with a conversion function named after an integer type, e.g. `u8(x)`. Integers, `bool` values
+/// and strings of decimal digits can be converted.
+///
+/// # Returns
+///
+/// `None` if the name is not an integer type, otherwise the converted value or the exception raised.
+#[inline]
+pub fn conversion<'ast>(name: &str, value: &Value<'ast>) -> Option<Result<Value<'ast>, Failure>> {
+    let kind: Kind = target(name)?;
+    Some(match *value {
+        Value::Int(_) | Value::Sized(..) | Value::BigInt(_) => convert(value, kind),
+        Value::Bool(found) => convert(&Value::Int(i64::from(found)), kind),
+        Value::Str(ref text) => match text.trim().parse::<BigInt>() {
+            Ok(found) => convert(&Value::BigInt(Rc::new(found)), kind),
+            Err(()) => Err(Failure::new(
+                "TypeError",
+                &format!("cannot convert \"{text}\" to `{name}`"),
+            )),
+        },
+        ref other => Err(Failure::new(
+            "TypeError",
+            &format!("cannot convert `{}` to `{name}`", other.type_name()),
+        )),
+    })
+}
+
+/// Converts an integer to an integer type.
+///
+/// # Errors
+///
+/// Raises an `OverflowError` if the value does not fit into the type.
+fn convert<'ast>(value: &Value<'ast>, kind: Kind) -> Result<Value<'ast>, Failure> {
+    if kind == Kind::Big {
+        return Ok(Value::BigInt(Rc::new(to_big(value).unwrap_or_default())));
+    }
+    value
+        .integer()
+        .and_then(|found| kind.value(found))
+        .ok_or_else(|| {
+            Failure::new(
+                "OverflowError",
+                &format!("`{value}` does not fit into `{}`", kind.name()),
+            )
+        })
+}
+
+/// Returns the integer type with a name, ignoring a `?`.
+fn target(type_name: &str) -> Option<Kind> {
+    match type_name.trim_end_matches('?') {
+        "int" => Some(Kind::Int),
+        integers::BIGINT => Some(Kind::Big),
+        name => Width::new(name).map(Kind::Sized),
+    }
+}
+
+/// Returns zero as a value of an integer type.
+fn zero<'ast>(kind: Kind) -> Value<'ast> {
+    match kind {
+        Kind::Int => Value::Int(0),
+        Kind::Sized(width) => Value::Sized(0, width),
+        Kind::Big => Value::BigInt(Rc::default()),
+    }
+}
+
+/// Returns an integer as a `bigint`, `None` if the value is not an integer.
+fn to_big(value: &Value) -> Option<BigInt> {
+    match *value {
+        Value::BigInt(ref found) => Some((**found).clone()),
+        ref other => other.integer().map(BigInt::from),
+    }
+}
+
+/// Returns the value integer variables of a type start with, `None` if the type is no integer type.
+#[inline]
+pub fn default_value<'ast>(type_name: &str) -> Option<Value<'ast>> {
+    target(type_name).map(zero)
+}
@@ -0,0 +1,400 @@
+//! I-Language interpreter runs checked programs of the I-Programming-Language.
+// I Language interpreter.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use core::cmp::Ordering;
+use core::str::FromStr;
+
+
+////////////
+// BIGINT //
+////////////
+
+/// An integer of any size, the value of a `bigint`.
+///
+/// The magnitude is stored in base 2^32 with the least significant digit first and without leading zero digits,
+/// so every number has exactly one representation and zero has no digits.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BigInt {
+    /// Whether the number is smaller than zero. Zero is never negative.
+    negative: bool,
+    /// The digits of the magnitude, least significant first.
+    digits: Vec<u32>,
+}
+
+impl BigInt {
+    /// Creates a number from its sign and digits, removing leading zero digits.
+    fn new(negative: bool, mut digits: Vec<u32>) -> Self {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        Self {
+            negative: negative && !digits.is_empty(),
+            digits,
+        }
+    }
+
+    /// Returns whether the number is zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// Returns whether the number is smaller than zero.
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the number as an `i128`, `None` if it does not fit.
+    #[inline]
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.digits.len() > 4 {
+            return None;
+        }
+        let magnitude: u128 = self.digits.iter().rev().fold(0, |magnitude, digit| {
+            (magnitude << 32_u32) | u128::from(*digit)
+        });
+        if self.negative {
+            0_i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+
+    /// Divides the number by another one, rounding towards zero like the division of `int` values.
+    ///
+    /// # Returns
+    ///
+    /// The quotient and the remainder, which has the sign of the dividend. `None` if the divisor is zero.
+    #[inline]
+    pub fn div_rem(&self, divisor: &Self) -> Option<(Self, Self)> {
+        if divisor.is_zero() {
+            return None;
+        }
+
+        let mut quotient: Vec<u32> = vec![0; self.digits.len()];
+        let mut remainder: Vec<u32> = vec![];
+        for (index, digit) in self.digits.iter().enumerate().rev() {
+            for bit in (0..32_u32).rev() {
+                remainder = shift_left(&remainder);
+                if (digit >> bit) & 1 == 1 {
+                    match remainder.first_mut() {
+                        Some(lowest) => *lowest |= 1,
+                        None => remainder.push(1),
+                    }
+                }
+                if compare(&remainder, &divisor.digits) != Ordering::Less {
+                    remainder = subtract(&remainder, &divisor.digits);
+                    if let Some(found) = quotient.get_mut(index) {
+                        *found |= 1 << bit;
+                    }
+                }
+            }
+        }
+
+        Some((
+            Self::new(self.negative != divisor.negative, quotient),
+            Self::new(self.negative, remainder),
+        ))
+    }
+
+    /// Raises the number to a power.
+    #[inline]
+    #[must_use]
+    pub fn pow(&self, mut exponent: u32) -> Self {
+        let mut result: Self = Self::from(1_i64);
+        let mut base: Self = self.clone();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1_u32;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    /// Writes the number in a base from 2 to 36 with lowercase letters, e.g. `-ff` for -255 in base 16.
+    #[inline]
+    pub fn to_string_radix(&self, radix: u32) -> String {
+        let mut magnitude: Vec<u32> = self.digits.clone();
+        let mut written: Vec<char> = vec![];
+        while !magnitude.is_empty() {
+            let digit: u32 = Self::divide_small(&mut magnitude, radix);
+            written.extend(char::from_digit(digit, radix));
+            while magnitude.last() == Some(&0) {
+                magnitude.pop();
+            }
+        }
+
+        if written.is_empty() {
+            written.push('0');
+        }
+        if self.negative {
+            written.push('-');
+        }
+        written.iter().rev().collect()
+    }
+
+    /// Divides the magnitude by a small number in place and returns the remainder.
+    fn divide_small(digits: &mut [u32], divisor: u32) -> u32 {
+        let mut remainder: u64 = 0;
+        for digit in digits.iter_mut().rev() {
+            let current: u64 = (remainder << 32_u32) | u64::from(*digit);
+            *digit = low(current.checked_div(u64::from(divisor)).unwrap_or_default());
+            remainder = current.checked_rem(u64::from(divisor)).unwrap_or_default();
+        }
+        low(remainder)
+    }
+}
+
+impl From<i64> for BigInt {
+    #[inline]
+    fn from(value: i64) -> Self {
+        Self::from(i128::from(value))
+    }
+}
+
+impl From<i128> for BigInt {
+    #[inline]
+    fn from(value: i128) -> Self {
+        let mut magnitude: u128 = value.unsigned_abs();
+        let mut digits: Vec<u32> = vec![];
+        while magnitude > 0 {
+            digits.push(u32::try_from(magnitude & 0xFFFF_FFFF).unwrap_or_default());
+            magnitude >>= 32_u32;
+        }
+        Self::new(value < 0, digits)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ();
+
+    /// Parses a decimal number with an optional sign. Underscores between digits are ignored.
+    #[inline]
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (negative, digits): (bool, &str) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        if digits.is_empty() || digits.starts_with('_') {
+            return Err(());
+        }
+
+        let mut magnitude: Vec<u32> = vec![];
+        for character in digits.chars().filter(|character| *character != '_') {
+            let digit: u32 = character.to_digit(10).ok_or(())?;
+            let mut carry: u64 = u64::from(digit);
+            for found in &mut magnitude {
+                let current: u64 = u64::from(*found) * 10 + carry;
+                *found = low(current);
+                carry = current >> 32_u32;
+            }
+            if carry > 0 {
+                magnitude.push(low(carry));
+            }
+        }
+        Ok(Self::new(negative, magnitude))
+    }
+}
+
+impl core::fmt::Display for BigInt {
+    #[inline]
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.is_zero() {
+            return write!(formatter, "0");
+        }
+
+        // Nine decimal digits at a time fit into a digit of the magnitude
+        let mut magnitude: Vec<u32> = self.digits.clone();
+        let mut chunks: Vec<u32> = vec![];
+        while !magnitude.is_empty() {
+            chunks.push(Self::divide_small(&mut magnitude, 1_000_000_000));
+            while magnitude.last() == Some(&0) {
+                magnitude.pop();
+            }
+        }
+
+        if self.negative {
+            write!(formatter, "-")?;
+        }
+        let mut chunks = chunks.iter().rev();
+        if let Some(first) = chunks.next() {
+            write!(formatter, "{first}")?;
+        }
+        for chunk in chunks {
+            write!(formatter, "{chunk:09}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Ord for BigInt {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare(&self.digits, &other.digits),
+            (true, true) => compare(&other.digits, &self.digits),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl core::ops::Neg for &BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.digits.clone())
+    }
+}
+
+impl core::ops::Add for &BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn add(self, other: Self) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::new(self.negative, add(&self.digits, &other.digits));
+        }
+        // The signs differ, so the smaller magnitude is subtracted from the larger one
+        match compare(&self.digits, &other.digits) {
+            Ordering::Less => BigInt::new(other.negative, subtract(&other.digits, &self.digits)),
+            _ => BigInt::new(self.negative, subtract(&self.digits, &other.digits)),
+        }
+    }
+}
+
+impl core::ops::Sub for &BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn sub(self, other: Self) -> BigInt {
+        self + &-other
+    }
+}
+
+impl core::ops::Mul for &BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn mul(self, other: Self) -> BigInt {
+        let mut digits: Vec<u32> = vec![0; self.digits.len() + other.digits.len()];
+        for (index, left) in self.digits.iter().enumerate() {
+            let mut carry: u64 = 0;
+            for (offset, right) in other.digits.iter().enumerate() {
+                let Some(slot) = digits.get_mut(index + offset) else {
+                    continue;
+                };
+                let current: u64 = u64::from(*left) * u64::from(*right) + u64::from(*slot) + carry;
+                *slot = low(current);
+                carry = current >> 32_u32;
+            }
+            if let Some(slot) = digits.get_mut(index + other.digits.len()) {
+                *slot = low(carry);
+            }
+        }
+        BigInt::new(self.negative != other.negative, digits)
+    }
+}
+
+
+/////////////
+// HELPERS //
+/////////////
+
+/// Returns the lower 32 bits of a number.
+fn low(value: u64) -> u32 {
+    u32::try_from(value & 0xFFFF_FFFF).unwrap_or_default()
+}
+
+/// Compares two magnitudes.
+fn compare(left: &[u32], right: &[u32]) -> Ordering {
+    left.len()
+        .cmp(&right.len())
+        .then_with(|| left.iter().rev().cmp(right.iter().rev()))
+}
+
+/// Adds two magnitudes.
+fn add(left: &[u32], right: &[u32]) -> Vec<u32> {
+    let mut digits: Vec<u32> = Vec::with_capacity(left.len().max(right.len()) + 1);
+    let mut carry: u64 = 0;
+    for index in 0..left.len().max(right.len()) {
+        let current: u64 = u64::from(left.get(index).copied().unwrap_or_default())
+            + u64::from(right.get(index).copied().unwrap_or_default())
+            + carry;
+        digits.push(low(current));
+        carry = current >> 32_u32;
+    }
+    if carry > 0 {
+        digits.push(low(carry));
+    }
+    digits
+}
+
+/// Subtracts a magnitude from a magnitude that is not smaller.
+fn subtract(left: &[u32], right: &[u32]) -> Vec<u32> {
+    let mut digits: Vec<u32> = Vec::with_capacity(left.len());
+    let mut borrow: bool = false;
+    for (index, digit) in left.iter().enumerate() {
+        let (current, first): (u32, bool) =
+            digit.overflowing_sub(right.get(index).copied().unwrap_or_default());
+        let (current, second): (u32, bool) = current.overflowing_sub(u32::from(borrow));
+        digits.push(current);
+        borrow = first || second;
+    }
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    digits
+}
+
+/// Multiplies a magnitude by two.
+fn shift_left(digits: &[u32]) -> Vec<u32> {
+    let mut shifted: Vec<u32> = Vec::with_capacity(digits.len() + 1);
+    let mut carry: u32 = 0;
+    for digit in digits {
+        shifted.push((digit << 1_u32) | carry);
+        carry = digit >> 31_u32;
+    }
+    if carry > 0 {
+        shifted.push(carry);
+    }
+    shifted
+}
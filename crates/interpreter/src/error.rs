@@ -0,0 +1,97 @@
+//! Errors reported when a program stops because of an exception.
+// I Language interpreter errors.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use lexer::tokens::token::Location;
+
+
+///////////
+// TRACE //
+///////////
+
+/// An entry of the stack trace of an exception: a running function and where it was when the exception was thrown.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TraceEntry {
+    /// The name of the function, e.g. `divide`, `Player.jump` or `<main>` for the top level of the program.
+    pub function: String,
+    /// The location inside the function, either the `throw` or the call of the next function.
+    pub location: Location,
+}
+
+
+//////////////
+// UNCAUGHT //
+//////////////
+
+/// An exception that was not caught by the program.
+///
+/// The stack trace starts with the function throwing the exception and ends with the top level of the program.
+/// A long trace, e.g. of a `RecursionError`, keeps as many innermost as outermost entries and leaves out the calls
+/// in between. The exception is displayed like this:
+///
+/// ```text
+/// Uncaught ValueError: the divisor must not be negative
+///     at divide (main.il:3:9)
+///     at <main> (main.il:7:7)
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Uncaught {
+    /// The name of the class of the exception, e.g. `TypeError`.
+    pub exception: String,
+    /// The message of the exception.
+    pub message: String,
+    /// The stack trace, innermost function first.
+    pub trace: Vec<TraceEntry>,
+    /// The number of entries left out of the middle of the stack trace.
+    pub omitted: usize,
+}
+
+impl core::fmt::Display for Uncaught {
+    #[inline]
+    #[allow(clippy::integer_division, clippy::integer_division_remainder_used)]
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(formatter, "Uncaught {}", self.exception)?;
+        if !self.message.is_empty() {
+            write!(formatter, ": {}", self.message)?;
+        }
+
+        for (index, entry) in self.trace.iter().enumerate() {
+            if self.omitted > 0 && index == self.trace.len() / 2 {
+                write!(formatter, "\n    ... {} more calls", self.omitted)?;
+            }
+            write!(
+                formatter,
+                "\n    at {} ({})",
+                entry.function, entry.location
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for Uncaught {}
@@ -0,0 +1,3148 @@
+//! Runs a checked program by walking its abstract syntax tree.
+// I Language interpreter.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use core::cell::RefCell;
+use core::time::Duration;
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::arithmetic::{self, Failure};
+use crate::bigint::BigInt;
+use crate::error::{TraceEntry, Uncaught};
+use crate::value::{Closure, Dict, Generator, Iteration, Key, Object, Status, VTable, Value};
+
+use compiler::annotations;
+use compiler::classes::ClassTable;
+use compiler::collections;
+use compiler::constants;
+use compiler::contracts;
+use compiler::formats::{Presentation, Specification};
+use compiler::generators::{self, State, StateMachine, Target};
+use compiler::integers;
+use compiler::modules::{self, Imports, Item, Module, Modules};
+use compiler::operators;
+use compiler::prelude;
+use compiler::resolve::{self, address, Resolution};
+use compiler::types;
+use lexer::tokens::mark::Mark;
+use lexer::tokens::token::{Location, TypeDefinition};
+use parser::ast::{
+    Assignment, Binary, Block, Call, Class, ClassMember, Expression, For, ForIterable, FormatField,
+    FormatPart, FormatString, Function, Identifier, Index, Lambda, LambdaBody, Literal, Match,
+    MatchArm, MatchBody, Member, Parameter, Pattern, Program, RangeBound, Slice, Statement, Try,
+    TypeAnnotation, Unary,
+};
+use parser::print;
+
+use unicode_segmentation::UnicodeSegmentation as _;
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// The maximum number of nested function calls. Deeper calls throw a `RecursionError`.
+const MAX_DEPTH: usize = 1000;
+
+/// The stack space a call needs at least. With less space left, the call continues on a newly allocated stack
+/// segment, so nested calls up to [`MAX_DEPTH`] do not overflow the native stack.
+const RED_ZONE: usize = 128 * 1024;
+
+/// The size of the stack segments allocated when the stack runs low, see [`RED_ZONE`].
+const STACK_SEGMENT: usize = 4 * 1024 * 1024;
+
+/// The number of innermost and of outermost entries shown of a longer stack trace.
+const TRACE_ENDS: usize = 10;
+
+/// The variable holding the matched value of a `match` in a generator while its cases run. It is a keyword, so it
+/// cannot hide a variable of the program.
+const SUBJECT: &str = "match";
+
+
+////////////
+// UNWIND //
+////////////
+
+/// The reasons to stop executing the statements of a block before its end.
+enum Unwind<'ast> {
+    /// A `break` statement, handled by the innermost loop.
+    Break,
+    /// A `continue` statement, handled by the innermost loop.
+    Continue,
+    /// A `return` statement, handled by the running function.
+    Return(Value<'ast>),
+    /// A thrown exception, handled by the innermost matching `catch` clause.
+    Throw(Thrown<'ast>),
+}
+
+/// A thrown exception on its way to a `catch` clause.
+struct Thrown<'ast> {
+    /// The exception object.
+    exception: Rc<RefCell<Object<'ast>>>,
+    /// The stack trace at the time the exception was thrown.
+    trace: Vec<TraceEntry>,
+}
+
+/// The result of executing a statement or evaluating an expression.
+type Flow<'ast, T = ()> = Result<T, Unwind<'ast>>;
+
+/// The outcome of each test function, paired with its name.
+pub type TestResults<'modules> = Vec<(&'modules str, Result<(), Uncaught>)>;
+
+
+///////////////////
+// RUN FUNCTIONS //
+///////////////////
+
+/// Runs a program that does not import other modules. The program should be checked by
+/// [`compiler::check::check`] first, errors the checks would have reported are turned into exceptions at runtime.
+/// Its constants are folded first, see [`constants::fold`].
+///
+/// # Parameters
+///
+/// - `program`: The program to run.
+/// - `input`: Where `input()` reads lines from, usually the standard input.
+/// - `output`: Where `print()` writes to, usually the standard output.
+///
+/// # Returns
+///
+/// Nothing if the program ran to its end.
+///
+/// # Errors
+///
+/// Errors with the exception and its stack trace if an exception was not caught.
+///
+/// # Examples
+///
+/// ```rust
+/// # use interpreter::interpret;
+/// let tokens = lexer::lex::lex("print(1 + 2);", "<stdin>").unwrap();
+/// let program = parser::parse::parse(&tokens).unwrap();
+/// let mut output: Vec<u8> = vec![];
+///
+/// interpret::run(&program, &mut std::io::empty(), &mut output).unwrap();
+/// assert_eq!(String::from_utf8(output).unwrap(), "3\n");
+/// ```
+///
+/// # See also
+///
+/// - [`run_modules`]
+/// - [`Uncaught`]
+#[inline]
+pub fn run(
+    program: &Program,
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+) -> Result<(), Uncaught> {
+    let program: Program = constants::fold(program.clone());
+    let imports: Imports = Imports::default();
+    let module: ModuleState = ModuleState {
+        name: "<main>".to_owned(),
+        file: program.location.file.clone(),
+        classes: ClassTable::new(&program, &imports),
+        resolution: resolve::resolve(&program, &imports),
+        imports,
+        globals: HashMap::new(),
+    };
+
+    Interpreter::new(vec![module], input, output).run(&[&program])
+}
+
+/// Runs all modules of a program, which should be checked by [`compiler::check::check_modules`] first.
+/// Every module runs once, after the modules it imports and before the modules importing it.
+///
+/// # Parameters
+///
+/// - `modules`: The modules to run, usually the output of [`Modules::load`].
+/// - `input`: Where `input()` reads lines from, usually the standard input.
+/// - `output`: Where `print()` writes to, usually the standard output.
+///
+/// # Returns
+///
+/// Nothing if the program ran to its end.
+///
+/// # Errors
+///
+/// Errors with the exception and its stack trace if an exception was not caught.
+///
+/// # See also
+///
+/// - [`run`]
+#[inline]
+pub fn run_modules(
+    modules: &Modules,
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+) -> Result<(), Uncaught> {
+    let programs: Vec<&Program> = modules.iter().map(|module| &module.program).collect();
+    Interpreter::new(module_states(modules), input, output).run(&programs)
+}
+
+/// Runs the functions of the entry module marked with `@test`, see [`annotations::tests`]. All modules run like
+/// with [`run_modules`] first, so the tests can use the variables of the modules.
+///
+/// # Parameters
+///
+/// - `modules`: The modules to test, usually the output of [`Modules::load`].
+/// - `input`: Where `input()` reads lines from, usually the standard input.
+/// - `output`: Where `print()` writes to, usually the standard output.
+///
+/// # Returns
+///
+/// The name of every test in source order, with the exception it did not catch if it failed.
+///
+/// # Errors
+///
+/// Errors with the exception and its stack trace if an exception was not caught while running the modules.
+///
+/// # Examples
+///
+/// ```rust
+/// # use compiler::modules::{Module, Modules};
+/// # use interpreter::interpret;
+/// let source = "@test function passes() { } @test function fails() { throw ValueError(\"wrong\"); }";
+/// let tokens = lexer::lex::lex(source, "main.il").unwrap();
+/// let program = parser::parse::parse(&tokens).unwrap();
+/// let entry = Module { name: "main".to_owned(), file: "main.il".to_owned(), source: source.to_owned(), program };
+/// let (modules, _) = Modules::load(entry);
+///
+/// let results = interpret::run_tests(&modules, &mut std::io::empty(), &mut std::io::sink()).unwrap();
+/// assert!(results[0].1.is_ok());
+/// assert_eq!(results[1].1.as_ref().unwrap_err().message, "wrong");
+/// ```
+#[inline]
+pub fn run_tests<'modules>(
+    modules: &'modules Modules,
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+) -> Result<TestResults<'modules>, Uncaught> {
+    let programs: Vec<&Program> = modules.iter().map(|module| &module.program).collect();
+    let mut interpreter: Interpreter = Interpreter::new(module_states(modules), input, output);
+    interpreter.run(&programs)?;
+
+    let entry: usize = programs.len() - 1;
+    Ok(annotations::tests(&modules.entry().program)
+        .into_iter()
+        .map(|test| (test.name.name.as_str(), interpreter.run_test(entry, test)))
+        .collect())
+}
+
+/// Creates the runtime state of every module of a program.
+fn module_states(modules: &Modules) -> Vec<ModuleState<'_>> {
+    let entry: &str = &modules.entry().name;
+    modules
+        .iter()
+        .map(|module| {
+            let imports: Imports = modules.imports(module);
+            ModuleState {
+                name: if module.name == entry {
+                    "<main>".to_owned()
+                } else {
+                    format!("<module {}>", module.name)
+                },
+                file: module.file.clone(),
+                classes: ClassTable::new(&module.program, &imports),
+                resolution: resolve::resolve(&module.program, &imports),
+                imports,
+                globals: HashMap::new(),
+            }
+        })
+        .collect()
+}
+
+
+/////////////
+// HELPERS //
+/////////////
+
+/// Returns the value variables of a type have before anything is assigned to them. Variables of nullable types
+/// are `none`, variables of integer types zero.
+fn default_value<'ast>(annotation: &TypeAnnotation) -> Value<'ast> {
+    if annotation.nullable {
+        return Value::None;
+    }
+
+    match annotation.name.as_str() {
+        "str" => Value::Str("".into()),
+        "bool" => Value::Bool(false),
+        name => arithmetic::default_value(name).unwrap_or(Value::None),
+    }
+}
+
+/// Returns the positions covered by a slice of a collection with the given length. Negative bounds count from the
+/// end and bounds out of range are clamped, so slices never raise an `IndexError`.
+fn slice_range(start: Option<i64>, end: Option<i64>, length: usize) -> core::ops::Range<usize> {
+    let clamp = |bound: i64| -> usize {
+        let signed_length: i64 = i64::try_from(length).unwrap_or(i64::MAX);
+        let bound: i64 = if bound < 0 {
+            bound.saturating_add(signed_length)
+        } else {
+            bound
+        };
+        usize::try_from(bound.clamp(0, signed_length)).unwrap_or(0)
+    };
+
+    let start: usize = start.map_or(0, clamp);
+    let end: usize = end.map_or(length, clamp).max(start);
+    start..end
+}
+
+/// Replaces the escape sequences of a string literal, e.g. `\n`, with the characters they stand for.
+/// Unknown escape sequences are kept as they are.
+fn unescape(value: &str) -> String {
+    let mut result: String = String::with_capacity(value.len());
+    let mut characters: core::str::Chars = value.chars();
+
+    while let Some(character) = characters.next() {
+        if character != '\\' {
+            result.push(character);
+            continue;
+        }
+
+        match characters.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('r') => result.push('\r'),
+            Some('0') => result.push('\0'),
+            Some(escaped @ ('\\' | '"' | '\'')) => result.push(escaped),
+            Some(other) => {
+                result.push('\\');
+                result.push(other);
+            }
+            None => result.push('\\'),
+        }
+    }
+
+    result
+}
+
+/// Returns a public item of a module.
+fn export<'ast>(module: &'ast Module, name: &str) -> Option<Item<'ast>> {
+    modules::items(&module.program)
+        .get(name)
+        .copied()
+        .filter(Item::is_public)
+}
+
+/// Returns the name of a function shown in stack traces, e.g. `Player.greet` for a method.
+fn function_name(function: &Function, class: Option<&Class>) -> String {
+    match class {
+        Some(class) if class.name.name == function.name.name => function.name.name.clone(),
+        Some(class) => format!("{}.{}", class.name.name, function.name.name),
+        None => function.name.name.clone(),
+    }
+}
+
+/// Returns the value a function call results in after its body stopped running.
+fn returned(result: Flow<'_>) -> Flow<'_, Value<'_>> {
+    match result {
+        Ok(()) | Err(Unwind::Break | Unwind::Continue) => Ok(Value::None),
+        Err(Unwind::Return(value)) => Ok(value),
+        Err(Unwind::Throw(thrown)) => Err(Unwind::Throw(thrown)),
+    }
+}
+
+/// Returns the location shown in stack traces for a call: the name of the called function or method.
+fn call_location(node: &Call) -> &Location {
+    match *node.callee {
+        Expression::Member(ref member) => &member.member.location,
+        ref callee => callee.location(),
+    }
+}
+
+
+/////////////////
+// INTERPRETER //
+/////////////////
+
+/// The state of a module of the running program.
+struct ModuleState<'ast> {
+    /// The name shown in stack traces for the top level of the module.
+    name: String,
+    /// The file the module is located in, used to find the module of a function.
+    file: String,
+    /// The classes visible in the module, including the built-in and imported ones.
+    classes: ClassTable<'ast>,
+    /// The names the module imports.
+    imports: Imports<'ast>,
+    /// What the names of the module refer to, used to find the variables its lambdas capture.
+    resolution: Resolution<'ast>,
+    /// The variables and functions declared at the top level of the module.
+    globals: HashMap<&'ast str, Value<'ast>>,
+}
+
+/// A running function call.
+struct Frame<'ast> {
+    /// The name of the function shown in stack traces.
+    function: String,
+    /// The index of the module declaring the function.
+    module: usize,
+    /// The variables of each block, innermost last. Empty for the top level of a module, which uses its globals.
+    scopes: Vec<HashMap<&'ast str, Variable<'ast>>>,
+    /// The object `this` refers to in methods and constructors.
+    this: Option<Rc<RefCell<Object<'ast>>>>,
+    /// The class declaring the running method, used for `super`.
+    class: Option<&'ast Class>,
+    /// The location the function was called at. `None` for the top level of the program.
+    call: Option<Location>,
+}
+
+/// A variable of a running function call.
+enum Variable<'ast> {
+    /// A variable only the function uses.
+    Local(Value<'ast>),
+    /// A variable captured by a lambda, shared between the function and the closures of the lambda.
+    Boxed(Rc<RefCell<Value<'ast>>>),
+}
+
+impl<'ast> Variable<'ast> {
+    /// Returns the value of the variable.
+    fn value(&self) -> Value<'ast> {
+        match *self {
+            Self::Local(ref value) => value.clone(),
+            Self::Boxed(ref cell) => cell.borrow().clone(),
+        }
+    }
+
+    /// Assigns a value to the variable.
+    fn set(&mut self, value: Value<'ast>) {
+        match *self {
+            Self::Local(ref mut slot) => *slot = value,
+            Self::Boxed(ref cell) => *cell.borrow_mut() = value,
+        }
+    }
+
+    /// Boxes the variable, unless it already is, and returns the box to share it with a closure.
+    fn share(&mut self) -> Rc<RefCell<Value<'ast>>> {
+        let cell: Rc<RefCell<Value>> = match *self {
+            Self::Boxed(ref cell) => return Rc::clone(cell),
+            Self::Local(ref mut value) => {
+                Rc::new(RefCell::new(core::mem::replace(value, Value::None)))
+            }
+        };
+        *self = Self::Boxed(Rc::clone(&cell));
+        cell
+    }
+}
+
+/// Boxes the variables of a scope, so a suspended generator keeps sharing them with the closures it created.
+fn share<'ast>(
+    scope: &mut HashMap<&'ast str, Variable<'ast>>,
+) -> HashMap<&'ast str, Rc<RefCell<Value<'ast>>>> {
+    scope
+        .iter_mut()
+        .map(|(&name, variable)| (name, variable.share()))
+        .collect()
+}
+
+/// The state of a running program.
+struct Interpreter<'ast, 'io> {
+    /// The modules of the program, each after the modules it imports.
+    modules: Vec<ModuleState<'ast>>,
+    /// The running function calls, the top level of a module first.
+    frames: Vec<Frame<'ast>>,
+    /// Where `input()` reads from.
+    input: &'io mut dyn BufRead,
+    /// Where `print()` writes to.
+    output: &'io mut dyn Write,
+    /// The vtables of the classes instantiated so far, keyed by the address of the class declaration.
+    vtables: RefCell<HashMap<*const Class, Rc<VTable<'ast>>>>,
+    /// The state machines of the generators called so far, keyed by the address of the function declaration.
+    machines: HashMap<*const Function, Rc<StateMachine<'ast>>>,
+}
+
+impl<'ast, 'io> Interpreter<'ast, 'io> {
+    /// Creates an interpreter for modules that did not run yet.
+    fn new(
+        modules: Vec<ModuleState<'ast>>,
+        input: &'io mut dyn BufRead,
+        output: &'io mut dyn Write,
+    ) -> Self {
+        Self {
+            modules,
+            frames: vec![],
+            input,
+            output,
+            vtables: RefCell::new(HashMap::new()),
+            machines: HashMap::new(),
+        }
+    }
+
+    /// Runs the top level of each module, in the order of the modules.
+    #[allow(clippy::indexing_slicing)]
+    fn run(&mut self, programs: &[&'ast Program]) -> Result<(), Uncaught> {
+        for (index, program) in programs.iter().enumerate() {
+            self.frames = vec![Frame {
+                function: self.modules[index].name.clone(),
+                module: index,
+                scopes: vec![],
+                this: None,
+                class: None,
+                call: None,
+            }];
+            self.hoist(&program.statements);
+
+            if let Err(Unwind::Throw(thrown)) = self.statements(&program.statements) {
+                return Err(Self::uncaught(&thrown));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Calls a test function without arguments from the top level of its module, after the modules ran.
+    #[allow(clippy::indexing_slicing)]
+    fn run_test(&mut self, module: usize, test: &'ast Function) -> Result<(), Uncaught> {
+        self.frames = vec![Frame {
+            function: self.modules[module].name.clone(),
+            module,
+            scopes: vec![],
+            this: None,
+            class: None,
+            call: None,
+        }];
+
+        match self.invoke(test, None, None, vec![], &test.location) {
+            Err(Unwind::Throw(thrown)) => Err(Self::uncaught(&thrown)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'ast> Interpreter<'ast, '_> {
+    // Variables
+
+    /// Returns the running function call.
+    fn frame(&mut self) -> &mut Frame<'ast> {
+        self.frames
+            .last_mut()
+            .expect("the top level of a module is always running")
+    }
+
+    /// Returns the module declaring the running function.
+    #[allow(clippy::indexing_slicing)]
+    fn module(&self) -> &ModuleState<'ast> {
+        let index: usize = self.frames.last().map_or(0, |frame| frame.module);
+        &self.modules[index]
+    }
+
+    /// Returns the classes visible in the module declaring the running function.
+    fn classes(&self) -> &ClassTable<'ast> {
+        &self.module().classes
+    }
+
+    /// Returns the [`VTable`] of a class, building it the first time an instance of the class is created.
+    fn vtable(&self, class: &'ast Class) -> Rc<VTable<'ast>> {
+        let key: *const Class = address(class);
+        if let Some(found) = self.vtables.borrow().get(&key) {
+            return Rc::clone(found);
+        }
+
+        let vtable: Rc<VTable> = Rc::new(self.classes().vtable(class));
+        self.vtables.borrow_mut().insert(key, Rc::clone(&vtable));
+        vtable
+    }
+
+    /// Returns the index of the module declaring a node, found by the file of its location.
+    /// Nodes of the prelude use the module of the running function.
+    fn module_of(&self, location: &Location) -> usize {
+        self.modules
+            .iter()
+            .position(|module| module.file == location.file)
+            .unwrap_or_else(|| self.frames.last().map_or(0, |frame| frame.module))
+    }
+
+    /// Opens a new scope. Functions declared directly in the statements are visible in the whole scope.
+    fn enter(&mut self, statements: &'ast [Statement]) {
+        self.frame().scopes.push(HashMap::new());
+        self.hoist(statements);
+    }
+
+    /// Declares the functions declared directly in the statements in the innermost scope.
+    fn hoist(&mut self, statements: &'ast [Statement]) {
+        for statement in statements {
+            if let Statement::Function(ref function) = *statement {
+                self.declare(&function.name.name, Value::Function(function));
+            }
+        }
+    }
+
+    /// Closes the innermost scope.
+    fn exit(&mut self) {
+        self.frame().scopes.pop();
+    }
+
+    /// Declares a variable in the innermost scope, or as a global at the top level of a module.
+    #[allow(clippy::indexing_slicing)]
+    fn declare(&mut self, name: &'ast str, value: Value<'ast>) {
+        let frame: &mut Frame = self.frame();
+        let module: usize = frame.module;
+
+        match frame.scopes.last_mut() {
+            Some(scope) => {
+                scope.insert(name, Variable::Local(value));
+            }
+            None => {
+                self.modules[module].globals.insert(name, value);
+            }
+        }
+    }
+
+    /// Returns the value of a variable. Functions only see their own variables and the globals of their module.
+    #[allow(clippy::indexing_slicing)]
+    fn lookup(&self, name: &str) -> Option<Value<'ast>> {
+        let frame: &Frame = self.frames.last()?;
+
+        match frame.scopes.iter().rev().find_map(|scope| scope.get(name)) {
+            Some(variable) => Some(variable.value()),
+            None => self.modules[frame.module].globals.get(name).cloned(),
+        }
+    }
+
+    /// Assigns a value to a variable. Returns `false` if the variable is not defined.
+    #[allow(clippy::indexing_slicing)]
+    fn assign(&mut self, name: &str, value: Value<'ast>) -> bool {
+        let Some(frame) = self.frames.last_mut() else {
+            return false;
+        };
+        let module: usize = frame.module;
+
+        match frame
+            .scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.get_mut(name))
+        {
+            Some(variable) => variable.set(value),
+            None => match self.modules[module].globals.get_mut(name) {
+                Some(slot) => *slot = value,
+                None => return false,
+            },
+        }
+        true
+    }
+
+    // Exceptions
+
+    /// Returns the stack trace of the running program, with the innermost function at the given location.
+    fn trace(&self, location: &Location) -> Vec<TraceEntry> {
+        let mut location: &Location = location;
+        let mut trace: Vec<TraceEntry> = vec![];
+
+        for frame in self.frames.iter().rev() {
+            trace.push(TraceEntry {
+                function: frame.function.clone(),
+                location: location.clone(),
+            });
+            if let Some(ref call) = frame.call {
+                location = call;
+            }
+        }
+
+        trace
+    }
+
+    /// Creates an instance of a built-in exception class.
+    fn exception(&self, class: &str, message: &str) -> Rc<RefCell<Object<'ast>>> {
+        let class: &Class = self
+            .classes()
+            .get(class)
+            .expect("built-in exception classes are declared in the prelude");
+
+        let mut fields: HashMap<String, Value> = self
+            .classes()
+            .fields(class)
+            .iter()
+            .map(|field| {
+                (
+                    field.name.name.clone(),
+                    default_value(&field.type_annotation),
+                )
+            })
+            .collect();
+        fields.insert("message".to_owned(), Value::Str(message.into()));
+
+        Rc::new(RefCell::new(Object {
+            class,
+            vtable: self.vtable(class),
+            fields,
+        }))
+    }
+
+    /// Throws an instance of a built-in exception class.
+    fn error(&self, class: &str, message: &str, location: &Location) -> Unwind<'ast> {
+        Unwind::Throw(Thrown {
+            exception: self.exception(class, message),
+            trace: self.trace(location),
+        })
+    }
+
+    /// Throws the exception an integer operation raised, see [`Failure`].
+    fn failure(&self, failure: &Failure, location: &Location) -> Unwind<'ast> {
+        self.error(failure.class, &failure.message, location)
+    }
+
+    /// Converts an integer to the integer type a variable, parameter, field or return value is declared with, see
+    /// [`arithmetic::adapt`].
+    fn adapt(
+        &self,
+        value: Value<'ast>,
+        annotation: Option<&TypeAnnotation>,
+        location: &Location,
+    ) -> Flow<'ast, Value<'ast>> {
+        match annotation {
+            Some(annotation) => arithmetic::adapt(value, &annotation.name)
+                .map_err(|failure| self.failure(&failure, location)),
+            None => Ok(value),
+        }
+    }
+
+    /// Converts an `int` assigned to a variable, field or element to the integer type of its current value, see
+    /// [`arithmetic::retain`].
+    fn retain(
+        &self,
+        current: Option<&Value>,
+        value: Value<'ast>,
+        location: &Location,
+    ) -> Flow<'ast, Value<'ast>> {
+        match current {
+            Some(current) => arithmetic::retain(current, value)
+                .map_err(|failure| self.failure(&failure, location)),
+            None => Ok(value),
+        }
+    }
+
+    /// Converts an exception that was not caught into an error. Of a trace longer than twice [`TRACE_ENDS`], only
+    /// the innermost and the outermost entries are kept.
+    fn uncaught(thrown: &Thrown) -> Uncaught {
+        let exception: core::cell::Ref<Object> = thrown.exception.borrow();
+        let mut trace: Vec<TraceEntry> = thrown.trace.clone();
+        let omitted: usize = trace.len().saturating_sub(2 * TRACE_ENDS);
+        if omitted > 0 {
+            trace.drain(TRACE_ENDS..TRACE_ENDS + omitted);
+        }
+
+        Uncaught {
+            exception: exception.class.name.name.clone(),
+            message: exception
+                .fields
+                .get("message")
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            trace,
+            omitted,
+        }
+    }
+
+    // Statements
+
+    /// Executes the statements of a block in a new scope.
+    fn block(&mut self, block: &'ast Block) -> Flow<'ast> {
+        self.enter(&block.statements);
+        let result: Flow = self.statements(&block.statements);
+        self.exit();
+
+        result
+    }
+
+    /// Executes statements in the current scope.
+    fn statements(&mut self, statements: &'ast [Statement]) -> Flow<'ast> {
+        for statement in statements {
+            self.statement(statement)?;
+        }
+
+        Ok(())
+    }
+
+    /// Executes a single statement.
+    #[allow(clippy::too_many_lines)]
+    fn statement(&mut self, statement: &'ast Statement) -> Flow<'ast> {
+        match *statement {
+            Statement::Assert(ref node) => self.check(
+                &node.condition,
+                node.message.as_ref(),
+                &node.location,
+                |source| format!("assertion `{source}` failed"),
+            ),
+            Statement::Block(ref block) => self.block(block),
+            Statement::Break(_) => Err(Unwind::Break),
+            Statement::Class(_) | Statement::Function(_) => Ok(()),
+            Statement::Continue(_) => Err(Unwind::Continue),
+            Statement::Expression(ref node) => self.expression(&node.expression).map(drop),
+            Statement::For(ref node) => self.for_statement(node),
+            Statement::If(ref node) => {
+                if self.condition(&node.condition)? {
+                    self.block(&node.then_block)
+                } else if let Some(ref else_block) = node.else_block {
+                    self.block(else_block)
+                } else {
+                    Ok(())
+                }
+            }
+            Statement::Import(ref node) => {
+                let name: &Identifier = node
+                    .alias
+                    .as_ref()
+                    .unwrap_or_else(|| node.path.last().expect("paths have at least one name"));
+                match self.module().imports.modules.get(name.name.as_str()) {
+                    Some(&module) => {
+                        self.declare(&name.name, Value::Module(module));
+                        Ok(())
+                    }
+                    None => Err(self.error(
+                        "RuntimeError",
+                        &format!("module `{}` is not loaded", name.name),
+                        &node.location,
+                    )),
+                }
+            }
+            Statement::Match(ref node) => self.match_value(node).map(drop),
+            Statement::Return(ref node) => {
+                let value: Value = match node.value {
+                    Some(ref value) => self.expression(value)?,
+                    None => Value::None,
+                };
+                Err(Unwind::Return(value))
+            }
+            Statement::Throw(ref node) => match self.expression(&node.value)? {
+                Value::Object(ref object) if self.classes().is_exception(object.borrow().class) => {
+                    Err(Unwind::Throw(Thrown {
+                        exception: Rc::clone(object),
+                        trace: self.trace(&node.location),
+                    }))
+                }
+                other => Err(self.error(
+                    "TypeError",
+                    &format!("cannot throw a value of type `{}`", other.type_name()),
+                    node.value.location(),
+                )),
+            },
+            Statement::Try(ref node) => self.try_statement(node),
+            Statement::Use(ref node) => {
+                let name: &Identifier = node
+                    .alias
+                    .as_ref()
+                    .unwrap_or_else(|| node.path.last().expect("paths have at least one name"));
+                match self.module().imports.items.get(name.name.as_str()) {
+                    Some(&Item::Function(function)) => {
+                        self.declare(&name.name, Value::Function(function));
+                        Ok(())
+                    }
+                    // Imported classes are part of the class table of the module
+                    Some(&Item::Class(_)) => Ok(()),
+                    None => Err(self.error(
+                        "RuntimeError",
+                        &format!("`{}` is not loaded", name.name),
+                        &node.location,
+                    )),
+                }
+            }
+            Statement::VariableDeclaration(ref node) => {
+                let value: Value = match node.value {
+                    Some(ref value) => {
+                        let found: Value = self.expression(value)?;
+                        self.adapt(found, node.type_annotation.as_ref(), value.location())?
+                    }
+                    None => node
+                        .type_annotation
+                        .as_ref()
+                        .map_or(Value::None, default_value),
+                };
+                self.declare(&node.name.name, value);
+                Ok(())
+            }
+            // Generators run their `yield` statements as states, see `Interpreter::resume`
+            Statement::Yield(ref node) => Err(self.error(
+                "RuntimeError",
+                "`yield` can only be used in generators",
+                &node.location,
+            )),
+            Statement::While(ref node) => {
+                while self.condition(&node.condition)? {
+                    match self.block(&node.body) {
+                        Ok(()) | Err(Unwind::Continue) => {}
+                        Err(Unwind::Break) => break,
+                        Err(other) => return Err(other),
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Executes a `for` loop, see [`Interpreter::start`] for the values it iterates over.
+    fn for_statement(&mut self, node: &'ast For) -> Flow<'ast> {
+        let mut iteration: Iteration = self.start(node)?;
+        while let Some(value) = self.advance(node, &mut iteration)? {
+            if !self.iteration(node, value)? {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the condition of an assertion or contract, throwing an `AssertionError` if it is false. The message
+    /// shows the condition and the values of its operands, e.g. ``assertion `x + 1 == y` failed (x + 1 = 3, y = 4)``.
+    ///
+    /// # Parameters
+    ///
+    /// - `condition`: The checked condition.
+    /// - `message`: The message of an `assert` statement, only evaluated if the condition is false.
+    /// - `location`: The location of the assertion or contract, shown in the stack trace.
+    /// - `describe`: Describes the failed check, given the condition as source code.
+    ///
+    /// # Errors
+    ///
+    /// Raises a `TypeError` if the condition is not a `bool`.
+    fn check(
+        &mut self,
+        condition: &'ast Expression,
+        message: Option<&'ast Expression>,
+        location: &Location,
+        describe: impl FnOnce(&str) -> String,
+    ) -> Flow<'ast> {
+        let mut report: Vec<(&Expression, Value)> = vec![];
+        if self.observe(condition, &mut report)? {
+            return Ok(());
+        }
+
+        let mut text: String = describe(&print::expression(condition));
+        if let Some(message) = message {
+            let value: Value = self.expression(message)?;
+            text = format!("{}: {text}", self.stringify(&value));
+        }
+        if !report.is_empty() {
+            let values: Vec<String> = report
+                .iter()
+                .map(|&(operand, ref value)| {
+                    format!("{} = {}", print::expression(operand), value.repr())
+                })
+                .collect();
+            text = format!("{text} ({})", values.join(", "));
+        }
+        Err(self.error("AssertionError", &text, location))
+    }
+
+    /// Evaluates a checked condition like [`Interpreter::condition`], recording the values of the operands of its
+    /// comparisons and other operators. Conditions combined with `&&`, `||` and `!` are followed, literals are not
+    /// recorded.
+    fn observe(
+        &mut self,
+        condition: &'ast Expression,
+        report: &mut Vec<(&'ast Expression, Value<'ast>)>,
+    ) -> Flow<'ast, bool> {
+        let value: Value = match *condition {
+            Expression::Binary(ref node) if matches!(node.operator, Mark::And | Mark::Or) => {
+                let left: bool = self.observe(&node.left, report)?;
+                if left == (node.operator == Mark::Or) {
+                    return Ok(left);
+                }
+                return self.observe(&node.right, report);
+            }
+            Expression::Unary(ref node) if node.operator == Mark::Bang => {
+                return self.observe(&node.operand, report).map(|value| !value);
+            }
+            Expression::Binary(ref node) if node.operator != Mark::Coalesce => {
+                let left: Value = self.expression(&node.left)?;
+                let right: Value = self.expression(&node.right)?;
+                for (operand, value) in [(&*node.left, &left), (&*node.right, &right)] {
+                    if !matches!(*operand, Expression::Literal(_)) {
+                        report.push((operand, value.clone()));
+                    }
+                }
+                self.operation(&node.operator, &left, &right, &node.location)?
+            }
+            _ => self.expression(condition)?,
+        };
+
+        match value {
+            Value::Bool(value) => Ok(value),
+            other => Err(self.error(
+                "TypeError",
+                &format!(
+                    "expected a condition of type `bool`, found `{}`",
+                    other.type_name()
+                ),
+                condition.location(),
+            )),
+        }
+    }
+
+    /// Checks the `requires` or `ensures` clauses of the running function, see [`Interpreter::check`].
+    fn contracts(
+        &mut self,
+        function: &'ast Function,
+        clause: &str,
+        conditions: &'ast [Expression],
+    ) -> Flow<'ast> {
+        let name: String =
+            function_name(function, self.frames.last().and_then(|frame| frame.class));
+        for condition in conditions {
+            self.check(condition, None, condition.location(), |source| {
+                format!("`{clause} {source}` of `{name}` failed")
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Evaluates the values a `for` loop iterates over. Ranges count with an `int` without creating the values up
+    /// front, lists are read by position so elements appended in the body are visited too, objects are iterated
+    /// with the `Iterable` and `Iterator` interfaces of the prelude and generators produce their values lazily.
+    ///
+    /// # Errors
+    ///
+    /// Raises a `TypeError` if the value cannot be iterated or a bound or the step of a range is not an `int`, and a
+    /// `ValueError` if the step of a range is zero.
+    fn start(&mut self, node: &'ast For) -> Flow<'ast, Iteration<'ast>> {
+        let iterable: &Expression = match node.iterable {
+            ForIterable::Range(ref range) => {
+                let start: i64 = self.integer(&range.start)?;
+                let end: i64 = self.integer(&range.end)?;
+                let step: i64 = match range.step {
+                    Some(ref step) => self.integer(step)?,
+                    None => 1,
+                };
+                if step == 0 {
+                    let location: &Location = range
+                        .step
+                        .as_ref()
+                        .map_or(&range.location, Expression::location);
+                    return Err(self.error(
+                        "ValueError",
+                        "the step of a range must not be zero",
+                        location,
+                    ));
+                }
+
+                return Ok(Iteration::Range {
+                    next: Some(start),
+                    end,
+                    step,
+                    inclusive: range.inclusive,
+                });
+            }
+            ForIterable::Value(ref iterable) => iterable,
+        };
+
+        match self.expression(iterable)? {
+            Value::List(list) => Ok(Iteration::List { list, position: 0 }),
+            Value::Tuple(elements) => Ok(Iteration::Values(
+                elements.iter().cloned().collect::<Vec<Value>>().into_iter(),
+            )),
+            Value::Str(string) => Ok(Iteration::Values(
+                string
+                    .chars()
+                    .map(|character| Value::Str(character.to_string().into()))
+                    .collect::<Vec<Value>>()
+                    .into_iter(),
+            )),
+            // The keys are copied so the body can change the dict
+            Value::Dict(dict) => Ok(Iteration::Values(
+                dict.borrow()
+                    .entries()
+                    .iter()
+                    .map(|&(ref key, _)| key.value())
+                    .collect::<Vec<Value>>()
+                    .into_iter(),
+            )),
+            Value::Object(object) => {
+                let has_iterator: bool = object.borrow().vtable.contains_key("iterator");
+                if !has_iterator {
+                    return Ok(Iteration::Iterator(object));
+                }
+                match self.protocol_method(&object, "iterator", iterable.location())? {
+                    Value::Object(iterator) => Ok(Iteration::Iterator(iterator)),
+                    Value::Generator(generator) => Ok(Iteration::Generator(generator)),
+                    other => Err(self.error(
+                        "TypeError",
+                        &format!(
+                            "`iterator` returned `{}` instead of an object",
+                            other.type_name()
+                        ),
+                        iterable.location(),
+                    )),
+                }
+            }
+            Value::Generator(generator) => Ok(Iteration::Generator(generator)),
+            other => Err(self.error(
+                "TypeError",
+                &format!("`{}` cannot be iterated", other.type_name()),
+                iterable.location(),
+            )),
+        }
+    }
+
+    /// Returns the next value of a `for` loop, or `None` after the last value.
+    fn advance(
+        &mut self,
+        node: &'ast For,
+        iteration: &mut Iteration<'ast>,
+    ) -> Flow<'ast, Option<Value<'ast>>> {
+        let location: &Location = match node.iterable {
+            ForIterable::Range(ref range) => &range.location,
+            ForIterable::Value(ref iterable) => iterable.location(),
+        };
+
+        match *iteration {
+            Iteration::Range {
+                ref mut next,
+                end,
+                step,
+                inclusive,
+            } => {
+                let Some(current) = *next else {
+                    return Ok(None);
+                };
+                let inside: bool = match (step > 0, inclusive) {
+                    (true, false) => current < end,
+                    (true, true) => current <= end,
+                    (false, false) => current > end,
+                    (false, true) => current >= end,
+                };
+                if !inside {
+                    return Ok(None);
+                }
+                // A range ending near the limits of `int` stops instead of overflowing
+                *next = current.checked_add(step);
+                Ok(Some(Value::Int(current)))
+            }
+            Iteration::List {
+                ref list,
+                ref mut position,
+            } => {
+                let element: Option<Value> = list.borrow().get(*position).cloned();
+                *position += 1;
+                Ok(element)
+            }
+            Iteration::Values(ref mut values) => Ok(values.next()),
+            Iteration::Iterator(ref iterator) => {
+                let iterator: Rc<RefCell<Object>> = Rc::clone(iterator);
+                match self.protocol_method(&iterator, "has_next", location)? {
+                    Value::Bool(true) => {}
+                    Value::Bool(false) => return Ok(None),
+                    other => {
+                        return Err(self.error(
+                            "TypeError",
+                            &format!(
+                                "`has_next` returned `{}` instead of a `bool`",
+                                other.type_name()
+                            ),
+                            location,
+                        ))
+                    }
+                }
+                self.protocol_method(&iterator, "next", location).map(Some)
+            }
+            Iteration::Generator(ref generator) => {
+                let generator: Rc<RefCell<Generator>> = Rc::clone(generator);
+                self.resume(&generator, location)
+            }
+        }
+    }
+
+    /// Evaluates a bound or the step of a range, which has to be an integer in the range of `int`.
+    fn integer(&mut self, expression: &'ast Expression) -> Flow<'ast, i64> {
+        let value: Value = self.expression(expression)?;
+        match value.integer() {
+            Some(found) => i64::try_from(found).ok().ok_or_else(|| {
+                self.error(
+                    "OverflowError",
+                    &format!("the bound {found} of a range does not fit into `int`"),
+                    expression.location(),
+                )
+            }),
+            None => Err(self.error(
+                "TypeError",
+                &format!(
+                    "ranges count with `int` values, found `{}`",
+                    value.type_name()
+                ),
+                expression.location(),
+            )),
+        }
+    }
+
+    /// Runs the body of a `for` loop once with the loop variable bound to a value.
+    ///
+    /// Returns whether the loop continues.
+    fn iteration(&mut self, node: &'ast For, value: Value<'ast>) -> Flow<'ast, bool> {
+        self.frame().scopes.push(HashMap::new());
+        self.declare(&node.variable.name, value);
+        let result: Flow = self.block(&node.body);
+        self.exit();
+
+        match result {
+            Ok(()) | Err(Unwind::Continue) => Ok(true),
+            Err(Unwind::Break) => Ok(false),
+            Err(other) => Err(other),
+        }
+    }
+
+    /// Calls a method of the iteration protocol, which takes no arguments, on an object.
+    ///
+    /// # Errors
+    ///
+    /// Raises a `TypeError` if the class of the object has no such method.
+    fn protocol_method(
+        &mut self,
+        object: &Rc<RefCell<Object<'ast>>>,
+        name: &str,
+        location: &'ast Location,
+    ) -> Flow<'ast, Value<'ast>> {
+        let method: Option<(&Class, &Function)> = object.borrow().vtable.get(name).copied();
+        match method {
+            Some((class, function)) => self.invoke(
+                function,
+                Some(Rc::clone(object)),
+                Some(class),
+                vec![],
+                location,
+            ),
+            None => Err(self.error(
+                "TypeError",
+                &format!("`{}` cannot be iterated", object.borrow().class.name.name),
+                location,
+            )),
+        }
+    }
+
+    /// Executes a `try` statement. The `finally` block runs however the body and the `catch` clause are left.
+    /// If the `finally` block is left early itself, e.g. with `return`, that replaces the previous result.
+    fn try_statement(&mut self, node: &'ast Try) -> Flow<'ast> {
+        let mut result: Flow = self.block(&node.body);
+
+        let thrown: Option<Rc<RefCell<Object>>> = match result {
+            Err(Unwind::Throw(ref thrown)) => Some(Rc::clone(&thrown.exception)),
+            _ => None,
+        };
+        if let Some(exception) = thrown {
+            let class: &Class = exception.borrow().class;
+            let catch = node.catches.iter().find(|catch| {
+                self.classes()
+                    .get(&catch.exception.name)
+                    .map_or(false, |caught| self.classes().is_subclass(class, caught))
+            });
+
+            if let Some(catch) = catch {
+                self.frame().scopes.push(HashMap::new());
+                if let Some(ref binding) = catch.binding {
+                    self.declare(&binding.name, Value::Object(exception));
+                }
+                result = self.block(&catch.body);
+                self.exit();
+            }
+        }
+
+        match node.finally {
+            Some(ref finally) => self.block(finally).and(result),
+            None => result,
+        }
+    }
+
+    /// Executes the first matching case of a match.
+    ///
+    /// Returns the value of the case for match expressions, `none` for cases with a block and `None` if no case matched.
+    fn match_value(&mut self, node: &'ast Match) -> Flow<'ast, Option<Value<'ast>>> {
+        let subject: Value = self.expression(&node.subject)?;
+
+        for arm in &node.arms {
+            let mut bindings: Vec<(&str, Value)> = vec![];
+            if let Some(ref pattern) = arm.pattern {
+                if !self.matches(pattern, &subject, &mut bindings) {
+                    continue;
+                }
+            }
+
+            self.frame().scopes.push(HashMap::new());
+            for (name, value) in bindings {
+                self.declare(name, value);
+            }
+            let result: Flow<Option<Value>> = self.arm(arm);
+            self.exit();
+
+            if let Some(value) = result? {
+                return Ok(Some(value));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Executes a case whose pattern matched. Returns `None` if the guard is false.
+    fn arm(&mut self, arm: &'ast MatchArm) -> Flow<'ast, Option<Value<'ast>>> {
+        if let Some(ref guard) = arm.guard {
+            if !self.condition(guard)? {
+                return Ok(None);
+            }
+        }
+
+        match arm.body {
+            MatchBody::Block(ref block) => self.block(block).map(|()| Some(Value::None)),
+            MatchBody::Expression(ref value) => self.expression(value).map(Some),
+        }
+    }
+
+    /// Returns whether a value matches a pattern, collecting the bound names.
+    fn matches(
+        &self,
+        pattern: &'ast Pattern,
+        value: &Value<'ast>,
+        bindings: &mut Vec<(&'ast str, Value<'ast>)>,
+    ) -> bool {
+        match *pattern {
+            Pattern::Alternative(ref alternative) => alternative.patterns.iter().any(|found| {
+                let mut inner: Vec<(&str, Value)> = vec![];
+                let matched: bool = self.matches(found, value, &mut inner);
+                if matched {
+                    bindings.extend(inner);
+                }
+                matched
+            }),
+            Pattern::Binding(ref binding) => {
+                let matched: bool = binding.type_annotation.as_ref().map_or(true, |annotation| {
+                    (annotation.nullable && *value == Value::None)
+                        || self.is_instance(value, &annotation.name)
+                });
+                if matched && binding.name.name != "_" {
+                    bindings.push((&binding.name.name, value.clone()));
+                }
+                matched
+            }
+            Pattern::Class(ref class_pattern) => {
+                let Some(class) = self.classes().get(&class_pattern.class.name) else {
+                    return false;
+                };
+                let Value::Object(ref object) = *value else {
+                    return false;
+                };
+                if !self.classes().is_subclass(object.borrow().class, class) {
+                    return false;
+                }
+
+                let fields: Vec<Value> = self
+                    .classes()
+                    .fields(class)
+                    .iter()
+                    .map(|field| {
+                        object
+                            .borrow()
+                            .fields
+                            .get(&field.name.name)
+                            .cloned()
+                            .unwrap_or(Value::None)
+                    })
+                    .collect();
+                fields.len() == class_pattern.fields.len()
+                    && class_pattern
+                        .fields
+                        .iter()
+                        .zip(&fields)
+                        .all(|(found, field)| self.matches(found, field, bindings))
+            }
+            Pattern::Literal(ref literal) => self
+                .literal(literal)
+                .map_or(false, |expected| expected == *value),
+            Pattern::Range(ref range) => {
+                // Constants are folded into literals, a remaining name is looked up like a variable
+                let bound = |bound: &RangeBound| match *bound {
+                    RangeBound::Literal(ref literal) => self.literal(literal).ok()?.integer(),
+                    RangeBound::Constant(ref name) => self.lookup(&name.name)?.integer(),
+                };
+                match (bound(&range.start), bound(&range.end), value.integer()) {
+                    (Some(start), Some(end), Some(found)) => start <= found && found < end,
+                    _ => false,
+                }
+            }
+        }
+    }
+
+    /// Returns whether a value has a type: a built-in type or a class (including subclasses).
+    fn is_instance(&self, value: &Value, type_name: &str) -> bool {
+        match (type_name, value) {
+            ("int", &Value::Int(_))
+            | ("bigint", &Value::BigInt(_))
+            | ("str", &Value::Str(_))
+            | ("bool", &Value::Bool(_))
+            | ("none", &Value::None)
+            | ("list", &Value::List(_))
+            | ("dict", &Value::Dict(_))
+            | ("tuple", &Value::Tuple(_))
+            | (prelude::ITERATOR, &Value::Generator(_)) => true,
+            (_, &Value::Sized(_, width)) => width.name() == type_name,
+            (_, &Value::Object(ref object)) => {
+                self.classes().get(type_name).map_or(false, |class| {
+                    self.classes().is_subclass(object.borrow().class, class)
+                })
+            }
+            _ => false,
+        }
+    }
+
+    // Expressions
+
+    /// Evaluates a condition, which has to be a `bool`.
+    fn condition(&mut self, expression: &'ast Expression) -> Flow<'ast, bool> {
+        match self.expression(expression)? {
+            Value::Bool(value) => Ok(value),
+            other => Err(self.error(
+                "TypeError",
+                &format!(
+                    "expected a condition of type `bool`, found `{}`",
+                    other.type_name()
+                ),
+                expression.location(),
+            )),
+        }
+    }
+
+    /// Evaluates an expression.
+    fn expression(&mut self, expression: &'ast Expression) -> Flow<'ast, Value<'ast>> {
+        match *expression {
+            Expression::Assignment(ref node) => self.assignment(node),
+            Expression::Binary(ref node) => self.binary(node),
+            Expression::Call(ref node) => {
+                stacker::maybe_grow(RED_ZONE, STACK_SEGMENT, || self.call(node))
+            }
+            Expression::Identifier(ref node) => {
+                if node.name == "this" {
+                    if let Some(ref this) = self.frame().this {
+                        return Ok(Value::Object(Rc::clone(this)));
+                    }
+                }
+
+                self.lookup(&node.name).ok_or_else(|| {
+                    self.error(
+                        "RuntimeError",
+                        &format!("`{}` is not defined", node.name),
+                        &node.location,
+                    )
+                })
+            }
+            Expression::Dict(ref node) => {
+                let mut dict: Dict = Dict::default();
+                for entry in &node.entries {
+                    let key: Value = self.expression(&entry.key)?;
+                    let key: Key = self.key(&key, entry.key.location())?;
+                    let value: Value = self.expression(&entry.value)?;
+                    dict.insert(key, value);
+                }
+                Ok(Value::Dict(Rc::new(RefCell::new(dict))))
+            }
+            Expression::FormatString(ref node) => self.format_string(node),
+            Expression::Index(ref node) => self.index(node),
+            Expression::Lambda(ref node) => Ok(self.closure(node)),
+            Expression::List(ref node) => {
+                let elements: Vec<Value> = self.elements(&node.elements)?;
+                Ok(Value::List(Rc::new(RefCell::new(elements))))
+            }
+            Expression::Literal(ref node) => self.literal(node),
+            Expression::Match(ref node) => {
+                if let Some(value) = self.match_value(node)? {
+                    return Ok(value);
+                }
+                let subject: Value = self.expression(&node.subject)?;
+                Err(self.error(
+                    "ValueError",
+                    &format!("no case matches `{}`", self.stringify(&subject)),
+                    &node.location,
+                ))
+            }
+            Expression::Member(ref node) => self.member(node),
+            Expression::Slice(ref node) => self.slice(node),
+            Expression::Tuple(ref node) => Ok(Value::Tuple(self.elements(&node.elements)?.into())),
+            Expression::Unary(ref node) => self.unary(node),
+        }
+    }
+
+    /// Evaluates the elements of a list or tuple literal, in order.
+    fn elements(&mut self, elements: &'ast [Expression]) -> Flow<'ast, Vec<Value<'ast>>> {
+        let mut values: Vec<Value> = Vec::with_capacity(elements.len());
+        for element in elements {
+            values.push(self.expression(element)?);
+        }
+        Ok(values)
+    }
+
+    /// Returns the key of a `dict` for a value. Only `none`, `bool`, `int` and `str` values can be keys.
+    fn key(&self, value: &Value, location: &Location) -> Flow<'ast, Key> {
+        Key::new(value).ok_or_else(|| {
+            self.error(
+                "TypeError",
+                &format!(
+                    "a value of type `{}` cannot be used as a key",
+                    value.type_name()
+                ),
+                location,
+            )
+        })
+    }
+
+    /// Returns the position of an element of a `list`, `tuple` or `str` for an index, which has to be an integer.
+    /// Negative indexes count from the end.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: The index.
+    /// - `length`: The number of elements of the indexed value.
+    /// - `type_name`: The type of the indexed value.
+    /// - `location`: The location of the index.
+    ///
+    /// # Errors
+    ///
+    /// Raises a `TypeError` if the index is not an `int` and an `IndexError` if it is out of range.
+    fn position(
+        &self,
+        index: &Value,
+        length: usize,
+        type_name: &str,
+        location: &Location,
+    ) -> Flow<'ast, usize> {
+        let Some(index) = index.integer() else {
+            return Err(self.error(
+                "TypeError",
+                &format!(
+                    "the index of a `{type_name}` must be an `int`, found `{}`",
+                    index.type_name()
+                ),
+                location,
+            ));
+        };
+        let index: i64 = i64::try_from(index).unwrap_or(i64::MAX);
+
+        let position: i64 = if index < 0 {
+            index.saturating_add(i64::try_from(length).unwrap_or(i64::MAX))
+        } else {
+            index
+        };
+        usize::try_from(position)
+            .ok()
+            .filter(|position| *position < length)
+            .ok_or_else(|| {
+                self.error(
+                    "IndexError",
+                    &format!(
+                        "index {index} is out of range for a `{type_name}` of length {length}"
+                    ),
+                    location,
+                )
+            })
+    }
+
+    /// Evaluates an indexing of a `list`, `dict`, `tuple` or `str`.
+    #[allow(clippy::indexing_slicing)]
+    fn index(&mut self, node: &'ast Index) -> Flow<'ast, Value<'ast>> {
+        let target: Value = self.expression(&node.target)?;
+        let index: Value = self.expression(&node.index)?;
+        let location: &Location = node.index.location();
+
+        match target {
+            Value::List(ref elements) => {
+                let elements: core::cell::Ref<Vec<Value>> = elements.borrow();
+                let position: usize = self.position(&index, elements.len(), "list", location)?;
+                Ok(elements[position].clone())
+            }
+            Value::Tuple(ref elements) => {
+                let position: usize = self.position(&index, elements.len(), "tuple", location)?;
+                Ok(elements[position].clone())
+            }
+            Value::Str(ref text) => {
+                let position: usize =
+                    self.position(&index, text.chars().count(), "str", location)?;
+                Ok(Value::Str(
+                    text.chars()
+                        .nth(position)
+                        .unwrap_or_default()
+                        .to_string()
+                        .into(),
+                ))
+            }
+            Value::Dict(ref dict) => {
+                let key: Key = self.key(&index, location)?;
+                let found: Option<Value> = dict.borrow().get(&key).cloned();
+                found.ok_or_else(|| {
+                    self.error(
+                        "KeyError",
+                        &format!("key {} not found", index.repr()),
+                        location,
+                    )
+                })
+            }
+            ref other => match Self::operator_method(other, operators::INDEX_METHOD) {
+                Some((object, class, method)) => self.invoke(
+                    method,
+                    Some(object),
+                    Some(class),
+                    vec![Some(index)],
+                    &node.location,
+                ),
+                None => Err(self.error(
+                    "TypeError",
+                    &format!("`{}` cannot be indexed", other.type_name()),
+                    &node.location,
+                )),
+            },
+        }
+    }
+
+    /// Evaluates a slice of a `list`, `tuple` or `str`. The end is excluded.
+    #[allow(clippy::indexing_slicing)]
+    fn slice(&mut self, node: &'ast Slice) -> Flow<'ast, Value<'ast>> {
+        let target: Value = self.expression(&node.target)?;
+        let mut bounds: [Option<i64>; 2] = [None, None];
+        for (bound, expression) in bounds.iter_mut().zip([&node.start, &node.end]) {
+            let Some(expression) = expression.as_deref() else {
+                continue;
+            };
+            match self.expression(expression)? {
+                Value::Int(value) => *bound = Some(value),
+                Value::Sized(value, _) => *bound = i64::try_from(value).ok().or(Some(i64::MAX)),
+                other => {
+                    return Err(self.error(
+                        "TypeError",
+                        &format!(
+                            "the bounds of a slice must be `int`, found `{}`",
+                            other.type_name()
+                        ),
+                        expression.location(),
+                    ))
+                }
+            }
+        }
+        let [start, end] = bounds;
+
+        match target {
+            Value::List(ref elements) => {
+                let elements: core::cell::Ref<Vec<Value>> = elements.borrow();
+                let range: core::ops::Range<usize> = slice_range(start, end, elements.len());
+                Ok(Value::List(Rc::new(RefCell::new(elements[range].to_vec()))))
+            }
+            Value::Tuple(ref elements) => {
+                let range: core::ops::Range<usize> = slice_range(start, end, elements.len());
+                Ok(Value::Tuple(elements[range].into()))
+            }
+            Value::Str(ref text) => {
+                let range: core::ops::Range<usize> = slice_range(start, end, text.chars().count());
+                Ok(Value::Str(
+                    text.chars()
+                        .skip(range.start)
+                        .take(range.len())
+                        .collect::<String>()
+                        .into(),
+                ))
+            }
+            ref other => Err(self.error(
+                "TypeError",
+                &format!("`{}` cannot be sliced", other.type_name()),
+                &node.location,
+            )),
+        }
+    }
+
+    /// Evaluates a literal. Integer literals are `int` values, or `bigint` values if they do not fit into an `int`.
+    fn literal(&self, node: &Literal) -> Flow<'ast, Value<'ast>> {
+        Ok(match node.kind {
+            TypeDefinition::Integer => match node.value.replace('_', "").parse() {
+                Ok(value) => Value::Int(value),
+                Err(_) => Value::BigInt(Rc::new(node.value.parse::<BigInt>().map_err(|()| {
+                    self.error(
+                        "ValueError",
+                        &format!("invalid integer literal `{}`", node.value),
+                        &node.location,
+                    )
+                })?)),
+            },
+            TypeDefinition::String | TypeDefinition::FormatString => {
+                Value::Str(unescape(&node.value).into())
+            }
+            TypeDefinition::True => Value::Bool(true),
+            TypeDefinition::False => Value::Bool(false),
+            TypeDefinition::None => Value::None,
+        })
+    }
+
+    /// Evaluates a format string. The values of the fields are converted like `str` does, unless their format
+    /// specification asks for another presentation.
+    fn format_string(&mut self, node: &'ast FormatString) -> Flow<'ast, Value<'ast>> {
+        let mut result: String = String::new();
+        for part in &node.parts {
+            match *part {
+                FormatPart::Text(ref text) => result.push_str(&unescape(text)),
+                FormatPart::Field(ref field) => {
+                    let value: Value = self.expression(&field.value)?;
+                    result.push_str(&self.format(&value, field)?);
+                }
+            }
+        }
+
+        Ok(Value::Str(result.into()))
+    }
+
+    /// Formats the value of a field of a format string with its format specification, see [`Specification`].
+    fn format(&self, value: &Value<'ast>, field: &FormatField) -> Flow<'ast, String> {
+        let Some(ref text) = field.specification else {
+            return Ok(self.stringify(value));
+        };
+        let specification: Specification = Specification::parse(text).map_err(|reason| {
+            self.error(
+                "ValueError",
+                &format!("invalid format specification `{text}`: {reason}"),
+                &field.location,
+            )
+        })?;
+        if let Some(reason) = specification.reject(&value.type_name()) {
+            return Err(self.error(
+                "TypeError",
+                &format!(
+                    "the format specification `{text}` cannot format a `{}`: {reason}",
+                    value.type_name()
+                ),
+                &field.location,
+            ));
+        }
+
+        let written: String = match (specification.presentation.radix(), value) {
+            (Some(radix), &Value::Int(number)) => BigInt::from(number).to_string_radix(radix),
+            (Some(radix), &Value::Sized(number, _)) => BigInt::from(number).to_string_radix(radix),
+            (Some(radix), &Value::BigInt(ref number)) => number.to_string_radix(radix),
+            _ if specification.presentation == Presentation::Debug => value.repr(),
+            _ => self.stringify(value),
+        };
+        let written: String = if specification.presentation == Presentation::UpperHex {
+            written.to_uppercase()
+        } else {
+            written
+        };
+        let integer: bool = matches!(*value, Value::Int(_) | Value::Sized(..) | Value::BigInt(_));
+        Ok(specification.align(&written, integer))
+    }
+
+    /// Reads a field of an object. Reading it with `?.` from `none` results in `none`.
+    fn member(&mut self, node: &'ast Member) -> Flow<'ast, Value<'ast>> {
+        let target: Value = self.expression(&node.target)?;
+        let field: Option<Value> = match target {
+            Value::None if node.safe => Some(Value::None),
+            Value::Object(ref object) => object.borrow().fields.get(&node.member.name).cloned(),
+            Value::Module(module) => match export(module, &node.member.name) {
+                Some(Item::Function(function)) => Some(Value::Function(function)),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        field.ok_or_else(|| {
+            self.error(
+                "TypeError",
+                &format!(
+                    "`{}` has no field `{}`",
+                    target.type_name(),
+                    node.member.name
+                ),
+                &node.member.location,
+            )
+        })
+    }
+
+    /// Assigns a value to a variable, field, element of a `list` or key of a `dict`.
+    #[allow(clippy::indexing_slicing)]
+    fn store(
+        &mut self,
+        target: &'ast Expression,
+        value: Value<'ast>,
+        location: &Location,
+    ) -> Flow<'ast> {
+        match *target {
+            Expression::Identifier(ref identifier) => {
+                let current: Option<Value> = self.lookup(&identifier.name);
+                let value: Value = self.retain(current.as_ref(), value, location)?;
+                if self.assign(&identifier.name, value) {
+                    return Ok(());
+                }
+                Err(self.error(
+                    "RuntimeError",
+                    &format!("`{}` is not defined", identifier.name),
+                    &identifier.location,
+                ))
+            }
+            Expression::Member(ref member) => match self.expression(&member.target)? {
+                Value::Object(object) => {
+                    let current: Option<Value> =
+                        object.borrow().fields.get(&member.member.name).cloned();
+                    let value: Value = self.retain(current.as_ref(), value, location)?;
+                    object
+                        .borrow_mut()
+                        .fields
+                        .insert(member.member.name.clone(), value);
+                    Ok(())
+                }
+                Value::None if member.safe => Ok(()),
+                other => Err(self.error(
+                    "TypeError",
+                    &format!(
+                        "`{}` has no field `{}`",
+                        other.type_name(),
+                        member.member.name
+                    ),
+                    &member.member.location,
+                )),
+            },
+            Expression::Index(ref index) => {
+                let indexed: Value = self.expression(&index.target)?;
+                let position: Value = self.expression(&index.index)?;
+                match indexed {
+                    Value::List(elements) => {
+                        let length: usize = elements.borrow().len();
+                        let position: usize =
+                            self.position(&position, length, "list", index.index.location())?;
+                        let current: Option<Value> = elements.borrow().get(position).cloned();
+                        let value: Value = self.retain(current.as_ref(), value, location)?;
+                        elements.borrow_mut()[position] = value;
+                        Ok(())
+                    }
+                    Value::Dict(dict) => {
+                        let key: Key = self.key(&position, index.index.location())?;
+                        let current: Option<Value> = dict.borrow().get(&key).cloned();
+                        let value: Value = self.retain(current.as_ref(), value, location)?;
+                        dict.borrow_mut().insert(key, value);
+                        Ok(())
+                    }
+                    other => match Self::operator_method(&other, operators::SET_INDEX_METHOD) {
+                        Some((object, class, method)) => {
+                            let arguments: Vec<Option<Value>> = vec![Some(position), Some(value)];
+                            self.invoke(
+                                method,
+                                Some(object),
+                                Some(class),
+                                arguments,
+                                &index.location,
+                            )?;
+                            Ok(())
+                        }
+                        None => Err(self.error(
+                            "TypeError",
+                            &format!(
+                                "the elements of a `{}` cannot be assigned",
+                                other.type_name()
+                            ),
+                            &index.location,
+                        )),
+                    },
+                }
+            }
+            _ => Err(self.error("TypeError", "cannot assign to this expression", location)),
+        }
+    }
+
+    /// Evaluates an assignment. Compound assignments apply their operator to the current value first.
+    fn assignment(&mut self, node: &'ast Assignment) -> Flow<'ast, Value<'ast>> {
+        let mut value: Value = self.expression(&node.value)?;
+        if let Some(operator) = types::compound_operator(&node.operator) {
+            let current: Value = self.expression(&node.target)?;
+            // A class may change its objects in place instead of using the binary operator
+            let in_place = operators::compound_method(&node.operator)
+                .and_then(|method| Self::operator_method(&current, method));
+            value = match in_place {
+                Some((object, class, method)) => {
+                    self.invoke(
+                        method,
+                        Some(object),
+                        Some(class),
+                        vec![Some(value)],
+                        &node.location,
+                    )?;
+                    current
+                }
+                None => self.operation(&operator, &current, &value, &node.location)?,
+            };
+        }
+
+        self.store(&node.target, value.clone(), &node.location)?;
+        Ok(value)
+    }
+
+    /// Evaluates a binary operation. `&&`, `||` and `??` only evaluate their right operand if needed.
+    fn binary(&mut self, node: &'ast Binary) -> Flow<'ast, Value<'ast>> {
+        if node.operator == Mark::Coalesce {
+            return match self.expression(&node.left)? {
+                Value::None => self.expression(&node.right),
+                left => Ok(left),
+            };
+        }
+        if matches!(node.operator, Mark::And | Mark::Or) {
+            let left: bool = self.condition(&node.left)?;
+            if left == (node.operator == Mark::Or) {
+                return Ok(Value::Bool(left));
+            }
+            return self.condition(&node.right).map(Value::Bool);
+        }
+
+        let left: Value = self.expression(&node.left)?;
+        let right: Value = self.expression(&node.right)?;
+        self.operation(&node.operator, &left, &right, &node.location)
+    }
+
+    /// Returns the method of the class of an object named after an operator, see [`operators`], with the object and
+    /// the class declaring the method. Returns `None` if the value is not an object or its class does not define
+    /// the method.
+    fn operator_method(
+        value: &Value<'ast>,
+        method: &str,
+    ) -> Option<(Rc<RefCell<Object<'ast>>>, &'ast Class, &'ast Function)> {
+        let Value::Object(ref object) = *value else {
+            return None;
+        };
+        let (class, function): (&Class, &Function) = object.borrow().vtable.get(method).copied()?;
+        function
+            .body
+            .is_some()
+            .then(|| (Rc::clone(object), class, function))
+    }
+
+    /// Applies a binary operator to two values. Objects use the method of their class named after the operator,
+    /// but are compared by identity if their class does not define `eq` and always with `none`.
+    fn operation(
+        &mut self,
+        operator: &Mark,
+        left: &Value<'ast>,
+        right: &Value<'ast>,
+        location: &Location,
+    ) -> Flow<'ast, Value<'ast>> {
+        let equality: bool = matches!(*operator, Mark::Equal | Mark::NotEqual);
+        let overloaded = operators::binary_method(operator)
+            .filter(|_| !equality || !matches!(*right, Value::None))
+            .and_then(|method| Self::operator_method(left, method));
+        if let Some((object, class, method)) = overloaded {
+            let result: Value = self.invoke(
+                method,
+                Some(object),
+                Some(class),
+                vec![Some(right.clone())],
+                location,
+            )?;
+            return Ok(match (operator, result) {
+                (&Mark::NotEqual, Value::Bool(equal)) => Value::Bool(!equal),
+                (_, result) => result,
+            });
+        }
+
+        // Integer arithmetic is checked and raises an `OverflowError` instead of wrapping around
+        if let Some(result) = arithmetic::binary(operator, left, right) {
+            return result.map_err(|failure| self.failure(&failure, location));
+        }
+
+        let result: Option<Value> = match (operator, left, right) {
+            (&Mark::Equal, _, _) => Some(Value::Bool(left == right)),
+            (&Mark::NotEqual, _, _) => Some(Value::Bool(left != right)),
+            (_, &Value::Str(ref left), &Value::Str(ref right)) => match *operator {
+                Mark::Add => Some(Value::Str(format!("{left}{right}").into())),
+                Mark::Less => Some(Value::Bool(left < right)),
+                Mark::LessEqual => Some(Value::Bool(left <= right)),
+                Mark::Greater => Some(Value::Bool(left > right)),
+                Mark::GreaterEqual => Some(Value::Bool(left >= right)),
+                _ => None,
+            },
+            (_, &Value::Bool(left), &Value::Bool(right)) => match *operator {
+                Mark::BitAnd => Some(Value::Bool(left & right)),
+                Mark::BitOr => Some(Value::Bool(left | right)),
+                Mark::BitXor => Some(Value::Bool(left ^ right)),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        result.ok_or_else(|| {
+            let message: String = match (left, operators::binary_method(operator)) {
+                (&Value::Object(_), Some(method)) => format!(
+                    "`{operator}` is not defined for `{}`, which has no method `{method}`",
+                    left.type_name()
+                ),
+                _ => format!(
+                    "unsupported operand types for `{operator}`: `{}` and `{}`",
+                    left.type_name(),
+                    right.type_name()
+                ),
+            };
+            self.error("TypeError", &message, location)
+        })
+    }
+
+    /// Evaluates a unary operation. `++` and `--` store the new value in their operand.
+    fn unary(&mut self, node: &'ast Unary) -> Flow<'ast, Value<'ast>> {
+        let operand: Value = self.expression(&node.operand)?;
+        let overloaded = operators::unary_method(&node.operator)
+            .and_then(|method| Self::operator_method(&operand, method));
+        if let Some((object, class, method)) = overloaded {
+            return self.invoke(method, Some(object), Some(class), vec![], &node.location);
+        }
+
+        let result: Option<Value> = match (&node.operator, &operand) {
+            (&Mark::Bang, &Value::Bool(value)) => Some(Value::Bool(!value)),
+            (operator, _) => arithmetic::unary(operator, &operand)
+                .transpose()
+                .map_err(|failure| self.failure(&failure, &node.location))?,
+        };
+        let Some(result) = result else {
+            return Err(self.error(
+                "TypeError",
+                &format!(
+                    "unsupported operand type for `{}`: `{}`",
+                    node.operator,
+                    operand.type_name()
+                ),
+                &node.location,
+            ));
+        };
+
+        if matches!(node.operator, Mark::Increase | Mark::Decrease) {
+            self.store(&node.operand, result.clone(), &node.location)?;
+            if node.postfix {
+                return Ok(operand);
+            }
+        }
+
+        Ok(result)
+    }
+
+    // Calls
+
+    /// Evaluates a call of a function, method, constructor or built-in function.
+    fn call(&mut self, node: &'ast Call) -> Flow<'ast, Value<'ast>> {
+        match *node.callee {
+            Expression::Identifier(ref identifier) => {
+                if identifier.name == "super" {
+                    return self.super_constructor(node);
+                }
+
+                if let Some(callee) = self.lookup(&identifier.name) {
+                    return self.call_value(callee, &identifier.location, node);
+                }
+
+                if let Some(class) = self.classes().get(&identifier.name) {
+                    return self.instantiate(class, node);
+                }
+
+                self.built_in(&identifier.name, node)
+            }
+            Expression::Member(ref member) => {
+                // A variant of an enum, e.g. `Shape.Circle(1)`, unless a variable shadows the enum
+                let variant: Option<&Class> = match *member.target {
+                    Expression::Identifier(ref target) if self.lookup(&target.name).is_none() => {
+                        self.classes().variant(member)
+                    }
+                    _ => None,
+                };
+                match variant {
+                    Some(variant) => self.instantiate(variant, node),
+                    None => self.call_method(member, node),
+                }
+            }
+            ref callee => {
+                let value: Value = self.expression(callee)?;
+                self.call_value(value, callee.location(), node)
+            }
+        }
+    }
+
+    /// Calls a function or closure stored in a variable, field or any other value.
+    fn call_value(
+        &mut self,
+        callee: Value<'ast>,
+        location: &Location,
+        node: &'ast Call,
+    ) -> Flow<'ast, Value<'ast>> {
+        match callee {
+            Value::Function(function) => self.call_function(function, None, None, node),
+            Value::Closure(closure) => {
+                let arguments: Vec<Option<Value>> =
+                    self.arguments("<lambda>", &closure.lambda.parameters, node)?;
+                self.run_closure(&closure, arguments, call_location(node))
+            }
+            other => Err(self.error(
+                "TypeError",
+                &format!("`{}` is not callable", other.type_name()),
+                location,
+            )),
+        }
+    }
+
+    /// Calls a function or closure with arguments that are already evaluated, e.g. a callback of a built-in method.
+    fn apply(
+        &mut self,
+        callee: &Value<'ast>,
+        arguments: Vec<Value<'ast>>,
+        location: &Location,
+    ) -> Flow<'ast, Value<'ast>> {
+        let parameters: &[Parameter] = match *callee {
+            Value::Function(function) => &function.parameters,
+            Value::Closure(ref closure) => &closure.lambda.parameters,
+            ref other => {
+                return Err(self.error(
+                    "TypeError",
+                    &format!("`{}` is not callable", other.type_name()),
+                    location,
+                ))
+            }
+        };
+        let required: usize = parameters
+            .iter()
+            .filter(|parameter| parameter.default.is_none())
+            .count();
+        if arguments.len() < required || arguments.len() > parameters.len() {
+            return Err(self.error(
+                "TypeError",
+                &format!(
+                    "the callback takes {} arguments, {} were given",
+                    parameters.len(),
+                    arguments.len()
+                ),
+                location,
+            ));
+        }
+
+        let mut values: Vec<Option<Value>> = arguments.into_iter().map(Some).collect();
+        values.resize(parameters.len(), None);
+        match *callee {
+            Value::Function(function) => self.invoke(function, None, None, values, location),
+            Value::Closure(ref closure) => self.run_closure(closure, values, location),
+            ref other => Err(self.error(
+                "TypeError",
+                &format!("`{}` is not callable", other.type_name()),
+                location,
+            )),
+        }
+    }
+
+    /// Calls a method of an object. Methods are looked up in the [`VTable`] of the class of the object, so a method
+    /// called through a base class or an interface runs the implementation of the class of the object.
+    /// Methods called on `super` are looked up starting at the parent of the class declaring the running method.
+    /// Calling a method with `?.` on `none` results in `none` without evaluating the arguments.
+    fn call_method(&mut self, member: &'ast Member, node: &'ast Call) -> Flow<'ast, Value<'ast>> {
+        let is_super: bool =
+            matches!(*member.target, Expression::Identifier(ref target) if target.name == "super");
+
+        let (object, method): (Rc<RefCell<Object>>, Option<(&Class, &Function)>) = if is_super {
+            let frame: &mut Frame = self.frame();
+            let class: Option<&Class> = frame.class;
+            match frame.this.clone() {
+                Some(this) => (
+                    this,
+                    class
+                        .and_then(|class| self.classes().parent(class))
+                        .and_then(|parent| self.classes().find_method(parent, &member.member.name)),
+                ),
+                None => {
+                    return Err(self.error(
+                        "RuntimeError",
+                        "`super` can only be used in methods",
+                        &member.location,
+                    ))
+                }
+            }
+        } else {
+            match self.expression(&member.target)? {
+                Value::Object(object) => {
+                    let method: Option<(&Class, &Function)> = object
+                        .borrow()
+                        .vtable
+                        .get(member.member.name.as_str())
+                        .copied();
+                    (object, method)
+                }
+                Value::None if member.safe => return Ok(Value::None),
+                Value::Module(module) => {
+                    return match export(module, &member.member.name) {
+                        Some(Item::Function(function)) => {
+                            self.call_function(function, None, None, node)
+                        }
+                        Some(Item::Class(class)) => self.instantiate(class, node),
+                        None => Err(self.error(
+                            "TypeError",
+                            &format!(
+                                "module `{}` has no public item `{}`",
+                                module.name, member.member.name
+                            ),
+                            &member.member.location,
+                        )),
+                    };
+                }
+                collection
+                @ (Value::List(_) | Value::Dict(_) | Value::Tuple(_) | Value::Str(_)) => {
+                    return self.collection_method(&collection, member, node);
+                }
+                Value::Generator(generator) => {
+                    return self.generator_method(&generator, member, node);
+                }
+                other => {
+                    return Err(self.error(
+                        "TypeError",
+                        &format!(
+                            "`{}` has no method `{}`",
+                            other.type_name(),
+                            member.member.name
+                        ),
+                        &member.member.location,
+                    ))
+                }
+            }
+        };
+
+        if let Some((declaring, function)) = method.filter(|&(_, function)| function.body.is_some())
+        {
+            return self.call_function(function, Some(object), Some(declaring), node);
+        }
+
+        // A field holding a function is called like a method, but without `this`
+        let field: Option<Value> = object.borrow().fields.get(&member.member.name).cloned();
+        if let Some(callee @ (Value::Function(_) | Value::Closure(_))) = field {
+            return self.call_value(callee, &member.member.location, node);
+        }
+
+        let type_name: String = Value::Object(object).type_name();
+        Err(self.error(
+            "TypeError",
+            &format!("`{type_name}` has no method `{}`", member.member.name),
+            &member.member.location,
+        ))
+    }
+
+    /// Calls a method of a `list`, `dict`, `tuple` or `str`, see [`collections::methods`].
+    #[allow(clippy::string_slice, clippy::too_many_lines)]
+    fn collection_method(
+        &mut self,
+        target: &Value<'ast>,
+        member: &'ast Member,
+        node: &'ast Call,
+    ) -> Flow<'ast, Value<'ast>> {
+        let mut arguments: Vec<Value> = vec![];
+        for argument in &node.arguments {
+            arguments.push(self.expression(&argument.value)?);
+        }
+        let name: &str = &member.member.name;
+        let location: &Location = &node.location;
+        let length = |length: usize| Value::Int(i64::try_from(length).unwrap_or(i64::MAX));
+        let string = |text: &str| Value::Str(text.into());
+        let strings = |parts: Vec<&str>| {
+            Value::List(Rc::new(RefCell::new(
+                parts.into_iter().map(string).collect(),
+            )))
+        };
+
+        let result: Option<Value> = match (target, name, arguments.as_slice()) {
+            (&Value::List(ref elements), "append", &[ref value]) => {
+                elements.borrow_mut().push(value.clone());
+                Some(Value::None)
+            }
+            (&Value::List(ref elements), "insert", &[ref index, ref value]) => {
+                let count: usize = elements.borrow().len();
+                // Inserting at the length appends the value
+                let position: usize = if *index == length(count) {
+                    count
+                } else {
+                    self.position(index, count, "list", location)?
+                };
+                elements.borrow_mut().insert(position, value.clone());
+                Some(Value::None)
+            }
+            (&Value::List(ref elements), "pop", &[]) => {
+                let popped: Option<Value> = elements.borrow_mut().pop();
+                Some(popped.ok_or_else(|| {
+                    self.error("IndexError", "pop from an empty `list`", location)
+                })?)
+            }
+            (&Value::List(ref elements), "contains", &[ref value]) => {
+                Some(Value::Bool(elements.borrow().contains(value)))
+            }
+            (&Value::List(ref elements), "len", &[]) => Some(length(elements.borrow().len())),
+            (&Value::List(ref elements), "sort", &[ref compare]) => {
+                // The comparator may change the list, so a copy is sorted and the list is not borrowed meanwhile
+                let copy: Vec<Value> = elements.borrow().clone();
+                let sorted: Vec<Value> = self.sort(copy, compare, location)?;
+                *elements.borrow_mut() = sorted;
+                Some(Value::None)
+            }
+            (&Value::Dict(ref dict), "contains", &[ref key]) => Some(Value::Bool(
+                Key::new(key).map_or(false, |key| dict.borrow().get(&key).is_some()),
+            )),
+            (&Value::Dict(ref dict), "remove", &[ref key]) => {
+                let removed: Option<Value> =
+                    Key::new(key).and_then(|found| dict.borrow_mut().remove(&found));
+                Some(removed.ok_or_else(|| {
+                    self.error(
+                        "KeyError",
+                        &format!("key {} not found", key.repr()),
+                        location,
+                    )
+                })?)
+            }
+            (&Value::Dict(ref dict), "keys", &[]) => {
+                let keys: Vec<Value> = dict
+                    .borrow()
+                    .entries()
+                    .iter()
+                    .map(|&(ref key, _)| key.value())
+                    .collect();
+                Some(Value::List(Rc::new(RefCell::new(keys))))
+            }
+            (&Value::Dict(ref dict), "values", &[]) => {
+                let values: Vec<Value> = dict
+                    .borrow()
+                    .entries()
+                    .iter()
+                    .map(|&(_, ref value)| value.clone())
+                    .collect();
+                Some(Value::List(Rc::new(RefCell::new(values))))
+            }
+            (&Value::Dict(ref dict), "len", &[]) => Some(length(dict.borrow().len())),
+            (&Value::Tuple(ref elements), "len", &[]) => Some(length(elements.len())),
+            (&Value::Str(ref text), "len", &[]) => Some(length(text.chars().count())),
+            (&Value::Str(ref text), "upper", &[]) => Some(string(&text.to_uppercase())),
+            (&Value::Str(ref text), "lower", &[]) => Some(string(&text.to_lowercase())),
+            (&Value::Str(ref text), "strip", &[]) => Some(string(text.trim())),
+            (&Value::Str(ref text), "split", &[Value::Str(ref separator)]) => {
+                if separator.is_empty() {
+                    return Err(self.error(
+                        "ValueError",
+                        "cannot split at an empty separator, use `chars` instead",
+                        location,
+                    ));
+                }
+                Some(strings(text.split(&**separator).collect()))
+            }
+            (&Value::Str(ref text), "join", &[Value::List(ref parts)]) => {
+                let mut joined: Vec<Rc<str>> = vec![];
+                for part in parts.borrow().iter() {
+                    let Value::Str(ref part) = *part else {
+                        return Err(self.error(
+                            "TypeError",
+                            &format!("`join` expected `str` parts, found `{}`", part.type_name()),
+                            location,
+                        ));
+                    };
+                    joined.push(Rc::clone(part));
+                }
+                Some(string(&joined.join(&**text)))
+            }
+            (&Value::Str(ref text), "replace", &[Value::Str(ref old), Value::Str(ref new)]) => {
+                Some(string(&text.replace(&**old, new)))
+            }
+            // Positions count characters, not the bytes before the match
+            (&Value::Str(ref text), "find", &[Value::Str(ref part)]) => Some(
+                text.find(&**part)
+                    .map_or(Value::None, |index| length(text[..index].chars().count())),
+            ),
+            (&Value::Str(ref text), "contains", &[Value::Str(ref part)]) => {
+                Some(Value::Bool(text.contains(&**part)))
+            }
+            (&Value::Str(ref text), "starts_with", &[Value::Str(ref prefix)]) => {
+                Some(Value::Bool(text.starts_with(&**prefix)))
+            }
+            (&Value::Str(ref text), "ends_with", &[Value::Str(ref suffix)]) => {
+                Some(Value::Bool(text.ends_with(&**suffix)))
+            }
+            (&Value::Str(ref text), "chars", &[]) => Some(Value::List(Rc::new(RefCell::new(
+                text.chars()
+                    .map(|character| string(&character.to_string()))
+                    .collect(),
+            )))),
+            (&Value::Str(ref text), "graphemes", &[]) => {
+                Some(strings(text.graphemes(true).collect()))
+            }
+            _ => None,
+        };
+
+        result.ok_or_else(|| {
+            let type_name: String = target.type_name();
+            let message: String = if collections::method(&type_name, name).is_some() {
+                format!("wrong number of arguments for `{name}`")
+            } else {
+                format!("`{type_name}` has no method `{name}`")
+            };
+            self.error("TypeError", &message, &member.member.location)
+        })
+    }
+
+    /// Sorts the elements of a list with a comparator returning a negative `int` if its first argument comes first,
+    /// a positive one if its second argument comes first and zero if their order is kept. The sort is a stable
+    /// merge sort, so inconsistent comparators only lead to an unexpected order.
+    #[allow(clippy::indexing_slicing)]
+    fn sort(
+        &mut self,
+        mut elements: Vec<Value<'ast>>,
+        compare: &Value<'ast>,
+        location: &Location,
+    ) -> Flow<'ast, Vec<Value<'ast>>> {
+        let mut width: usize = 1;
+        while width < elements.len() {
+            let mut merged: Vec<Value> = Vec::with_capacity(elements.len());
+            for chunk in elements.chunks(width * 2) {
+                let (mut left, mut right) = (0, width.min(chunk.len()));
+                while left < width.min(chunk.len()) && right < chunk.len() {
+                    let arguments: Vec<Value> = vec![chunk[right].clone(), chunk[left].clone()];
+                    match self.apply(compare, arguments, location)? {
+                        Value::Int(order) if order < 0 => {
+                            merged.push(chunk[right].clone());
+                            right += 1;
+                        }
+                        Value::Int(_) => {
+                            merged.push(chunk[left].clone());
+                            left += 1;
+                        }
+                        other => {
+                            return Err(self.error(
+                                "TypeError",
+                                &format!(
+                                    "the comparator of `sort` must return an `int`, found `{}`",
+                                    other.type_name()
+                                ),
+                                location,
+                            ))
+                        }
+                    }
+                }
+                merged.extend_from_slice(&chunk[left..width.min(chunk.len())]);
+                merged.extend_from_slice(&chunk[right..]);
+            }
+            elements = merged;
+            width *= 2;
+        }
+        Ok(elements)
+    }
+
+    /// Evaluates the arguments of a call, in the order they are given.
+    /// Returns the values in the order of the parameters, `None` for parameters using their default value.
+    fn arguments(
+        &mut self,
+        name: &str,
+        parameters: &[Parameter],
+        node: &'ast Call,
+    ) -> Flow<'ast, Vec<Option<Value<'ast>>>> {
+        let mut values: Vec<Option<Value>> = vec![None; parameters.len()];
+        let mut position: usize = 0;
+
+        for argument in &node.arguments {
+            let value: Value = self.expression(&argument.value)?;
+            let index: Option<usize> = if let Some(ref keyword) = argument.name {
+                parameters
+                    .iter()
+                    .position(|parameter| parameter.name.name == keyword.name)
+            } else {
+                position += 1;
+                Some(position - 1).filter(|index| *index < parameters.len())
+            };
+
+            match index.and_then(|index| values.get_mut(index)) {
+                Some(slot) => *slot = Some(value),
+                None => {
+                    return Err(self.error(
+                        "TypeError",
+                        &format!("invalid argument for `{name}`"),
+                        &argument.location,
+                    ))
+                }
+            }
+        }
+
+        for (parameter, value) in parameters.iter().zip(&values) {
+            if value.is_none() && parameter.default.is_none() {
+                return Err(self.error(
+                    "TypeError",
+                    &format!(
+                        "missing argument for parameter `{}` of `{name}`",
+                        parameter.name.name
+                    ),
+                    &node.location,
+                ));
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// Calls a function, method or constructor.
+    fn call_function(
+        &mut self,
+        function: &'ast Function,
+        this: Option<Rc<RefCell<Object<'ast>>>>,
+        class: Option<&'ast Class>,
+        node: &'ast Call,
+    ) -> Flow<'ast, Value<'ast>> {
+        let arguments: Vec<Option<Value>> =
+            self.arguments(&function_name(function, class), &function.parameters, node)?;
+        self.invoke(function, this, class, arguments, call_location(node))
+    }
+
+    /// Runs a function or method with arguments that are already evaluated.
+    ///
+    /// # Parameters
+    ///
+    /// - `function`: The function to run.
+    /// - `this`: The object a method is called on.
+    /// - `class`: The class declaring a called method.
+    /// - `arguments`: The values in the order of the parameters, `None` for parameters using their default value.
+    /// - `location`: The location of the call, shown in stack traces.
+    fn invoke(
+        &mut self,
+        function: &'ast Function,
+        this: Option<Rc<RefCell<Object<'ast>>>>,
+        class: Option<&'ast Class>,
+        arguments: Vec<Option<Value<'ast>>>,
+        location: &Location,
+    ) -> Flow<'ast, Value<'ast>> {
+        if self.frames.len() >= MAX_DEPTH {
+            return Err(self.error("RecursionError", "maximum call depth exceeded", location));
+        }
+
+        let name: String = function_name(function, class);
+        let module: usize = self.module_of(&function.location);
+        // A constructor without a body, e.g. of an enum variant, assigns its arguments to the fields
+        let fields: Option<Rc<RefCell<Object>>> = this.clone().filter(|_| function.body.is_none());
+        self.frames.push(Frame {
+            function: name,
+            module,
+            scopes: vec![HashMap::new()],
+            this,
+            class,
+            call: Some(location.clone()),
+        });
+
+        let mut result: Flow = self
+            .bind(&function.parameters, arguments, fields.as_ref())
+            .and_then(|()| self.contracts(function, "requires", &function.requires));
+        if result.is_ok() && generators::is_generator(function) {
+            let value: Value = self.generator(function);
+            let checked: Flow<Value> = self.postconditions(function, value);
+            self.frames.pop();
+            return checked;
+        }
+        if let (true, Some(body)) = (result.is_ok(), function.body.as_ref()) {
+            result = self.block(body);
+        }
+        let value: Flow<Value> = returned(result)
+            .and_then(|value| self.adapt(value, function.return_type.as_ref(), location))
+            .and_then(|value| self.postconditions(function, value));
+        self.frames.pop();
+
+        value
+    }
+
+    /// Checks the `ensures` clauses of the running function with the value it returns bound to `result`.
+    ///
+    /// # Returns
+    ///
+    /// The returned value, if the clauses are fulfilled.
+    fn postconditions(
+        &mut self,
+        function: &'ast Function,
+        value: Value<'ast>,
+    ) -> Flow<'ast, Value<'ast>> {
+        if function.ensures.is_empty() {
+            return Ok(value);
+        }
+
+        self.frame().scopes.push(HashMap::new());
+        self.declare(contracts::RESULT, value.clone());
+        let result: Flow = self.contracts(function, "ensures", &function.ensures);
+        self.exit();
+
+        result.map(|()| value)
+    }
+
+    /// Creates the generator of a call of a generator function whose parameters are bound in the running frame.
+    /// The body does not run until the first value is needed.
+    fn generator(&mut self, function: &'ast Function) -> Value<'ast> {
+        let key: *const Function = address(function);
+        let machine: Rc<StateMachine> = Rc::clone(
+            self.machines
+                .entry(key)
+                .or_insert_with(|| Rc::new(generators::lower(function))),
+        );
+        if let Some(ref body) = function.body {
+            self.hoist(&body.statements);
+        }
+
+        let frame: &mut Frame = self.frame();
+        Value::Generator(Rc::new(RefCell::new(Generator {
+            function,
+            machine,
+            state: 0,
+            scopes: frame.scopes.iter_mut().map(share).collect(),
+            iterations: vec![],
+            this: frame.this.clone(),
+            class: frame.class,
+            peeked: None,
+            status: Status::Suspended,
+        })))
+    }
+
+    /// Continues a generator until it produces its next value.
+    ///
+    /// The generator runs in a frame of its own, with its variables restored, until a `yield` suspends it again.
+    /// A generator that returns, reaches the end of its body or throws an exception is finished.
+    ///
+    /// # Returns
+    ///
+    /// The next value, or `None` if the generator is finished.
+    ///
+    /// # Errors
+    ///
+    /// Raises a `RuntimeError` if the generator is already running, e.g. when its body iterates over itself.
+    fn resume(
+        &mut self,
+        generator: &Rc<RefCell<Generator<'ast>>>,
+        location: &Location,
+    ) -> Flow<'ast, Option<Value<'ast>>> {
+        let mut suspended: core::cell::RefMut<Generator> = generator.borrow_mut();
+        if let Some(value) = suspended.peeked.take() {
+            return Ok(Some(value));
+        }
+        match suspended.status {
+            Status::Suspended => {}
+            Status::Running => {
+                return Err(self.error(
+                    "RuntimeError",
+                    "the generator is already running",
+                    location,
+                ))
+            }
+            Status::Finished => return Ok(None),
+        }
+        if self.frames.len() >= MAX_DEPTH {
+            return Err(self.error("RecursionError", "maximum call depth exceeded", location));
+        }
+
+        suspended.status = Status::Running;
+        let function: &Function = suspended.function;
+        let machine: Rc<StateMachine> = Rc::clone(&suspended.machine);
+        let mut state: usize = suspended.state;
+        let mut iterations: Vec<Iteration> = core::mem::take(&mut suspended.iterations);
+        self.frames.push(Frame {
+            function: function_name(function, suspended.class),
+            module: self.module_of(&function.location),
+            scopes: suspended
+                .scopes
+                .drain(..)
+                .map(|scope| {
+                    scope
+                        .into_iter()
+                        .map(|(name, cell)| (name, Variable::Boxed(cell)))
+                        .collect()
+                })
+                .collect(),
+            this: suspended.this.clone(),
+            class: suspended.class,
+            call: Some(location.clone()),
+        });
+        // The body can use the generator, e.g. to check that it is not resumed while running
+        drop(suspended);
+
+        let result: Flow<Option<Value>> =
+            self.run_states(function, &machine, &mut state, &mut iterations);
+        let mut frame: Frame = self.frames.pop().expect("the generator frame was pushed");
+
+        let mut resumed: core::cell::RefMut<Generator> = generator.borrow_mut();
+        if let Ok(Some(_)) = result {
+            resumed.state = state;
+            resumed.scopes = frame.scopes.iter_mut().map(share).collect();
+            resumed.iterations = iterations;
+            resumed.status = Status::Suspended;
+        } else {
+            resumed.status = Status::Finished;
+        }
+
+        result
+    }
+
+    /// Runs the states of a generator from a state until a `yield` produces a value, see [`State`].
+    ///
+    /// # Returns
+    ///
+    /// The produced value, or `None` if the generator returned or reached the end of its body.
+    fn run_states(
+        &mut self,
+        function: &'ast Function,
+        machine: &StateMachine<'ast>,
+        state: &mut usize,
+        iterations: &mut Vec<Iteration<'ast>>,
+    ) -> Flow<'ast, Option<Value<'ast>>> {
+        while let Some(current) = machine.states.get(*state) {
+            *state += 1;
+            match *current {
+                State::Run {
+                    statement,
+                    ref exits,
+                } => match (self.statement(statement), exits) {
+                    (Ok(()), _) => {}
+                    (Err(Unwind::Break), &Some((after, _))) => {
+                        self.jump(after, state, iterations);
+                    }
+                    (Err(Unwind::Continue), &Some((_, next))) => {
+                        self.jump(next, state, iterations);
+                    }
+                    // Checked programs have no `break` and `continue` outside of loops
+                    (Err(Unwind::Return(_) | Unwind::Break | Unwind::Continue), _) => {
+                        return Ok(None)
+                    }
+                    (Err(thrown), _) => return Err(thrown),
+                },
+                State::Yield(node) => {
+                    let value: Value = self.expression(&node.value)?;
+                    return match generators::element_type(function) {
+                        Some(element) => arithmetic::adapt(value, &element.to_string())
+                            .map(Some)
+                            .map_err(|failure| self.failure(&failure, node.value.location())),
+                        None => Ok(Some(value)),
+                    };
+                }
+                State::Enter(block) => self.enter(&block.statements),
+                State::Exit => self.exit(),
+                State::Jump(target) => self.jump(target, state, iterations),
+                State::Branch {
+                    condition,
+                    otherwise,
+                } => {
+                    if !self.condition(condition)? {
+                        self.jump(otherwise, state, iterations);
+                    }
+                }
+                State::Subject(node) => {
+                    let subject: Value = self.expression(&node.subject)?;
+                    self.frame().scopes.push(HashMap::new());
+                    self.declare(SUBJECT, subject);
+                }
+                State::Case { arm, otherwise } => {
+                    let subject: Value = self
+                        .lookup(SUBJECT)
+                        .expect("`Case` follows the `Subject` state of its match");
+                    let mut bindings: Vec<(&str, Value)> = vec![];
+                    if arm.pattern.as_ref().map_or(false, |pattern| {
+                        !self.matches(pattern, &subject, &mut bindings)
+                    }) {
+                        self.jump(otherwise, state, iterations);
+                        continue;
+                    }
+
+                    self.frame().scopes.push(HashMap::new());
+                    for (name, value) in bindings {
+                        self.declare(name, value);
+                    }
+                    if let Some(ref guard) = arm.guard {
+                        if !self.condition(guard)? {
+                            self.jump(otherwise, state, iterations);
+                        }
+                    }
+                }
+                State::Evaluate(value) => {
+                    self.expression(value)?;
+                }
+                State::Iterate(node) => {
+                    let iteration: Iteration = self.start(node)?;
+                    iterations.push(iteration);
+                }
+                State::Next { node, done } => {
+                    let iteration: &mut Iteration = iterations
+                        .last_mut()
+                        .expect("`Next` follows the `Iterate` state of its loop");
+                    match self.advance(node, iteration)? {
+                        Some(value) => {
+                            self.frame().scopes.push(HashMap::new());
+                            self.declare(&node.variable.name, value);
+                        }
+                        None => self.jump(done, state, iterations),
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Continues a generator with another state, closing the scopes and ending the iterations opened after it.
+    fn jump(&mut self, target: Target, state: &mut usize, iterations: &mut Vec<Iteration<'ast>>) {
+        *state = target.state;
+        self.frame().scopes.truncate(target.scopes + 1);
+        iterations.truncate(target.iterations);
+    }
+
+    /// Calls a method of a generator: `has_next()` produces the next value and keeps it for `next()`, which
+    /// returns it.
+    ///
+    /// # Errors
+    ///
+    /// Raises a `RuntimeError` if `next()` is called on a finished generator.
+    fn generator_method(
+        &mut self,
+        generator: &Rc<RefCell<Generator<'ast>>>,
+        member: &'ast Member,
+        node: &'ast Call,
+    ) -> Flow<'ast, Value<'ast>> {
+        if !node.arguments.is_empty() {
+            return Err(self.error(
+                "TypeError",
+                &format!("`{}` takes no arguments", member.member.name),
+                &node.location,
+            ));
+        }
+
+        match member.member.name.as_str() {
+            "has_next" => {
+                let value: Option<Value> = self.resume(generator, &node.location)?;
+                let found: bool = value.is_some();
+                generator.borrow_mut().peeked = value;
+                Ok(Value::Bool(found))
+            }
+            "next" => match self.resume(generator, &node.location)? {
+                Some(value) => Ok(value),
+                None => Err(self.error(
+                    "RuntimeError",
+                    "the generator has no more values",
+                    &node.location,
+                )),
+            },
+            name => Err(self.error(
+                "TypeError",
+                &format!("`generator` has no method `{name}`"),
+                &member.member.location,
+            )),
+        }
+    }
+
+    /// Runs a closure with arguments that are already evaluated, see [`Interpreter::invoke`].
+    /// The captured variables are visible in a scope around the parameters.
+    fn run_closure(
+        &mut self,
+        closure: &Closure<'ast>,
+        arguments: Vec<Option<Value<'ast>>>,
+        location: &Location,
+    ) -> Flow<'ast, Value<'ast>> {
+        if self.frames.len() >= MAX_DEPTH {
+            return Err(self.error("RecursionError", "maximum call depth exceeded", location));
+        }
+
+        let captured: HashMap<&str, Variable> = closure
+            .captured
+            .iter()
+            .map(|&(name, ref cell)| (name, Variable::Boxed(Rc::clone(cell))))
+            .collect();
+        let module: usize = self.module_of(&closure.lambda.location);
+        self.frames.push(Frame {
+            function: "<lambda>".to_owned(),
+            module,
+            scopes: vec![captured, HashMap::new()],
+            this: closure.this.clone(),
+            class: closure.class,
+            call: Some(location.clone()),
+        });
+
+        let result: Flow<Value> = self
+            .bind(&closure.lambda.parameters, arguments, None)
+            .and_then(|()| match closure.lambda.body {
+                LambdaBody::Block(ref body) => returned(self.block(body)),
+                LambdaBody::Expression(ref value) => self.expression(value),
+            });
+        self.frames.pop();
+
+        result
+    }
+
+    /// Declares the parameters of a running function with their arguments.
+    /// Default values are evaluated when the function is called and can use the previous parameters.
+    ///
+    /// # Parameters
+    ///
+    /// - `parameters`: The parameters of the function.
+    /// - `arguments`: The values in the order of the parameters, `None` for parameters using their default value.
+    /// - `fields`: The object whose fields a constructor without a body assigns the arguments to.
+    fn bind(
+        &mut self,
+        parameters: &'ast [Parameter],
+        arguments: Vec<Option<Value<'ast>>>,
+        fields: Option<&Rc<RefCell<Object<'ast>>>>,
+    ) -> Flow<'ast> {
+        for (parameter, argument) in parameters.iter().zip(arguments) {
+            let value: Value = match (argument, &parameter.default) {
+                (Some(value), _) => value,
+                (None, &Some(ref default)) => self.expression(default)?,
+                (None, &None) => Value::None,
+            };
+            let value: Value =
+                self.adapt(value, Some(&parameter.type_annotation), &parameter.location)?;
+            if let Some(object) = fields {
+                object
+                    .borrow_mut()
+                    .fields
+                    .insert(parameter.name.name.clone(), value.clone());
+            }
+            self.declare(&parameter.name.name, value);
+        }
+        Ok(())
+    }
+
+    /// Creates a closure of a lambda. The variables the lambda captures are boxed, so the closure shares them with
+    /// the running function.
+    fn closure(&mut self, node: &'ast Lambda) -> Value<'ast> {
+        let names: Vec<&str> = self.module().resolution.captures(node).to_vec();
+        let frame: &mut Frame = self.frame();
+
+        let mut captured: Vec<(&str, Rc<RefCell<Value>>)> = Vec::with_capacity(names.len());
+        for name in names {
+            if let Some(variable) = frame
+                .scopes
+                .iter_mut()
+                .rev()
+                .find_map(|scope| scope.get_mut(name))
+            {
+                captured.push((name, variable.share()));
+            }
+        }
+
+        Value::Closure(Rc::new(Closure {
+            lambda: node,
+            captured,
+            this: frame.this.clone(),
+            class: frame.class,
+        }))
+    }
+
+    /// Returns the constructor used to instantiate a class and the class declaring it.
+    fn constructor(&self, class: &'ast Class) -> Option<(&'ast Class, &'ast Function)> {
+        core::iter::once(class)
+            .chain(self.classes().ancestors(class))
+            .find_map(|candidate| {
+                candidate.members.iter().find_map(|member| match *member {
+                    ClassMember::Constructor(ref function) => Some((candidate, function)),
+                    _ => None,
+                })
+            })
+    }
+
+    /// Creates an instance of a class. Fields get their default values, then the constructor is called.
+    fn instantiate(&mut self, class: &'ast Class, node: &'ast Call) -> Flow<'ast, Value<'ast>> {
+        if class.interface {
+            return Err(self.error(
+                "TypeError",
+                &format!("cannot instantiate interface `{}`", class.name.name),
+                node.callee.location(),
+            ));
+        }
+
+        let object: Rc<RefCell<Object>> = Rc::new(RefCell::new(Object {
+            class,
+            vtable: self.vtable(class),
+            fields: HashMap::new(),
+        }));
+
+        self.frames.push(Frame {
+            function: class.name.name.clone(),
+            module: self.module_of(&class.location),
+            scopes: vec![HashMap::new()],
+            this: Some(Rc::clone(&object)),
+            class: Some(class),
+            call: Some(call_location(node).clone()),
+        });
+        let mut result: Flow = Ok(());
+        for field in self.classes().fields(class) {
+            let value: Value = match field.value {
+                Some(ref value) => match self.expression(value).and_then(|found| {
+                    self.adapt(found, Some(&field.type_annotation), value.location())
+                }) {
+                    Ok(value) => value,
+                    Err(unwind) => {
+                        result = Err(unwind);
+                        break;
+                    }
+                },
+                None => default_value(&field.type_annotation),
+            };
+            object
+                .borrow_mut()
+                .fields
+                .insert(field.name.name.clone(), value);
+        }
+        self.frames.pop();
+        result?;
+
+        match self.constructor(class) {
+            Some((declaring, constructor)) => {
+                self.call_function(constructor, Some(Rc::clone(&object)), Some(declaring), node)?;
+            }
+            None if !node.arguments.is_empty() => {
+                return Err(self.error(
+                    "TypeError",
+                    &format!("`{}` takes no arguments", class.name.name),
+                    &node.location,
+                ));
+            }
+            None => {}
+        }
+
+        Ok(Value::Object(object))
+    }
+
+    /// Calls the constructor of the parent class with `super(...)`.
+    fn super_constructor(&mut self, node: &'ast Call) -> Flow<'ast, Value<'ast>> {
+        let frame: &mut Frame = self.frame();
+        let (Some(this), Some(class)) = (frame.this.clone(), frame.class) else {
+            return Err(self.error(
+                "RuntimeError",
+                "`super` can only be used in methods",
+                node.callee.location(),
+            ));
+        };
+
+        match self
+            .classes()
+            .parent(class)
+            .and_then(|parent| self.constructor(parent))
+        {
+            Some((declaring, constructor)) => {
+                self.call_function(constructor, Some(this), Some(declaring), node)
+            }
+            None if node.arguments.is_empty() => Ok(Value::None),
+            None => Err(self.error(
+                "TypeError",
+                &format!("the parent of `{}` takes no arguments", class.name.name),
+                &node.location,
+            )),
+        }
+    }
+
+    // Built-in functions
+
+    /// Converts a value to a string. Exceptions are converted to their message.
+    fn stringify(&self, value: &Value) -> String {
+        if let Value::Object(ref object) = *value {
+            let object: core::cell::Ref<Object> = object.borrow();
+            if self.classes().is_exception(object.class) {
+                return object
+                    .fields
+                    .get("message")
+                    .map(ToString::to_string)
+                    .unwrap_or_default();
+            }
+        }
+
+        value.to_string()
+    }
+
+    /// Calls a built-in function: `print`, `input`, `time`, `str`, `bool` or the conversion to an integer type, e.g.
+    /// `int`.
+    fn built_in(&mut self, name: &str, node: &'ast Call) -> Flow<'ast, Value<'ast>> {
+        if !prelude::BUILT_IN_FUNCTIONS.contains(&name) {
+            return Err(self.error(
+                "RuntimeError",
+                &format!("`{name}` is not defined"),
+                node.callee.location(),
+            ));
+        }
+
+        let mut arguments: Vec<Value> = vec![];
+        for argument in &node.arguments {
+            arguments.push(self.expression(&argument.value)?);
+        }
+
+        match (name, arguments.as_slice()) {
+            ("print", _) => {
+                let text: Vec<String> = arguments
+                    .iter()
+                    .map(|argument| self.stringify(argument))
+                    .collect();
+                writeln!(self.output, "{}", text.join(" ")).map_err(|error| {
+                    self.error(
+                        "RuntimeError",
+                        &format!("could not print: {error}"),
+                        &node.location,
+                    )
+                })?;
+                Ok(Value::None)
+            }
+            ("input", &([] | [_])) => {
+                if let Some(prompt) = arguments.first() {
+                    let prompt: String = self.stringify(prompt);
+                    write!(self.output, "{prompt}")
+                        .and_then(|()| self.output.flush())
+                        .map_err(|error| {
+                            self.error(
+                                "RuntimeError",
+                                &format!("could not print: {error}"),
+                                &node.location,
+                            )
+                        })?;
+                }
+
+                let mut line: String = String::new();
+                let read: usize = self.input.read_line(&mut line).map_err(|error| {
+                    self.error(
+                        "RuntimeError",
+                        &format!("could not read the input: {error}"),
+                        &node.location,
+                    )
+                })?;
+                if read == 0 {
+                    return Err(self.error("EOFError", "the input has ended", &node.location));
+                }
+                Ok(Value::Str(line.trim_end_matches(['\n', '\r']).into()))
+            }
+            ("time", &[]) => {
+                let elapsed: Duration = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default();
+                Ok(Value::Int(
+                    i64::try_from(elapsed.as_nanos()).unwrap_or(i64::MAX),
+                ))
+            }
+            (_, &[ref value]) if integers::is_integer(name) => arithmetic::conversion(name, value)
+                .expect("the name is an integer type")
+                .map_err(|failure| self.failure(&failure, &node.location)),
+            ("str", &[ref value]) => Ok(Value::Str(self.stringify(value).into())),
+            ("bool", &[ref value]) => Ok(Value::Bool(match *value {
+                Value::None => false,
+                Value::Bool(value) => value,
+                Value::Int(value) => value != 0,
+                Value::Sized(value, _) => value != 0,
+                Value::BigInt(ref value) => !value.is_zero(),
+                Value::Str(ref value) => !value.is_empty(),
+                Value::List(ref elements) => !elements.borrow().is_empty(),
+                Value::Dict(ref dict) => !dict.borrow().is_empty(),
+                Value::Tuple(ref elements) => !elements.is_empty(),
+                Value::Object(_)
+                | Value::Function(_)
+                | Value::Closure(_)
+                | Value::Module(_)
+                | Value::Generator(_) => true,
+            })),
+            _ => Err(self.error(
+                "TypeError",
+                &format!("wrong number of arguments for `{name}`"),
+                &node.location,
+            )),
+        }
+    }
+}
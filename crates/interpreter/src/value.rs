@@ -0,0 +1,536 @@
+//! The values a running program works with.
+// I Language interpreter values.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use core::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::bigint::BigInt;
+
+use compiler::generators::StateMachine;
+use compiler::integers::Width;
+use compiler::modules::Module;
+use parser::ast::{Class, ClassMember, Function, Lambda};
+
+
+///////////
+// VALUE //
+///////////
+
+/// A value of a running program.
+///
+/// Strings, collections and objects are reference counted, so copying a value is cheap.
+/// Lists, dicts and objects are shared: assigning one to another variable does not copy it.
+#[derive(Clone, Debug)]
+pub enum Value<'ast> {
+    /// The `none` value.
+    None,
+    /// A `bool` value.
+    Bool(bool),
+    /// An `int` value.
+    Int(i64),
+    /// A value of a sized integer type, e.g. `u8`, which is always in the range of its type.
+    Sized(i128, Width),
+    /// A `bigint` value.
+    BigInt(Rc<BigInt>),
+    /// A `str` value.
+    Str(Rc<str>),
+    /// A `list` value, a growable array.
+    List(Rc<RefCell<Vec<Value<'ast>>>>),
+    /// A `dict` value, a hash map keeping the order its keys were inserted in.
+    Dict(Rc<RefCell<Dict<'ast>>>),
+    /// A `tuple` value, which cannot be changed after it is created.
+    Tuple(Rc<[Value<'ast>]>),
+    /// An instance of a class.
+    Object(Rc<RefCell<Object<'ast>>>),
+    /// A function declared with `function`.
+    Function(&'ast Function),
+    /// A lambda with the variables it captured.
+    Closure(Rc<Closure<'ast>>),
+    /// A module bound with `import`.
+    Module(&'ast Module),
+    /// The values produced by a call of a generator function.
+    Generator(Rc<RefCell<Generator<'ast>>>),
+}
+
+impl Value<'_> {
+    /// Returns the name of the type of the value, e.g. `int` or the name of the class of an object.
+    #[inline]
+    pub fn type_name(&self) -> String {
+        match *self {
+            Self::None => "none".to_owned(),
+            Self::Bool(_) => "bool".to_owned(),
+            Self::Int(_) => "int".to_owned(),
+            Self::Sized(_, width) => width.name().to_owned(),
+            Self::BigInt(_) => "bigint".to_owned(),
+            Self::Str(_) => "str".to_owned(),
+            Self::List(_) => "list".to_owned(),
+            Self::Dict(_) => "dict".to_owned(),
+            Self::Tuple(_) => "tuple".to_owned(),
+            Self::Object(ref object) => object.borrow().class.name.name.clone(),
+            Self::Function(_) | Self::Closure(_) => "function".to_owned(),
+            Self::Module(_) => "module".to_owned(),
+            Self::Generator(_) => "generator".to_owned(),
+        }
+    }
+
+    /// Returns the value as it is shown inside a collection. Strings are quoted, so `["1"]` and `[1]` can be told
+    /// apart. Only quotes, backslashes and control characters are escaped, other characters are kept as they are.
+    #[inline]
+    pub fn repr(&self) -> String {
+        match *self {
+            Self::Str(ref value) => {
+                let escaped: String = value
+                    .chars()
+                    .map(|character| match character {
+                        '"' | '\\' | '\n' | '\t' | '\r' => character.escape_default().to_string(),
+                        other if other.is_control() => other.escape_unicode().to_string(),
+                        other => other.to_string(),
+                    })
+                    .collect();
+                format!("\"{escaped}\"")
+            }
+            ref other => other.to_string(),
+        }
+    }
+
+    /// Returns the value of an integer of any integer type, `None` for other values and `bigint` values too large
+    /// for an `i128`.
+    #[inline]
+    pub fn integer(&self) -> Option<i128> {
+        match *self {
+            Self::Int(value) => Some(i128::from(value)),
+            Self::Sized(value, _) => Some(value),
+            Self::BigInt(ref value) => value.to_i128(),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for Value<'_> {
+    /// Compares two values. Collections are equal if they have equal elements, a `dict` regardless of the order of
+    /// its keys. Enum values are equal if they are the same variant with equal fields, other objects, functions and
+    /// modules are only equal to themselves.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (&Self::None, &Self::None) => true,
+            (&Self::Bool(left), &Self::Bool(right)) => left == right,
+            (&Self::Int(left), &Self::Int(right)) => left == right,
+            (&Self::BigInt(ref left), &Self::BigInt(ref right)) => left == right,
+            // Integers of different types are compared by their values
+            (&(Self::Int(_) | Self::Sized(..) | Self::BigInt(_)), _) => self
+                .integer()
+                .map_or(false, |left| other.integer() == Some(left)),
+            (&Self::Str(ref left), &Self::Str(ref right)) => left == right,
+            (&Self::List(ref left), &Self::List(ref right)) => {
+                Rc::ptr_eq(left, right) || *left.borrow() == *right.borrow()
+            }
+            (&Self::Dict(ref left), &Self::Dict(ref right)) => {
+                Rc::ptr_eq(left, right) || {
+                    let (left, right) = (left.borrow(), right.borrow());
+                    left.len() == right.len()
+                        && left
+                            .entries()
+                            .iter()
+                            .all(|&(ref key, ref value)| right.get(key) == Some(value))
+                }
+            }
+            (&Self::Tuple(ref left), &Self::Tuple(ref right)) => left == right,
+            (&Self::Object(ref left), &Self::Object(ref right)) => {
+                Rc::ptr_eq(left, right) || {
+                    let (left, right) = (left.borrow(), right.borrow());
+                    core::ptr::eq(left.class, right.class)
+                        && left.is_variant()
+                        && left.fields == right.fields
+                }
+            }
+            (&Self::Function(left), &Self::Function(right)) => core::ptr::eq(left, right),
+            (&Self::Closure(ref left), &Self::Closure(ref right)) => Rc::ptr_eq(left, right),
+            (&Self::Module(left), &Self::Module(right)) => core::ptr::eq(left, right),
+            (&Self::Generator(ref left), &Self::Generator(ref right)) => Rc::ptr_eq(left, right),
+            _ => false,
+        }
+    }
+}
+
+impl core::fmt::Display for Value<'_> {
+    #[inline]
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::None => write!(formatter, "none"),
+            Self::Bool(value) => write!(formatter, "{value}"),
+            Self::Int(value) => write!(formatter, "{value}"),
+            Self::Sized(value, _) => write!(formatter, "{value}"),
+            Self::BigInt(ref value) => write!(formatter, "{value}"),
+            Self::Str(ref value) => write!(formatter, "{value}"),
+            Self::List(ref elements) => {
+                write!(formatter, "[")?;
+                write_elements(formatter, &elements.borrow())?;
+                write!(formatter, "]")
+            }
+            Self::Dict(ref dict) => {
+                write!(formatter, "{{")?;
+                for (index, &(ref key, ref value)) in dict.borrow().entries().iter().enumerate() {
+                    if index > 0 {
+                        write!(formatter, ", ")?;
+                    }
+                    write_element(formatter, &key.value())?;
+                    write!(formatter, ": ")?;
+                    write_element(formatter, value)?;
+                }
+                write!(formatter, "}}")
+            }
+            Self::Tuple(ref elements) => {
+                write!(formatter, "(")?;
+                write_elements(formatter, elements)?;
+                write!(formatter, "{})", if elements.len() == 1 { "," } else { "" })
+            }
+            Self::Object(ref object) => {
+                write!(formatter, "<{} object>", object.borrow().class.name.name)
+            }
+            Self::Function(function) => write!(formatter, "<function {}>", function.name.name),
+            Self::Closure(_) => write!(formatter, "<lambda>"),
+            Self::Module(module) => write!(formatter, "<module {}>", module.name),
+            Self::Generator(ref generator) => {
+                write!(
+                    formatter,
+                    "<generator {}>",
+                    generator.borrow().function.name.name
+                )
+            }
+        }
+    }
+}
+
+
+/// Writes the elements of a list or tuple, separated by commas.
+fn write_elements(
+    formatter: &mut core::fmt::Formatter<'_>,
+    elements: &[Value],
+) -> core::fmt::Result {
+    for (index, element) in elements.iter().enumerate() {
+        if index > 0 {
+            write!(formatter, ", ")?;
+        }
+        write_element(formatter, element)?;
+    }
+    Ok(())
+}
+
+/// Writes an element of a collection, see [`Value::repr`].
+fn write_element(formatter: &mut core::fmt::Formatter<'_>, element: &Value) -> core::fmt::Result {
+    write!(formatter, "{}", element.repr())
+}
+
+
+//////////
+// DICT //
+//////////
+
+/// A key of a `dict`. Only `none`, `bool`, integer and `str` values can be used as keys.
+///
+/// Integer keys are equal if their values are, whatever their types are, so `1` finds the entry of a `u8` key `1`.
+#[derive(Clone, Debug)]
+pub enum Key {
+    /// The `none` key.
+    None,
+    /// A `bool` key.
+    Bool(bool),
+    /// An `int` key.
+    Int(i64),
+    /// A key of a sized integer type.
+    Sized(i128, Width),
+    /// A `bigint` key.
+    BigInt(Rc<BigInt>),
+    /// A `str` key.
+    Str(Rc<str>),
+}
+
+impl Key {
+    /// Returns the key for a value, `None` if the value cannot be used as a key.
+    #[inline]
+    pub fn new(value: &Value) -> Option<Self> {
+        match *value {
+            Value::None => Some(Self::None),
+            Value::Bool(value) => Some(Self::Bool(value)),
+            Value::Int(value) => Some(Self::Int(value)),
+            Value::Sized(value, width) => Some(Self::Sized(value, width)),
+            Value::BigInt(ref value) => Some(Self::BigInt(Rc::clone(value))),
+            Value::Str(ref value) => Some(Self::Str(Rc::clone(value))),
+            _ => None,
+        }
+    }
+
+    /// Returns the value the key was created from.
+    #[inline]
+    pub fn value<'ast>(&self) -> Value<'ast> {
+        match *self {
+            Self::None => Value::None,
+            Self::Bool(value) => Value::Bool(value),
+            Self::Int(value) => Value::Int(value),
+            Self::Sized(value, width) => Value::Sized(value, width),
+            Self::BigInt(ref value) => Value::BigInt(Rc::clone(value)),
+            Self::Str(ref value) => Value::Str(Rc::clone(value)),
+        }
+    }
+
+    /// Returns the value of an integer key, `None` for other keys and `bigint` keys too large for an `i128`.
+    fn integer(&self) -> Option<i128> {
+        match *self {
+            Self::Int(value) => Some(i128::from(value)),
+            Self::Sized(value, _) => Some(value),
+            Self::BigInt(ref value) => value.to_i128(),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for Key {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (&Self::None, &Self::None) => true,
+            (&Self::Bool(left), &Self::Bool(right)) => left == right,
+            (&Self::BigInt(ref left), &Self::BigInt(ref right)) => left == right,
+            (&Self::Str(ref left), &Self::Str(ref right)) => left == right,
+            (&(Self::Int(_) | Self::Sized(..) | Self::BigInt(_)), _) => self
+                .integer()
+                .map_or(false, |left| other.integer() == Some(left)),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Key {}
+
+impl core::hash::Hash for Key {
+    /// Hashes the key. Integer keys are hashed by their values, so equal keys of different types have the same
+    /// hash.
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        match (self, self.integer()) {
+            (_, Some(value)) => value.hash(state),
+            (&Self::BigInt(ref value), None) => value.hash(state),
+            (&Self::Bool(value), None) => value.hash(state),
+            (&Self::Str(ref value), None) => value.hash(state),
+            _ => {}
+        }
+    }
+}
+
+/// The entries of a `dict`, in the order their keys were first inserted.
+#[derive(Clone, Debug, Default)]
+pub struct Dict<'ast> {
+    /// The keys and values.
+    entries: Vec<(Key, Value<'ast>)>,
+    /// The positions of the keys in `entries`.
+    positions: HashMap<Key, usize>,
+}
+
+impl<'ast> Dict<'ast> {
+    /// Returns the entries, in the order their keys were first inserted.
+    #[inline]
+    pub fn entries(&self) -> &[(Key, Value<'ast>)] {
+        &self.entries
+    }
+
+    /// Returns the number of entries.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the dict has no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the value of a key.
+    #[inline]
+    #[allow(clippy::indexing_slicing)]
+    pub fn get(&self, key: &Key) -> Option<&Value<'ast>> {
+        self.positions
+            .get(key)
+            .map(|&position| &self.entries[position].1)
+    }
+
+    /// Sets the value of a key. A new key is added after all other keys, an existing one keeps its position.
+    #[inline]
+    #[allow(clippy::indexing_slicing)]
+    pub fn insert(&mut self, key: Key, value: Value<'ast>) {
+        if let Some(&position) = self.positions.get(&key) {
+            self.entries[position].1 = value;
+        } else {
+            self.positions.insert(key.clone(), self.entries.len());
+            self.entries.push((key, value));
+        }
+    }
+
+    /// Removes a key and returns its value, `None` if the dict does not contain the key.
+    #[inline]
+    #[allow(clippy::iter_over_hash_type)]
+    pub fn remove(&mut self, key: &Key) -> Option<Value<'ast>> {
+        let position: usize = self.positions.remove(key)?;
+        let (_, value): (Key, Value) = self.entries.remove(position);
+        for moved in self.positions.values_mut() {
+            if *moved > position {
+                *moved -= 1;
+            }
+        }
+        Some(value)
+    }
+}
+
+
+/////////////
+// CLOSURE //
+/////////////
+
+/// A lambda created at runtime, see [`Value::Closure`].
+///
+/// The variables a lambda captures are boxed: the lambda and the code around it share them, so an assignment on
+/// either side is seen by the other, even after the function declaring the variables returned.
+#[derive(Debug)]
+pub struct Closure<'ast> {
+    /// The lambda.
+    pub lambda: &'ast Lambda,
+    /// The captured variables, see [`Resolution::captures`](`compiler::resolve::Resolution::captures`).
+    pub captured: Vec<(&'ast str, Rc<RefCell<Value<'ast>>>)>,
+    /// The object `this` refers to if the lambda was created in a method or constructor.
+    pub this: Option<Rc<RefCell<Object<'ast>>>>,
+    /// The class declaring the method the lambda was created in, used for `super`.
+    pub class: Option<&'ast Class>,
+}
+
+
+///////////////
+// GENERATOR //
+///////////////
+
+/// A suspended call of a generator function, see [`Value::Generator`].
+///
+/// The generator runs the [`StateMachine`] of the function until a `yield` produces the next value, then keeps the
+/// state to continue with, its variables and the loops in progress until the value after it is needed.
+#[derive(Debug)]
+pub struct Generator<'ast> {
+    /// The generator function.
+    pub function: &'ast Function,
+    /// The state machine of the function.
+    pub machine: Rc<StateMachine<'ast>>,
+    /// The index of the state to continue with.
+    pub state: usize,
+    /// The variables of the suspended call, starting with the scope of the parameters. They are boxed, so lambdas
+    /// created by the generator share them.
+    pub scopes: Vec<HashMap<&'ast str, Rc<RefCell<Value<'ast>>>>>,
+    /// The `for` loops in progress, innermost last.
+    pub iterations: Vec<Iteration<'ast>>,
+    /// The object `this` refers to if the generator is a method.
+    pub this: Option<Rc<RefCell<Object<'ast>>>>,
+    /// The class declaring the method, used for `super`.
+    pub class: Option<&'ast Class>,
+    /// A value already produced to answer `has_next`, returned by the next call of `next`.
+    pub peeked: Option<Value<'ast>>,
+    /// Whether the generator is running, suspended or finished.
+    pub status: Status,
+}
+
+/// Whether a [`Generator`] can produce more values.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Status {
+    /// The generator waits for the next value to be needed.
+    Suspended,
+    /// The generator is producing a value. It cannot be resumed from its own body.
+    Running,
+    /// The generator returned or threw an exception and produces no more values.
+    Finished,
+}
+
+/// A `for` loop in progress: the values it iterates over and how far it got.
+#[derive(Debug)]
+pub enum Iteration<'ast> {
+    /// A range of `int` values, counting without creating the values up front.
+    Range {
+        /// The next value, `None` after the largest `int`.
+        next: Option<i64>,
+        /// The end of the range.
+        end: i64,
+        /// The difference between two values, never zero.
+        step: i64,
+        /// Whether the end is part of the range.
+        inclusive: bool,
+    },
+    /// The elements of a `list`, read by position so elements appended while iterating are visited too.
+    List {
+        /// The list.
+        list: Rc<RefCell<Vec<Value<'ast>>>>,
+        /// The position of the next element.
+        position: usize,
+    },
+    /// Values taken up front: the elements of a `tuple`, the characters of a `str` or the keys of a `dict`.
+    Values(std::vec::IntoIter<Value<'ast>>),
+    /// An object implementing the `Iterator` interface of the prelude.
+    Iterator(Rc<RefCell<Object<'ast>>>),
+    /// A generator.
+    Generator(Rc<RefCell<Generator<'ast>>>),
+}
+
+
+////////////
+// OBJECT //
+////////////
+
+/// The methods called on the instances of a class by name, with the classes or interfaces declaring them.
+/// Built once per class by [`ClassTable::vtable`](`compiler::classes::ClassTable::vtable`) and shared by all instances.
+pub type VTable<'ast> = HashMap<&'ast str, (&'ast Class, &'ast Function)>;
+
+/// An instance of a class.
+///
+/// A value of an enum is an instance of one of its variants: the class of the object tells which variant it is
+/// and the fields hold the data of the variant.
+#[derive(Clone, Debug)]
+pub struct Object<'ast> {
+    /// The class the object was created from.
+    pub class: &'ast Class,
+    /// The methods of the class of the object, used to find the method to run when one is called.
+    pub vtable: Rc<VTable<'ast>>,
+    /// The values of the fields, including inherited ones.
+    pub fields: HashMap<String, Value<'ast>>,
+}
+
+impl Object<'_> {
+    /// Returns whether the object is a value of an enum. Only the variants of enums have a constructor without a
+    /// body.
+    #[inline]
+    pub fn is_variant(&self) -> bool {
+        self.class.members.iter().any(|member| {
+            matches!(*member, ClassMember::Constructor(ref constructor) if constructor.body.is_none())
+        })
+    }
+}
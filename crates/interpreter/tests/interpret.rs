@@ -0,0 +1,901 @@
+// I Language interpreter tests.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use interpreter::error::Uncaught;
+
+    fn run(input: &str) -> Result<String, Uncaught> {
+        let program: parser::ast::Program =
+            parser::parse::parse(&lexer::lex::lex(input, "<stdin>").unwrap()).unwrap();
+        let mut output: Vec<u8> = vec![];
+
+        interpreter::interpret::run(&program, &mut "42\n".as_bytes(), &mut output)?;
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_expressions() {
+        assert_eq!(
+            run("int x = 7; x += 3; print(x * 2 - 1, x / 3, x % 3, 2 ** 10);").unwrap(),
+            "19 3 1 1024\n"
+        );
+        assert_eq!(
+            run("str name = \"I\"; print(\"Hello \" + name, name == \"I\", !true);").unwrap(),
+            "Hello I true false\n"
+        );
+        assert_eq!(run("int x = 1; print(x++, x, --x);").unwrap(), "1 2 1\n");
+        assert_eq!(
+            run("var x = 2; var name; name = \"x\"; print(name, x * 3);").unwrap(),
+            "x 6\n"
+        );
+        assert_eq!(
+            run("const int MAX = 25 * 4;
+                function limit(int value = MAX - 1) -> int { return value; }
+                match (limit()) { case MAX -> print(\"max\"); case other -> print(other); }
+                match (MAX) { case MAX -> print(\"max\"); default -> print(\"other\"); }")
+            .unwrap(),
+            "99\nmax\n"
+        );
+        assert_eq!(
+            run("int guess = int(input(\"Guess: \")); print(guess + 1);").unwrap(),
+            "Guess: 43\n"
+        );
+        assert_eq!(run("print(time() > 0);").unwrap(), "true\n");
+    }
+
+    #[test]
+    fn test_statements() {
+        assert_eq!(
+            run("int i = 0;
+                while (true) {
+                    i++;
+                    if (i % 2 == 0) { continue; }
+                    if (i > 5) { break; }
+                    print(i);
+                }")
+            .unwrap(),
+            "1\n3\n5\n"
+        );
+        assert_eq!(
+            run("function greet(str name, str greeting = \"Hello\") -> str {
+                    return greeting + \" \" + name;
+                }
+                print(greet(\"I\"), greet(greeting = \"Hi\", name = \"you\"));")
+            .unwrap(),
+            "Hello I Hi you\n"
+        );
+        assert_eq!(
+            run("class Point {
+                    pub int x;
+                    pub int y = 2;
+                    pub Point(int x) { this.x = x; }
+                    pub function sum() -> int { return this.x + this.y; }
+                }
+                class SpacePoint: Point {
+                    pub int z = 3;
+                    pub function sum() -> int { return super.sum() + this.z; }
+                }
+                Point point = SpacePoint(1);
+                print(point.sum());
+                match (point) {
+                    case Point(x, 0) { print(\"never\"); }
+                    case Point(int x, _) if (x > 0) { print(x); }
+                    default { }
+                }
+                print(match (point.y) { case 0 .. 2 -> \"small\", default -> \"large\" });")
+            .unwrap(),
+            "6\n1\nlarge\n"
+        );
+        assert_eq!(
+            run("const int SMALL = 10;
+                const int LARGE = SMALL * 10;
+                for (var size in [5, 50, 500]) {
+                    print(match (size) { case 0 .. SMALL -> \"small\", case SMALL .. LARGE -> \"medium\", default -> \"large\" });
+                }")
+            .unwrap(),
+            "small\nmedium\nlarge\n"
+        );
+        assert_eq!(
+            run("class Box<T> {
+                    pub T value;
+                    pub Box(T value) { this.value = value; }
+                    pub function get() -> T { return this.value; }
+                }
+                function first<T>(T a, T b) -> T { return a; }
+                Box<Box<str>> nested = Box(Box(\"I\"));
+                Box<str> inner = nested.get();
+                var number = Box(first(4, 2));
+                print(inner.value, number.get() * 2);")
+            .unwrap(),
+            "I 8\n"
+        );
+        assert_eq!(
+            run("interface Named {
+                    function name() -> str;
+                    function greet() -> str { return \"Hello \" + this.name(); }
+                }
+                class Player implements Named {
+                    pub function name() -> str { return \"player\"; }
+                }
+                class Loud: Player {
+                    pub function greet() -> str { return \"HELLO \" + this.name(); }
+                }
+                Named named = Player();
+                print(named.greet());
+                named = Loud();
+                print(named.greet());")
+            .unwrap(),
+            "Hello player\nHELLO player\n"
+        );
+        assert_eq!(
+            run("enum Shape {
+                    Circle(int radius), Square(int side), Empty;
+                    pub function area() -> int {
+                        return match (this) {
+                            case Shape.Circle(r) -> 3 * r * r,
+                            case Shape.Square(s) -> s * s,
+                            case Shape.Empty() -> 0,
+                        };
+                    }
+                }
+                Shape circle = Shape.Circle(2);
+                Shape empty = Shape.Empty();
+                print(circle.area(), empty.area());
+                print(circle == Shape.Circle(2), circle == Shape.Circle(3), empty == Shape.Empty());")
+            .unwrap(),
+            "12 0\ntrue false true\n"
+        );
+        // Variants are named after their enum, so they can share names with other variants and classes
+        assert_eq!(
+            run("enum Reply { None, Value(int value) }
+                enum Status { None }
+                class Value { pub int value = 5; }
+                Reply reply = Reply.Value(3);
+                print(match (reply) { case Reply.Value(v) -> v, case Reply.None() -> 0 });
+                print(Value().value, Status.None() == Status.None(), Reply.None());")
+            .unwrap(),
+            "3\n5 true <Reply.None object>\n"
+        );
+    }
+
+    #[test]
+    fn test_collections() {
+        assert_eq!(
+            run("list<int> items = [3, 1, 4];
+                items.append(5);
+                items.insert(0, 9);
+                items[1] += 10;
+                print(items, items[-1], items[1 .. 3], items[.. -2]);
+                print(items.pop(), items.len());
+                dict<str, int> ages = {\"ann\": 3, \"bob\": 5};
+                ages[\"cid\"] = 7;
+                ages[\"ann\"] = 4;
+                print(ages, ages[\"bob\"]);
+                print(ages.remove(\"bob\"), ages.keys(), ages.contains(\"bob\"));
+                tuple<int, str> pair = (1, \"x\");
+                print(pair, pair[1], (2,), \"hello\"[1 ..], \"hello\"[-1]);
+                print([1, [2]] == [1, [2]], {1: 2, 3: 4} == {3: 4, 1: 2}, bool([]), bool((0,)));")
+            .unwrap(),
+            "[9, 13, 1, 4, 5] 5 [13, 1] [9, 13, 1]\n5 4\n\
+            {\"ann\": 4, \"bob\": 5, \"cid\": 7} 5\n5 [\"ann\", \"cid\"] false\n\
+            (1, \"x\") x (2,) ello o\n\
+            true true false true\n"
+        );
+
+        let error: Uncaught = run("list<int> items = [1, 2]; print(items[2]);").unwrap_err();
+        assert_eq!(error.exception, "IndexError");
+        assert_eq!(
+            error.message,
+            "index 2 is out of range for a `list` of length 2"
+        );
+
+        let error: Uncaught = run("dict<str, int> ages = {}; print(ages[\"ann\"]);").unwrap_err();
+        assert_eq!(error.exception, "KeyError");
+        assert_eq!(error.message, "key \"ann\" not found");
+    }
+
+    #[test]
+    fn test_for() {
+        assert_eq!(
+            run("list<int> counted = [];
+                for (var i in 0 .. 3) { counted.append(i); }
+                for (int i in 10 ..= 0 step -5) { counted.append(i); }
+                for (var i in 0 .. 100) { if (i == 2) { break; } counted.append(i); }
+                print(counted);
+                list<int> items = [1, 2];
+                for (var item in items) { if (item == 1) { items.append(3); } }
+                print(items);
+                for (var key in {\"a\": 1, \"b\": 2}) { print(key); }
+                for (var character in \"hé\") { if (character == \"h\") { continue; } print(character); }
+                for (var element in (1, \"x\")) { print(element); }")
+            .unwrap(),
+            "[0, 1, 2, 10, 5, 0, 0, 1]\n[1, 2, 3]\na\nb\né\n1\nx\n"
+        );
+        assert_eq!(
+            run("class Countdown implements Iterator<int> {
+                    pub int current;
+                    pub Countdown(int start) { this.current = start; }
+                    pub function has_next() -> bool { return this.current > 0; }
+                    pub function next() -> int {
+                        this.current -= 1;
+                        return this.current + 1;
+                    }
+                }
+                class Launch implements Iterable<int> {
+                    pub function iterator() -> Iterator<int> { return Countdown(2); }
+                }
+                for (var count in Countdown(3)) { print(count); }
+                for (var count in Launch()) { print(count); }")
+            .unwrap(),
+            "3\n2\n1\n2\n1\n"
+        );
+
+        let error: Uncaught = run("for (var i in 0 .. 3 step 0) { }").unwrap_err();
+        assert_eq!(error.exception, "ValueError");
+        assert_eq!(error.message, "the step of a range must not be zero");
+    }
+
+    #[test]
+    fn test_closures() {
+        // Captured variables are shared and outlive the call declaring them
+        assert_eq!(
+            run("function counter() -> function() -> int {
+                    int count = 0;
+                    var step = () -> int { count += 1; return count; };
+                    step();
+                    count += 10;
+                    return step;
+                }
+                var first = counter();
+                var second = counter();
+                first();
+                print(first(), second());
+                list<function() -> int> getters = [];
+                for (var i in 0 .. 3) { getters.append(() -> i * 10); }
+                print(getters[0](), getters[2]());")
+            .unwrap(),
+            "13 12
+0 20
+"
+        );
+        assert_eq!(
+            run(
+                "function apply(function(int) -> int f, int value) -> int { return f(value); }
+                var add = function(int a, int b = 1) -> int { return a + b; };
+                print(apply((int x) -> x * 2, 21), add(1), add(b = 2, a = 3));
+                class Player {
+                    pub str name;
+                    pub function() -> str greet;
+                    pub Player(str name) {
+                        this.name = name;
+                        this.greet = () -> \"Hi \" + this.name;
+                    }
+                }
+                Player player = Player(\"Ada\");
+                player.name = \"Bob\";
+                print(player.greet());
+                var pairs = [(2, \"a\"), (1, \"b\"), (3, \"c\"), (2, \"d\")];
+                pairs.sort((tuple<int, str> a, tuple<int, str> b) -> a[0] - b[0]);
+                print(pairs);"
+            )
+            .unwrap(),
+            "42 2 5\nHi Bob\n[(1, \"b\"), (2, \"a\"), (2, \"d\"), (3, \"c\")]\n"
+        );
+
+        let error: Uncaught =
+            run("list<int> numbers = [2, 1]; numbers.sort((int a, int b) -> a > b);").unwrap_err();
+        assert_eq!(error.exception, "TypeError");
+        assert_eq!(
+            error.message,
+            "the comparator of `sort` must return an `int`, found `bool`"
+        );
+
+        // The list is not borrowed while the comparator runs, the sorted copy replaces its changes
+        assert_eq!(
+            run("list<int> numbers = [3, 1, 2];
+                function compare(int a, int b) -> int {
+                    numbers.append(a);
+                    return a - b;
+                }
+                numbers.sort(compare);
+                print(numbers);")
+            .unwrap(),
+            "[1, 2, 3]\n"
+        );
+    }
+
+    #[test]
+    fn test_overloading() {
+        let vector: &str = "class Vector {
+                pub int x;
+                pub int y;
+                pub Vector(int x, int y) { this.x = x; this.y = y; }
+                pub function add(Vector other) -> Vector {
+                    return Vector(this.x + other.x, this.y + other.y);
+                }
+                pub function mul(int factor) -> Vector { return Vector(this.x * factor, this.y * factor); }
+                pub function neg() -> Vector { return Vector(0 - this.x, 0 - this.y); }
+                pub function eq(Vector other) -> bool { return this.x == other.x && this.y == other.y; }
+                pub function index(int position) -> int {
+                    if (position == 0) { return this.x; }
+                    return this.y;
+                }
+                pub function set_index(int position, int value) {
+                    if (position == 0) { this.x = value; } else { this.y = value; }
+                }
+                pub function show() -> str { return str(this.x) + \" \" + str(this.y); }
+            }
+            class Total {
+                pub int value = 0;
+                pub function add_assign(int amount) { this.value += amount; }
+            }";
+        let run_with = |code: &str| run(&format!("{vector}\n{code}"));
+
+        assert_eq!(
+            run_with(
+                "Vector a = Vector(1, 2);
+                Vector b = a + Vector(3, 4) * 2;
+                b[0] = 10;
+                print(b.show(), (-b).show(), b[1]);
+                print(a == Vector(1, 2), a != Vector(1, 2), a == none);
+                Vector c = a;
+                c += b;
+                print(a.show(), c.show());
+                Total total = Total();
+                Total same = total;
+                same += 5;
+                print(total.value);"
+            )
+            .unwrap(),
+            "10 10 -10 -10 10\ntrue false false\n1 2 11 12\n5\n"
+        );
+
+        let error: Uncaught = run_with("Vector(1, 2) - Vector(1, 2);").unwrap_err();
+        assert_eq!(error.exception, "TypeError");
+        assert_eq!(
+            error.message,
+            "`-` is not defined for `Vector`, which has no method `sub`"
+        );
+    }
+
+    #[test]
+    fn test_nullable() {
+        assert_eq!(
+            run("class Node {
+                    pub int value;
+                    pub Node? next;
+                    pub Node(int value) { this.value = value; }
+                    pub function label() -> str { return \"node \" + str(this.value); }
+                }
+                Node head = Node(1);
+                head.next = Node(2);
+                int? missing;
+                print(head.next?.value, head.next?.next?.value, head.next?.next?.label());
+                print(missing ?? 3, head.next?.label() ?? \"none\", head.next?.next ?? head);
+                Node? empty = none;
+                empty?.next = head;
+                print(empty == none, missing);")
+            .unwrap(),
+            "2 none none\n3 node 2 <Node object>\ntrue none\n"
+        );
+    }
+
+    #[test]
+    fn test_exceptions() {
+        assert_eq!(
+            run("try {
+                    int(\"Test\");
+                    print(\"never\");
+                } catch TypeError(value) {
+                    print(\"caught\", value);
+                } finally {
+                    print(\"finally\");
+                }")
+            .unwrap(),
+            "caught cannot convert \"Test\" to `int`\nfinally\n"
+        );
+        assert_eq!(
+            run("class NegativeError: ValueError {
+                    pub int value;
+                    pub NegativeError(int value) {
+                        super(\"negative\");
+                        this.value = value;
+                    }
+                }
+                try {
+                    throw NegativeError(0 - 1);
+                } catch TypeError {
+                    print(\"never\");
+                } catch ValueError(error) {
+                    print(error, error.message, error.value);
+                }")
+            .unwrap(),
+            "negative negative -1\n"
+        );
+
+        // Finally blocks run when leaving with return or break
+        assert_eq!(
+            run("function f() -> int {
+                    try { return 1; } finally { print(\"finally\"); }
+                }
+                print(f());
+                while (true) {
+                    try { break; } finally { print(\"break\"); }
+                }")
+            .unwrap(),
+            "finally\n1\nbreak\n"
+        );
+        assert_eq!(
+            run("try {
+                    try { throw RuntimeError(\"inner\"); } finally { print(\"inner\"); }
+                } catch Exception(error) {
+                    print(error);
+                }")
+            .unwrap(),
+            "inner\ninner\n"
+        );
+
+        // Errors of the runtime itself can be caught as well
+        assert_eq!(
+            run("input();
+                try { input(); } catch EOFError(error) { print(error); }")
+            .unwrap(),
+            "the input has ended\n"
+        );
+        assert_eq!(
+            run("int deepest = 0;
+                function dive(int depth) {
+                    deepest = depth;
+                    dive(depth + 1);
+                }
+                try { dive(1); } catch RecursionError(error) { print(error, deepest); }")
+            .unwrap(),
+            "maximum call depth exceeded 999\n"
+        );
+    }
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(
+            run("u8 small = 250;
+                small += 5;
+                i8 negative = -128;
+                function half(u16 value) -> u16 { return value / 2; }
+                list<u32> values = [1, 2, 3];
+                values[0] = 4;
+                dict<u8, str> names = {};
+                names[1] = \"one\";
+                print(small, negative, half(65535), values, names[u8(1)]);
+                print(~u8(1), u8(1) << 7, i8(1) << 7, 7 / -2, -7 % 2);
+                print(u64(\"18446744073709551615\"), i16(true), int(u8(200)));
+                match (u8(7)) { case 0 .. 5 -> print(\"small\"); default -> print(\"large\"); }")
+            .unwrap(),
+            "255 -128 32767 [4, 2, 3] one\n254 128 -128 -3 -1\n18446744073709551615 1 200\nlarge\n"
+        );
+        assert_eq!(
+            run("bigint huge = 9223372036854775807;
+                huge = huge * huge * 1000;
+                var literal = 100000000000000000000;
+                print(huge, huge / 1000 / 9223372036854775807, -huge % 7, literal - 1);
+                bigint factorial = 1;
+                for (var i in 1 ..= 30) { factorial = factorial * bigint(i); }
+                print(factorial, bigint(2) ** 110 > factorial, bigint(\"-12\") == -12);")
+            .unwrap(),
+            "85070591730234615847396907784232501249000 9223372036854775807 0 99999999999999999999\n\
+             265252859812191058636308480000000 true true\n"
+        );
+
+        // Integers with a fixed width never wrap around
+        for (program, message) in [
+            (
+                "int big = 9223372036854775807; big += 1;",
+                "`9223372036854775807 + 1` overflows `int`",
+            ),
+            (
+                "int small = -9223372036854775808; print(small * -1);",
+                "`-9223372036854775808 * -1` overflows `int`",
+            ),
+            (
+                "int small = -9223372036854775808; print(-small);",
+                "`-(-9223372036854775808)` overflows `int`",
+            ),
+            ("u8 small = 255; small++;", "`255 + 1` overflows `u8`"),
+            ("u32 small = 0; small -= 1;", "`0 - 1` overflows `u32`"),
+            (
+                "i16 value = 2; print(value ** 15);",
+                "`2 ** 15` overflows `i16`",
+            ),
+            (
+                "int wide = 300; print(u8(wide));",
+                "`300` does not fit into `u8`",
+            ),
+            (
+                "function f(u8 x) {} int wide = 256; f(wide);",
+                "`256` does not fit into `u8`",
+            ),
+            ("print(i8(\"1000\"));", "`1000` does not fit into `i8`"),
+        ] {
+            let error: Uncaught = run(program).unwrap_err();
+            assert_eq!(
+                (error.exception.as_str(), error.message.as_str()),
+                ("OverflowError", message),
+                "{program}"
+            );
+        }
+        assert_eq!(
+            run("bigint zero = 0; print(1 / zero);")
+                .unwrap_err()
+                .exception,
+            "ZeroDivisionError"
+        );
+        assert_eq!(run("print(u8(\"x\"));").unwrap_err().exception, "TypeError");
+    }
+
+    #[test]
+    fn test_generators() {
+        // Generators run lazily, so an endless generator can feed a pipeline
+        assert_eq!(
+            run("function naturals(int start) -> Iterator<int> {
+                    int i = start;
+                    while (true) { yield i; i += 1; }
+                }
+                function odd_squares(Iterator<int> source) -> Iterator<int> {
+                    for (var value in source) {
+                        if (value % 2 == 0) { continue; }
+                        yield value * value;
+                    }
+                }
+                function take(Iterator<int> source, int count) -> Iterator<int> {
+                    int taken = 0;
+                    for (var value in source) {
+                        if (taken == count) { return; }
+                        yield value;
+                        taken += 1;
+                    }
+                }
+                for (var value in take(odd_squares(naturals(1)), 4)) { print(value); }")
+            .unwrap(),
+            "1\n9\n25\n49\n"
+        );
+        assert_eq!(
+            run("class Grid implements Iterable<str> {
+                    pub int size;
+                    pub Grid(int size) { this.size = size; }
+                    pub function iterator() -> Iterator<str> {
+                        for (var row in 0 .. this.size) {
+                            for (var column in 0 .. row) { yield str(row) + str(column); }
+                        }
+                    }
+                }
+                for (var cell in Grid(3)) { print(cell); }
+                var cells = Grid(3).iterator();
+                print(cells.has_next(), cells.has_next(), cells.next(), cells.next());
+                print(cells.next(), cells.has_next(), cells);")
+            .unwrap(),
+            "10\n20\n21\ntrue true 10 20\n21 false <generator iterator>\n"
+        );
+        // A generator can be suspended inside the cases of a match
+        assert_eq!(
+            run("enum Shape { Circle(int radius), Square(int side), Empty }
+                function sizes(list<Shape> found) -> Iterator<int> {
+                    for (var shape in found) {
+                        match (shape) {
+                            case Shape.Circle(r) if (r > 1) { yield r; yield r * 2; }
+                            case Shape.Circle(r) { continue; }
+                            case Shape.Square(s) { yield s * s; }
+                            default -> print(\"empty\");
+                        }
+                        print(\"next\");
+                    }
+                }
+                list<Shape> shapes = [];
+                shapes.append(Shape.Circle(3));
+                shapes.append(Shape.Circle(1));
+                shapes.append(Shape.Empty());
+                shapes.append(Shape.Square(4));
+                for (var size in sizes(shapes)) { print(size); }")
+            .unwrap(),
+            "3\n6\nnext\nempty\nnext\n16\nnext\n"
+        );
+        // Closures created by a generator keep sharing its variables
+        assert_eq!(
+            run("list<function() -> int> readers = [];
+                function counter() -> Iterator<int> {
+                    int count = 0;
+                    readers.append(() -> count);
+                    while (count < 3) { count += 1; yield count; }
+                }
+                for (var value in counter()) { print(value, readers[0]()); }")
+            .unwrap(),
+            "1 1\n2 2\n3 3\n"
+        );
+
+        let error: Uncaught = run("function failing() -> Iterator<int> {
+                yield 1;
+                throw ValueError(\"failed\");
+            }
+            var values = failing();
+            print(values.next());
+            try { values.next(); } catch ValueError(e) { print(e.message); }
+            print(values.has_next());
+            values.next();")
+        .unwrap_err();
+        assert_eq!(
+            (error.exception.as_str(), error.message.as_str()),
+            ("RuntimeError", "the generator has no more values")
+        );
+        assert_eq!(
+            run("function recursive() -> Iterator<int> { for (var value in values) { yield value; } }
+                var values = recursive();
+                values.next();")
+            .unwrap_err()
+            .message,
+            "the generator is already running"
+        );
+    }
+
+    #[test]
+    fn test_contracts() {
+        // A failed assertion reports its source and the values of its operands
+        let error: Uncaught = run("int a = 3; int b = 4;
+            assert a * 2 == b && a > 0, \"doubling \" + str(a);")
+        .unwrap_err();
+        assert_eq!(
+            (error.exception.as_str(), error.message.as_str()),
+            (
+                "AssertionError",
+                "doubling 3: assertion `a * 2 == b && a > 0` failed (a * 2 = 6, b = 4)"
+            )
+        );
+        assert_eq!(
+            run("list<int> items = [1, 2]; assert items.len() > 2;")
+                .unwrap_err()
+                .message,
+            "assertion `items.len() > 2` failed (items.len() = 2)"
+        );
+        assert_eq!(
+            run(
+                "function divide(int x, int y) -> int requires y != 0 { return x / y; }
+                int zero = 0;
+                print(divide(6, 3));
+                print(divide(1, zero));"
+            )
+            .unwrap_err()
+            .message,
+            "`requires y != 0` of `divide` failed (y = 0)"
+        );
+        assert_eq!(
+            run(
+                "function absolute(int x) -> int ensures result >= 0 { return x; }
+                print(absolute(5));
+                print(absolute(-5));"
+            )
+            .unwrap_err()
+            .message,
+            "`ensures result >= 0` of `absolute` failed (result = -5)"
+        );
+        // Passing checks are silent and failures can be caught
+        assert_eq!(
+            run("class Account {
+                    pub int balance;
+                    pub Account(int balance) requires balance >= 0 { this.balance = balance; }
+                }
+                assert Account(5).balance == 5;
+                try { Account(-1); } catch AssertionError(e) { print(e.message); }")
+            .unwrap(),
+            "`requires balance >= 0` of `Account` failed (balance = -1)\n"
+        );
+    }
+
+    #[test]
+    fn test_strings() {
+        // Lengths and positions count characters, graphemes join combining characters
+        assert_eq!(
+            run("str name = \"  Zoë Ångström \".strip();
+                print(name.len(), name.upper(), name.lower(), name.find(\"Å\"), name.find(\"x\"));
+                print(\"-\".join(\"a,b,,c\".split(\",\")), \"banana\".replace(\"an\", \"AN\"));
+                print(name.contains(\"ë\"), name.starts_with(\"Zo\"), name.ends_with(\"m\"));
+                str flag = \"🇩🇪e\u{301}\";
+                print(flag.len(), flag.chars().len(), flag.graphemes(), name[1 .. 3]);")
+            .unwrap(),
+            "12 ZOË ÅNGSTRÖM zoë ångström 4 none\na-b--c bANANa\ntrue true true\n\
+             4 4 [\"🇩🇪\", \"e\u{301}\"] oë\n"
+        );
+        assert_eq!(
+            run("int n = 255; str name = \"Zoë\";
+                print(f\"{n} {n:x} {n:X} {n:08b} {-n:o} [{n:>5}] [{n:<5}] [{n:*^7}] {-42:06}\");
+                print(f\"[{name:>5}] [{name:.2}] [{name:-^9.2}] {name:?} {[name, '']} {{{n + 1}}}\");
+                print(f\"{bigint(2) ** 70:x} {u8(5):03} {none} {(1, 'a')}\");")
+            .unwrap(),
+            "255 ff FF 11111111 -377 [  255] [255  ] [**255**] -00042\n\
+             [  Zoë] [Zo] [---Zo----] \"Zoë\" [\"Zoë\", \"\"] {256}\n\
+             400000000000000000 005 none (1, \"a\")\n"
+        );
+        // Exceptions are formatted with their message, like `str` does
+        assert_eq!(
+            run("try { int(\"abc\"); } catch TypeError(error) { print(f\"{error} is not a number\"); }")
+                .unwrap(),
+            "cannot convert \"abc\" to `int` is not a number\n"
+        );
+        assert_eq!(
+            run("\"a b\".split(\"\");").unwrap_err().exception,
+            "ValueError"
+        );
+    }
+
+    #[test]
+    fn test_modules() {
+        let root: std::path::PathBuf = std::env::temp_dir().join("i-language-interpreter-modules");
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("geometry")).unwrap();
+        std::fs::write(
+            root.join("geometry/shapes.il"),
+            "int created = 0;
+            print(\"loading shapes\");
+
+            pub class Square {
+                pub int side;
+                pub Square(int side) { this.side = side; created += 1; }
+            }
+
+            pub function count() -> int { return created; }",
+        )
+        .unwrap();
+
+        let file: String = root.join("main.il").display().to_string();
+        let source: &str = "import geometry.shapes as shapes;
+            use geometry.shapes.Square as Tile;
+            use geometry.shapes.count;
+            import random;
+
+            int created = 10;
+            Tile tile = Tile(2);
+            shapes.Square(3);
+            random.seed(1);
+            int roll = random.randint(1, 6);
+            random.seed(1);
+            print(tile.side, count(), created, roll >= 1 && roll <= 6, roll == random.randint(1, 6));
+            random.randint(0, 4294967295);";
+        let (modules, diagnostics) = compiler::modules::Modules::load(compiler::modules::Module {
+            name: "main".to_owned(),
+            file: file.clone(),
+            source: source.to_owned(),
+            program: parser::parse::parse(&lexer::lex::lex(source, &file).unwrap()).unwrap(),
+        });
+        assert!(diagnostics.is_empty());
+
+        let mut output: Vec<u8> = vec![];
+        let uncaught: interpreter::error::Uncaught =
+            interpreter::interpret::run_modules(&modules, &mut std::io::empty(), &mut output)
+                .unwrap_err();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "loading shapes\n2 2 10 true true\n"
+        );
+        assert_eq!(
+            uncaught.message,
+            "the range of `randint` is larger than 2147483648 numbers"
+        );
+    }
+
+    #[test]
+    fn test_run_tests() {
+        let source: &str = "int runs = 0;
+            print(\"setup\");
+            function add(int a, int b) -> int { return a + b; }
+
+            @test function adds() {
+                runs += 1;
+                if (add(1, 2) != 3) { throw ValueError(\"wrong sum\"); }
+            }
+
+            @test function counts() {
+                runs += 1;
+                print(runs);
+                throw ValueError(\"failed\");
+            }";
+        let (modules, _) = compiler::modules::Modules::load(compiler::modules::Module {
+            name: "main".to_owned(),
+            file: "main.il".to_owned(),
+            source: source.to_owned(),
+            program: parser::parse::parse(&lexer::lex::lex(source, "main.il").unwrap()).unwrap(),
+        });
+
+        let mut output: Vec<u8> = vec![];
+        let results: Vec<(&str, Result<(), Uncaught>)> =
+            interpreter::interpret::run_tests(&modules, &mut std::io::empty(), &mut output)
+                .unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "setup\n2\n");
+        assert_eq!(results[0], ("adds", Ok(())));
+        assert_eq!(results[1].0, "counts");
+        assert_eq!(
+            results[1].1.as_ref().unwrap_err().to_string(),
+            "Uncaught ValueError: failed\n    at counts (main.il:13:17)\n    at <main> (main.il:10:19)"
+        );
+    }
+
+    #[test]
+    fn test_uncaught() {
+        let error: Uncaught = run("function divide(int x, int y) -> int {
+                return x / y;
+            }
+            print(\"before\");
+            divide(1, 0);")
+        .unwrap_err();
+
+        assert_eq!(error.exception, "ZeroDivisionError");
+        assert_eq!(error.message, "division by zero");
+        assert_eq!(
+            error.to_string(),
+            "Uncaught ZeroDivisionError: division by zero
+    at divide (<stdin>:2:26)
+    at <main> (<stdin>:5:13)"
+        );
+
+        assert_eq!(
+            run("class Failure: Exception {} throw Failure(\"failed\");")
+                .unwrap_err()
+                .to_string(),
+            "Uncaught Failure: failed\n    at <main> (<stdin>:1:29)"
+        );
+
+        // Only the ends of a long trace are kept
+        let error: Uncaught = run("function dive() { dive(); } dive();").unwrap_err();
+        assert_eq!(error.exception, "RecursionError");
+        assert_eq!((error.trace.len(), error.omitted), (20, 980));
+        assert!(error.to_string().contains(
+            "    at dive (<stdin>:1:19)\n    ... 980 more calls\n    at dive (<stdin>:1:19)\n"
+        ));
+    }
+
+    #[test]
+    fn test_nesting() {
+        let depth: usize = parser::parse::MAX_NESTING - 10;
+        assert_eq!(
+            run(&format!(
+                "print({}1{});",
+                "(".repeat(depth),
+                ")".repeat(depth)
+            )),
+            Ok("1\n".to_owned())
+        );
+        assert_eq!(
+            run(&format!("print(0{});", " + 1".repeat(depth))),
+            Ok(format!("{depth}\n"))
+        );
+        assert_eq!(
+            run(&format!(
+                "int x = 1; {}print(x);{}",
+                "if (x > 0) { ".repeat(depth),
+                " }".repeat(depth)
+            )),
+            Ok("1\n".to_owned())
+        );
+    }
+}
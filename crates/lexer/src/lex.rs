@@ -154,6 +154,7 @@ pub fn lex(input: &str, file: &str) -> Result<Vec<Token>, LexerError> {
                     | '|'
                     | ':'
                     | '.'
+                    | '?'
                     | ','
                     | ';'
                     | '~'
@@ -215,14 +216,27 @@ pub fn lex(input: &str, file: &str) -> Result<Vec<Token>, LexerError> {
                 buffer.append(
                     &mut iterator
                         .peek_while(|&(_, next_character)| {
-                            next_character.is_alphabetic() || next_character == '_'
+                            next_character.is_alphanumeric() || next_character == '_'
                         })
                         .iter()
                         .map(|&(_, found)| found)
                         .collect::<Vec<char>>(),
                 );
 
-                if let Some(value) = Keyword::get_token(location.clone(), &buffer) {
+                // A quote right after a single `f` starts a format string, e.g. `f"{guess}"`
+                if let (&['f'], Some(&(_, quote @ ('"' | '\'')))) =
+                    (buffer.as_slice(), iterator.peek())
+                {
+                    iterator.next();
+                    match TypeDefinition::lex_string(&mut iterator, line, location, quote) {
+                        Ok(value) => result.push(Token {
+                            token_type: TokenType::TypeDefinition(TypeDefinition::FormatString),
+                            ..value
+                        }),
+                        Err(error_value) => error = Some(error_value),
+                    }
+                    buffer.clear();
+                } else if let Some(value) = Keyword::get_token(location.clone(), &buffer) {
                     result.push(value);
                     buffer.clear();
                 } else if let Some(value) = Type::get_token(location.clone(), &buffer) {
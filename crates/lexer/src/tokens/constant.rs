@@ -44,6 +44,12 @@ pub enum Type {
     Int,
     /// The `bool` type. Alias: `boolean`. The type of boolean literals (`true`, `false`).
     Bool,
+    /// The `dict` type, e.g. `dict<str, int>`. Alias: `dictionary`. The type of dict literals (e.g. `{"a": 1}`).
+    Dict,
+    /// The `list` type, e.g. `list<int>`. The type of list literals (e.g. `[1, 2, 3]`).
+    List,
+    /// The `tuple` type, e.g. `tuple<int, str>`. The type of tuple literals (e.g. `(1, "x")`).
+    Tuple,
 }
 
 impl core::fmt::Display for Type {
@@ -54,6 +60,9 @@ impl core::fmt::Display for Type {
             &Self::Str => write!(formatter, "string"),
             &Self::Int => write!(formatter, "integer"),
             &Self::Bool => write!(formatter, "boolean"),
+            &Self::Dict => write!(formatter, "dictionary"),
+            &Self::List => write!(formatter, "list"),
+            &Self::Tuple => write!(formatter, "tuple"),
         }
     }
 }
@@ -79,6 +88,21 @@ impl GetToken for Type {
                 content: "boolean".to_owned(),
                 token_type: TokenType::Type(Type::Bool),
             }),
+            "dict" | "dictionary" => Some(Token {
+                location,
+                content: "dictionary".to_owned(),
+                token_type: TokenType::Type(Type::Dict),
+            }),
+            "list" => Some(Token {
+                location,
+                content: "list".to_owned(),
+                token_type: TokenType::Type(Type::List),
+            }),
+            "tuple" => Some(Token {
+                location,
+                content: "tuple".to_owned(),
+                token_type: TokenType::Type(Type::Tuple),
+            }),
             _ => None,
         }
     }
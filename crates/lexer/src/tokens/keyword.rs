@@ -40,6 +40,8 @@ use crate::tokens::token::{GetToken, Location, Token, TokenType};
 pub enum Keyword {
     /// The `as` keyword. Used with the [`import`](`Keyword::Import`) keyword to import items into a specific namespace.
     As,
+    /// The `assert` keyword. Used to check a condition that has to be true, throwing an `AssertionError` otherwise.
+    Assert,
     /// The `break` keyword. Used for exiting out of a loop.
     Break,
     /// The `case` keyword. Used in combination with the [`match`](`Keyword::Match`) keyword to match a specific case.
@@ -56,6 +58,10 @@ pub enum Keyword {
     Default,
     /// The `else` keyword. Used to define the "otherwise" block of an [`if`](`Keyword::If`) statement.
     Else,
+    /// The `ensures` keyword. Used after the parameters of a function to state a condition its result fulfills.
+    Ensures,
+    /// The `enum` keyword. Used to define enums, whose values are one of several variants which can carry data.
+    Enum,
     /// The `finally` keyword. Used in combination with the [`try`](`Keyword::Try`) keyword to execute code even after an exception has been raised.
     Finally,
     /// The `for` keyword. Used to create a loop over an iterator.
@@ -64,12 +70,20 @@ pub enum Keyword {
     Function, // TODO (ElBe): Replace with "fn" or "func"?
     /// The `if` keyword. Used to check whether a condition is true or false and execute code based on that condition.
     If,
+    /// The `implements` keyword. Used to list the [`interface`](`Keyword::Interface`)s a class implements.
+    Implements,
     /// The `import` keyword. Used to import code from other modules.
     Import,
+    /// The `in` keyword. Used in [`for`](`Keyword::For`) loops to separate the loop variable from the iterated values.
+    In,
+    /// The `interface` keyword. Used to define interfaces, which describe methods a class has to implement.
+    Interface,
     /// The `match` keyword. Used in combination with the [`case`](`Keyword::Case`) and [`default`](`Keyword::Default`) keywords.
     Match,
     /// The `pub` keyword. Used to export an item out of the current scope.
     Pub,
+    /// The `requires` keyword. Used after the parameters of a function to state a condition its arguments have to fulfill.
+    Requires,
     /// The `return` keyword. Used to return something from a function.
     Return,
     /// The `throw` keyword. Used to throw (raise) an exception.
@@ -82,6 +96,8 @@ pub enum Keyword {
     Var,
     /// The `while` keyword. Used to crate a loop which will iterates as long as a condition is true.
     While,
+    /// The `yield` keyword. Used in generator functions to produce the next value of the generator.
+    Yield,
 }
 
 impl core::fmt::Display for Keyword {
@@ -90,6 +106,7 @@ impl core::fmt::Display for Keyword {
     fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             &Self::As => write!(formatter, "as"),
+            &Self::Assert => write!(formatter, "assert"),
             &Self::Break => write!(formatter, "break"),
             &Self::Case => write!(formatter, "case"),
             &Self::Catch => write!(formatter, "catch"),
@@ -98,19 +115,26 @@ impl core::fmt::Display for Keyword {
             &Self::Continue => write!(formatter, "continue"),
             &Self::Default => write!(formatter, "default"),
             &Self::Else => write!(formatter, "else"),
+            &Self::Ensures => write!(formatter, "ensures"),
+            &Self::Enum => write!(formatter, "enum"),
             &Self::Finally => write!(formatter, "finally"),
             &Self::For => write!(formatter, "for"),
             &Self::Function => write!(formatter, "function"),
             &Self::If => write!(formatter, "if"),
+            &Self::Implements => write!(formatter, "implements"),
             &Self::Import => write!(formatter, "import"),
+            &Self::In => write!(formatter, "in"),
+            &Self::Interface => write!(formatter, "interface"),
             &Self::Match => write!(formatter, "match"),
             &Self::Pub => write!(formatter, "pub"),
+            &Self::Requires => write!(formatter, "requires"),
             &Self::Return => write!(formatter, "return"),
             &Self::Throw => write!(formatter, "throw"),
             &Self::Try => write!(formatter, "try"),
             &Self::Use => write!(formatter, "use"),
             &Self::Var => write!(formatter, "var"),
             &Self::While => write!(formatter, "while"),
+            &Self::Yield => write!(formatter, "yield"),
         }
     }
 }
@@ -127,6 +151,11 @@ impl GetToken for Keyword {
                 content: "as".to_owned(),
                 token_type: TokenType::Keyword(Keyword::As),
             }),
+            "assert" => Some(Token {
+                location,
+                content: "assert".to_owned(),
+                token_type: TokenType::Keyword(Keyword::Assert),
+            }),
             "break" => Some(Token {
                 location,
                 content: "break".to_owned(),
@@ -167,6 +196,16 @@ impl GetToken for Keyword {
                 content: "else".to_owned(),
                 token_type: TokenType::Keyword(Keyword::Else),
             }),
+            "ensures" => Some(Token {
+                location,
+                content: "ensures".to_owned(),
+                token_type: TokenType::Keyword(Keyword::Ensures),
+            }),
+            "enum" => Some(Token {
+                location,
+                content: "enum".to_owned(),
+                token_type: TokenType::Keyword(Keyword::Enum),
+            }),
             "finally" => Some(Token {
                 location,
                 content: "finally".to_owned(),
@@ -187,11 +226,26 @@ impl GetToken for Keyword {
                 content: "if".to_owned(),
                 token_type: TokenType::Keyword(Keyword::If),
             }),
+            "implements" => Some(Token {
+                location,
+                content: "implements".to_owned(),
+                token_type: TokenType::Keyword(Keyword::Implements),
+            }),
             "import" => Some(Token {
                 location,
                 content: "import".to_owned(),
                 token_type: TokenType::Keyword(Keyword::Import),
             }),
+            "in" => Some(Token {
+                location,
+                content: "in".to_owned(),
+                token_type: TokenType::Keyword(Keyword::In),
+            }),
+            "interface" => Some(Token {
+                location,
+                content: "interface".to_owned(),
+                token_type: TokenType::Keyword(Keyword::Interface),
+            }),
             "match" => Some(Token {
                 location,
                 content: "match".to_owned(),
@@ -202,6 +256,11 @@ impl GetToken for Keyword {
                 content: "pub".to_owned(),
                 token_type: TokenType::Keyword(Keyword::Pub),
             }),
+            "requires" => Some(Token {
+                location,
+                content: "requires".to_owned(),
+                token_type: TokenType::Keyword(Keyword::Requires),
+            }),
             "return" => Some(Token {
                 location,
                 content: "return".to_owned(),
@@ -232,6 +291,11 @@ impl GetToken for Keyword {
                 content: "while".to_owned(),
                 token_type: TokenType::Keyword(Keyword::While),
             }),
+            "yield" => Some(Token {
+                location,
+                content: "yield".to_owned(),
+                token_type: TokenType::Keyword(Keyword::Yield),
+            }),
             _ => None,
         }
     }
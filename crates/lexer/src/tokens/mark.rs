@@ -48,7 +48,7 @@ pub enum Mark {
     Arrow,
     /// The `=` mark. Used for assigning a value to a variable.
     Assign,
-    /// The `@` mark. Currently, it has no use, but it's reserved for later usage and will probably be used in future.
+    /// The `@` mark. Used for annotating functions, classes and fields, e.g. `@deprecated("Use jump instead")`.
     At,
     /// The `!` (**logical** not) mark. Used for flipping a true value to false and vice versa.
     Bang,
@@ -76,6 +76,8 @@ pub enum Mark {
     BracketOpen,
     /// The `]` mark. Used for indexing a value and closing an array.
     BracketClose,
+    /// The `??` (null coalescing) mark. Used for falling back to the right value if the left value is `none`.
+    Coalesce,
     /// The `:` mark. Used for defining type parameters and as a separator between a dictionary's key and value.
     Colon,
     /// The `,` mark. Used for adding more items to a data structure.
@@ -118,10 +120,14 @@ pub enum Mark {
     ParenthesisOpen,
     /// The `)` mark. Used for function parameters and operator precedence for mathematical operations.
     ParenthesisClose,
-    /// The `?` mark. Currently, it has no use, but it's reserved for later usage and will probably be used in future.
+    /// The `?` mark. Used after a type to make it nullable, e.g. `int?`, which also accepts `none`.
     QuestionMark,
     /// The `..` mark. Used to create a range for indexing or looping. Currently, only ranges with spaces around them are supported, `1..2` will not work and returns a float.
     Range,
+    /// The `..=` mark. Used to create a range that includes its end for looping, e.g. `1 ..= 10`.
+    RangeInclusive,
+    /// The `?.` (safe navigation) mark. Used for accessing a member of a value that may be `none`, which results in `none` instead of an error.
+    SafeNavigation,
     /// The `;` mark. Used to end a line of code.
     Semicolon,
     /// The `<<` (**bitwise** left shift) mark. Used to shift bytes n digits to the left, where n is the right value.
@@ -162,6 +168,7 @@ impl core::fmt::Display for Mark {
             &Self::BraceClose => write!(formatter, "}}"),
             &Self::BracketOpen => write!(formatter, "["),
             &Self::BracketClose => write!(formatter, "]"),
+            &Self::Coalesce => write!(formatter, "??"),
             &Self::Colon => write!(formatter, ":"),
             &Self::Comma => write!(formatter, ","),
             &Self::Decrease => write!(formatter, "--"),
@@ -185,6 +192,8 @@ impl core::fmt::Display for Mark {
             &Self::ParenthesisClose => write!(formatter, ")"),
             &Self::QuestionMark => write!(formatter, "?"),
             &Self::Range => write!(formatter, ".."),
+            &Self::RangeInclusive => write!(formatter, "..="),
+            &Self::SafeNavigation => write!(formatter, "?."),
             &Self::Semicolon => write!(formatter, ";"),
             &Self::ShiftLeft => write!(formatter, "<<"),
             &Self::ShiftLeftAssign => write!(formatter, "<<="),
@@ -413,6 +422,21 @@ impl GetToken for Mark {
                 content: "..".to_owned(),
                 token_type: TokenType::Mark(Mark::Range),
             }),
+            "??" => Some(Token {
+                location,
+                content: "??".to_owned(),
+                token_type: TokenType::Mark(Mark::Coalesce),
+            }),
+            "?." => Some(Token {
+                location,
+                content: "?.".to_owned(),
+                token_type: TokenType::Mark(Mark::SafeNavigation),
+            }),
+            "..=" => Some(Token {
+                location,
+                content: "..=".to_owned(),
+                token_type: TokenType::Mark(Mark::RangeInclusive),
+            }),
             ";" => Some(Token {
                 location,
                 content: ";".to_owned(),
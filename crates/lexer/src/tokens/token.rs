@@ -102,6 +102,9 @@ pub trait GetToken {
 pub enum TypeDefinition {
     /// The string literal. Examples: `'"'`, `"hello"`, `'world'`, `"\""`.
     String,
+    /// The format string literal, a string with expressions in braces. Examples: `f"{guess} is too high"`,
+    /// `f'{name:>10}'`, `f"{{}}"`.
+    FormatString,
     /// The integer literal. Examples: `1`, `123`, `-10`, `1_000_000`
     Integer,
     /// The `true` literal.
@@ -118,6 +121,7 @@ impl core::fmt::Display for TypeDefinition {
     fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             &Self::String => write!(formatter, "string literal"),
+            &Self::FormatString => write!(formatter, "format string literal"),
             &Self::Integer => write!(formatter, "integer literal"),
             &Self::True => write!(formatter, "`true`"),
             &Self::False => write!(formatter, "`false`"),
@@ -238,9 +242,11 @@ impl TypeDefinition {
                         label: "String starts here",
                         annotation_type: annotate_snippets::AnnotationType::Help,
                     },
-                    if buffer.get(
-                        TryInto::<usize>::try_into(buffer.len() - 2).unwrap_or(buffer.len() - 1),
-                    ) == Some(&'\\')
+                    if buffer
+                        .len()
+                        .checked_sub(2)
+                        .and_then(|index| buffer.get(index))
+                        == Some(&'\\')
                         && buffer.last() == Some(&quote_type)
                     {
                         annotate_snippets::SourceAnnotation {
@@ -382,18 +388,29 @@ impl TokenType {
         let mut buffer: Vec<char> = vec![character];
 
         if let Some(&(_, next_character)) = iterator.clone().peek() {
+            // The next character only belongs to the mark if they form a mark or a comment together, so
+            // `[-1]` and `(..)` are lexed as separate marks
+            let pair: [char; 2] = [character, next_character];
+            let is_pair: bool = matches!(pair, ['/', '/' | '*'])
+                || Mark::get_token(location.clone(), &pair.to_vec()).is_some();
+
             #[allow(clippy::else_if_without_else)]
-            if matches!(
-                next_character,
-                '+' | '-' | '/' | '*' | '=' | '&' | '|' | '.'
-            ) {
+            if is_pair
+                && matches!(
+                    next_character,
+                    '+' | '-' | '/' | '*' | '=' | '&' | '|' | '?'
+                )
+            {
                 iterator.next();
                 buffer.push(next_character);
-            } else if matches!(next_character, '<' | '>') {
+            } else if is_pair && matches!(next_character, '<' | '>' | '.') {
                 iterator.next();
                 buffer.push(next_character);
 
-                if let Some(&(_, '=')) = iterator.clone().peek() {
+                let triple: [char; 3] = [character, next_character, '='];
+                if iterator.clone().peek().map(|&(_, found)| found) == Some('=')
+                    && Mark::get_token(location.clone(), &triple.to_vec()).is_some()
+                {
                     iterator.next();
                     buffer.push('=');
                 }
@@ -109,5 +109,63 @@ mod tests {
                 },
             ])
         );
+
+        assert_eq!(
+            lexer::lex::lex("u8 9", "<stdin>").map(|tokens| tokens
+                .into_iter()
+                .map(|token| token.content)
+                .collect::<Vec<String>>()),
+            Ok(vec!["u8".to_owned(), "9".to_owned()])
+        );
+
+        // A quote right after `f` starts a format string, other names before a string stay identifiers
+        assert_eq!(
+            lexer::lex::lex("f\"{x} \\\"{y}\\\"!\" f 'a' fa'b' f'{1}'", "<stdin>").map(|tokens| {
+                tokens
+                    .into_iter()
+                    .map(|token| (token.token_type, token.content, token.location.column))
+                    .collect::<Vec<_>>()
+            }),
+            Ok(vec![
+                (
+                    lexer::tokens::token::TokenType::TypeDefinition(
+                        lexer::tokens::token::TypeDefinition::FormatString
+                    ),
+                    "{x} \\\"{y}\\\"!".to_owned(),
+                    1
+                ),
+                (
+                    lexer::tokens::token::TokenType::Identifier,
+                    "f".to_owned(),
+                    17
+                ),
+                (
+                    lexer::tokens::token::TokenType::TypeDefinition(
+                        lexer::tokens::token::TypeDefinition::String
+                    ),
+                    "a".to_owned(),
+                    19
+                ),
+                (
+                    lexer::tokens::token::TokenType::Identifier,
+                    "fa".to_owned(),
+                    23
+                ),
+                (
+                    lexer::tokens::token::TokenType::TypeDefinition(
+                        lexer::tokens::token::TypeDefinition::String
+                    ),
+                    "b".to_owned(),
+                    25
+                ),
+                (
+                    lexer::tokens::token::TokenType::TypeDefinition(
+                        lexer::tokens::token::TypeDefinition::FormatString
+                    ),
+                    "{1}".to_owned(),
+                    29
+                ),
+            ])
+        );
     }
 }
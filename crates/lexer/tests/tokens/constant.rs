@@ -35,6 +35,9 @@ mod tests {
         assert_eq!(&format!("{}", Type::Str), "string");
         assert_eq!(&format!("{}", Type::Int), "integer");
         assert_eq!(&format!("{}", Type::Bool), "boolean");
+        assert_eq!(&format!("{}", Type::List), "list");
+        assert_eq!(&format!("{}", Type::Dict), "dictionary");
+        assert_eq!(&format!("{}", Type::Tuple), "tuple");
     }
 
     #[test]
@@ -93,6 +96,30 @@ mod tests {
                 token_type: TokenType::Type(Type::Bool),
             },)
         );
+        assert_eq!(
+            Type::get_token(location.clone(), &"list".chars().collect::<Vec<char>>()),
+            Some(Token {
+                location: location.clone(),
+                content: "list".to_owned(),
+                token_type: TokenType::Type(Type::List),
+            },)
+        );
+        assert_eq!(
+            Type::get_token(location.clone(), &"dict".chars().collect::<Vec<char>>()),
+            Some(Token {
+                location: location.clone(),
+                content: "dictionary".to_owned(),
+                token_type: TokenType::Type(Type::Dict),
+            },)
+        );
+        assert_eq!(
+            Type::get_token(location.clone(), &"tuple".chars().collect::<Vec<char>>()),
+            Some(Token {
+                location: location.clone(),
+                content: "tuple".to_owned(),
+                token_type: TokenType::Type(Type::Tuple),
+            },)
+        );
         assert_eq!(
             Type::get_token(location.clone(), &"/".chars().collect::<Vec<char>>()),
             None
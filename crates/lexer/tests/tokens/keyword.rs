@@ -42,6 +42,7 @@ mod tests {
     #[test]
     fn test_display() {
         assert_eq!(&format!("{}", Keyword::As), "as");
+        assert_eq!(&format!("{}", Keyword::Assert), "assert");
         assert_eq!(&format!("{}", Keyword::Break), "break");
         assert_eq!(&format!("{}", Keyword::Case), "case");
         assert_eq!(&format!("{}", Keyword::Catch), "catch");
@@ -50,19 +51,26 @@ mod tests {
         assert_eq!(&format!("{}", Keyword::Continue), "continue");
         assert_eq!(&format!("{}", Keyword::Default), "default");
         assert_eq!(&format!("{}", Keyword::Else), "else");
+        assert_eq!(&format!("{}", Keyword::Enum), "enum");
+        assert_eq!(&format!("{}", Keyword::Ensures), "ensures");
         assert_eq!(&format!("{}", Keyword::Finally), "finally");
         assert_eq!(&format!("{}", Keyword::For), "for");
         assert_eq!(&format!("{}", Keyword::Function), "function");
         assert_eq!(&format!("{}", Keyword::If), "if");
+        assert_eq!(&format!("{}", Keyword::Implements), "implements");
         assert_eq!(&format!("{}", Keyword::Import), "import");
+        assert_eq!(&format!("{}", Keyword::In), "in");
+        assert_eq!(&format!("{}", Keyword::Interface), "interface");
         assert_eq!(&format!("{}", Keyword::Match), "match");
         assert_eq!(&format!("{}", Keyword::Pub), "pub");
+        assert_eq!(&format!("{}", Keyword::Requires), "requires");
         assert_eq!(&format!("{}", Keyword::Return), "return");
         assert_eq!(&format!("{}", Keyword::Throw), "throw");
         assert_eq!(&format!("{}", Keyword::Try), "try");
         assert_eq!(&format!("{}", Keyword::Use), "use");
         assert_eq!(&format!("{}", Keyword::Var), "var");
         assert_eq!(&format!("{}", Keyword::While), "while");
+        assert_eq!(&format!("{}", Keyword::Yield), "yield");
     }
 
     #[test]
@@ -75,6 +83,7 @@ mod tests {
         };
 
         assert!(generate_test(&location, "as", Keyword::As));
+        assert!(generate_test(&location, "assert", Keyword::Assert));
         assert!(generate_test(&location, "break", Keyword::Break));
         assert!(generate_test(&location, "case", Keyword::Case));
         assert!(generate_test(&location, "catch", Keyword::Catch));
@@ -83,19 +92,26 @@ mod tests {
         assert!(generate_test(&location, "continue", Keyword::Continue));
         assert!(generate_test(&location, "default", Keyword::Default));
         assert!(generate_test(&location, "else", Keyword::Else));
+        assert!(generate_test(&location, "enum", Keyword::Enum));
+        assert!(generate_test(&location, "ensures", Keyword::Ensures));
         assert!(generate_test(&location, "finally", Keyword::Finally));
         assert!(generate_test(&location, "for", Keyword::For));
         assert!(generate_test(&location, "function", Keyword::Function));
         assert!(generate_test(&location, "if", Keyword::If));
+        assert!(generate_test(&location, "implements", Keyword::Implements));
         assert!(generate_test(&location, "import", Keyword::Import));
+        assert!(generate_test(&location, "in", Keyword::In));
+        assert!(generate_test(&location, "interface", Keyword::Interface));
         assert!(generate_test(&location, "match", Keyword::Match));
         assert!(generate_test(&location, "pub", Keyword::Pub));
+        assert!(generate_test(&location, "requires", Keyword::Requires));
         assert!(generate_test(&location, "return", Keyword::Return));
         assert!(generate_test(&location, "throw", Keyword::Throw));
         assert!(generate_test(&location, "try", Keyword::Try));
         assert!(generate_test(&location, "use", Keyword::Use));
         assert!(generate_test(&location, "var", Keyword::Var));
         assert!(generate_test(&location, "while", Keyword::While));
+        assert!(generate_test(&location, "yield", Keyword::Yield));
         assert_eq!(
             Keyword::get_token(location.clone(), &" ".chars().collect::<Vec<char>>()),
             None
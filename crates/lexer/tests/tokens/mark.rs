@@ -60,6 +60,7 @@ mod tests {
         assert_eq!(&format!("{}", Mark::BraceClose), "}");
         assert_eq!(&format!("{}", Mark::BracketOpen), "[");
         assert_eq!(&format!("{}", Mark::BracketClose), "]");
+        assert_eq!(&format!("{}", Mark::Coalesce), "??");
         assert_eq!(&format!("{}", Mark::Colon), ":");
         assert_eq!(&format!("{}", Mark::Comma), ",");
         assert_eq!(&format!("{}", Mark::Decrease), "--");
@@ -83,6 +84,8 @@ mod tests {
         assert_eq!(&format!("{}", Mark::ParenthesisClose), ")");
         assert_eq!(&format!("{}", Mark::QuestionMark), "?");
         assert_eq!(&format!("{}", Mark::Range), "..");
+        assert_eq!(&format!("{}", Mark::RangeInclusive), "..=");
+        assert_eq!(&format!("{}", Mark::SafeNavigation), "?.");
         assert_eq!(&format!("{}", Mark::Semicolon), ";");
         assert_eq!(&format!("{}", Mark::ShiftLeft), "<<");
         assert_eq!(&format!("{}", Mark::ShiftLeftAssign), "<<=");
@@ -120,6 +123,7 @@ mod tests {
         assert!(generate_test(&location, "}", Mark::BraceClose));
         assert!(generate_test(&location, "[", Mark::BracketOpen));
         assert!(generate_test(&location, "]", Mark::BracketClose));
+        assert!(generate_test(&location, "??", Mark::Coalesce));
         assert!(generate_test(&location, ":", Mark::Colon));
         assert!(generate_test(&location, ",", Mark::Comma));
         assert!(generate_test(&location, "--", Mark::Decrease));
@@ -143,6 +147,8 @@ mod tests {
         assert!(generate_test(&location, ")", Mark::ParenthesisClose));
         assert!(generate_test(&location, "?", Mark::QuestionMark));
         assert!(generate_test(&location, "..", Mark::Range));
+        assert!(generate_test(&location, "..=", Mark::RangeInclusive));
+        assert!(generate_test(&location, "?.", Mark::SafeNavigation));
         assert!(generate_test(&location, ";", Mark::Semicolon));
         assert!(generate_test(&location, "<<", Mark::ShiftLeft));
         assert!(generate_test(&location, "<<=", Mark::ShiftLeftAssign));
@@ -34,6 +34,10 @@ mod tests {
         use lexer::tokens::token::TypeDefinition;
 
         assert_eq!(&format!("{}", TypeDefinition::String), "string literal");
+        assert_eq!(
+            &format!("{}", TypeDefinition::FormatString),
+            "format string literal"
+        );
         assert_eq!(&format!("{}", TypeDefinition::Integer), "integer literal");
         assert_eq!(&format!("{}", TypeDefinition::True), "`true`");
         assert_eq!(&format!("{}", TypeDefinition::False), "`false`");
@@ -99,11 +103,26 @@ mod tests {
         assert_eq!(
             TypeDefinition::lex_string(&mut iterator, input, location.clone(), '\''),
             Ok(Token {
-                location,
+                location: location.clone(),
                 content: "my string".to_owned(),
                 token_type: TokenType::TypeDefinition(TypeDefinition::String)
             })
         );
+
+        // Empty strings and strings with a single character
+        for (input, content) in [("'", ""), ("a'", "a")] {
+            let mut iterator: std::iter::Peekable<std::iter::Enumerate<std::str::Chars>> =
+                input.chars().enumerate().peekable();
+
+            assert_eq!(
+                TypeDefinition::lex_string(&mut iterator, input, location.clone(), '\''),
+                Ok(Token {
+                    location: location.clone(),
+                    content: content.to_owned(),
+                    token_type: TokenType::TypeDefinition(TypeDefinition::String)
+                })
+            );
+        }
     }
 
     #[test]
@@ -125,11 +144,39 @@ mod tests {
         assert_eq!(
             TokenType::lex_mark(&mut iterator, input, location.clone(), '='),
             Ok(Some(Token {
-                location,
+                location: location.clone(),
                 content: "==".to_owned(),
                 token_type: TokenType::Mark(Mark::Equal)
             }))
         );
+
+        let input: &str = ".=";
+        let mut iterator: std::iter::Peekable<std::iter::Enumerate<std::str::Chars>> =
+            input.chars().enumerate().peekable();
+
+        assert_eq!(
+            TokenType::lex_mark(&mut iterator, input, location.clone(), '.'),
+            Ok(Some(Token {
+                location: location.clone(),
+                content: "..=".to_owned(),
+                token_type: TokenType::Mark(Mark::RangeInclusive)
+            }))
+        );
+
+        // Characters that do not form a mark together are separate marks
+        let input: &str = "-1]";
+        let mut iterator: std::iter::Peekable<std::iter::Enumerate<std::str::Chars>> =
+            input.chars().enumerate().peekable();
+
+        assert_eq!(
+            TokenType::lex_mark(&mut iterator, input, location.clone(), '['),
+            Ok(Some(Token {
+                location,
+                content: "[".to_owned(),
+                token_type: TokenType::Mark(Mark::BracketOpen)
+            }))
+        );
+        assert_eq!(iterator.next(), Some((0, '-')));
     }
 
     #[test]
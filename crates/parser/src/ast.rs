@@ -0,0 +1,909 @@
+//! The abstract syntax tree (AST) produced by the parser.
+// I Language abstract syntax tree.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use core::fmt::{self, Display};
+
+use lexer::tokens::mark::Mark;
+use lexer::tokens::token::{Location, TypeDefinition};
+
+
+/////////////
+// PROGRAM //
+/////////////
+
+/// The root node of the AST, representing a whole source file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Program {
+    /// The top level statements of the file, in source order.
+    pub statements: Vec<Statement>,
+    /// The location of the first token of the file.
+    pub location: Location,
+}
+
+/// A block of statements surrounded by braces (`{ ... }`).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Block {
+    /// The statements inside the block, in source order.
+    pub statements: Vec<Statement>,
+    /// The location of the opening brace.
+    pub location: Location,
+}
+
+/// A type annotation, for example the `int` in `int goal = 3;` or the `Box<int>` in `Box<int> box = Box(3);`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TypeAnnotation {
+    /// The name of the type. Built-in types use their short name (`int`, `str`, `bool`, `none`).
+    pub name: String,
+    /// The type arguments inside angle brackets (`<...>`), empty if there are none.
+    pub arguments: Vec<TypeAnnotation>,
+    /// Whether the type is followed by `?`, which makes it accept `none`, e.g. `int?`.
+    pub nullable: bool,
+    /// The location of the type name.
+    pub location: Location,
+}
+
+impl Display for TypeAnnotation {
+    /// Formats the type with its type arguments, e.g. `Box<list<int>>` or `int?`. Function types list their parameter
+    /// types followed by the return type, e.g. `function(int, str) -> bool`.
+    #[inline]
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((result, parameters)) = self
+            .arguments
+            .split_last()
+            .filter(|_| self.name == "function")
+        {
+            write!(formatter, "function(")?;
+            for (index, parameter) in parameters.iter().enumerate() {
+                if index > 0 {
+                    write!(formatter, ", ")?;
+                }
+                write!(formatter, "{parameter}")?;
+            }
+            return write!(formatter, ") -> {result}");
+        }
+
+        write!(formatter, "{}", self.name)?;
+        if let Some((first, rest)) = self.arguments.split_first() {
+            write!(formatter, "<{first}")?;
+            for argument in rest {
+                write!(formatter, ", {argument}")?;
+            }
+            write!(formatter, ">")?;
+        }
+        if self.nullable {
+            write!(formatter, "?")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A type parameter of a generic class or function, e.g. the `T: Animal` in `class Cage<T: Animal> { ... }`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TypeParameter {
+    /// The name of the type parameter.
+    pub name: Identifier,
+    /// The class the type arguments must be or inherit from, if any.
+    pub bound: Option<TypeAnnotation>,
+    /// The location of the name.
+    pub location: Location,
+}
+
+/// A name referring to a variable, function, class or module, e.g. `goal`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Identifier {
+    /// The name itself.
+    pub name: String,
+    /// The location of the name.
+    pub location: Location,
+}
+
+
+////////////////
+// STATEMENTS //
+////////////////
+
+/// The different kinds of statements.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Statement {
+    /// An `assert` statement, e.g. `assert guess > 0, "the guess is positive";`.
+    Assert(Assert),
+    /// A nested block, e.g. `{ print("Hello"); }`.
+    Block(Block),
+    /// A `break;` statement.
+    Break(Break),
+    /// A class declaration, e.g. `class Player: Entity { ... }`.
+    Class(Class),
+    /// A `continue;` statement.
+    Continue(Continue),
+    /// An expression followed by a semicolon, e.g. `print("Hello");`.
+    Expression(ExpressionStatement),
+    /// A `for` loop, e.g. `for (int i in 0 .. 10) { ... }`.
+    For(Box<For>),
+    /// A function declaration, e.g. `function add(int a, int b) -> int { ... }`.
+    Function(Function),
+    /// An `if` statement with an optional `else` block.
+    If(If),
+    /// An `import` statement, e.g. `import random;`.
+    Import(Import),
+    /// A `match` statement, e.g. `match (guess) { case 0 { ... } default { ... } }`.
+    Match(Match),
+    /// A `return` statement with an optional value.
+    Return(Return),
+    /// A `throw` statement, e.g. `throw ValueError("negative");`.
+    Throw(Throw),
+    /// A `try` statement with `catch` clauses and an optional `finally` block.
+    Try(Try),
+    /// A `use` statement, e.g. `use random.randint as random_integer;`.
+    Use(Use),
+    /// A variable declaration, e.g. `int goal = 3;`.
+    VariableDeclaration(VariableDeclaration),
+    /// A `while` loop.
+    While(While),
+    /// A `yield` statement, e.g. `yield line;`, which makes its function a generator.
+    Yield(Yield),
+}
+
+impl Statement {
+    /// Returns the location of the statement.
+    #[inline]
+    #[allow(clippy::pattern_type_mismatch)]
+    pub const fn location(&self) -> &Location {
+        match self {
+            Self::Assert(node) => &node.location,
+            Self::Block(node) => &node.location,
+            Self::Break(node) => &node.location,
+            Self::Class(node) => &node.location,
+            Self::Continue(node) => &node.location,
+            Self::Expression(node) => &node.location,
+            Self::For(node) => &node.location,
+            Self::Function(node) => &node.location,
+            Self::If(node) => &node.location,
+            Self::Import(node) => &node.location,
+            Self::Match(node) => &node.location,
+            Self::Return(node) => &node.location,
+            Self::Throw(node) => &node.location,
+            Self::Try(node) => &node.location,
+            Self::Use(node) => &node.location,
+            Self::VariableDeclaration(node) => &node.location,
+            Self::While(node) => &node.location,
+            Self::Yield(node) => &node.location,
+        }
+    }
+}
+
+/// An `assert` statement, e.g. `assert guess > 0, "the guess is positive";`. Throws an `AssertionError` if the
+/// condition is false. Assertions are only checked in debug builds.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Assert {
+    /// The checked condition.
+    pub condition: Expression,
+    /// The message after the comma, if any.
+    pub message: Option<Expression>,
+    /// The location of the `assert` keyword.
+    pub location: Location,
+}
+
+/// A `break;` statement, exiting the innermost loop.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Break {
+    /// The location of the `break` keyword.
+    pub location: Location,
+}
+
+/// A `continue;` statement, skipping to the next iteration of the innermost loop.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Continue {
+    /// The location of the `continue` keyword.
+    pub location: Location,
+}
+
+/// An expression used as a statement, e.g. `print("Hello");`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExpressionStatement {
+    /// The expression to evaluate.
+    pub expression: Expression,
+    /// The location of the start of the expression.
+    pub location: Location,
+}
+
+/// A `for` loop, e.g. `for (int i in 0 .. 10) { ... }` or `for (var item in items) { ... }`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct For {
+    /// The declared type of the loop variable, `None` if it is declared with `var`.
+    pub type_annotation: Option<TypeAnnotation>,
+    /// The loop variable, bound to the next value before every iteration.
+    pub variable: Identifier,
+    /// The values the loop iterates over.
+    pub iterable: ForIterable,
+    /// The loop body.
+    pub body: Block,
+    /// The location of the `for` keyword.
+    pub location: Location,
+}
+
+/// The values a `for` loop iterates over.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ForIterable {
+    /// A range of integers, e.g. `0 .. 10`.
+    Range(Box<ForRange>),
+    /// A value that can be iterated, e.g. a `list` or an instance of a class implementing `Iterable`.
+    Value(Box<Expression>),
+}
+
+/// A range of integers a `for` loop iterates over, e.g. `0 .. 10`, `1 ..= 10` or `10 .. 0 step -2`.
+/// The loop counts from the start to the end, the values are never stored.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForRange {
+    /// The first value.
+    pub start: Expression,
+    /// The end of the range.
+    pub end: Expression,
+    /// Whether the end is part of the range, `..=` instead of `..`.
+    pub inclusive: bool,
+    /// The difference between two values, counting down if negative. `1` if it is not given.
+    pub step: Option<Expression>,
+    /// The location of the start of the range.
+    pub location: Location,
+}
+
+/// An `if` statement, e.g. `if (guess > goal) { ... } else { ... }`.
+/// An `else if` is represented as an `else` block containing only the nested `if`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct If {
+    /// The condition inside the parentheses.
+    pub condition: Expression,
+    /// The block executed when the condition is true.
+    pub then_block: Block,
+    /// The block executed when the condition is false, if any.
+    pub else_block: Option<Block>,
+    /// The location of the `if` keyword.
+    pub location: Location,
+}
+
+/// An `import` statement, e.g. `import foo.bar as baz;`. Binds the module to its last name or the alias.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Import {
+    /// The names of the module path, e.g. `foo` and `bar` for `foo.bar`. Has at least one name.
+    pub path: Vec<Identifier>,
+    /// The name after `as`, if any.
+    pub alias: Option<Identifier>,
+    /// The location of the `import` keyword.
+    pub location: Location,
+}
+
+/// A `return` statement, e.g. `return 0;`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Return {
+    /// The returned value, if any.
+    pub value: Option<Expression>,
+    /// The location of the `return` keyword.
+    pub location: Location,
+}
+
+/// A `throw` statement, e.g. `throw ValueError("negative");`. Only exceptions can be thrown.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Throw {
+    /// The thrown exception.
+    pub value: Expression,
+    /// The location of the `throw` keyword.
+    pub location: Location,
+}
+
+/// A `yield` statement, e.g. `yield line;`. Produces the next value of the generator the function returns and
+/// suspends the function until the value after it is needed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Yield {
+    /// The produced value.
+    pub value: Expression,
+    /// The location of the `yield` keyword.
+    pub location: Location,
+}
+
+/// A `try` statement, e.g. `try { ... } catch TypeError(error) { ... } finally { ... }`.
+/// Has at least one `catch` clause or a `finally` block.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Try {
+    /// The block whose exceptions are caught.
+    pub body: Block,
+    /// The `catch` clauses, in source order. The first clause matching the exception is executed.
+    pub catches: Vec<Catch>,
+    /// The block executed after the body and the catch clause, however they are left.
+    pub finally: Option<Block>,
+    /// The location of the `try` keyword.
+    pub location: Location,
+}
+
+/// A `catch` clause of a [`Try`], e.g. `catch TypeError(error) { ... }`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Catch {
+    /// The caught exception class. Subclasses are caught too.
+    pub exception: TypeAnnotation,
+    /// The name the caught exception is bound to, if any.
+    pub binding: Option<Identifier>,
+    /// The block executed when the exception is caught.
+    pub body: Block,
+    /// The location of the `catch` keyword.
+    pub location: Location,
+}
+
+/// A `use` statement, e.g. `use foo.bar.item as alias;`. Binds a single public item of a module to its name or the alias.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Use {
+    /// The names of the module path followed by the name of the item. Has at least two names.
+    pub path: Vec<Identifier>,
+    /// The name after `as`, if any.
+    pub alias: Option<Identifier>,
+    /// The location of the `use` keyword.
+    pub location: Location,
+}
+
+/// A variable declaration, e.g. `int goal = 3;`, `int goal;` or `var goal = 3;`, or a constant declaration,
+/// e.g. `const int MAX = 25 * 4;`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VariableDeclaration {
+    /// Whether the declaration is marked with `const`. Constants always have a value, which is evaluated at
+    /// compile time.
+    pub constant: bool,
+    /// The declared type of the variable, `None` if it is declared with `var` and its type is inferred.
+    pub type_annotation: Option<TypeAnnotation>,
+    /// The name of the variable.
+    pub name: Identifier,
+    /// The initial value, if any.
+    pub value: Option<Expression>,
+    /// The location of the start of the declaration.
+    pub location: Location,
+}
+
+/// A `while` loop, e.g. `while (guess != goal) { ... }`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct While {
+    /// The condition checked before every iteration.
+    pub condition: Expression,
+    /// The loop body.
+    pub body: Block,
+    /// The location of the `while` keyword.
+    pub location: Location,
+}
+
+
+//////////////////
+// DECLARATIONS //
+//////////////////
+
+/// A class declaration, e.g. `pub class Player: Entity implements Drawable { ... }`, an interface declaration,
+/// e.g. `interface Drawable { function draw() -> none; }`, or an enum declaration, e.g.
+/// `enum Shape { Circle(int radius), Empty; function area() -> int { ... } }`.
+///
+/// The variants of an enum are classes extending the enum, named after it, e.g. `Shape.Circle`. Their fields are
+/// the data of the variant and their constructor has no body, it assigns its arguments to the fields with the same
+/// names.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Class {
+    /// The annotations before the class, e.g. `@deprecated("Use Vector instead")`.
+    pub annotations: Vec<Annotation>,
+    /// Whether the class is marked with `pub`.
+    pub public: bool,
+    /// Whether this is an interface, declared with `interface` instead of `class`.
+    pub interface: bool,
+    /// Whether this is an enum, declared with `enum` instead of `class`.
+    pub enumeration: bool,
+    /// The name of the class.
+    pub name: Identifier,
+    /// The type parameters inside angle brackets (`<...>`), empty if the class is not generic.
+    pub type_parameters: Vec<TypeParameter>,
+    /// The types listed after the colon. Only a single base class is allowed, an interface may extend several
+    /// interfaces.
+    pub bases: Vec<TypeAnnotation>,
+    /// The interfaces listed after `implements`.
+    pub interfaces: Vec<TypeAnnotation>,
+    /// The variants of an enum, in declaration order. Empty for classes and interfaces.
+    pub variants: Vec<Class>,
+    /// The fields, methods and constructors of the class, in source order.
+    pub members: Vec<ClassMember>,
+    /// The location of the `class`, `interface` or `enum` keyword, or of `pub` if the class is public.
+    /// The location of the name for variants.
+    pub location: Location,
+}
+
+/// The different kinds of class members.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ClassMember {
+    /// A constructor, declared with the name of the class, e.g. `Player(str name) { ... }`.
+    Constructor(Function),
+    /// A field, e.g. `int health = 100;`.
+    Field(Field),
+    /// A method, e.g. `function jump() { ... }`.
+    Method(Function),
+}
+
+/// A field of a class, e.g. `pub int health = 100;`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Field {
+    /// The annotations before the field.
+    pub annotations: Vec<Annotation>,
+    /// Whether the field is marked with `pub`.
+    pub public: bool,
+    /// The declared type of the field.
+    pub type_annotation: TypeAnnotation,
+    /// The name of the field.
+    pub name: Identifier,
+    /// The default value of the field, if any.
+    pub value: Option<Expression>,
+    /// The location of the start of the field.
+    pub location: Location,
+}
+
+/// A function, method or constructor, e.g. `function jump(int height = 1) -> bool { ... }`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Function {
+    /// The annotations before the function, e.g. `@test` or `@inline`.
+    pub annotations: Vec<Annotation>,
+    /// Whether the function is marked with `pub`.
+    pub public: bool,
+    /// The name of the function. For constructors, this is the name of the class.
+    pub name: Identifier,
+    /// The type parameters inside angle brackets (`<...>`), empty if the function is not generic.
+    /// Constructors use the type parameters of their class.
+    pub type_parameters: Vec<TypeParameter>,
+    /// The parameters of the function, in source order.
+    pub parameters: Vec<Parameter>,
+    /// The return type after the arrow (`->`), if any.
+    pub return_type: Option<TypeAnnotation>,
+    /// The conditions of the `requires` clauses, which the arguments have to fulfill when the function is called.
+    pub requires: Vec<Expression>,
+    /// The conditions of the `ensures` clauses, which the function fulfills when it returns. They refer to the
+    /// returned value as `result`.
+    pub ensures: Vec<Expression>,
+    /// The body of the function. Methods without a body (`function area() -> int;`) are abstract.
+    pub body: Option<Block>,
+    /// The location of the start of the function.
+    pub location: Location,
+}
+
+/// An annotation of a function, class or field, e.g. `@deprecated("Use jump instead")`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Annotation {
+    /// The name of the annotation after the `@` mark.
+    pub name: Identifier,
+    /// The arguments inside parentheses, empty if there are none.
+    pub arguments: Vec<Argument>,
+    /// The location of the `@` mark.
+    pub location: Location,
+}
+
+/// A parameter of a function, e.g. `int height` or `str name = "Player"`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Parameter {
+    /// The declared type of the parameter.
+    pub type_annotation: TypeAnnotation,
+    /// The name of the parameter.
+    pub name: Identifier,
+    /// The default value used when no argument is given, if any.
+    pub default: Option<Expression>,
+    /// The location of the start of the parameter.
+    pub location: Location,
+}
+
+
+/////////////////
+// EXPRESSIONS //
+/////////////////
+
+/// The different kinds of expressions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Expression {
+    /// An assignment, e.g. `guess = 0` or `guess += 1`.
+    Assignment(Assignment),
+    /// A binary operation, e.g. `guess <= 0`.
+    Binary(Binary),
+    /// A call, e.g. `print("Hello")`.
+    Call(Call),
+    /// A dict literal, e.g. `{"a": 1}`.
+    Dict(Dict),
+    /// A format string, e.g. `f"{guess} is too high"`.
+    FormatString(FormatString),
+    /// A name, e.g. `goal`.
+    Identifier(Identifier),
+    /// An index operation, e.g. `items[0]`.
+    Index(Index),
+    /// A lambda, e.g. `(int x) -> x * 2` or `function (int x) -> int { return x * 2; }`.
+    Lambda(Lambda),
+    /// A list literal, e.g. `[1, 2, 3]`.
+    List(List),
+    /// A literal, e.g. `1`, `"Hello"` or `true`.
+    Literal(Literal),
+    /// A `match` expression, e.g. `match (guess) { case 0 -> "zero", default -> "other" }`.
+    Match(Match),
+    /// A member access, e.g. `random.randint`.
+    Member(Member),
+    /// A slice, e.g. `items[1 .. 3]`.
+    Slice(Slice),
+    /// A tuple literal, e.g. `(1, "x")`.
+    Tuple(Tuple),
+    /// A unary operation, e.g. `!done` or `counter++`.
+    Unary(Unary),
+}
+
+impl Expression {
+    /// Returns the location of the expression.
+    #[inline]
+    #[allow(clippy::pattern_type_mismatch)]
+    pub const fn location(&self) -> &Location {
+        match self {
+            Self::Assignment(node) => &node.location,
+            Self::Binary(node) => &node.location,
+            Self::Call(node) => &node.location,
+            Self::Dict(node) => &node.location,
+            Self::FormatString(node) => &node.location,
+            Self::Identifier(node) => &node.location,
+            Self::Index(node) => &node.location,
+            Self::Lambda(node) => &node.location,
+            Self::List(node) => &node.location,
+            Self::Literal(node) => &node.location,
+            Self::Match(node) => &node.location,
+            Self::Member(node) => &node.location,
+            Self::Slice(node) => &node.location,
+            Self::Tuple(node) => &node.location,
+            Self::Unary(node) => &node.location,
+        }
+    }
+}
+
+/// An assignment, e.g. `guess = 0`. Compound assignments (`+=`, `-=`, etc.) store their mark as the operator.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Assignment {
+    /// The assigned place. Either an identifier, a member access or an index operation.
+    pub target: Box<Expression>,
+    /// The assignment mark, e.g. [`Mark::Assign`] or [`Mark::AddAssign`].
+    pub operator: Mark,
+    /// The assigned value.
+    pub value: Box<Expression>,
+    /// The location of the start of the target.
+    pub location: Location,
+}
+
+/// A binary operation, e.g. `guess <= 0`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Binary {
+    /// The left operand.
+    pub left: Box<Expression>,
+    /// The operator mark, e.g. [`Mark::LessEqual`].
+    pub operator: Mark,
+    /// The right operand.
+    pub right: Box<Expression>,
+    /// The location of the operator.
+    pub location: Location,
+}
+
+/// A call, e.g. `random.randint(0, 25)`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Call {
+    /// The called expression.
+    pub callee: Box<Expression>,
+    /// The arguments, in source order.
+    pub arguments: Vec<Argument>,
+    /// The location of the opening parenthesis.
+    pub location: Location,
+}
+
+/// An argument of a call, either positional (`25`) or named (`maximum = 25`).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Argument {
+    /// The name of the parameter for keyword arguments.
+    pub name: Option<Identifier>,
+    /// The value of the argument.
+    pub value: Expression,
+    /// The location of the start of the argument.
+    pub location: Location,
+}
+
+/// A dict literal, e.g. `{"a": 1, "b": 2}`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dict {
+    /// The entries, in source order.
+    pub entries: Vec<DictEntry>,
+    /// The location of the opening brace.
+    pub location: Location,
+}
+
+/// An entry of a dict literal, e.g. `"a": 1`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DictEntry {
+    /// The key before the colon.
+    pub key: Expression,
+    /// The value after the colon.
+    pub value: Expression,
+    /// The location of the start of the key.
+    pub location: Location,
+}
+
+/// A format string, a string literal with expressions in braces whose values are inserted into the text, e.g.
+/// `f"{guess} is not in the range of 0 to {maximum}."`. Doubled braces stand for a single brace.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FormatString {
+    /// The text and the fields, in source order.
+    pub parts: Vec<FormatPart>,
+    /// The location of the `f` before the opening quote.
+    pub location: Location,
+}
+
+/// A part of a [`FormatString`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FormatPart {
+    /// Text as written in the source, with doubled braces replaced by a single brace, e.g. ` is too high`.
+    Text(String),
+    /// An expression in braces, e.g. `{guess}` or `{name:>10}`.
+    Field(Box<FormatField>),
+}
+
+/// An expression in braces of a [`FormatString`], e.g. `{guess}` or `{price:>8}`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FormatField {
+    /// The expression whose value is inserted.
+    pub value: Expression,
+    /// The format specification after the colon, e.g. `>8`, if any.
+    pub specification: Option<String>,
+    /// The location of the opening brace.
+    pub location: Location,
+}
+
+/// An index operation, e.g. `items[0]`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Index {
+    /// The indexed expression.
+    pub target: Box<Expression>,
+    /// The index inside the brackets.
+    pub index: Box<Expression>,
+    /// The location of the opening bracket.
+    pub location: Location,
+}
+
+/// A lambda, a function without a name that can use the variables around it, e.g. `(int x) -> x * 2`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Lambda {
+    /// The parameters of the lambda, in source order.
+    pub parameters: Vec<Parameter>,
+    /// The return type of a lambda with a block, if any. Lambdas with an expression return its value.
+    pub return_type: Option<TypeAnnotation>,
+    /// The body of the lambda.
+    pub body: LambdaBody,
+    /// The location of the start of the lambda.
+    pub location: Location,
+}
+
+/// The body of a [`Lambda`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LambdaBody {
+    /// A block, e.g. `(int x) -> { print(x); }`.
+    Block(Block),
+    /// An expression whose value is returned, e.g. `(int x) -> x * 2`.
+    Expression(Box<Expression>),
+}
+
+/// A list literal, e.g. `[1, 2, 3]`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct List {
+    /// The elements, in source order.
+    pub elements: Vec<Expression>,
+    /// The location of the opening bracket.
+    pub location: Location,
+}
+
+/// A literal, e.g. `1`, `"Hello"`, `true` or `none`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Literal {
+    /// The kind of the literal.
+    pub kind: TypeDefinition,
+    /// The content of the literal as written in the source. String literals do not include the quotes.
+    pub value: String,
+    /// The location of the literal.
+    pub location: Location,
+}
+
+/// A member access, e.g. `random.randint`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Member {
+    /// The expression whose member is accessed.
+    pub target: Box<Expression>,
+    /// The name of the member.
+    pub member: Identifier,
+    /// Whether the member is accessed with `?.`, which results in `none` instead of an error if the target is `none`.
+    pub safe: bool,
+    /// The location of the dot.
+    pub location: Location,
+}
+
+/// A slice of a list, a tuple or a string, e.g. `items[1 .. 3]`, `items[.. 3]` or `items[1 ..]`. The end is
+/// excluded, missing bounds are the start and the end of the sliced value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Slice {
+    /// The sliced expression.
+    pub target: Box<Expression>,
+    /// The index of the first element, `None` to start at the first element.
+    pub start: Option<Box<Expression>>,
+    /// The index after the last element, `None` to end at the last element.
+    pub end: Option<Box<Expression>>,
+    /// The location of the opening bracket.
+    pub location: Location,
+}
+
+/// A tuple literal, e.g. `(1, "x")`. A tuple with a single element needs a trailing comma, e.g. `(1,)`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tuple {
+    /// The elements, in source order.
+    pub elements: Vec<Expression>,
+    /// The location of the opening parenthesis.
+    pub location: Location,
+}
+
+/// A unary operation, e.g. `!done`, `-value`, `++counter` or `counter++`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Unary {
+    /// The operator mark, e.g. [`Mark::Bang`] or [`Mark::Increase`].
+    pub operator: Mark,
+    /// The operand.
+    pub operand: Box<Expression>,
+    /// Whether the operator is written after the operand (`counter++`).
+    pub postfix: bool,
+    /// The location of the operator.
+    pub location: Location,
+}
+
+
+//////////////
+// PATTERNS //
+//////////////
+
+/// A `match` statement or expression, e.g. `match (guess) { case 1 | 2 { ... } default { ... } }`.
+/// The first case whose pattern matches (and whose guard is true) is executed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Match {
+    /// The matched value inside the parentheses.
+    pub subject: Box<Expression>,
+    /// The cases, in source order.
+    pub arms: Vec<MatchArm>,
+    /// The location of the `match` keyword.
+    pub location: Location,
+}
+
+/// A single case of a [`Match`], e.g. `case 1 .. 10 if (lucky) { ... }` or `default -> "other"`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchArm {
+    /// The pattern after `case`, or `None` for the `default` case.
+    pub pattern: Option<Pattern>,
+    /// The condition after `if`, if any.
+    pub guard: Option<Expression>,
+    /// The code executed when the case matches.
+    pub body: MatchBody,
+    /// The location of the `case` or `default` keyword.
+    pub location: Location,
+}
+
+/// The body of a [`MatchArm`]. Match expressions only allow expressions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MatchBody {
+    /// A block, e.g. `case 0 { print("zero"); }`.
+    Block(Block),
+    /// An expression after an arrow, e.g. `case 0 -> "zero"`.
+    Expression(Expression),
+}
+
+/// The different kinds of patterns.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Pattern {
+    /// Alternatives separated by `|`, e.g. `1 | 2 | 3`.
+    Alternative(AlternativePattern),
+    /// A name binding the matched value, optionally checking its type, e.g. `value`, `_` or `Player player`.
+    Binding(BindingPattern),
+    /// A class destructuring its fields in declaration order, e.g. `Point(x, 0)`.
+    Class(ClassPattern),
+    /// A literal, e.g. `1`, `-1`, `"yes"` or `true`.
+    Literal(Literal),
+    /// A range of integers, e.g. `1 .. 10` or `0 .. MAX`. The end is excluded.
+    Range(RangePattern),
+}
+
+impl Pattern {
+    /// Returns the location of the pattern.
+    #[inline]
+    #[allow(clippy::pattern_type_mismatch)]
+    pub const fn location(&self) -> &Location {
+        match self {
+            Self::Alternative(node) => &node.location,
+            Self::Binding(node) => &node.location,
+            Self::Class(node) => &node.location,
+            Self::Literal(node) => &node.location,
+            Self::Range(node) => &node.location,
+        }
+    }
+}
+
+/// Alternative patterns separated by `|`, e.g. `1 | 2 | 3`. Matches if any of the patterns matches.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AlternativePattern {
+    /// The alternatives, in source order.
+    pub patterns: Vec<Pattern>,
+    /// The location of the first alternative.
+    pub location: Location,
+}
+
+/// A pattern binding the matched value to a name, e.g. `value` or `Player player`.
+/// The name `_` matches anything without binding it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BindingPattern {
+    /// The type the value must have, if any.
+    pub type_annotation: Option<TypeAnnotation>,
+    /// The bound name.
+    pub name: Identifier,
+    /// The location of the start of the pattern.
+    pub location: Location,
+}
+
+/// A pattern matching instances of a class and their fields, e.g. `Point(x, 0)`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClassPattern {
+    /// The matched class.
+    pub class: TypeAnnotation,
+    /// The patterns for the fields of the class, in declaration order, inherited fields first.
+    pub fields: Vec<Pattern>,
+    /// The location of the class name.
+    pub location: Location,
+}
+
+/// A pattern matching a range of integers, e.g. `1 .. 10` or `0 .. MAX`. The end is excluded.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RangePattern {
+    /// The first matched integer.
+    pub start: RangeBound,
+    /// The first integer after the range.
+    pub end: RangeBound,
+    /// The location of the start.
+    pub location: Location,
+}
+
+/// A bound of a range pattern.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RangeBound {
+    /// An integer literal, e.g. `-1`.
+    Literal(Literal),
+    /// The name of a constant, e.g. `MAX`. It is replaced with its value before the program is checked.
+    Constant(Identifier),
+}
+
+impl RangeBound {
+    /// Returns the location of the bound.
+    #[inline]
+    #[allow(clippy::pattern_type_mismatch)]
+    pub const fn location(&self) -> &Location {
+        match self {
+            Self::Literal(node) => &node.location,
+            Self::Constant(node) => &node.location,
+        }
+    }
+}
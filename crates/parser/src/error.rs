@@ -0,0 +1,85 @@
+//! Errors that may occur while parsing.
+// I Language parser errors.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use lexer::tokens::token::Location;
+
+use thiserror::Error;
+
+
+////////////
+// ERRORS //
+////////////
+
+/// The different kinds of errors the parser can raise.
+/// All of these errors implement [`std::error::Error`].
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Eq, Error, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ParserError {
+    /// An error which will be returned if the parser found a token it did not expect at this position.
+    #[error("expected {expected}, found `{found}`")]
+    UnexpectedToken {
+        expected: String,
+        found: String,
+        location: Location,
+    },
+
+    /// An error which will be returned if the input ended while the parser still expected more tokens.
+    #[error("expected {expected}, found end of input")]
+    UnexpectedEndOfInput {
+        expected: String,
+        location: Location,
+    },
+
+    /// An error which will be returned if the left side of an assignment is not assignable, e.g. `1 = 2`.
+    #[error("invalid assignment target")]
+    InvalidAssignmentTarget { location: Location },
+
+    /// An error which will be returned if the braces of a format string are invalid, e.g. `f"{}"` or `f"}"`.
+    #[error("invalid format string: {reason}")]
+    InvalidFormatString { reason: String, location: Location },
+
+    /// An error which will be returned if the code is nested deeper than the parser allows, e.g. in `((((1))))` with
+    /// thousands of parentheses.
+    #[error("the code is nested too deeply, the limit is {limit} levels")]
+    NestedTooDeeply { limit: usize, location: Location },
+}
+
+impl ParserError {
+    /// Returns the location the error occurred at.
+    #[inline]
+    #[allow(clippy::pattern_type_mismatch)]
+    pub const fn location(&self) -> &Location {
+        match self {
+            Self::UnexpectedToken { location, .. }
+            | Self::UnexpectedEndOfInput { location, .. }
+            | Self::InvalidAssignmentTarget { location }
+            | Self::InvalidFormatString { location, .. }
+            | Self::NestedTooDeeply { location, .. } => location,
+        }
+    }
+}
@@ -0,0 +1,1146 @@
+//! A folder consuming the AST and building a new one, allowing nodes to be replaced.
+// I Language AST folder.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+// This file is generated by `tools/generate_visitors.py` from `ast.rs`. Do not edit it by hand,
+// instead change the AST and run `just generate-visitors`.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use crate::ast::{
+    AlternativePattern, Annotation, Argument, Assert, Assignment, Binary, BindingPattern, Block,
+    Break, Call, Catch, Class, ClassMember, ClassPattern, Continue, Dict, DictEntry, Expression,
+    ExpressionStatement, Field, For, ForIterable, ForRange, FormatField, FormatPart, FormatString,
+    Function, Identifier, If, Import, Index, Lambda, LambdaBody, List, Literal, Match, MatchArm,
+    MatchBody, Member, Parameter, Pattern, Program, RangeBound, RangePattern, Return, Slice,
+    Statement, Throw, Try, Tuple, TypeAnnotation, TypeParameter, Unary, Use, VariableDeclaration,
+    While, Yield,
+};
+
+
+///////////
+// TRAIT //
+///////////
+
+/// A folder consuming the AST and returning a new one. Every method defaults to folding the children of the node.
+/// Override a method to handle a node type and call the matching `walk_*` function to continue with its children.
+pub trait Fold {
+    /// Folds an [`AlternativePattern`] node. Defaults to [`walk_alternative_pattern`].
+    #[inline]
+    fn fold_alternative_pattern(&mut self, node: AlternativePattern) -> AlternativePattern {
+        walk_alternative_pattern(self, node)
+    }
+
+    /// Folds an [`Annotation`] node. Defaults to [`walk_annotation`].
+    #[inline]
+    fn fold_annotation(&mut self, node: Annotation) -> Annotation {
+        walk_annotation(self, node)
+    }
+
+    /// Folds an [`Argument`] node. Defaults to [`walk_argument`].
+    #[inline]
+    fn fold_argument(&mut self, node: Argument) -> Argument {
+        walk_argument(self, node)
+    }
+
+    /// Folds an [`Assert`] node. Defaults to [`walk_assert`].
+    #[inline]
+    fn fold_assert(&mut self, node: Assert) -> Assert {
+        walk_assert(self, node)
+    }
+
+    /// Folds an [`Assignment`] node. Defaults to [`walk_assignment`].
+    #[inline]
+    fn fold_assignment(&mut self, node: Assignment) -> Assignment {
+        walk_assignment(self, node)
+    }
+
+    /// Folds a [`Binary`] node. Defaults to [`walk_binary`].
+    #[inline]
+    fn fold_binary(&mut self, node: Binary) -> Binary {
+        walk_binary(self, node)
+    }
+
+    /// Folds a [`BindingPattern`] node. Defaults to [`walk_binding_pattern`].
+    #[inline]
+    fn fold_binding_pattern(&mut self, node: BindingPattern) -> BindingPattern {
+        walk_binding_pattern(self, node)
+    }
+
+    /// Folds a [`Block`] node. Defaults to [`walk_block`].
+    #[inline]
+    fn fold_block(&mut self, node: Block) -> Block {
+        walk_block(self, node)
+    }
+
+    /// Folds a [`Break`] node. Defaults to [`walk_break`].
+    #[inline]
+    fn fold_break(&mut self, node: Break) -> Break {
+        walk_break(self, node)
+    }
+
+    /// Folds a [`Call`] node. Defaults to [`walk_call`].
+    #[inline]
+    fn fold_call(&mut self, node: Call) -> Call {
+        walk_call(self, node)
+    }
+
+    /// Folds a [`Catch`] node. Defaults to [`walk_catch`].
+    #[inline]
+    fn fold_catch(&mut self, node: Catch) -> Catch {
+        walk_catch(self, node)
+    }
+
+    /// Folds a [`Class`] node. Defaults to [`walk_class`].
+    #[inline]
+    fn fold_class(&mut self, node: Class) -> Class {
+        walk_class(self, node)
+    }
+
+    /// Folds a [`ClassMember`] node. Defaults to [`walk_class_member`].
+    #[inline]
+    fn fold_class_member(&mut self, node: ClassMember) -> ClassMember {
+        walk_class_member(self, node)
+    }
+
+    /// Folds a [`ClassPattern`] node. Defaults to [`walk_class_pattern`].
+    #[inline]
+    fn fold_class_pattern(&mut self, node: ClassPattern) -> ClassPattern {
+        walk_class_pattern(self, node)
+    }
+
+    /// Folds a [`Continue`] node. Defaults to [`walk_continue`].
+    #[inline]
+    fn fold_continue(&mut self, node: Continue) -> Continue {
+        walk_continue(self, node)
+    }
+
+    /// Folds a [`Dict`] node. Defaults to [`walk_dict`].
+    #[inline]
+    fn fold_dict(&mut self, node: Dict) -> Dict {
+        walk_dict(self, node)
+    }
+
+    /// Folds a [`DictEntry`] node. Defaults to [`walk_dict_entry`].
+    #[inline]
+    fn fold_dict_entry(&mut self, node: DictEntry) -> DictEntry {
+        walk_dict_entry(self, node)
+    }
+
+    /// Folds an [`Expression`] node. Defaults to [`walk_expression`].
+    #[inline]
+    fn fold_expression(&mut self, node: Expression) -> Expression {
+        walk_expression(self, node)
+    }
+
+    /// Folds an [`ExpressionStatement`] node. Defaults to [`walk_expression_statement`].
+    #[inline]
+    fn fold_expression_statement(&mut self, node: ExpressionStatement) -> ExpressionStatement {
+        walk_expression_statement(self, node)
+    }
+
+    /// Folds a [`Field`] node. Defaults to [`walk_field`].
+    #[inline]
+    fn fold_field(&mut self, node: Field) -> Field {
+        walk_field(self, node)
+    }
+
+    /// Folds a [`For`] node. Defaults to [`walk_for`].
+    #[inline]
+    fn fold_for(&mut self, node: For) -> For {
+        walk_for(self, node)
+    }
+
+    /// Folds a [`ForIterable`] node. Defaults to [`walk_for_iterable`].
+    #[inline]
+    fn fold_for_iterable(&mut self, node: ForIterable) -> ForIterable {
+        walk_for_iterable(self, node)
+    }
+
+    /// Folds a [`ForRange`] node. Defaults to [`walk_for_range`].
+    #[inline]
+    fn fold_for_range(&mut self, node: ForRange) -> ForRange {
+        walk_for_range(self, node)
+    }
+
+    /// Folds a [`FormatField`] node. Defaults to [`walk_format_field`].
+    #[inline]
+    fn fold_format_field(&mut self, node: FormatField) -> FormatField {
+        walk_format_field(self, node)
+    }
+
+    /// Folds a [`FormatPart`] node. Defaults to [`walk_format_part`].
+    #[inline]
+    fn fold_format_part(&mut self, node: FormatPart) -> FormatPart {
+        walk_format_part(self, node)
+    }
+
+    /// Folds a [`FormatString`] node. Defaults to [`walk_format_string`].
+    #[inline]
+    fn fold_format_string(&mut self, node: FormatString) -> FormatString {
+        walk_format_string(self, node)
+    }
+
+    /// Folds a [`Function`] node. Defaults to [`walk_function`].
+    #[inline]
+    fn fold_function(&mut self, node: Function) -> Function {
+        walk_function(self, node)
+    }
+
+    /// Folds an [`Identifier`] node. Defaults to [`walk_identifier`].
+    #[inline]
+    fn fold_identifier(&mut self, node: Identifier) -> Identifier {
+        walk_identifier(self, node)
+    }
+
+    /// Folds an [`If`] node. Defaults to [`walk_if`].
+    #[inline]
+    fn fold_if(&mut self, node: If) -> If {
+        walk_if(self, node)
+    }
+
+    /// Folds an [`Import`] node. Defaults to [`walk_import`].
+    #[inline]
+    fn fold_import(&mut self, node: Import) -> Import {
+        walk_import(self, node)
+    }
+
+    /// Folds an [`Index`] node. Defaults to [`walk_index`].
+    #[inline]
+    fn fold_index(&mut self, node: Index) -> Index {
+        walk_index(self, node)
+    }
+
+    /// Folds a [`Lambda`] node. Defaults to [`walk_lambda`].
+    #[inline]
+    fn fold_lambda(&mut self, node: Lambda) -> Lambda {
+        walk_lambda(self, node)
+    }
+
+    /// Folds a [`LambdaBody`] node. Defaults to [`walk_lambda_body`].
+    #[inline]
+    fn fold_lambda_body(&mut self, node: LambdaBody) -> LambdaBody {
+        walk_lambda_body(self, node)
+    }
+
+    /// Folds a [`List`] node. Defaults to [`walk_list`].
+    #[inline]
+    fn fold_list(&mut self, node: List) -> List {
+        walk_list(self, node)
+    }
+
+    /// Folds a [`Literal`] node. Defaults to [`walk_literal`].
+    #[inline]
+    fn fold_literal(&mut self, node: Literal) -> Literal {
+        walk_literal(self, node)
+    }
+
+    /// Folds a [`Match`] node. Defaults to [`walk_match`].
+    #[inline]
+    fn fold_match(&mut self, node: Match) -> Match {
+        walk_match(self, node)
+    }
+
+    /// Folds a [`MatchArm`] node. Defaults to [`walk_match_arm`].
+    #[inline]
+    fn fold_match_arm(&mut self, node: MatchArm) -> MatchArm {
+        walk_match_arm(self, node)
+    }
+
+    /// Folds a [`MatchBody`] node. Defaults to [`walk_match_body`].
+    #[inline]
+    fn fold_match_body(&mut self, node: MatchBody) -> MatchBody {
+        walk_match_body(self, node)
+    }
+
+    /// Folds a [`Member`] node. Defaults to [`walk_member`].
+    #[inline]
+    fn fold_member(&mut self, node: Member) -> Member {
+        walk_member(self, node)
+    }
+
+    /// Folds a [`Parameter`] node. Defaults to [`walk_parameter`].
+    #[inline]
+    fn fold_parameter(&mut self, node: Parameter) -> Parameter {
+        walk_parameter(self, node)
+    }
+
+    /// Folds a [`Pattern`] node. Defaults to [`walk_pattern`].
+    #[inline]
+    fn fold_pattern(&mut self, node: Pattern) -> Pattern {
+        walk_pattern(self, node)
+    }
+
+    /// Folds a [`Program`] node. Defaults to [`walk_program`].
+    #[inline]
+    fn fold_program(&mut self, node: Program) -> Program {
+        walk_program(self, node)
+    }
+
+    /// Folds a [`RangeBound`] node. Defaults to [`walk_range_bound`].
+    #[inline]
+    fn fold_range_bound(&mut self, node: RangeBound) -> RangeBound {
+        walk_range_bound(self, node)
+    }
+
+    /// Folds a [`RangePattern`] node. Defaults to [`walk_range_pattern`].
+    #[inline]
+    fn fold_range_pattern(&mut self, node: RangePattern) -> RangePattern {
+        walk_range_pattern(self, node)
+    }
+
+    /// Folds a [`Return`] node. Defaults to [`walk_return`].
+    #[inline]
+    fn fold_return(&mut self, node: Return) -> Return {
+        walk_return(self, node)
+    }
+
+    /// Folds a [`Slice`] node. Defaults to [`walk_slice`].
+    #[inline]
+    fn fold_slice(&mut self, node: Slice) -> Slice {
+        walk_slice(self, node)
+    }
+
+    /// Folds a [`Statement`] node. Defaults to [`walk_statement`].
+    #[inline]
+    fn fold_statement(&mut self, node: Statement) -> Statement {
+        walk_statement(self, node)
+    }
+
+    /// Folds a [`Throw`] node. Defaults to [`walk_throw`].
+    #[inline]
+    fn fold_throw(&mut self, node: Throw) -> Throw {
+        walk_throw(self, node)
+    }
+
+    /// Folds a [`Try`] node. Defaults to [`walk_try`].
+    #[inline]
+    fn fold_try(&mut self, node: Try) -> Try {
+        walk_try(self, node)
+    }
+
+    /// Folds a [`Tuple`] node. Defaults to [`walk_tuple`].
+    #[inline]
+    fn fold_tuple(&mut self, node: Tuple) -> Tuple {
+        walk_tuple(self, node)
+    }
+
+    /// Folds a [`TypeAnnotation`] node. Defaults to [`walk_type_annotation`].
+    #[inline]
+    fn fold_type_annotation(&mut self, node: TypeAnnotation) -> TypeAnnotation {
+        walk_type_annotation(self, node)
+    }
+
+    /// Folds a [`TypeParameter`] node. Defaults to [`walk_type_parameter`].
+    #[inline]
+    fn fold_type_parameter(&mut self, node: TypeParameter) -> TypeParameter {
+        walk_type_parameter(self, node)
+    }
+
+    /// Folds a [`Unary`] node. Defaults to [`walk_unary`].
+    #[inline]
+    fn fold_unary(&mut self, node: Unary) -> Unary {
+        walk_unary(self, node)
+    }
+
+    /// Folds a [`Use`] node. Defaults to [`walk_use`].
+    #[inline]
+    fn fold_use(&mut self, node: Use) -> Use {
+        walk_use(self, node)
+    }
+
+    /// Folds a [`VariableDeclaration`] node. Defaults to [`walk_variable_declaration`].
+    #[inline]
+    fn fold_variable_declaration(&mut self, node: VariableDeclaration) -> VariableDeclaration {
+        walk_variable_declaration(self, node)
+    }
+
+    /// Folds a [`While`] node. Defaults to [`walk_while`].
+    #[inline]
+    fn fold_while(&mut self, node: While) -> While {
+        walk_while(self, node)
+    }
+
+    /// Folds a [`Yield`] node. Defaults to [`walk_yield`].
+    #[inline]
+    fn fold_yield(&mut self, node: Yield) -> Yield {
+        walk_yield(self, node)
+    }
+}
+
+
+///////////
+// WALKS //
+///////////
+
+/// Walks the children of an [`AlternativePattern`] node.
+#[inline]
+pub fn walk_alternative_pattern<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: AlternativePattern,
+) -> AlternativePattern {
+    AlternativePattern {
+        patterns: node
+            .patterns
+            .into_iter()
+            .map(|item| folder.fold_pattern(item))
+            .collect(),
+        location: node.location,
+    }
+}
+
+/// Walks the children of an [`Annotation`] node.
+#[inline]
+pub fn walk_annotation<F: Fold + ?Sized>(folder: &mut F, node: Annotation) -> Annotation {
+    Annotation {
+        name: folder.fold_identifier(node.name),
+        arguments: node
+            .arguments
+            .into_iter()
+            .map(|item| folder.fold_argument(item))
+            .collect(),
+        location: node.location,
+    }
+}
+
+/// Walks the children of an [`Argument`] node.
+#[inline]
+pub fn walk_argument<F: Fold + ?Sized>(folder: &mut F, node: Argument) -> Argument {
+    Argument {
+        name: node.name.map(|item| folder.fold_identifier(item)),
+        value: folder.fold_expression(node.value),
+        location: node.location,
+    }
+}
+
+/// Walks the children of an [`Assert`] node.
+#[inline]
+pub fn walk_assert<F: Fold + ?Sized>(folder: &mut F, node: Assert) -> Assert {
+    Assert {
+        condition: folder.fold_expression(node.condition),
+        message: node.message.map(|item| folder.fold_expression(item)),
+        location: node.location,
+    }
+}
+
+/// Walks the children of an [`Assignment`] node.
+#[inline]
+pub fn walk_assignment<F: Fold + ?Sized>(folder: &mut F, node: Assignment) -> Assignment {
+    Assignment {
+        target: Box::new(folder.fold_expression(*node.target)),
+        operator: node.operator,
+        value: Box::new(folder.fold_expression(*node.value)),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`Binary`] node.
+#[inline]
+pub fn walk_binary<F: Fold + ?Sized>(folder: &mut F, node: Binary) -> Binary {
+    Binary {
+        left: Box::new(folder.fold_expression(*node.left)),
+        operator: node.operator,
+        right: Box::new(folder.fold_expression(*node.right)),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`BindingPattern`] node.
+#[inline]
+pub fn walk_binding_pattern<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: BindingPattern,
+) -> BindingPattern {
+    BindingPattern {
+        type_annotation: node
+            .type_annotation
+            .map(|item| folder.fold_type_annotation(item)),
+        name: folder.fold_identifier(node.name),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`Block`] node.
+#[inline]
+pub fn walk_block<F: Fold + ?Sized>(folder: &mut F, node: Block) -> Block {
+    Block {
+        statements: node
+            .statements
+            .into_iter()
+            .map(|item| folder.fold_statement(item))
+            .collect(),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`Break`] node.
+#[inline]
+pub fn walk_break<F: Fold + ?Sized>(_folder: &mut F, node: Break) -> Break {
+    node
+}
+
+/// Walks the children of a [`Call`] node.
+#[inline]
+pub fn walk_call<F: Fold + ?Sized>(folder: &mut F, node: Call) -> Call {
+    Call {
+        callee: Box::new(folder.fold_expression(*node.callee)),
+        arguments: node
+            .arguments
+            .into_iter()
+            .map(|item| folder.fold_argument(item))
+            .collect(),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`Catch`] node.
+#[inline]
+pub fn walk_catch<F: Fold + ?Sized>(folder: &mut F, node: Catch) -> Catch {
+    Catch {
+        exception: folder.fold_type_annotation(node.exception),
+        binding: node.binding.map(|item| folder.fold_identifier(item)),
+        body: folder.fold_block(node.body),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`Class`] node.
+#[inline]
+pub fn walk_class<F: Fold + ?Sized>(folder: &mut F, node: Class) -> Class {
+    Class {
+        annotations: node
+            .annotations
+            .into_iter()
+            .map(|item| folder.fold_annotation(item))
+            .collect(),
+        public: node.public,
+        interface: node.interface,
+        enumeration: node.enumeration,
+        name: folder.fold_identifier(node.name),
+        type_parameters: node
+            .type_parameters
+            .into_iter()
+            .map(|item| folder.fold_type_parameter(item))
+            .collect(),
+        bases: node
+            .bases
+            .into_iter()
+            .map(|item| folder.fold_type_annotation(item))
+            .collect(),
+        interfaces: node
+            .interfaces
+            .into_iter()
+            .map(|item| folder.fold_type_annotation(item))
+            .collect(),
+        variants: node
+            .variants
+            .into_iter()
+            .map(|item| folder.fold_class(item))
+            .collect(),
+        members: node
+            .members
+            .into_iter()
+            .map(|item| folder.fold_class_member(item))
+            .collect(),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`ClassMember`] node.
+#[inline]
+pub fn walk_class_member<F: Fold + ?Sized>(folder: &mut F, node: ClassMember) -> ClassMember {
+    match node {
+        ClassMember::Constructor(inner) => ClassMember::Constructor(folder.fold_function(inner)),
+        ClassMember::Field(inner) => ClassMember::Field(folder.fold_field(inner)),
+        ClassMember::Method(inner) => ClassMember::Method(folder.fold_function(inner)),
+    }
+}
+
+/// Walks the children of a [`ClassPattern`] node.
+#[inline]
+pub fn walk_class_pattern<F: Fold + ?Sized>(folder: &mut F, node: ClassPattern) -> ClassPattern {
+    ClassPattern {
+        class: folder.fold_type_annotation(node.class),
+        fields: node
+            .fields
+            .into_iter()
+            .map(|item| folder.fold_pattern(item))
+            .collect(),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`Continue`] node.
+#[inline]
+pub fn walk_continue<F: Fold + ?Sized>(_folder: &mut F, node: Continue) -> Continue {
+    node
+}
+
+/// Walks the children of a [`Dict`] node.
+#[inline]
+pub fn walk_dict<F: Fold + ?Sized>(folder: &mut F, node: Dict) -> Dict {
+    Dict {
+        entries: node
+            .entries
+            .into_iter()
+            .map(|item| folder.fold_dict_entry(item))
+            .collect(),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`DictEntry`] node.
+#[inline]
+pub fn walk_dict_entry<F: Fold + ?Sized>(folder: &mut F, node: DictEntry) -> DictEntry {
+    DictEntry {
+        key: folder.fold_expression(node.key),
+        value: folder.fold_expression(node.value),
+        location: node.location,
+    }
+}
+
+/// Walks the children of an [`Expression`] node.
+#[inline]
+pub fn walk_expression<F: Fold + ?Sized>(folder: &mut F, node: Expression) -> Expression {
+    match node {
+        Expression::Assignment(inner) => Expression::Assignment(folder.fold_assignment(inner)),
+        Expression::Binary(inner) => Expression::Binary(folder.fold_binary(inner)),
+        Expression::Call(inner) => Expression::Call(folder.fold_call(inner)),
+        Expression::Dict(inner) => Expression::Dict(folder.fold_dict(inner)),
+        Expression::FormatString(inner) => {
+            Expression::FormatString(folder.fold_format_string(inner))
+        }
+        Expression::Identifier(inner) => Expression::Identifier(folder.fold_identifier(inner)),
+        Expression::Index(inner) => Expression::Index(folder.fold_index(inner)),
+        Expression::Lambda(inner) => Expression::Lambda(folder.fold_lambda(inner)),
+        Expression::List(inner) => Expression::List(folder.fold_list(inner)),
+        Expression::Literal(inner) => Expression::Literal(folder.fold_literal(inner)),
+        Expression::Match(inner) => Expression::Match(folder.fold_match(inner)),
+        Expression::Member(inner) => Expression::Member(folder.fold_member(inner)),
+        Expression::Slice(inner) => Expression::Slice(folder.fold_slice(inner)),
+        Expression::Tuple(inner) => Expression::Tuple(folder.fold_tuple(inner)),
+        Expression::Unary(inner) => Expression::Unary(folder.fold_unary(inner)),
+    }
+}
+
+/// Walks the children of an [`ExpressionStatement`] node.
+#[inline]
+pub fn walk_expression_statement<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ExpressionStatement,
+) -> ExpressionStatement {
+    ExpressionStatement {
+        expression: folder.fold_expression(node.expression),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`Field`] node.
+#[inline]
+pub fn walk_field<F: Fold + ?Sized>(folder: &mut F, node: Field) -> Field {
+    Field {
+        annotations: node
+            .annotations
+            .into_iter()
+            .map(|item| folder.fold_annotation(item))
+            .collect(),
+        public: node.public,
+        type_annotation: folder.fold_type_annotation(node.type_annotation),
+        name: folder.fold_identifier(node.name),
+        value: node.value.map(|item| folder.fold_expression(item)),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`For`] node.
+#[inline]
+pub fn walk_for<F: Fold + ?Sized>(folder: &mut F, node: For) -> For {
+    For {
+        type_annotation: node
+            .type_annotation
+            .map(|item| folder.fold_type_annotation(item)),
+        variable: folder.fold_identifier(node.variable),
+        iterable: folder.fold_for_iterable(node.iterable),
+        body: folder.fold_block(node.body),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`ForIterable`] node.
+#[inline]
+pub fn walk_for_iterable<F: Fold + ?Sized>(folder: &mut F, node: ForIterable) -> ForIterable {
+    match node {
+        ForIterable::Range(inner) => ForIterable::Range(Box::new(folder.fold_for_range(*inner))),
+        ForIterable::Value(inner) => ForIterable::Value(Box::new(folder.fold_expression(*inner))),
+    }
+}
+
+/// Walks the children of a [`ForRange`] node.
+#[inline]
+pub fn walk_for_range<F: Fold + ?Sized>(folder: &mut F, node: ForRange) -> ForRange {
+    ForRange {
+        start: folder.fold_expression(node.start),
+        end: folder.fold_expression(node.end),
+        inclusive: node.inclusive,
+        step: node.step.map(|item| folder.fold_expression(item)),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`FormatField`] node.
+#[inline]
+pub fn walk_format_field<F: Fold + ?Sized>(folder: &mut F, node: FormatField) -> FormatField {
+    FormatField {
+        value: folder.fold_expression(node.value),
+        specification: node.specification,
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`FormatPart`] node.
+#[inline]
+pub fn walk_format_part<F: Fold + ?Sized>(folder: &mut F, node: FormatPart) -> FormatPart {
+    match node {
+        FormatPart::Text(inner) => FormatPart::Text(inner),
+        FormatPart::Field(inner) => FormatPart::Field(Box::new(folder.fold_format_field(*inner))),
+    }
+}
+
+/// Walks the children of a [`FormatString`] node.
+#[inline]
+pub fn walk_format_string<F: Fold + ?Sized>(folder: &mut F, node: FormatString) -> FormatString {
+    FormatString {
+        parts: node
+            .parts
+            .into_iter()
+            .map(|item| folder.fold_format_part(item))
+            .collect(),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`Function`] node.
+#[inline]
+pub fn walk_function<F: Fold + ?Sized>(folder: &mut F, node: Function) -> Function {
+    Function {
+        annotations: node
+            .annotations
+            .into_iter()
+            .map(|item| folder.fold_annotation(item))
+            .collect(),
+        public: node.public,
+        name: folder.fold_identifier(node.name),
+        type_parameters: node
+            .type_parameters
+            .into_iter()
+            .map(|item| folder.fold_type_parameter(item))
+            .collect(),
+        parameters: node
+            .parameters
+            .into_iter()
+            .map(|item| folder.fold_parameter(item))
+            .collect(),
+        return_type: node
+            .return_type
+            .map(|item| folder.fold_type_annotation(item)),
+        requires: node
+            .requires
+            .into_iter()
+            .map(|item| folder.fold_expression(item))
+            .collect(),
+        ensures: node
+            .ensures
+            .into_iter()
+            .map(|item| folder.fold_expression(item))
+            .collect(),
+        body: node.body.map(|item| folder.fold_block(item)),
+        location: node.location,
+    }
+}
+
+/// Walks the children of an [`Identifier`] node.
+#[inline]
+pub fn walk_identifier<F: Fold + ?Sized>(_folder: &mut F, node: Identifier) -> Identifier {
+    node
+}
+
+/// Walks the children of an [`If`] node.
+#[inline]
+pub fn walk_if<F: Fold + ?Sized>(folder: &mut F, node: If) -> If {
+    If {
+        condition: folder.fold_expression(node.condition),
+        then_block: folder.fold_block(node.then_block),
+        else_block: node.else_block.map(|item| folder.fold_block(item)),
+        location: node.location,
+    }
+}
+
+/// Walks the children of an [`Import`] node.
+#[inline]
+pub fn walk_import<F: Fold + ?Sized>(folder: &mut F, node: Import) -> Import {
+    Import {
+        path: node
+            .path
+            .into_iter()
+            .map(|item| folder.fold_identifier(item))
+            .collect(),
+        alias: node.alias.map(|item| folder.fold_identifier(item)),
+        location: node.location,
+    }
+}
+
+/// Walks the children of an [`Index`] node.
+#[inline]
+pub fn walk_index<F: Fold + ?Sized>(folder: &mut F, node: Index) -> Index {
+    Index {
+        target: Box::new(folder.fold_expression(*node.target)),
+        index: Box::new(folder.fold_expression(*node.index)),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`Lambda`] node.
+#[inline]
+pub fn walk_lambda<F: Fold + ?Sized>(folder: &mut F, node: Lambda) -> Lambda {
+    Lambda {
+        parameters: node
+            .parameters
+            .into_iter()
+            .map(|item| folder.fold_parameter(item))
+            .collect(),
+        return_type: node
+            .return_type
+            .map(|item| folder.fold_type_annotation(item)),
+        body: folder.fold_lambda_body(node.body),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`LambdaBody`] node.
+#[inline]
+pub fn walk_lambda_body<F: Fold + ?Sized>(folder: &mut F, node: LambdaBody) -> LambdaBody {
+    match node {
+        LambdaBody::Block(inner) => LambdaBody::Block(folder.fold_block(inner)),
+        LambdaBody::Expression(inner) => {
+            LambdaBody::Expression(Box::new(folder.fold_expression(*inner)))
+        }
+    }
+}
+
+/// Walks the children of a [`List`] node.
+#[inline]
+pub fn walk_list<F: Fold + ?Sized>(folder: &mut F, node: List) -> List {
+    List {
+        elements: node
+            .elements
+            .into_iter()
+            .map(|item| folder.fold_expression(item))
+            .collect(),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`Literal`] node.
+#[inline]
+pub fn walk_literal<F: Fold + ?Sized>(_folder: &mut F, node: Literal) -> Literal {
+    node
+}
+
+/// Walks the children of a [`Match`] node.
+#[inline]
+pub fn walk_match<F: Fold + ?Sized>(folder: &mut F, node: Match) -> Match {
+    Match {
+        subject: Box::new(folder.fold_expression(*node.subject)),
+        arms: node
+            .arms
+            .into_iter()
+            .map(|item| folder.fold_match_arm(item))
+            .collect(),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`MatchArm`] node.
+#[inline]
+pub fn walk_match_arm<F: Fold + ?Sized>(folder: &mut F, node: MatchArm) -> MatchArm {
+    MatchArm {
+        pattern: node.pattern.map(|item| folder.fold_pattern(item)),
+        guard: node.guard.map(|item| folder.fold_expression(item)),
+        body: folder.fold_match_body(node.body),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`MatchBody`] node.
+#[inline]
+pub fn walk_match_body<F: Fold + ?Sized>(folder: &mut F, node: MatchBody) -> MatchBody {
+    match node {
+        MatchBody::Block(inner) => MatchBody::Block(folder.fold_block(inner)),
+        MatchBody::Expression(inner) => MatchBody::Expression(folder.fold_expression(inner)),
+    }
+}
+
+/// Walks the children of a [`Member`] node.
+#[inline]
+pub fn walk_member<F: Fold + ?Sized>(folder: &mut F, node: Member) -> Member {
+    Member {
+        target: Box::new(folder.fold_expression(*node.target)),
+        member: folder.fold_identifier(node.member),
+        safe: node.safe,
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`Parameter`] node.
+#[inline]
+pub fn walk_parameter<F: Fold + ?Sized>(folder: &mut F, node: Parameter) -> Parameter {
+    Parameter {
+        type_annotation: folder.fold_type_annotation(node.type_annotation),
+        name: folder.fold_identifier(node.name),
+        default: node.default.map(|item| folder.fold_expression(item)),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`Pattern`] node.
+#[inline]
+pub fn walk_pattern<F: Fold + ?Sized>(folder: &mut F, node: Pattern) -> Pattern {
+    match node {
+        Pattern::Alternative(inner) => Pattern::Alternative(folder.fold_alternative_pattern(inner)),
+        Pattern::Binding(inner) => Pattern::Binding(folder.fold_binding_pattern(inner)),
+        Pattern::Class(inner) => Pattern::Class(folder.fold_class_pattern(inner)),
+        Pattern::Literal(inner) => Pattern::Literal(folder.fold_literal(inner)),
+        Pattern::Range(inner) => Pattern::Range(folder.fold_range_pattern(inner)),
+    }
+}
+
+/// Walks the children of a [`Program`] node.
+#[inline]
+pub fn walk_program<F: Fold + ?Sized>(folder: &mut F, node: Program) -> Program {
+    Program {
+        statements: node
+            .statements
+            .into_iter()
+            .map(|item| folder.fold_statement(item))
+            .collect(),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`RangeBound`] node.
+#[inline]
+pub fn walk_range_bound<F: Fold + ?Sized>(folder: &mut F, node: RangeBound) -> RangeBound {
+    match node {
+        RangeBound::Literal(inner) => RangeBound::Literal(folder.fold_literal(inner)),
+        RangeBound::Constant(inner) => RangeBound::Constant(folder.fold_identifier(inner)),
+    }
+}
+
+/// Walks the children of a [`RangePattern`] node.
+#[inline]
+pub fn walk_range_pattern<F: Fold + ?Sized>(folder: &mut F, node: RangePattern) -> RangePattern {
+    RangePattern {
+        start: folder.fold_range_bound(node.start),
+        end: folder.fold_range_bound(node.end),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`Return`] node.
+#[inline]
+pub fn walk_return<F: Fold + ?Sized>(folder: &mut F, node: Return) -> Return {
+    Return {
+        value: node.value.map(|item| folder.fold_expression(item)),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`Slice`] node.
+#[inline]
+pub fn walk_slice<F: Fold + ?Sized>(folder: &mut F, node: Slice) -> Slice {
+    Slice {
+        target: Box::new(folder.fold_expression(*node.target)),
+        start: node
+            .start
+            .map(|item| Box::new(folder.fold_expression(*item))),
+        end: node.end.map(|item| Box::new(folder.fold_expression(*item))),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`Statement`] node.
+#[inline]
+pub fn walk_statement<F: Fold + ?Sized>(folder: &mut F, node: Statement) -> Statement {
+    match node {
+        Statement::Assert(inner) => Statement::Assert(folder.fold_assert(inner)),
+        Statement::Block(inner) => Statement::Block(folder.fold_block(inner)),
+        Statement::Break(inner) => Statement::Break(folder.fold_break(inner)),
+        Statement::Class(inner) => Statement::Class(folder.fold_class(inner)),
+        Statement::Continue(inner) => Statement::Continue(folder.fold_continue(inner)),
+        Statement::Expression(inner) => {
+            Statement::Expression(folder.fold_expression_statement(inner))
+        }
+        Statement::For(inner) => Statement::For(Box::new(folder.fold_for(*inner))),
+        Statement::Function(inner) => Statement::Function(folder.fold_function(inner)),
+        Statement::If(inner) => Statement::If(folder.fold_if(inner)),
+        Statement::Import(inner) => Statement::Import(folder.fold_import(inner)),
+        Statement::Match(inner) => Statement::Match(folder.fold_match(inner)),
+        Statement::Return(inner) => Statement::Return(folder.fold_return(inner)),
+        Statement::Throw(inner) => Statement::Throw(folder.fold_throw(inner)),
+        Statement::Try(inner) => Statement::Try(folder.fold_try(inner)),
+        Statement::Use(inner) => Statement::Use(folder.fold_use(inner)),
+        Statement::VariableDeclaration(inner) => {
+            Statement::VariableDeclaration(folder.fold_variable_declaration(inner))
+        }
+        Statement::While(inner) => Statement::While(folder.fold_while(inner)),
+        Statement::Yield(inner) => Statement::Yield(folder.fold_yield(inner)),
+    }
+}
+
+/// Walks the children of a [`Throw`] node.
+#[inline]
+pub fn walk_throw<F: Fold + ?Sized>(folder: &mut F, node: Throw) -> Throw {
+    Throw {
+        value: folder.fold_expression(node.value),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`Try`] node.
+#[inline]
+pub fn walk_try<F: Fold + ?Sized>(folder: &mut F, node: Try) -> Try {
+    Try {
+        body: folder.fold_block(node.body),
+        catches: node
+            .catches
+            .into_iter()
+            .map(|item| folder.fold_catch(item))
+            .collect(),
+        finally: node.finally.map(|item| folder.fold_block(item)),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`Tuple`] node.
+#[inline]
+pub fn walk_tuple<F: Fold + ?Sized>(folder: &mut F, node: Tuple) -> Tuple {
+    Tuple {
+        elements: node
+            .elements
+            .into_iter()
+            .map(|item| folder.fold_expression(item))
+            .collect(),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`TypeAnnotation`] node.
+#[inline]
+pub fn walk_type_annotation<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: TypeAnnotation,
+) -> TypeAnnotation {
+    TypeAnnotation {
+        name: node.name,
+        arguments: node
+            .arguments
+            .into_iter()
+            .map(|item| folder.fold_type_annotation(item))
+            .collect(),
+        nullable: node.nullable,
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`TypeParameter`] node.
+#[inline]
+pub fn walk_type_parameter<F: Fold + ?Sized>(folder: &mut F, node: TypeParameter) -> TypeParameter {
+    TypeParameter {
+        name: folder.fold_identifier(node.name),
+        bound: node.bound.map(|item| folder.fold_type_annotation(item)),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`Unary`] node.
+#[inline]
+pub fn walk_unary<F: Fold + ?Sized>(folder: &mut F, node: Unary) -> Unary {
+    Unary {
+        operator: node.operator,
+        operand: Box::new(folder.fold_expression(*node.operand)),
+        postfix: node.postfix,
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`Use`] node.
+#[inline]
+pub fn walk_use<F: Fold + ?Sized>(folder: &mut F, node: Use) -> Use {
+    Use {
+        path: node
+            .path
+            .into_iter()
+            .map(|item| folder.fold_identifier(item))
+            .collect(),
+        alias: node.alias.map(|item| folder.fold_identifier(item)),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`VariableDeclaration`] node.
+#[inline]
+pub fn walk_variable_declaration<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: VariableDeclaration,
+) -> VariableDeclaration {
+    VariableDeclaration {
+        constant: node.constant,
+        type_annotation: node
+            .type_annotation
+            .map(|item| folder.fold_type_annotation(item)),
+        name: folder.fold_identifier(node.name),
+        value: node.value.map(|item| folder.fold_expression(item)),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`While`] node.
+#[inline]
+pub fn walk_while<F: Fold + ?Sized>(folder: &mut F, node: While) -> While {
+    While {
+        condition: folder.fold_expression(node.condition),
+        body: folder.fold_block(node.body),
+        location: node.location,
+    }
+}
+
+/// Walks the children of a [`Yield`] node.
+#[inline]
+pub fn walk_yield<F: Fold + ?Sized>(folder: &mut F, node: Yield) -> Yield {
+    Yield {
+        value: folder.fold_expression(node.value),
+        location: node.location,
+    }
+}
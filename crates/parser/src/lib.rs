@@ -1,14 +1,49 @@
-pub fn add(left: usize, right: usize) -> usize {
-    left + right
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
+//! I-Language parser is the parser for the I-Programming-Language, turning tokens into an abstract syntax tree.
+// I Language parser.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+// Restriction lints contradicting the idioms of the parser: errors are propagated with `?`, patterns bind with `ref`
+// as `pattern_type_mismatch` asks for, items are grouped by banner sections instead of alphabetically, long parsing
+// functions are split into helpers, matches on tokens only handle the kinds they expect, `pub(crate)` is written the
+// way rustfmt formats it and trait implementations name their parameters like the lexer does, e.g. `formatter`.
+#![allow(
+    clippy::arbitrary_source_item_ordering,
+    clippy::pub_with_shorthand,
+    clippy::question_mark_used,
+    clippy::ref_patterns,
+    clippy::renamed_function_params,
+    clippy::single_call_fn,
+    clippy::wildcard_enum_match_arm
+)]
+
+/////////////
+// EXPORTS //
+/////////////
+
+pub mod ast;
+pub mod error;
+pub mod fold;
+pub mod parse;
+pub mod print;
+pub mod visit;
+pub mod visit_mut;
@@ -0,0 +1,2050 @@
+//! Parses a list of tokens into an abstract syntax tree.
+// I Language parser.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use crate::ast::{
+    AlternativePattern, Annotation, Argument, Assert, Assignment, Binary, BindingPattern, Block,
+    Break, Call, Catch, Class, ClassMember, ClassPattern, Continue, Dict, DictEntry, Expression,
+    ExpressionStatement, Field, For, ForIterable, ForRange, FormatField, FormatPart, FormatString,
+    Function, Identifier, If, Import, Index, Lambda, LambdaBody, List, Literal, Match, MatchArm,
+    MatchBody, Member, Parameter, Pattern, Program, RangeBound, RangePattern, Return, Slice,
+    Statement, Throw, Try, Tuple, TypeAnnotation, TypeParameter, Unary, Use, VariableDeclaration,
+    While, Yield,
+};
+use crate::error::ParserError;
+
+use lexer::tokens::constant::Type;
+use lexer::tokens::keyword::Keyword;
+use lexer::tokens::mark::Mark;
+use lexer::tokens::token::{Location, Token, TokenType, TypeDefinition};
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// The maximum nesting depth of expressions, statements, patterns and type annotations. Deeper nesting is reported as
+/// an error, so the recursive passes over the AST do not overflow the stack.
+pub const MAX_NESTING: usize = 200;
+
+/// The stack space a nesting level needs at least. With less space left, parsing continues on a newly allocated stack
+/// segment, so nesting up to [`MAX_NESTING`] does not overflow the native stack.
+const RED_ZONE: usize = 128 * 1024;
+
+/// The size of the stack segments allocated when the stack runs low, see [`RED_ZONE`].
+const STACK_SEGMENT: usize = 4 * 1024 * 1024;
+
+
+////////////////////
+// PARSE FUNCTION //
+////////////////////
+
+/// Parses a list of [`Token`]s (usually the output of [`lexer::lex::lex`]) into a [`Program`].
+/// Comment tokens are ignored.
+///
+/// # Parameters
+///
+/// - `tokens`: The tokens to parse.
+///
+/// # Returns
+///
+/// A result of the parsed [`Program`].
+///
+/// # Errors
+///
+/// Errors when the tokens do not form a valid program, e.g. when a semicolon is missing.
+///
+/// # Examples
+///
+/// ```rust
+/// # use parser::ast::{Expression, Statement};
+/// # use parser::parse;
+/// let tokens = lexer::lex::lex("print(\"Hello World!\");", "<stdin>").unwrap();
+/// let program = parse::parse(&tokens).unwrap();
+///
+/// assert!(matches!(
+///     &program.statements[0],
+///     Statement::Expression(statement) if matches!(statement.expression, Expression::Call(_))
+/// ));
+/// ```
+///
+/// # See also
+///
+/// - [`Program`]
+/// - [`ParserError`]
+#[inline]
+pub fn parse(tokens: &[Token]) -> Result<Program, ParserError> {
+    Parser::new(tokens).parse_program()
+}
+
+
+/////////////
+// HELPERS //
+/////////////
+
+/// Returns the precedence of a binary operator mark, or [`None`] if the mark is not a binary operator.
+/// Higher numbers bind stronger.
+pub(crate) const fn binary_precedence(mark: &Mark) -> Option<u8> {
+    match *mark {
+        Mark::Coalesce => Some(1),
+        Mark::Or => Some(2),
+        Mark::And => Some(3),
+        Mark::BitOr => Some(4),
+        Mark::BitXor => Some(5),
+        Mark::BitAnd => Some(6),
+        Mark::Equal | Mark::NotEqual => Some(7),
+        Mark::Less | Mark::LessEqual | Mark::Greater | Mark::GreaterEqual => Some(8),
+        Mark::ShiftLeft | Mark::ShiftRight => Some(9),
+        Mark::Add | Mark::Subtract => Some(10),
+        Mark::Multiply | Mark::Divide | Mark::Modulo => Some(11),
+        Mark::Exponentiation => Some(12),
+        _ => None,
+    }
+}
+
+/// Returns whether a mark is an assignment operator (`=`, `+=`, `-=`, etc.).
+const fn is_assignment_mark(mark: &Mark) -> bool {
+    matches!(
+        *mark,
+        Mark::Assign
+            | Mark::AddAssign
+            | Mark::BitAndAssign
+            | Mark::BitNotAssign
+            | Mark::BitOrAssign
+            | Mark::BitXorAssign
+            | Mark::DivideAssign
+            | Mark::ModuloAssign
+            | Mark::MultiplyAssign
+            | Mark::ShiftLeftAssign
+            | Mark::ShiftRightAssign
+            | Mark::SubtractAssign
+    )
+}
+
+/// Returns the index of the closing brace of a field of a format string and the index of the colon before its
+/// format specification, if any. Braces, brackets, parentheses and quotes inside the expression are skipped.
+///
+/// # Parameters
+///
+/// - `characters`: The characters of the format string.
+/// - `start`: The index after the opening brace of the field.
+fn field_end(characters: &[char], start: usize) -> Option<(usize, Option<usize>)> {
+    let mut depth: usize = 0;
+    let mut quote: Option<char> = None;
+    let mut colon: Option<usize> = None;
+    for (index, &character) in characters.iter().enumerate().skip(start) {
+        match (quote, character) {
+            (Some(open), _) if character == open => quote = None,
+            (None, '"' | '\'') => quote = Some(character),
+            (None, '(' | '[' | '{') => depth += 1,
+            (None, '}') if depth == 0 => return Some((index, colon)),
+            (None, ')' | ']' | '}') => depth = depth.saturating_sub(1),
+            (None, ':') if depth == 0 && colon.is_none() => colon = Some(index),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Parses the content of a format string token into its text and its fields. The expressions of the fields are
+/// lexed and parsed on their own, padded to their place in the source so their locations are the same.
+///
+/// # Errors
+///
+/// Errors when a brace is not closed or not doubled, when a field is empty or when an expression is invalid.
+fn parse_format_string(token: &Token, depth: usize) -> Result<FormatString, ParserError> {
+    let characters: Vec<char> = token.content.chars().collect();
+    // The content starts after the `f` and the opening quote
+    let column = |index: usize| token.location.column + 2 + index;
+    let located = |index: usize| Location {
+        column: column(index),
+        ..token.location.clone()
+    };
+
+    let mut parts: Vec<FormatPart> = vec![];
+    let mut text: String = String::new();
+    let mut index: usize = 0;
+    while let Some(&character) = characters.get(index) {
+        let doubled: bool = characters.get(index + 1) == Some(&character);
+        match character {
+            '{' | '}' if doubled => {
+                text.push(character);
+                index += 2;
+            }
+            '}' => {
+                return Err(ParserError::InvalidFormatString {
+                    reason: "a single `}` must be written as `}}`".to_owned(),
+                    location: located(index),
+                })
+            }
+            '{' => {
+                let Some((end, colon)) = field_end(&characters, index + 1) else {
+                    return Err(ParserError::InvalidFormatString {
+                        reason: "the `{` is not closed, a single `{` must be written as `{{`"
+                            .to_owned(),
+                        location: located(index),
+                    });
+                };
+                let source: String = characters
+                    .get(index + 1..colon.unwrap_or(end))
+                    .unwrap_or_default()
+                    .iter()
+                    .collect();
+                if source.trim().is_empty() {
+                    return Err(ParserError::InvalidFormatString {
+                        reason: "the field has no expression".to_owned(),
+                        location: located(index),
+                    });
+                }
+
+                let invalid = || ParserError::InvalidFormatString {
+                    reason: format!("`{}` is not a valid expression", source.trim()),
+                    location: located(index),
+                };
+                let padded: String = format!(
+                    "{}{}{source}",
+                    "\n".repeat(token.location.line - 1),
+                    " ".repeat(column(index))
+                );
+                // The lexer error points into the padded source, the field is reported as a whole instead
+                #[allow(clippy::map_err_ignore)]
+                let tokens: Vec<Token> =
+                    lexer::lex::lex(&padded, &token.location.file).map_err(|_| invalid())?;
+                let mut parser: Parser = Parser::new(&tokens);
+                parser.depth = depth;
+                let value: Expression = parser.parse_expression()?;
+                if parser.peek().is_some() {
+                    return Err(parser.error("`}` after the expression of the field"));
+                }
+
+                if !text.is_empty() {
+                    parts.push(FormatPart::Text(core::mem::take(&mut text)));
+                }
+                parts.push(FormatPart::Field(Box::new(FormatField {
+                    value,
+                    specification: colon
+                        .and_then(|separator| characters.get(separator + 1..end))
+                        .map(|specification| specification.iter().collect()),
+                    location: located(index),
+                })));
+                index = end + 1;
+            }
+            _ => {
+                text.push(character);
+                index += 1;
+            }
+        }
+    }
+    if !text.is_empty() {
+        parts.push(FormatPart::Text(text));
+    }
+
+    Ok(FormatString {
+        parts,
+        location: token.location.clone(),
+    })
+}
+
+/// Returns the short name of a built-in type, which is used in the AST.
+const fn type_name(type_name: &Type) -> &'static str {
+    match *type_name {
+        Type::Str => "str",
+        Type::Int => "int",
+        Type::Bool => "bool",
+        Type::List => "list",
+        Type::Dict => "dict",
+        Type::Tuple => "tuple",
+    }
+}
+
+
+////////////
+// PARSER //
+////////////
+
+/// A recursive descent parser over a list of tokens.
+struct Parser<'tokens> {
+    /// The tokens to parse, without comments.
+    tokens: Vec<&'tokens Token>,
+    /// The index of the next token.
+    position: usize,
+    /// The number of closing angle brackets of type arguments already consumed as part of a `>>` token,
+    /// e.g. the second one in `Box<Box<int>>`.
+    closing: usize,
+    /// The current nesting depth, see [`MAX_NESTING`].
+    depth: usize,
+}
+
+impl<'tokens> Parser<'tokens> {
+    /// Creates a new parser, removing all comments from the tokens.
+    fn new(tokens: &'tokens [Token]) -> Self {
+        Self {
+            tokens: tokens
+                .iter()
+                .filter(|token| token.token_type != TokenType::Comment)
+                .collect(),
+            position: 0,
+            closing: 0,
+            depth: 0,
+        }
+    }
+
+    // Token helpers
+
+    /// Returns the next token without consuming it.
+    fn peek(&self) -> Option<&'tokens Token> {
+        self.peek_nth(0)
+    }
+
+    /// Returns the token `offset` tokens after the next one without consuming anything.
+    fn peek_nth(&self, offset: usize) -> Option<&'tokens Token> {
+        self.tokens.get(self.position + offset).copied()
+    }
+
+    /// Consumes and returns the next token.
+    fn advance(&mut self) -> Option<&'tokens Token> {
+        let token: Option<&Token> = self.peek();
+        if token.is_some() {
+            self.position += 1;
+        }
+
+        token
+    }
+
+    /// Returns whether the token `offset` tokens after the next one is the given mark.
+    fn is_mark_at(&self, offset: usize, mark: &Mark) -> bool {
+        matches!(
+            self.peek_nth(offset),
+            Some(Token { token_type: TokenType::Mark(found), .. }) if found == mark
+        )
+    }
+
+    /// Returns whether the next token is the given mark.
+    fn is_mark(&self, mark: &Mark) -> bool {
+        self.is_mark_at(0, mark)
+    }
+
+    /// Returns whether the token `offset` tokens after the next one is the given keyword.
+    fn is_keyword_at(&self, offset: usize, keyword: &Keyword) -> bool {
+        matches!(
+            self.peek_nth(offset),
+            Some(Token { token_type: TokenType::Keyword(found), .. }) if found == keyword
+        )
+    }
+
+    /// Returns whether the next token is the given keyword.
+    fn is_keyword(&self, keyword: &Keyword) -> bool {
+        self.is_keyword_at(0, keyword)
+    }
+
+    /// Consumes the next token if it is the given mark.
+    fn eat_mark(&mut self, mark: &Mark) -> Option<&'tokens Token> {
+        if self.is_mark(mark) {
+            return self.advance();
+        }
+
+        None
+    }
+
+    /// Consumes the next token if it is the given keyword.
+    fn eat_keyword(&mut self, keyword: &Keyword) -> Option<&'tokens Token> {
+        if self.is_keyword(keyword) {
+            return self.advance();
+        }
+
+        None
+    }
+
+    /// Consumes the next token, erroring if it is not the given mark.
+    fn expect_mark(&mut self, mark: &Mark) -> Result<&'tokens Token, ParserError> {
+        self.eat_mark(mark)
+            .ok_or_else(|| self.error(&format!("`{mark}`")))
+    }
+
+    /// Consumes the next token, erroring if it is not the given keyword.
+    fn expect_keyword(&mut self, keyword: &Keyword) -> Result<&'tokens Token, ParserError> {
+        self.eat_keyword(keyword)
+            .ok_or_else(|| self.error(&format!("`{keyword}`")))
+    }
+
+    /// Consumes the next token, erroring if it is not an identifier.
+    fn expect_identifier(&mut self) -> Result<Identifier, ParserError> {
+        match self.peek() {
+            Some(token) if token.token_type == TokenType::Identifier => {
+                self.advance();
+                Ok(Identifier {
+                    name: token.content.clone(),
+                    location: token.location.clone(),
+                })
+            }
+            _ => Err(self.error("an identifier")),
+        }
+    }
+
+    /// Returns the location of the next token, or of the last token if the input ended.
+    fn location(&self) -> Location {
+        self.peek()
+            .or_else(|| self.tokens.last().copied())
+            .map(|token| token.location.clone())
+            .unwrap_or_default()
+    }
+
+    /// Creates an error describing what was expected at the next token.
+    fn error(&self, expected: &str) -> ParserError {
+        match self.peek() {
+            Some(token) => ParserError::UnexpectedToken {
+                expected: expected.to_owned(),
+                found: token.content.clone(),
+                location: token.location.clone(),
+            },
+            None => ParserError::UnexpectedEndOfInput {
+                expected: expected.to_owned(),
+                location: self.location(),
+            },
+        }
+    }
+
+    /// Enters a nesting level, e.g. of a nested expression or of an operation in a chain like `1 + 2 + 3`.
+    fn deeper(&mut self) -> Result<(), ParserError> {
+        if self.depth >= MAX_NESTING {
+            return Err(ParserError::NestedTooDeeply {
+                limit: MAX_NESTING,
+                location: self.location(),
+            });
+        }
+        self.depth += 1;
+
+        Ok(())
+    }
+
+    /// Runs a parsing function one nesting level deeper, see [`Parser::deeper`].
+    fn nested<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<T, ParserError>,
+    ) -> Result<T, ParserError> {
+        self.deeper()?;
+        let result: Result<T, ParserError> =
+            stacker::maybe_grow(RED_ZONE, STACK_SEGMENT, || parse(self));
+        self.depth -= 1;
+
+        result
+    }
+
+    // Program and statements
+
+    /// Parses all tokens into a program.
+    fn parse_program(&mut self) -> Result<Program, ParserError> {
+        let location: Location = self.location();
+        let mut statements: Vec<Statement> = vec![];
+
+        while self.peek().is_some() {
+            statements.push(self.parse_statement()?);
+        }
+
+        Ok(Program {
+            statements,
+            location,
+        })
+    }
+
+    /// Parses a block surrounded by braces.
+    fn parse_block(&mut self) -> Result<Block, ParserError> {
+        let location: Location = self.expect_mark(&Mark::BraceOpen)?.location.clone();
+        let mut statements: Vec<Statement> = vec![];
+
+        while self.eat_mark(&Mark::BraceClose).is_none() {
+            if self.peek().is_none() {
+                return Err(self.error("`}`"));
+            }
+
+            statements.push(self.nested(Self::parse_statement)?);
+        }
+
+        Ok(Block {
+            statements,
+            location,
+        })
+    }
+
+    /// Returns whether the next tokens start a variable declaration, e.g. `int goal`, `Player player`,
+    /// `Box<int> box` or `function(int) -> int double`.
+    fn is_declaration_start(&self) -> bool {
+        self.type_end(0).map_or(false, |end| {
+            matches!(
+                self.peek_nth(end),
+                Some(Token {
+                    token_type: TokenType::Identifier,
+                    ..
+                })
+            )
+        })
+    }
+
+    /// Returns the offset of the token after the type starting `offset` tokens after the next one, or `None` if no
+    /// type starts there. Type arguments are only skipped if their angle brackets are balanced, a trailing `?` is
+    /// part of the type.
+    fn type_end(&self, offset: usize) -> Option<usize> {
+        if self.is_keyword_at(offset, &Keyword::Function) {
+            return self.function_type_end(offset);
+        }
+        let name_end: usize = match self.peek_nth(offset) {
+            Some(&Token {
+                token_type: TokenType::Type(_),
+                ..
+            }) => offset + 1,
+            Some(&Token {
+                token_type: TokenType::Identifier,
+                ..
+            }) => self.name_end(offset),
+            _ => return None,
+        };
+        if !self.is_mark_at(name_end, &Mark::Less) {
+            return Some(self.nullable_end(name_end));
+        }
+
+        let mut depth: usize = 0;
+        let mut position: usize = name_end;
+        loop {
+            let token: &Token = self.peek_nth(position)?;
+            match token.token_type {
+                TokenType::Mark(Mark::Less) => depth += 1,
+                TokenType::Mark(Mark::Greater) => depth = depth.checked_sub(1)?,
+                TokenType::Mark(Mark::ShiftRight) => depth = depth.checked_sub(2)?,
+                TokenType::Mark(Mark::Comma | Mark::Dot | Mark::QuestionMark)
+                | TokenType::Type(_)
+                | TokenType::Identifier
+                | TokenType::TypeDefinition(TypeDefinition::None) => {}
+                TokenType::Keyword(Keyword::Function) => {
+                    position = self.function_type_end(position)?;
+                    continue;
+                }
+                _ => return None,
+            }
+            position += 1;
+
+            if depth == 0 {
+                return Some(self.nullable_end(position));
+            }
+        }
+    }
+
+    /// Returns the offset of the token after the name starting with the identifier `offset` tokens after the next
+    /// one. The name of an enum variant includes the name of its enum, e.g. `Shape.Circle`.
+    fn name_end(&self, offset: usize) -> usize {
+        let mut end: usize = offset + 1;
+        while self.is_mark_at(end, &Mark::Dot)
+            && matches!(
+                self.peek_nth(end + 1),
+                Some(Token {
+                    token_type: TokenType::Identifier,
+                    ..
+                })
+            )
+        {
+            end += 2;
+        }
+
+        end
+    }
+
+    /// Skips the `?` of a nullable type if it is `offset` tokens after the next one.
+    fn nullable_end(&self, offset: usize) -> usize {
+        if self.is_mark_at(offset, &Mark::QuestionMark) {
+            offset + 1
+        } else {
+            offset
+        }
+    }
+
+    /// Returns the offset of the token after the function type starting `offset` tokens after the next one, e.g.
+    /// `function(int, str) -> bool`, or `None` if no function type starts there.
+    fn function_type_end(&self, offset: usize) -> Option<usize> {
+        if !self.is_mark_at(offset + 1, &Mark::ParenthesisOpen) {
+            return None;
+        }
+
+        let mut position: usize = offset + 2;
+        if !self.is_mark_at(position, &Mark::ParenthesisClose) {
+            loop {
+                position = self.type_end(position)?;
+                if !self.is_mark_at(position, &Mark::Comma) {
+                    break;
+                }
+                position += 1;
+            }
+        }
+        if !self.is_mark_at(position, &Mark::ParenthesisClose)
+            || !self.is_mark_at(position + 1, &Mark::Arrow)
+        {
+            return None;
+        }
+
+        self.type_end(position + 2)
+    }
+
+    /// Parses a single statement.
+    #[allow(clippy::too_many_lines)]
+    fn parse_statement(&mut self) -> Result<Statement, ParserError> {
+        let Some(token) = self.peek() else {
+            return Err(self.error("a statement"));
+        };
+
+        match token.token_type {
+            TokenType::Mark(Mark::At) => {
+                let annotations: Vec<Annotation> = self.parse_annotations()?;
+                let is_declaration: bool = (self.is_keyword(&Keyword::Function)
+                    && !self.is_declaration_start())
+                    || [
+                        Keyword::Class,
+                        Keyword::Enum,
+                        Keyword::Interface,
+                        Keyword::Pub,
+                    ]
+                    .iter()
+                    .any(|keyword| self.is_keyword(keyword));
+                if !is_declaration {
+                    return Err(self.error("a function or class after the annotations"));
+                }
+
+                match self.parse_statement()? {
+                    Statement::Class(mut class) => {
+                        class.annotations = annotations;
+                        Ok(Statement::Class(class))
+                    }
+                    Statement::Function(mut function) => {
+                        function.annotations = annotations;
+                        Ok(Statement::Function(function))
+                    }
+                    statement => Ok(statement),
+                }
+            }
+            TokenType::Mark(Mark::BraceOpen) => Ok(Statement::Block(self.parse_block()?)),
+            TokenType::Keyword(Keyword::Break) => {
+                self.advance();
+                self.expect_mark(&Mark::Semicolon)?;
+                Ok(Statement::Break(Break {
+                    location: token.location.clone(),
+                }))
+            }
+            TokenType::Keyword(Keyword::Class | Keyword::Enum | Keyword::Interface) => {
+                Ok(Statement::Class(self.parse_class()?))
+            }
+            TokenType::Keyword(Keyword::Continue) => {
+                self.advance();
+                self.expect_mark(&Mark::Semicolon)?;
+                Ok(Statement::Continue(Continue {
+                    location: token.location.clone(),
+                }))
+            }
+            TokenType::Keyword(Keyword::Function) if self.is_declaration_start() => Ok(
+                Statement::VariableDeclaration(self.parse_variable_declaration()?),
+            ),
+            TokenType::Keyword(Keyword::Function) => Ok(Statement::Function(
+                self.parse_function(false, token.location.clone())?,
+            )),
+            TokenType::Keyword(Keyword::If) => Ok(Statement::If(self.parse_if()?)),
+            TokenType::Keyword(Keyword::Import) => Ok(Statement::Import(self.parse_import()?)),
+            TokenType::Keyword(Keyword::Match) => Ok(Statement::Match(self.parse_match(false)?)),
+            TokenType::Keyword(Keyword::Pub) => match self.peek_nth(1) {
+                Some(&Token {
+                    token_type: TokenType::Keyword(Keyword::Function),
+                    ..
+                }) => {
+                    self.advance();
+                    Ok(Statement::Function(
+                        self.parse_function(true, token.location.clone())?,
+                    ))
+                }
+                _ => Ok(Statement::Class(self.parse_class()?)),
+            },
+            TokenType::Keyword(Keyword::Return) => Ok(Statement::Return(self.parse_return()?)),
+            TokenType::Keyword(Keyword::Throw) => {
+                self.advance();
+                let value: Expression = self.parse_expression()?;
+                self.expect_mark(&Mark::Semicolon)?;
+                Ok(Statement::Throw(Throw {
+                    value,
+                    location: token.location.clone(),
+                }))
+            }
+            TokenType::Keyword(Keyword::Try) => Ok(Statement::Try(self.parse_try()?)),
+            TokenType::Keyword(Keyword::Use) => Ok(Statement::Use(self.parse_use()?)),
+            TokenType::Keyword(Keyword::Const | Keyword::Var) => Ok(
+                Statement::VariableDeclaration(self.parse_variable_declaration()?),
+            ),
+            TokenType::Keyword(Keyword::While) => Ok(Statement::While(self.parse_while()?)),
+            TokenType::Keyword(Keyword::For) => Ok(Statement::For(Box::new(self.parse_for()?))),
+            TokenType::Keyword(Keyword::Assert) => {
+                self.advance();
+                let condition: Expression = self.parse_expression()?;
+                let message: Option<Expression> = if self.eat_mark(&Mark::Comma).is_some() {
+                    Some(self.parse_expression()?)
+                } else {
+                    None
+                };
+                self.expect_mark(&Mark::Semicolon)?;
+                Ok(Statement::Assert(Assert {
+                    condition,
+                    message,
+                    location: token.location.clone(),
+                }))
+            }
+            TokenType::Keyword(Keyword::Yield) => {
+                self.advance();
+                let value: Expression = self.parse_expression()?;
+                self.expect_mark(&Mark::Semicolon)?;
+                Ok(Statement::Yield(Yield {
+                    value,
+                    location: token.location.clone(),
+                }))
+            }
+            _ if self.is_declaration_start() => Ok(Statement::VariableDeclaration(
+                self.parse_variable_declaration()?,
+            )),
+            _ => {
+                let expression: Expression = self.parse_expression()?;
+                self.expect_mark(&Mark::Semicolon)?;
+                Ok(Statement::Expression(ExpressionStatement {
+                    location: expression.location().clone(),
+                    expression,
+                }))
+            }
+        }
+    }
+
+    /// Parses an `if` statement, including its `else` or `else if` branches.
+    fn parse_if(&mut self) -> Result<If, ParserError> {
+        let location: Location = self.expect_keyword(&Keyword::If)?.location.clone();
+        let condition: Expression = self.parse_parenthesized_expression()?;
+        let then_block: Block = self.parse_block()?;
+
+        let else_block: Option<Block> = if self.eat_keyword(&Keyword::Else).is_none() {
+            None
+        } else if self.is_keyword(&Keyword::If) {
+            let nested: If = self.parse_if()?;
+            Some(Block {
+                location: nested.location.clone(),
+                statements: vec![Statement::If(nested)],
+            })
+        } else {
+            Some(self.parse_block()?)
+        };
+
+        Ok(If {
+            condition,
+            then_block,
+            else_block,
+            location,
+        })
+    }
+
+    /// Parses an `import` statement.
+    fn parse_import(&mut self) -> Result<Import, ParserError> {
+        let location: Location = self.expect_keyword(&Keyword::Import)?.location.clone();
+        let path: Vec<Identifier> = self.parse_path()?;
+        let alias: Option<Identifier> = self.parse_alias()?;
+        self.expect_mark(&Mark::Semicolon)?;
+
+        Ok(Import {
+            path,
+            alias,
+            location,
+        })
+    }
+
+    /// Parses a `use` statement. The path needs a module and an item, e.g. `random.randint`.
+    fn parse_use(&mut self) -> Result<Use, ParserError> {
+        let location: Location = self.expect_keyword(&Keyword::Use)?.location.clone();
+        let path: Vec<Identifier> = self.parse_path()?;
+        if path.len() < 2 {
+            return Err(self.error("`.`"));
+        }
+        let alias: Option<Identifier> = self.parse_alias()?;
+        self.expect_mark(&Mark::Semicolon)?;
+
+        Ok(Use {
+            path,
+            alias,
+            location,
+        })
+    }
+
+    /// Parses names separated by dots, e.g. `foo.bar`.
+    fn parse_path(&mut self) -> Result<Vec<Identifier>, ParserError> {
+        let mut path: Vec<Identifier> = vec![self.expect_identifier()?];
+        while self.eat_mark(&Mark::Dot).is_some() {
+            path.push(self.expect_identifier()?);
+        }
+
+        Ok(path)
+    }
+
+    /// Parses an optional alias after the `as` keyword.
+    fn parse_alias(&mut self) -> Result<Option<Identifier>, ParserError> {
+        if self.eat_keyword(&Keyword::As).is_some() {
+            Ok(Some(self.expect_identifier()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Parses a `return` statement.
+    fn parse_return(&mut self) -> Result<Return, ParserError> {
+        let location: Location = self.expect_keyword(&Keyword::Return)?.location.clone();
+
+        let value: Option<Expression> = if self.eat_mark(&Mark::Semicolon).is_some() {
+            None
+        } else {
+            let expression: Expression = self.parse_expression()?;
+            self.expect_mark(&Mark::Semicolon)?;
+            Some(expression)
+        };
+
+        Ok(Return { value, location })
+    }
+
+    /// Parses a `try` statement with its `catch` clauses and `finally` block.
+    fn parse_try(&mut self) -> Result<Try, ParserError> {
+        let location: Location = self.expect_keyword(&Keyword::Try)?.location.clone();
+        let body: Block = self.parse_block()?;
+
+        let mut catches: Vec<Catch> = vec![];
+        while let Some(token) = self.eat_keyword(&Keyword::Catch) {
+            let exception: TypeAnnotation = self.parse_type_annotation()?;
+            let binding: Option<Identifier> = if self.eat_mark(&Mark::ParenthesisOpen).is_some() {
+                let name: Identifier = self.expect_identifier()?;
+                self.expect_mark(&Mark::ParenthesisClose)?;
+                Some(name)
+            } else {
+                None
+            };
+
+            catches.push(Catch {
+                exception,
+                binding,
+                body: self.parse_block()?,
+                location: token.location.clone(),
+            });
+        }
+
+        let finally: Option<Block> = if self.eat_keyword(&Keyword::Finally).is_some() {
+            Some(self.parse_block()?)
+        } else if catches.is_empty() {
+            return Err(self.error("`catch` or `finally`"));
+        } else {
+            None
+        };
+
+        Ok(Try {
+            body,
+            catches,
+            finally,
+            location,
+        })
+    }
+
+    /// Parses a `while` loop.
+    fn parse_while(&mut self) -> Result<While, ParserError> {
+        let location: Location = self.expect_keyword(&Keyword::While)?.location.clone();
+        let condition: Expression = self.parse_parenthesized_expression()?;
+        let body: Block = self.parse_block()?;
+
+        Ok(While {
+            condition,
+            body,
+            location,
+        })
+    }
+
+    /// Parses a `for` loop, e.g. `for (int i in 0 .. 10 step 2) { ... }` or `for (var item in items) { ... }`.
+    /// `step` is only special after the end of a range, so it can still be used as a name.
+    fn parse_for(&mut self) -> Result<For, ParserError> {
+        let location: Location = self.expect_keyword(&Keyword::For)?.location.clone();
+        self.expect_mark(&Mark::ParenthesisOpen)?;
+        let type_annotation: Option<TypeAnnotation> = if self.eat_keyword(&Keyword::Var).is_some() {
+            None
+        } else {
+            Some(self.parse_type_annotation()?)
+        };
+        let variable: Identifier = self.expect_identifier()?;
+        self.expect_keyword(&Keyword::In)?;
+
+        let value: Expression = self.parse_expression()?;
+        let inclusive: bool = self.is_mark(&Mark::RangeInclusive);
+        let iterable: ForIterable = if inclusive || self.is_mark(&Mark::Range) {
+            self.advance();
+            let end: Expression = self.parse_expression()?;
+            let step: Option<Expression> = if self.peek().map_or(false, |token| {
+                token.token_type == TokenType::Identifier && token.content == "step"
+            }) {
+                self.advance();
+                Some(self.parse_expression()?)
+            } else {
+                None
+            };
+
+            ForIterable::Range(Box::new(ForRange {
+                location: value.location().clone(),
+                start: value,
+                end,
+                inclusive,
+                step,
+            }))
+        } else {
+            ForIterable::Value(Box::new(value))
+        };
+        self.expect_mark(&Mark::ParenthesisClose)?;
+        let body: Block = self.parse_block()?;
+
+        Ok(For {
+            type_annotation,
+            variable,
+            iterable,
+            body,
+            location,
+        })
+    }
+
+    /// Parses a variable declaration, e.g. `int goal = 3;` or `var goal = 3;`, or a constant declaration,
+    /// e.g. `const int MAX = 25 * 4;` or `const MAX = 25 * 4;`.
+    fn parse_variable_declaration(&mut self) -> Result<VariableDeclaration, ParserError> {
+        let location: Location = self.location();
+        let constant: bool = self.eat_keyword(&Keyword::Const).is_some();
+        let inferred: bool = self.eat_keyword(&Keyword::Var).is_some()
+            || (constant
+                && self
+                    .peek()
+                    .map_or(false, |token| token.token_type == TokenType::Identifier)
+                && self.is_mark_at(1, &Mark::Assign));
+        let type_annotation: Option<TypeAnnotation> = if inferred {
+            None
+        } else {
+            Some(self.parse_type_annotation()?)
+        };
+        let name: Identifier = self.expect_identifier()?;
+
+        if constant && !self.is_mark(&Mark::Assign) {
+            return Err(self.error("`=`"));
+        }
+
+        let value: Option<Expression> = if self.eat_mark(&Mark::Assign).is_some() {
+            Some(self.parse_expression()?)
+        } else {
+            None
+        };
+        self.expect_mark(&Mark::Semicolon)?;
+
+        Ok(VariableDeclaration {
+            constant,
+            type_annotation,
+            name,
+            value,
+            location,
+        })
+    }
+
+    /// Parses a type annotation, e.g. `int`, `none`, `Player`, `Box<int>` or `function(int, str) -> bool`.
+    fn parse_type_annotation(&mut self) -> Result<TypeAnnotation, ParserError> {
+        if let Some(token) = self.eat_keyword(&Keyword::Function) {
+            // The return type is stored after the parameter types, see the `Display` implementation
+            self.expect_mark(&Mark::ParenthesisOpen)?;
+            let mut arguments: Vec<TypeAnnotation> = vec![];
+            while self.eat_mark(&Mark::ParenthesisClose).is_none() {
+                arguments.push(self.nested(Self::parse_type_annotation)?);
+                if self.eat_mark(&Mark::Comma).is_none() {
+                    self.expect_mark(&Mark::ParenthesisClose)?;
+                    break;
+                }
+            }
+            self.expect_mark(&Mark::Arrow)?;
+            arguments.push(self.nested(Self::parse_type_annotation)?);
+
+            return Ok(TypeAnnotation {
+                name: "function".to_owned(),
+                arguments,
+                nullable: false,
+                location: token.location.clone(),
+            });
+        }
+
+        let mut annotation: TypeAnnotation = self.parse_type_name()?;
+
+        if self.eat_mark(&Mark::Less).is_some() {
+            loop {
+                annotation
+                    .arguments
+                    .push(self.nested(Self::parse_type_annotation)?);
+                if self.eat_mark(&Mark::Comma).is_none() {
+                    break;
+                }
+            }
+            self.expect_closing_angle()?;
+        }
+        // After `>>` closed two lists, the `?` belongs to the outer type, e.g. in `Box<Box<int>>?`
+        if self.closing == 0 && self.eat_mark(&Mark::QuestionMark).is_some() {
+            annotation.nullable = true;
+        }
+
+        Ok(annotation)
+    }
+
+    /// Consumes the closing angle bracket of a list of type arguments or type parameters.
+    /// A `>>` token closes two lists, e.g. in `Box<Box<int>>`.
+    fn expect_closing_angle(&mut self) -> Result<(), ParserError> {
+        if self.closing > 0 {
+            self.closing -= 1;
+        } else if self.eat_mark(&Mark::ShiftRight).is_some() {
+            self.closing += 1;
+        } else {
+            self.expect_mark(&Mark::Greater)?;
+        }
+
+        Ok(())
+    }
+
+    /// Parses the type parameters of a generic class or function, e.g. `<T, U: Animal>`.
+    /// Returns an empty list if the next token is not `<`.
+    fn parse_type_parameters(&mut self) -> Result<Vec<TypeParameter>, ParserError> {
+        let mut parameters: Vec<TypeParameter> = vec![];
+        if self.eat_mark(&Mark::Less).is_none() {
+            return Ok(parameters);
+        }
+
+        loop {
+            let name: Identifier = self.expect_identifier()?;
+            let bound: Option<TypeAnnotation> = if self.eat_mark(&Mark::Colon).is_some() {
+                Some(self.parse_type_annotation()?)
+            } else {
+                None
+            };
+            parameters.push(TypeParameter {
+                location: name.location.clone(),
+                name,
+                bound,
+            });
+
+            if self.eat_mark(&Mark::Comma).is_none() {
+                break;
+            }
+        }
+        self.expect_closing_angle()?;
+
+        Ok(parameters)
+    }
+
+    /// Parses the name of a type without type arguments.
+    fn parse_type_name(&mut self) -> Result<TypeAnnotation, ParserError> {
+        match self.peek() {
+            Some(&Token {
+                token_type: TokenType::TypeDefinition(TypeDefinition::None),
+                ref location,
+                ..
+            }) => {
+                self.advance();
+                Ok(TypeAnnotation {
+                    name: "none".to_owned(),
+                    arguments: vec![],
+                    nullable: false,
+                    location: location.clone(),
+                })
+            }
+            Some(&Token {
+                token_type: TokenType::Type(ref built_in),
+                ref location,
+                ..
+            }) => {
+                self.advance();
+                Ok(TypeAnnotation {
+                    name: type_name(built_in).to_owned(),
+                    arguments: vec![],
+                    nullable: false,
+                    location: location.clone(),
+                })
+            }
+            Some(token) if token.token_type == TokenType::Identifier => {
+                // The variants of an enum are named after it, e.g. `Shape.Circle`
+                let mut name: String = String::new();
+                for _ in 0..self.name_end(0) {
+                    if let Some(part) = self.advance() {
+                        name.push_str(&part.content);
+                    }
+                }
+
+                Ok(TypeAnnotation {
+                    name,
+                    arguments: vec![],
+                    nullable: false,
+                    location: token.location.clone(),
+                })
+            }
+            _ => Err(self.error("a type")),
+        }
+    }
+
+    // Declarations
+
+    /// Parses a class declaration, e.g. `pub class Player: Entity implements Drawable { ... }` or
+    /// `class Box<T> { ... }`, an interface declaration, e.g. `interface Shape: Drawable { ... }`, or an enum
+    /// declaration, e.g. `enum Shape { Circle(int radius), Empty; ... }`.
+    fn parse_class(&mut self) -> Result<Class, ParserError> {
+        let location: Location = self.location();
+        let public: bool = self.eat_keyword(&Keyword::Pub).is_some();
+        let interface: bool = self.eat_keyword(&Keyword::Interface).is_some();
+        let enumeration: bool = !interface && self.eat_keyword(&Keyword::Enum).is_some();
+        if !interface && !enumeration {
+            self.expect_keyword(&Keyword::Class)?;
+        }
+        let name: Identifier = self.expect_identifier()?;
+        let type_parameters: Vec<TypeParameter> = self.parse_type_parameters()?;
+
+        let mut bases: Vec<TypeAnnotation> = vec![];
+        if !enumeration && self.eat_mark(&Mark::Colon).is_some() {
+            loop {
+                bases.push(self.parse_type_annotation()?);
+
+                if self.eat_mark(&Mark::Comma).is_none() {
+                    break;
+                }
+            }
+        }
+
+        let mut interfaces: Vec<TypeAnnotation> = vec![];
+        if !interface && self.eat_keyword(&Keyword::Implements).is_some() {
+            loop {
+                interfaces.push(self.parse_type_annotation()?);
+
+                if self.eat_mark(&Mark::Comma).is_none() {
+                    break;
+                }
+            }
+        }
+
+        self.expect_mark(&Mark::BraceOpen)?;
+        let variants: Vec<Class> = if enumeration {
+            self.parse_variants(public, &name, &type_parameters)?
+        } else {
+            vec![]
+        };
+
+        let mut members: Vec<ClassMember> = vec![];
+        while self.eat_mark(&Mark::BraceClose).is_none() {
+            if self.peek().is_none() {
+                return Err(self.error("`}`"));
+            }
+
+            members.push(self.parse_class_member(&name.name)?);
+        }
+
+        Ok(Class {
+            annotations: vec![],
+            public,
+            interface,
+            enumeration,
+            name,
+            type_parameters,
+            bases,
+            interfaces,
+            variants,
+            members,
+            location,
+        })
+    }
+
+    /// Parses the variants of an enum, e.g. `Circle(int radius), Empty;`. The variants end with a semicolon if the
+    /// enum has methods, or with the closing brace of the enum.
+    ///
+    /// # Parameters
+    ///
+    /// - `public`: Whether the enum is public.
+    /// - `name`: The name of the enum.
+    /// - `type_parameters`: The type parameters of the enum, which the variants share.
+    fn parse_variants(
+        &mut self,
+        public: bool,
+        name: &Identifier,
+        type_parameters: &[TypeParameter],
+    ) -> Result<Vec<Class>, ParserError> {
+        let base: TypeAnnotation = TypeAnnotation {
+            name: name.name.clone(),
+            arguments: type_parameters
+                .iter()
+                .map(|parameter| TypeAnnotation {
+                    name: parameter.name.name.clone(),
+                    arguments: vec![],
+                    nullable: false,
+                    location: parameter.location.clone(),
+                })
+                .collect(),
+            nullable: false,
+            location: name.location.clone(),
+        };
+
+        let mut variants: Vec<Class> = vec![];
+        while matches!(self.peek(), Some(token) if token.token_type == TokenType::Identifier) {
+            let short: Identifier = self.expect_identifier()?;
+            // The variant is named after its enum, so enums can have variants with the same names
+            let variant: Identifier = Identifier {
+                name: format!("{}.{}", name.name, short.name),
+                location: short.location,
+            };
+            let parameters: Vec<Parameter> = if self.is_mark(&Mark::ParenthesisOpen) {
+                self.parse_parameters()?
+            } else {
+                vec![]
+            };
+
+            let mut members: Vec<ClassMember> = parameters
+                .iter()
+                .map(|parameter| {
+                    ClassMember::Field(Field {
+                        annotations: vec![],
+                        public: true,
+                        type_annotation: parameter.type_annotation.clone(),
+                        name: parameter.name.clone(),
+                        value: None,
+                        location: parameter.location.clone(),
+                    })
+                })
+                .collect();
+            members.push(ClassMember::Constructor(Function {
+                annotations: vec![],
+                public: true,
+                name: variant.clone(),
+                type_parameters: vec![],
+                parameters,
+                return_type: None,
+                requires: vec![],
+                ensures: vec![],
+                body: None,
+                location: variant.location.clone(),
+            }));
+
+            variants.push(Class {
+                annotations: vec![],
+                public,
+                interface: false,
+                enumeration: false,
+                location: variant.location.clone(),
+                name: variant,
+                type_parameters: type_parameters.to_vec(),
+                bases: vec![base.clone()],
+                interfaces: vec![],
+                variants: vec![],
+                members,
+            });
+
+            if self.eat_mark(&Mark::Comma).is_none() {
+                break;
+            }
+        }
+
+        if !self.is_mark(&Mark::BraceClose) {
+            self.expect_mark(&Mark::Semicolon)?;
+        }
+
+        Ok(variants)
+    }
+
+    /// Parses a field, method or constructor of the class named `class_name`.
+    fn parse_class_member(&mut self, class_name: &str) -> Result<ClassMember, ParserError> {
+        let annotations: Vec<Annotation> = self.parse_annotations()?;
+        let location: Location = self.location();
+        let public: bool = self.eat_keyword(&Keyword::Pub).is_some();
+
+        // A field can have a function type, e.g. `function(int) -> int callback;`
+        if self.is_keyword(&Keyword::Function) && !self.is_mark_at(1, &Mark::ParenthesisOpen) {
+            let mut method: Function = self.parse_function(public, location)?;
+            method.annotations = annotations;
+            return Ok(ClassMember::Method(method));
+        }
+
+        let is_constructor: bool = matches!(
+            self.peek(),
+            Some(token) if token.token_type == TokenType::Identifier && token.content == class_name
+        ) && self.is_mark_at(1, &Mark::ParenthesisOpen);
+        if is_constructor {
+            let name: Identifier = self.expect_identifier()?;
+            let parameters: Vec<Parameter> = self.parse_parameters()?;
+            let (requires, ensures): (Vec<Expression>, Vec<Expression>) = self.parse_contracts()?;
+            let body: Block = self.parse_block()?;
+
+            return Ok(ClassMember::Constructor(Function {
+                annotations,
+                public,
+                name,
+                type_parameters: vec![],
+                parameters,
+                return_type: None,
+                requires,
+                ensures,
+                body: Some(body),
+                location,
+            }));
+        }
+
+        let type_annotation: TypeAnnotation = self.parse_type_annotation()?;
+        let name: Identifier = self.expect_identifier()?;
+        let value: Option<Expression> = if self.eat_mark(&Mark::Assign).is_some() {
+            Some(self.parse_expression()?)
+        } else {
+            None
+        };
+        self.expect_mark(&Mark::Semicolon)?;
+
+        Ok(ClassMember::Field(Field {
+            annotations,
+            public,
+            type_annotation,
+            name,
+            value,
+            location,
+        }))
+    }
+
+    /// Parses a function after an optional `pub`, e.g. `function jump(int height) -> bool { ... }` or
+    /// `function first<T>(list<T> values) -> T { ... }`. A semicolon instead of a body declares an abstract method.
+    fn parse_function(
+        &mut self,
+        public: bool,
+        location: Location,
+    ) -> Result<Function, ParserError> {
+        self.expect_keyword(&Keyword::Function)?;
+        let name: Identifier = self.expect_identifier()?;
+        let type_parameters: Vec<TypeParameter> = self.parse_type_parameters()?;
+        let parameters: Vec<Parameter> = self.parse_parameters()?;
+
+        let return_type: Option<TypeAnnotation> = if self.eat_mark(&Mark::Arrow).is_some() {
+            Some(self.parse_type_annotation()?)
+        } else {
+            None
+        };
+        let (requires, ensures): (Vec<Expression>, Vec<Expression>) = self.parse_contracts()?;
+
+        let body: Option<Block> = if self.eat_mark(&Mark::Semicolon).is_some() {
+            None
+        } else {
+            Some(self.parse_block()?)
+        };
+
+        Ok(Function {
+            annotations: vec![],
+            public,
+            name,
+            type_parameters,
+            parameters,
+            return_type,
+            requires,
+            ensures,
+            body,
+            location,
+        })
+    }
+
+    /// Parses the `requires` and `ensures` clauses of a function, e.g. `requires divisor != 0 ensures result >= 0`.
+    /// Each clause has one condition, the clauses can be repeated and mixed.
+    ///
+    /// # Returns
+    ///
+    /// The conditions of the `requires` clauses and of the `ensures` clauses.
+    fn parse_contracts(&mut self) -> Result<(Vec<Expression>, Vec<Expression>), ParserError> {
+        let mut requires: Vec<Expression> = vec![];
+        let mut ensures: Vec<Expression> = vec![];
+
+        loop {
+            if self.eat_keyword(&Keyword::Requires).is_some() {
+                requires.push(self.parse_expression()?);
+            } else if self.eat_keyword(&Keyword::Ensures).is_some() {
+                ensures.push(self.parse_expression()?);
+            } else {
+                return Ok((requires, ensures));
+            }
+        }
+    }
+
+    /// Parses the annotations before a declaration, e.g. `@deprecated("Use jump instead") @inline`. Returns an
+    /// empty list if the next token is not `@`.
+    fn parse_annotations(&mut self) -> Result<Vec<Annotation>, ParserError> {
+        let mut annotations: Vec<Annotation> = vec![];
+
+        while let Some(token) = self.eat_mark(&Mark::At) {
+            let name: Identifier = self.expect_identifier()?;
+            let arguments: Vec<Argument> = if self.eat_mark(&Mark::ParenthesisOpen).is_some() {
+                self.parse_arguments()?
+            } else {
+                vec![]
+            };
+
+            annotations.push(Annotation {
+                name,
+                arguments,
+                location: token.location.clone(),
+            });
+        }
+
+        Ok(annotations)
+    }
+
+    /// Parses a parenthesized parameter list, e.g. `(int height, str name = "Player")`.
+    fn parse_parameters(&mut self) -> Result<Vec<Parameter>, ParserError> {
+        self.expect_mark(&Mark::ParenthesisOpen)?;
+        let mut parameters: Vec<Parameter> = vec![];
+
+        while self.eat_mark(&Mark::ParenthesisClose).is_none() {
+            let type_annotation: TypeAnnotation = self.parse_type_annotation()?;
+            let name: Identifier = self.expect_identifier()?;
+            let default: Option<Expression> = if self.eat_mark(&Mark::Assign).is_some() {
+                Some(self.parse_expression()?)
+            } else {
+                None
+            };
+            parameters.push(Parameter {
+                location: type_annotation.location.clone(),
+                type_annotation,
+                name,
+                default,
+            });
+
+            if self.eat_mark(&Mark::Comma).is_none() {
+                self.expect_mark(&Mark::ParenthesisClose)?;
+                break;
+            }
+        }
+
+        Ok(parameters)
+    }
+
+    // Patterns
+
+    /// Parses a `match` statement or expression.
+    /// The cases of match expressions must use an arrow (`->`) followed by an expression instead of a block.
+    fn parse_match(&mut self, expression: bool) -> Result<Match, ParserError> {
+        let location: Location = self.expect_keyword(&Keyword::Match)?.location.clone();
+        let subject: Expression = self.parse_parenthesized_expression()?;
+        self.expect_mark(&Mark::BraceOpen)?;
+
+        let mut arms: Vec<MatchArm> = vec![];
+        while self.eat_mark(&Mark::BraceClose).is_none() {
+            arms.push(self.parse_match_arm(expression)?);
+        }
+
+        Ok(Match {
+            subject: Box::new(subject),
+            arms,
+            location,
+        })
+    }
+
+    /// Parses a single case of a match, e.g. `case 1 | 2 if (lucky) { ... }` or `default -> 0,`.
+    fn parse_match_arm(&mut self, expression: bool) -> Result<MatchArm, ParserError> {
+        let location: Location = self.location();
+        let pattern: Option<Pattern> = if self.eat_keyword(&Keyword::Default).is_some() {
+            None
+        } else if self.eat_keyword(&Keyword::Case).is_some() {
+            Some(self.parse_pattern()?)
+        } else {
+            return Err(self.error("`case`, `default` or `}`"));
+        };
+
+        let guard: Option<Expression> = if self.eat_keyword(&Keyword::If).is_some() {
+            Some(self.parse_parenthesized_expression()?)
+        } else {
+            None
+        };
+
+        let body: MatchBody = if self.eat_mark(&Mark::Arrow).is_some() {
+            let value: Expression = self.parse_expression()?;
+            if expression {
+                self.eat_mark(&Mark::Comma);
+            } else {
+                self.expect_mark(&Mark::Semicolon)?;
+            }
+            MatchBody::Expression(value)
+        } else if expression {
+            return Err(self.error("`->`"));
+        } else {
+            MatchBody::Block(self.parse_block()?)
+        };
+
+        Ok(MatchArm {
+            pattern,
+            guard,
+            body,
+            location,
+        })
+    }
+
+    /// Parses a pattern, including alternatives separated by `|`.
+    fn parse_pattern(&mut self) -> Result<Pattern, ParserError> {
+        let location: Location = self.location();
+        let first: Pattern = self.parse_single_pattern()?;
+        if !self.is_mark(&Mark::BitOr) {
+            return Ok(first);
+        }
+
+        let mut patterns: Vec<Pattern> = vec![first];
+        while self.eat_mark(&Mark::BitOr).is_some() {
+            patterns.push(self.parse_single_pattern()?);
+        }
+
+        Ok(Pattern::Alternative(AlternativePattern {
+            patterns,
+            location,
+        }))
+    }
+
+    /// Parses a pattern without alternatives.
+    fn parse_single_pattern(&mut self) -> Result<Pattern, ParserError> {
+        let location: Location = self.location();
+
+        if self.is_mark(&Mark::Subtract)
+            || matches!(
+                self.peek(),
+                Some(Token {
+                    token_type: TokenType::TypeDefinition(_),
+                    ..
+                })
+            )
+        {
+            let start: Literal = self.parse_literal_pattern()?;
+            if self.eat_mark(&Mark::Range).is_none() {
+                return Ok(Pattern::Literal(start));
+            }
+
+            let end: RangeBound = self.parse_range_bound()?;
+            return Ok(Pattern::Range(RangePattern {
+                start: RangeBound::Literal(start),
+                end,
+                location,
+            }));
+        }
+
+        // A constant as the start of a range, e.g. `MIN .. 0`
+        if self.is_mark_at(1, &Mark::Range) {
+            let start: Identifier = self.expect_identifier()?;
+            self.expect_mark(&Mark::Range)?;
+            let end: RangeBound = self.parse_range_bound()?;
+            return Ok(Pattern::Range(RangePattern {
+                start: RangeBound::Constant(start),
+                end,
+                location,
+            }));
+        }
+
+        let is_class: bool = matches!(
+            self.peek(),
+            Some(Token {
+                token_type: TokenType::Identifier,
+                ..
+            })
+        ) && self.is_mark_at(self.name_end(0), &Mark::ParenthesisOpen);
+        if is_class {
+            let class: TypeAnnotation = self.parse_type_annotation()?;
+            self.expect_mark(&Mark::ParenthesisOpen)?;
+
+            let mut fields: Vec<Pattern> = vec![];
+            if self.eat_mark(&Mark::ParenthesisClose).is_none() {
+                loop {
+                    fields.push(self.nested(Self::parse_pattern)?);
+                    if self.eat_mark(&Mark::Comma).is_none() {
+                        break;
+                    }
+                }
+                self.expect_mark(&Mark::ParenthesisClose)?;
+            }
+
+            return Ok(Pattern::Class(ClassPattern {
+                class,
+                fields,
+                location,
+            }));
+        }
+
+        let type_annotation: Option<TypeAnnotation> = if self.is_declaration_start() {
+            Some(self.parse_type_annotation()?)
+        } else {
+            None
+        };
+        let name: Identifier = self.expect_identifier()?;
+
+        Ok(Pattern::Binding(BindingPattern {
+            type_annotation,
+            name,
+            location,
+        }))
+    }
+
+    /// Parses a literal inside a pattern. Integers may be negative, e.g. `-1`.
+    fn parse_literal_pattern(&mut self) -> Result<Literal, ParserError> {
+        let minus: Option<&Token> = self.eat_mark(&Mark::Subtract);
+
+        match self.peek() {
+            Some(&Token {
+                token_type: TokenType::TypeDefinition(ref kind),
+                ref content,
+                ref location,
+            }) if minus.is_none() || *kind == TypeDefinition::Integer => {
+                self.advance();
+                Ok(Literal {
+                    kind: kind.clone(),
+                    value: minus.map_or_else(|| content.clone(), |_| format!("-{content}")),
+                    location: minus.map_or(location, |token| &token.location).clone(),
+                })
+            }
+            _ => Err(self.error("a literal")),
+        }
+    }
+
+    /// Parses a bound of a range pattern: an integer literal or the name of a constant.
+    fn parse_range_bound(&mut self) -> Result<RangeBound, ParserError> {
+        if matches!(
+            self.peek(),
+            Some(Token {
+                token_type: TokenType::Identifier,
+                ..
+            })
+        ) {
+            return Ok(RangeBound::Constant(self.expect_identifier()?));
+        }
+
+        Ok(RangeBound::Literal(self.parse_literal_pattern()?))
+    }
+
+    // Expressions
+
+    /// Parses an expression surrounded by parentheses, e.g. the condition of an `if` statement.
+    fn parse_parenthesized_expression(&mut self) -> Result<Expression, ParserError> {
+        self.expect_mark(&Mark::ParenthesisOpen)?;
+        let expression: Expression = self.parse_expression()?;
+        self.expect_mark(&Mark::ParenthesisClose)?;
+
+        Ok(expression)
+    }
+
+    /// Parses an expression.
+    fn parse_expression(&mut self) -> Result<Expression, ParserError> {
+        self.nested(Self::parse_assignment)
+    }
+
+    /// Parses an assignment or anything binding stronger. Assignments are right associative.
+    fn parse_assignment(&mut self) -> Result<Expression, ParserError> {
+        let target: Expression = self.parse_binary(0)?;
+
+        let Some(&Token {
+            token_type: TokenType::Mark(ref operator),
+            ..
+        }) = self.peek()
+        else {
+            return Ok(target);
+        };
+        if !is_assignment_mark(operator) {
+            return Ok(target);
+        }
+
+        if !matches!(
+            target,
+            Expression::Identifier(_) | Expression::Member(_) | Expression::Index(_)
+        ) {
+            return Err(ParserError::InvalidAssignmentTarget {
+                location: target.location().clone(),
+            });
+        }
+
+        self.advance();
+        let value: Expression = self.parse_assignment()?;
+
+        Ok(Expression::Assignment(Assignment {
+            location: target.location().clone(),
+            target: Box::new(target),
+            operator: operator.clone(),
+            value: Box::new(value),
+        }))
+    }
+
+    /// Parses binary operations using precedence climbing.
+    /// Only operators with a precedence of at least `minimum_precedence` are consumed.
+    fn parse_binary(&mut self, minimum_precedence: u8) -> Result<Expression, ParserError> {
+        let depth: usize = self.depth;
+        let mut left: Expression = self.parse_unary()?;
+
+        while let Some(&Token {
+            token_type: TokenType::Mark(ref operator),
+            ref location,
+            ..
+        }) = self.peek()
+        {
+            let Some(precedence) = binary_precedence(operator) else {
+                break;
+            };
+            if precedence < minimum_precedence {
+                break;
+            }
+
+            self.advance();
+            // Every operation nests the operations before it one level deeper, e.g. `1 + 2 + 3` is `(1 + 2) + 3`
+            self.deeper()?;
+            // `**` is right associative, all other binary operators are left associative
+            let next_minimum: u8 = if *operator == Mark::Exponentiation {
+                precedence
+            } else {
+                precedence + 1
+            };
+            let right: Expression = self.nested(|parser| parser.parse_binary(next_minimum))?;
+
+            left = Expression::Binary(Binary {
+                left: Box::new(left),
+                operator: operator.clone(),
+                right: Box::new(right),
+                location: location.clone(),
+            });
+        }
+        self.depth = depth;
+
+        Ok(left)
+    }
+
+    /// Parses prefix unary operations (`!`, `-`, `~`, `++`, `--`).
+    fn parse_unary(&mut self) -> Result<Expression, ParserError> {
+        if let Some(&Token {
+            token_type:
+                TokenType::Mark(
+                    ref operator @ (Mark::Bang
+                    | Mark::Subtract
+                    | Mark::BitNot
+                    | Mark::Increase
+                    | Mark::Decrease),
+                ),
+            ref location,
+            ..
+        }) = self.peek()
+        {
+            self.advance();
+            let operand: Expression = self.nested(Self::parse_unary)?;
+
+            return Ok(Expression::Unary(Unary {
+                operator: operator.clone(),
+                operand: Box::new(operand),
+                postfix: false,
+                location: location.clone(),
+            }));
+        }
+
+        self.parse_postfix()
+    }
+
+    /// Parses calls, member accesses, index operations and postfix `++`/`--`.
+    fn parse_postfix(&mut self) -> Result<Expression, ParserError> {
+        let depth: usize = self.depth;
+        let mut expression: Expression = self.parse_primary()?;
+
+        loop {
+            if let Some(token) = self.eat_mark(&Mark::ParenthesisOpen) {
+                let arguments: Vec<Argument> = self.parse_arguments()?;
+                expression = Expression::Call(Call {
+                    callee: Box::new(expression),
+                    arguments,
+                    location: token.location.clone(),
+                });
+            } else if let Some(token) = self.eat_mark(&Mark::Dot) {
+                let member: Identifier = self.expect_identifier()?;
+                expression = Expression::Member(Member {
+                    target: Box::new(expression),
+                    member,
+                    safe: false,
+                    location: token.location.clone(),
+                });
+            } else if let Some(token) = self.eat_mark(&Mark::SafeNavigation) {
+                let member: Identifier = self.expect_identifier()?;
+                expression = Expression::Member(Member {
+                    target: Box::new(expression),
+                    member,
+                    safe: true,
+                    location: token.location.clone(),
+                });
+            } else if let Some(token) = self.eat_mark(&Mark::BracketOpen) {
+                let start: Option<Expression> = if self.is_mark(&Mark::Range) {
+                    None
+                } else {
+                    Some(self.parse_expression()?)
+                };
+
+                expression = match start {
+                    Some(index) if self.eat_mark(&Mark::Range).is_none() => {
+                        Expression::Index(Index {
+                            target: Box::new(expression),
+                            index: Box::new(index),
+                            location: token.location.clone(),
+                        })
+                    }
+                    bound => {
+                        self.eat_mark(&Mark::Range);
+                        let end: Option<Expression> = if self.is_mark(&Mark::BracketClose) {
+                            None
+                        } else {
+                            Some(self.parse_expression()?)
+                        };
+                        Expression::Slice(Slice {
+                            target: Box::new(expression),
+                            start: bound.map(Box::new),
+                            end: end.map(Box::new),
+                            location: token.location.clone(),
+                        })
+                    }
+                };
+                self.expect_mark(&Mark::BracketClose)?;
+            } else if let Some(&Token {
+                token_type: TokenType::Mark(ref operator @ (Mark::Increase | Mark::Decrease)),
+                ref location,
+                ..
+            }) = self.peek()
+            {
+                self.advance();
+                expression = Expression::Unary(Unary {
+                    operator: operator.clone(),
+                    operand: Box::new(expression),
+                    postfix: true,
+                    location: location.clone(),
+                });
+            } else {
+                self.depth = depth;
+                return Ok(expression);
+            }
+            // Every operation nests the operations before it one level deeper, e.g. `a.b.c` is `(a.b).c`
+            self.deeper()?;
+        }
+    }
+
+    /// Parses the arguments of a call. The opening parenthesis must already be consumed.
+    /// Arguments starting with a name and `=` are keyword arguments, e.g. `maximum = 25`.
+    fn parse_arguments(&mut self) -> Result<Vec<Argument>, ParserError> {
+        let mut arguments: Vec<Argument> = vec![];
+
+        while self.eat_mark(&Mark::ParenthesisClose).is_none() {
+            let location: Location = self.location();
+            let is_keyword: bool = matches!(
+                self.peek(),
+                Some(Token {
+                    token_type: TokenType::Identifier,
+                    ..
+                })
+            ) && self.is_mark_at(1, &Mark::Assign);
+
+            let name: Option<Identifier> = if is_keyword {
+                let name: Identifier = self.expect_identifier()?;
+                self.advance();
+                Some(name)
+            } else {
+                None
+            };
+            arguments.push(Argument {
+                name,
+                value: self.parse_expression()?,
+                location,
+            });
+
+            if self.eat_mark(&Mark::Comma).is_none() {
+                self.expect_mark(&Mark::ParenthesisClose)?;
+                break;
+            }
+        }
+
+        Ok(arguments)
+    }
+
+    /// Parses the elements of a list or tuple literal up to the closing mark, which is consumed. The opening mark
+    /// must already be consumed. A trailing comma is allowed.
+    fn parse_elements(&mut self, close: &Mark) -> Result<Vec<Expression>, ParserError> {
+        let mut elements: Vec<Expression> = vec![];
+
+        while self.eat_mark(close).is_none() {
+            elements.push(self.parse_expression()?);
+
+            if self.eat_mark(&Mark::Comma).is_none() {
+                self.expect_mark(close)?;
+                break;
+            }
+        }
+
+        Ok(elements)
+    }
+
+    /// Parses a dict literal, e.g. `{"a": 1, "b": 2}`. A trailing comma is allowed.
+    fn parse_dict(&mut self) -> Result<Dict, ParserError> {
+        let location: Location = self.expect_mark(&Mark::BraceOpen)?.location.clone();
+        let mut entries: Vec<DictEntry> = vec![];
+
+        while self.eat_mark(&Mark::BraceClose).is_none() {
+            let entry_location: Location = self.location();
+            let key: Expression = self.parse_expression()?;
+            self.expect_mark(&Mark::Colon)?;
+            entries.push(DictEntry {
+                key,
+                value: self.parse_expression()?,
+                location: entry_location,
+            });
+
+            if self.eat_mark(&Mark::Comma).is_none() {
+                self.expect_mark(&Mark::BraceClose)?;
+                break;
+            }
+        }
+
+        Ok(Dict { entries, location })
+    }
+
+    /// Parses a parenthesized expression or a tuple literal, e.g. `(1 + 2)`, `(1, "x")` or `(1,)`.
+    fn parse_parenthesized_or_tuple(&mut self) -> Result<Expression, ParserError> {
+        let location: Location = self.expect_mark(&Mark::ParenthesisOpen)?.location.clone();
+        if self.eat_mark(&Mark::ParenthesisClose).is_some() {
+            return Ok(Expression::Tuple(Tuple {
+                elements: vec![],
+                location,
+            }));
+        }
+
+        let first: Expression = self.parse_expression()?;
+        if self.eat_mark(&Mark::Comma).is_none() {
+            self.expect_mark(&Mark::ParenthesisClose)?;
+            return Ok(first);
+        }
+
+        let mut elements: Vec<Expression> = vec![first];
+        elements.extend(self.parse_elements(&Mark::ParenthesisClose)?);
+
+        Ok(Expression::Tuple(Tuple { elements, location }))
+    }
+
+    /// Returns whether the next tokens start a lambda, e.g. `(int x) -> x * 2`: parentheses followed by an arrow.
+    fn is_lambda_start(&self) -> bool {
+        let mut depth: usize = 0;
+        let mut offset: usize = 0;
+        while let Some(token) = self.peek_nth(offset) {
+            match token.token_type {
+                TokenType::Mark(Mark::ParenthesisOpen) => depth += 1,
+                TokenType::Mark(Mark::ParenthesisClose) => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return self.is_mark_at(offset + 1, &Mark::Arrow);
+                    }
+                }
+                _ if depth == 0 => return false,
+                _ => {}
+            }
+            offset += 1;
+        }
+
+        false
+    }
+
+    /// Parses a lambda, e.g. `(int x) -> x * 2`, `(int x) -> int { return x * 2; }` or
+    /// `function (int x) -> int { return x * 2; }`. Only lambdas with a block can have a return type.
+    fn parse_lambda(&mut self) -> Result<Lambda, ParserError> {
+        let location: Location = self.location();
+        let anonymous: bool = self.eat_keyword(&Keyword::Function).is_some();
+        let parameters: Vec<Parameter> = self.parse_parameters()?;
+
+        let arrow: bool = self.eat_mark(&Mark::Arrow).is_some();
+        let returns_block: bool = self
+            .type_end(0)
+            .map_or(false, |end| self.is_mark_at(end, &Mark::BraceOpen));
+        let return_type: Option<TypeAnnotation> = if arrow && (anonymous || returns_block) {
+            Some(self.parse_type_annotation()?)
+        } else {
+            None
+        };
+
+        let body: LambdaBody = if self.is_mark(&Mark::BraceOpen) {
+            LambdaBody::Block(self.parse_block()?)
+        } else if arrow && !anonymous {
+            LambdaBody::Expression(Box::new(self.parse_expression()?))
+        } else {
+            return Err(self.error("`{`"));
+        };
+
+        Ok(Lambda {
+            parameters,
+            return_type,
+            body,
+            location,
+        })
+    }
+
+    /// Parses literals, identifiers, built-in type names used as conversion functions, parenthesized expressions,
+    /// lambdas and list, dict and tuple literals.
+    fn parse_primary(&mut self) -> Result<Expression, ParserError> {
+        let Some(token) = self.peek() else {
+            return Err(self.error("an expression"));
+        };
+
+        match token.token_type {
+            TokenType::TypeDefinition(TypeDefinition::FormatString) => {
+                self.advance();
+                Ok(Expression::FormatString(parse_format_string(
+                    token, self.depth,
+                )?))
+            }
+            TokenType::TypeDefinition(ref kind) => {
+                self.advance();
+                Ok(Expression::Literal(Literal {
+                    kind: kind.clone(),
+                    value: token.content.clone(),
+                    location: token.location.clone(),
+                }))
+            }
+            TokenType::Identifier => {
+                self.advance();
+                Ok(Expression::Identifier(Identifier {
+                    name: token.content.clone(),
+                    location: token.location.clone(),
+                }))
+            }
+            // Conversion functions like `int(...)`
+            TokenType::Type(ref built_in) if self.is_mark_at(1, &Mark::ParenthesisOpen) => {
+                self.advance();
+                Ok(Expression::Identifier(Identifier {
+                    name: type_name(built_in).to_owned(),
+                    location: token.location.clone(),
+                }))
+            }
+            TokenType::Keyword(Keyword::Match) => Ok(Expression::Match(self.parse_match(true)?)),
+            TokenType::Mark(Mark::ParenthesisOpen) if self.is_lambda_start() => {
+                Ok(Expression::Lambda(self.parse_lambda()?))
+            }
+            TokenType::Keyword(Keyword::Function) => Ok(Expression::Lambda(self.parse_lambda()?)),
+            TokenType::Mark(Mark::ParenthesisOpen) => self.parse_parenthesized_or_tuple(),
+            TokenType::Mark(Mark::BracketOpen) => {
+                self.advance();
+                Ok(Expression::List(List {
+                    elements: self.parse_elements(&Mark::BracketClose)?,
+                    location: token.location.clone(),
+                }))
+            }
+            TokenType::Mark(Mark::BraceOpen) => Ok(Expression::Dict(self.parse_dict()?)),
+            _ => Err(self.error("an expression")),
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! Prints nodes of the abstract syntax tree as source code, e.g. to show the checked condition of an assertion.
+// I Language parser.
+// Version: 1.0.0
+
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use lexer::tokens::mark::Mark;
+use lexer::tokens::token::TypeDefinition;
+
+use crate::ast::{Argument, Expression, FormatPart, LambdaBody, Parameter};
+use crate::parse::binary_precedence;
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// The precedence of prefix unary operators, which bind stronger than all binary operators.
+const UNARY: u8 = 13;
+
+/// The precedence of calls, member accesses, indexing and the other postfix operations.
+const POSTFIX: u8 = 14;
+
+
+//////////////
+// PRINTING //
+//////////////
+
+/// Prints an expression as source code. Parentheses are only added where the precedence of the operators needs
+/// them, so the result can differ from the source the expression was parsed from. Blocks are abbreviated as
+/// `{ ... }`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use parser::print;
+/// let tokens = lexer::lex::lex("(a+1)*items[0].size(2);", "<stdin>").unwrap();
+/// let program = parser::parse::parse(&tokens).unwrap();
+/// let parser::ast::Statement::Expression(ref statement) = program.statements[0] else { unreachable!() };
+///
+/// assert_eq!(print::expression(&statement.expression), "(a + 1) * items[0].size(2)");
+/// ```
+#[inline]
+#[must_use]
+#[allow(clippy::too_many_lines)]
+pub fn expression(node: &Expression) -> String {
+    match *node {
+        Expression::Assignment(ref assignment) => format!(
+            "{} {} {}",
+            expression(&assignment.target),
+            assignment.operator,
+            expression(&assignment.value)
+        ),
+        Expression::Binary(ref binary) => {
+            let precedence: u8 = binary_precedence(&binary.operator).unwrap_or_default();
+            // `**` is right associative, all other binary operators are left associative
+            let (left, right): (u8, u8) = if binary.operator == Mark::Exponentiation {
+                (precedence + 1, precedence)
+            } else {
+                (precedence, precedence + 1)
+            };
+            format!(
+                "{} {} {}",
+                operand(&binary.left, left),
+                binary.operator,
+                operand(&binary.right, right)
+            )
+        }
+        Expression::Call(ref call) => format!(
+            "{}({})",
+            operand(&call.callee, POSTFIX),
+            list(&call.arguments, argument)
+        ),
+        Expression::Dict(ref dict) => format!(
+            "{{{}}}",
+            list(&dict.entries, |entry| format!(
+                "{}: {}",
+                expression(&entry.key),
+                expression(&entry.value)
+            ))
+        ),
+        Expression::FormatString(ref format_string) => {
+            let parts: String = format_string
+                .parts
+                .iter()
+                .map(|part| match *part {
+                    FormatPart::Text(ref text) => text.replace('{', "{{").replace('}', "}}"),
+                    FormatPart::Field(ref field) => match field.specification {
+                        Some(ref specification) => {
+                            format!("{{{}:{specification}}}", expression(&field.value))
+                        }
+                        None => format!("{{{}}}", expression(&field.value)),
+                    },
+                })
+                .collect();
+            format!("f\"{parts}\"")
+        }
+        Expression::Identifier(ref identifier) => identifier.name.clone(),
+        Expression::Index(ref index) => format!(
+            "{}[{}]",
+            operand(&index.target, POSTFIX),
+            expression(&index.index)
+        ),
+        Expression::Lambda(ref lambda) => {
+            let return_type: String = lambda
+                .return_type
+                .as_ref()
+                .map(|annotation| format!(" {annotation}"))
+                .unwrap_or_default();
+            let body: String = match lambda.body {
+                LambdaBody::Block(_) => "{ ... }".to_owned(),
+                LambdaBody::Expression(ref value) => expression(value),
+            };
+            match lambda.return_type {
+                Some(_) => format!(
+                    "function ({}) ->{return_type} {body}",
+                    list(&lambda.parameters, parameter)
+                ),
+                None => format!("({}) -> {body}", list(&lambda.parameters, parameter)),
+            }
+        }
+        Expression::List(ref elements) => format!("[{}]", list(&elements.elements, expression)),
+        Expression::Literal(ref literal) => match literal.kind {
+            TypeDefinition::String => format!("\"{}\"", literal.value),
+            _ => literal.value.clone(),
+        },
+        Expression::Match(ref matched) => {
+            format!("match ({}) {{ ... }}", expression(&matched.subject))
+        }
+        Expression::Member(ref member) => format!(
+            "{}{}{}",
+            operand(&member.target, POSTFIX),
+            if member.safe { "?." } else { "." },
+            member.member.name
+        ),
+        Expression::Slice(ref slice) => {
+            let range: Vec<String> = [
+                slice.start.as_deref().map(expression),
+                Some(Mark::Range.to_string()),
+                slice.end.as_deref().map(expression),
+            ]
+            .into_iter()
+            .flatten()
+            .collect();
+            format!("{}[{}]", operand(&slice.target, POSTFIX), range.join(" "))
+        }
+        Expression::Tuple(ref tuple) => match *tuple.elements {
+            [ref element] => format!("({},)", expression(element)),
+            ref elements => format!("({})", list(elements, expression)),
+        },
+        Expression::Unary(ref unary) if unary.postfix => {
+            format!("{}{}", operand(&unary.operand, POSTFIX), unary.operator)
+        }
+        Expression::Unary(ref unary) => {
+            format!("{}{}", unary.operator, operand(&unary.operand, UNARY))
+        }
+    }
+}
+
+/// Returns how strongly an expression binds, see [`binary_precedence`]. Assignments and lambdas bind the weakest.
+fn precedence(node: &Expression) -> u8 {
+    match *node {
+        Expression::Assignment(_) | Expression::Lambda(_) => 0,
+        Expression::Binary(ref binary) => binary_precedence(&binary.operator).unwrap_or_default(),
+        Expression::Unary(ref unary) if !unary.postfix => UNARY,
+        _ => POSTFIX,
+    }
+}
+
+/// Prints an operand, in parentheses if it binds weaker than the minimum precedence of its position.
+fn operand(node: &Expression, minimum: u8) -> String {
+    if precedence(node) < minimum {
+        format!("({})", expression(node))
+    } else {
+        expression(node)
+    }
+}
+
+/// Prints an argument of a call, with its name if it is a keyword argument.
+fn argument(node: &Argument) -> String {
+    match node.name {
+        Some(ref name) => format!("{} = {}", name.name, expression(&node.value)),
+        None => expression(&node.value),
+    }
+}
+
+/// Prints a parameter of a lambda, with its default value if it has one.
+fn parameter(node: &Parameter) -> String {
+    match node.default {
+        Some(ref default) => format!(
+            "{} {} = {}",
+            node.type_annotation,
+            node.name.name,
+            expression(default)
+        ),
+        None => format!("{} {}", node.type_annotation, node.name.name),
+    }
+}
+
+/// Prints nodes separated by commas.
+fn list<T>(nodes: &[T], print: impl Fn(&T) -> String) -> String {
+    nodes.iter().map(print).collect::<Vec<String>>().join(", ")
+}
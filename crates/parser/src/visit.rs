@@ -0,0 +1,1081 @@
+//! A visitor walking the AST by reference.
+// I Language AST visitor.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+// This file is generated by `tools/generate_visitors.py` from `ast.rs`. Do not edit it by hand,
+// instead change the AST and run `just generate-visitors`.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use crate::ast::{
+    AlternativePattern, Annotation, Argument, Assert, Assignment, Binary, BindingPattern, Block,
+    Break, Call, Catch, Class, ClassMember, ClassPattern, Continue, Dict, DictEntry, Expression,
+    ExpressionStatement, Field, For, ForIterable, ForRange, FormatField, FormatPart, FormatString,
+    Function, Identifier, If, Import, Index, Lambda, LambdaBody, List, Literal, Match, MatchArm,
+    MatchBody, Member, Parameter, Pattern, Program, RangeBound, RangePattern, Return, Slice,
+    Statement, Throw, Try, Tuple, TypeAnnotation, TypeParameter, Unary, Use, VariableDeclaration,
+    While, Yield,
+};
+
+
+///////////
+// TRAIT //
+///////////
+
+/// A visitor walking the AST by reference. Every method defaults to visiting the children of the node.
+/// Override a method to handle a node type and call the matching `walk_*` function to continue with its children.
+/// The `'ast` lifetime allows implementations to keep references to the visited nodes.
+pub trait Visitor<'ast> {
+    /// Visits an [`AlternativePattern`] node. Defaults to [`walk_alternative_pattern`].
+    #[inline]
+    fn visit_alternative_pattern(&mut self, node: &'ast AlternativePattern) {
+        walk_alternative_pattern(self, node);
+    }
+
+    /// Visits an [`Annotation`] node. Defaults to [`walk_annotation`].
+    #[inline]
+    fn visit_annotation(&mut self, node: &'ast Annotation) {
+        walk_annotation(self, node);
+    }
+
+    /// Visits an [`Argument`] node. Defaults to [`walk_argument`].
+    #[inline]
+    fn visit_argument(&mut self, node: &'ast Argument) {
+        walk_argument(self, node);
+    }
+
+    /// Visits an [`Assert`] node. Defaults to [`walk_assert`].
+    #[inline]
+    fn visit_assert(&mut self, node: &'ast Assert) {
+        walk_assert(self, node);
+    }
+
+    /// Visits an [`Assignment`] node. Defaults to [`walk_assignment`].
+    #[inline]
+    fn visit_assignment(&mut self, node: &'ast Assignment) {
+        walk_assignment(self, node);
+    }
+
+    /// Visits a [`Binary`] node. Defaults to [`walk_binary`].
+    #[inline]
+    fn visit_binary(&mut self, node: &'ast Binary) {
+        walk_binary(self, node);
+    }
+
+    /// Visits a [`BindingPattern`] node. Defaults to [`walk_binding_pattern`].
+    #[inline]
+    fn visit_binding_pattern(&mut self, node: &'ast BindingPattern) {
+        walk_binding_pattern(self, node);
+    }
+
+    /// Visits a [`Block`] node. Defaults to [`walk_block`].
+    #[inline]
+    fn visit_block(&mut self, node: &'ast Block) {
+        walk_block(self, node);
+    }
+
+    /// Visits a [`Break`] node. Defaults to [`walk_break`].
+    #[inline]
+    fn visit_break(&mut self, node: &'ast Break) {
+        walk_break(self, node);
+    }
+
+    /// Visits a [`Call`] node. Defaults to [`walk_call`].
+    #[inline]
+    fn visit_call(&mut self, node: &'ast Call) {
+        walk_call(self, node);
+    }
+
+    /// Visits a [`Catch`] node. Defaults to [`walk_catch`].
+    #[inline]
+    fn visit_catch(&mut self, node: &'ast Catch) {
+        walk_catch(self, node);
+    }
+
+    /// Visits a [`Class`] node. Defaults to [`walk_class`].
+    #[inline]
+    fn visit_class(&mut self, node: &'ast Class) {
+        walk_class(self, node);
+    }
+
+    /// Visits a [`ClassMember`] node. Defaults to [`walk_class_member`].
+    #[inline]
+    fn visit_class_member(&mut self, node: &'ast ClassMember) {
+        walk_class_member(self, node);
+    }
+
+    /// Visits a [`ClassPattern`] node. Defaults to [`walk_class_pattern`].
+    #[inline]
+    fn visit_class_pattern(&mut self, node: &'ast ClassPattern) {
+        walk_class_pattern(self, node);
+    }
+
+    /// Visits a [`Continue`] node. Defaults to [`walk_continue`].
+    #[inline]
+    fn visit_continue(&mut self, node: &'ast Continue) {
+        walk_continue(self, node);
+    }
+
+    /// Visits a [`Dict`] node. Defaults to [`walk_dict`].
+    #[inline]
+    fn visit_dict(&mut self, node: &'ast Dict) {
+        walk_dict(self, node);
+    }
+
+    /// Visits a [`DictEntry`] node. Defaults to [`walk_dict_entry`].
+    #[inline]
+    fn visit_dict_entry(&mut self, node: &'ast DictEntry) {
+        walk_dict_entry(self, node);
+    }
+
+    /// Visits an [`Expression`] node. Defaults to [`walk_expression`].
+    #[inline]
+    fn visit_expression(&mut self, node: &'ast Expression) {
+        walk_expression(self, node);
+    }
+
+    /// Visits an [`ExpressionStatement`] node. Defaults to [`walk_expression_statement`].
+    #[inline]
+    fn visit_expression_statement(&mut self, node: &'ast ExpressionStatement) {
+        walk_expression_statement(self, node);
+    }
+
+    /// Visits a [`Field`] node. Defaults to [`walk_field`].
+    #[inline]
+    fn visit_field(&mut self, node: &'ast Field) {
+        walk_field(self, node);
+    }
+
+    /// Visits a [`For`] node. Defaults to [`walk_for`].
+    #[inline]
+    fn visit_for(&mut self, node: &'ast For) {
+        walk_for(self, node);
+    }
+
+    /// Visits a [`ForIterable`] node. Defaults to [`walk_for_iterable`].
+    #[inline]
+    fn visit_for_iterable(&mut self, node: &'ast ForIterable) {
+        walk_for_iterable(self, node);
+    }
+
+    /// Visits a [`ForRange`] node. Defaults to [`walk_for_range`].
+    #[inline]
+    fn visit_for_range(&mut self, node: &'ast ForRange) {
+        walk_for_range(self, node);
+    }
+
+    /// Visits a [`FormatField`] node. Defaults to [`walk_format_field`].
+    #[inline]
+    fn visit_format_field(&mut self, node: &'ast FormatField) {
+        walk_format_field(self, node);
+    }
+
+    /// Visits a [`FormatPart`] node. Defaults to [`walk_format_part`].
+    #[inline]
+    fn visit_format_part(&mut self, node: &'ast FormatPart) {
+        walk_format_part(self, node);
+    }
+
+    /// Visits a [`FormatString`] node. Defaults to [`walk_format_string`].
+    #[inline]
+    fn visit_format_string(&mut self, node: &'ast FormatString) {
+        walk_format_string(self, node);
+    }
+
+    /// Visits a [`Function`] node. Defaults to [`walk_function`].
+    #[inline]
+    fn visit_function(&mut self, node: &'ast Function) {
+        walk_function(self, node);
+    }
+
+    /// Visits an [`Identifier`] node. Defaults to [`walk_identifier`].
+    #[inline]
+    fn visit_identifier(&mut self, node: &'ast Identifier) {
+        walk_identifier(self, node);
+    }
+
+    /// Visits an [`If`] node. Defaults to [`walk_if`].
+    #[inline]
+    fn visit_if(&mut self, node: &'ast If) {
+        walk_if(self, node);
+    }
+
+    /// Visits an [`Import`] node. Defaults to [`walk_import`].
+    #[inline]
+    fn visit_import(&mut self, node: &'ast Import) {
+        walk_import(self, node);
+    }
+
+    /// Visits an [`Index`] node. Defaults to [`walk_index`].
+    #[inline]
+    fn visit_index(&mut self, node: &'ast Index) {
+        walk_index(self, node);
+    }
+
+    /// Visits a [`Lambda`] node. Defaults to [`walk_lambda`].
+    #[inline]
+    fn visit_lambda(&mut self, node: &'ast Lambda) {
+        walk_lambda(self, node);
+    }
+
+    /// Visits a [`LambdaBody`] node. Defaults to [`walk_lambda_body`].
+    #[inline]
+    fn visit_lambda_body(&mut self, node: &'ast LambdaBody) {
+        walk_lambda_body(self, node);
+    }
+
+    /// Visits a [`List`] node. Defaults to [`walk_list`].
+    #[inline]
+    fn visit_list(&mut self, node: &'ast List) {
+        walk_list(self, node);
+    }
+
+    /// Visits a [`Literal`] node. Defaults to [`walk_literal`].
+    #[inline]
+    fn visit_literal(&mut self, node: &'ast Literal) {
+        walk_literal(self, node);
+    }
+
+    /// Visits a [`Match`] node. Defaults to [`walk_match`].
+    #[inline]
+    fn visit_match(&mut self, node: &'ast Match) {
+        walk_match(self, node);
+    }
+
+    /// Visits a [`MatchArm`] node. Defaults to [`walk_match_arm`].
+    #[inline]
+    fn visit_match_arm(&mut self, node: &'ast MatchArm) {
+        walk_match_arm(self, node);
+    }
+
+    /// Visits a [`MatchBody`] node. Defaults to [`walk_match_body`].
+    #[inline]
+    fn visit_match_body(&mut self, node: &'ast MatchBody) {
+        walk_match_body(self, node);
+    }
+
+    /// Visits a [`Member`] node. Defaults to [`walk_member`].
+    #[inline]
+    fn visit_member(&mut self, node: &'ast Member) {
+        walk_member(self, node);
+    }
+
+    /// Visits a [`Parameter`] node. Defaults to [`walk_parameter`].
+    #[inline]
+    fn visit_parameter(&mut self, node: &'ast Parameter) {
+        walk_parameter(self, node);
+    }
+
+    /// Visits a [`Pattern`] node. Defaults to [`walk_pattern`].
+    #[inline]
+    fn visit_pattern(&mut self, node: &'ast Pattern) {
+        walk_pattern(self, node);
+    }
+
+    /// Visits a [`Program`] node. Defaults to [`walk_program`].
+    #[inline]
+    fn visit_program(&mut self, node: &'ast Program) {
+        walk_program(self, node);
+    }
+
+    /// Visits a [`RangeBound`] node. Defaults to [`walk_range_bound`].
+    #[inline]
+    fn visit_range_bound(&mut self, node: &'ast RangeBound) {
+        walk_range_bound(self, node);
+    }
+
+    /// Visits a [`RangePattern`] node. Defaults to [`walk_range_pattern`].
+    #[inline]
+    fn visit_range_pattern(&mut self, node: &'ast RangePattern) {
+        walk_range_pattern(self, node);
+    }
+
+    /// Visits a [`Return`] node. Defaults to [`walk_return`].
+    #[inline]
+    fn visit_return(&mut self, node: &'ast Return) {
+        walk_return(self, node);
+    }
+
+    /// Visits a [`Slice`] node. Defaults to [`walk_slice`].
+    #[inline]
+    fn visit_slice(&mut self, node: &'ast Slice) {
+        walk_slice(self, node);
+    }
+
+    /// Visits a [`Statement`] node. Defaults to [`walk_statement`].
+    #[inline]
+    fn visit_statement(&mut self, node: &'ast Statement) {
+        walk_statement(self, node);
+    }
+
+    /// Visits a [`Throw`] node. Defaults to [`walk_throw`].
+    #[inline]
+    fn visit_throw(&mut self, node: &'ast Throw) {
+        walk_throw(self, node);
+    }
+
+    /// Visits a [`Try`] node. Defaults to [`walk_try`].
+    #[inline]
+    fn visit_try(&mut self, node: &'ast Try) {
+        walk_try(self, node);
+    }
+
+    /// Visits a [`Tuple`] node. Defaults to [`walk_tuple`].
+    #[inline]
+    fn visit_tuple(&mut self, node: &'ast Tuple) {
+        walk_tuple(self, node);
+    }
+
+    /// Visits a [`TypeAnnotation`] node. Defaults to [`walk_type_annotation`].
+    #[inline]
+    fn visit_type_annotation(&mut self, node: &'ast TypeAnnotation) {
+        walk_type_annotation(self, node);
+    }
+
+    /// Visits a [`TypeParameter`] node. Defaults to [`walk_type_parameter`].
+    #[inline]
+    fn visit_type_parameter(&mut self, node: &'ast TypeParameter) {
+        walk_type_parameter(self, node);
+    }
+
+    /// Visits a [`Unary`] node. Defaults to [`walk_unary`].
+    #[inline]
+    fn visit_unary(&mut self, node: &'ast Unary) {
+        walk_unary(self, node);
+    }
+
+    /// Visits a [`Use`] node. Defaults to [`walk_use`].
+    #[inline]
+    fn visit_use(&mut self, node: &'ast Use) {
+        walk_use(self, node);
+    }
+
+    /// Visits a [`VariableDeclaration`] node. Defaults to [`walk_variable_declaration`].
+    #[inline]
+    fn visit_variable_declaration(&mut self, node: &'ast VariableDeclaration) {
+        walk_variable_declaration(self, node);
+    }
+
+    /// Visits a [`While`] node. Defaults to [`walk_while`].
+    #[inline]
+    fn visit_while(&mut self, node: &'ast While) {
+        walk_while(self, node);
+    }
+
+    /// Visits a [`Yield`] node. Defaults to [`walk_yield`].
+    #[inline]
+    fn visit_yield(&mut self, node: &'ast Yield) {
+        walk_yield(self, node);
+    }
+}
+
+
+///////////
+// WALKS //
+///////////
+
+/// Walks the children of an [`AlternativePattern`] node.
+#[inline]
+pub fn walk_alternative_pattern<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast AlternativePattern,
+) {
+    for item in &node.patterns {
+        visitor.visit_pattern(item);
+    }
+}
+
+/// Walks the children of an [`Annotation`] node.
+#[inline]
+pub fn walk_annotation<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Annotation) {
+    visitor.visit_identifier(&node.name);
+    for item in &node.arguments {
+        visitor.visit_argument(item);
+    }
+}
+
+/// Walks the children of an [`Argument`] node.
+#[inline]
+pub fn walk_argument<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Argument) {
+    if let Some(ref item) = node.name {
+        visitor.visit_identifier(item);
+    }
+    visitor.visit_expression(&node.value);
+}
+
+/// Walks the children of an [`Assert`] node.
+#[inline]
+pub fn walk_assert<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Assert) {
+    visitor.visit_expression(&node.condition);
+    if let Some(ref item) = node.message {
+        visitor.visit_expression(item);
+    }
+}
+
+/// Walks the children of an [`Assignment`] node.
+#[inline]
+pub fn walk_assignment<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Assignment) {
+    visitor.visit_expression(&node.target);
+    visitor.visit_expression(&node.value);
+}
+
+/// Walks the children of a [`Binary`] node.
+#[inline]
+pub fn walk_binary<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Binary) {
+    visitor.visit_expression(&node.left);
+    visitor.visit_expression(&node.right);
+}
+
+/// Walks the children of a [`BindingPattern`] node.
+#[inline]
+pub fn walk_binding_pattern<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast BindingPattern,
+) {
+    if let Some(ref item) = node.type_annotation {
+        visitor.visit_type_annotation(item);
+    }
+    visitor.visit_identifier(&node.name);
+}
+
+/// Walks the children of a [`Block`] node.
+#[inline]
+pub fn walk_block<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Block) {
+    for item in &node.statements {
+        visitor.visit_statement(item);
+    }
+}
+
+/// Walks the children of a [`Break`] node.
+#[inline]
+pub fn walk_break<'ast, V: Visitor<'ast> + ?Sized>(_visitor: &mut V, _node: &'ast Break) {}
+
+/// Walks the children of a [`Call`] node.
+#[inline]
+pub fn walk_call<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Call) {
+    visitor.visit_expression(&node.callee);
+    for item in &node.arguments {
+        visitor.visit_argument(item);
+    }
+}
+
+/// Walks the children of a [`Catch`] node.
+#[inline]
+pub fn walk_catch<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Catch) {
+    visitor.visit_type_annotation(&node.exception);
+    if let Some(ref item) = node.binding {
+        visitor.visit_identifier(item);
+    }
+    visitor.visit_block(&node.body);
+}
+
+/// Walks the children of a [`Class`] node.
+#[inline]
+pub fn walk_class<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Class) {
+    for item in &node.annotations {
+        visitor.visit_annotation(item);
+    }
+    visitor.visit_identifier(&node.name);
+    for item in &node.type_parameters {
+        visitor.visit_type_parameter(item);
+    }
+    for item in &node.bases {
+        visitor.visit_type_annotation(item);
+    }
+    for item in &node.interfaces {
+        visitor.visit_type_annotation(item);
+    }
+    for item in &node.variants {
+        visitor.visit_class(item);
+    }
+    for item in &node.members {
+        visitor.visit_class_member(item);
+    }
+}
+
+/// Walks the children of a [`ClassMember`] node.
+#[inline]
+pub fn walk_class_member<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ClassMember,
+) {
+    match *node {
+        ClassMember::Constructor(ref inner) | ClassMember::Method(ref inner) => {
+            visitor.visit_function(inner);
+        }
+        ClassMember::Field(ref inner) => {
+            visitor.visit_field(inner);
+        }
+    }
+}
+
+/// Walks the children of a [`ClassPattern`] node.
+#[inline]
+pub fn walk_class_pattern<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ClassPattern,
+) {
+    visitor.visit_type_annotation(&node.class);
+    for item in &node.fields {
+        visitor.visit_pattern(item);
+    }
+}
+
+/// Walks the children of a [`Continue`] node.
+#[inline]
+pub fn walk_continue<'ast, V: Visitor<'ast> + ?Sized>(_visitor: &mut V, _node: &'ast Continue) {}
+
+/// Walks the children of a [`Dict`] node.
+#[inline]
+pub fn walk_dict<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Dict) {
+    for item in &node.entries {
+        visitor.visit_dict_entry(item);
+    }
+}
+
+/// Walks the children of a [`DictEntry`] node.
+#[inline]
+pub fn walk_dict_entry<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast DictEntry) {
+    visitor.visit_expression(&node.key);
+    visitor.visit_expression(&node.value);
+}
+
+/// Walks the children of an [`Expression`] node.
+#[inline]
+pub fn walk_expression<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Expression) {
+    match *node {
+        Expression::Assignment(ref inner) => {
+            visitor.visit_assignment(inner);
+        }
+        Expression::Binary(ref inner) => {
+            visitor.visit_binary(inner);
+        }
+        Expression::Call(ref inner) => {
+            visitor.visit_call(inner);
+        }
+        Expression::Dict(ref inner) => {
+            visitor.visit_dict(inner);
+        }
+        Expression::FormatString(ref inner) => {
+            visitor.visit_format_string(inner);
+        }
+        Expression::Identifier(ref inner) => {
+            visitor.visit_identifier(inner);
+        }
+        Expression::Index(ref inner) => {
+            visitor.visit_index(inner);
+        }
+        Expression::Lambda(ref inner) => {
+            visitor.visit_lambda(inner);
+        }
+        Expression::List(ref inner) => {
+            visitor.visit_list(inner);
+        }
+        Expression::Literal(ref inner) => {
+            visitor.visit_literal(inner);
+        }
+        Expression::Match(ref inner) => {
+            visitor.visit_match(inner);
+        }
+        Expression::Member(ref inner) => {
+            visitor.visit_member(inner);
+        }
+        Expression::Slice(ref inner) => {
+            visitor.visit_slice(inner);
+        }
+        Expression::Tuple(ref inner) => {
+            visitor.visit_tuple(inner);
+        }
+        Expression::Unary(ref inner) => {
+            visitor.visit_unary(inner);
+        }
+    }
+}
+
+/// Walks the children of an [`ExpressionStatement`] node.
+#[inline]
+pub fn walk_expression_statement<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ExpressionStatement,
+) {
+    visitor.visit_expression(&node.expression);
+}
+
+/// Walks the children of a [`Field`] node.
+#[inline]
+pub fn walk_field<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Field) {
+    for item in &node.annotations {
+        visitor.visit_annotation(item);
+    }
+    visitor.visit_type_annotation(&node.type_annotation);
+    visitor.visit_identifier(&node.name);
+    if let Some(ref item) = node.value {
+        visitor.visit_expression(item);
+    }
+}
+
+/// Walks the children of a [`For`] node.
+#[inline]
+pub fn walk_for<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast For) {
+    if let Some(ref item) = node.type_annotation {
+        visitor.visit_type_annotation(item);
+    }
+    visitor.visit_identifier(&node.variable);
+    visitor.visit_for_iterable(&node.iterable);
+    visitor.visit_block(&node.body);
+}
+
+/// Walks the children of a [`ForIterable`] node.
+#[inline]
+pub fn walk_for_iterable<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ForIterable,
+) {
+    match *node {
+        ForIterable::Range(ref inner) => {
+            visitor.visit_for_range(inner);
+        }
+        ForIterable::Value(ref inner) => {
+            visitor.visit_expression(inner);
+        }
+    }
+}
+
+/// Walks the children of a [`ForRange`] node.
+#[inline]
+pub fn walk_for_range<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast ForRange) {
+    visitor.visit_expression(&node.start);
+    visitor.visit_expression(&node.end);
+    if let Some(ref item) = node.step {
+        visitor.visit_expression(item);
+    }
+}
+
+/// Walks the children of a [`FormatField`] node.
+#[inline]
+pub fn walk_format_field<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast FormatField,
+) {
+    visitor.visit_expression(&node.value);
+}
+
+/// Walks the children of a [`FormatPart`] node.
+#[inline]
+pub fn walk_format_part<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast FormatPart) {
+    match *node {
+        FormatPart::Field(ref inner) => {
+            visitor.visit_format_field(inner);
+        }
+        FormatPart::Text(_) => {}
+    }
+}
+
+/// Walks the children of a [`FormatString`] node.
+#[inline]
+pub fn walk_format_string<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast FormatString,
+) {
+    for item in &node.parts {
+        visitor.visit_format_part(item);
+    }
+}
+
+/// Walks the children of a [`Function`] node.
+#[inline]
+pub fn walk_function<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Function) {
+    for item in &node.annotations {
+        visitor.visit_annotation(item);
+    }
+    visitor.visit_identifier(&node.name);
+    for item in &node.type_parameters {
+        visitor.visit_type_parameter(item);
+    }
+    for item in &node.parameters {
+        visitor.visit_parameter(item);
+    }
+    if let Some(ref item) = node.return_type {
+        visitor.visit_type_annotation(item);
+    }
+    for item in &node.requires {
+        visitor.visit_expression(item);
+    }
+    for item in &node.ensures {
+        visitor.visit_expression(item);
+    }
+    if let Some(ref item) = node.body {
+        visitor.visit_block(item);
+    }
+}
+
+/// Walks the children of an [`Identifier`] node.
+#[inline]
+pub fn walk_identifier<'ast, V: Visitor<'ast> + ?Sized>(_visitor: &mut V, _node: &'ast Identifier) {
+}
+
+/// Walks the children of an [`If`] node.
+#[inline]
+pub fn walk_if<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast If) {
+    visitor.visit_expression(&node.condition);
+    visitor.visit_block(&node.then_block);
+    if let Some(ref item) = node.else_block {
+        visitor.visit_block(item);
+    }
+}
+
+/// Walks the children of an [`Import`] node.
+#[inline]
+pub fn walk_import<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Import) {
+    for item in &node.path {
+        visitor.visit_identifier(item);
+    }
+    if let Some(ref item) = node.alias {
+        visitor.visit_identifier(item);
+    }
+}
+
+/// Walks the children of an [`Index`] node.
+#[inline]
+pub fn walk_index<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Index) {
+    visitor.visit_expression(&node.target);
+    visitor.visit_expression(&node.index);
+}
+
+/// Walks the children of a [`Lambda`] node.
+#[inline]
+pub fn walk_lambda<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Lambda) {
+    for item in &node.parameters {
+        visitor.visit_parameter(item);
+    }
+    if let Some(ref item) = node.return_type {
+        visitor.visit_type_annotation(item);
+    }
+    visitor.visit_lambda_body(&node.body);
+}
+
+/// Walks the children of a [`LambdaBody`] node.
+#[inline]
+pub fn walk_lambda_body<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast LambdaBody) {
+    match *node {
+        LambdaBody::Block(ref inner) => {
+            visitor.visit_block(inner);
+        }
+        LambdaBody::Expression(ref inner) => {
+            visitor.visit_expression(inner);
+        }
+    }
+}
+
+/// Walks the children of a [`List`] node.
+#[inline]
+pub fn walk_list<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast List) {
+    for item in &node.elements {
+        visitor.visit_expression(item);
+    }
+}
+
+/// Walks the children of a [`Literal`] node.
+#[inline]
+pub fn walk_literal<'ast, V: Visitor<'ast> + ?Sized>(_visitor: &mut V, _node: &'ast Literal) {}
+
+/// Walks the children of a [`Match`] node.
+#[inline]
+pub fn walk_match<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Match) {
+    visitor.visit_expression(&node.subject);
+    for item in &node.arms {
+        visitor.visit_match_arm(item);
+    }
+}
+
+/// Walks the children of a [`MatchArm`] node.
+#[inline]
+pub fn walk_match_arm<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast MatchArm) {
+    if let Some(ref item) = node.pattern {
+        visitor.visit_pattern(item);
+    }
+    if let Some(ref item) = node.guard {
+        visitor.visit_expression(item);
+    }
+    visitor.visit_match_body(&node.body);
+}
+
+/// Walks the children of a [`MatchBody`] node.
+#[inline]
+pub fn walk_match_body<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast MatchBody) {
+    match *node {
+        MatchBody::Block(ref inner) => {
+            visitor.visit_block(inner);
+        }
+        MatchBody::Expression(ref inner) => {
+            visitor.visit_expression(inner);
+        }
+    }
+}
+
+/// Walks the children of a [`Member`] node.
+#[inline]
+pub fn walk_member<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Member) {
+    visitor.visit_expression(&node.target);
+    visitor.visit_identifier(&node.member);
+}
+
+/// Walks the children of a [`Parameter`] node.
+#[inline]
+pub fn walk_parameter<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Parameter) {
+    visitor.visit_type_annotation(&node.type_annotation);
+    visitor.visit_identifier(&node.name);
+    if let Some(ref item) = node.default {
+        visitor.visit_expression(item);
+    }
+}
+
+/// Walks the children of a [`Pattern`] node.
+#[inline]
+pub fn walk_pattern<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Pattern) {
+    match *node {
+        Pattern::Alternative(ref inner) => {
+            visitor.visit_alternative_pattern(inner);
+        }
+        Pattern::Binding(ref inner) => {
+            visitor.visit_binding_pattern(inner);
+        }
+        Pattern::Class(ref inner) => {
+            visitor.visit_class_pattern(inner);
+        }
+        Pattern::Literal(ref inner) => {
+            visitor.visit_literal(inner);
+        }
+        Pattern::Range(ref inner) => {
+            visitor.visit_range_pattern(inner);
+        }
+    }
+}
+
+/// Walks the children of a [`Program`] node.
+#[inline]
+pub fn walk_program<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Program) {
+    for item in &node.statements {
+        visitor.visit_statement(item);
+    }
+}
+
+/// Walks the children of a [`RangeBound`] node.
+#[inline]
+pub fn walk_range_bound<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast RangeBound) {
+    match *node {
+        RangeBound::Literal(ref inner) => {
+            visitor.visit_literal(inner);
+        }
+        RangeBound::Constant(ref inner) => {
+            visitor.visit_identifier(inner);
+        }
+    }
+}
+
+/// Walks the children of a [`RangePattern`] node.
+#[inline]
+pub fn walk_range_pattern<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast RangePattern,
+) {
+    visitor.visit_range_bound(&node.start);
+    visitor.visit_range_bound(&node.end);
+}
+
+/// Walks the children of a [`Return`] node.
+#[inline]
+pub fn walk_return<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Return) {
+    if let Some(ref item) = node.value {
+        visitor.visit_expression(item);
+    }
+}
+
+/// Walks the children of a [`Slice`] node.
+#[inline]
+pub fn walk_slice<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Slice) {
+    visitor.visit_expression(&node.target);
+    if let Some(ref item) = node.start {
+        visitor.visit_expression(item);
+    }
+    if let Some(ref item) = node.end {
+        visitor.visit_expression(item);
+    }
+}
+
+/// Walks the children of a [`Statement`] node.
+#[inline]
+pub fn walk_statement<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Statement) {
+    match *node {
+        Statement::Assert(ref inner) => {
+            visitor.visit_assert(inner);
+        }
+        Statement::Block(ref inner) => {
+            visitor.visit_block(inner);
+        }
+        Statement::Break(ref inner) => {
+            visitor.visit_break(inner);
+        }
+        Statement::Class(ref inner) => {
+            visitor.visit_class(inner);
+        }
+        Statement::Continue(ref inner) => {
+            visitor.visit_continue(inner);
+        }
+        Statement::Expression(ref inner) => {
+            visitor.visit_expression_statement(inner);
+        }
+        Statement::For(ref inner) => {
+            visitor.visit_for(inner);
+        }
+        Statement::Function(ref inner) => {
+            visitor.visit_function(inner);
+        }
+        Statement::If(ref inner) => {
+            visitor.visit_if(inner);
+        }
+        Statement::Import(ref inner) => {
+            visitor.visit_import(inner);
+        }
+        Statement::Match(ref inner) => {
+            visitor.visit_match(inner);
+        }
+        Statement::Return(ref inner) => {
+            visitor.visit_return(inner);
+        }
+        Statement::Throw(ref inner) => {
+            visitor.visit_throw(inner);
+        }
+        Statement::Try(ref inner) => {
+            visitor.visit_try(inner);
+        }
+        Statement::Use(ref inner) => {
+            visitor.visit_use(inner);
+        }
+        Statement::VariableDeclaration(ref inner) => {
+            visitor.visit_variable_declaration(inner);
+        }
+        Statement::While(ref inner) => {
+            visitor.visit_while(inner);
+        }
+        Statement::Yield(ref inner) => {
+            visitor.visit_yield(inner);
+        }
+    }
+}
+
+/// Walks the children of a [`Throw`] node.
+#[inline]
+pub fn walk_throw<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Throw) {
+    visitor.visit_expression(&node.value);
+}
+
+/// Walks the children of a [`Try`] node.
+#[inline]
+pub fn walk_try<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Try) {
+    visitor.visit_block(&node.body);
+    for item in &node.catches {
+        visitor.visit_catch(item);
+    }
+    if let Some(ref item) = node.finally {
+        visitor.visit_block(item);
+    }
+}
+
+/// Walks the children of a [`Tuple`] node.
+#[inline]
+pub fn walk_tuple<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Tuple) {
+    for item in &node.elements {
+        visitor.visit_expression(item);
+    }
+}
+
+/// Walks the children of a [`TypeAnnotation`] node.
+#[inline]
+pub fn walk_type_annotation<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast TypeAnnotation,
+) {
+    for item in &node.arguments {
+        visitor.visit_type_annotation(item);
+    }
+}
+
+/// Walks the children of a [`TypeParameter`] node.
+#[inline]
+pub fn walk_type_parameter<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast TypeParameter,
+) {
+    visitor.visit_identifier(&node.name);
+    if let Some(ref item) = node.bound {
+        visitor.visit_type_annotation(item);
+    }
+}
+
+/// Walks the children of a [`Unary`] node.
+#[inline]
+pub fn walk_unary<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Unary) {
+    visitor.visit_expression(&node.operand);
+}
+
+/// Walks the children of a [`Use`] node.
+#[inline]
+pub fn walk_use<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Use) {
+    for item in &node.path {
+        visitor.visit_identifier(item);
+    }
+    if let Some(ref item) = node.alias {
+        visitor.visit_identifier(item);
+    }
+}
+
+/// Walks the children of a [`VariableDeclaration`] node.
+#[inline]
+pub fn walk_variable_declaration<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast VariableDeclaration,
+) {
+    if let Some(ref item) = node.type_annotation {
+        visitor.visit_type_annotation(item);
+    }
+    visitor.visit_identifier(&node.name);
+    if let Some(ref item) = node.value {
+        visitor.visit_expression(item);
+    }
+}
+
+/// Walks the children of a [`While`] node.
+#[inline]
+pub fn walk_while<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast While) {
+    visitor.visit_expression(&node.condition);
+    visitor.visit_block(&node.body);
+}
+
+/// Walks the children of a [`Yield`] node.
+#[inline]
+pub fn walk_yield<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Yield) {
+    visitor.visit_expression(&node.value);
+}
@@ -0,0 +1,1052 @@
+//! A visitor walking the AST by mutable reference, allowing nodes to be changed in place.
+// I Language mutable AST visitor.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+// This file is generated by `tools/generate_visitors.py` from `ast.rs`. Do not edit it by hand,
+// instead change the AST and run `just generate-visitors`.
+
+/////////////
+// IMPORTS //
+/////////////
+
+use crate::ast::{
+    AlternativePattern, Annotation, Argument, Assert, Assignment, Binary, BindingPattern, Block,
+    Break, Call, Catch, Class, ClassMember, ClassPattern, Continue, Dict, DictEntry, Expression,
+    ExpressionStatement, Field, For, ForIterable, ForRange, FormatField, FormatPart, FormatString,
+    Function, Identifier, If, Import, Index, Lambda, LambdaBody, List, Literal, Match, MatchArm,
+    MatchBody, Member, Parameter, Pattern, Program, RangeBound, RangePattern, Return, Slice,
+    Statement, Throw, Try, Tuple, TypeAnnotation, TypeParameter, Unary, Use, VariableDeclaration,
+    While, Yield,
+};
+
+
+///////////
+// TRAIT //
+///////////
+
+/// A visitor walking the AST by mutable reference. Every method defaults to visiting the children of the node.
+/// Override a method to handle a node type and call the matching `walk_*` function to continue with its children.
+pub trait VisitorMut {
+    /// Visits an [`AlternativePattern`] node. Defaults to [`walk_alternative_pattern`].
+    #[inline]
+    fn visit_alternative_pattern(&mut self, node: &mut AlternativePattern) {
+        walk_alternative_pattern(self, node);
+    }
+
+    /// Visits an [`Annotation`] node. Defaults to [`walk_annotation`].
+    #[inline]
+    fn visit_annotation(&mut self, node: &mut Annotation) {
+        walk_annotation(self, node);
+    }
+
+    /// Visits an [`Argument`] node. Defaults to [`walk_argument`].
+    #[inline]
+    fn visit_argument(&mut self, node: &mut Argument) {
+        walk_argument(self, node);
+    }
+
+    /// Visits an [`Assert`] node. Defaults to [`walk_assert`].
+    #[inline]
+    fn visit_assert(&mut self, node: &mut Assert) {
+        walk_assert(self, node);
+    }
+
+    /// Visits an [`Assignment`] node. Defaults to [`walk_assignment`].
+    #[inline]
+    fn visit_assignment(&mut self, node: &mut Assignment) {
+        walk_assignment(self, node);
+    }
+
+    /// Visits a [`Binary`] node. Defaults to [`walk_binary`].
+    #[inline]
+    fn visit_binary(&mut self, node: &mut Binary) {
+        walk_binary(self, node);
+    }
+
+    /// Visits a [`BindingPattern`] node. Defaults to [`walk_binding_pattern`].
+    #[inline]
+    fn visit_binding_pattern(&mut self, node: &mut BindingPattern) {
+        walk_binding_pattern(self, node);
+    }
+
+    /// Visits a [`Block`] node. Defaults to [`walk_block`].
+    #[inline]
+    fn visit_block(&mut self, node: &mut Block) {
+        walk_block(self, node);
+    }
+
+    /// Visits a [`Break`] node. Defaults to [`walk_break`].
+    #[inline]
+    fn visit_break(&mut self, node: &mut Break) {
+        walk_break(self, node);
+    }
+
+    /// Visits a [`Call`] node. Defaults to [`walk_call`].
+    #[inline]
+    fn visit_call(&mut self, node: &mut Call) {
+        walk_call(self, node);
+    }
+
+    /// Visits a [`Catch`] node. Defaults to [`walk_catch`].
+    #[inline]
+    fn visit_catch(&mut self, node: &mut Catch) {
+        walk_catch(self, node);
+    }
+
+    /// Visits a [`Class`] node. Defaults to [`walk_class`].
+    #[inline]
+    fn visit_class(&mut self, node: &mut Class) {
+        walk_class(self, node);
+    }
+
+    /// Visits a [`ClassMember`] node. Defaults to [`walk_class_member`].
+    #[inline]
+    fn visit_class_member(&mut self, node: &mut ClassMember) {
+        walk_class_member(self, node);
+    }
+
+    /// Visits a [`ClassPattern`] node. Defaults to [`walk_class_pattern`].
+    #[inline]
+    fn visit_class_pattern(&mut self, node: &mut ClassPattern) {
+        walk_class_pattern(self, node);
+    }
+
+    /// Visits a [`Continue`] node. Defaults to [`walk_continue`].
+    #[inline]
+    fn visit_continue(&mut self, node: &mut Continue) {
+        walk_continue(self, node);
+    }
+
+    /// Visits a [`Dict`] node. Defaults to [`walk_dict`].
+    #[inline]
+    fn visit_dict(&mut self, node: &mut Dict) {
+        walk_dict(self, node);
+    }
+
+    /// Visits a [`DictEntry`] node. Defaults to [`walk_dict_entry`].
+    #[inline]
+    fn visit_dict_entry(&mut self, node: &mut DictEntry) {
+        walk_dict_entry(self, node);
+    }
+
+    /// Visits an [`Expression`] node. Defaults to [`walk_expression`].
+    #[inline]
+    fn visit_expression(&mut self, node: &mut Expression) {
+        walk_expression(self, node);
+    }
+
+    /// Visits an [`ExpressionStatement`] node. Defaults to [`walk_expression_statement`].
+    #[inline]
+    fn visit_expression_statement(&mut self, node: &mut ExpressionStatement) {
+        walk_expression_statement(self, node);
+    }
+
+    /// Visits a [`Field`] node. Defaults to [`walk_field`].
+    #[inline]
+    fn visit_field(&mut self, node: &mut Field) {
+        walk_field(self, node);
+    }
+
+    /// Visits a [`For`] node. Defaults to [`walk_for`].
+    #[inline]
+    fn visit_for(&mut self, node: &mut For) {
+        walk_for(self, node);
+    }
+
+    /// Visits a [`ForIterable`] node. Defaults to [`walk_for_iterable`].
+    #[inline]
+    fn visit_for_iterable(&mut self, node: &mut ForIterable) {
+        walk_for_iterable(self, node);
+    }
+
+    /// Visits a [`ForRange`] node. Defaults to [`walk_for_range`].
+    #[inline]
+    fn visit_for_range(&mut self, node: &mut ForRange) {
+        walk_for_range(self, node);
+    }
+
+    /// Visits a [`FormatField`] node. Defaults to [`walk_format_field`].
+    #[inline]
+    fn visit_format_field(&mut self, node: &mut FormatField) {
+        walk_format_field(self, node);
+    }
+
+    /// Visits a [`FormatPart`] node. Defaults to [`walk_format_part`].
+    #[inline]
+    fn visit_format_part(&mut self, node: &mut FormatPart) {
+        walk_format_part(self, node);
+    }
+
+    /// Visits a [`FormatString`] node. Defaults to [`walk_format_string`].
+    #[inline]
+    fn visit_format_string(&mut self, node: &mut FormatString) {
+        walk_format_string(self, node);
+    }
+
+    /// Visits a [`Function`] node. Defaults to [`walk_function`].
+    #[inline]
+    fn visit_function(&mut self, node: &mut Function) {
+        walk_function(self, node);
+    }
+
+    /// Visits an [`Identifier`] node. Defaults to [`walk_identifier`].
+    #[inline]
+    fn visit_identifier(&mut self, node: &mut Identifier) {
+        walk_identifier(self, node);
+    }
+
+    /// Visits an [`If`] node. Defaults to [`walk_if`].
+    #[inline]
+    fn visit_if(&mut self, node: &mut If) {
+        walk_if(self, node);
+    }
+
+    /// Visits an [`Import`] node. Defaults to [`walk_import`].
+    #[inline]
+    fn visit_import(&mut self, node: &mut Import) {
+        walk_import(self, node);
+    }
+
+    /// Visits an [`Index`] node. Defaults to [`walk_index`].
+    #[inline]
+    fn visit_index(&mut self, node: &mut Index) {
+        walk_index(self, node);
+    }
+
+    /// Visits a [`Lambda`] node. Defaults to [`walk_lambda`].
+    #[inline]
+    fn visit_lambda(&mut self, node: &mut Lambda) {
+        walk_lambda(self, node);
+    }
+
+    /// Visits a [`LambdaBody`] node. Defaults to [`walk_lambda_body`].
+    #[inline]
+    fn visit_lambda_body(&mut self, node: &mut LambdaBody) {
+        walk_lambda_body(self, node);
+    }
+
+    /// Visits a [`List`] node. Defaults to [`walk_list`].
+    #[inline]
+    fn visit_list(&mut self, node: &mut List) {
+        walk_list(self, node);
+    }
+
+    /// Visits a [`Literal`] node. Defaults to [`walk_literal`].
+    #[inline]
+    fn visit_literal(&mut self, node: &mut Literal) {
+        walk_literal(self, node);
+    }
+
+    /// Visits a [`Match`] node. Defaults to [`walk_match`].
+    #[inline]
+    fn visit_match(&mut self, node: &mut Match) {
+        walk_match(self, node);
+    }
+
+    /// Visits a [`MatchArm`] node. Defaults to [`walk_match_arm`].
+    #[inline]
+    fn visit_match_arm(&mut self, node: &mut MatchArm) {
+        walk_match_arm(self, node);
+    }
+
+    /// Visits a [`MatchBody`] node. Defaults to [`walk_match_body`].
+    #[inline]
+    fn visit_match_body(&mut self, node: &mut MatchBody) {
+        walk_match_body(self, node);
+    }
+
+    /// Visits a [`Member`] node. Defaults to [`walk_member`].
+    #[inline]
+    fn visit_member(&mut self, node: &mut Member) {
+        walk_member(self, node);
+    }
+
+    /// Visits a [`Parameter`] node. Defaults to [`walk_parameter`].
+    #[inline]
+    fn visit_parameter(&mut self, node: &mut Parameter) {
+        walk_parameter(self, node);
+    }
+
+    /// Visits a [`Pattern`] node. Defaults to [`walk_pattern`].
+    #[inline]
+    fn visit_pattern(&mut self, node: &mut Pattern) {
+        walk_pattern(self, node);
+    }
+
+    /// Visits a [`Program`] node. Defaults to [`walk_program`].
+    #[inline]
+    fn visit_program(&mut self, node: &mut Program) {
+        walk_program(self, node);
+    }
+
+    /// Visits a [`RangeBound`] node. Defaults to [`walk_range_bound`].
+    #[inline]
+    fn visit_range_bound(&mut self, node: &mut RangeBound) {
+        walk_range_bound(self, node);
+    }
+
+    /// Visits a [`RangePattern`] node. Defaults to [`walk_range_pattern`].
+    #[inline]
+    fn visit_range_pattern(&mut self, node: &mut RangePattern) {
+        walk_range_pattern(self, node);
+    }
+
+    /// Visits a [`Return`] node. Defaults to [`walk_return`].
+    #[inline]
+    fn visit_return(&mut self, node: &mut Return) {
+        walk_return(self, node);
+    }
+
+    /// Visits a [`Slice`] node. Defaults to [`walk_slice`].
+    #[inline]
+    fn visit_slice(&mut self, node: &mut Slice) {
+        walk_slice(self, node);
+    }
+
+    /// Visits a [`Statement`] node. Defaults to [`walk_statement`].
+    #[inline]
+    fn visit_statement(&mut self, node: &mut Statement) {
+        walk_statement(self, node);
+    }
+
+    /// Visits a [`Throw`] node. Defaults to [`walk_throw`].
+    #[inline]
+    fn visit_throw(&mut self, node: &mut Throw) {
+        walk_throw(self, node);
+    }
+
+    /// Visits a [`Try`] node. Defaults to [`walk_try`].
+    #[inline]
+    fn visit_try(&mut self, node: &mut Try) {
+        walk_try(self, node);
+    }
+
+    /// Visits a [`Tuple`] node. Defaults to [`walk_tuple`].
+    #[inline]
+    fn visit_tuple(&mut self, node: &mut Tuple) {
+        walk_tuple(self, node);
+    }
+
+    /// Visits a [`TypeAnnotation`] node. Defaults to [`walk_type_annotation`].
+    #[inline]
+    fn visit_type_annotation(&mut self, node: &mut TypeAnnotation) {
+        walk_type_annotation(self, node);
+    }
+
+    /// Visits a [`TypeParameter`] node. Defaults to [`walk_type_parameter`].
+    #[inline]
+    fn visit_type_parameter(&mut self, node: &mut TypeParameter) {
+        walk_type_parameter(self, node);
+    }
+
+    /// Visits a [`Unary`] node. Defaults to [`walk_unary`].
+    #[inline]
+    fn visit_unary(&mut self, node: &mut Unary) {
+        walk_unary(self, node);
+    }
+
+    /// Visits a [`Use`] node. Defaults to [`walk_use`].
+    #[inline]
+    fn visit_use(&mut self, node: &mut Use) {
+        walk_use(self, node);
+    }
+
+    /// Visits a [`VariableDeclaration`] node. Defaults to [`walk_variable_declaration`].
+    #[inline]
+    fn visit_variable_declaration(&mut self, node: &mut VariableDeclaration) {
+        walk_variable_declaration(self, node);
+    }
+
+    /// Visits a [`While`] node. Defaults to [`walk_while`].
+    #[inline]
+    fn visit_while(&mut self, node: &mut While) {
+        walk_while(self, node);
+    }
+
+    /// Visits a [`Yield`] node. Defaults to [`walk_yield`].
+    #[inline]
+    fn visit_yield(&mut self, node: &mut Yield) {
+        walk_yield(self, node);
+    }
+}
+
+
+///////////
+// WALKS //
+///////////
+
+/// Walks the children of an [`AlternativePattern`] node.
+#[inline]
+pub fn walk_alternative_pattern<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut AlternativePattern,
+) {
+    for item in &mut node.patterns {
+        visitor.visit_pattern(item);
+    }
+}
+
+/// Walks the children of an [`Annotation`] node.
+#[inline]
+pub fn walk_annotation<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Annotation) {
+    visitor.visit_identifier(&mut node.name);
+    for item in &mut node.arguments {
+        visitor.visit_argument(item);
+    }
+}
+
+/// Walks the children of an [`Argument`] node.
+#[inline]
+pub fn walk_argument<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Argument) {
+    if let Some(ref mut item) = node.name {
+        visitor.visit_identifier(item);
+    }
+    visitor.visit_expression(&mut node.value);
+}
+
+/// Walks the children of an [`Assert`] node.
+#[inline]
+pub fn walk_assert<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Assert) {
+    visitor.visit_expression(&mut node.condition);
+    if let Some(ref mut item) = node.message {
+        visitor.visit_expression(item);
+    }
+}
+
+/// Walks the children of an [`Assignment`] node.
+#[inline]
+pub fn walk_assignment<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Assignment) {
+    visitor.visit_expression(&mut node.target);
+    visitor.visit_expression(&mut node.value);
+}
+
+/// Walks the children of a [`Binary`] node.
+#[inline]
+pub fn walk_binary<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Binary) {
+    visitor.visit_expression(&mut node.left);
+    visitor.visit_expression(&mut node.right);
+}
+
+/// Walks the children of a [`BindingPattern`] node.
+#[inline]
+pub fn walk_binding_pattern<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut BindingPattern) {
+    if let Some(ref mut item) = node.type_annotation {
+        visitor.visit_type_annotation(item);
+    }
+    visitor.visit_identifier(&mut node.name);
+}
+
+/// Walks the children of a [`Block`] node.
+#[inline]
+pub fn walk_block<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Block) {
+    for item in &mut node.statements {
+        visitor.visit_statement(item);
+    }
+}
+
+/// Walks the children of a [`Break`] node.
+#[inline]
+pub fn walk_break<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut Break) {}
+
+/// Walks the children of a [`Call`] node.
+#[inline]
+pub fn walk_call<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Call) {
+    visitor.visit_expression(&mut node.callee);
+    for item in &mut node.arguments {
+        visitor.visit_argument(item);
+    }
+}
+
+/// Walks the children of a [`Catch`] node.
+#[inline]
+pub fn walk_catch<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Catch) {
+    visitor.visit_type_annotation(&mut node.exception);
+    if let Some(ref mut item) = node.binding {
+        visitor.visit_identifier(item);
+    }
+    visitor.visit_block(&mut node.body);
+}
+
+/// Walks the children of a [`Class`] node.
+#[inline]
+pub fn walk_class<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Class) {
+    for item in &mut node.annotations {
+        visitor.visit_annotation(item);
+    }
+    visitor.visit_identifier(&mut node.name);
+    for item in &mut node.type_parameters {
+        visitor.visit_type_parameter(item);
+    }
+    for item in &mut node.bases {
+        visitor.visit_type_annotation(item);
+    }
+    for item in &mut node.interfaces {
+        visitor.visit_type_annotation(item);
+    }
+    for item in &mut node.variants {
+        visitor.visit_class(item);
+    }
+    for item in &mut node.members {
+        visitor.visit_class_member(item);
+    }
+}
+
+/// Walks the children of a [`ClassMember`] node.
+#[inline]
+pub fn walk_class_member<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut ClassMember) {
+    match *node {
+        ClassMember::Constructor(ref mut inner) | ClassMember::Method(ref mut inner) => {
+            visitor.visit_function(inner);
+        }
+        ClassMember::Field(ref mut inner) => {
+            visitor.visit_field(inner);
+        }
+    }
+}
+
+/// Walks the children of a [`ClassPattern`] node.
+#[inline]
+pub fn walk_class_pattern<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut ClassPattern) {
+    visitor.visit_type_annotation(&mut node.class);
+    for item in &mut node.fields {
+        visitor.visit_pattern(item);
+    }
+}
+
+/// Walks the children of a [`Continue`] node.
+#[inline]
+pub fn walk_continue<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut Continue) {}
+
+/// Walks the children of a [`Dict`] node.
+#[inline]
+pub fn walk_dict<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Dict) {
+    for item in &mut node.entries {
+        visitor.visit_dict_entry(item);
+    }
+}
+
+/// Walks the children of a [`DictEntry`] node.
+#[inline]
+pub fn walk_dict_entry<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut DictEntry) {
+    visitor.visit_expression(&mut node.key);
+    visitor.visit_expression(&mut node.value);
+}
+
+/// Walks the children of an [`Expression`] node.
+#[inline]
+pub fn walk_expression<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Expression) {
+    match *node {
+        Expression::Assignment(ref mut inner) => {
+            visitor.visit_assignment(inner);
+        }
+        Expression::Binary(ref mut inner) => {
+            visitor.visit_binary(inner);
+        }
+        Expression::Call(ref mut inner) => {
+            visitor.visit_call(inner);
+        }
+        Expression::Dict(ref mut inner) => {
+            visitor.visit_dict(inner);
+        }
+        Expression::FormatString(ref mut inner) => {
+            visitor.visit_format_string(inner);
+        }
+        Expression::Identifier(ref mut inner) => {
+            visitor.visit_identifier(inner);
+        }
+        Expression::Index(ref mut inner) => {
+            visitor.visit_index(inner);
+        }
+        Expression::Lambda(ref mut inner) => {
+            visitor.visit_lambda(inner);
+        }
+        Expression::List(ref mut inner) => {
+            visitor.visit_list(inner);
+        }
+        Expression::Literal(ref mut inner) => {
+            visitor.visit_literal(inner);
+        }
+        Expression::Match(ref mut inner) => {
+            visitor.visit_match(inner);
+        }
+        Expression::Member(ref mut inner) => {
+            visitor.visit_member(inner);
+        }
+        Expression::Slice(ref mut inner) => {
+            visitor.visit_slice(inner);
+        }
+        Expression::Tuple(ref mut inner) => {
+            visitor.visit_tuple(inner);
+        }
+        Expression::Unary(ref mut inner) => {
+            visitor.visit_unary(inner);
+        }
+    }
+}
+
+/// Walks the children of an [`ExpressionStatement`] node.
+#[inline]
+pub fn walk_expression_statement<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ExpressionStatement,
+) {
+    visitor.visit_expression(&mut node.expression);
+}
+
+/// Walks the children of a [`Field`] node.
+#[inline]
+pub fn walk_field<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Field) {
+    for item in &mut node.annotations {
+        visitor.visit_annotation(item);
+    }
+    visitor.visit_type_annotation(&mut node.type_annotation);
+    visitor.visit_identifier(&mut node.name);
+    if let Some(ref mut item) = node.value {
+        visitor.visit_expression(item);
+    }
+}
+
+/// Walks the children of a [`For`] node.
+#[inline]
+pub fn walk_for<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut For) {
+    if let Some(ref mut item) = node.type_annotation {
+        visitor.visit_type_annotation(item);
+    }
+    visitor.visit_identifier(&mut node.variable);
+    visitor.visit_for_iterable(&mut node.iterable);
+    visitor.visit_block(&mut node.body);
+}
+
+/// Walks the children of a [`ForIterable`] node.
+#[inline]
+pub fn walk_for_iterable<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut ForIterable) {
+    match *node {
+        ForIterable::Range(ref mut inner) => {
+            visitor.visit_for_range(inner);
+        }
+        ForIterable::Value(ref mut inner) => {
+            visitor.visit_expression(inner);
+        }
+    }
+}
+
+/// Walks the children of a [`ForRange`] node.
+#[inline]
+pub fn walk_for_range<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut ForRange) {
+    visitor.visit_expression(&mut node.start);
+    visitor.visit_expression(&mut node.end);
+    if let Some(ref mut item) = node.step {
+        visitor.visit_expression(item);
+    }
+}
+
+/// Walks the children of a [`FormatField`] node.
+#[inline]
+pub fn walk_format_field<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut FormatField) {
+    visitor.visit_expression(&mut node.value);
+}
+
+/// Walks the children of a [`FormatPart`] node.
+#[inline]
+pub fn walk_format_part<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut FormatPart) {
+    match *node {
+        FormatPart::Field(ref mut inner) => {
+            visitor.visit_format_field(inner);
+        }
+        FormatPart::Text(_) => {}
+    }
+}
+
+/// Walks the children of a [`FormatString`] node.
+#[inline]
+pub fn walk_format_string<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut FormatString) {
+    for item in &mut node.parts {
+        visitor.visit_format_part(item);
+    }
+}
+
+/// Walks the children of a [`Function`] node.
+#[inline]
+pub fn walk_function<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Function) {
+    for item in &mut node.annotations {
+        visitor.visit_annotation(item);
+    }
+    visitor.visit_identifier(&mut node.name);
+    for item in &mut node.type_parameters {
+        visitor.visit_type_parameter(item);
+    }
+    for item in &mut node.parameters {
+        visitor.visit_parameter(item);
+    }
+    if let Some(ref mut item) = node.return_type {
+        visitor.visit_type_annotation(item);
+    }
+    for item in &mut node.requires {
+        visitor.visit_expression(item);
+    }
+    for item in &mut node.ensures {
+        visitor.visit_expression(item);
+    }
+    if let Some(ref mut item) = node.body {
+        visitor.visit_block(item);
+    }
+}
+
+/// Walks the children of an [`Identifier`] node.
+#[inline]
+pub fn walk_identifier<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut Identifier) {}
+
+/// Walks the children of an [`If`] node.
+#[inline]
+pub fn walk_if<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut If) {
+    visitor.visit_expression(&mut node.condition);
+    visitor.visit_block(&mut node.then_block);
+    if let Some(ref mut item) = node.else_block {
+        visitor.visit_block(item);
+    }
+}
+
+/// Walks the children of an [`Import`] node.
+#[inline]
+pub fn walk_import<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Import) {
+    for item in &mut node.path {
+        visitor.visit_identifier(item);
+    }
+    if let Some(ref mut item) = node.alias {
+        visitor.visit_identifier(item);
+    }
+}
+
+/// Walks the children of an [`Index`] node.
+#[inline]
+pub fn walk_index<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Index) {
+    visitor.visit_expression(&mut node.target);
+    visitor.visit_expression(&mut node.index);
+}
+
+/// Walks the children of a [`Lambda`] node.
+#[inline]
+pub fn walk_lambda<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Lambda) {
+    for item in &mut node.parameters {
+        visitor.visit_parameter(item);
+    }
+    if let Some(ref mut item) = node.return_type {
+        visitor.visit_type_annotation(item);
+    }
+    visitor.visit_lambda_body(&mut node.body);
+}
+
+/// Walks the children of a [`LambdaBody`] node.
+#[inline]
+pub fn walk_lambda_body<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut LambdaBody) {
+    match *node {
+        LambdaBody::Block(ref mut inner) => {
+            visitor.visit_block(inner);
+        }
+        LambdaBody::Expression(ref mut inner) => {
+            visitor.visit_expression(inner);
+        }
+    }
+}
+
+/// Walks the children of a [`List`] node.
+#[inline]
+pub fn walk_list<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut List) {
+    for item in &mut node.elements {
+        visitor.visit_expression(item);
+    }
+}
+
+/// Walks the children of a [`Literal`] node.
+#[inline]
+pub fn walk_literal<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut Literal) {}
+
+/// Walks the children of a [`Match`] node.
+#[inline]
+pub fn walk_match<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Match) {
+    visitor.visit_expression(&mut node.subject);
+    for item in &mut node.arms {
+        visitor.visit_match_arm(item);
+    }
+}
+
+/// Walks the children of a [`MatchArm`] node.
+#[inline]
+pub fn walk_match_arm<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut MatchArm) {
+    if let Some(ref mut item) = node.pattern {
+        visitor.visit_pattern(item);
+    }
+    if let Some(ref mut item) = node.guard {
+        visitor.visit_expression(item);
+    }
+    visitor.visit_match_body(&mut node.body);
+}
+
+/// Walks the children of a [`MatchBody`] node.
+#[inline]
+pub fn walk_match_body<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut MatchBody) {
+    match *node {
+        MatchBody::Block(ref mut inner) => {
+            visitor.visit_block(inner);
+        }
+        MatchBody::Expression(ref mut inner) => {
+            visitor.visit_expression(inner);
+        }
+    }
+}
+
+/// Walks the children of a [`Member`] node.
+#[inline]
+pub fn walk_member<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Member) {
+    visitor.visit_expression(&mut node.target);
+    visitor.visit_identifier(&mut node.member);
+}
+
+/// Walks the children of a [`Parameter`] node.
+#[inline]
+pub fn walk_parameter<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Parameter) {
+    visitor.visit_type_annotation(&mut node.type_annotation);
+    visitor.visit_identifier(&mut node.name);
+    if let Some(ref mut item) = node.default {
+        visitor.visit_expression(item);
+    }
+}
+
+/// Walks the children of a [`Pattern`] node.
+#[inline]
+pub fn walk_pattern<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Pattern) {
+    match *node {
+        Pattern::Alternative(ref mut inner) => {
+            visitor.visit_alternative_pattern(inner);
+        }
+        Pattern::Binding(ref mut inner) => {
+            visitor.visit_binding_pattern(inner);
+        }
+        Pattern::Class(ref mut inner) => {
+            visitor.visit_class_pattern(inner);
+        }
+        Pattern::Literal(ref mut inner) => {
+            visitor.visit_literal(inner);
+        }
+        Pattern::Range(ref mut inner) => {
+            visitor.visit_range_pattern(inner);
+        }
+    }
+}
+
+/// Walks the children of a [`Program`] node.
+#[inline]
+pub fn walk_program<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Program) {
+    for item in &mut node.statements {
+        visitor.visit_statement(item);
+    }
+}
+
+/// Walks the children of a [`RangeBound`] node.
+#[inline]
+pub fn walk_range_bound<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut RangeBound) {
+    match *node {
+        RangeBound::Literal(ref mut inner) => {
+            visitor.visit_literal(inner);
+        }
+        RangeBound::Constant(ref mut inner) => {
+            visitor.visit_identifier(inner);
+        }
+    }
+}
+
+/// Walks the children of a [`RangePattern`] node.
+#[inline]
+pub fn walk_range_pattern<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut RangePattern) {
+    visitor.visit_range_bound(&mut node.start);
+    visitor.visit_range_bound(&mut node.end);
+}
+
+/// Walks the children of a [`Return`] node.
+#[inline]
+pub fn walk_return<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Return) {
+    if let Some(ref mut item) = node.value {
+        visitor.visit_expression(item);
+    }
+}
+
+/// Walks the children of a [`Slice`] node.
+#[inline]
+pub fn walk_slice<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Slice) {
+    visitor.visit_expression(&mut node.target);
+    if let Some(ref mut item) = node.start {
+        visitor.visit_expression(item);
+    }
+    if let Some(ref mut item) = node.end {
+        visitor.visit_expression(item);
+    }
+}
+
+/// Walks the children of a [`Statement`] node.
+#[inline]
+pub fn walk_statement<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Statement) {
+    match *node {
+        Statement::Assert(ref mut inner) => {
+            visitor.visit_assert(inner);
+        }
+        Statement::Block(ref mut inner) => {
+            visitor.visit_block(inner);
+        }
+        Statement::Break(ref mut inner) => {
+            visitor.visit_break(inner);
+        }
+        Statement::Class(ref mut inner) => {
+            visitor.visit_class(inner);
+        }
+        Statement::Continue(ref mut inner) => {
+            visitor.visit_continue(inner);
+        }
+        Statement::Expression(ref mut inner) => {
+            visitor.visit_expression_statement(inner);
+        }
+        Statement::For(ref mut inner) => {
+            visitor.visit_for(inner);
+        }
+        Statement::Function(ref mut inner) => {
+            visitor.visit_function(inner);
+        }
+        Statement::If(ref mut inner) => {
+            visitor.visit_if(inner);
+        }
+        Statement::Import(ref mut inner) => {
+            visitor.visit_import(inner);
+        }
+        Statement::Match(ref mut inner) => {
+            visitor.visit_match(inner);
+        }
+        Statement::Return(ref mut inner) => {
+            visitor.visit_return(inner);
+        }
+        Statement::Throw(ref mut inner) => {
+            visitor.visit_throw(inner);
+        }
+        Statement::Try(ref mut inner) => {
+            visitor.visit_try(inner);
+        }
+        Statement::Use(ref mut inner) => {
+            visitor.visit_use(inner);
+        }
+        Statement::VariableDeclaration(ref mut inner) => {
+            visitor.visit_variable_declaration(inner);
+        }
+        Statement::While(ref mut inner) => {
+            visitor.visit_while(inner);
+        }
+        Statement::Yield(ref mut inner) => {
+            visitor.visit_yield(inner);
+        }
+    }
+}
+
+/// Walks the children of a [`Throw`] node.
+#[inline]
+pub fn walk_throw<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Throw) {
+    visitor.visit_expression(&mut node.value);
+}
+
+/// Walks the children of a [`Try`] node.
+#[inline]
+pub fn walk_try<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Try) {
+    visitor.visit_block(&mut node.body);
+    for item in &mut node.catches {
+        visitor.visit_catch(item);
+    }
+    if let Some(ref mut item) = node.finally {
+        visitor.visit_block(item);
+    }
+}
+
+/// Walks the children of a [`Tuple`] node.
+#[inline]
+pub fn walk_tuple<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Tuple) {
+    for item in &mut node.elements {
+        visitor.visit_expression(item);
+    }
+}
+
+/// Walks the children of a [`TypeAnnotation`] node.
+#[inline]
+pub fn walk_type_annotation<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut TypeAnnotation) {
+    for item in &mut node.arguments {
+        visitor.visit_type_annotation(item);
+    }
+}
+
+/// Walks the children of a [`TypeParameter`] node.
+#[inline]
+pub fn walk_type_parameter<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut TypeParameter) {
+    visitor.visit_identifier(&mut node.name);
+    if let Some(ref mut item) = node.bound {
+        visitor.visit_type_annotation(item);
+    }
+}
+
+/// Walks the children of a [`Unary`] node.
+#[inline]
+pub fn walk_unary<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Unary) {
+    visitor.visit_expression(&mut node.operand);
+}
+
+/// Walks the children of a [`Use`] node.
+#[inline]
+pub fn walk_use<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Use) {
+    for item in &mut node.path {
+        visitor.visit_identifier(item);
+    }
+    if let Some(ref mut item) = node.alias {
+        visitor.visit_identifier(item);
+    }
+}
+
+/// Walks the children of a [`VariableDeclaration`] node.
+#[inline]
+pub fn walk_variable_declaration<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut VariableDeclaration,
+) {
+    if let Some(ref mut item) = node.type_annotation {
+        visitor.visit_type_annotation(item);
+    }
+    visitor.visit_identifier(&mut node.name);
+    if let Some(ref mut item) = node.value {
+        visitor.visit_expression(item);
+    }
+}
+
+/// Walks the children of a [`While`] node.
+#[inline]
+pub fn walk_while<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut While) {
+    visitor.visit_expression(&mut node.condition);
+    visitor.visit_block(&mut node.body);
+}
+
+/// Walks the children of a [`Yield`] node.
+#[inline]
+pub fn walk_yield<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Yield) {
+    visitor.visit_expression(&mut node.value);
+}
@@ -0,0 +1,885 @@
+// I Language parser tests.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use lexer::tokens::mark::Mark;
+    use lexer::tokens::token::{Location, TypeDefinition};
+    use parser::ast::{
+        Binary, Class, ClassMember, Expression, ForIterable, FormatPart, Identifier, Lambda,
+        LambdaBody, Literal, MatchBody, Pattern, Program, RangeBound, Statement, TypeAnnotation,
+        VariableDeclaration,
+    };
+    use parser::error::ParserError;
+
+    fn parse(input: &str) -> Result<Program, ParserError> {
+        parser::parse::parse(&lexer::lex::lex(input, "<stdin>").unwrap())
+    }
+
+    fn location(line: usize, column: usize) -> Location {
+        Location {
+            file: "<stdin>".to_owned(),
+            line,
+            column,
+        }
+    }
+
+    #[test]
+    fn test_variable_declaration() {
+        assert_eq!(
+            parse("int goal = 3;").unwrap().statements,
+            vec![Statement::VariableDeclaration(VariableDeclaration {
+                constant: false,
+                type_annotation: Some(TypeAnnotation {
+                    name: "int".to_owned(),
+                    arguments: vec![],
+                    nullable: false,
+                    location: location(1, 1),
+                }),
+                name: Identifier {
+                    name: "goal".to_owned(),
+                    location: location(1, 5),
+                },
+                value: Some(Expression::Literal(Literal {
+                    kind: TypeDefinition::Integer,
+                    value: "3".to_owned(),
+                    location: location(1, 12),
+                })),
+                location: location(1, 1),
+            })]
+        );
+
+        assert!(matches!(
+            &parse("Player player;").unwrap().statements[0],
+            Statement::VariableDeclaration(declaration)
+                if declaration.type_annotation.as_ref().map_or(false, |annotation| annotation.name == "Player")
+                    && declaration.value.is_none()
+        ));
+        assert!(matches!(
+            &parse("var goal = 3;").unwrap().statements[0],
+            Statement::VariableDeclaration(declaration)
+                if declaration.type_annotation.is_none() && declaration.value.is_some()
+        ));
+        assert!(parse("var goal: int = 3;").is_err());
+        assert!(matches!(
+            &parse("const int MAX = 25 * 4;").unwrap().statements[0],
+            Statement::VariableDeclaration(declaration)
+                if declaration.constant && declaration.type_annotation.is_some()
+        ));
+        assert!(matches!(
+            &parse("const MAX = 100;").unwrap().statements[0],
+            Statement::VariableDeclaration(declaration)
+                if declaration.constant && declaration.type_annotation.is_none()
+        ));
+        assert!(parse("const int MAX;").is_err());
+    }
+
+    #[test]
+    fn test_precedence() {
+        let program: Program = parse("1 + 2 * 3;").unwrap();
+        let Statement::Expression(ref statement) = program.statements[0] else {
+            panic!("expected an expression statement");
+        };
+
+        assert_eq!(
+            statement.expression,
+            Expression::Binary(Binary {
+                left: Box::new(Expression::Literal(Literal {
+                    kind: TypeDefinition::Integer,
+                    value: "1".to_owned(),
+                    location: location(1, 1),
+                })),
+                operator: Mark::Add,
+                right: Box::new(Expression::Binary(Binary {
+                    left: Box::new(Expression::Literal(Literal {
+                        kind: TypeDefinition::Integer,
+                        value: "2".to_owned(),
+                        location: location(1, 5),
+                    })),
+                    operator: Mark::Multiply,
+                    right: Box::new(Expression::Literal(Literal {
+                        kind: TypeDefinition::Integer,
+                        value: "3".to_owned(),
+                        location: location(1, 9),
+                    })),
+                    location: location(1, 7),
+                })),
+                location: location(1, 3),
+            })
+        );
+
+        let program: Program = parse("guess <= 0 || guess > 25;").unwrap();
+        assert!(matches!(
+            &program.statements[0],
+            Statement::Expression(statement)
+                if matches!(&statement.expression, Expression::Binary(binary) if binary.operator == Mark::Or)
+        ));
+    }
+
+    #[test]
+    fn test_statements() {
+        let program: Program = parse(
+            "int guess = 0;
+            while (guess != 3) {
+                guess = int(input(\"Guess: \"));
+                if (guess > 3) { continue; } else if (guess < 3) { print(\"Higher\"); } else { break; }
+            }
+            return;",
+        )
+        .unwrap();
+
+        assert_eq!(program.statements.len(), 3);
+        assert!(matches!(program.statements[1], Statement::While(_)));
+        assert!(
+            matches!(program.statements[2], Statement::Return(ref value) if value.value.is_none())
+        );
+    }
+
+    #[test]
+    fn test_class() {
+        let program: Program = parse(
+            "pub class Player: Entity {
+                pub int health = 100;
+                str name;
+                pub Player(str name) { this.name = name; }
+                pub function damage(int amount) -> none { this.health -= amount; }
+                function area() -> int;
+            }",
+        )
+        .unwrap();
+
+        let Statement::Class(ref class) = program.statements[0] else {
+            panic!("expected a class");
+        };
+        assert!(class.public);
+        assert_eq!(class.name.name, "Player");
+        assert_eq!(class.bases[0].name, "Entity");
+        assert_eq!(class.members.len(), 5);
+        assert!(
+            matches!(class.members[0], ClassMember::Field(ref field) if field.public && field.value.is_some())
+        );
+        assert!(matches!(class.members[1], ClassMember::Field(ref field) if !field.public));
+        assert!(
+            matches!(class.members[2], ClassMember::Constructor(ref constructor) if constructor.parameters.len() == 1)
+        );
+        assert!(matches!(
+            class.members[3],
+            ClassMember::Method(ref method)
+                if method.return_type.as_ref().map_or(false, |return_type| return_type.name == "none")
+        ));
+        assert!(
+            matches!(class.members[4], ClassMember::Method(ref method) if method.body.is_none())
+        );
+
+        assert!(parse("class Player { pub }").is_err());
+
+        let program: Program = parse(
+            "pub interface Shape: Named, Drawable { function area() -> int; }
+            class Square: Tile implements Shape, Named {}",
+        )
+        .unwrap();
+        let Statement::Class(ref interface) = program.statements[0] else {
+            panic!("expected an interface");
+        };
+        assert!(interface.interface && interface.public);
+        assert_eq!(interface.bases.len(), 2);
+        let Statement::Class(ref class) = program.statements[1] else {
+            panic!("expected a class");
+        };
+        assert!(!class.interface);
+        assert_eq!(class.bases[0].name, "Tile");
+        assert_eq!(class.interfaces[1].name, "Named");
+
+        assert!(parse("interface Shape implements Named {}").is_err());
+    }
+
+    #[test]
+    fn test_enum() {
+        let program: Program = parse(
+            "pub enum Option<T> { Some(T value), Nothing; function empty() -> bool { return true; } }
+            enum Color { Red, Green }",
+        )
+        .unwrap();
+
+        let Statement::Class(ref enumeration) = program.statements[0] else {
+            panic!("expected an enum");
+        };
+        assert!(enumeration.enumeration && enumeration.public);
+        assert_eq!(enumeration.variants.len(), 2);
+        assert_eq!(enumeration.members.len(), 1);
+
+        let some: &Class = &enumeration.variants[0];
+        assert!(some.public && !some.enumeration);
+        assert_eq!(some.name.name, "Option.Some");
+        assert_eq!(some.bases[0].to_string(), "Option<T>");
+        assert_eq!(some.type_parameters.len(), 1);
+        assert!(
+            matches!(some.members[0], ClassMember::Field(ref field) if field.public && field.name.name == "value")
+        );
+        assert!(matches!(
+            some.members[1],
+            ClassMember::Constructor(ref constructor)
+                if constructor.parameters.len() == 1 && constructor.body.is_none()
+        ));
+        assert_eq!(enumeration.variants[1].members.len(), 1);
+
+        let Statement::Class(ref color) = program.statements[1] else {
+            panic!("expected an enum");
+        };
+        assert_eq!(color.variants.len(), 2);
+
+        // Variants are named after their enum in types and patterns
+        let program: Program = parse(
+            "Color.Red red = Color.Red();
+            match (red) { case Color.Red() {} case Color.Green green {} }",
+        )
+        .unwrap();
+        let Statement::VariableDeclaration(ref declaration) = program.statements[0] else {
+            panic!("expected a variable declaration");
+        };
+        assert_eq!(
+            declaration.type_annotation.as_ref().unwrap().name,
+            "Color.Red"
+        );
+        let Statement::Match(ref node) = program.statements[1] else {
+            panic!("expected a match statement");
+        };
+        assert!(matches!(
+            node.arms[0].pattern,
+            Some(Pattern::Class(ref pattern)) if pattern.class.name == "Color.Red"
+        ));
+        assert!(matches!(
+            node.arms[1].pattern,
+            Some(Pattern::Binding(ref pattern))
+                if pattern.type_annotation.as_ref().map(|found| found.name.as_str()) == Some("Color.Green")
+        ));
+
+        assert!(parse("enum Color: Base { Red }").is_err());
+        assert!(parse("enum Color { Red Green }").is_err());
+        assert!(parse("enum Color { Red, Green function f() -> none {} }").is_err());
+    }
+
+    #[test]
+    fn test_generics() {
+        let program: Program = parse(
+            "class Box<T: Entity> { T value; }
+            function first<T, U>(T a, U b) -> T { return a; }
+            Box<Box<int>> box = Box(Box(1));
+            a < b;",
+        )
+        .unwrap();
+
+        let Statement::Class(ref class) = program.statements[0] else {
+            panic!("expected a class");
+        };
+        assert_eq!(class.type_parameters.len(), 1);
+        assert!(class.type_parameters[0]
+            .bound
+            .as_ref()
+            .map_or(false, |bound| bound.name == "Entity"));
+
+        let Statement::Function(ref function) = program.statements[1] else {
+            panic!("expected a function");
+        };
+        assert_eq!(function.type_parameters.len(), 2);
+        assert!(function.type_parameters[1].bound.is_none());
+
+        let Statement::VariableDeclaration(ref declaration) = program.statements[2] else {
+            panic!("expected a variable declaration");
+        };
+        assert!(declaration
+            .type_annotation
+            .as_ref()
+            .map_or(false, |annotation| annotation.to_string()
+                == "Box<Box<int>>"));
+
+        // A comparison is no type annotation
+        assert!(matches!(program.statements[3], Statement::Expression(_)));
+
+        assert!(parse("Box<int b;").is_err());
+        assert!(parse("class Box<1> {}").is_err());
+    }
+
+    #[test]
+    fn test_collections() {
+        let program: Program = parse(
+            "list<int> items = [1, 2, 3,];
+            dict<str, list<int>> groups = {\"a\": [1], \"b\": []};
+            var pair = (1, \"x\");
+            var single = (1,);
+            var grouped = (1);
+            items[1 .. -1];
+            items[.. 2];
+            items[0] = ();",
+        )
+        .unwrap();
+
+        let value = |index: usize| match program.statements[index] {
+            Statement::VariableDeclaration(ref declaration) => declaration.value.clone(),
+            _ => None,
+        };
+        assert!(matches!(value(0), Some(Expression::List(ref list)) if list.elements.len() == 3));
+        assert!(matches!(
+            value(1),
+            Some(Expression::Dict(ref dict))
+                if matches!(dict.entries[1].value, Expression::List(ref list) if list.elements.is_empty())
+        ));
+        assert!(
+            matches!(value(2), Some(Expression::Tuple(ref tuple)) if tuple.elements.len() == 2)
+        );
+        assert!(
+            matches!(value(3), Some(Expression::Tuple(ref tuple)) if tuple.elements.len() == 1)
+        );
+        assert!(matches!(value(4), Some(Expression::Literal(_))));
+
+        let expression = |index: usize| match program.statements[index] {
+            Statement::Expression(ref statement) => Some(&statement.expression),
+            _ => None,
+        };
+        let Some(Expression::Slice(slice)) = expression(5) else {
+            panic!("expected a slice");
+        };
+        assert!(slice.start.is_some() && slice.end.is_some());
+        assert!(matches!(expression(6), Some(Expression::Slice(slice)) if slice.start.is_none()));
+        assert!(matches!(
+            expression(7),
+            Some(Expression::Assignment(assignment))
+                if matches!(*assignment.target, Expression::Index(_))
+                    && matches!(*assignment.value, Expression::Tuple(ref tuple) if tuple.elements.is_empty())
+        ));
+
+        assert!(parse("[1, 2").is_err());
+        assert!(parse("var entries = {\"a\" 1};").is_err());
+        assert!(parse("items[1 .. 2] = [];").is_err());
+    }
+
+    #[test]
+    fn test_function() {
+        let program: Program = parse(
+            "pub function greet(str name, int times = 1) -> bool { return true; }
+            greet(\"Max\", times = 2);",
+        )
+        .unwrap();
+
+        let Statement::Function(ref function) = program.statements[0] else {
+            panic!("expected a function");
+        };
+        assert!(function.public);
+        assert_eq!(function.name.name, "greet");
+        assert_eq!(function.parameters.len(), 2);
+        assert!(function.parameters[0].default.is_none());
+        assert!(function.parameters[1].default.is_some());
+        assert!(function
+            .return_type
+            .as_ref()
+            .map_or(false, |return_type| return_type.name == "bool"));
+
+        let Statement::Expression(ref statement) = program.statements[1] else {
+            panic!("expected an expression statement");
+        };
+        let Expression::Call(ref call) = statement.expression else {
+            panic!("expected a call");
+        };
+        assert!(call.arguments[0].name.is_none());
+        assert!(call.arguments[1]
+            .name
+            .as_ref()
+            .map_or(false, |name| name.name == "times"));
+
+        // Comparisons are no keyword arguments
+        let program: Program = parse("greet(times == 2);").unwrap();
+        let Statement::Expression(ref statement) = program.statements[0] else {
+            panic!("expected an expression statement");
+        };
+        assert!(
+            matches!(statement.expression, Expression::Call(ref call) if call.arguments[0].name.is_none())
+        );
+
+        assert!(parse("function greet(int times = ) {}").is_err());
+    }
+
+    #[test]
+    fn test_match() {
+        let program: Program = parse(
+            "match (value) {
+                case 1 | -2 { }
+                case 3 .. 10 if (lucky) { }
+                case Point(x, 0) -> print(x);
+                case Player player { }
+                default { }
+            }
+            str name = match (value) { case 1 -> \"one\", default -> \"other\" };",
+        )
+        .unwrap();
+
+        let Statement::Match(ref node) = program.statements[0] else {
+            panic!("expected a match");
+        };
+        assert_eq!(node.arms.len(), 5);
+        assert!(matches!(
+            node.arms[0].pattern,
+            Some(Pattern::Alternative(ref alternative))
+                if matches!(alternative.patterns[1], Pattern::Literal(ref literal) if literal.value == "-2")
+        ));
+        assert!(matches!(node.arms[1].pattern, Some(Pattern::Range(_))));
+        assert!(node.arms[1].guard.is_some());
+        assert!(matches!(
+            node.arms[2].pattern,
+            Some(Pattern::Class(ref class)) if class.fields.len() == 2
+        ));
+        assert!(matches!(node.arms[2].body, MatchBody::Expression(_)));
+        assert!(matches!(
+            node.arms[3].pattern,
+            Some(Pattern::Binding(ref binding)) if binding.type_annotation.is_some()
+        ));
+        assert!(node.arms[4].pattern.is_none());
+
+        let Statement::VariableDeclaration(ref declaration) = program.statements[1] else {
+            panic!("expected a variable declaration");
+        };
+        assert!(
+            matches!(declaration.value, Some(Expression::Match(ref node)) if node.arms.len() == 2)
+        );
+
+        // Match expressions need arrows
+        assert!(parse("int x = match (value) { default { } };").is_err());
+        assert!(parse("match (value) { 1 { } }").is_err());
+
+        // Range bounds may name constants
+        let program: Program =
+            parse("match (value) { case MIN .. 0 { } case -1 .. MAX { } }").unwrap();
+        let Statement::Match(ref node) = program.statements[0] else {
+            panic!("expected a match");
+        };
+        assert!(matches!(
+            node.arms[0].pattern,
+            Some(Pattern::Range(ref range))
+                if matches!(range.start, RangeBound::Constant(ref name) if name.name == "MIN")
+                    && matches!(range.end, RangeBound::Literal(_))
+        ));
+        assert!(matches!(
+            node.arms[1].pattern,
+            Some(Pattern::Range(ref range))
+                if matches!(range.end, RangeBound::Constant(ref name) if name.name == "MAX")
+        ));
+        assert!(parse("match (value) { case 0 .. { } }").is_err());
+    }
+
+    #[test]
+    fn test_imports() {
+        let program: Program = parse(
+            "import random; import geometry.shapes as shapes; use geometry.shapes.area as area;",
+        )
+        .unwrap();
+
+        assert!(matches!(
+            program.statements[0],
+            Statement::Import(ref node) if node.path.len() == 1 && node.alias.is_none()
+        ));
+        assert!(matches!(
+            program.statements[1],
+            Statement::Import(ref node)
+                if node.path[1].name == "shapes" && node.alias.as_ref().map_or(false, |alias| alias.name == "shapes")
+        ));
+        assert!(matches!(
+            program.statements[2],
+            Statement::Use(ref node) if node.path.len() == 3 && node.path[2].name == "area"
+        ));
+
+        // A use statement needs a module and an item
+        assert!(parse("use random;").is_err());
+        assert!(parse("import random as;").is_err());
+    }
+
+    #[test]
+    fn test_try() {
+        let program: Program = parse(
+            "try { throw ValueError(\"bad\"); } catch TypeError(value) { } catch ValueError { } finally { }
+            try { } finally { }",
+        )
+        .unwrap();
+
+        let Statement::Try(ref node) = program.statements[0] else {
+            panic!("expected a try statement");
+        };
+        assert!(matches!(node.body.statements[0], Statement::Throw(_)));
+        assert_eq!(node.catches.len(), 2);
+        assert_eq!(node.catches[0].exception.name, "TypeError");
+        assert_eq!(
+            node.catches[0]
+                .binding
+                .as_ref()
+                .map(|binding| binding.name.as_str()),
+            Some("value")
+        );
+        assert!(node.catches[1].binding.is_none());
+        assert!(node.finally.is_some());
+        assert!(
+            matches!(program.statements[1], Statement::Try(ref node) if node.catches.is_empty())
+        );
+
+        // A try statement needs a catch clause or a finally block
+        assert!(parse("try { }").is_err());
+    }
+
+    #[test]
+    fn test_for() {
+        let program: Program = parse(
+            "for (var i in 0 .. 10) { }
+            for (int i in 10 ..= 0 step -2) { break; }
+            for (str name in names) { continue; }",
+        )
+        .unwrap();
+
+        let for_statement = |index: usize| match program.statements[index] {
+            Statement::For(ref node) => node,
+            _ => panic!("expected a for loop"),
+        };
+        let first = for_statement(0);
+        assert_eq!(first.variable.name, "i");
+        assert!(first.type_annotation.is_none());
+        assert!(matches!(
+            first.iterable,
+            ForIterable::Range(ref range) if !range.inclusive && range.step.is_none()
+        ));
+        let second = for_statement(1);
+        assert_eq!(
+            second
+                .type_annotation
+                .as_ref()
+                .map(|annotation| annotation.name.as_str()),
+            Some("int")
+        );
+        assert!(matches!(
+            second.iterable,
+            ForIterable::Range(ref range)
+                if range.inclusive && matches!(range.step, Some(Expression::Unary(_)))
+        ));
+        assert!(matches!(
+            for_statement(2).iterable,
+            ForIterable::Value(ref value)
+                if matches!(**value, Expression::Identifier(ref identifier) if identifier.name == "names")
+        ));
+
+        assert!(parse("for (i in items) { }").is_err());
+        assert!(parse("for (var i of items) { }").is_err());
+        assert!(parse("for (var i in 0 .. 10 step) { }").is_err());
+    }
+
+    #[test]
+    fn test_yield() {
+        let program: Program =
+            parse("function count() -> Iterator<int> { yield 1; yield 1 + 1; }").unwrap();
+
+        let Statement::Function(ref function) = program.statements[0] else {
+            panic!("expected a function");
+        };
+        let statements: &[Statement] = &function.body.as_ref().unwrap().statements;
+        assert!(matches!(
+            statements[0],
+            Statement::Yield(ref node) if matches!(node.value, Expression::Literal(_))
+        ));
+        assert!(matches!(
+            statements[1],
+            Statement::Yield(ref node) if matches!(node.value, Expression::Binary(_))
+        ));
+
+        assert!(parse("function count() -> Iterator<int> { yield; }").is_err());
+        assert!(parse("function count() -> Iterator<int> { yield 1 }").is_err());
+    }
+
+    #[test]
+    fn test_contracts() {
+        let program: Program = parse(
+            "function divide(int x, int y) -> int requires y != 0 ensures result * y <= x requires x >= 0 {
+                assert x / y >= 0;
+                assert y > 0, \"positive\";
+                return x / y;
+            }
+            class Account { pub int balance; pub Account(int balance) requires balance >= 0 { } }",
+        )
+        .unwrap();
+
+        let Statement::Function(ref function) = program.statements[0] else {
+            panic!("expected a function");
+        };
+        assert_eq!(function.requires.len(), 2);
+        assert_eq!(function.ensures.len(), 1);
+        let statements: &[Statement] = &function.body.as_ref().unwrap().statements;
+        assert!(matches!(statements[0], Statement::Assert(ref node) if node.message.is_none()));
+        assert!(matches!(
+            statements[1],
+            Statement::Assert(ref node) if matches!(node.message, Some(Expression::Literal(_)))
+        ));
+        let Statement::Class(ref class) = program.statements[1] else {
+            panic!("expected a class");
+        };
+        assert!(matches!(
+            class.members[1],
+            ClassMember::Constructor(ref constructor) if constructor.requires.len() == 1
+        ));
+
+        assert!(parse("assert;").is_err());
+        assert!(parse("assert x,;").is_err());
+        assert!(parse("function f() requires { }").is_err());
+    }
+
+    #[test]
+    fn test_format_strings() {
+        let program: Program =
+            parse("print(f\"{{{guess}}} is not in {names['a:b'] + {'k': 1}[x]:>8} {{\");").unwrap();
+        let Statement::Expression(ref statement) = program.statements[0] else {
+            panic!("expected an expression statement");
+        };
+        let Expression::Call(ref call) = statement.expression else {
+            panic!("expected a call");
+        };
+        let Expression::FormatString(ref format_string) = call.arguments[0].value else {
+            panic!("expected a format string");
+        };
+        assert_eq!(format_string.location.column, 7);
+        let [FormatPart::Text(ref open), FormatPart::Field(ref guess), FormatPart::Text(ref middle), FormatPart::Field(ref name), FormatPart::Text(ref close)] =
+            format_string.parts[..]
+        else {
+            panic!("unexpected parts {:?}", format_string.parts);
+        };
+        assert_eq!(
+            (open.as_str(), middle.as_str(), close.as_str()),
+            ("{", "} is not in ", " {")
+        );
+        assert!(
+            matches!(guess.value, Expression::Identifier(ref identifier) if identifier.location.column == 12)
+        );
+        assert_eq!(
+            (guess.location.column, guess.specification.as_deref()),
+            (11, None)
+        );
+        assert!(matches!(name.value, Expression::Binary(_)));
+        assert_eq!(name.specification.as_deref(), Some(">8"));
+
+        assert!(parse("f\"{}\";").is_err());
+        assert!(parse("f\"{x\";").is_err());
+        assert!(parse("f\"x}\";").is_err());
+        assert!(parse("f\"{x y}\";").is_err());
+        assert!(parse("f\"{x $ 1}\";").is_err());
+    }
+
+    #[test]
+    fn test_lambda() {
+        let program: Program = parse(
+            "var double = (int x) -> x * 2;
+            var add = function(int a, int b = 1) -> int { return a + b; };
+            var run = () -> { print(1); };
+            function(int) -> bool check = (int x) -> bool { return x > 0; };
+            var grouped = (x) * 2;",
+        )
+        .unwrap();
+
+        let declaration = |index: usize| match program.statements[index] {
+            Statement::VariableDeclaration(ref node) => node,
+            _ => panic!("expected a variable declaration"),
+        };
+        let lambda = |index: usize| -> &Lambda {
+            match declaration(index).value {
+                Some(Expression::Lambda(ref lambda)) => lambda,
+                _ => panic!("expected a lambda"),
+            }
+        };
+        let double = lambda(0);
+        assert_eq!(double.parameters.len(), 1);
+        assert!(double.return_type.is_none());
+        assert!(matches!(
+            double.body,
+            LambdaBody::Expression(ref body) if matches!(**body, Expression::Binary(_))
+        ));
+        let add = lambda(1);
+        assert_eq!(add.parameters.len(), 2);
+        assert!(add.parameters[1].default.is_some());
+        assert_eq!(
+            add.return_type
+                .as_ref()
+                .map(|annotation| annotation.name.as_str()),
+            Some("int")
+        );
+        assert!(matches!(add.body, LambdaBody::Block(_)));
+        let run = lambda(2);
+        assert!(run.parameters.is_empty() && run.return_type.is_none());
+        assert!(matches!(run.body, LambdaBody::Block(_)));
+        assert_eq!(
+            declaration(3)
+                .type_annotation
+                .as_ref()
+                .map(ToString::to_string),
+            Some("function(int) -> bool".to_owned())
+        );
+        assert!(lambda(3).return_type.is_some());
+        assert!(matches!(declaration(4).value, Some(Expression::Binary(_))));
+
+        assert!(parse("var f = (int x) -> ;").is_err());
+        assert!(parse("var f = function(int x) -> int x;").is_err());
+        assert!(parse("function(int) check = none;").is_err());
+    }
+
+    #[test]
+    fn test_nullable() {
+        let program: Program = parse(
+            "int? count = none;
+            Box<Box<int>>? nested = none;
+            var name = player?.name ?? \"nobody\" || ready;
+            function(str?) -> int? parse = none;",
+        )
+        .unwrap();
+
+        let declaration = |index: usize| match program.statements[index] {
+            Statement::VariableDeclaration(ref node) => node,
+            _ => panic!("expected a variable declaration"),
+        };
+        let annotation = |index: usize| {
+            declaration(index)
+                .type_annotation
+                .as_ref()
+                .map(ToString::to_string)
+        };
+        assert_eq!(annotation(0), Some("int?".to_owned()));
+        assert_eq!(annotation(1), Some("Box<Box<int>>?".to_owned()));
+        assert!(!declaration(1).type_annotation.as_ref().unwrap().arguments[0].nullable);
+        assert_eq!(annotation(3), Some("function(str?) -> int?".to_owned()));
+
+        // `??` binds weaker than `||`
+        let Some(Expression::Binary(ref coalesce)) = declaration(2).value else {
+            panic!("expected a binary expression");
+        };
+        assert_eq!(coalesce.operator, Mark::Coalesce);
+        assert!(matches!(*coalesce.left, Expression::Member(ref member) if member.safe));
+        assert!(matches!(*coalesce.right, Expression::Binary(_)));
+
+        assert!(parse("int?? count = none;").is_err());
+    }
+
+    #[test]
+    fn test_annotations() {
+        let program: Program = parse(
+            "@deprecated(\"Use jump instead\") @inline
+            pub function hop() {}
+            @test class Player {
+                @deprecated(message = \"Use height\") pub int size;
+                @noinline function jump();
+            }",
+        )
+        .unwrap();
+
+        let Statement::Function(ref function) = program.statements[0] else {
+            panic!("expected a function");
+        };
+        assert!(function.public);
+        assert_eq!(function.annotations.len(), 2);
+        assert_eq!(function.annotations[0].name.name, "deprecated");
+        assert_eq!(function.annotations[0].location, location(1, 1));
+        assert!(matches!(
+            function.annotations[0].arguments[0].value,
+            Expression::Literal(ref literal) if literal.value == "Use jump instead"
+        ));
+        assert!(function.annotations[1].arguments.is_empty());
+
+        let Statement::Class(ref class) = program.statements[1] else {
+            panic!("expected a class");
+        };
+        assert_eq!(class.annotations[0].name.name, "test");
+        let ClassMember::Field(ref field) = class.members[0] else {
+            panic!("expected a field");
+        };
+        assert_eq!(
+            field.annotations[0].arguments[0]
+                .name
+                .as_ref()
+                .map(|name| name.name.as_str()),
+            Some("message")
+        );
+        assert!(matches!(
+            class.members[1],
+            ClassMember::Method(ref method) if method.annotations[0].name.name == "noinline"
+        ));
+
+        assert!(parse("@deprecated int x = 1;").is_err());
+        assert!(parse("@inline").is_err());
+        assert!(parse("@(1) function f() {}").is_err());
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            parse("int goal = 3"),
+            Err(ParserError::UnexpectedEndOfInput {
+                expected: "`;`".to_owned(),
+                location: location(1, 12),
+            })
+        );
+        assert_eq!(
+            parse("if guess {}"),
+            Err(ParserError::UnexpectedToken {
+                expected: "`(`".to_owned(),
+                found: "guess".to_owned(),
+                location: location(1, 4),
+            })
+        );
+        assert_eq!(
+            parse("1 = 2;"),
+            Err(ParserError::InvalidAssignmentTarget {
+                location: location(1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_nesting() {
+        let parentheses =
+            |depth: usize| format!("print({}1{});", "(".repeat(depth), ")".repeat(depth));
+        assert!(parse(&parentheses(100)).is_ok());
+        assert!(matches!(
+            parse(&parentheses(5000)),
+            Err(ParserError::NestedTooDeeply { limit: 200, .. })
+        ));
+
+        assert!(parse(&format!("int x = 1{};", " + 1".repeat(100))).is_ok());
+        assert!(matches!(
+            parse(&format!("int x = 1{};", " + 1".repeat(5000))),
+            Err(ParserError::NestedTooDeeply { .. })
+        ));
+        assert!(matches!(
+            parse(&format!("int x = {}1;", "-".repeat(5000))),
+            Err(ParserError::NestedTooDeeply { .. })
+        ));
+        assert!(matches!(
+            parse(&format!(
+                "{}{}",
+                "if (true) { ".repeat(5000),
+                "}".repeat(5000)
+            )),
+            Err(ParserError::NestedTooDeeply { .. })
+        ));
+    }
+}
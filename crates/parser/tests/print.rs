@@ -0,0 +1,71 @@
+// I Language parser visitor tests.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use parser::ast::{Program, Statement};
+
+    fn print(input: &str) -> String {
+        let tokens: Vec<lexer::tokens::token::Token> =
+            lexer::lex::lex(&format!("{input};"), "<stdin>").unwrap();
+        let program: Program = parser::parse::parse(&tokens).unwrap();
+        match program.statements[0] {
+            Statement::Expression(ref statement) => {
+                parser::print::expression(&statement.expression)
+            }
+            _ => panic!("expected an expression"),
+        }
+    }
+
+    #[test]
+    fn test_print() {
+        for (input, expected) in [
+            ("x+1==y", "x + 1 == y"),
+            ("(a+b)*c-(d-e)", "(a + b) * c - (d - e)"),
+            ("a-(b+c)", "a - (b + c)"),
+            ("(2**3)**2", "(2 ** 3) ** 2"),
+            ("2**3**2", "2 ** 3 ** 2"),
+            ("!(done||ready)&&-count<0", "!(done || ready) && -count < 0"),
+            ("(-x).size()", "(-x).size()"),
+            ("items[i+1][0 ..]", "items[i + 1][0 ..]"),
+            ("player?.name", "player?.name"),
+            ("counter++", "counter++"),
+            ("scores[.. 3]", "scores[.. 3]"),
+            ("greet(\"Hi\",name=first)", "greet(\"Hi\", name = first)"),
+            ("[1,(2,),{\"a\":none}]", "[1, (2,), {\"a\": none}]"),
+            ("total+=(int x)->x*2", "total += (int x) -> x * 2"),
+            ("f'{a+1:>3} {{x}}'", "f\"{a + 1:>3} {{x}}\""),
+            (
+                "print(match (x) { case 0 -> 1, default -> 2 })",
+                "print(match (x) { ... })",
+            ),
+        ] {
+            assert_eq!(print(input), expected, "{input}");
+        }
+    }
+}
@@ -0,0 +1,151 @@
+// I Language parser visitor tests.
+// Version: 1.0.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use parser::ast::{Identifier, Literal, Program};
+    use parser::fold::Fold;
+    use parser::visit::Visitor;
+    use parser::visit_mut::VisitorMut;
+
+    const AST: &str = include_str!("../src/ast.rs");
+    const VISIT: &str = include_str!("../src/visit.rs");
+    const VISIT_MUT: &str = include_str!("../src/visit_mut.rs");
+    const FOLD: &str = include_str!("../src/fold.rs");
+
+    fn parse(input: &str) -> Program {
+        parser::parse::parse(&lexer::lex::lex(input, "<stdin>").unwrap()).unwrap()
+    }
+
+    fn snake_case(name: &str) -> String {
+        let mut result: String = String::new();
+        for (index, character) in name.chars().enumerate() {
+            if character.is_uppercase() && index != 0 {
+                result.push('_');
+            }
+            result.push(character.to_ascii_lowercase());
+        }
+
+        result
+    }
+
+    #[test]
+    fn test_every_node_is_visited() {
+        let nodes: Vec<&str> = AST
+            .lines()
+            .filter_map(|line| {
+                line.strip_prefix("pub struct ")
+                    .or_else(|| line.strip_prefix("pub enum "))
+            })
+            .filter_map(|line| line.split_whitespace().next())
+            .collect();
+        assert!(!nodes.is_empty());
+
+        for node in nodes {
+            let name: String = snake_case(node);
+
+            for (file, method) in [(VISIT, "visit"), (VISIT_MUT, "visit"), (FOLD, "fold")] {
+                assert!(
+                    file.contains(&format!("fn {method}_{name}(")),
+                    "`{node}` has no `{method}_{name}` method, run `just generate-visitors`"
+                );
+                assert!(
+                    file.contains(&format!("pub fn walk_{name}<")),
+                    "`{node}` has no `walk_{name}` function, run `just generate-visitors`"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_visitor() {
+        struct Counter {
+            identifiers: Vec<String>,
+            literals: usize,
+        }
+
+        impl Visitor<'_> for Counter {
+            fn visit_identifier(&mut self, node: &Identifier) {
+                self.identifiers.push(node.name.clone());
+            }
+
+            fn visit_literal(&mut self, _node: &Literal) {
+                self.literals += 1;
+            }
+        }
+
+        let mut counter: Counter = Counter {
+            identifiers: vec![],
+            literals: 0,
+        };
+        counter.visit_program(&parse(
+            "int goal = 3; while (guess != goal) { if (guess > 25) { print(\"Too high\"); } }",
+        ));
+
+        assert_eq!(
+            counter.identifiers,
+            vec!["goal", "guess", "goal", "guess", "print"]
+        );
+        assert_eq!(counter.literals, 3);
+    }
+
+    #[test]
+    fn test_visitor_mut() {
+        struct Renamer;
+
+        impl VisitorMut for Renamer {
+            fn visit_identifier(&mut self, node: &mut Identifier) {
+                node.name = node.name.to_uppercase();
+            }
+        }
+
+        let mut program: Program = parse("int goal = guess;");
+        Renamer.visit_program(&mut program);
+
+        assert_eq!(program, parse("int GOAL = GUESS;"));
+    }
+
+    #[test]
+    fn test_fold() {
+        struct Doubler;
+
+        impl Fold for Doubler {
+            fn fold_literal(&mut self, mut node: Literal) -> Literal {
+                if let Ok(value) = node.value.parse::<i64>() {
+                    node.value = (value * 2).to_string();
+                }
+
+                node
+            }
+        }
+
+        assert_eq!(
+            Doubler.fold_program(parse("print(1 + 2);")),
+            parse("print(2 + 4);")
+        );
+    }
+}
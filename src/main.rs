@@ -50,6 +50,14 @@ struct Cli {
     #[arg(short, long)]
     beta: bool,
 
+    /// Whether to run the functions marked with `@test` instead of the program
+    #[arg(short, long)]
+    test: bool,
+
+    /// Whether to build for release, which removes assertions and contracts instead of checking them
+    #[arg(short, long)]
+    release: bool,
+
     /// The directory where the output should be written to
     #[arg(short = 'o', long = "output", default_value = "out")]
     output: String,
@@ -122,6 +130,123 @@ fn main() {
                         &arguments.output, "/tokens"
                     )
                 });
+
+            let start: std::time::Instant = std::time::Instant::now();
+            let program: parser::ast::Program = match parser::parse::parse(&tokens) {
+                Ok(program) => program,
+                Err(error) => {
+                    eprintln!(
+                        "{}",
+                        compiler::diagnostic::Diagnostic::from(&error).render(input.trim())
+                    );
+                    eprintln!("Compiling `{file_name}` was not successful.");
+                    std::process::exit(1);
+                }
+            };
+            debug!(
+                "Parsing `{file_name}` took {}ms.",
+                start.elapsed().as_millis()
+            );
+
+            let start: std::time::Instant = std::time::Instant::now();
+            let entry: compiler::modules::Module = compiler::modules::Module {
+                name: std::path::Path::new(&file_name)
+                    .file_stem()
+                    .map_or_else(String::new, |stem| stem.to_string_lossy().into_owned()),
+                file: file_name.clone(),
+                source: input.trim().to_owned(),
+                program,
+            };
+            let (mut modules, mut diagnostics): (
+                compiler::modules::Modules,
+                Vec<compiler::diagnostic::Diagnostic>,
+            ) = compiler::modules::Modules::load(entry);
+            debug!(
+                "Loading the modules of `{file_name}` took {}ms.",
+                start.elapsed().as_millis()
+            );
+
+            let start: std::time::Instant = std::time::Instant::now();
+            diagnostics.extend(compiler::check::check_modules(&modules));
+            debug!(
+                "Checking `{file_name}` took {}ms.",
+                start.elapsed().as_millis()
+            );
+
+            for diagnostic in &diagnostics {
+                eprintln!("{}", diagnostic.render_with(|file| modules.source(file)));
+            }
+            if diagnostics
+                .iter()
+                .any(compiler::diagnostic::Diagnostic::is_error)
+            {
+                eprintln!("Compiling `{file_name}` was not successful.");
+                std::process::exit(1);
+            }
+            if arguments.release {
+                modules.strip_contracts();
+            }
+
+            if arguments.test {
+                run_tests(&modules, &file_name);
+            }
+
+            let start: std::time::Instant = std::time::Instant::now();
+            let result: Result<(), interpreter::error::Uncaught> =
+                interpreter::interpret::run_modules(
+                    &modules,
+                    &mut std::io::stdin().lock(),
+                    &mut std::io::stdout(),
+                );
+            debug!(
+                "Running `{file_name}` took {}ms.",
+                start.elapsed().as_millis()
+            );
+
+            if let Err(error) = result {
+                eprintln!("{error}");
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+/// Runs the functions of the program marked with `@test`, prints whether each of them passed and exits.
+fn run_tests(modules: &compiler::modules::Modules, file_name: &str) -> ! {
+    let start: std::time::Instant = std::time::Instant::now();
+    let results: Vec<(&str, Result<(), interpreter::error::Uncaught>)> =
+        match interpreter::interpret::run_tests(
+            modules,
+            &mut std::io::stdin().lock(),
+            &mut std::io::stdout(),
+        ) {
+            Ok(results) => results,
+            Err(error) => {
+                eprintln!("{error}");
+                std::process::exit(1);
+            }
+        };
+    debug!(
+        "Testing `{file_name}` took {}ms.",
+        start.elapsed().as_millis()
+    );
+
+    let mut failed: usize = 0;
+    for (name, result) in &results {
+        match *result {
+            Ok(()) => println!("test {name} ... ok"),
+            Err(ref error) => {
+                failed += 1;
+                println!("test {name} ... FAILED");
+                eprintln!("{error}");
+            }
         }
     }
+
+    println!(
+        "\ntest result: {}. {} passed; {failed} failed",
+        if failed == 0 { "ok" } else { "FAILED" },
+        results.len() - failed
+    );
+    std::process::exit(i32::from(failed > 0));
 }
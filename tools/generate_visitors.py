@@ -0,0 +1,437 @@
+"""
+I Language generate visitors tool.
+Version: 1.0.0
+
+Copyright (c) 2023-present I Language Development.
+
+Permission is hereby granted, free of charge, to any person obtaining a
+copy of this software and associated documentation files (the 'Software'),
+to deal in the Software without restriction, including without limitation
+the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the
+Software is furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+DEALINGS IN THE SOFTWARE.
+"""
+
+
+###########
+# IMPORTS #
+###########
+
+import pathlib
+import re
+import typing
+
+
+#############
+# CONSTANTS #
+#############
+
+AST_PATH: pathlib.Path = pathlib.Path("crates", "parser", "src", "ast.rs")
+OUTPUT_FOLDER: pathlib.Path = pathlib.Path("crates", "parser", "src")
+LICENSE: str = "".join(
+    line
+    for line in AST_PATH.read_text(encoding="utf-8").splitlines(keepends=True)[4:23]
+)
+WRAPPERS: typing.Tuple[str, ...] = ("Box", "Option", "Vec")
+
+STRUCT_PATTERN: re.Pattern = re.compile(r"^pub struct (?P<name>\w+) \{$(?P<body>.*?)^\}", re.M | re.S)
+ENUM_PATTERN: re.Pattern = re.compile(r"^pub enum (?P<name>\w+) \{$(?P<body>.*?)^\}", re.M | re.S)
+FIELD_PATTERN: re.Pattern = re.compile(r"^    pub (?P<name>\w+): (?P<type>.+),$", re.M)
+VARIANT_PATTERN: re.Pattern = re.compile(r"^    (?P<name>\w+)(?:\((?P<type>.+)\))?,$", re.M)
+
+
+###########
+# CLASSES #
+###########
+
+
+class Node(typing.NamedTuple):
+    """A node type of the AST.
+
+    Attributes:
+        name (str): Name of the type.
+        is_enum (bool): Whether the node is an enum or a struct.
+        members (typing.List[typing.Tuple[str, typing.Optional[str]]]): Fields of a struct or
+            variants of an enum. Contains the name and type of each member.
+    """
+
+    name: str
+    is_enum: bool
+    members: typing.List[typing.Tuple[str, typing.Optional[str]]]
+
+
+class Mode(typing.NamedTuple):
+    """A kind of visitor to generate.
+
+    Attributes:
+        module (str): Name of the module to generate.
+        trait (str): Name of the trait to generate.
+        method (str): Prefix of the trait methods.
+        description (str): Documentation of the module.
+        title (str): Title in the license header.
+        trait_description (str): Documentation of the trait.
+    """
+
+    module: str
+    trait: str
+    method: str
+    description: str
+    title: str
+    trait_description: str
+
+
+MODES: typing.List[Mode] = [
+    Mode(
+        "visit",
+        "Visitor",
+        "visit",
+        "A visitor walking the AST by reference.",
+        "I Language AST visitor.",
+        "A visitor walking the AST by reference. Every method defaults to visiting the children of the node.",
+    ),
+    Mode(
+        "visit_mut",
+        "VisitorMut",
+        "visit",
+        "A visitor walking the AST by mutable reference, allowing nodes to be changed in place.",
+        "I Language mutable AST visitor.",
+        "A visitor walking the AST by mutable reference. Every method defaults to visiting the children of the node.",
+    ),
+    Mode(
+        "fold",
+        "Fold",
+        "fold",
+        "A folder consuming the AST and building a new one, allowing nodes to be replaced.",
+        "I Language AST folder.",
+        "A folder consuming the AST and returning a new one. Every method defaults to folding the children of the node.",
+    ),
+]
+
+
+####################
+# HELPER FUNCTIONS #
+####################
+
+
+def snake_case(name: str) -> str:
+    """Converts a type name to snake case.
+
+    Args:
+        name (str): Name in pascal case.
+
+    Returns:
+        str: Name in snake case.
+    """
+
+    return re.sub(r"(?<!^)(?=[A-Z])", "_", name).lower()
+
+
+def article(name: str) -> str:
+    """Returns the indefinite article for a name.
+
+    Args:
+        name (str): Name to get the article for.
+
+    Returns:
+        str: Either "a" or "an".
+    """
+
+    return "an" if name[0].lower() in "aeio" else "a"
+
+
+def parse_nodes(text: str) -> typing.List[Node]:
+    """Parses all node types from the AST source.
+
+    Args:
+        text (str): Source of `ast.rs`.
+
+    Returns:
+        typing.List[Node]: All structs and enums, sorted by name.
+    """
+
+    nodes = [
+        Node(
+            match.group("name"),
+            False,
+            [(field.group("name"), field.group("type")) for field in FIELD_PATTERN.finditer(match.group("body"))],
+        )
+        for match in STRUCT_PATTERN.finditer(text)
+    ]
+    nodes.extend(
+        Node(
+            match.group("name"),
+            True,
+            [
+                (variant.group("name"), variant.group("type"))
+                for variant in VARIANT_PATTERN.finditer(match.group("body"))
+            ],
+        )
+        for match in ENUM_PATTERN.finditer(text)
+    )
+
+    return sorted(nodes, key=lambda node: node.name)
+
+
+def split_type(type_name: str) -> typing.Tuple[typing.Optional[str], str]:
+    """Splits a wrapper type into the wrapper and the inner type.
+
+    Args:
+        type_name (str): Type to split, e.g. `Option<Box<Expression>>`.
+
+    Returns:
+        typing.Tuple[typing.Optional[str], str]: The wrapper (or None) and the inner type.
+    """
+
+    match = re.match(r"^(?P<wrapper>\w+)<(?P<inner>.+)>$", type_name)
+    if match is not None and match.group("wrapper") in WRAPPERS:
+        return match.group("wrapper"), match.group("inner")
+
+    return None, type_name
+
+
+def contains_node(type_name: str, names: typing.Set[str]) -> bool:
+    """Checks whether a type contains a node type.
+
+    Args:
+        type_name (str): Type to check.
+        names (typing.Set[str]): Names of all node types.
+
+    Returns:
+        bool: Whether the type is or contains a node.
+    """
+
+    wrapper, inner = split_type(type_name)
+    return contains_node(inner, names) if wrapper else type_name in names
+
+
+def visit_code(mode: Mode, expression: str, type_name: str, names: typing.Set[str], depth: int = 0) -> str:
+    """Generates code visiting a value by reference.
+
+    Args:
+        mode (Mode): Visitor kind, either `visit` or `visit_mut`.
+        expression (str): Expression evaluating to a reference to the value.
+        type_name (str): Type of the value.
+        names (typing.Set[str]): Names of all node types.
+        depth (int): Nesting depth, used for naming variables. Defaults to 0.
+
+    Returns:
+        str: Generated statement.
+    """
+
+    wrapper, inner = split_type(type_name)
+    item = "item" if depth == 0 else f"item_{depth}"
+
+    if wrapper == "Box":
+        return visit_code(mode, expression, inner, names, depth)
+    if wrapper == "Vec":
+        return f"for {item} in {expression} {{ {visit_code(mode, item, inner, names, depth + 1)} }}"
+    if wrapper == "Option":
+        # Match the place instead of the reference, so the binding mode is explicit
+        if expression.startswith("&mut "):
+            place, binding = expression[len("&mut ") :], "ref mut"
+        elif expression.startswith("&"):
+            place, binding = expression[1:], "ref"
+        else:
+            place, binding = f"*{expression}", "ref mut" if mode.module == "visit_mut" else "ref"
+        return f"if let Some({binding} {item}) = {place} {{ {visit_code(mode, item, inner, names, depth + 1)} }}"
+
+    return f"visitor.visit_{snake_case(type_name)}({expression});"
+
+
+def fold_code(expression: str, type_name: str, names: typing.Set[str], depth: int = 0) -> str:
+    """Generates code folding a value.
+
+    Args:
+        expression (str): Expression evaluating to the owned value.
+        type_name (str): Type of the value.
+        names (typing.Set[str]): Names of all node types.
+        depth (int): Nesting depth, used for naming variables. Defaults to 0.
+
+    Returns:
+        str: Generated expression.
+    """
+
+    if not contains_node(type_name, names):
+        return expression
+
+    wrapper, inner = split_type(type_name)
+    item = "item" if depth == 0 else f"item_{depth}"
+
+    if wrapper == "Box":
+        return f"Box::new({fold_code(f'*{expression}', inner, names, depth)})"
+    if wrapper == "Vec":
+        return (
+            f"{expression}.into_iter().map(|{item}| {fold_code(item, inner, names, depth + 1)}).collect()"
+        )
+    if wrapper == "Option":
+        return f"{expression}.map(|{item}| {fold_code(item, inner, names, depth + 1)})"
+
+    return f"folder.fold_{snake_case(type_name)}({expression})"
+
+
+def generate_walk(mode: Mode, node: Node, names: typing.Set[str]) -> typing.List[str]:
+    """Generates the walk function of a node.
+
+    Args:
+        mode (Mode): Visitor kind.
+        node (Node): Node to generate the function for.
+        names (typing.Set[str]): Names of all node types.
+
+    Returns:
+        typing.List[str]: Lines of the generated function.
+    """
+
+    snake = snake_case(node.name)
+    reference = {"visit": "&'ast ", "visit_mut": "&mut ", "fold": ""}[mode.module]
+    borrow = {"visit": "&", "visit_mut": "&mut ", "fold": ""}[mode.module]
+    generics = "'ast, " if mode.module == "visit" else ""
+    bound = "<'ast>" if mode.module == "visit" else ""
+    lines = [f"/// Walks the children of {article(node.name)} [`{node.name}`] node.", "#[inline]"]
+
+    used = node.is_enum or any(contains_node(type_name, names) for _, type_name in node.members)
+
+    if mode.module == "fold":
+        folder = "folder" if used else "_folder"
+        lines.append(f"pub fn walk_{snake}<F: Fold + ?Sized>({folder}: &mut F, node: {node.name}) -> {node.name} {{")
+        if not used:
+            lines.append("node")
+        elif node.is_enum:
+            lines.append("match node {")
+            for variant, type_name in node.members:
+                if type_name is None:
+                    lines.append(f"{node.name}::{variant} => {node.name}::{variant},")
+                else:
+                    lines.append(
+                        f"{node.name}::{variant}(inner) => {node.name}::{variant}({fold_code('inner', type_name, names)}),"
+                    )
+            lines.append("}")
+        else:
+            lines.append(f"{node.name} {{")
+            for field, type_name in node.members:
+                lines.append(f"{field}: {fold_code(f'node.{field}', type_name, names)},")
+            lines.append("}")
+    else:
+        visitor = "visitor" if used else "_visitor"
+        argument = "node" if used else "_node"
+        lines.append(
+            f"pub fn walk_{snake}<{generics}V: {mode.trait}{bound} + ?Sized>({visitor}: &mut V, {argument}: {reference}{node.name}) {{"
+        )
+        if node.is_enum:
+            lines.append("match *node {")
+            # Variants with the same body share one arm, variants without children have an empty body
+            arms: typing.Dict[str, typing.List[str]] = {}
+            for variant, type_name in node.members:
+                if type_name is None or not contains_node(type_name, names):
+                    arms.setdefault("", []).append(f"{node.name}::{variant}{'(_)' if type_name else ''}")
+                else:
+                    binding = "ref inner" if mode.module == "visit" else "ref mut inner"
+                    body = visit_code(mode, "inner", type_name, names)
+                    arms.setdefault(body, []).append(f"{node.name}::{variant}({binding})")
+            for body, patterns in sorted(arms.items(), key=lambda arm: arm[0] == ""):
+                lines.append(f"{' | '.join(patterns)} => {{ {body} }}")
+            lines.append("}")
+        else:
+            for field, type_name in node.members:
+                if contains_node(type_name, names):
+                    lines.append(visit_code(mode, f"{borrow}node.{field}", type_name, names))
+
+    lines.append("}")
+    return lines
+
+
+def generate(mode: Mode, nodes: typing.List[Node]) -> str:
+    """Generates a visitor module.
+
+    Args:
+        mode (Mode): Visitor kind.
+        nodes (typing.List[Node]): All node types.
+
+    Returns:
+        str: Source of the generated module.
+    """
+
+    names = {node.name for node in nodes}
+    reference = {"visit": "&'ast ", "visit_mut": "&mut ", "fold": ""}[mode.module]
+    returns = {"visit": "", "visit_mut": "", "fold": " -> {name}"}[mode.module]
+    verb = "Folds" if mode.module == "fold" else "Visits"
+    lifetime = "<'ast>" if mode.module == "visit" else ""
+
+    lines = [
+        f"//! {mode.description}",
+        f"// {mode.title}",
+        "// Version: 1.0.0",
+        "",
+        LICENSE.rstrip("\n"),
+        "",
+        "// This file is generated by `tools/generate_visitors.py` from `ast.rs`. Do not edit it by hand,",
+        "// instead change the AST and run `just generate-visitors`.",
+        "",
+        "/////////////",
+        "// IMPORTS //",
+        "/////////////",
+        "",
+        f"use crate::ast::{{{', '.join(sorted(names))}}};",
+        "",
+        "",
+        "///////////",
+        "// TRAIT //",
+        "///////////",
+        "",
+        f"/// {mode.trait_description}",
+        "/// Override a method to handle a node type and call the matching `walk_*` function to continue with its children.",
+        *(
+            ["/// The `'ast` lifetime allows implementations to keep references to the visited nodes."]
+            if mode.module == "visit"
+            else []
+        ),
+        f"pub trait {mode.trait}{lifetime} {{",
+    ]
+
+    for node in nodes:
+        snake = snake_case(node.name)
+        lines.append(
+            f"/// {verb} {article(node.name)} [`{node.name}`] node. Defaults to [`walk_{snake}`]."
+        )
+        lines.append("#[inline]")
+        if mode.module == "fold":
+            lines.append(
+                f"fn fold_{snake}(&mut self, node: {node.name}){returns.format(name=node.name)} {{ walk_{snake}(self, node) }}"
+            )
+        else:
+            lines.append(f"fn visit_{snake}(&mut self, node: {reference}{node.name}) {{ walk_{snake}(self, node); }}")
+        lines.append("")
+
+    lines[-1] = "}"
+    lines.extend(["", "", "///////////", "// WALKS //", "///////////", ""])
+
+    for node in nodes:
+        lines.extend(generate_walk(mode, node, names))
+        lines.append("")
+
+    return "\n".join(lines)
+
+
+#################
+# MAIN FUNCTION #
+#################
+
+if __name__ == "__main__":
+    ast_nodes = parse_nodes(AST_PATH.read_text(encoding="utf-8"))
+
+    for visitor_mode in MODES:
+        path = pathlib.Path(OUTPUT_FOLDER, f"{visitor_mode.module}.rs")
+        with path.open("w", encoding="utf-8") as _file:
+            _file.write(generate(visitor_mode, ast_nodes))
+
+    print(f"Generated visitors for {len(ast_nodes)} node types")